@@ -51,6 +51,9 @@ object FixedSizeField {
 
   // 8-bit integer
   val unsignedByte = FixedSizeField("value", UInt8Field, Some(FieldAttribute.Value))
+
+  // association of a security statistic
+  val associationId = FixedSizeField("association_id", UInt16Field)
 }
 
 object VariableFields {
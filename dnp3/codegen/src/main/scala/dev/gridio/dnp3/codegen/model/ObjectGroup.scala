@@ -35,9 +35,20 @@ object ObjectGroup {
     Group60,
     Group70,
     Group80,
+    Group85,
+    Group86,
+    Group87,
+    Group88,
+    Group90,
+    Group91,
     Group102,
     Group110,
     Group111,
+    Group112,
+    Group113,
+    Group120,
+    Group121,
+    Group122,
   )
 
 }
@@ -68,6 +79,7 @@ object GroupType {
   object StaticOctetString extends Static
 
   object StaticUnsignedInteger extends Static
+  object StaticSecurityStatistic extends Static
 
   object BinaryEvent extends Event
   object BinaryOutputEvent extends Event
@@ -81,13 +93,18 @@ object GroupType {
   object AnalogOutputCommandEvent extends Event
   object OctetStringEvent extends Event
   object VirtualTerminalEvent extends Event
+  object SecurityStatisticEvent extends Event
 
   object Command extends GroupType
   object Time extends GroupType
   object ClassData extends GroupType
   object FileControl extends GroupType
+  object ApplicationIdentifier extends GroupType
+  object ActivateConfig extends GroupType
+  object DataSet extends GroupType
   object InternalIndications extends GroupType
   object VirtualTerminalOutput extends GroupType
+  object Authentication extends GroupType
 
   object DeviceAttributes extends GroupType
 }
@@ -49,6 +49,9 @@ trait Variation {
 
 class FreeFormat(g: ObjectGroup, v: Byte, desc: String) extends BasicGroupVariation(g, Variation.Value(v), desc)
 
+// variable length object whose encoding is implemented by hand
+class VariableSize(g: ObjectGroup, v: Byte, desc: String) extends BasicGroupVariation(g, Variation.Value(v), desc)
+
 class AnyVariation(g: ObjectGroup, v: Byte) extends BasicGroupVariation(g, Variation.Value(v), "Any Variation")
 
 class ClassData(g: ObjectGroup, v: Byte, desc: String) extends BasicGroupVariation(g,  Variation.Value(v), desc)
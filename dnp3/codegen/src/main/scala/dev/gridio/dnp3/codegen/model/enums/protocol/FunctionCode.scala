@@ -45,8 +45,12 @@ object FunctionCode extends EnumModel {
     EnumValue("GetFileInfo", 28, "Outstation shall retrieve information about a file"),
     EnumValue("AuthenticateFile", 29, "Outstation shall return a file authentication key"),
     EnumValue("AbortFile", 30, "Outstation shall abort a file transfer operation"),
+    EnumValue("ActivateConfig", 31, "Outstation shall activate the configuration specified by the objects in the request"),
+    EnumValue("AuthRequest", 32, "Master sends this to an outstation to authenticate a critical request or change session keys"),
+    EnumValue("AuthRequestNoAck", 33, "Same as AuthRequest but outstation shall not send a response"),
     EnumValue("Response", 129, "Master shall interpret this fragment as an Application Layer response to an ApplicationLayer request"),
     EnumValue("UnsolicitedResponse", 130, "Master shall interpret this fragment as an unsolicited response that was not prompted by an explicit request"),
+    EnumValue("AuthResponse", 131, "Outstation sends this to challenge a critical request or report the status of the session keys"),
   )
 
 }
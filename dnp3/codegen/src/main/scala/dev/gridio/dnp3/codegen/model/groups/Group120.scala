@@ -0,0 +1,48 @@
+package dev.gridio.dnp3.codegen.model.groups
+
+import dev.gridio.dnp3.codegen.model._
+
+object Group120 extends ObjectGroup {
+  def variations: List[Variation] = List(
+    Group120Var1,
+    Group120Var2,
+    Group120Var3,
+    Group120Var4,
+    Group120Var5,
+    Group120Var6,
+    Group120Var7,
+    Group120Var9,
+    Group120Var10,
+    Group120Var11,
+    Group120Var12,
+    Group120Var13,
+    Group120Var14,
+    Group120Var15,
+  )
+
+  def group: Byte = 120
+
+  def desc: String = "Authentication"
+
+  override def groupType: GroupType = GroupType.Authentication
+}
+
+object Group120Var1 extends FreeFormat(Group120, 1, "challenge")
+object Group120Var2 extends FreeFormat(Group120, 2, "reply")
+object Group120Var3 extends FixedSize(Group120, 3, "aggressive mode request")(
+  FixedSizeField("challenge_seq", UInt32Field),
+  FixedSizeField("user", UInt16Field),
+)
+object Group120Var4 extends FixedSize(Group120, 4, "session key status request")(
+  FixedSizeField("user", UInt16Field),
+)
+object Group120Var5 extends FreeFormat(Group120, 5, "session key status")
+object Group120Var6 extends FreeFormat(Group120, 6, "session key change")
+object Group120Var7 extends FreeFormat(Group120, 7, "error")
+object Group120Var9 extends FreeFormat(Group120, 9, "HMAC")
+object Group120Var10 extends FreeFormat(Group120, 10, "user status change")
+object Group120Var11 extends FreeFormat(Group120, 11, "update key change request")
+object Group120Var12 extends FreeFormat(Group120, 12, "update key change reply")
+object Group120Var13 extends FreeFormat(Group120, 13, "update key change")
+object Group120Var14 extends FreeFormat(Group120, 14, "update key change signature")
+object Group120Var15 extends FreeFormat(Group120, 15, "update key change confirmation")
@@ -0,0 +1,20 @@
+package dev.gridio.dnp3.codegen.model.groups
+
+import dev.gridio.dnp3.codegen.model._
+import dev.gridio.dnp3.codegen.model.FixedSizeField._
+import dev.gridio.dnp3.codegen.model.VariationNames._
+
+// security statistic
+object Group121 extends ObjectGroup {
+  def variations: List[Variation] = List(Group121Var0, Group121Var1)
+
+  def group: Byte = 121
+
+  def desc: String = "Security Statistic"
+
+  override def groupType: GroupType = GroupType.StaticSecurityStatistic
+}
+
+object Group121Var0 extends AnyVariation(Group121, 0)
+
+object Group121Var1 extends FixedSize(Group121, 1, bit32WithFlag)(flags, associationId, count32)
@@ -0,0 +1,22 @@
+package dev.gridio.dnp3.codegen.model.groups
+
+import dev.gridio.dnp3.codegen.model._
+import dev.gridio.dnp3.codegen.model.FixedSizeField._
+import dev.gridio.dnp3.codegen.model.VariationNames._
+
+// security statistic event
+object Group122 extends ObjectGroup {
+  def variations: List[Variation] = List(Group122Var0, Group122Var1, Group122Var2)
+
+  def group: Byte = 122
+
+  def desc: String = "Security Statistic Event"
+
+  override def groupType: GroupType = GroupType.SecurityStatisticEvent
+}
+
+object Group122Var0 extends AnyVariation(Group122, 0)
+
+object Group122Var1 extends FixedSize(Group122, 1, bit32WithFlag)(flags, associationId, count32)
+
+object Group122Var2 extends FixedSize(Group122, 2, bit32WithFlagTime)(flags, associationId, count32, time48)
@@ -0,0 +1,15 @@
+package dev.gridio.dnp3.codegen.model.groups
+
+import dev.gridio.dnp3.codegen.model._
+
+object Group86 extends ObjectGroup {
+  def variations: List[Variation] = List(Group86Var1)
+
+  def group: Byte = 86
+
+  def desc: String = "Data-Set Descriptor"
+
+  override def groupType: GroupType = GroupType.DataSet
+}
+
+object Group86Var1 extends FreeFormat(Group86, 1, "Data-Set Contents")
@@ -0,0 +1,15 @@
+package dev.gridio.dnp3.codegen.model.groups
+
+import dev.gridio.dnp3.codegen.model._
+
+object Group87 extends ObjectGroup {
+  def variations: List[Variation] = List(Group87Var1)
+
+  def group: Byte = 87
+
+  def desc: String = "Data-Set"
+
+  override def groupType: GroupType = GroupType.DataSet
+}
+
+object Group87Var1 extends FreeFormat(Group87, 1, "Present Value")
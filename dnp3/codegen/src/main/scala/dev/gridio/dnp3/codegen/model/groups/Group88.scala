@@ -0,0 +1,15 @@
+package dev.gridio.dnp3.codegen.model.groups
+
+import dev.gridio.dnp3.codegen.model._
+
+object Group88 extends ObjectGroup {
+  def variations: List[Variation] = List(Group88Var1)
+
+  def group: Byte = 88
+
+  def desc: String = "Data-Set Event"
+
+  override def groupType: GroupType = GroupType.DataSet
+}
+
+object Group88Var1 extends FreeFormat(Group88, 1, "Snapshot")
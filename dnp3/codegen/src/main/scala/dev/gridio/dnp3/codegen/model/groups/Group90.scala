@@ -0,0 +1,15 @@
+package dev.gridio.dnp3.codegen.model.groups
+
+import dev.gridio.dnp3.codegen.model._
+
+object Group90 extends ObjectGroup {
+  def variations: List[Variation] = List(Group90Var1)
+
+  def group: Byte = 90
+
+  def desc: String = "Application Identifier"
+
+  override def groupType: GroupType = GroupType.ApplicationIdentifier
+}
+
+object Group90Var1 extends FreeFormat(Group90, 1, "Application Identifier")
@@ -0,0 +1,15 @@
+package dev.gridio.dnp3.codegen.model.groups
+
+import dev.gridio.dnp3.codegen.model._
+
+object Group91 extends ObjectGroup {
+  def variations: List[Variation] = List(Group91Var1)
+
+  def group: Byte = 91
+
+  def desc: String = "Activate Configuration"
+
+  override def groupType: GroupType = GroupType.ActivateConfig
+}
+
+object Group91Var1 extends VariableSize(Group91, 1, "Status of Requested Operations")
@@ -1,7 +1,7 @@
 package dev.gridio.dnp3.codegen.render.modules
 
 import dev.gridio.dnp3.codegen.model._
-import dev.gridio.dnp3.codegen.model.groups.{AllAttributesRequest, Group0, Group110, Group111, SpecificAttribute}
+import dev.gridio.dnp3.codegen.model.groups.{AllAttributesRequest, Group0, Group110, Group111, Group90Var1, SpecificAttribute}
 import dev.gridio.dnp3.codegen.render.*
 import dev.gridio.dnp3.codegen.render.given_Conversion_String_RenderString
 
@@ -58,6 +58,7 @@ object AllObjectsVariationModule extends Module {
       case v : FixedSize if v.parent.groupType.isStatic || v.parent.groupType.isEvent => v
       case v : FixedSize if v.parent.groupType == GroupType.AnalogInputDeadband => v
       case v if v.parent == Group110 || v.parent == Group111 => v
+      case Group90Var1 => Group90Var1
       case AllAttributesRequest => AllAttributesRequest
       case SpecificAttribute => SpecificAttribute
     }
@@ -9,6 +9,7 @@ object CountVariationModule extends Module {
 
   override def lines(implicit indent: Indentation) : Iterator[String] = {
       "use crate::app::variations::*;".eol ++ "use crate::app::QualifierCode;".eol ++
+      "use crate::app::activation::Group91Var1;".eol ++
       "use crate::app::parse::count::CountSequence;".eol ++
       "use crate::app::parse::parser::*;".eol ++
       "use crate::app::ObjectParseError;".eol ++
@@ -25,6 +26,7 @@ object CountVariationModule extends Module {
     def definition(v : Variation): Iterator[String] = {
       v match {
         case v : FixedSize if v.parent.groupType == GroupType.Time => s"${v.name}(CountSequence<'a, ${v.name}>),".eol
+        case v : VariableSize => s"${v.name}(${v.name}<'a>),".eol
         case _ : SizedByVariation => {
             s"${v.parent.name}Var0,".eol ++
             s"${v.parent.name}VarX(u8),".eol
@@ -45,6 +47,7 @@ object CountVariationModule extends Module {
     def parseMatcher(v : Variation) : Iterator[String] = {
       v match {
         case v : FixedSize if v.parent.groupType == GroupType.Time => s"Variation::${v.name} => Ok(CountVariation::${v.name}(CountSequence::parse(count, cursor)?)),".eol
+        case v : VariableSize => s"Variation::${v.name} => Ok(CountVariation::${v.name}(${v.name}::parse(count, cursor)?)),".eol
         case _ : SizedByVariation => {
             s"Variation::${v.parent.name}(0) => Ok(CountVariation::${v.parent.name}Var0),".eol ++
             s"Variation::${v.parent.name}(x) => Ok(CountVariation::${v.parent.name}VarX(x)),".eol
@@ -55,6 +58,7 @@ object CountVariationModule extends Module {
     def fmtMatcher(v : Variation) : Iterator[String] = {
       v match {
         case v : FixedSize if v.parent.groupType == GroupType.Time => s"CountVariation::${v.name}(seq) => format_count_of_items(f, seq.iter()),".eol
+        case v : VariableSize => s"CountVariation::${v.name}(x) => x.format(f),".eol
         case _ : SizedByVariation => {
             s"CountVariation::${v.parent.name}Var0 => Ok(()),".eol ++
             s"CountVariation::${v.parent.name}VarX(_) => Ok(()),".eol
@@ -86,6 +90,7 @@ object CountVariationModule extends Module {
         case v : SizedByVariation if v.parent.groupType.isEvent => Some(v)
         case v : ClassData if v != Group60Var1 => Some(v)
         case v : FixedSize if v.parent.groupType == GroupType.Time => Some(v)
+        case v : VariableSize => Some(v)
         case _ => None
       }
     }
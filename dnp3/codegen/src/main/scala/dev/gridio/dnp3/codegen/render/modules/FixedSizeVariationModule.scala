@@ -120,6 +120,8 @@ object FixedSizeVariationModule extends Module {
           case GroupType.CounterEvent => counter
           case GroupType.StaticFrozenCounter => counter
           case GroupType.FrozenCounterEvent => counter
+          case GroupType.StaticSecurityStatistic => counter
+          case GroupType.SecurityStatisticEvent => counter
           case GroupType.BinaryOutputEvent => binaryOutputStatus
           case GroupType.StaticBinaryOutputStatus => binaryOutputStatus
           case GroupType.StaticDoubleBinary => doubleBitBinary
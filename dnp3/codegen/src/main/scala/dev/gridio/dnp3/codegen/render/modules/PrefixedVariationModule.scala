@@ -100,6 +100,7 @@ object PrefixedVariationModule extends Module {
         case GroupType.AnalogOutputEvent => "analog_output_status"
         case GroupType.AnalogOutputCommandEvent => "analog_output_command_event"
         case GroupType.BinaryOutputCommandEvent => "binary_output_command_event"
+        case GroupType.SecurityStatisticEvent => "security_statistic"
         case _ => throw new Exception("unhandled variation")
       }
 
@@ -135,6 +135,7 @@ object RangedVariationModule extends Module {
         case GroupType.StaticFrozenCounter => "frozen_counter"
         case GroupType.AnalogInputDeadband => "analog_input_dead_band"
         case GroupType.StaticUnsignedInteger => "unsigned_integer"
+        case GroupType.StaticSecurityStatistic => "security_statistic"
         case _ => throw new Exception("unhandled variation")
       }
     }
@@ -0,0 +1,178 @@
+use crate::app::format::WriteError;
+use crate::app::ObjectParseError;
+
+use scursor::{ReadCursor, WriteCursor};
+
+/// Group 91 Variation 1 - Status of requested operations
+///
+/// Each status element is encoded as a length octet followed by the status code and any
+/// ancillary data. The length counts the status code and the ancillary data.
+///
+/// This representation is borrowed from the underlying ASDU
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct Group91Var1<'a> {
+    pub(crate) time_delay_ms: u32,
+    count: u8,
+    elements: &'a [u8],
+}
+
+/// A single status element within g91v1
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct StatusElement<'a> {
+    pub(crate) code: u8,
+    pub(crate) ancillary: &'a [u8],
+}
+
+impl<'a> Group91Var1<'a> {
+    pub(crate) fn iter(&self) -> StatusElementIterator<'a> {
+        StatusElementIterator {
+            remaining: self.count,
+            cursor: ReadCursor::new(self.elements),
+        }
+    }
+
+    pub(crate) fn format(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "\ntime delay: {} ms", self.time_delay_ms)?;
+        for element in self.iter() {
+            write!(
+                f,
+                "\nstatus code: {} ancillary length: {}",
+                element.code,
+                element.ancillary.len()
+            )?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn parse(count: u16, cursor: &mut ReadCursor<'a>) -> Result<Self, ObjectParseError> {
+        // the object is variable length so a header may only contain a single instance
+        if count != 1 {
+            return Err(ObjectParseError::BadEncoding);
+        }
+
+        let time_delay_ms = cursor.read_u32_le()?;
+        let num_elements = cursor.read_u8()?;
+
+        // validate the elements before borrowing them
+        let mut scan = *cursor;
+        for _ in 0..num_elements {
+            let length = scan.read_u8()?;
+            if length == 0 {
+                // every element contains at least a status code
+                return Err(ObjectParseError::BadEncoding);
+            }
+            scan.read_bytes(length as usize)?;
+        }
+
+        let elements = cursor.read_bytes(cursor.remaining() - scan.remaining())?;
+
+        Ok(Self {
+            time_delay_ms,
+            count: num_elements,
+            elements,
+        })
+    }
+
+    pub(crate) fn write(
+        cursor: &mut WriteCursor,
+        time_delay_ms: u32,
+        elements: &[StatusElement],
+    ) -> Result<(), WriteError> {
+        let count: u8 = elements
+            .len()
+            .try_into()
+            .map_err(|_| WriteError::Overflow)?;
+        cursor.write_u32_le(time_delay_ms)?;
+        cursor.write_u8(count)?;
+        for element in elements {
+            let length: u8 = (element.ancillary.len() + 1)
+                .try_into()
+                .map_err(|_| WriteError::Overflow)?;
+            cursor.write_u8(length)?;
+            cursor.write_u8(element.code)?;
+            cursor.write_bytes(element.ancillary)?;
+        }
+        Ok(())
+    }
+}
+
+pub(crate) struct StatusElementIterator<'a> {
+    remaining: u8,
+    cursor: ReadCursor<'a>,
+}
+
+impl<'a> Iterator for StatusElementIterator<'a> {
+    type Item = StatusElement<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        // the elements were validated during parsing
+        let length = self.cursor.read_u8().ok()?;
+        let code = self.cursor.read_u8().ok()?;
+        let ancillary = self.cursor.read_bytes(length as usize - 1).ok()?;
+
+        Some(StatusElement { code, ancillary })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const ELEMENTS: &[StatusElement] = &[
+        StatusElement {
+            code: 0,
+            ancillary: &[],
+        },
+        StatusElement {
+            code: 4,
+            ancillary: b"bad",
+        },
+    ];
+
+    const DATA: &[u8] = &[
+        0xE8, 0x03, 0x00, 0x00, 0x02, 0x01, 0x00, 0x04, 0x04, b'b', b'a', b'd',
+    ];
+
+    #[test]
+    fn writes_valid_object() {
+        let mut buffer = [0; 64];
+
+        let mut cursor = WriteCursor::new(&mut buffer);
+        Group91Var1::write(&mut cursor, 1000, ELEMENTS).unwrap();
+
+        assert_eq!(cursor.written(), DATA)
+    }
+
+    #[test]
+    fn parses_valid_object() {
+        let mut cursor = ReadCursor::new(DATA);
+        let obj = Group91Var1::parse(1, &mut cursor).unwrap();
+
+        assert_eq!(obj.time_delay_ms, 1000);
+        assert_eq!(obj.iter().collect::<Vec<_>>(), ELEMENTS);
+        assert!(cursor.is_empty());
+    }
+
+    #[test]
+    fn rejects_truncated_element() {
+        let mut cursor = ReadCursor::new(&DATA[..DATA.len() - 1]);
+        assert_eq!(
+            Group91Var1::parse(1, &mut cursor),
+            Err(ObjectParseError::InsufficientBytes)
+        );
+    }
+
+    #[test]
+    fn rejects_count_other_than_one() {
+        let mut cursor = ReadCursor::new(DATA);
+        assert_eq!(
+            Group91Var1::parse(2, &mut cursor),
+            Err(ObjectParseError::BadEncoding)
+        );
+    }
+}
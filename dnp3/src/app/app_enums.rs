@@ -136,10 +136,18 @@ pub enum FunctionCode {
     AuthenticateFile,
     ///  Outstation shall abort a file transfer operation (value == 30)
     AbortFile,
+    ///  Outstation shall activate the configuration specified by the objects in the request (value == 31)
+    ActivateConfig,
+    ///  Master sends this to an outstation to authenticate a critical request or change session keys (value == 32)
+    AuthRequest,
+    ///  Same as AuthRequest but outstation shall not send a response (value == 33)
+    AuthRequestNoAck,
     ///  Master shall interpret this fragment as an Application Layer response to an ApplicationLayer request (value == 129)
     Response,
     ///  Master shall interpret this fragment as an unsolicited response that was not prompted by an explicit request (value == 130)
     UnsolicitedResponse,
+    ///  Outstation sends this to challenge a critical request or report the status of the session keys (value == 131)
+    AuthResponse,
 }
 
 impl FunctionCode {
@@ -178,8 +186,12 @@ impl FunctionCode {
             28 => Some(FunctionCode::GetFileInfo),
             29 => Some(FunctionCode::AuthenticateFile),
             30 => Some(FunctionCode::AbortFile),
+            31 => Some(FunctionCode::ActivateConfig),
+            32 => Some(FunctionCode::AuthRequest),
+            33 => Some(FunctionCode::AuthRequestNoAck),
             129 => Some(FunctionCode::Response),
             130 => Some(FunctionCode::UnsolicitedResponse),
+            131 => Some(FunctionCode::AuthResponse),
             _ => None,
         }
     }
@@ -218,8 +230,12 @@ impl FunctionCode {
             FunctionCode::GetFileInfo => 28,
             FunctionCode::AuthenticateFile => 29,
             FunctionCode::AbortFile => 30,
+            FunctionCode::ActivateConfig => 31,
+            FunctionCode::AuthRequest => 32,
+            FunctionCode::AuthRequestNoAck => 33,
             FunctionCode::Response => 129,
             FunctionCode::UnsolicitedResponse => 130,
+            FunctionCode::AuthResponse => 131,
         }
     }
     
@@ -0,0 +1,70 @@
+use crate::app::ObjectParseError;
+
+use scursor::ReadCursor;
+
+/// Group 90 Variation 1 - Application identifier
+///
+/// This representation is borrowed from the underlying ASDU
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct Group90Var1<'a> {
+    pub(crate) name: &'a str,
+}
+
+impl<'a> Group90Var1<'a> {
+    pub(crate) fn format(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "\napplication: {}", self.name)?;
+        Ok(())
+    }
+
+    // not using this in production yet
+    #[cfg(test)]
+    pub(crate) fn write(
+        &self,
+        cursor: &mut scursor::WriteCursor,
+    ) -> Result<(), scursor::WriteError> {
+        cursor.write_bytes(self.name.as_bytes())
+    }
+
+    pub(crate) fn read(cursor: &mut ReadCursor<'a>) -> Result<Self, ObjectParseError> {
+        let name =
+            std::str::from_utf8(cursor.read_all()).map_err(|_| ObjectParseError::BadEncoding)?;
+        Ok(Self { name })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const OBJECT: Group90Var1 = Group90Var1 { name: "logic" };
+
+    const DATA: &[u8] = b"logic";
+
+    #[test]
+    fn writes_valid_object() {
+        let mut buffer = [0; 64];
+
+        let mut cursor = scursor::WriteCursor::new(&mut buffer);
+        OBJECT.write(&mut cursor).unwrap();
+
+        assert_eq!(cursor.written(), DATA)
+    }
+
+    #[test]
+    fn parses_valid_object() {
+        let mut cursor = ReadCursor::new(DATA);
+        let obj = Group90Var1::read(&mut cursor).unwrap();
+
+        assert_eq!(obj, OBJECT);
+        assert!(cursor.is_empty());
+    }
+
+    #[test]
+    fn rejects_invalid_utf8() {
+        let mut cursor = ReadCursor::new(&[0xFF, 0xFE]);
+        assert_eq!(
+            Group90Var1::read(&mut cursor),
+            Err(ObjectParseError::BadEncoding)
+        );
+    }
+}
@@ -1052,14 +1052,14 @@ impl From<WriteError> for AttrWriteError {
 }
 
 #[derive(Copy, Clone)]
-enum UInt {
+pub(crate) enum UInt {
     U8(u8),
     U16(u16),
     U32(u32),
 }
 
 impl UInt {
-    fn new(value: u32) -> Self {
+    pub(crate) fn new(value: u32) -> Self {
         if value <= u8::MAX as u32 {
             Self::U8(value as u8)
         } else if value <= u16::MAX as u32 {
@@ -1069,7 +1069,7 @@ impl UInt {
         }
     }
 
-    fn len(self) -> u8 {
+    pub(crate) fn len(self) -> u8 {
         match self {
             UInt::U8(_) => 1,
             UInt::U16(_) => 2,
@@ -1077,7 +1077,7 @@ impl UInt {
         }
     }
 
-    fn write(self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+    pub(crate) fn write(self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
         match self {
             UInt::U8(x) => cursor.write_u8(x),
             UInt::U16(x) => cursor.write_u16_le(x),
@@ -1087,7 +1087,7 @@ impl UInt {
 }
 
 #[derive(Copy, Clone)]
-enum Int {
+pub(crate) enum Int {
     I8(u8),
     I16(i16),
     I32(i32),
@@ -1097,7 +1097,7 @@ impl Int {
     const I8_RANGE: core::ops::Range<i32> = i8::MIN as i32..i8::MAX as i32;
     const I16_RANGE: core::ops::Range<i32> = i16::MIN as i32..i16::MAX as i32;
 
-    fn new(value: i32) -> Self {
+    pub(crate) fn new(value: i32) -> Self {
         if Self::I8_RANGE.contains(&value) {
             Self::I8(value as u8)
         } else if Self::I16_RANGE.contains(&value) {
@@ -1107,7 +1107,7 @@ impl Int {
         }
     }
 
-    fn len(self) -> u8 {
+    pub(crate) fn len(self) -> u8 {
         match self {
             Self::I8(_) => 1,
             Self::I16(_) => 2,
@@ -1115,7 +1115,7 @@ impl Int {
         }
     }
 
-    fn write(self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+    pub(crate) fn write(self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
         match self {
             Self::I8(x) => cursor.write_u8(x),
             Self::I16(x) => cursor.write_i16_le(x),
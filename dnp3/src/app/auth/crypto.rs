@@ -0,0 +1,381 @@
+use crate::app::auth::{AuthorityKey, KeyWrapAlgorithm, MacAlgorithm, SessionKeys, UpdateKey};
+
+use aes_kw::{KekAes128, KekAes256};
+use hmac::{Hmac, Mac};
+use rand::RngCore;
+use sha2::Sha256;
+
+/// RFC 3394 operates on 64-bit blocks
+const KEY_WRAP_BLOCK_SIZE: usize = 8;
+
+pub(crate) fn random_bytes(length: usize) -> Vec<u8> {
+    let mut data = vec![0; length];
+    rand::rng().fill_bytes(&mut data);
+    data
+}
+
+/// Calculate the untruncated HMAC-SHA-256 over the concatenation of `data`
+pub(crate) fn hmac_sha256(key: &[u8], data: &[&[u8]]) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    for part in data {
+        mac.update(part);
+    }
+    mac.finalize().into_bytes().to_vec()
+}
+
+/// Calculate the MAC over the concatenation of `data` truncated to the length required by the algorithm
+pub(crate) fn calc_mac(algorithm: MacAlgorithm, key: &[u8], data: &[&[u8]]) -> Vec<u8> {
+    let mut value = hmac_sha256(key, data);
+    value.truncate(algorithm.length());
+    value
+}
+
+/// Compare a received MAC value without leaking how many octets matched
+fn mac_matches(expected: &[u8], received: &[u8]) -> bool {
+    expected.len() == received.len()
+        && expected
+            .iter()
+            .zip(received)
+            .fold(0, |acc, (x, y)| acc | (x ^ y))
+            == 0
+}
+
+/// Verify a received MAC value without leaking how many octets matched
+pub(crate) fn verify_mac(
+    algorithm: MacAlgorithm,
+    key: &[u8],
+    data: &[&[u8]],
+    received: &[u8],
+) -> bool {
+    mac_matches(&calc_mac(algorithm, key, data), received)
+}
+
+/// Verify a received untruncated HMAC-SHA-256 value
+pub(crate) fn verify_hmac_sha256(key: &[u8], data: &[&[u8]], received: &[u8]) -> bool {
+    mac_matches(&hmac_sha256(key, data), received)
+}
+
+fn pad_to_key_wrap_block(data: &mut Vec<u8>) {
+    while data.len() % KEY_WRAP_BLOCK_SIZE != 0 {
+        data.push(0);
+    }
+}
+
+/// Wrap the session keys for a session key change (g120v6)
+///
+/// The plaintext is the length of each key, the control and monitoring direction keys, and the
+/// session key status object (g120v5) that preceded the change. It is padded with zeros
+/// to a multiple of the key wrap block size.
+pub(crate) fn wrap_session_keys(
+    update_key: &UpdateKey,
+    keys: &SessionKeys,
+    key_status: &[u8],
+) -> Vec<u8> {
+    let mut data = Vec::new();
+    data.extend_from_slice(&(keys.control.len() as u16).to_le_bytes());
+    data.extend_from_slice(&keys.control);
+    data.extend_from_slice(&keys.monitoring);
+    data.extend_from_slice(key_status);
+    pad_to_key_wrap_block(&mut data);
+
+    let wrapped = match update_key.algorithm() {
+        KeyWrapAlgorithm::Aes128 => {
+            KekAes128::try_from(update_key.data()).and_then(|kek| kek.wrap_vec(&data))
+        }
+        KeyWrapAlgorithm::Aes256 => {
+            KekAes256::try_from(update_key.data()).and_then(|kek| kek.wrap_vec(&data))
+        }
+    };
+
+    // the key length is fixed by the algorithm and the data is always padded
+    wrapped.expect("key wrap inputs are always valid")
+}
+
+/// Unwrap the session keys from a session key change (g120v6)
+///
+/// Returns the keys and the remainder of the plaintext which starts with the
+/// session key status object (g120v5) that preceded the change
+pub(crate) fn unwrap_session_keys(
+    update_key: &UpdateKey,
+    wrapped: &[u8],
+) -> Option<(SessionKeys, Vec<u8>)> {
+    let data = match update_key.algorithm() {
+        KeyWrapAlgorithm::Aes128 => {
+            KekAes128::try_from(update_key.data()).and_then(|kek| kek.unwrap_vec(wrapped))
+        }
+        KeyWrapAlgorithm::Aes256 => {
+            KekAes256::try_from(update_key.data()).and_then(|kek| kek.unwrap_vec(wrapped))
+        }
+    }
+    .ok()?;
+
+    let mut cursor = scursor::ReadCursor::new(&data);
+    let length = cursor.read_u16_le().ok()? as usize;
+    if length != update_key.algorithm().key_length() {
+        return None;
+    }
+    let control = cursor.read_bytes(length).ok()?.to_vec();
+    let monitoring = cursor.read_bytes(length).ok()?.to_vec();
+    let remainder = cursor.read_all().to_vec();
+
+    Some((
+        SessionKeys {
+            control,
+            monitoring,
+        },
+        remainder,
+    ))
+}
+
+/// Plaintext of an update key change (g120v13)
+///
+/// The plaintext is the length of the key, the new update key, the user name, and the challenge
+/// data of the outstation's reply (g120v12) which binds the change to that particular exchange
+pub(crate) fn update_key_data(
+    update_key: &UpdateKey,
+    user_name: &[u8],
+    challenge_data: &[u8],
+) -> Vec<u8> {
+    let mut data = Vec::new();
+    data.extend_from_slice(&(update_key.data().len() as u16).to_le_bytes());
+    data.extend_from_slice(update_key.data());
+    data.extend_from_slice(user_name);
+    data.extend_from_slice(challenge_data);
+    data
+}
+
+/// Extract the new update key from the plaintext of an update key change
+///
+/// Returns `None` unless the plaintext names the expected user and challenge data
+pub(crate) fn parse_update_key_data(
+    data: &[u8],
+    user_name: &[u8],
+    challenge_data: &[u8],
+) -> Option<UpdateKey> {
+    let mut cursor = scursor::ReadCursor::new(data);
+    let length = cursor.read_u16_le().ok()? as usize;
+    let update_key = UpdateKey::from_data(cursor.read_bytes(length).ok()?)?;
+    let remainder = cursor.read_all();
+    let expected = [user_name, challenge_data].concat();
+    remainder.starts_with(&expected).then_some(update_key)
+}
+
+/// Encrypt the plaintext of an update key change with the authority key (symmetric method)
+pub(crate) fn wrap_update_key(authority_key: &AuthorityKey, plaintext: &[u8]) -> Vec<u8> {
+    let mut data = plaintext.to_vec();
+    pad_to_key_wrap_block(&mut data);
+    KekAes256::try_from(authority_key.data())
+        .and_then(|kek| kek.wrap_vec(&data))
+        // the key length is fixed and the data is always padded
+        .expect("key wrap inputs are always valid")
+}
+
+/// Decrypt the plaintext of an update key change with the authority key (symmetric method)
+pub(crate) fn unwrap_update_key(authority_key: &AuthorityKey, wrapped: &[u8]) -> Option<Vec<u8>> {
+    KekAes256::try_from(authority_key.data())
+        .and_then(|kek| kek.unwrap_vec(wrapped))
+        .ok()
+}
+
+/// MAC that confirms an update key change (g120v15)
+///
+/// Each side proves that it holds the new update key by authenticating, in the order given by
+/// IEEE 1815, the name of the other side, the challenge data of both sides with its own first,
+/// the key change sequence number, and the user number. The master uses the name of the
+/// outstation and the outstation uses the name of the user.
+pub(crate) fn update_key_confirmation(
+    update_key: &UpdateKey,
+    name: &[u8],
+    own_challenge_data: &[u8],
+    other_challenge_data: &[u8],
+    key_change_seq: u32,
+    user: u16,
+) -> Vec<u8> {
+    hmac_sha256(
+        update_key.data(),
+        &[
+            name,
+            own_challenge_data,
+            other_challenge_data,
+            &key_change_seq.to_le_bytes(),
+            &user.to_le_bytes(),
+        ],
+    )
+}
+
+/// Verify the MAC that confirms an update key change (g120v15)
+pub(crate) fn verify_update_key_confirmation(
+    update_key: &UpdateKey,
+    name: &[u8],
+    own_challenge_data: &[u8],
+    other_challenge_data: &[u8],
+    key_change_seq: u32,
+    user: u16,
+    received: &[u8],
+) -> bool {
+    mac_matches(
+        &update_key_confirmation(
+            update_key,
+            name,
+            own_challenge_data,
+            other_challenge_data,
+            key_change_seq,
+            user,
+        ),
+        received,
+    )
+}
+
+/// Data signed with the private key of the user in an asymmetric update key change (g120v14)
+pub(crate) fn update_key_signature_data(
+    master_challenge_data: &[u8],
+    outstation_challenge_data: &[u8],
+    key_change_seq: u32,
+    user: u16,
+    encrypted_key_data: &[u8],
+) -> Vec<u8> {
+    [
+        master_challenge_data,
+        outstation_challenge_data,
+        &key_change_seq.to_le_bytes(),
+        &user.to_le_bytes(),
+        encrypted_key_data,
+    ]
+    .concat()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn hmac_sha256_matches_rfc_4231_test_case_2() {
+        let mac = calc_mac(
+            MacAlgorithm::HmacSha256Trunc16,
+            b"Jefe",
+            &[b"what do ya want ", b"for nothing?"],
+        );
+        assert_eq!(
+            mac,
+            [
+                0x5b, 0xdc, 0xc1, 0x46, 0xbf, 0x60, 0x75, 0x4e, 0x6a, 0x04, 0x24, 0x26, 0x08, 0x95,
+                0x75, 0xc7
+            ]
+        );
+        assert!(verify_mac(
+            MacAlgorithm::HmacSha256Trunc8,
+            b"Jefe",
+            &[b"what do ya want for nothing?"],
+            &mac[0..8]
+        ));
+        assert!(!verify_mac(
+            MacAlgorithm::HmacSha256Trunc8,
+            b"Jefe",
+            &[b"what do ya want for nothing?"],
+            &mac
+        ));
+    }
+
+    #[test]
+    fn session_keys_survive_wrap_and_unwrap() {
+        let update_key = UpdateKey::aes128([0xAB; 16]);
+        let keys = SessionKeys::random(16);
+        let status = [0x01, 0x02, 0x03];
+
+        let wrapped = wrap_session_keys(&update_key, &keys, &status);
+        // 2 + 16 + 16 + 3 rounds up to 40 plus the 8 octet integrity block
+        assert_eq!(wrapped.len(), 48);
+
+        let (unwrapped, remainder) = unwrap_session_keys(&update_key, &wrapped).unwrap();
+        assert!(unwrapped == keys);
+        assert_eq!(remainder, [0x01, 0x02, 0x03, 0x00, 0x00, 0x00]);
+
+        let other_key = UpdateKey::aes128([0xCD; 16]);
+        assert!(unwrap_session_keys(&other_key, &wrapped).is_none());
+    }
+
+    #[test]
+    fn update_key_survives_wrap_and_unwrap() {
+        let authority_key = AuthorityKey::new([0x11; 32]);
+        let update_key = UpdateKey::aes256([0x22; 32]);
+
+        let plaintext = update_key_data(&update_key, b"bob", &[0x01, 0x02, 0x03, 0x04]);
+        let wrapped = wrap_update_key(&authority_key, &plaintext);
+        // 2 + 32 + 3 + 4 rounds up to 48 plus the 8 octet integrity block
+        assert_eq!(wrapped.len(), 56);
+
+        let unwrapped = unwrap_update_key(&authority_key, &wrapped).unwrap();
+        assert!(
+            parse_update_key_data(&unwrapped, b"bob", &[0x01, 0x02, 0x03, 0x04])
+                == Some(update_key)
+        );
+        assert!(parse_update_key_data(&unwrapped, b"eve", &[0x01, 0x02, 0x03, 0x04]).is_none());
+        assert!(parse_update_key_data(&unwrapped, b"bob", &[0x01, 0x02, 0x03, 0x05]).is_none());
+
+        let other_key = AuthorityKey::new([0x33; 32]);
+        assert!(unwrap_update_key(&other_key, &wrapped).is_none());
+    }
+
+    // MAC values calculated independently with Python's hmac module over the fields laid out
+    // in the order given by IEEE 1815: name, own challenge data, other challenge data, KSQ, USR
+    #[test]
+    fn update_key_confirmation_matches_known_answers() {
+        let update_key = UpdateKey::aes256([0x22; 32]);
+        let master_challenge_data = [0x0A, 0x0B, 0x0C, 0x0D];
+        let outstation_challenge_data = [0xDE, 0xAD, 0xBE, 0xEF];
+
+        // sent by the master for user 2 with KSQ 5
+        let master_mac = update_key_confirmation(
+            &update_key,
+            b"outstation",
+            &master_challenge_data,
+            &outstation_challenge_data,
+            5,
+            2,
+        );
+        assert_eq!(
+            master_mac,
+            [
+                0x71, 0x61, 0x4e, 0x39, 0x26, 0x97, 0x45, 0x14, 0xd5, 0xd1, 0xa9, 0xd5, 0xc9, 0x0e,
+                0xca, 0xde, 0x5e, 0x08, 0xb0, 0xa4, 0x40, 0x82, 0xed, 0xf9, 0x2a, 0x1e, 0xe8, 0x02,
+                0x28, 0x43, 0xbd, 0x2d
+            ]
+        );
+
+        // sent by the outstation in reply
+        let outstation_mac = update_key_confirmation(
+            &update_key,
+            b"bob",
+            &outstation_challenge_data,
+            &master_challenge_data,
+            5,
+            2,
+        );
+        assert_eq!(
+            outstation_mac,
+            [
+                0x49, 0xea, 0xff, 0xe1, 0x38, 0x11, 0x3e, 0x4b, 0xe2, 0x23, 0x3a, 0x13, 0x4d, 0x91,
+                0xc1, 0x7d, 0xfb, 0xb7, 0x6f, 0x14, 0x68, 0xe9, 0x93, 0x3e, 0x1a, 0x3d, 0xab, 0x8f,
+                0xd8, 0x6c, 0x75, 0x34
+            ]
+        );
+        assert!(verify_update_key_confirmation(
+            &update_key,
+            b"bob",
+            &outstation_challenge_data,
+            &master_challenge_data,
+            5,
+            2,
+            &outstation_mac
+        ));
+        assert!(!verify_update_key_confirmation(
+            &update_key,
+            b"outstation",
+            &outstation_challenge_data,
+            &master_challenge_data,
+            5,
+            2,
+            &outstation_mac
+        ));
+    }
+}
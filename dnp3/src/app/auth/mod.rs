@@ -0,0 +1,482 @@
+use crate::app::FunctionCode;
+
+mod crypto;
+mod objects;
+
+pub(crate) use crypto::*;
+pub(crate) use objects::*;
+
+const MAC_HMAC_SHA256_TRUNC_8: u8 = 3;
+const MAC_HMAC_SHA256_TRUNC_16: u8 = 4;
+
+const KEY_WRAP_AES_128: u8 = 1;
+const KEY_WRAP_AES_256: u8 = 2;
+
+const KEY_STATUS_OK: u8 = 1;
+const KEY_STATUS_NOT_INIT: u8 = 2;
+const KEY_STATUS_COMM_FAIL: u8 = 3;
+const KEY_STATUS_AUTH_FAIL: u8 = 4;
+
+/// reason (RSN) of a challenge of a critical request
+pub(crate) const CHALLENGE_REASON_CRITICAL: u8 = 1;
+
+const KCM_SYMMETRIC_AES_256_SHA_256: u8 = 4;
+const KCM_ASYMMETRIC_RSA_2048_SHA_256: u8 = 68;
+const KCM_ASYMMETRIC_RSA_3072_SHA_256: u8 = 69;
+
+const OPERATION_ADD: u8 = 1;
+const OPERATION_DELETE: u8 = 2;
+const OPERATION_CHANGE: u8 = 3;
+
+const ROLE_VIEWER: u16 = 0;
+const ROLE_OPERATOR: u16 = 1;
+const ROLE_ENGINEER: u16 = 2;
+const ROLE_INSTALLER: u16 = 3;
+const ROLE_SECURITY_ADMINISTRATOR: u16 = 4;
+const ROLE_SECURITY_AUDITOR: u16 = 5;
+const ROLE_RBAC_MANAGEMENT: u16 = 6;
+const ROLE_SINGLE_USER: u16 = 32768;
+
+const ERROR_AUTHENTICATION_FAILED: u8 = 1;
+const ERROR_AGGRESSIVE_MODE_NOT_SUPPORTED: u8 = 4;
+const ERROR_MAC_NOT_SUPPORTED: u8 = 5;
+const ERROR_KEY_WRAP_NOT_SUPPORTED: u8 = 6;
+const ERROR_AUTHORIZATION_FAILED: u8 = 7;
+const ERROR_UPDATE_KEY_METHOD_NOT_PERMITTED: u8 = 8;
+const ERROR_INVALID_SIGNATURE: u8 = 9;
+const ERROR_INVALID_CERTIFICATION_DATA: u8 = 10;
+const ERROR_UNKNOWN_USER: u8 = 11;
+const ERROR_MAX_SESSION_KEY_STATUS_REQUESTS_EXCEEDED: u8 = 12;
+
+/// User number of the default user defined by the standard
+pub const DEFAULT_USER: u16 = 1;
+
+/// MAC algorithm (MAL) used to authenticate messages
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub enum MacAlgorithm {
+    /// HMAC-SHA-256 truncated to 8 octets, intended for serial links
+    HmacSha256Trunc8,
+    /// HMAC-SHA-256 truncated to 16 octets, intended for networked links
+    HmacSha256Trunc16,
+}
+
+impl MacAlgorithm {
+    pub(crate) fn new(value: u8) -> Option<Self> {
+        match value {
+            MAC_HMAC_SHA256_TRUNC_8 => Some(Self::HmacSha256Trunc8),
+            MAC_HMAC_SHA256_TRUNC_16 => Some(Self::HmacSha256Trunc16),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_u8(self) -> u8 {
+        match self {
+            Self::HmacSha256Trunc8 => MAC_HMAC_SHA256_TRUNC_8,
+            Self::HmacSha256Trunc16 => MAC_HMAC_SHA256_TRUNC_16,
+        }
+    }
+
+    /// length of the truncated MAC value in octets
+    pub(crate) fn length(self) -> usize {
+        match self {
+            Self::HmacSha256Trunc8 => 8,
+            Self::HmacSha256Trunc16 => 16,
+        }
+    }
+}
+
+/// Key wrap algorithm (KWA) used to encrypt session keys with the update key
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub enum KeyWrapAlgorithm {
+    /// AES-128 key wrap (RFC 3394)
+    Aes128,
+    /// AES-256 key wrap (RFC 3394)
+    Aes256,
+}
+
+impl KeyWrapAlgorithm {
+    pub(crate) fn new(value: u8) -> Option<Self> {
+        match value {
+            KEY_WRAP_AES_128 => Some(Self::Aes128),
+            KEY_WRAP_AES_256 => Some(Self::Aes256),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_u8(self) -> u8 {
+        match self {
+            Self::Aes128 => KEY_WRAP_AES_128,
+            Self::Aes256 => KEY_WRAP_AES_256,
+        }
+    }
+
+    /// length of the update key and of each session key in octets
+    pub(crate) fn key_length(self) -> usize {
+        match self {
+            Self::Aes128 => 16,
+            Self::Aes256 => 32,
+        }
+    }
+}
+
+/// Method (KCM) used to change user status and update keys remotely
+///
+/// Symmetric methods rely on an authority certification key shared by the authority and the
+/// outstation. Asymmetric methods rely on public key cryptography which the library doesn't
+/// implement itself: the signatures and encryption are delegated to the application.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub enum KeyChangeMethod {
+    /// AES-256 key wrap and HMAC-SHA-256 with the authority certification key
+    SymmetricAes256Sha256,
+    /// RSA-2048 encryption, DSA-2048 signatures with SHA-256, and HMAC-SHA-256
+    AsymmetricRsa2048Sha256,
+    /// RSA-3072 encryption, DSA-3072 signatures with SHA-256, and HMAC-SHA-256
+    AsymmetricRsa3072Sha256,
+}
+
+impl KeyChangeMethod {
+    pub(crate) fn new(value: u8) -> Option<Self> {
+        match value {
+            KCM_SYMMETRIC_AES_256_SHA_256 => Some(Self::SymmetricAes256Sha256),
+            KCM_ASYMMETRIC_RSA_2048_SHA_256 => Some(Self::AsymmetricRsa2048Sha256),
+            KCM_ASYMMETRIC_RSA_3072_SHA_256 => Some(Self::AsymmetricRsa3072Sha256),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_u8(self) -> u8 {
+        match self {
+            Self::SymmetricAes256Sha256 => KCM_SYMMETRIC_AES_256_SHA_256,
+            Self::AsymmetricRsa2048Sha256 => KCM_ASYMMETRIC_RSA_2048_SHA_256,
+            Self::AsymmetricRsa3072Sha256 => KCM_ASYMMETRIC_RSA_3072_SHA_256,
+        }
+    }
+
+    /// True if the method uses the authority certification key
+    pub fn is_symmetric(self) -> bool {
+        matches!(self, Self::SymmetricAes256Sha256)
+    }
+}
+
+/// Operation (OP) of a user status change
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub enum UserStatusOperation {
+    /// Add a new user
+    Add,
+    /// Delete an existing user along with its update key
+    Delete,
+    /// Change the role of an existing user
+    Change,
+}
+
+impl UserStatusOperation {
+    pub(crate) fn new(value: u8) -> Option<Self> {
+        match value {
+            OPERATION_ADD => Some(Self::Add),
+            OPERATION_DELETE => Some(Self::Delete),
+            OPERATION_CHANGE => Some(Self::Change),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_u8(self) -> u8 {
+        match self {
+            Self::Add => OPERATION_ADD,
+            Self::Delete => OPERATION_DELETE,
+            Self::Change => OPERATION_CHANGE,
+        }
+    }
+}
+
+/// Role of a user as defined by IEC 62351-8
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub enum UserRole {
+    /// May view data
+    Viewer,
+    /// May view data and operate controls
+    Operator,
+    /// May view data and change the configuration
+    Engineer,
+    /// May view data, change the configuration, and install firmware
+    Installer,
+    /// May change the security configuration
+    SecurityAdministrator,
+    /// May view data and audit logs
+    SecurityAuditor,
+    /// May manage roles and their permissions
+    RbacManagement,
+    /// Every right, used when a device has a single user
+    SingleUser,
+    /// Role not defined by the standard
+    Other(u16),
+}
+
+impl UserRole {
+    pub(crate) fn new(value: u16) -> Self {
+        match value {
+            ROLE_VIEWER => Self::Viewer,
+            ROLE_OPERATOR => Self::Operator,
+            ROLE_ENGINEER => Self::Engineer,
+            ROLE_INSTALLER => Self::Installer,
+            ROLE_SECURITY_ADMINISTRATOR => Self::SecurityAdministrator,
+            ROLE_SECURITY_AUDITOR => Self::SecurityAuditor,
+            ROLE_RBAC_MANAGEMENT => Self::RbacManagement,
+            ROLE_SINGLE_USER => Self::SingleUser,
+            _ => Self::Other(value),
+        }
+    }
+
+    pub(crate) fn as_u16(self) -> u16 {
+        match self {
+            Self::Viewer => ROLE_VIEWER,
+            Self::Operator => ROLE_OPERATOR,
+            Self::Engineer => ROLE_ENGINEER,
+            Self::Installer => ROLE_INSTALLER,
+            Self::SecurityAdministrator => ROLE_SECURITY_ADMINISTRATOR,
+            Self::SecurityAuditor => ROLE_SECURITY_AUDITOR,
+            Self::RbacManagement => ROLE_RBAC_MANAGEMENT,
+            Self::SingleUser => ROLE_SINGLE_USER,
+            Self::Other(x) => x,
+        }
+    }
+}
+
+/// Status of the session keys (KST) reported by the outstation
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum KeyStatus {
+    /// OK - session keys are valid
+    Ok,
+    /// NOT_INIT - session keys are not initialized
+    NotInit,
+    /// COMM_FAIL - session keys expired due to a communication failure
+    CommFail,
+    /// AUTH_FAIL - session keys are invalid due to an authentication failure
+    AuthFail,
+    /// Status code not defined in the standard
+    Other(u8),
+}
+
+impl KeyStatus {
+    pub(crate) fn new(value: u8) -> Self {
+        match value {
+            KEY_STATUS_OK => Self::Ok,
+            KEY_STATUS_NOT_INIT => Self::NotInit,
+            KEY_STATUS_COMM_FAIL => Self::CommFail,
+            KEY_STATUS_AUTH_FAIL => Self::AuthFail,
+            _ => Self::Other(value),
+        }
+    }
+
+    pub(crate) fn as_u8(self) -> u8 {
+        match self {
+            Self::Ok => KEY_STATUS_OK,
+            Self::NotInit => KEY_STATUS_NOT_INIT,
+            Self::CommFail => KEY_STATUS_COMM_FAIL,
+            Self::AuthFail => KEY_STATUS_AUTH_FAIL,
+            Self::Other(x) => x,
+        }
+    }
+}
+
+/// Error code (ERR) carried by an authentication error (g120v7)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AuthErrorCode {
+    /// The MAC or the key wrap data could not be verified
+    AuthenticationFailed,
+    /// The receiver does not support aggressive mode
+    AggressiveModeNotSupported,
+    /// The requested MAC algorithm is not supported
+    MacNotSupported,
+    /// The requested key wrap algorithm is not supported
+    KeyWrapNotSupported,
+    /// The user is not authorized to perform the requested function
+    AuthorizationFailed,
+    /// The requested update key change method is not permitted
+    UpdateKeyChangeMethodNotPermitted,
+    /// The digital signature could not be verified
+    InvalidSignature,
+    /// The certification data could not be verified
+    InvalidCertificationData,
+    /// The user is not known to the receiver
+    UnknownUser,
+    /// The maximum number of session key status requests was exceeded
+    MaxSessionKeyStatusRequestsExceeded,
+    /// Error code not defined in the standard
+    Other(u8),
+}
+
+impl AuthErrorCode {
+    pub(crate) fn new(value: u8) -> Self {
+        match value {
+            ERROR_AUTHENTICATION_FAILED => Self::AuthenticationFailed,
+            ERROR_AGGRESSIVE_MODE_NOT_SUPPORTED => Self::AggressiveModeNotSupported,
+            ERROR_MAC_NOT_SUPPORTED => Self::MacNotSupported,
+            ERROR_KEY_WRAP_NOT_SUPPORTED => Self::KeyWrapNotSupported,
+            ERROR_AUTHORIZATION_FAILED => Self::AuthorizationFailed,
+            ERROR_UPDATE_KEY_METHOD_NOT_PERMITTED => Self::UpdateKeyChangeMethodNotPermitted,
+            ERROR_INVALID_SIGNATURE => Self::InvalidSignature,
+            ERROR_INVALID_CERTIFICATION_DATA => Self::InvalidCertificationData,
+            ERROR_UNKNOWN_USER => Self::UnknownUser,
+            ERROR_MAX_SESSION_KEY_STATUS_REQUESTS_EXCEEDED => {
+                Self::MaxSessionKeyStatusRequestsExceeded
+            }
+            _ => Self::Other(value),
+        }
+    }
+
+    pub(crate) fn as_u8(self) -> u8 {
+        match self {
+            Self::AuthenticationFailed => ERROR_AUTHENTICATION_FAILED,
+            Self::AggressiveModeNotSupported => ERROR_AGGRESSIVE_MODE_NOT_SUPPORTED,
+            Self::MacNotSupported => ERROR_MAC_NOT_SUPPORTED,
+            Self::KeyWrapNotSupported => ERROR_KEY_WRAP_NOT_SUPPORTED,
+            Self::AuthorizationFailed => ERROR_AUTHORIZATION_FAILED,
+            Self::UpdateKeyChangeMethodNotPermitted => ERROR_UPDATE_KEY_METHOD_NOT_PERMITTED,
+            Self::InvalidSignature => ERROR_INVALID_SIGNATURE,
+            Self::InvalidCertificationData => ERROR_INVALID_CERTIFICATION_DATA,
+            Self::UnknownUser => ERROR_UNKNOWN_USER,
+            Self::MaxSessionKeyStatusRequestsExceeded => {
+                ERROR_MAX_SESSION_KEY_STATUS_REQUESTS_EXCEEDED
+            }
+            Self::Other(x) => x,
+        }
+    }
+}
+
+/// Update key shared by the master and the outstation for a particular user
+///
+/// The update key is only used to encrypt (wrap) the session keys when they are changed
+#[derive(Clone, PartialEq, Eq)]
+pub struct UpdateKey {
+    algorithm: KeyWrapAlgorithm,
+    data: Vec<u8>,
+}
+
+impl UpdateKey {
+    /// Create a 128-bit update key used with AES-128 key wrap
+    pub fn aes128(key: [u8; 16]) -> Self {
+        Self {
+            algorithm: KeyWrapAlgorithm::Aes128,
+            data: key.to_vec(),
+        }
+    }
+
+    /// Create a 256-bit update key used with AES-256 key wrap
+    pub fn aes256(key: [u8; 32]) -> Self {
+        Self {
+            algorithm: KeyWrapAlgorithm::Aes256,
+            data: key.to_vec(),
+        }
+    }
+
+    /// Key wrap algorithm used with this key
+    pub fn algorithm(&self) -> KeyWrapAlgorithm {
+        self.algorithm
+    }
+
+    /// Create an update key from its raw value, choosing the algorithm from its length
+    pub(crate) fn from_data(data: &[u8]) -> Option<Self> {
+        let algorithm = match data.len() {
+            16 => KeyWrapAlgorithm::Aes128,
+            32 => KeyWrapAlgorithm::Aes256,
+            _ => return None,
+        };
+        Some(Self {
+            algorithm,
+            data: data.to_vec(),
+        })
+    }
+
+    pub(crate) fn data(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+impl std::fmt::Debug for UpdateKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // never log the key material
+        f.debug_struct("UpdateKey")
+            .field("algorithm", &self.algorithm)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Authority certification key shared by the authority and the outstation
+///
+/// Used by the symmetric key change method to certify user status changes and
+/// to encrypt new update keys on their way to the outstation
+#[derive(Clone, PartialEq, Eq)]
+pub struct AuthorityKey {
+    data: Vec<u8>,
+}
+
+impl AuthorityKey {
+    /// Create a 256-bit authority certification key
+    pub fn new(key: [u8; 32]) -> Self {
+        Self { data: key.to_vec() }
+    }
+
+    pub(crate) fn data(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+impl std::fmt::Debug for AuthorityKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // never log the key material
+        f.debug_struct("AuthorityKey").finish_non_exhaustive()
+    }
+}
+
+/// Pair of session keys used to authenticate messages in each direction
+#[derive(Clone, PartialEq, Eq)]
+pub(crate) struct SessionKeys {
+    /// authenticates messages sent by the master
+    pub(crate) control: Vec<u8>,
+    /// authenticates messages sent by the outstation
+    pub(crate) monitoring: Vec<u8>,
+}
+
+impl SessionKeys {
+    /// generate a new pair of random session keys
+    pub(crate) fn random(length: usize) -> Self {
+        Self {
+            control: random_bytes(length),
+            monitoring: random_bytes(length),
+        }
+    }
+}
+
+/// Requests with these function codes must be authenticated when secure authentication is enabled
+pub(crate) fn is_critical(function: FunctionCode) -> bool {
+    !matches!(
+        function,
+        FunctionCode::Confirm
+            | FunctionCode::Read
+            | FunctionCode::AuthRequest
+            | FunctionCode::AuthRequestNoAck
+            | FunctionCode::Response
+            | FunctionCode::UnsolicitedResponse
+            | FunctionCode::AuthResponse
+    )
+}
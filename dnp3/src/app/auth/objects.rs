@@ -0,0 +1,666 @@
+use crate::app::auth::{AuthErrorCode, KeyStatus};
+use crate::app::format::{to_u16, WriteError};
+use crate::app::{ObjectParseError, Timestamp};
+
+use scursor::{ReadCursor, WriteCursor};
+
+fn read_counted<'a>(cursor: &mut ReadCursor<'a>) -> Result<&'a [u8], ObjectParseError> {
+    let length = cursor.read_u16_le()?;
+    Ok(cursor.read_bytes(length as usize)?)
+}
+
+fn write_counted(cursor: &mut WriteCursor, data: &[u8]) -> Result<(), WriteError> {
+    cursor.write_u16_le(to_u16(data.len())?)?;
+    cursor.write_bytes(data)?;
+    Ok(())
+}
+
+/// Group 120 Variation 1 - Authentication challenge
+///
+/// This representation is borrowed from the underlying ASDU
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct Group120Var1<'a> {
+    pub(crate) challenge_seq: u32,
+    pub(crate) user: u16,
+    pub(crate) mac_algorithm: u8,
+    pub(crate) reason: u8,
+    pub(crate) challenge_data: &'a [u8],
+}
+
+impl<'a> Group120Var1<'a> {
+    pub(crate) fn format(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "\nchallenge seq: {} user: {} MAL: {} reason: {} challenge data: {:02X?}",
+            self.challenge_seq, self.user, self.mac_algorithm, self.reason, self.challenge_data
+        )
+    }
+
+    pub(crate) fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u32_le(self.challenge_seq)?;
+        cursor.write_u16_le(self.user)?;
+        cursor.write_u8(self.mac_algorithm)?;
+        cursor.write_u8(self.reason)?;
+        cursor.write_bytes(self.challenge_data)?;
+        Ok(())
+    }
+
+    pub(crate) fn read(cursor: &mut ReadCursor<'a>) -> Result<Self, ObjectParseError> {
+        Ok(Self {
+            challenge_seq: cursor.read_u32_le()?,
+            user: cursor.read_u16_le()?,
+            mac_algorithm: cursor.read_u8()?,
+            reason: cursor.read_u8()?,
+            challenge_data: cursor.read_all(),
+        })
+    }
+}
+
+/// Group 120 Variation 2 - Authentication reply
+///
+/// This representation is borrowed from the underlying ASDU
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct Group120Var2<'a> {
+    pub(crate) challenge_seq: u32,
+    pub(crate) user: u16,
+    pub(crate) mac: &'a [u8],
+}
+
+impl<'a> Group120Var2<'a> {
+    pub(crate) fn format(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "\nchallenge seq: {} user: {} MAC: {:02X?}",
+            self.challenge_seq, self.user, self.mac
+        )
+    }
+
+    pub(crate) fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u32_le(self.challenge_seq)?;
+        cursor.write_u16_le(self.user)?;
+        cursor.write_bytes(self.mac)?;
+        Ok(())
+    }
+
+    pub(crate) fn read(cursor: &mut ReadCursor<'a>) -> Result<Self, ObjectParseError> {
+        Ok(Self {
+            challenge_seq: cursor.read_u32_le()?,
+            user: cursor.read_u16_le()?,
+            mac: cursor.read_all(),
+        })
+    }
+}
+
+/// Group 120 Variation 5 - Session key status
+///
+/// This representation is borrowed from the underlying ASDU
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct Group120Var5<'a> {
+    pub(crate) key_change_seq: u32,
+    pub(crate) user: u16,
+    pub(crate) key_wrap_algorithm: u8,
+    pub(crate) key_status: KeyStatus,
+    pub(crate) mac_algorithm: u8,
+    pub(crate) challenge_data: &'a [u8],
+    pub(crate) mac: &'a [u8],
+}
+
+impl<'a> Group120Var5<'a> {
+    pub(crate) fn format(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "\nkey change seq: {} user: {} KWA: {} status: {:?} MAL: {} challenge data: {:02X?} MAC: {:02X?}",
+            self.key_change_seq,
+            self.user,
+            self.key_wrap_algorithm,
+            self.key_status,
+            self.mac_algorithm,
+            self.challenge_data,
+            self.mac
+        )
+    }
+
+    pub(crate) fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u32_le(self.key_change_seq)?;
+        cursor.write_u16_le(self.user)?;
+        cursor.write_u8(self.key_wrap_algorithm)?;
+        cursor.write_u8(self.key_status.as_u8())?;
+        cursor.write_u8(self.mac_algorithm)?;
+        write_counted(cursor, self.challenge_data)?;
+        cursor.write_bytes(self.mac)?;
+        Ok(())
+    }
+
+    pub(crate) fn read(cursor: &mut ReadCursor<'a>) -> Result<Self, ObjectParseError> {
+        Ok(Self {
+            key_change_seq: cursor.read_u32_le()?,
+            user: cursor.read_u16_le()?,
+            key_wrap_algorithm: cursor.read_u8()?,
+            key_status: KeyStatus::new(cursor.read_u8()?),
+            mac_algorithm: cursor.read_u8()?,
+            challenge_data: read_counted(cursor)?,
+            mac: cursor.read_all(),
+        })
+    }
+
+    /// encoding of the entire object which is included in the key wrap data of the next key change
+    pub(crate) fn encode(&self) -> Result<Vec<u8>, WriteError> {
+        let mut buffer = vec![0; 11 + self.challenge_data.len() + self.mac.len()];
+        let mut cursor = WriteCursor::new(&mut buffer);
+        self.write(&mut cursor)?;
+        Ok(buffer)
+    }
+}
+
+/// Group 120 Variation 6 - Session key change
+///
+/// This representation is borrowed from the underlying ASDU
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct Group120Var6<'a> {
+    pub(crate) key_change_seq: u32,
+    pub(crate) user: u16,
+    pub(crate) wrapped_key_data: &'a [u8],
+}
+
+impl<'a> Group120Var6<'a> {
+    pub(crate) fn format(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "\nkey change seq: {} user: {} wrapped key data length: {}",
+            self.key_change_seq,
+            self.user,
+            self.wrapped_key_data.len()
+        )
+    }
+
+    pub(crate) fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u32_le(self.key_change_seq)?;
+        cursor.write_u16_le(self.user)?;
+        cursor.write_bytes(self.wrapped_key_data)?;
+        Ok(())
+    }
+
+    pub(crate) fn read(cursor: &mut ReadCursor<'a>) -> Result<Self, ObjectParseError> {
+        Ok(Self {
+            key_change_seq: cursor.read_u32_le()?,
+            user: cursor.read_u16_le()?,
+            wrapped_key_data: cursor.read_all(),
+        })
+    }
+}
+
+/// Group 120 Variation 7 - Authentication error
+///
+/// This representation is borrowed from the underlying ASDU
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct Group120Var7<'a> {
+    pub(crate) challenge_seq: u32,
+    pub(crate) user: u16,
+    pub(crate) association_id: u16,
+    pub(crate) error: AuthErrorCode,
+    pub(crate) time: Timestamp,
+    pub(crate) text: &'a [u8],
+}
+
+impl<'a> Group120Var7<'a> {
+    pub(crate) fn format(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "\nchallenge seq: {} user: {} association: {} error: {:?} time: {} text: {}",
+            self.challenge_seq,
+            self.user,
+            self.association_id,
+            self.error,
+            self.time,
+            String::from_utf8_lossy(self.text)
+        )
+    }
+
+    pub(crate) fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u32_le(self.challenge_seq)?;
+        cursor.write_u16_le(self.user)?;
+        cursor.write_u16_le(self.association_id)?;
+        cursor.write_u8(self.error.as_u8())?;
+        cursor.write_u48_le(self.time.raw_value())?;
+        cursor.write_bytes(self.text)?;
+        Ok(())
+    }
+
+    pub(crate) fn read(cursor: &mut ReadCursor<'a>) -> Result<Self, ObjectParseError> {
+        Ok(Self {
+            challenge_seq: cursor.read_u32_le()?,
+            user: cursor.read_u16_le()?,
+            association_id: cursor.read_u16_le()?,
+            error: AuthErrorCode::new(cursor.read_u8()?),
+            time: Timestamp::new(cursor.read_u48_le()?),
+            text: cursor.read_all(),
+        })
+    }
+}
+
+/// Group 120 Variation 9 - Authentication MAC
+///
+/// This representation is borrowed from the underlying ASDU
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct Group120Var9<'a> {
+    pub(crate) mac: &'a [u8],
+}
+
+impl<'a> Group120Var9<'a> {
+    pub(crate) fn format(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "\nMAC: {:02X?}", self.mac)
+    }
+
+    pub(crate) fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_bytes(self.mac)?;
+        Ok(())
+    }
+
+    pub(crate) fn read(cursor: &mut ReadCursor<'a>) -> Result<Self, ObjectParseError> {
+        Ok(Self {
+            mac: cursor.read_all(),
+        })
+    }
+}
+
+/// Group 120 Variation 10 - User status change
+///
+/// This representation is borrowed from the underlying ASDU
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct Group120Var10<'a> {
+    pub(crate) key_change_method: u8,
+    pub(crate) operation: u8,
+    pub(crate) status_change_seq: u32,
+    pub(crate) role: u16,
+    pub(crate) role_expiry_days: u16,
+    pub(crate) user_name: &'a [u8],
+    pub(crate) user_public_key: &'a [u8],
+    pub(crate) certification_data: &'a [u8],
+}
+
+impl<'a> Group120Var10<'a> {
+    pub(crate) fn format(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "\nKCM: {} operation: {} status change seq: {} role: {} role expiry: {} user name: {} public key length: {} certification data length: {}",
+            self.key_change_method,
+            self.operation,
+            self.status_change_seq,
+            self.role,
+            self.role_expiry_days,
+            String::from_utf8_lossy(self.user_name),
+            self.user_public_key.len(),
+            self.certification_data.len()
+        )
+    }
+
+    pub(crate) fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u8(self.key_change_method)?;
+        cursor.write_u8(self.operation)?;
+        cursor.write_u32_le(self.status_change_seq)?;
+        cursor.write_u16_le(self.role)?;
+        cursor.write_u16_le(self.role_expiry_days)?;
+        cursor.write_u16_le(to_u16(self.user_name.len())?)?;
+        cursor.write_u16_le(to_u16(self.user_public_key.len())?)?;
+        cursor.write_u16_le(to_u16(self.certification_data.len())?)?;
+        cursor.write_bytes(self.user_name)?;
+        cursor.write_bytes(self.user_public_key)?;
+        cursor.write_bytes(self.certification_data)?;
+        Ok(())
+    }
+
+    pub(crate) fn read(cursor: &mut ReadCursor<'a>) -> Result<Self, ObjectParseError> {
+        let key_change_method = cursor.read_u8()?;
+        let operation = cursor.read_u8()?;
+        let status_change_seq = cursor.read_u32_le()?;
+        let role = cursor.read_u16_le()?;
+        let role_expiry_days = cursor.read_u16_le()?;
+        let user_name_length = cursor.read_u16_le()?;
+        let user_public_key_length = cursor.read_u16_le()?;
+        let certification_data_length = cursor.read_u16_le()?;
+        Ok(Self {
+            key_change_method,
+            operation,
+            status_change_seq,
+            role,
+            role_expiry_days,
+            user_name: cursor.read_bytes(user_name_length as usize)?,
+            user_public_key: cursor.read_bytes(user_public_key_length as usize)?,
+            certification_data: cursor.read_bytes(certification_data_length as usize)?,
+        })
+    }
+
+    /// encoding of the object without its certification data, which is what the authority certifies
+    pub(crate) fn certified_data(&self) -> Result<Vec<u8>, WriteError> {
+        let uncertified = Self {
+            certification_data: &[],
+            ..*self
+        };
+        let mut buffer = vec![0; 16 + self.user_name.len() + self.user_public_key.len()];
+        let mut cursor = WriteCursor::new(&mut buffer);
+        uncertified.write(&mut cursor)?;
+        Ok(buffer)
+    }
+}
+
+/// Group 120 Variation 11 - Update key change request
+///
+/// This representation is borrowed from the underlying ASDU
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct Group120Var11<'a> {
+    pub(crate) key_change_method: u8,
+    pub(crate) user_name: &'a [u8],
+    pub(crate) challenge_data: &'a [u8],
+}
+
+impl<'a> Group120Var11<'a> {
+    pub(crate) fn format(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "\nKCM: {} user name: {} challenge data: {:02X?}",
+            self.key_change_method,
+            String::from_utf8_lossy(self.user_name),
+            self.challenge_data
+        )
+    }
+
+    pub(crate) fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u8(self.key_change_method)?;
+        cursor.write_u16_le(to_u16(self.user_name.len())?)?;
+        cursor.write_u16_le(to_u16(self.challenge_data.len())?)?;
+        cursor.write_bytes(self.user_name)?;
+        cursor.write_bytes(self.challenge_data)?;
+        Ok(())
+    }
+
+    pub(crate) fn read(cursor: &mut ReadCursor<'a>) -> Result<Self, ObjectParseError> {
+        let key_change_method = cursor.read_u8()?;
+        let user_name_length = cursor.read_u16_le()?;
+        let challenge_data_length = cursor.read_u16_le()?;
+        Ok(Self {
+            key_change_method,
+            user_name: cursor.read_bytes(user_name_length as usize)?,
+            challenge_data: cursor.read_bytes(challenge_data_length as usize)?,
+        })
+    }
+}
+
+/// Group 120 Variation 12 - Update key change reply
+///
+/// This representation is borrowed from the underlying ASDU
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct Group120Var12<'a> {
+    pub(crate) key_change_seq: u32,
+    pub(crate) user: u16,
+    pub(crate) challenge_data: &'a [u8],
+}
+
+impl<'a> Group120Var12<'a> {
+    pub(crate) fn format(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "\nkey change seq: {} user: {} challenge data: {:02X?}",
+            self.key_change_seq, self.user, self.challenge_data
+        )
+    }
+
+    pub(crate) fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u32_le(self.key_change_seq)?;
+        cursor.write_u16_le(self.user)?;
+        write_counted(cursor, self.challenge_data)?;
+        Ok(())
+    }
+
+    pub(crate) fn read(cursor: &mut ReadCursor<'a>) -> Result<Self, ObjectParseError> {
+        Ok(Self {
+            key_change_seq: cursor.read_u32_le()?,
+            user: cursor.read_u16_le()?,
+            challenge_data: read_counted(cursor)?,
+        })
+    }
+}
+
+/// Group 120 Variation 13 - Update key change
+///
+/// This representation is borrowed from the underlying ASDU
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct Group120Var13<'a> {
+    pub(crate) key_change_seq: u32,
+    pub(crate) user: u16,
+    pub(crate) encrypted_key_data: &'a [u8],
+}
+
+impl<'a> Group120Var13<'a> {
+    pub(crate) fn format(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "\nkey change seq: {} user: {} encrypted key data length: {}",
+            self.key_change_seq,
+            self.user,
+            self.encrypted_key_data.len()
+        )
+    }
+
+    pub(crate) fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u32_le(self.key_change_seq)?;
+        cursor.write_u16_le(self.user)?;
+        write_counted(cursor, self.encrypted_key_data)?;
+        Ok(())
+    }
+
+    pub(crate) fn read(cursor: &mut ReadCursor<'a>) -> Result<Self, ObjectParseError> {
+        Ok(Self {
+            key_change_seq: cursor.read_u32_le()?,
+            user: cursor.read_u16_le()?,
+            encrypted_key_data: read_counted(cursor)?,
+        })
+    }
+}
+
+/// Group 120 Variation 14 - Update key change signature
+///
+/// This representation is borrowed from the underlying ASDU
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct Group120Var14<'a> {
+    pub(crate) signature: &'a [u8],
+}
+
+impl<'a> Group120Var14<'a> {
+    pub(crate) fn format(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "\nsignature: {:02X?}", self.signature)
+    }
+
+    pub(crate) fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_bytes(self.signature)?;
+        Ok(())
+    }
+
+    pub(crate) fn read(cursor: &mut ReadCursor<'a>) -> Result<Self, ObjectParseError> {
+        Ok(Self {
+            signature: cursor.read_all(),
+        })
+    }
+}
+
+/// Group 120 Variation 15 - Update key change confirmation
+///
+/// This representation is borrowed from the underlying ASDU
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct Group120Var15<'a> {
+    pub(crate) mac: &'a [u8],
+}
+
+impl<'a> Group120Var15<'a> {
+    pub(crate) fn format(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "\nMAC: {:02X?}", self.mac)
+    }
+
+    pub(crate) fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_bytes(self.mac)?;
+        Ok(())
+    }
+
+    pub(crate) fn read(cursor: &mut ReadCursor<'a>) -> Result<Self, ObjectParseError> {
+        Ok(Self {
+            mac: cursor.read_all(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn write_object<F>(f: F) -> Vec<u8>
+    where
+        F: FnOnce(&mut WriteCursor) -> Result<(), WriteError>,
+    {
+        let mut buffer = [0; 256];
+        let mut cursor = WriteCursor::new(&mut buffer);
+        f(&mut cursor).unwrap();
+        cursor.written().to_vec()
+    }
+
+    #[test]
+    fn writes_and_parses_challenge() {
+        let object = Group120Var1 {
+            challenge_seq: 0x01020304,
+            user: 1,
+            mac_algorithm: 4,
+            reason: 1,
+            challenge_data: &[0xAA, 0xBB, 0xCC, 0xDD],
+        };
+
+        let data = write_object(|cur| object.write(cur));
+        assert_eq!(
+            data,
+            [0x04, 0x03, 0x02, 0x01, 0x01, 0x00, 0x04, 0x01, 0xAA, 0xBB, 0xCC, 0xDD]
+        );
+
+        let mut cursor = ReadCursor::new(&data);
+        assert_eq!(Group120Var1::read(&mut cursor).unwrap(), object);
+        assert!(cursor.is_empty());
+    }
+
+    #[test]
+    fn writes_and_parses_key_status() {
+        let object = Group120Var5 {
+            key_change_seq: 7,
+            user: 1,
+            key_wrap_algorithm: 1,
+            key_status: KeyStatus::NotInit,
+            mac_algorithm: 4,
+            challenge_data: &[0x01, 0x02, 0x03, 0x04],
+            mac: &[0xEE, 0xFF],
+        };
+
+        let data = write_object(|cur| object.write(cur));
+        assert_eq!(
+            data,
+            [
+                0x07, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x02, 0x04, 0x04, 0x00, 0x01, 0x02, 0x03,
+                0x04, 0xEE, 0xFF
+            ]
+        );
+
+        let mut cursor = ReadCursor::new(&data);
+        assert_eq!(Group120Var5::read(&mut cursor).unwrap(), object);
+        assert_eq!(object.encode().unwrap(), data);
+    }
+
+    #[test]
+    fn rejects_key_status_with_truncated_challenge_data() {
+        let data = [
+            0x07, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x02, 0x04, 0x04, 0x00, 0x01,
+        ];
+        let mut cursor = ReadCursor::new(&data);
+        assert_eq!(
+            Group120Var5::read(&mut cursor),
+            Err(ObjectParseError::InsufficientBytes)
+        );
+    }
+
+    #[test]
+    fn writes_and_parses_error() {
+        let object = Group120Var7 {
+            challenge_seq: 2,
+            user: 1,
+            association_id: 3,
+            error: AuthErrorCode::AuthenticationFailed,
+            time: Timestamp::new(0x010203040506),
+            text: b"bad",
+        };
+
+        let data = write_object(|cur| object.write(cur));
+        assert_eq!(
+            data,
+            [
+                0x02, 0x00, 0x00, 0x00, 0x01, 0x00, 0x03, 0x00, 0x01, 0x06, 0x05, 0x04, 0x03, 0x02,
+                0x01, b'b', b'a', b'd'
+            ]
+        );
+
+        let mut cursor = ReadCursor::new(&data);
+        assert_eq!(Group120Var7::read(&mut cursor).unwrap(), object);
+    }
+
+    #[test]
+    fn writes_and_parses_user_status_change() {
+        let object = Group120Var10 {
+            key_change_method: 4,
+            operation: 1,
+            status_change_seq: 9,
+            role: 1,
+            role_expiry_days: 365,
+            user_name: b"bob",
+            user_public_key: &[],
+            certification_data: &[0xAA, 0xBB],
+        };
+
+        let data = write_object(|cur| object.write(cur));
+        assert_eq!(
+            data,
+            [
+                0x04, 0x01, 0x09, 0x00, 0x00, 0x00, 0x01, 0x00, 0x6D, 0x01, 0x03, 0x00, 0x00, 0x00,
+                0x02, 0x00, b'b', b'o', b'b', 0xAA, 0xBB
+            ]
+        );
+
+        let mut cursor = ReadCursor::new(&data);
+        assert_eq!(Group120Var10::read(&mut cursor).unwrap(), object);
+        assert!(cursor.is_empty());
+
+        let mut certified = data[0..19].to_vec();
+        certified[14] = 0;
+        assert_eq!(object.certified_data().unwrap(), certified);
+    }
+
+    #[test]
+    fn writes_and_parses_update_key_change_request() {
+        let object = Group120Var11 {
+            key_change_method: 4,
+            user_name: b"bob",
+            challenge_data: &[0x01, 0x02, 0x03, 0x04],
+        };
+
+        let data = write_object(|cur| object.write(cur));
+        assert_eq!(
+            data,
+            [0x04, 0x03, 0x00, 0x04, 0x00, b'b', b'o', b'b', 0x01, 0x02, 0x03, 0x04]
+        );
+
+        let mut cursor = ReadCursor::new(&data);
+        assert_eq!(Group120Var11::read(&mut cursor).unwrap(), object);
+        assert!(cursor.is_empty());
+    }
+
+    #[test]
+    fn rejects_update_key_change_with_truncated_key_data() {
+        let data = [0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x08, 0x00, 0xAA];
+        let mut cursor = ReadCursor::new(&data);
+        assert_eq!(
+            Group120Var13::read(&mut cursor),
+            Err(ObjectParseError::InsufficientBytes)
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use crate::app::control_enums::{OpType, TripCloseCode};
+use crate::app::variations::Group12Var2;
 
 /// Control code field used within g12v1
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -84,6 +85,26 @@ impl std::fmt::Display for ControlCode {
     }
 }
 
+/// Pattern control block (g12v2) applied to the outputs selected by a pattern mask (g12v3)
+///
+/// When added to a [`CommandBuilder`](crate::master::CommandBuilder), the index identifies the
+/// output that corresponds to the first element of the mask. Elements of the mask that fall
+/// outside of the range of the index type are ignored.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PatternControl {
+    /// Control applied to every output selected by the mask
+    pub control: Group12Var2,
+    /// Outputs selected by the pattern, beginning with the starting index
+    pub mask: Vec<bool>,
+}
+
+impl PatternControl {
+    /// Create a `PatternControl` from its control block and mask
+    pub fn new(control: Group12Var2, mask: Vec<bool>) -> Self {
+        Self { control, mask }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
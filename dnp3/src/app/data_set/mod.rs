@@ -0,0 +1,423 @@
+use crate::app::attr::{Int, UInt};
+use crate::app::format::WriteError;
+use crate::app::Timestamp;
+
+mod objects;
+
+pub(crate) use objects::*;
+
+const ID: u8 = 1;
+const UUID: u8 = 2;
+const NAMESPACE: u8 = 3;
+const NAME: u8 = 4;
+const DATA_ELEMENT: u8 = 5;
+const PROTOTYPE: u8 = 6;
+const CONTROL_VALUE: u8 = 7;
+const CONTROL_STATUS: u8 = 8;
+
+const NONE: u8 = 0;
+const VISIBLE_STRING: u8 = 1;
+const UNSIGNED_INT: u8 = 2;
+const SIGNED_INT: u8 = 3;
+const FLOATING_POINT: u8 = 4;
+const OCTET_STRING: u8 = 5;
+const BIT_STRING: u8 = 6;
+const TIME: u8 = 7;
+
+/// Length of the UUID that identifies a data set prototype
+pub const UUID_LENGTH: usize = 16;
+
+/// Code that defines the purpose of a descriptor element within a
+/// data set prototype (g85) or data set descriptor (g86)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DescriptorCode {
+    /// ID - Identifier of the prototype or descriptor
+    Id,
+    /// UUID - Universally unique identifier of a prototype
+    Uuid,
+    /// NSPC - Namespace of the prototype or descriptor
+    Namespace,
+    /// NAME - Name of the prototype or descriptor
+    Name,
+    /// DAEL - Data element, i.e. a value that is reported
+    DataElement,
+    /// PTYP - Reference to a prototype by UUID
+    Prototype,
+    /// CTLV - Control value, i.e. a value that may be written by the master
+    ControlValue,
+    /// CTLS - Control status, i.e. a value that is reported with a control
+    ControlStatus,
+    /// Code not defined in the standard
+    Other(u8),
+}
+
+impl DescriptorCode {
+    pub(crate) fn new(value: u8) -> Self {
+        match value {
+            ID => Self::Id,
+            UUID => Self::Uuid,
+            NAMESPACE => Self::Namespace,
+            NAME => Self::Name,
+            DATA_ELEMENT => Self::DataElement,
+            PROTOTYPE => Self::Prototype,
+            CONTROL_VALUE => Self::ControlValue,
+            CONTROL_STATUS => Self::ControlStatus,
+            _ => Self::Other(value),
+        }
+    }
+
+    /// true if elements with this code have a corresponding value in the data set
+    pub(crate) fn has_value(self) -> bool {
+        matches!(
+            self,
+            Self::DataElement | Self::ControlValue | Self::ControlStatus
+        )
+    }
+}
+
+impl From<DescriptorCode> for u8 {
+    fn from(value: DescriptorCode) -> Self {
+        match value {
+            DescriptorCode::Id => ID,
+            DescriptorCode::Uuid => UUID,
+            DescriptorCode::Namespace => NAMESPACE,
+            DescriptorCode::Name => NAME,
+            DescriptorCode::DataElement => DATA_ELEMENT,
+            DescriptorCode::Prototype => PROTOTYPE,
+            DescriptorCode::ControlValue => CONTROL_VALUE,
+            DescriptorCode::ControlStatus => CONTROL_STATUS,
+            DescriptorCode::Other(x) => x,
+        }
+    }
+}
+
+/// Data type code of a descriptor element
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DataSetType {
+    /// NONE - Element has no associated value
+    None,
+    /// VSTR - Visible character suitable for print and display
+    VisibleString,
+    /// UINT - Unsigned integer
+    UnsignedInt,
+    /// INT - Signed integer
+    SignedInt,
+    /// FLT - Floating-point
+    FloatingPoint,
+    /// OSTR - Octet string
+    OctetString,
+    /// BSTR - Bit string
+    BitString,
+    /// TIME - DNP3 time
+    Time,
+    /// Type code not defined in the standard
+    Other(u8),
+}
+
+impl DataSetType {
+    pub(crate) fn new(value: u8) -> Self {
+        match value {
+            NONE => Self::None,
+            VISIBLE_STRING => Self::VisibleString,
+            UNSIGNED_INT => Self::UnsignedInt,
+            SIGNED_INT => Self::SignedInt,
+            FLOATING_POINT => Self::FloatingPoint,
+            OCTET_STRING => Self::OctetString,
+            BIT_STRING => Self::BitString,
+            TIME => Self::Time,
+            _ => Self::Other(value),
+        }
+    }
+}
+
+impl From<DataSetType> for u8 {
+    fn from(value: DataSetType) -> Self {
+        match value {
+            DataSetType::None => NONE,
+            DataSetType::VisibleString => VISIBLE_STRING,
+            DataSetType::UnsignedInt => UNSIGNED_INT,
+            DataSetType::SignedInt => SIGNED_INT,
+            DataSetType::FloatingPoint => FLOATING_POINT,
+            DataSetType::OctetString => OCTET_STRING,
+            DataSetType::BitString => BIT_STRING,
+            DataSetType::Time => TIME,
+            DataSetType::Other(x) => x,
+        }
+    }
+}
+
+/// Element of a data set prototype or descriptor
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DescriptorElement {
+    /// Purpose of the element
+    pub code: DescriptorCode,
+    /// Type of the corresponding value
+    pub data_type: DataSetType,
+    /// Maximum length of the corresponding value in octets
+    pub max_length: u8,
+    /// Ancillary value, e.g. the name of a data element or the UUID of a prototype
+    pub ancillary: Vec<u8>,
+}
+
+impl DescriptorElement {
+    /// Construct an element from its components
+    pub fn new(
+        code: DescriptorCode,
+        data_type: DataSetType,
+        max_length: u8,
+        ancillary: Vec<u8>,
+    ) -> Self {
+        Self {
+            code,
+            data_type,
+            max_length,
+            ancillary,
+        }
+    }
+
+    /// Construct a NAME element
+    pub fn name(name: &str) -> Self {
+        Self::named(DescriptorCode::Name, DataSetType::None, 0, name)
+    }
+
+    /// Construct a DAEL element with a name
+    pub fn data_element(data_type: DataSetType, max_length: u8, name: &str) -> Self {
+        Self::named(DescriptorCode::DataElement, data_type, max_length, name)
+    }
+
+    /// Construct a CTLV element with a name
+    pub fn control_value(data_type: DataSetType, max_length: u8, name: &str) -> Self {
+        Self::named(DescriptorCode::ControlValue, data_type, max_length, name)
+    }
+
+    /// Construct a CTLS element with a name
+    pub fn control_status(data_type: DataSetType, max_length: u8, name: &str) -> Self {
+        Self::named(DescriptorCode::ControlStatus, data_type, max_length, name)
+    }
+
+    /// Construct a PTYP element that includes the elements of a prototype
+    pub fn prototype(uuid: [u8; UUID_LENGTH]) -> Self {
+        Self::new(
+            DescriptorCode::Prototype,
+            DataSetType::None,
+            0,
+            uuid.to_vec(),
+        )
+    }
+
+    fn named(code: DescriptorCode, data_type: DataSetType, max_length: u8, name: &str) -> Self {
+        Self::new(code, data_type, max_length, name.as_bytes().to_vec())
+    }
+
+    pub(crate) fn encode(&self, out: &mut Vec<u8>) -> Result<(), WriteError> {
+        let length: u8 = (self.ancillary.len() + 3)
+            .try_into()
+            .map_err(|_| WriteError::Overflow)?;
+        out.push(length);
+        out.push(self.code.into());
+        out.push(self.data_type.into());
+        out.push(self.max_length);
+        out.extend_from_slice(&self.ancillary);
+        Ok(())
+    }
+}
+
+/// Data set prototype (g85v1) that may be referenced by descriptors
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DataSetPrototype {
+    /// UUID referenced by PTYP elements in descriptors
+    pub uuid: [u8; UUID_LENGTH],
+    /// Elements of the prototype
+    pub elements: Vec<DescriptorElement>,
+}
+
+impl DataSetPrototype {
+    /// Construct a prototype from its UUID and elements
+    pub fn new(uuid: [u8; UUID_LENGTH], elements: Vec<DescriptorElement>) -> Self {
+        Self { uuid, elements }
+    }
+}
+
+/// Data set descriptor (g86v1) that defines the structure of a data set
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DataSetDescriptor {
+    /// Elements of the descriptor
+    pub elements: Vec<DescriptorElement>,
+}
+
+impl DataSetDescriptor {
+    /// Construct a descriptor from its elements
+    pub fn new(elements: Vec<DescriptorElement>) -> Self {
+        Self { elements }
+    }
+
+    /// Types of the values in a data set that uses this descriptor
+    ///
+    /// PTYP elements are expanded using the prototypes returned by `find`. Returns
+    /// `None` if a referenced prototype cannot be found.
+    pub(crate) fn value_types<'a, F>(&self, find: F) -> Option<Vec<DataSetType>>
+    where
+        F: Fn(&[u8]) -> Option<&'a DataSetPrototype>,
+    {
+        let mut types = Vec::new();
+        for element in self.elements.iter() {
+            if element.code == DescriptorCode::Prototype {
+                let prototype = find(&element.ancillary)?;
+                types.extend(
+                    prototype
+                        .elements
+                        .iter()
+                        .filter(|x| x.code.has_value())
+                        .map(|x| x.data_type),
+                );
+            } else if element.code.has_value() {
+                types.push(element.data_type);
+            }
+        }
+        Some(types)
+    }
+}
+
+/// Typed value of a data set element
+#[derive(Clone, Debug, PartialEq)]
+pub enum DataSetValue {
+    /// VSTR value
+    VisibleString(String),
+    /// UINT value
+    UnsignedInt(u32),
+    /// INT value
+    SignedInt(i32),
+    /// FLT value encoded as 4 octets
+    Float32(f32),
+    /// FLT value encoded as 8 octets
+    Float64(f64),
+    /// OSTR value, also used for values whose type is not known
+    OctetString(Vec<u8>),
+    /// BSTR value
+    BitString(Vec<u8>),
+    /// TIME value
+    Time(Timestamp),
+}
+
+impl DataSetValue {
+    /// Data type code of the value
+    pub fn data_type(&self) -> DataSetType {
+        match self {
+            Self::VisibleString(_) => DataSetType::VisibleString,
+            Self::UnsignedInt(_) => DataSetType::UnsignedInt,
+            Self::SignedInt(_) => DataSetType::SignedInt,
+            Self::Float32(_) => DataSetType::FloatingPoint,
+            Self::Float64(_) => DataSetType::FloatingPoint,
+            Self::OctetString(_) => DataSetType::OctetString,
+            Self::BitString(_) => DataSetType::BitString,
+            Self::Time(_) => DataSetType::Time,
+        }
+    }
+
+    /// decode a value given the type from the descriptor
+    pub(crate) fn decode(data_type: DataSetType, data: &[u8]) -> Option<Self> {
+        let value = match data_type {
+            DataSetType::VisibleString => {
+                Self::VisibleString(std::str::from_utf8(data).ok()?.to_string())
+            }
+            DataSetType::UnsignedInt => Self::UnsignedInt(match *data {
+                [a] => a as u32,
+                [a, b] => u16::from_le_bytes([a, b]) as u32,
+                [a, b, c, d] => u32::from_le_bytes([a, b, c, d]),
+                _ => return None,
+            }),
+            DataSetType::SignedInt => Self::SignedInt(match *data {
+                [a] => a as i8 as i32,
+                [a, b] => i16::from_le_bytes([a, b]) as i32,
+                [a, b, c, d] => i32::from_le_bytes([a, b, c, d]),
+                _ => return None,
+            }),
+            DataSetType::FloatingPoint => match data.len() {
+                4 => Self::Float32(f32::from_le_bytes(data.try_into().ok()?)),
+                8 => Self::Float64(f64::from_le_bytes(data.try_into().ok()?)),
+                _ => return None,
+            },
+            DataSetType::OctetString => Self::OctetString(data.to_vec()),
+            DataSetType::BitString => Self::BitString(data.to_vec()),
+            DataSetType::Time => match *data {
+                [a, b, c, d, e, f] => {
+                    Self::Time(Timestamp::new(u64::from_le_bytes([a, b, c, d, e, f, 0, 0])))
+                }
+                _ => return None,
+            },
+            DataSetType::None | DataSetType::Other(_) => return None,
+        };
+        Some(value)
+    }
+
+    pub(crate) fn encode(&self, out: &mut Vec<u8>) -> Result<(), WriteError> {
+        fn push(out: &mut Vec<u8>, data: &[u8]) -> Result<(), WriteError> {
+            let length: u8 = data.len().try_into().map_err(|_| WriteError::Overflow)?;
+            out.push(length);
+            out.extend_from_slice(data);
+            Ok(())
+        }
+
+        match self {
+            Self::VisibleString(x) => push(out, x.as_bytes()),
+            Self::UnsignedInt(x) => match UInt::new(*x) {
+                UInt::U8(x) => push(out, &[x]),
+                UInt::U16(x) => push(out, &x.to_le_bytes()),
+                UInt::U32(x) => push(out, &x.to_le_bytes()),
+            },
+            Self::SignedInt(x) => match Int::new(*x) {
+                Int::I8(x) => push(out, &[x]),
+                Int::I16(x) => push(out, &x.to_le_bytes()),
+                Int::I32(x) => push(out, &x.to_le_bytes()),
+            },
+            Self::Float32(x) => push(out, &x.to_le_bytes()),
+            Self::Float64(x) => push(out, &x.to_le_bytes()),
+            Self::OctetString(x) => push(out, x),
+            Self::BitString(x) => push(out, x),
+            Self::Time(x) => push(out, &x.raw_value().to_le_bytes()[..6]),
+        }
+    }
+}
+
+/// Values of a data set (g87v1) or a snapshot event (g88v1)
+#[derive(Clone, Debug, PartialEq)]
+pub struct DataSet {
+    /// Time associated with the values
+    pub time: Timestamp,
+    /// Values of the data set in the order defined by the descriptor
+    pub values: Vec<DataSetValue>,
+}
+
+impl DataSet {
+    /// Construct a data set from a timestamp and values
+    pub fn new(time: Timestamp, values: Vec<DataSetValue>) -> Self {
+        Self { time, values }
+    }
+
+    /// decode the raw values of a data set using the types from the descriptor
+    ///
+    /// values whose type is unknown or doesn't match the encoding are reported as octet strings
+    pub(crate) fn decode(time: Timestamp, values: ValueElements, types: &[DataSetType]) -> Self {
+        let values = values
+            .iter()
+            .enumerate()
+            .map(|(i, data)| {
+                types
+                    .get(i)
+                    .and_then(|t| DataSetValue::decode(*t, data))
+                    .unwrap_or_else(|| DataSetValue::OctetString(data.to_vec()))
+            })
+            .collect();
+        Self { time, values }
+    }
+
+    /// true if the values match the types from the descriptor
+    pub(crate) fn matches(&self, types: &[DataSetType]) -> bool {
+        self.values.len() == types.len()
+            && self
+                .values
+                .iter()
+                .zip(types.iter())
+                .all(|(v, t)| v.data_type() == *t)
+    }
+}
@@ -0,0 +1,442 @@
+use crate::app::attr::UInt;
+use crate::app::data_set::{DataSetType, DescriptorCode, DescriptorElement, UUID_LENGTH};
+use crate::app::format::WriteError;
+use crate::app::{ObjectParseError, Timestamp};
+
+use scursor::{ReadCursor, WriteCursor};
+
+const TIME_LENGTH: u8 = 6;
+
+fn read_element<'a>(cursor: &mut ReadCursor<'a>) -> Result<&'a [u8], ObjectParseError> {
+    let length = cursor.read_u8()?;
+    Ok(cursor.read_bytes(length as usize)?)
+}
+
+fn read_id(cursor: &mut ReadCursor) -> Result<u32, ObjectParseError> {
+    match *read_element(cursor)? {
+        [a] => Ok(a as u32),
+        [a, b] => Ok(u16::from_le_bytes([a, b]) as u32),
+        [a, b, c] => Ok(u32::from_le_bytes([a, b, c, 0])),
+        [a, b, c, d] => Ok(u32::from_le_bytes([a, b, c, d])),
+        _ => Err(ObjectParseError::BadEncoding),
+    }
+}
+
+fn write_id(cursor: &mut WriteCursor, id: u32) -> Result<(), WriteError> {
+    let id = UInt::new(id);
+    cursor.write_u8(id.len())?;
+    id.write(cursor)?;
+    Ok(())
+}
+
+fn read_time(cursor: &mut ReadCursor) -> Result<Timestamp, ObjectParseError> {
+    let mut element = ReadCursor::new(read_element(cursor)?);
+    let time = Timestamp::new(element.read_u48_le()?);
+    element.expect_empty()?;
+    Ok(time)
+}
+
+fn write_time(cursor: &mut WriteCursor, time: Timestamp) -> Result<(), WriteError> {
+    cursor.write_u8(TIME_LENGTH)?;
+    cursor.write_u48_le(time.raw_value())?;
+    Ok(())
+}
+
+/// Sequence of encoded descriptor elements
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct DescriptorElements<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> DescriptorElements<'a> {
+    /// wrap data produced by [`DescriptorElements::encode`]
+    pub(crate) fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    pub(crate) fn encode(elements: &[DescriptorElement]) -> Result<Vec<u8>, WriteError> {
+        let mut data = Vec::new();
+        for element in elements {
+            element.encode(&mut data)?;
+        }
+        Ok(data)
+    }
+
+    fn read(cursor: &mut ReadCursor<'a>) -> Result<Self, ObjectParseError> {
+        let data = cursor.read_all();
+        // validate the encoding up front so that iteration cannot fail
+        let mut validate = ReadCursor::new(data);
+        while !validate.is_empty() {
+            if read_element(&mut validate)?.len() < 3 {
+                return Err(ObjectParseError::BadEncoding);
+            }
+        }
+        Ok(Self { data })
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = DescriptorElement> + 'a {
+        let mut cursor = ReadCursor::new(self.data);
+        std::iter::from_fn(move || {
+            let element = read_element(&mut cursor).ok()?;
+            Some(DescriptorElement {
+                code: DescriptorCode::new(element[0]),
+                data_type: DataSetType::new(element[1]),
+                max_length: element[2],
+                ancillary: element[3..].to_vec(),
+            })
+        })
+    }
+
+    fn format(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for element in self.iter() {
+            write!(
+                f,
+                "\n{:?} type: {:?} max length: {} ancillary: {:02X?}",
+                element.code, element.data_type, element.max_length, element.ancillary
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Sequence of encoded data set values
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct ValueElements<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> ValueElements<'a> {
+    /// wrap data produced by [`ValueElements::encode`]
+    pub(crate) fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    pub(crate) fn encode(values: &[super::DataSetValue]) -> Result<Vec<u8>, WriteError> {
+        let mut data = Vec::new();
+        for value in values {
+            value.encode(&mut data)?;
+        }
+        Ok(data)
+    }
+
+    fn read(cursor: &mut ReadCursor<'a>) -> Result<Self, ObjectParseError> {
+        let data = cursor.read_all();
+        // validate the encoding up front so that iteration cannot fail
+        let mut validate = ReadCursor::new(data);
+        while !validate.is_empty() {
+            read_element(&mut validate)?;
+        }
+        Ok(Self { data })
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &'a [u8]> {
+        let mut cursor = ReadCursor::new(self.data);
+        std::iter::from_fn(move || read_element(&mut cursor).ok())
+    }
+
+    fn format(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for value in self.iter() {
+            write!(f, "\nvalue: {value:02X?}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Group 85 Variation 1 - Data set prototype
+///
+/// This representation is borrowed from the underlying ASDU
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct Group85Var1<'a> {
+    pub(crate) id: u32,
+    pub(crate) uuid: [u8; UUID_LENGTH],
+    pub(crate) elements: DescriptorElements<'a>,
+}
+
+impl<'a> Group85Var1<'a> {
+    pub(crate) fn format(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "\nprototype id: {} uuid: {:02X?}", self.id, self.uuid)?;
+        self.elements.format(f)
+    }
+
+    pub(crate) fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        write_id(cursor, self.id)?;
+        cursor.write_u8(UUID_LENGTH as u8)?;
+        cursor.write_bytes(&self.uuid)?;
+        cursor.write_bytes(self.elements.data)?;
+        Ok(())
+    }
+
+    pub(crate) fn read(cursor: &mut ReadCursor<'a>) -> Result<Self, ObjectParseError> {
+        let id = read_id(cursor)?;
+        let uuid = read_element(cursor)?
+            .try_into()
+            .map_err(|_| ObjectParseError::BadEncoding)?;
+        let elements = DescriptorElements::read(cursor)?;
+        Ok(Self { id, uuid, elements })
+    }
+}
+
+/// Group 86 Variation 1 - Data set descriptor
+///
+/// This representation is borrowed from the underlying ASDU
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct Group86Var1<'a> {
+    pub(crate) id: u32,
+    pub(crate) elements: DescriptorElements<'a>,
+}
+
+impl<'a> Group86Var1<'a> {
+    pub(crate) fn format(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "\ndescriptor id: {}", self.id)?;
+        self.elements.format(f)
+    }
+
+    pub(crate) fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        write_id(cursor, self.id)?;
+        cursor.write_bytes(self.elements.data)?;
+        Ok(())
+    }
+
+    pub(crate) fn read(cursor: &mut ReadCursor<'a>) -> Result<Self, ObjectParseError> {
+        let id = read_id(cursor)?;
+        let elements = DescriptorElements::read(cursor)?;
+        Ok(Self { id, elements })
+    }
+}
+
+/// Group 87 Variation 1 - Data set present value
+///
+/// This representation is borrowed from the underlying ASDU
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct Group87Var1<'a> {
+    pub(crate) id: u32,
+    pub(crate) time: Timestamp,
+    pub(crate) values: ValueElements<'a>,
+}
+
+impl<'a> Group87Var1<'a> {
+    pub(crate) fn format(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "\ndata set id: {} time: {}", self.id, self.time)?;
+        self.values.format(f)
+    }
+
+    pub(crate) fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        write_id(cursor, self.id)?;
+        write_time(cursor, self.time)?;
+        cursor.write_bytes(self.values.data)?;
+        Ok(())
+    }
+
+    pub(crate) fn read(cursor: &mut ReadCursor<'a>) -> Result<Self, ObjectParseError> {
+        let id = read_id(cursor)?;
+        let time = read_time(cursor)?;
+        let values = ValueElements::read(cursor)?;
+        Ok(Self { id, time, values })
+    }
+}
+
+/// Group 88 Variation 1 - Data set snapshot event
+///
+/// This representation is borrowed from the underlying ASDU
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct Group88Var1<'a> {
+    pub(crate) id: u32,
+    pub(crate) time: Timestamp,
+    pub(crate) values: ValueElements<'a>,
+}
+
+impl<'a> Group88Var1<'a> {
+    pub(crate) fn format(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "\ndata set id: {} time: {}", self.id, self.time)?;
+        self.values.format(f)
+    }
+
+    pub(crate) fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        write_id(cursor, self.id)?;
+        write_time(cursor, self.time)?;
+        cursor.write_bytes(self.values.data)?;
+        Ok(())
+    }
+
+    pub(crate) fn read(cursor: &mut ReadCursor<'a>) -> Result<Self, ObjectParseError> {
+        let id = read_id(cursor)?;
+        let time = read_time(cursor)?;
+        let values = ValueElements::read(cursor)?;
+        Ok(Self { id, time, values })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::app::data_set::{DataSet, DataSetDescriptor, DataSetPrototype, DataSetValue};
+
+    const UUID: [u8; UUID_LENGTH] = [
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E,
+        0x0F,
+    ];
+
+    fn write_object<F>(f: F) -> Vec<u8>
+    where
+        F: FnOnce(&mut WriteCursor) -> Result<(), WriteError>,
+    {
+        let mut buffer = [0; 256];
+        let mut cursor = WriteCursor::new(&mut buffer);
+        f(&mut cursor).unwrap();
+        cursor.written().to_vec()
+    }
+
+    #[test]
+    fn writes_and_parses_prototype() {
+        let elements = DescriptorElements::encode(&[DescriptorElement::data_element(
+            DataSetType::UnsignedInt,
+            4,
+            "A",
+        )])
+        .unwrap();
+
+        let object = Group85Var1 {
+            id: 7,
+            uuid: UUID,
+            elements: DescriptorElements::new(&elements),
+        };
+
+        let data = write_object(|cur| object.write(cur));
+
+        let mut expected = vec![0x01, 0x07, 0x10];
+        expected.extend_from_slice(&UUID);
+        expected.extend_from_slice(&[0x04, 0x05, 0x02, 0x04, b'A']);
+        assert_eq!(data, expected);
+
+        let mut cursor = ReadCursor::new(&data);
+        assert_eq!(Group85Var1::read(&mut cursor).unwrap(), object);
+        assert!(cursor.is_empty());
+    }
+
+    #[test]
+    fn writes_and_parses_descriptor() {
+        let descriptor = DataSetDescriptor::new(vec![
+            DescriptorElement::name("ds"),
+            DescriptorElement::data_element(DataSetType::FloatingPoint, 4, "V"),
+            DescriptorElement::prototype(UUID),
+        ]);
+        let elements = DescriptorElements::encode(&descriptor.elements).unwrap();
+        let object = Group86Var1 {
+            id: 0x0102,
+            elements: DescriptorElements::new(&elements),
+        };
+
+        let data = write_object(|cur| object.write(cur));
+        assert_eq!(
+            &data[0..8],
+            &[0x02, 0x02, 0x01, 0x05, 0x04, 0x00, 0x00, b'd']
+        );
+
+        let mut cursor = ReadCursor::new(&data);
+        let parsed = Group86Var1::read(&mut cursor).unwrap();
+        assert_eq!(parsed.id, 0x0102);
+        assert_eq!(
+            parsed.elements.iter().collect::<Vec<_>>(),
+            descriptor.elements
+        );
+    }
+
+    #[test]
+    fn writes_and_parses_present_value() {
+        let data_set = DataSet::new(
+            Timestamp::new(0x010203040506),
+            vec![
+                DataSetValue::UnsignedInt(0x1234),
+                DataSetValue::SignedInt(-2),
+                DataSetValue::VisibleString("on".to_string()),
+            ],
+        );
+        let values = ValueElements::encode(&data_set.values).unwrap();
+        let object = Group87Var1 {
+            id: 1,
+            time: data_set.time,
+            values: ValueElements::new(&values),
+        };
+
+        let data = write_object(|cur| object.write(cur));
+        assert_eq!(
+            data,
+            [
+                0x01, 0x01, 0x06, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01, 0x02, 0x34, 0x12, 0x01, 0xFE,
+                0x02, b'o', b'n'
+            ]
+        );
+
+        let mut cursor = ReadCursor::new(&data);
+        let parsed = Group87Var1::read(&mut cursor).unwrap();
+        let types = [
+            DataSetType::UnsignedInt,
+            DataSetType::SignedInt,
+            DataSetType::VisibleString,
+        ];
+        assert_eq!(
+            DataSet::decode(parsed.time, parsed.values, &types),
+            data_set
+        );
+    }
+
+    #[test]
+    fn unknown_types_are_decoded_as_octet_strings() {
+        let data = [0x01, 0x01, 0x06, 0, 0, 0, 0, 0, 0, 0x03, 0xAA, 0xBB, 0xCC];
+        let mut cursor = ReadCursor::new(&data);
+        let parsed = Group88Var1::read(&mut cursor).unwrap();
+
+        // the type says UINT, but 3 octets is not a valid encoding
+        let data_set = DataSet::decode(parsed.time, parsed.values, &[DataSetType::UnsignedInt]);
+        assert_eq!(
+            data_set.values,
+            vec![DataSetValue::OctetString(vec![0xAA, 0xBB, 0xCC])]
+        );
+    }
+
+    #[test]
+    fn rejects_truncated_value() {
+        let data = [0x01, 0x01, 0x06, 0, 0, 0, 0, 0, 0, 0x03, 0xAA];
+        let mut cursor = ReadCursor::new(&data);
+        assert_eq!(
+            Group87Var1::read(&mut cursor),
+            Err(ObjectParseError::InsufficientBytes)
+        );
+    }
+
+    #[test]
+    fn rejects_short_descriptor_element() {
+        let data = [0x01, 0x01, 0x02, 0x05, 0x02];
+        let mut cursor = ReadCursor::new(&data);
+        assert_eq!(
+            Group86Var1::read(&mut cursor),
+            Err(ObjectParseError::BadEncoding)
+        );
+    }
+
+    #[test]
+    fn descriptor_value_types_expand_prototypes() {
+        let prototype = DataSetPrototype::new(
+            UUID,
+            vec![
+                DescriptorElement::name("proto"),
+                DescriptorElement::data_element(DataSetType::Time, 6, "T"),
+                DescriptorElement::control_value(DataSetType::SignedInt, 2, "C"),
+            ],
+        );
+        let descriptor = DataSetDescriptor::new(vec![
+            DescriptorElement::data_element(DataSetType::UnsignedInt, 4, "U"),
+            DescriptorElement::prototype(UUID),
+        ]);
+
+        assert_eq!(
+            descriptor.value_types(|uuid| (uuid == UUID).then_some(&prototype)),
+            Some(vec![
+                DataSetType::UnsignedInt,
+                DataSetType::Time,
+                DataSetType::SignedInt
+            ])
+        );
+        assert_eq!(descriptor.value_types(|_| None), None);
+    }
+}
@@ -46,6 +46,45 @@ impl Group12Var1 {
     }
 }
 
+impl Group12Var2 {
+    /// construct a `Group12Var2` instance. The status field is automatically set to `CommandStatus::Success`
+    pub const fn new(code: ControlCode, count: u8, on_time: u32, off_time: u32) -> Self {
+        Self {
+            code,
+            count,
+            on_time,
+            off_time,
+            status: CommandStatus::Success,
+        }
+    }
+
+    /// construct a `Group12Var2` instance from the `ControlCode`. Other fields are set to the following defaults:
+    /// * count = 1
+    /// * on_time = 1000
+    /// * off_time = 1000
+    /// * status = `CommandStatus::Success`
+    pub fn from_code(code: ControlCode) -> Self {
+        Self {
+            code,
+            count: 1,
+            on_time: 1000,
+            off_time: 1000,
+            status: CommandStatus::Success,
+        }
+    }
+
+    /// CROB with the same fields, applied to each output selected by a pattern mask
+    pub(crate) fn to_crob(self) -> Group12Var1 {
+        Group12Var1 {
+            code: self.code,
+            count: self.count,
+            on_time: self.on_time,
+            off_time: self.off_time,
+            status: self.status,
+        }
+    }
+}
+
 impl Group41Var1 {
     /// construct a `Group41Var1` instance. The status field is automatically set to `CommandStatus::Success`
     pub const fn new(value: i32) -> Self {
@@ -132,6 +171,12 @@ impl WireFlags for AnalogOutputStatus {
     }
 }
 
+impl WireFlags for SecurityStatistic {
+    fn get_wire_flags(&self) -> u8 {
+        self.flags.value
+    }
+}
+
 impl AnalogConversions for AnalogInput {
     fn get_value(&self) -> f64 {
         self.value
@@ -254,6 +299,9 @@ impl FunctionCode {
             FunctionCode::GetFileInfo => FunctionInfo::request_with_objects(),
             FunctionCode::AuthenticateFile => FunctionInfo::request_with_objects(),
             FunctionCode::AbortFile => FunctionInfo::request_with_objects(),
+            FunctionCode::ActivateConfig => FunctionInfo::request_with_objects(),
+            FunctionCode::AuthRequest => FunctionInfo::request_with_objects(),
+            FunctionCode::AuthRequestNoAck => FunctionInfo::request_with_objects(),
             // requests that never have object headers
             FunctionCode::ColdRestart => FunctionInfo::request_by_function_only(),
             FunctionCode::WarmRestart => FunctionInfo::request_by_function_only(),
@@ -264,6 +312,7 @@ impl FunctionCode {
             // responses
             FunctionCode::Response => FunctionInfo::response(),
             FunctionCode::UnsolicitedResponse => FunctionInfo::response(),
+            FunctionCode::AuthResponse => FunctionInfo::response(),
         }
     }
 }
@@ -21,8 +21,6 @@ impl<'a> Group70Var6<'a> {
         Ok(())
     }
 
-    // we aren't using this in production yet but will be needed for the outstation
-    #[cfg(test)]
     pub(crate) fn write(
         &self,
         cursor: &mut scursor::WriteCursor,
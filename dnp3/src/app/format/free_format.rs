@@ -1,3 +1,8 @@
+use crate::app::auth::{
+    Group120Var1, Group120Var10, Group120Var11, Group120Var12, Group120Var13, Group120Var14,
+    Group120Var15, Group120Var2, Group120Var5, Group120Var6, Group120Var7, Group120Var9,
+};
+use crate::app::data_set::{Group85Var1, Group86Var1, Group87Var1, Group88Var1};
 use crate::app::file::*;
 use crate::app::format::WriteError;
 use crate::app::Variation;
@@ -41,6 +46,14 @@ impl FreeFormat for Group70Var5<'_> {
     }
 }
 
+impl FreeFormat for Group70Var6<'_> {
+    const VARIATION: Variation = Variation::Group70Var6;
+
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        self.write(cursor)
+    }
+}
+
 impl FreeFormat for Group70Var7<'_> {
     const VARIATION: Variation = Variation::Group70Var7;
 
@@ -48,3 +61,131 @@ impl FreeFormat for Group70Var7<'_> {
         self.write(cursor)
     }
 }
+
+impl FreeFormat for Group85Var1<'_> {
+    const VARIATION: Variation = Variation::Group85Var1;
+
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        self.write(cursor)
+    }
+}
+
+impl FreeFormat for Group86Var1<'_> {
+    const VARIATION: Variation = Variation::Group86Var1;
+
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        self.write(cursor)
+    }
+}
+
+impl FreeFormat for Group87Var1<'_> {
+    const VARIATION: Variation = Variation::Group87Var1;
+
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        self.write(cursor)
+    }
+}
+
+impl FreeFormat for Group88Var1<'_> {
+    const VARIATION: Variation = Variation::Group88Var1;
+
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        self.write(cursor)
+    }
+}
+
+impl FreeFormat for Group120Var1<'_> {
+    const VARIATION: Variation = Variation::Group120Var1;
+
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        self.write(cursor)
+    }
+}
+
+impl FreeFormat for Group120Var2<'_> {
+    const VARIATION: Variation = Variation::Group120Var2;
+
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        self.write(cursor)
+    }
+}
+
+impl FreeFormat for Group120Var5<'_> {
+    const VARIATION: Variation = Variation::Group120Var5;
+
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        self.write(cursor)
+    }
+}
+
+impl FreeFormat for Group120Var6<'_> {
+    const VARIATION: Variation = Variation::Group120Var6;
+
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        self.write(cursor)
+    }
+}
+
+impl FreeFormat for Group120Var7<'_> {
+    const VARIATION: Variation = Variation::Group120Var7;
+
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        self.write(cursor)
+    }
+}
+
+impl FreeFormat for Group120Var9<'_> {
+    const VARIATION: Variation = Variation::Group120Var9;
+
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        self.write(cursor)
+    }
+}
+
+impl FreeFormat for Group120Var10<'_> {
+    const VARIATION: Variation = Variation::Group120Var10;
+
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        self.write(cursor)
+    }
+}
+
+impl FreeFormat for Group120Var11<'_> {
+    const VARIATION: Variation = Variation::Group120Var11;
+
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        self.write(cursor)
+    }
+}
+
+impl FreeFormat for Group120Var12<'_> {
+    const VARIATION: Variation = Variation::Group120Var12;
+
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        self.write(cursor)
+    }
+}
+
+impl FreeFormat for Group120Var13<'_> {
+    const VARIATION: Variation = Variation::Group120Var13;
+
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        self.write(cursor)
+    }
+}
+
+impl FreeFormat for Group120Var14<'_> {
+    const VARIATION: Variation = Variation::Group120Var14;
+
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        self.write(cursor)
+    }
+}
+
+impl FreeFormat for Group120Var15<'_> {
+    const VARIATION: Variation = Variation::Group120Var15;
+
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        self.write(cursor)
+    }
+}
@@ -1,3 +1,4 @@
+use crate::app::activation::{Group91Var1, StatusElement};
 use crate::app::attr::{AttrWriteError, OwnedAttribute};
 use crate::app::header::{ControlField, RequestHeader};
 #[cfg(test)]
@@ -57,6 +58,11 @@ impl<'a, 'b> HeaderWriter<'a, 'b> {
         Self { cursor }
     }
 
+    /// bytes of the fragment written so far, including the request header
+    pub(crate) fn written(&self) -> &[u8] {
+        self.cursor.written()
+    }
+
     pub(crate) fn write_all_objects_header(
         &mut self,
         variation: Variation,
@@ -96,6 +102,37 @@ impl<'a, 'b> HeaderWriter<'a, 'b> {
         Ok(())
     }
 
+    /// write a range header followed by the packed bit values, e.g. a g12v3 pattern mask
+    pub(crate) fn write_packed_bits<T>(
+        &mut self,
+        variation: Variation,
+        start: T,
+        stop: T,
+        bits: impl Iterator<Item = bool>,
+    ) -> Result<(), scursor::WriteError>
+    where
+        T: Index,
+    {
+        self.write_range_only(variation, start, stop)?;
+        let mut byte: u8 = 0;
+        let mut pos: u8 = 0;
+        for bit in bits {
+            if bit {
+                byte |= 1 << pos;
+            }
+            pos += 1;
+            if pos == 8 {
+                self.cursor.write_u8(byte)?;
+                byte = 0;
+                pos = 0;
+            }
+        }
+        if pos > 0 {
+            self.cursor.write_u8(byte)?;
+        }
+        Ok(())
+    }
+
     pub(crate) fn write_clear_restart(&mut self) -> Result<(), scursor::WriteError> {
         self.write_range_only(Variation::Group80Var1, 7u8, 7u8)?;
         self.cursor.write_u8(0)?;
@@ -125,6 +162,22 @@ impl<'a, 'b> HeaderWriter<'a, 'b> {
         self.cursor.at_pos(pos_of_count, |cur| count.write(cur))
     }
 
+    /// write a single object whose length is encoded in the variation (e.g. g112) using a
+    /// two-byte count and prefix
+    pub(crate) fn write_sized_by_variation(
+        &mut self,
+        variation: Variation,
+        index: u16,
+        data: &[u8],
+    ) -> Result<(), scursor::WriteError> {
+        variation.write(self.cursor)?;
+        QualifierCode::CountAndPrefix16.write(self.cursor)?;
+        self.cursor.write_u16_le(1)?;
+        self.cursor.write_u16_le(index)?;
+        self.cursor.write_bytes(data)?;
+        Ok(())
+    }
+
     pub(crate) fn write_count_of_one<V>(&mut self, item: V) -> Result<(), scursor::WriteError>
     where
         V: FixedSizeVariation,
@@ -164,6 +217,17 @@ impl<'a, 'b> HeaderWriter<'a, 'b> {
         Ok(())
     }
 
+    pub(crate) fn write_activation_status(
+        &mut self,
+        time_delay_ms: u32,
+        elements: &[StatusElement],
+    ) -> Result<(), crate::app::format::WriteError> {
+        Variation::Group91Var1.write(self.cursor)?;
+        QualifierCode::Count8.write(self.cursor)?;
+        self.cursor.write_u8(1)?;
+        Group91Var1::write(self.cursor, time_delay_ms, elements)
+    }
+
     #[cfg(test)]
     pub(crate) fn to_parsed(&'a self) -> ParsedFragment<'a> {
         ParsedFragment::parse(
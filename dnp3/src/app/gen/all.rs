@@ -36,6 +36,7 @@ pub(crate) enum AllObjectsVariation {
     Group11Var0,
     Group11Var1,
     Group11Var2,
+    Group13Var0,
     Group13Var1,
     Group13Var2,
     Group20Var0,
@@ -112,6 +113,7 @@ pub(crate) enum AllObjectsVariation {
     Group42Var6,
     Group42Var7,
     Group42Var8,
+    Group43Var0,
     Group43Var1,
     Group43Var2,
     Group43Var3,
@@ -125,10 +127,21 @@ pub(crate) enum AllObjectsVariation {
     Group60Var3,
     Group60Var4,
     Group80Var1,
+    Group85Var1,
+    Group86Var1,
+    Group87Var1,
+    Group88Var1,
+    Group90Var1,
     Group102Var0,
     Group102Var1,
     Group110Var0,
     Group111Var0,
+    Group113Var0,
+    Group121Var0,
+    Group121Var1,
+    Group122Var0,
+    Group122Var1,
+    Group122Var2,
 }
 
 impl AllObjectsVariation {
@@ -156,6 +169,7 @@ impl AllObjectsVariation {
             Variation::Group11Var0 => Some(AllObjectsVariation::Group11Var0),
             Variation::Group11Var1 => Some(AllObjectsVariation::Group11Var1),
             Variation::Group11Var2 => Some(AllObjectsVariation::Group11Var2),
+            Variation::Group13Var0 => Some(AllObjectsVariation::Group13Var0),
             Variation::Group13Var1 => Some(AllObjectsVariation::Group13Var1),
             Variation::Group13Var2 => Some(AllObjectsVariation::Group13Var2),
             Variation::Group20Var0 => Some(AllObjectsVariation::Group20Var0),
@@ -232,6 +246,7 @@ impl AllObjectsVariation {
             Variation::Group42Var6 => Some(AllObjectsVariation::Group42Var6),
             Variation::Group42Var7 => Some(AllObjectsVariation::Group42Var7),
             Variation::Group42Var8 => Some(AllObjectsVariation::Group42Var8),
+            Variation::Group43Var0 => Some(AllObjectsVariation::Group43Var0),
             Variation::Group43Var1 => Some(AllObjectsVariation::Group43Var1),
             Variation::Group43Var2 => Some(AllObjectsVariation::Group43Var2),
             Variation::Group43Var3 => Some(AllObjectsVariation::Group43Var3),
@@ -245,10 +260,21 @@ impl AllObjectsVariation {
             Variation::Group60Var3 => Some(AllObjectsVariation::Group60Var3),
             Variation::Group60Var4 => Some(AllObjectsVariation::Group60Var4),
             Variation::Group80Var1 => Some(AllObjectsVariation::Group80Var1),
+            Variation::Group85Var1 => Some(AllObjectsVariation::Group85Var1),
+            Variation::Group86Var1 => Some(AllObjectsVariation::Group86Var1),
+            Variation::Group87Var1 => Some(AllObjectsVariation::Group87Var1),
+            Variation::Group88Var1 => Some(AllObjectsVariation::Group88Var1),
+            Variation::Group90Var1 => Some(AllObjectsVariation::Group90Var1),
             Variation::Group102Var0 => Some(AllObjectsVariation::Group102Var0),
             Variation::Group102Var1 => Some(AllObjectsVariation::Group102Var1),
             Variation::Group110(0) => Some(AllObjectsVariation::Group110Var0),
             Variation::Group111(0) => Some(AllObjectsVariation::Group111Var0),
+            Variation::Group113(0) => Some(AllObjectsVariation::Group113Var0),
+            Variation::Group121Var0 => Some(AllObjectsVariation::Group121Var0),
+            Variation::Group121Var1 => Some(AllObjectsVariation::Group121Var1),
+            Variation::Group122Var0 => Some(AllObjectsVariation::Group122Var0),
+            Variation::Group122Var1 => Some(AllObjectsVariation::Group122Var1),
+            Variation::Group122Var2 => Some(AllObjectsVariation::Group122Var2),
             _ => None,
         }
     }
@@ -12,6 +12,7 @@
 
 use crate::app::variations::*;
 use crate::app::QualifierCode;
+use crate::app::activation::Group91Var1;
 use crate::app::parse::count::CountSequence;
 use crate::app::parse::parser::*;
 use crate::app::ObjectParseError;
@@ -42,6 +43,10 @@ pub(crate) enum CountVariation<'a> {
     Group11Var1,
     /// Binary Output Event - Output Status With Time
     Group11Var2,
+    /// Binary Command - Pattern Control Block
+    Group12Var2(CountSequence<'a, Group12Var2>),
+    /// Binary Output Command Event - Any Variation
+    Group13Var0,
     /// Binary Output Command Event - Without Time
     Group13Var1,
     /// Binary Output Command Event - With Time
@@ -120,6 +125,8 @@ pub(crate) enum CountVariation<'a> {
     Group42Var7,
     /// Analog Output Event - Double-precision With Flag and Time
     Group42Var8,
+    /// Analog Output Command Event - Any Variation
+    Group43Var0,
     /// Analog Output Command Event - 32-bit
     Group43Var1,
     /// Analog Output Command Event - 16-bit
@@ -158,9 +165,22 @@ pub(crate) enum CountVariation<'a> {
     Group60Var3,
     /// Class Data - Class 3
     Group60Var4,
+    /// Activate Configuration - Status of Requested Operations
+    Group91Var1(Group91Var1<'a>),
     /// Octet String Event - Sized by variation
     Group111Var0,
     Group111VarX(u8),
+    /// Virtual Terminal Event Data - Sized by variation
+    Group113Var0,
+    Group113VarX(u8),
+    Group120Var3(CountSequence<'a, Group120Var3>),
+    Group120Var4(CountSequence<'a, Group120Var4>),
+    /// Security Statistic Event - Any Variation
+    Group122Var0,
+    /// Security Statistic Event - 32-bit With Flag
+    Group122Var1,
+    /// Security Statistic Event - 32-bit With Flag and Time
+    Group122Var2,
 }
 
 impl<'a> CountVariation<'a> {
@@ -177,6 +197,8 @@ impl<'a> CountVariation<'a> {
             Variation::Group11Var0 => Ok(CountVariation::Group11Var0),
             Variation::Group11Var1 => Ok(CountVariation::Group11Var1),
             Variation::Group11Var2 => Ok(CountVariation::Group11Var2),
+            Variation::Group12Var2 => Ok(CountVariation::Group12Var2(CountSequence::parse(count, cursor)?)),
+            Variation::Group13Var0 => Ok(CountVariation::Group13Var0),
             Variation::Group13Var1 => Ok(CountVariation::Group13Var1),
             Variation::Group13Var2 => Ok(CountVariation::Group13Var2),
             Variation::Group22Var0 => Ok(CountVariation::Group22Var0),
@@ -216,6 +238,7 @@ impl<'a> CountVariation<'a> {
             Variation::Group42Var6 => Ok(CountVariation::Group42Var6),
             Variation::Group42Var7 => Ok(CountVariation::Group42Var7),
             Variation::Group42Var8 => Ok(CountVariation::Group42Var8),
+            Variation::Group43Var0 => Ok(CountVariation::Group43Var0),
             Variation::Group43Var1 => Ok(CountVariation::Group43Var1),
             Variation::Group43Var2 => Ok(CountVariation::Group43Var2),
             Variation::Group43Var3 => Ok(CountVariation::Group43Var3),
@@ -235,8 +258,16 @@ impl<'a> CountVariation<'a> {
             Variation::Group60Var2 => Ok(CountVariation::Group60Var2),
             Variation::Group60Var3 => Ok(CountVariation::Group60Var3),
             Variation::Group60Var4 => Ok(CountVariation::Group60Var4),
+            Variation::Group91Var1 => Ok(CountVariation::Group91Var1(Group91Var1::parse(count, cursor)?)),
             Variation::Group111(0) => Ok(CountVariation::Group111Var0),
             Variation::Group111(x) => Ok(CountVariation::Group111VarX(x)),
+            Variation::Group113(0) => Ok(CountVariation::Group113Var0),
+            Variation::Group113(x) => Ok(CountVariation::Group113VarX(x)),
+            Variation::Group120Var3 => Ok(CountVariation::Group120Var3(CountSequence::parse(count, cursor)?)),
+            Variation::Group120Var4 => Ok(CountVariation::Group120Var4(CountSequence::parse(count, cursor)?)),
+            Variation::Group122Var0 => Ok(CountVariation::Group122Var0),
+            Variation::Group122Var1 => Ok(CountVariation::Group122Var1),
+            Variation::Group122Var2 => Ok(CountVariation::Group122Var2),
             _ => Err(ObjectParseError::InvalidQualifierForVariation(v, qualifier)),
         }
     }
@@ -254,6 +285,8 @@ impl<'a> CountVariation<'a> {
             CountVariation::Group11Var0 => Ok(()),
             CountVariation::Group11Var1 => Ok(()),
             CountVariation::Group11Var2 => Ok(()),
+            CountVariation::Group12Var2(seq) => format_count_of_items(f, seq.iter()),
+            CountVariation::Group13Var0 => Ok(()),
             CountVariation::Group13Var1 => Ok(()),
             CountVariation::Group13Var2 => Ok(()),
             CountVariation::Group22Var0 => Ok(()),
@@ -293,6 +326,7 @@ impl<'a> CountVariation<'a> {
             CountVariation::Group42Var6 => Ok(()),
             CountVariation::Group42Var7 => Ok(()),
             CountVariation::Group42Var8 => Ok(()),
+            CountVariation::Group43Var0 => Ok(()),
             CountVariation::Group43Var1 => Ok(()),
             CountVariation::Group43Var2 => Ok(()),
             CountVariation::Group43Var3 => Ok(()),
@@ -312,8 +346,16 @@ impl<'a> CountVariation<'a> {
             CountVariation::Group60Var2 => Ok(()),
             CountVariation::Group60Var3 => Ok(()),
             CountVariation::Group60Var4 => Ok(()),
+            CountVariation::Group91Var1(x) => x.format(f),
             CountVariation::Group111Var0 => Ok(()),
             CountVariation::Group111VarX(_) => Ok(()),
+            CountVariation::Group113Var0 => Ok(()),
+            CountVariation::Group113VarX(_) => Ok(()),
+            CountVariation::Group120Var3(seq) => format_count_of_items(f, seq.iter()),
+            CountVariation::Group120Var4(seq) => format_count_of_items(f, seq.iter()),
+            CountVariation::Group122Var0 => Ok(()),
+            CountVariation::Group122Var1 => Ok(()),
+            CountVariation::Group122Var2 => Ok(()),
         }
     }
 }
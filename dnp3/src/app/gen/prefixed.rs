@@ -143,8 +143,20 @@ pub(crate) enum PrefixedVariation<'a, I> where I : FixedSize + Index + std::fmt:
     Group43Var7(CountSequence<'a, Prefix<I, Group43Var7>>),
     /// Analog Output Command Event - Double-precision With Time
     Group43Var8(CountSequence<'a, Prefix<I, Group43Var8>>),
+    /// Time and Date - Indexed absolute time and long interval
+    Group50Var4(CountSequence<'a, Prefix<I, Group50Var4>>),
+    /// Octet String - Sized by variation
+    Group110VarX(u8, PrefixedBytesSequence<'a, I>),
     /// Octet String Event - Sized by variation
     Group111VarX(u8, PrefixedBytesSequence<'a, I>),
+    /// Virtual Terminal Output Block - Sized by variation
+    Group112VarX(u8, PrefixedBytesSequence<'a, I>),
+    /// Virtual Terminal Event Data - Sized by variation
+    Group113VarX(u8, PrefixedBytesSequence<'a, I>),
+    /// Security Statistic Event - 32-bit With Flag
+    Group122Var1(CountSequence<'a, Prefix<I, Group122Var1>>),
+    /// Security Statistic Event - 32-bit With Flag and Time
+    Group122Var2(CountSequence<'a, Prefix<I, Group122Var2>>),
 }
 
 impl<'a, I> PrefixedVariation<'a, I> where I : FixedSize + Index + std::fmt::Display {
@@ -209,7 +221,13 @@ impl<'a, I> PrefixedVariation<'a, I> where I : FixedSize + Index + std::fmt::Dis
             Variation::Group43Var6 => Ok(PrefixedVariation::Group43Var6(CountSequence::parse(count, cursor)?)),
             Variation::Group43Var7 => Ok(PrefixedVariation::Group43Var7(CountSequence::parse(count, cursor)?)),
             Variation::Group43Var8 => Ok(PrefixedVariation::Group43Var8(CountSequence::parse(count, cursor)?)),
+            Variation::Group50Var4 => Ok(PrefixedVariation::Group50Var4(CountSequence::parse(count, cursor)?)),
+            Variation::Group110(x) => Ok(PrefixedVariation::Group110VarX(x, PrefixedBytesSequence::parse(options, x, count, cursor)?)),
             Variation::Group111(x) => Ok(PrefixedVariation::Group111VarX(x, PrefixedBytesSequence::parse(options, x, count, cursor)?)),
+            Variation::Group112(x) => Ok(PrefixedVariation::Group112VarX(x, PrefixedBytesSequence::parse(options, x, count, cursor)?)),
+            Variation::Group113(x) => Ok(PrefixedVariation::Group113VarX(x, PrefixedBytesSequence::parse(options, x, count, cursor)?)),
+            Variation::Group122Var1 => Ok(PrefixedVariation::Group122Var1(CountSequence::parse(count, cursor)?)),
+            Variation::Group122Var2 => Ok(PrefixedVariation::Group122Var2(CountSequence::parse(count, cursor)?)),
             _ => Err(ObjectParseError::InvalidQualifierForVariation(v, I::COUNT_AND_PREFIX_QUALIFIER)),
         }
     }
@@ -275,7 +293,13 @@ impl<'a, I> PrefixedVariation<'a, I> where I : FixedSize + Index + std::fmt::Dis
             PrefixedVariation::Group43Var6(seq) => format_prefixed_items(f, seq.iter()),
             PrefixedVariation::Group43Var7(seq) => format_prefixed_items(f, seq.iter()),
             PrefixedVariation::Group43Var8(seq) => format_prefixed_items(f, seq.iter()),
+            PrefixedVariation::Group50Var4(seq) => format_prefixed_items(f, seq.iter()),
+            PrefixedVariation::Group110VarX(_, seq) => format_indexed_items(f, seq.iter().map(|(x, i)| (Bytes::new(x), i))),
             PrefixedVariation::Group111VarX(_, seq) => format_indexed_items(f, seq.iter().map(|(x, i)| (Bytes::new(x), i))),
+            PrefixedVariation::Group112VarX(_, seq) => format_indexed_items(f, seq.iter().map(|(x, i)| (Bytes::new(x), i))),
+            PrefixedVariation::Group113VarX(_, seq) => format_indexed_items(f, seq.iter().map(|(x, i)| (Bytes::new(x), i))),
+            PrefixedVariation::Group122Var1(seq) => format_prefixed_items(f, seq.iter()),
+            PrefixedVariation::Group122Var2(seq) => format_prefixed_items(f, seq.iter()),
         }
     }
     
@@ -660,6 +684,16 @@ impl<'a, I> PrefixedVariation<'a, I> where I : FixedSize + Index + std::fmt::Dis
                 );
                 true
             }
+            PrefixedVariation::Group50Var4(_) => {
+                false // time and interval
+            }
+            PrefixedVariation::Group110VarX(_, seq) => {
+                handler.handle_octet_string(
+                    self.get_header_info(),
+                    &mut seq.iter().map(|x| (x.0, x.1.widen_to_u16()))
+                );
+                true
+            }
             PrefixedVariation::Group111VarX(_, seq) => {
                 handler.handle_octet_string(
                     self.get_header_info(),
@@ -667,6 +701,30 @@ impl<'a, I> PrefixedVariation<'a, I> where I : FixedSize + Index + std::fmt::Dis
                 );
                 true
             }
+            PrefixedVariation::Group112VarX(_, _) => {
+                false // virtual terminal output is only written by the master
+            }
+            PrefixedVariation::Group113VarX(_, seq) => {
+                handler.handle_virtual_terminal(
+                    self.get_header_info(),
+                    &mut seq.iter().map(|x| (x.0, x.1.widen_to_u16()))
+                );
+                true
+            }
+            PrefixedVariation::Group122Var1(seq) => {
+                handler.handle_security_statistic(
+                    self.get_header_info(),
+                    &mut seq.iter().map(|x| (x.value.into(), x.index.widen_to_u16()))
+                );
+                true
+            }
+            PrefixedVariation::Group122Var2(seq) => {
+                handler.handle_security_statistic(
+                    self.get_header_info(),
+                    &mut seq.iter().map(|x| (x.value.into(), x.index.widen_to_u16()))
+                );
+                true
+            }
         }
     }
     
@@ -731,7 +789,13 @@ impl<'a, I> PrefixedVariation<'a, I> where I : FixedSize + Index + std::fmt::Dis
             PrefixedVariation::Group43Var6(_) => HeaderInfo::new(Variation::Group43Var6, I::COUNT_AND_PREFIX_QUALIFIER, true, false),
             PrefixedVariation::Group43Var7(_) => HeaderInfo::new(Variation::Group43Var7, I::COUNT_AND_PREFIX_QUALIFIER, true, false),
             PrefixedVariation::Group43Var8(_) => HeaderInfo::new(Variation::Group43Var8, I::COUNT_AND_PREFIX_QUALIFIER, true, false),
+            PrefixedVariation::Group50Var4(_) => HeaderInfo::new(Variation::Group50Var4, I::COUNT_AND_PREFIX_QUALIFIER, false, false),
+            PrefixedVariation::Group110VarX(x, _) =>  HeaderInfo::new(Variation::Group110(*x), I::COUNT_AND_PREFIX_QUALIFIER, false, false),
             PrefixedVariation::Group111VarX(x, _) =>  HeaderInfo::new(Variation::Group111(*x), I::COUNT_AND_PREFIX_QUALIFIER, true, false),
+            PrefixedVariation::Group112VarX(x, _) =>  HeaderInfo::new(Variation::Group112(*x), I::COUNT_AND_PREFIX_QUALIFIER, false, false),
+            PrefixedVariation::Group113VarX(x, _) =>  HeaderInfo::new(Variation::Group113(*x), I::COUNT_AND_PREFIX_QUALIFIER, true, false),
+            PrefixedVariation::Group122Var1(_) => HeaderInfo::new(Variation::Group122Var1, I::COUNT_AND_PREFIX_QUALIFIER, true, true),
+            PrefixedVariation::Group122Var2(_) => HeaderInfo::new(Variation::Group122Var2, I::COUNT_AND_PREFIX_QUALIFIER, true, true),
         }
     }
 }
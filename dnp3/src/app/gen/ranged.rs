@@ -47,6 +47,8 @@ pub(crate) enum RangedVariation<'a> {
     Group10Var1(BitSequence<'a>),
     /// Binary Output - Output Status With Flags
     Group10Var2(RangedSequence<'a, Group10Var2>),
+    /// Binary Command - Pattern Mask
+    Group12Var3(BitSequence<'a>),
     /// Counter - Any Variation
     Group20Var0,
     /// Counter - 32-bit With Flag
@@ -128,6 +130,10 @@ pub(crate) enum RangedVariation<'a> {
     /// Octet String - Sized by variation
     Group110Var0,
     Group110VarX(u8, RangedBytesSequence<'a>),
+    /// Security Statistic - Any Variation
+    Group121Var0,
+    /// Security Statistic - 32-bit With Flag
+    Group121Var1(RangedSequence<'a, Group121Var1>),
 }
 
 impl<'a> RangedVariation<'a> {
@@ -144,6 +150,7 @@ impl<'a> RangedVariation<'a> {
             Variation::Group10Var0 => Ok(RangedVariation::Group10Var0),
             Variation::Group10Var1 => Ok(RangedVariation::Group10Var1(BitSequence::parse(range, cursor)?)),
             Variation::Group10Var2 => Ok(RangedVariation::Group10Var2(RangedSequence::parse(range, cursor)?)),
+            Variation::Group12Var3 => Ok(RangedVariation::Group12Var3(BitSequence::parse(range, cursor)?)),
             Variation::Group20Var0 => Ok(RangedVariation::Group20Var0),
             Variation::Group20Var1 => Ok(RangedVariation::Group20Var1(RangedSequence::parse(range, cursor)?)),
             Variation::Group20Var2 => Ok(RangedVariation::Group20Var2(RangedSequence::parse(range, cursor)?)),
@@ -186,6 +193,8 @@ impl<'a> RangedVariation<'a> {
             Variation::Group110(x) => {
                 Ok(RangedVariation::Group110VarX(x, RangedBytesSequence::parse(options, x, range.get_start(), range.get_count(), cursor)?))
             },
+            Variation::Group121Var0 => Ok(RangedVariation::Group121Var0),
+            Variation::Group121Var1 => Ok(RangedVariation::Group121Var1(RangedSequence::parse(range, cursor)?)),
             _ => Err(ObjectParseError::InvalidQualifierForVariation(v, qualifier)),
         }
     }
@@ -243,6 +252,8 @@ impl<'a> RangedVariation<'a> {
             Variation::Group102Var0 => Ok(RangedVariation::Group102Var0),
             Variation::Group102Var1 => Ok(RangedVariation::Group102Var1(RangedSequence::empty())),
             Variation::Group110(0) => Ok(RangedVariation::Group110Var0),
+            Variation::Group121Var0 => Ok(RangedVariation::Group121Var0),
+            Variation::Group121Var1 => Ok(RangedVariation::Group121Var1(RangedSequence::empty())),
             _ => Err(ObjectParseError::InvalidQualifierForVariation(v, qualifier)),
         }
     }
@@ -260,6 +271,7 @@ impl<'a> RangedVariation<'a> {
             RangedVariation::Group10Var0 => Ok(()),
             RangedVariation::Group10Var1(seq) => format_indexed_items(f, seq.iter()),
             RangedVariation::Group10Var2(seq) => format_indexed_items(f, seq.iter()),
+            RangedVariation::Group12Var3(seq) => format_indexed_items(f, seq.iter()),
             RangedVariation::Group20Var0 => Ok(()),
             RangedVariation::Group20Var1(seq) => format_indexed_items(f, seq.iter()),
             RangedVariation::Group20Var2(seq) => format_indexed_items(f, seq.iter()),
@@ -301,6 +313,8 @@ impl<'a> RangedVariation<'a> {
             RangedVariation::Group102Var1(seq) => format_indexed_items(f, seq.iter()),
             RangedVariation::Group110Var0 => Ok(()),
             RangedVariation::Group110VarX(_, seq) => format_indexed_items(f, seq.iter().map(|(x, i)| (Bytes::new(x), i))),
+            RangedVariation::Group121Var0 => Ok(()),
+            RangedVariation::Group121Var1(seq) => format_indexed_items(f, seq.iter()),
         }
     }
     
@@ -364,6 +378,9 @@ impl<'a> RangedVariation<'a> {
                 );
                 true
             }
+            RangedVariation::Group12Var3(_) => {
+                false // pattern mask
+            }
             RangedVariation::Group20Var0 => {
                 false // extraction not supported
             }
@@ -619,6 +636,16 @@ impl<'a> RangedVariation<'a> {
                 );
                 true
             }
+            RangedVariation::Group121Var0 => {
+                false // extraction not supported
+            }
+            RangedVariation::Group121Var1(seq) => {
+                handler.handle_security_statistic(
+                    HeaderInfo::new(var, qualifier, false, true),
+                    &mut seq.iter().map(|(v,i)| (v.into(), i))
+                );
+                true
+            }
         }
     }
 }
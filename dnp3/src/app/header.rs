@@ -3,7 +3,7 @@ use std::ops::{Add, BitOr, BitOrAssign};
 
 use crate::app::sequence::Sequence;
 use crate::app::FunctionCode;
-use crate::outstation::{ApplicationIin, RequestError};
+use crate::outstation::{ApplicationControlError, ApplicationIin, RequestError};
 use crate::util::bit::bits::*;
 use crate::util::bit::{format_bitfield, Bitfield};
 
@@ -422,6 +422,17 @@ impl From<RequestError> for Iin2 {
     }
 }
 
+impl From<ApplicationControlError> for Iin2 {
+    fn from(from: ApplicationControlError) -> Self {
+        match from {
+            ApplicationControlError::NotSupported => Iin2::NO_FUNC_CODE_SUPPORT,
+            ApplicationControlError::UnknownApplication => Iin2::OBJECT_UNKNOWN,
+            ApplicationControlError::ParameterError => Iin2::PARAMETER_ERROR,
+            ApplicationControlError::AlreadyExecuting => Iin2::ALREADY_EXECUTING,
+        }
+    }
+}
+
 impl Add<Iin2> for Iin1 {
     type Output = Iin;
 
@@ -505,13 +516,15 @@ pub struct RequestHeader {
     pub function: FunctionCode,
 }
 
-/// Only 2 function codes allowed in responses
+/// Function codes allowed in responses
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum ResponseFunction {
     /// (solicited) response (0x81)
     Response,
     /// unsolicited response (0x82)
     UnsolicitedResponse,
+    /// authentication response (0x83)
+    AuthResponse,
 }
 
 /// Application-layer header for responses
@@ -519,7 +532,7 @@ pub enum ResponseFunction {
 pub struct ResponseHeader {
     /// control field
     pub control: ControlField,
-    /// Function code limited to Response or UnsolicitedResponse
+    /// Function code limited to Response, UnsolicitedResponse, or AuthResponse
     pub function: ResponseFunction,
     /// internal indications field
     pub iin: Iin,
@@ -531,6 +544,7 @@ impl ResponseFunction {
         match self {
             ResponseFunction::Response => false,
             ResponseFunction::UnsolicitedResponse => true,
+            ResponseFunction::AuthResponse => false,
         }
     }
 
@@ -539,6 +553,7 @@ impl ResponseFunction {
         match self {
             ResponseFunction::Response => FunctionCode::Response,
             ResponseFunction::UnsolicitedResponse => FunctionCode::UnsolicitedResponse,
+            ResponseFunction::AuthResponse => FunctionCode::AuthResponse,
         }
     }
 }
@@ -548,11 +563,14 @@ impl From<ResponseFunction> for FunctionCode {
         match from {
             ResponseFunction::Response => FunctionCode::Response,
             ResponseFunction::UnsolicitedResponse => FunctionCode::UnsolicitedResponse,
+            ResponseFunction::AuthResponse => FunctionCode::AuthResponse,
         }
     }
 }
 
 impl RequestHeader {
+    pub(crate) const LENGTH: usize = 2;
+
     pub(crate) fn new(control: ControlField, function: FunctionCode) -> Self {
         Self { control, function }
     }
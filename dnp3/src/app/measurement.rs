@@ -3,8 +3,9 @@ use std::time::Duration;
 
 use crate::app::types::Timestamp;
 use crate::app::variations::{
-    Group102Var1, Group13Var1, Group13Var2, Group34Var1, Group34Var2, Group34Var3, Group43Var1,
-    Group43Var2, Group43Var3, Group43Var4, Group43Var5, Group43Var6, Group43Var7, Group43Var8,
+    Group102Var1, Group121Var1, Group122Var1, Group122Var2, Group13Var1, Group13Var2, Group34Var1,
+    Group34Var2, Group34Var3, Group43Var1, Group43Var2, Group43Var3, Group43Var4, Group43Var5,
+    Group43Var6, Group43Var7, Group43Var8,
 };
 use crate::util::bit::bits;
 use crate::util::bit::BitMask;
@@ -12,6 +13,10 @@ use crate::util::bit::Bitfield;
 
 /// Enumeration modeling two stables states and an in-transit state
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum DoubleBit {
     /// Transitioning between end conditions
     Intermediate,
@@ -25,6 +30,10 @@ pub enum DoubleBit {
 
 /// A DNP3 time value that may be Synchronized or NotSynchronized
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum Time {
     /// The timestamp is UTC synchronized at the remote device
     Synchronized(Timestamp),
@@ -55,6 +64,10 @@ impl Time {
 /// should refer to the standard to determine what flag values
 /// correspond to each type.
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct Flags {
     /// underlying bitmask
     pub value: u8,
@@ -136,6 +149,10 @@ impl Time {
 
 /// Measurement type corresponding to groups 1 and 2
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct BinaryInput {
     /// value of the type
     pub value: bool,
@@ -158,6 +175,10 @@ impl BinaryInput {
 
 /// Measurement type corresponding to groups 3 and 4
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct DoubleBitBinaryInput {
     /// value of the type
     pub value: DoubleBit,
@@ -180,6 +201,10 @@ impl DoubleBitBinaryInput {
 
 /// Measurement type corresponding to groups 10 and 11
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct BinaryOutputStatus {
     /// value of the type
     pub value: bool,
@@ -202,6 +227,10 @@ impl BinaryOutputStatus {
 
 /// Measurement type corresponding to groups 20 and 22
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct Counter {
     /// value of the type
     pub value: u32,
@@ -224,6 +253,10 @@ impl Counter {
 
 /// Measurement type corresponding to groups 21 and 23
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct FrozenCounter {
     /// value of the type
     pub value: u32,
@@ -246,6 +279,10 @@ impl FrozenCounter {
 
 /// Measurement type corresponding to groups 30 and 32
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct AnalogInput {
     /// value of the type
     pub value: f64,
@@ -316,6 +353,11 @@ impl BinaryOutputCommandEvent {
         let status = CommandStatus::from(value & 0b0111_1111);
         (state, status)
     }
+
+    fn get_wire_flags(&self) -> u8 {
+        let state = if self.commanded_state { 0b1000_0000 } else { 0 };
+        state | (self.status.as_u8() & 0b0111_1111)
+    }
 }
 
 /// Corresponds to the different ways in which an analog output value
@@ -332,6 +374,46 @@ pub enum AnalogCommandValue {
     F64(f64),
 }
 
+impl AnalogCommandValue {
+    pub(crate) fn to_i16(self) -> i16 {
+        match self {
+            Self::I16(x) => x,
+            Self::I32(x) => x.clamp(i16::MIN.into(), i16::MAX.into()) as i16,
+            // float to integer conversions saturate
+            Self::F32(x) => x as i16,
+            Self::F64(x) => x as i16,
+        }
+    }
+
+    pub(crate) fn to_i32(self) -> i32 {
+        match self {
+            Self::I16(x) => x.into(),
+            Self::I32(x) => x,
+            // float to integer conversions saturate
+            Self::F32(x) => x as i32,
+            Self::F64(x) => x as i32,
+        }
+    }
+
+    pub(crate) fn to_f32(self) -> f32 {
+        match self {
+            Self::I16(x) => x.into(),
+            Self::I32(x) => x as f32,
+            Self::F32(x) => x,
+            Self::F64(x) => x.clamp(f32::MIN.into(), f32::MAX.into()) as f32,
+        }
+    }
+
+    pub(crate) fn to_f64(self) -> f64 {
+        match self {
+            Self::I16(x) => x.into(),
+            Self::I32(x) => x.into(),
+            Self::F32(x) => x.into(),
+            Self::F64(x) => x,
+        }
+    }
+}
+
 /// Event transferred from master to outstation when the outstation receives an analog output.
 /// The primary use case of these objects are that they allow one master see that commands
 /// were issued to an outstation from another master.
@@ -362,11 +444,54 @@ pub enum AnalogInputDeadBand {
 
 /// Type corresponding to g102v1
 #[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct UnsignedInteger {
     /// Value of the type
     pub value: u8,
 }
 
+impl UnsignedInteger {
+    /// construct an `UnsignedInteger` from its value
+    pub const fn new(value: u8) -> Self {
+        Self { value }
+    }
+}
+
+/// Measurement type corresponding to groups 121 and 122
+///
+/// Security statistics are maintained per association by the
+/// secure authentication layer of the outstation
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct SecurityStatistic {
+    /// association to which the statistic applies
+    pub association_id: u16,
+    /// value of the statistic
+    pub value: u32,
+    /// associated flags
+    pub flags: Flags,
+    /// associated time
+    pub time: Option<Time>,
+}
+
+impl SecurityStatistic {
+    /// construct a `SecurityStatistic` from its fields
+    pub const fn new(association_id: u16, value: u32, flags: Flags, time: Time) -> Self {
+        Self {
+            association_id,
+            value,
+            flags,
+            time: Some(time),
+        }
+    }
+}
+
 impl From<Group34Var1> for AnalogInputDeadBand {
     fn from(value: Group34Var1) -> Self {
         Self::U16(value.value)
@@ -487,12 +612,175 @@ impl From<Group43Var8> for AnalogOutputCommandEvent {
     }
 }
 
+impl ToVariation<Group13Var1> for BinaryOutputCommandEvent {
+    fn to_variation(&self) -> Group13Var1 {
+        Group13Var1 {
+            flags: self.get_wire_flags(),
+        }
+    }
+}
+
+impl ToVariation<Group13Var2> for BinaryOutputCommandEvent {
+    fn to_variation(&self) -> Group13Var2 {
+        Group13Var2 {
+            flags: self.get_wire_flags(),
+            time: self.time.into(),
+        }
+    }
+}
+
+impl ToVariation<Group43Var1> for AnalogOutputCommandEvent {
+    fn to_variation(&self) -> Group43Var1 {
+        Group43Var1 {
+            status: self.status,
+            value: self.commanded_value.to_i32(),
+        }
+    }
+}
+
+impl ToVariation<Group43Var2> for AnalogOutputCommandEvent {
+    fn to_variation(&self) -> Group43Var2 {
+        Group43Var2 {
+            status: self.status,
+            value: self.commanded_value.to_i16(),
+        }
+    }
+}
+
+impl ToVariation<Group43Var3> for AnalogOutputCommandEvent {
+    fn to_variation(&self) -> Group43Var3 {
+        Group43Var3 {
+            status: self.status,
+            value: self.commanded_value.to_i32(),
+            time: self.time.into(),
+        }
+    }
+}
+
+impl ToVariation<Group43Var4> for AnalogOutputCommandEvent {
+    fn to_variation(&self) -> Group43Var4 {
+        Group43Var4 {
+            status: self.status,
+            value: self.commanded_value.to_i16(),
+            time: self.time.into(),
+        }
+    }
+}
+
+impl ToVariation<Group43Var5> for AnalogOutputCommandEvent {
+    fn to_variation(&self) -> Group43Var5 {
+        Group43Var5 {
+            status: self.status,
+            value: self.commanded_value.to_f32(),
+        }
+    }
+}
+
+impl ToVariation<Group43Var6> for AnalogOutputCommandEvent {
+    fn to_variation(&self) -> Group43Var6 {
+        Group43Var6 {
+            status: self.status,
+            value: self.commanded_value.to_f64(),
+        }
+    }
+}
+
+impl ToVariation<Group43Var7> for AnalogOutputCommandEvent {
+    fn to_variation(&self) -> Group43Var7 {
+        Group43Var7 {
+            status: self.status,
+            value: self.commanded_value.to_f32(),
+            time: self.time.into(),
+        }
+    }
+}
+
+impl ToVariation<Group43Var8> for AnalogOutputCommandEvent {
+    fn to_variation(&self) -> Group43Var8 {
+        Group43Var8 {
+            status: self.status,
+            value: self.commanded_value.to_f64(),
+            time: self.time.into(),
+        }
+    }
+}
+
 impl From<Group102Var1> for UnsignedInteger {
     fn from(obj: Group102Var1) -> Self {
         Self { value: obj.value }
     }
 }
 
+impl ToVariation<Group102Var1> for UnsignedInteger {
+    fn to_variation(&self) -> Group102Var1 {
+        Group102Var1 { value: self.value }
+    }
+}
+
+impl From<Group121Var1> for SecurityStatistic {
+    fn from(v: Group121Var1) -> Self {
+        Self {
+            association_id: v.association_id,
+            value: v.value,
+            flags: Flags::new(v.flags),
+            time: None,
+        }
+    }
+}
+
+impl ToVariation<Group121Var1> for SecurityStatistic {
+    fn to_variation(&self) -> Group121Var1 {
+        Group121Var1 {
+            flags: self.flags.value,
+            association_id: self.association_id,
+            value: self.value,
+        }
+    }
+}
+
+impl From<Group122Var1> for SecurityStatistic {
+    fn from(v: Group122Var1) -> Self {
+        Self {
+            association_id: v.association_id,
+            value: v.value,
+            flags: Flags::new(v.flags),
+            time: None,
+        }
+    }
+}
+
+impl ToVariation<Group122Var1> for SecurityStatistic {
+    fn to_variation(&self) -> Group122Var1 {
+        Group122Var1 {
+            flags: self.flags.value,
+            association_id: self.association_id,
+            value: self.value,
+        }
+    }
+}
+
+impl From<Group122Var2> for SecurityStatistic {
+    fn from(v: Group122Var2) -> Self {
+        Self {
+            association_id: v.association_id,
+            value: v.value,
+            flags: Flags::new(v.flags),
+            time: Some(Time::Synchronized(v.time)),
+        }
+    }
+}
+
+impl ToVariation<Group122Var2> for SecurityStatistic {
+    fn to_variation(&self) -> Group122Var2 {
+        Group122Var2 {
+            flags: self.flags.value,
+            association_id: self.association_id,
+            value: self.value,
+            time: self.time.into(),
+        }
+    }
+}
+
 impl std::ops::BitOr<Flags> for Flags {
     type Output = Flags;
 
@@ -775,6 +1063,10 @@ pub(crate) trait AnalogConversions {
 
 /// Measurement type corresponding to groups 40 and 42
 #[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct AnalogOutputStatus {
     /// value of the type
     pub value: f64,
@@ -804,6 +1096,11 @@ impl AnalogOutputStatus {
 /// C-style string.
 #[allow(missing_copy_implementations)]
 #[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "Vec<u8>", into = "Vec<u8>")
+)]
 pub struct OctetString {
     value: [u8; Self::MAX_SIZE],
     len: u8,
@@ -850,6 +1147,20 @@ impl OctetString {
     }
 }
 
+impl From<OctetString> for Vec<u8> {
+    fn from(value: OctetString) -> Self {
+        value.value().to_vec()
+    }
+}
+
+impl TryFrom<Vec<u8>> for OctetString {
+    type Error = OctetStringLengthError;
+
+    fn try_from(value: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::new(&value)
+    }
+}
+
 /// Errors when creating an octet string
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum OctetStringLengthError {
@@ -861,6 +1172,15 @@ pub enum OctetStringLengthError {
     MoreThan255Octets,
 }
 
+impl std::fmt::Display for OctetStringLengthError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ZeroLength => f.write_str("octet string has a length of zero"),
+            Self::MoreThan255Octets => f.write_str("octet string is longer than 255 octets"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -15,8 +15,10 @@ pub use variations::Variation;
 /// Types used for making binary and analog output control requests
 pub mod control {
     pub use super::control_enums::*;
-    pub use super::control_types::ControlCode;
-    pub use super::variations::{Group12Var1, Group41Var1, Group41Var2, Group41Var3, Group41Var4};
+    pub use super::control_types::{ControlCode, PatternControl};
+    pub use super::variations::{
+        Group12Var1, Group12Var2, Group41Var1, Group41Var2, Group41Var3, Group41Var4,
+    };
 }
 
 /// internal enum used all over the place to specify master or outstation
@@ -32,13 +34,19 @@ impl EndpointType {
     }
 }
 
+pub(crate) mod activation;
+pub(crate) mod app_id;
 /// Types association with Device Attributes (Group 0)
 pub mod attr;
+/// Types associated with secure authentication (Group 120)
+pub mod auth;
 mod control_types;
 #[rustfmt::skip]
 mod app_enums;
 mod buffer_size;
 mod control_enums;
+/// Types associated with data sets (Groups 85, 86, 87, and 88)
+pub mod data_set;
 mod extensions;
 
 pub(crate) mod file;
@@ -1,3 +1,9 @@
+use crate::app::app_id::Group90Var1;
+use crate::app::auth::{
+    Group120Var1, Group120Var10, Group120Var11, Group120Var12, Group120Var13, Group120Var14,
+    Group120Var15, Group120Var2, Group120Var5, Group120Var6, Group120Var7, Group120Var9,
+};
+use crate::app::data_set::{Group85Var1, Group86Var1, Group87Var1, Group88Var1};
 use crate::app::{file, ObjectParseError, QualifierCode, Variation};
 use scursor::ReadCursor;
 
@@ -10,6 +16,23 @@ pub(crate) enum FreeFormatVariation<'a> {
     Group70Var6(file::Group70Var6<'a>),
     Group70Var7(file::Group70Var7<'a>),
     Group70Var8(file::Group70Var8<'a>),
+    Group85Var1(Group85Var1<'a>),
+    Group86Var1(Group86Var1<'a>),
+    Group87Var1(Group87Var1<'a>),
+    Group88Var1(Group88Var1<'a>),
+    Group90Var1(Group90Var1<'a>),
+    Group120Var1(Group120Var1<'a>),
+    Group120Var2(Group120Var2<'a>),
+    Group120Var5(Group120Var5<'a>),
+    Group120Var6(Group120Var6<'a>),
+    Group120Var7(Group120Var7<'a>),
+    Group120Var9(Group120Var9<'a>),
+    Group120Var10(Group120Var10<'a>),
+    Group120Var11(Group120Var11<'a>),
+    Group120Var12(Group120Var12<'a>),
+    Group120Var13(Group120Var13<'a>),
+    Group120Var14(Group120Var14<'a>),
+    Group120Var15(Group120Var15<'a>),
 }
 
 impl<'a> FreeFormatVariation<'a> {
@@ -39,6 +62,47 @@ impl<'a> FreeFormatVariation<'a> {
             Variation::Group70Var8 => {
                 FreeFormatVariation::Group70Var8(file::Group70Var8::read(cursor)?)
             }
+            Variation::Group85Var1 => FreeFormatVariation::Group85Var1(Group85Var1::read(cursor)?),
+            Variation::Group86Var1 => FreeFormatVariation::Group86Var1(Group86Var1::read(cursor)?),
+            Variation::Group87Var1 => FreeFormatVariation::Group87Var1(Group87Var1::read(cursor)?),
+            Variation::Group88Var1 => FreeFormatVariation::Group88Var1(Group88Var1::read(cursor)?),
+            Variation::Group90Var1 => FreeFormatVariation::Group90Var1(Group90Var1::read(cursor)?),
+            Variation::Group120Var1 => {
+                FreeFormatVariation::Group120Var1(Group120Var1::read(cursor)?)
+            }
+            Variation::Group120Var2 => {
+                FreeFormatVariation::Group120Var2(Group120Var2::read(cursor)?)
+            }
+            Variation::Group120Var5 => {
+                FreeFormatVariation::Group120Var5(Group120Var5::read(cursor)?)
+            }
+            Variation::Group120Var6 => {
+                FreeFormatVariation::Group120Var6(Group120Var6::read(cursor)?)
+            }
+            Variation::Group120Var7 => {
+                FreeFormatVariation::Group120Var7(Group120Var7::read(cursor)?)
+            }
+            Variation::Group120Var9 => {
+                FreeFormatVariation::Group120Var9(Group120Var9::read(cursor)?)
+            }
+            Variation::Group120Var10 => {
+                FreeFormatVariation::Group120Var10(Group120Var10::read(cursor)?)
+            }
+            Variation::Group120Var11 => {
+                FreeFormatVariation::Group120Var11(Group120Var11::read(cursor)?)
+            }
+            Variation::Group120Var12 => {
+                FreeFormatVariation::Group120Var12(Group120Var12::read(cursor)?)
+            }
+            Variation::Group120Var13 => {
+                FreeFormatVariation::Group120Var13(Group120Var13::read(cursor)?)
+            }
+            Variation::Group120Var14 => {
+                FreeFormatVariation::Group120Var14(Group120Var14::read(cursor)?)
+            }
+            Variation::Group120Var15 => {
+                FreeFormatVariation::Group120Var15(Group120Var15::read(cursor)?)
+            }
             _ => {
                 return Err(ObjectParseError::InvalidQualifierForVariation(
                     v,
@@ -59,6 +123,23 @@ impl<'a> FreeFormatVariation<'a> {
             FreeFormatVariation::Group70Var6(x) => x.format(f),
             FreeFormatVariation::Group70Var7(x) => x.format(f),
             FreeFormatVariation::Group70Var8(x) => x.format(f),
+            FreeFormatVariation::Group85Var1(x) => x.format(f),
+            FreeFormatVariation::Group86Var1(x) => x.format(f),
+            FreeFormatVariation::Group87Var1(x) => x.format(f),
+            FreeFormatVariation::Group88Var1(x) => x.format(f),
+            FreeFormatVariation::Group90Var1(x) => x.format(f),
+            FreeFormatVariation::Group120Var1(x) => x.format(f),
+            FreeFormatVariation::Group120Var2(x) => x.format(f),
+            FreeFormatVariation::Group120Var5(x) => x.format(f),
+            FreeFormatVariation::Group120Var6(x) => x.format(f),
+            FreeFormatVariation::Group120Var7(x) => x.format(f),
+            FreeFormatVariation::Group120Var9(x) => x.format(f),
+            FreeFormatVariation::Group120Var10(x) => x.format(f),
+            FreeFormatVariation::Group120Var11(x) => x.format(f),
+            FreeFormatVariation::Group120Var12(x) => x.format(f),
+            FreeFormatVariation::Group120Var13(x) => x.format(f),
+            FreeFormatVariation::Group120Var14(x) => x.format(f),
+            FreeFormatVariation::Group120Var15(x) => x.format(f),
         }
     }
 }
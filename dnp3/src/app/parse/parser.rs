@@ -86,6 +86,7 @@ impl<'a> ParsedFragment<'a> {
             (FunctionCode::UnsolicitedResponse, Some(x)) => {
                 (ResponseFunction::UnsolicitedResponse, x)
             }
+            (FunctionCode::AuthResponse, Some(x)) => (ResponseFunction::AuthResponse, x),
             _ => return Err(ResponseValidationError::UnexpectedFunction(self.function)),
         };
 
@@ -103,6 +104,7 @@ impl<'a> ParsedFragment<'a> {
 
         Ok(Response {
             header: ResponseHeader::new(self.control, function, iin),
+            raw_fragment: self.raw_fragment,
             raw_objects: self.raw_objects,
             objects: self.objects,
         })
@@ -123,6 +125,7 @@ impl<'a> ParsedFragment<'a> {
         let iin = match function {
             FunctionCode::Response => Some(Iin::parse(&mut cursor)?),
             FunctionCode::UnsolicitedResponse => Some(Iin::parse(&mut cursor)?),
+            FunctionCode::AuthResponse => Some(Iin::parse(&mut cursor)?),
             _ => None,
         };
 
@@ -356,6 +359,7 @@ pub(crate) struct Request<'a> {
 #[derive(Copy, Clone, Debug)]
 pub(crate) struct Response<'a> {
     pub(crate) header: ResponseHeader,
+    pub(crate) raw_fragment: &'a [u8],
     pub(crate) raw_objects: &'a [u8],
     pub(crate) objects: Result<HeaderCollection<'a>, ObjectParseError>,
 }
@@ -406,6 +410,10 @@ impl<'a> HeaderCollection<'a> {
         ObjectParser::parse(options, function, data)
     }
 
+    pub(crate) fn options(&self) -> ParseOptions {
+        self.options
+    }
+
     pub(crate) fn is_empty(&self) -> bool {
         self.data.is_empty()
     }
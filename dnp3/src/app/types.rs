@@ -9,6 +9,11 @@ use scursor::{WriteCursor, WriteError};
 
 /// Wrapper around a u64 count of milliseconds since Unix epoch UTC
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "u64", into = "u64")
+)]
 pub struct Timestamp {
     value: u64,
 }
@@ -84,6 +89,43 @@ impl Timestamp {
     }
 }
 
+impl From<Timestamp> for u64 {
+    fn from(value: Timestamp) -> Self {
+        value.value
+    }
+}
+
+impl TryFrom<u64> for Timestamp {
+    type Error = TimestampRangeError;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        if value > Self::MAX_VALUE {
+            return Err(TimestampRangeError { value });
+        }
+        Ok(Self::new(value))
+    }
+}
+
+/// The value exceeds [`Timestamp::MAX_VALUE`] and cannot be represented in 48-bits
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TimestampRangeError {
+    /// value that is out of range
+    pub value: u64,
+}
+
+impl std::error::Error for TimestampRangeError {}
+
+impl std::fmt::Display for TimestampRangeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "timestamp ({}) exceeds the maximum 48-bit value ({})",
+            self.value,
+            Timestamp::MAX_VALUE
+        )
+    }
+}
+
 impl std::fmt::Display for Timestamp {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self.to_datetime_utc() {
@@ -165,6 +207,31 @@ impl QualifierCode {
 mod test {
     use super::*;
 
+    #[test]
+    fn conversion_from_u64_rejects_values_larger_than_48_bits() {
+        assert_eq!(
+            Timestamp::try_from(Timestamp::MAX_VALUE),
+            Ok(Timestamp::max())
+        );
+        assert_eq!(
+            Timestamp::try_from(Timestamp::MAX_VALUE + 1),
+            Err(TimestampRangeError {
+                value: Timestamp::MAX_VALUE + 1
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serialization")]
+    fn deserialization_rejects_values_larger_than_48_bits() {
+        assert!(serde_json::from_str::<Timestamp>("281474976710656").is_err());
+        assert_eq!(
+            serde_json::from_str::<Timestamp>("281474976710655").unwrap(),
+            Timestamp::max()
+        );
+        assert_eq!(serde_json::to_string(&Timestamp::new(42)).unwrap(), "42");
+    }
+
     #[test]
     fn conversion_from_timestamp_to_datetime_utc_cannot_overflow() {
         let timestamp = Timestamp::new(u64::MAX);
@@ -66,6 +66,12 @@ pub enum Variation {
     Group11Var2,
     /// Binary Command - Control Relay Output Block
     Group12Var1,
+    /// Binary Command - Pattern Control Block
+    Group12Var2,
+    /// Binary Command - Pattern Mask
+    Group12Var3,
+    /// Binary Output Command Event - Any Variation
+    Group13Var0,
     /// Binary Output Command Event - Without Time
     Group13Var1,
     /// Binary Output Command Event - With Time
@@ -226,6 +232,8 @@ pub enum Variation {
     Group42Var7,
     /// Analog Output Event - Double-precision With Flag and Time
     Group42Var8,
+    /// Analog Output Command Event - Any Variation
+    Group43Var0,
     /// Analog Output Command Event - 32-bit
     Group43Var1,
     /// Analog Output Command Event - 16-bit
@@ -282,6 +290,18 @@ pub enum Variation {
     Group70Var8,
     /// Internal Indications - Packed Format
     Group80Var1,
+    /// Data-Set Prototype - With UUID
+    Group85Var1,
+    /// Data-Set Descriptor - Data-Set Contents
+    Group86Var1,
+    /// Data-Set - Present Value
+    Group87Var1,
+    /// Data-Set Event - Snapshot
+    Group88Var1,
+    /// Application Identifier - Application Identifier
+    Group90Var1,
+    /// Activate Configuration - Status of Requested Operations
+    Group91Var1,
     /// Unsigned Integer - Any Variation
     Group102Var0,
     /// Unsigned Integer - 8-bit
@@ -290,6 +310,48 @@ pub enum Variation {
     Group110(u8),
     /// Octet String Event - Sized by variation
     Group111(u8),
+    /// Virtual Terminal Output Block - Sized by variation
+    Group112(u8),
+    /// Virtual Terminal Event Data - Sized by variation
+    Group113(u8),
+    /// Authentication - challenge
+    Group120Var1,
+    /// Authentication - reply
+    Group120Var2,
+    /// Authentication - aggressive mode request
+    Group120Var3,
+    /// Authentication - session key status request
+    Group120Var4,
+    /// Authentication - session key status
+    Group120Var5,
+    /// Authentication - session key change
+    Group120Var6,
+    /// Authentication - error
+    Group120Var7,
+    /// Authentication - HMAC
+    Group120Var9,
+    /// Authentication - user status change
+    Group120Var10,
+    /// Authentication - update key change request
+    Group120Var11,
+    /// Authentication - update key change reply
+    Group120Var12,
+    /// Authentication - update key change
+    Group120Var13,
+    /// Authentication - update key change signature
+    Group120Var14,
+    /// Authentication - update key change confirmation
+    Group120Var15,
+    /// Security Statistic - Any Variation
+    Group121Var0,
+    /// Security Statistic - 32-bit With Flag
+    Group121Var1,
+    /// Security Statistic Event - Any Variation
+    Group122Var0,
+    /// Security Statistic Event - 32-bit With Flag
+    Group122Var1,
+    /// Security Statistic Event - 32-bit With Flag and Time
+    Group122Var2,
 }
 
 impl Variation {
@@ -340,9 +402,12 @@ impl Variation {
             },
             12 => match var {
                 1 => Some(Variation::Group12Var1),
+                2 => Some(Variation::Group12Var2),
+                3 => Some(Variation::Group12Var3),
                 _ => None,
             },
             13 => match var {
+                0 => Some(Variation::Group13Var0),
                 1 => Some(Variation::Group13Var1),
                 2 => Some(Variation::Group13Var2),
                 _ => None,
@@ -462,6 +527,7 @@ impl Variation {
                 _ => None,
             },
             43 => match var {
+                0 => Some(Variation::Group43Var0),
                 1 => Some(Variation::Group43Var1),
                 2 => Some(Variation::Group43Var2),
                 3 => Some(Variation::Group43Var3),
@@ -510,6 +576,30 @@ impl Variation {
                 1 => Some(Variation::Group80Var1),
                 _ => None,
             },
+            85 => match var {
+                1 => Some(Variation::Group85Var1),
+                _ => None,
+            },
+            86 => match var {
+                1 => Some(Variation::Group86Var1),
+                _ => None,
+            },
+            87 => match var {
+                1 => Some(Variation::Group87Var1),
+                _ => None,
+            },
+            88 => match var {
+                1 => Some(Variation::Group88Var1),
+                _ => None,
+            },
+            90 => match var {
+                1 => Some(Variation::Group90Var1),
+                _ => None,
+            },
+            91 => match var {
+                1 => Some(Variation::Group91Var1),
+                _ => None,
+            },
             102 => match var {
                 0 => Some(Variation::Group102Var0),
                 1 => Some(Variation::Group102Var1),
@@ -517,6 +607,36 @@ impl Variation {
             },
             110 => Some(Variation::Group110(var)),
             111 => Some(Variation::Group111(var)),
+            112 => Some(Variation::Group112(var)),
+            113 => Some(Variation::Group113(var)),
+            120 => match var {
+                1 => Some(Variation::Group120Var1),
+                2 => Some(Variation::Group120Var2),
+                3 => Some(Variation::Group120Var3),
+                4 => Some(Variation::Group120Var4),
+                5 => Some(Variation::Group120Var5),
+                6 => Some(Variation::Group120Var6),
+                7 => Some(Variation::Group120Var7),
+                9 => Some(Variation::Group120Var9),
+                10 => Some(Variation::Group120Var10),
+                11 => Some(Variation::Group120Var11),
+                12 => Some(Variation::Group120Var12),
+                13 => Some(Variation::Group120Var13),
+                14 => Some(Variation::Group120Var14),
+                15 => Some(Variation::Group120Var15),
+                _ => None,
+            },
+            121 => match var {
+                0 => Some(Variation::Group121Var0),
+                1 => Some(Variation::Group121Var1),
+                _ => None,
+            },
+            122 => match var {
+                0 => Some(Variation::Group122Var0),
+                1 => Some(Variation::Group122Var1),
+                2 => Some(Variation::Group122Var2),
+                _ => None,
+            },
             _ => None,
         }
     }
@@ -546,6 +666,9 @@ impl Variation {
             Variation::Group11Var1 => (11, 1),
             Variation::Group11Var2 => (11, 2),
             Variation::Group12Var1 => (12, 1),
+            Variation::Group12Var2 => (12, 2),
+            Variation::Group12Var3 => (12, 3),
+            Variation::Group13Var0 => (13, 0),
             Variation::Group13Var1 => (13, 1),
             Variation::Group13Var2 => (13, 2),
             Variation::Group20Var0 => (20, 0),
@@ -626,6 +749,7 @@ impl Variation {
             Variation::Group42Var6 => (42, 6),
             Variation::Group42Var7 => (42, 7),
             Variation::Group42Var8 => (42, 8),
+            Variation::Group43Var0 => (43, 0),
             Variation::Group43Var1 => (43, 1),
             Variation::Group43Var2 => (43, 2),
             Variation::Group43Var3 => (43, 3),
@@ -654,10 +778,37 @@ impl Variation {
             Variation::Group70Var7 => (70, 7),
             Variation::Group70Var8 => (70, 8),
             Variation::Group80Var1 => (80, 1),
+            Variation::Group85Var1 => (85, 1),
+            Variation::Group86Var1 => (86, 1),
+            Variation::Group87Var1 => (87, 1),
+            Variation::Group88Var1 => (88, 1),
+            Variation::Group90Var1 => (90, 1),
+            Variation::Group91Var1 => (91, 1),
             Variation::Group102Var0 => (102, 0),
             Variation::Group102Var1 => (102, 1),
             Variation::Group110(x) => (110, x),
             Variation::Group111(x) => (111, x),
+            Variation::Group112(x) => (112, x),
+            Variation::Group113(x) => (113, x),
+            Variation::Group120Var1 => (120, 1),
+            Variation::Group120Var2 => (120, 2),
+            Variation::Group120Var3 => (120, 3),
+            Variation::Group120Var4 => (120, 4),
+            Variation::Group120Var5 => (120, 5),
+            Variation::Group120Var6 => (120, 6),
+            Variation::Group120Var7 => (120, 7),
+            Variation::Group120Var9 => (120, 9),
+            Variation::Group120Var10 => (120, 10),
+            Variation::Group120Var11 => (120, 11),
+            Variation::Group120Var12 => (120, 12),
+            Variation::Group120Var13 => (120, 13),
+            Variation::Group120Var14 => (120, 14),
+            Variation::Group120Var15 => (120, 15),
+            Variation::Group121Var0 => (121, 0),
+            Variation::Group121Var1 => (121, 1),
+            Variation::Group122Var0 => (122, 0),
+            Variation::Group122Var1 => (122, 1),
+            Variation::Group122Var2 => (122, 2),
         }
     }
     
@@ -686,6 +837,9 @@ impl Variation {
             Variation::Group11Var1 => "Binary Output Event - Output Status Without Time",
             Variation::Group11Var2 => "Binary Output Event - Output Status With Time",
             Variation::Group12Var1 => "Binary Command - Control Relay Output Block",
+            Variation::Group12Var2 => "Binary Command - Pattern Control Block",
+            Variation::Group12Var3 => "Binary Command - Pattern Mask",
+            Variation::Group13Var0 => "Binary Output Command Event - Any Variation",
             Variation::Group13Var1 => "Binary Output Command Event - Without Time",
             Variation::Group13Var2 => "Binary Output Command Event - With Time",
             Variation::Group20Var0 => "Counter - Any Variation",
@@ -766,6 +920,7 @@ impl Variation {
             Variation::Group42Var6 => "Analog Output Event - Double-precision With Flag",
             Variation::Group42Var7 => "Analog Output Event - Single-precision With Flag and Time",
             Variation::Group42Var8 => "Analog Output Event - Double-precision With Flag and Time",
+            Variation::Group43Var0 => "Analog Output Command Event - Any Variation",
             Variation::Group43Var1 => "Analog Output Command Event - 32-bit",
             Variation::Group43Var2 => "Analog Output Command Event - 16-bit",
             Variation::Group43Var3 => "Analog Output Command Event - 32-bit With Time",
@@ -794,14 +949,92 @@ impl Variation {
             Variation::Group70Var7 => "File-control - file descriptor",
             Variation::Group70Var8 => "File-control - file specification string",
             Variation::Group80Var1 => "Internal Indications - Packed Format",
+            Variation::Group85Var1 => "Data-Set Prototype - With UUID",
+            Variation::Group86Var1 => "Data-Set Descriptor - Data-Set Contents",
+            Variation::Group87Var1 => "Data-Set - Present Value",
+            Variation::Group88Var1 => "Data-Set Event - Snapshot",
+            Variation::Group90Var1 => "Application Identifier - Application Identifier",
+            Variation::Group91Var1 => "Activate Configuration - Status of Requested Operations",
             Variation::Group102Var0 => "Unsigned Integer - Any Variation",
             Variation::Group102Var1 => "Unsigned Integer - 8-bit",
             Variation::Group110(_) => "Octet String - Sized by variation",
             Variation::Group111(_) => "Octet String Event - Sized by variation",
+            Variation::Group112(_) => "Virtual Terminal Output Block - Sized by variation",
+            Variation::Group113(_) => "Virtual Terminal Event Data - Sized by variation",
+            Variation::Group120Var1 => "Authentication - challenge",
+            Variation::Group120Var2 => "Authentication - reply",
+            Variation::Group120Var3 => "Authentication - aggressive mode request",
+            Variation::Group120Var4 => "Authentication - session key status request",
+            Variation::Group120Var5 => "Authentication - session key status",
+            Variation::Group120Var6 => "Authentication - session key change",
+            Variation::Group120Var7 => "Authentication - error",
+            Variation::Group120Var9 => "Authentication - HMAC",
+            Variation::Group120Var10 => "Authentication - user status change",
+            Variation::Group120Var11 => "Authentication - update key change request",
+            Variation::Group120Var12 => "Authentication - update key change reply",
+            Variation::Group120Var13 => "Authentication - update key change",
+            Variation::Group120Var14 => "Authentication - update key change signature",
+            Variation::Group120Var15 => "Authentication - update key change confirmation",
+            Variation::Group121Var0 => "Security Statistic - Any Variation",
+            Variation::Group121Var1 => "Security Statistic - 32-bit With Flag",
+            Variation::Group122Var0 => "Security Statistic Event - Any Variation",
+            Variation::Group122Var1 => "Security Statistic Event - 32-bit With Flag",
+            Variation::Group122Var2 => "Security Statistic Event - 32-bit With Flag and Time",
         }
     }
 }
 
+/// Authentication - session key status request
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group120Var4 {
+    /// user field of the variation
+    pub(crate) user: u16,
+}
+
+/// Authentication - aggressive mode request
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group120Var3 {
+    /// challenge_seq field of the variation
+    pub(crate) challenge_seq: u32,
+    /// user field of the variation
+    pub(crate) user: u16,
+}
+
+/// Security Statistic Event - 32-bit With Flag and Time
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group122Var2 {
+    /// flags field of the variation
+    pub(crate) flags: u8,
+    /// association_id field of the variation
+    pub(crate) association_id: u16,
+    /// value field of the variation
+    pub(crate) value: u32,
+    /// time field of the variation
+    pub(crate) time: Timestamp,
+}
+
+/// Security Statistic Event - 32-bit With Flag
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group122Var1 {
+    /// flags field of the variation
+    pub(crate) flags: u8,
+    /// association_id field of the variation
+    pub(crate) association_id: u16,
+    /// value field of the variation
+    pub(crate) value: u32,
+}
+
+/// Security Statistic - 32-bit With Flag
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Group121Var1 {
+    /// flags field of the variation
+    pub(crate) flags: u8,
+    /// association_id field of the variation
+    pub(crate) association_id: u16,
+    /// value field of the variation
+    pub(crate) value: u32,
+}
+
 /// Unsigned Integer - 8-bit
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub(crate) struct Group102Var1 {
@@ -1588,6 +1821,21 @@ pub(crate) struct Group13Var1 {
     pub(crate) flags: u8,
 }
 
+/// Binary Command - Pattern Control Block
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Group12Var2 {
+    /// code field of the variation
+    pub code: ControlCode,
+    /// count field of the variation
+    pub count: u8,
+    /// on_time field of the variation
+    pub on_time: u32,
+    /// off_time field of the variation
+    pub off_time: u32,
+    /// status field of the variation
+    pub status: CommandStatus,
+}
+
 /// Binary Command - Control Relay Output Block
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct Group12Var1 {
@@ -1691,6 +1939,97 @@ pub(crate) struct Group1Var2 {
 }
 
 
+impl FixedSize for Group120Var4 {
+    const SIZE: u8 = 2;
+    fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
+        Ok(
+            Group120Var4 {
+                user: cursor.read_u16_le()?,
+            }
+        )
+    }
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u16_le(self.user)?;
+        Ok(())
+    }
+}
+
+impl FixedSize for Group120Var3 {
+    const SIZE: u8 = 6;
+    fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
+        Ok(
+            Group120Var3 {
+                challenge_seq: cursor.read_u32_le()?,
+                user: cursor.read_u16_le()?,
+            }
+        )
+    }
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u32_le(self.challenge_seq)?;
+        cursor.write_u16_le(self.user)?;
+        Ok(())
+    }
+}
+
+impl FixedSize for Group122Var2 {
+    const SIZE: u8 = 13;
+    fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
+        Ok(
+            Group122Var2 {
+                flags: cursor.read_u8()?,
+                association_id: cursor.read_u16_le()?,
+                value: cursor.read_u32_le()?,
+                time: Timestamp::new(cursor.read_u48_le()?),
+            }
+        )
+    }
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u8(self.flags)?;
+        cursor.write_u16_le(self.association_id)?;
+        cursor.write_u32_le(self.value)?;
+        self.time.write(cursor)?;
+        Ok(())
+    }
+}
+
+impl FixedSize for Group122Var1 {
+    const SIZE: u8 = 7;
+    fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
+        Ok(
+            Group122Var1 {
+                flags: cursor.read_u8()?,
+                association_id: cursor.read_u16_le()?,
+                value: cursor.read_u32_le()?,
+            }
+        )
+    }
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u8(self.flags)?;
+        cursor.write_u16_le(self.association_id)?;
+        cursor.write_u32_le(self.value)?;
+        Ok(())
+    }
+}
+
+impl FixedSize for Group121Var1 {
+    const SIZE: u8 = 7;
+    fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
+        Ok(
+            Group121Var1 {
+                flags: cursor.read_u8()?,
+                association_id: cursor.read_u16_le()?,
+                value: cursor.read_u32_le()?,
+            }
+        )
+    }
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u8(self.flags)?;
+        cursor.write_u16_le(self.association_id)?;
+        cursor.write_u32_le(self.value)?;
+        Ok(())
+    }
+}
+
 impl FixedSize for Group102Var1 {
     const SIZE: u8 = 1;
     fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
@@ -3165,6 +3504,29 @@ impl FixedSize for Group13Var1 {
     }
 }
 
+impl FixedSize for Group12Var2 {
+    const SIZE: u8 = 11;
+    fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
+        Ok(
+            Group12Var2 {
+                code: ControlCode::from(cursor.read_u8()?),
+                count: cursor.read_u8()?,
+                on_time: cursor.read_u32_le()?,
+                off_time: cursor.read_u32_le()?,
+                status: CommandStatus::from(cursor.read_u8()?),
+            }
+        )
+    }
+    fn write(&self, cursor: &mut WriteCursor) -> Result<(), WriteError> {
+        cursor.write_u8(self.code.as_u8())?;
+        cursor.write_u8(self.count)?;
+        cursor.write_u32_le(self.on_time)?;
+        cursor.write_u32_le(self.off_time)?;
+        self.status.write(cursor)?;
+        Ok(())
+    }
+}
+
 impl FixedSize for Group12Var1 {
     const SIZE: u8 = 11;
     fn read(cursor: &mut ReadCursor) -> Result<Self, ReadError> {
@@ -3364,6 +3726,36 @@ impl FixedSize for Group1Var2 {
 }
 
 
+impl std::fmt::Display for Group120Var4 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "user: {}", self.user)
+    }
+}
+
+impl std::fmt::Display for Group120Var3 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "challenge_seq: {} user: {}", self.challenge_seq, self.user)
+    }
+}
+
+impl std::fmt::Display for Group122Var2 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "flags: {} association_id: {} value: {} time: {}", CounterFlagFormatter::new(self.flags), self.association_id, self.value, self.time)
+    }
+}
+
+impl std::fmt::Display for Group122Var1 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "flags: {} association_id: {} value: {}", CounterFlagFormatter::new(self.flags), self.association_id, self.value)
+    }
+}
+
+impl std::fmt::Display for Group121Var1 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "flags: {} association_id: {} value: {}", CounterFlagFormatter::new(self.flags), self.association_id, self.value)
+    }
+}
+
 impl std::fmt::Display for Group102Var1 {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(f, "value: {}", self.value)
@@ -3880,6 +4272,12 @@ impl std::fmt::Display for Group13Var1 {
     }
 }
 
+impl std::fmt::Display for Group12Var2 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "code: {} count: {} on_time: {} off_time: {} status: {:?}", self.code, self.count, self.on_time, self.off_time, self.status)
+    }
+}
+
 impl std::fmt::Display for Group12Var1 {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(f, "code: {} count: {} on_time: {} off_time: {} status: {:?}", self.code, self.count, self.on_time, self.off_time, self.status)
@@ -3953,6 +4351,26 @@ impl std::fmt::Display for Group1Var2 {
 }
 
 
+impl FixedSizeVariation for Group120Var4 {
+    const VARIATION : Variation = Variation::Group120Var4;
+}
+
+impl FixedSizeVariation for Group120Var3 {
+    const VARIATION : Variation = Variation::Group120Var3;
+}
+
+impl FixedSizeVariation for Group122Var2 {
+    const VARIATION : Variation = Variation::Group122Var2;
+}
+
+impl FixedSizeVariation for Group122Var1 {
+    const VARIATION : Variation = Variation::Group122Var1;
+}
+
+impl FixedSizeVariation for Group121Var1 {
+    const VARIATION : Variation = Variation::Group121Var1;
+}
+
 impl FixedSizeVariation for Group102Var1 {
     const VARIATION : Variation = Variation::Group102Var1;
 }
@@ -4297,6 +4715,10 @@ impl FixedSizeVariation for Group13Var1 {
     const VARIATION : Variation = Variation::Group13Var1;
 }
 
+impl FixedSizeVariation for Group12Var2 {
+    const VARIATION : Variation = Variation::Group12Var2;
+}
+
 impl FixedSizeVariation for Group12Var1 {
     const VARIATION : Variation = Variation::Group12Var1;
 }
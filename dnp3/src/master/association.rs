@@ -3,19 +3,23 @@ use std::time::Duration;
 
 use xxhash_rust::xxh64::xxh64;
 
+use crate::app::auth::{SessionKeys, UpdateKey};
 use crate::app::parse::parser::{HeaderCollection, Response};
 use crate::app::Timestamp;
 use crate::app::{ExponentialBackOff, FunctionCode, RetryStrategy};
 use crate::app::{Iin, ResponseHeader};
 use crate::app::{Sequence, Timeout};
 use crate::link::EndpointAddress;
-use crate::master::error::{AssociationError, TaskError, TimeSyncError};
+use crate::master::auth::{AuthConfig, AuthMessage, ChallengeReply, MasterAuth};
+use crate::master::data_set::DataSetRegistry;
+use crate::master::error::{AssociationError, AuthError, TaskError, TimeSyncError};
 use crate::master::extract::extract_measurements;
 use crate::master::handler::AssociationHandler;
 use crate::master::messages::AssociationMsgType;
 use crate::master::poll::{PollHandle, PollMap, PollMsg};
 use crate::master::request::{Classes, EventClasses, TimeSyncProcedure};
 use crate::master::tasks::auto::AutoTask;
+use crate::master::tasks::session_keys::SessionKeyTask;
 use crate::master::tasks::time::TimeSyncTask;
 use crate::master::tasks::NonReadTask::TimeSync;
 use crate::master::tasks::{AppTask, AssociationTask, ReadTask, Task};
@@ -73,6 +77,11 @@ pub struct AssociationConfig {
         serde(default = "AssociationConfig::default_max_queued_user_requests")
     )]
     pub max_queued_user_requests: usize,
+    /// Secure authentication (SAv5) of critical requests
+    ///
+    /// When enabled, the update keys are retrieved from [`AssociationHandler::update_key_store`]
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub authentication: Option<AuthConfig>,
 }
 
 impl AssociationConfig {
@@ -100,6 +109,7 @@ impl AssociationConfig {
             auto_integrity_scan_on_buffer_overflow: false,
             event_scan_on_events_available,
             max_queued_user_requests: Self::default_max_queued_user_requests(),
+            authentication: None,
         }
     }
 
@@ -117,6 +127,7 @@ impl AssociationConfig {
             auto_integrity_scan_on_buffer_overflow: false,
             event_scan_on_events_available: EventClasses::none(),
             max_queued_user_requests: Self::default_max_queued_user_requests(),
+            authentication: None,
         }
     }
 }
@@ -134,6 +145,7 @@ impl Default for AssociationConfig {
             auto_integrity_scan_on_buffer_overflow: true,
             event_scan_on_events_available: EventClasses::none(),
             max_queued_user_requests: Self::default_max_queued_user_requests(),
+            authentication: None,
         }
     }
 }
@@ -207,6 +219,7 @@ impl AutoTaskState {
 
 #[derive(Debug)]
 pub(crate) struct TaskStates {
+    session_keys: AutoTaskState,
     disable_unsolicited: AutoTaskState,
     integrity_scan: AutoTaskState,
     enabled_unsolicited: AutoTaskState,
@@ -218,6 +231,7 @@ pub(crate) struct TaskStates {
 impl TaskStates {
     pub(crate) fn new() -> Self {
         Self {
+            session_keys: AutoTaskState::Pending,
             disable_unsolicited: AutoTaskState::Pending,
             integrity_scan: AutoTaskState::Pending,
             enabled_unsolicited: AutoTaskState::Pending,
@@ -238,6 +252,16 @@ impl TaskStates {
     }
 
     fn next(&self, config: &AssociationConfig, association: &Association) -> Next<Task> {
+        // critical requests can't be authenticated until the session keys are established
+        if self.session_keys.is_pending() {
+            if let Some(auth) = &association.auth {
+                let user = auth.user();
+                return self
+                    .session_keys
+                    .create_next_task(|| SessionKeyTask::StatusRequest(user).wrap());
+            }
+        }
+
         if self.clear_restart_iin.is_pending() {
             return self
                 .clear_restart_iin
@@ -310,6 +334,7 @@ pub(crate) struct Association {
     max_request_queue_size: usize,
     auto_tasks: TaskStates,
     read_handler: Box<dyn ReadHandler>,
+    data_sets: DataSetRegistry,
     assoc_handler: Box<dyn AssociationHandler>,
     assoc_info: Box<dyn AssociationInformation>,
     config: AssociationConfig,
@@ -317,6 +342,7 @@ pub(crate) struct Association {
     next_link_status_deadline: Option<Instant>,
     startup_integrity_done: bool,
     events_available: EventClasses,
+    auth: Option<MasterAuth>,
 }
 
 impl Association {
@@ -338,6 +364,7 @@ impl Association {
             max_request_queue_size: config.max_queued_user_requests,
             auto_tasks: TaskStates::new(),
             read_handler,
+            data_sets: DataSetRegistry::default(),
             assoc_handler,
             assoc_info,
             config,
@@ -345,6 +372,7 @@ impl Association {
             next_link_status_deadline: config.keep_alive_timeout.map(|delay| now + delay),
             startup_integrity_done: false,
             events_available: EventClasses::none(),
+            auth: config.authentication.map(MasterAuth::new),
         }
     }
 
@@ -395,6 +423,11 @@ impl Association {
 
         // Clear last unsolicited fragment
         self.last_unsol_frag = None;
+
+        // Session keys don't survive a communication failure
+        if let Some(auth) = &mut self.auth {
+            auth.invalidate();
+        }
     }
 
     pub(crate) fn get_system_time(&self) -> Option<Timestamp> {
@@ -517,6 +550,75 @@ impl Association {
         self.auto_tasks.disable_unsolicited.failure(&self.config);
     }
 
+    pub(crate) fn auth_mut(&mut self) -> Option<&mut MasterAuth> {
+        self.auth.as_mut()
+    }
+
+    pub(crate) fn last_auth_request(&self) -> Option<&[u8]> {
+        self.auth.as_ref().map(|x| x.last_request())
+    }
+
+    pub(crate) fn get_update_key(&mut self, user: u16) -> Option<UpdateKey> {
+        self.assoc_handler
+            .update_key_store()
+            .and_then(|store| store.get_update_key(user))
+    }
+
+    pub(crate) fn on_session_keys_changed(&mut self, keys: SessionKeys) {
+        if let Some(auth) = &mut self.auth {
+            tracing::info!("session keys changed (user == {})", auth.user());
+            auth.set_session_keys(keys);
+        }
+        self.auto_tasks.session_keys.done();
+    }
+
+    /// Store the key confirmed by the outstation and change the session keys if they depend on it
+    pub(crate) fn on_update_key_changed(&mut self, user: u16, key: UpdateKey) {
+        tracing::info!("update key changed (user == {})", user);
+        if let Some(store) = self.assoc_handler.update_key_store() {
+            store.set_update_key(user, key);
+        }
+
+        if let Some(auth) = &mut self.auth {
+            // the outstation invalidates the session keys of the user when its update key changes
+            if auth.user() == user {
+                auth.invalidate();
+                if self.auto_tasks.session_keys.demand() {
+                    tracing::info!("scheduled session key change");
+                }
+            }
+        }
+    }
+
+    pub(crate) fn on_session_key_change_failure(&mut self, err: TaskError) {
+        tracing::warn!("session key change failed: {}", err);
+        self.auto_tasks.session_keys.failure(&self.config);
+    }
+
+    /// Process a challenge or an error received in place of the response to a request
+    pub(crate) fn on_auth_message(
+        &mut self,
+        fragment: &[u8],
+        message: AuthMessage,
+    ) -> Result<ChallengeReply, AuthError> {
+        let auth = self.auth.as_mut().ok_or(AuthError::NotConfigured)?;
+
+        let result = match message {
+            AuthMessage::Challenge(challenge) => auth.reply(fragment, &challenge),
+            AuthMessage::Error(err) => Err(AuthError::Rejected(err.error)),
+        };
+
+        if let Err(err) = result {
+            tracing::warn!("authentication failed: {}", err);
+            auth.invalidate();
+            if self.auto_tasks.session_keys.demand() {
+                tracing::info!("scheduled session key change");
+            }
+        }
+
+        result
+    }
+
     pub(crate) fn on_link_activity(&mut self) {
         self.next_link_status_deadline = self
             .config
@@ -552,6 +654,7 @@ impl Association {
                     ReadType::Unsolicited,
                     response.header,
                     objects,
+                    &mut self.data_sets,
                     self.read_handler.as_mut(),
                 )
                 .await;
@@ -577,6 +680,7 @@ impl Association {
             ReadType::StartupIntegrity,
             header,
             objects,
+            &mut self.data_sets,
             self.read_handler.as_mut(),
         )
         .await;
@@ -591,6 +695,7 @@ impl Association {
             ReadType::PeriodicPoll,
             header,
             objects,
+            &mut self.data_sets,
             self.read_handler.as_mut(),
         )
         .await;
@@ -605,11 +710,16 @@ impl Association {
             ReadType::PeriodicPoll,
             header,
             objects,
+            &mut self.data_sets,
             self.read_handler.as_mut(),
         )
         .await;
     }
 
+    pub(crate) fn data_sets(&mut self) -> &mut DataSetRegistry {
+        &mut self.data_sets
+    }
+
     pub(crate) async fn handle_read_response(
         &mut self,
         header: ResponseHeader,
@@ -619,6 +729,7 @@ impl Association {
             ReadType::SinglePoll,
             header,
             objects,
+            &mut self.data_sets,
             self.read_handler.as_mut(),
         )
         .await;
@@ -661,6 +772,10 @@ impl Association {
     }
 
     fn next_task(&mut self, now: Instant) -> Next<Task> {
+        if self.is_key_change_due(now) && self.auto_tasks.session_keys.demand() {
+            tracing::info!("session keys expired, scheduled session key change");
+        }
+
         loop {
             let next_task = self.get_next_task(now);
 
@@ -675,6 +790,14 @@ impl Association {
         }
     }
 
+    fn is_key_change_due(&self, now: Instant) -> bool {
+        self.key_expiration().is_some_and(|x| now >= x)
+    }
+
+    fn key_expiration(&self) -> Option<Instant> {
+        self.auth.as_ref().and_then(|x| x.key_expiration())
+    }
+
     fn next_link_status_task(&self, now: Instant) -> Next<Task> {
         match self.next_link_status_deadline {
             None => Next::None,
@@ -697,6 +820,17 @@ impl Association {
             return next_auto_task;
         }
 
+        // wake up in time to change the session keys
+        match (self.get_next_periodic_task(now), self.key_expiration()) {
+            (Next::None, Some(expiration)) => Next::NotBefore(expiration),
+            (Next::NotBefore(next), Some(expiration)) => {
+                Next::NotBefore(Instant::min(next, expiration))
+            }
+            (next, _) => next,
+        }
+    }
+
+    fn get_next_periodic_task(&self, now: Instant) -> Next<Task> {
         match self.polls.next(now) {
             Next::Now(poll) => {
                 // always prioritize polls over link status requests
@@ -0,0 +1,330 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+use crate::app::auth::*;
+use crate::app::format::write::HeaderWriter;
+use crate::app::parse::free_format::FreeFormatVariation;
+use crate::app::parse::parser::{HeaderDetails, Response};
+use crate::app::variations::Group120Var3;
+use crate::app::ResponseFunction;
+use crate::master::error::{AuthError, TaskError};
+use crate::master::tasks::RequestWriter;
+
+/// Secure authentication (SAv5) configuration for a master association
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct AuthConfig {
+    /// User on whose behalf critical requests are authenticated
+    #[cfg_attr(feature = "serialization", serde(default = "AuthConfig::default_user"))]
+    pub user: u16,
+    /// Period after which the master changes the session keys
+    #[cfg_attr(
+        feature = "serialization",
+        serde(default = "AuthConfig::default_key_change_interval")
+    )]
+    pub key_change_interval: Duration,
+    /// Authenticate critical requests in aggressive mode
+    ///
+    /// Aggressive mode is used once the outstation has sent its first challenge
+    /// and saves a round trip for every critical request that follows.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub aggressive_mode: bool,
+}
+
+impl AuthConfig {
+    const fn default_user() -> u16 {
+        DEFAULT_USER
+    }
+
+    const fn default_key_change_interval() -> Duration {
+        Duration::from_secs(15 * 60)
+    }
+
+    /// Construct an `AuthConfig` for a user
+    ///
+    /// Other fields are set to defaults
+    pub fn new(user: u16) -> Self {
+        Self {
+            user,
+            ..Self::default()
+        }
+    }
+}
+
+impl Default for AuthConfig {
+    fn default() -> Self {
+        Self {
+            user: Self::default_user(),
+            key_change_interval: Self::default_key_change_interval(),
+            aggressive_mode: false,
+        }
+    }
+}
+
+/// Storage for the update keys the master shares with its outstations
+///
+/// The update keys are only used to change the session keys. Implementations
+/// may keep them in memory, in a file, or in a hardware security module.
+pub trait UpdateKeyStore: Send + Sync {
+    /// Retrieve the update key of a user
+    fn get_update_key(&self, user: u16) -> Option<UpdateKey>;
+
+    /// Store the new update key of a user after the outstation confirmed an update key change
+    fn set_update_key(&mut self, user: u16, key: UpdateKey);
+}
+
+impl UpdateKeyStore for HashMap<u16, UpdateKey> {
+    fn get_update_key(&self, user: u16) -> Option<UpdateKey> {
+        self.get(&user).cloned()
+    }
+
+    fn set_update_key(&mut self, user: u16, key: UpdateKey) {
+        self.insert(user, key);
+    }
+}
+
+/// Public key operations of the asymmetric key change methods
+///
+/// The library doesn't implement public key cryptography, so applications that use the
+/// asymmetric methods perform these operations with the cryptographic library of their choice
+pub trait AsymmetricKeyChange: Send + Sync {
+    /// Certification data of a user status change signed by the authority
+    ///
+    /// `data` is the user status change object (g120v10) without its certification data
+    fn certify(&mut self, data: &[u8]) -> Vec<u8>;
+
+    /// Encrypt the plaintext of an update key change with the public key of the outstation
+    fn encrypt(&mut self, plaintext: &[u8]) -> Vec<u8>;
+
+    /// Sign an update key change with the private key of the user
+    fn sign(&mut self, data: &[u8]) -> Vec<u8>;
+}
+
+/// Credentials of the authority used to change users and update keys remotely
+pub enum KeyChangeAuthority {
+    /// Symmetric method using the authority certification key shared with the outstation
+    Symmetric(AuthorityKey),
+    /// Asymmetric method with the public key operations performed by the application
+    Asymmetric(KeyChangeMethod, Box<dyn AsymmetricKeyChange>),
+}
+
+impl KeyChangeAuthority {
+    pub(crate) fn method(&self) -> KeyChangeMethod {
+        match self {
+            Self::Symmetric(_) => KeyChangeMethod::SymmetricAes256Sha256,
+            Self::Asymmetric(method, _) => *method,
+        }
+    }
+}
+
+/// Change of a user's status issued on behalf of the authority (g120v10)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UserStatusChange {
+    /// Add, delete, or change the user
+    pub operation: UserStatusOperation,
+    /// Status change sequence number (SCS) assigned by the authority
+    ///
+    /// The outstation only accepts changes with a sequence number greater than the last one
+    pub status_change_seq: u32,
+    /// Globally unique name of the user
+    pub user_name: String,
+    /// Role of the user
+    pub role: UserRole,
+    /// Number of days after which the role expires
+    pub role_expiry_days: u16,
+    /// Public key of the user, only used by the asymmetric methods
+    pub user_public_key: Vec<u8>,
+}
+
+impl UserStatusChange {
+    /// Construct a user status change without a public key
+    pub fn new(
+        operation: UserStatusOperation,
+        status_change_seq: u32,
+        user_name: String,
+        role: UserRole,
+        role_expiry_days: u16,
+    ) -> Self {
+        Self {
+            operation,
+            status_change_seq,
+            user_name,
+            role,
+            role_expiry_days,
+            user_public_key: Vec::new(),
+        }
+    }
+}
+
+/// Authentication messages the outstation may send in place of a response
+pub(crate) enum AuthMessage<'a> {
+    Challenge(Group120Var1<'a>),
+    Error(Group120Var7<'a>),
+}
+
+impl<'a> AuthMessage<'a> {
+    pub(crate) fn parse(response: &Response<'a>) -> Option<Self> {
+        if response.header.function != ResponseFunction::AuthResponse {
+            return None;
+        }
+
+        match response.get_only_object_header().ok()?.details {
+            HeaderDetails::TwoByteFreeFormat(1, FreeFormatVariation::Group120Var1(x)) => {
+                Some(Self::Challenge(x))
+            }
+            HeaderDetails::TwoByteFreeFormat(1, FreeFormatVariation::Group120Var7(x)) => {
+                Some(Self::Error(x))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Reply to a challenge (g120v2)
+pub(crate) struct ChallengeReply {
+    challenge_seq: u32,
+    user: u16,
+    mac: Vec<u8>,
+}
+
+impl ChallengeReply {
+    pub(crate) fn write(&self, writer: &mut HeaderWriter) -> Result<(), TaskError> {
+        writer.write_free_format(&Group120Var2 {
+            challenge_seq: self.challenge_seq,
+            user: self.user,
+            mac: &self.mac,
+        })?;
+        Ok(())
+    }
+}
+
+struct ActiveKeys {
+    keys: SessionKeys,
+    expiration: Instant,
+}
+
+struct LastChallenge {
+    mac_algorithm: MacAlgorithm,
+    fragment: Vec<u8>,
+}
+
+/// Authentication state of an association
+pub(crate) struct MasterAuth {
+    config: AuthConfig,
+    keys: Option<ActiveKeys>,
+    /// challenge sequence number of the last challenge or aggressive mode request
+    challenge_seq: u32,
+    last_challenge: Option<LastChallenge>,
+    /// copy of the last request sent to the outstation
+    last_request: Vec<u8>,
+}
+
+impl MasterAuth {
+    pub(crate) fn new(config: AuthConfig) -> Self {
+        Self {
+            config,
+            keys: None,
+            challenge_seq: 0,
+            last_challenge: None,
+            last_request: Vec::new(),
+        }
+    }
+
+    pub(crate) fn user(&self) -> u16 {
+        self.config.user
+    }
+
+    pub(crate) fn last_request(&self) -> &[u8] {
+        &self.last_request
+    }
+
+    pub(crate) fn record_request(&mut self, request: &[u8]) {
+        self.last_request.clear();
+        self.last_request.extend_from_slice(request);
+    }
+
+    /// Time at which the session keys must be changed, if they were established
+    pub(crate) fn key_expiration(&self) -> Option<Instant> {
+        self.keys.as_ref().map(|x| x.expiration)
+    }
+
+    pub(crate) fn set_session_keys(&mut self, keys: SessionKeys) {
+        self.keys = Some(ActiveKeys {
+            keys,
+            expiration: Instant::now() + self.config.key_change_interval,
+        });
+    }
+
+    pub(crate) fn invalidate(&mut self) {
+        self.keys = None;
+        self.last_challenge = None;
+    }
+
+    /// Calculate the reply to a challenge of the last request
+    pub(crate) fn reply(
+        &mut self,
+        fragment: &[u8],
+        challenge: &Group120Var1,
+    ) -> Result<ChallengeReply, AuthError> {
+        let mac_algorithm = MacAlgorithm::new(challenge.mac_algorithm)
+            .ok_or(AuthError::UnsupportedMacAlgorithm(challenge.mac_algorithm))?;
+        let keys = self.keys.as_ref().ok_or(AuthError::NoSessionKeys)?;
+
+        let mac = calc_mac(
+            mac_algorithm,
+            &keys.keys.control,
+            &[fragment, &self.last_request],
+        );
+
+        self.challenge_seq = challenge.challenge_seq;
+        self.last_challenge = Some(LastChallenge {
+            mac_algorithm,
+            fragment: fragment.to_vec(),
+        });
+
+        Ok(ChallengeReply {
+            challenge_seq: challenge.challenge_seq,
+            user: self.config.user,
+            mac,
+        })
+    }
+
+    /// Write the objects of a request, authenticating it in aggressive mode when possible
+    pub(crate) fn write_request<U>(
+        &mut self,
+        request: &U,
+        writer: &mut HeaderWriter,
+    ) -> Result<(), TaskError>
+    where
+        U: RequestWriter,
+    {
+        if !self.config.aggressive_mode || !is_critical(request.function()) {
+            return request.write(writer);
+        }
+
+        let (keys, challenge) = match (&self.keys, &self.last_challenge) {
+            (Some(keys), Some(challenge)) => (keys, challenge),
+            _ => return request.write(writer),
+        };
+
+        self.challenge_seq = self.challenge_seq.wrapping_add(1);
+        writer.write_count_of_one(Group120Var3 {
+            challenge_seq: self.challenge_seq,
+            user: self.config.user,
+        })?;
+        request.write(writer)?;
+
+        let mac = calc_mac(
+            challenge.mac_algorithm,
+            &keys.keys.control,
+            &[&challenge.fragment, writer.written()],
+        );
+        writer.write_free_format(&Group120Var9 { mac: &mac })?;
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+use std::collections::BTreeMap;
+
+use crate::app::data_set::*;
+use crate::app::parse::free_format::FreeFormatVariation;
+use crate::app::{QualifierCode, Timestamp, Variation};
+use crate::master::{HeaderInfo, ReadHandler};
+
+/// Prototypes and descriptors received from an outstation
+///
+/// These are used to determine the types of the values in data sets (g87) and
+/// snapshot events (g88) received from the same outstation.
+#[derive(Default)]
+pub(crate) struct DataSetRegistry {
+    prototypes: BTreeMap<u32, DataSetPrototype>,
+    descriptors: BTreeMap<u32, DataSetDescriptor>,
+}
+
+impl DataSetRegistry {
+    /// record and forward any data set objects, returning false if the variation isn't a data set
+    pub(crate) fn extract(
+        &mut self,
+        variation: Variation,
+        object: &FreeFormatVariation,
+        handler: &mut dyn ReadHandler,
+    ) -> bool {
+        let info =
+            |is_event| HeaderInfo::new(variation, QualifierCode::FreeFormat16, is_event, false);
+
+        match object {
+            FreeFormatVariation::Group85Var1(obj) => {
+                let prototype = DataSetPrototype::new(obj.uuid, obj.elements.iter().collect());
+                handler.handle_data_set_prototype(info(false), obj.id, &prototype);
+                self.prototypes.insert(obj.id, prototype);
+            }
+            FreeFormatVariation::Group86Var1(obj) => {
+                let descriptor = DataSetDescriptor::new(obj.elements.iter().collect());
+                handler.handle_data_set_descriptor(info(false), obj.id, &descriptor);
+                self.descriptors.insert(obj.id, descriptor);
+            }
+            FreeFormatVariation::Group87Var1(obj) => {
+                let data_set = self.decode(obj.id, obj.time, obj.values);
+                handler.handle_data_set(info(false), obj.id, &data_set);
+            }
+            FreeFormatVariation::Group88Var1(obj) => {
+                let data_set = self.decode(obj.id, obj.time, obj.values);
+                handler.handle_data_set(info(true), obj.id, &data_set);
+            }
+            _ => return false,
+        }
+
+        true
+    }
+
+    fn decode(&self, id: u32, time: Timestamp, values: ValueElements) -> DataSet {
+        let types = self.value_types(id).unwrap_or_else(|| {
+            tracing::warn!(
+                "data set {id} has no known descriptor, values are reported as octet strings"
+            );
+            Vec::new()
+        });
+        DataSet::decode(time, values, &types)
+    }
+
+    /// types of the values in the specified data set, if the descriptor and all
+    /// referenced prototypes are known
+    fn value_types(&self, id: u32) -> Option<Vec<DataSetType>> {
+        self.descriptors
+            .get(&id)?
+            .value_types(|uuid| self.prototypes.values().find(|x| x.uuid == uuid))
+    }
+}
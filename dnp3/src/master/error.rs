@@ -1,5 +1,6 @@
 use std::error::Error;
 
+use crate::app::auth::{AuthErrorCode, KeyStatus};
 use crate::app::control::CommandStatus;
 use crate::app::{Iin, Iin2};
 use crate::app::{ObjectParseError, Shutdown};
@@ -87,6 +88,30 @@ pub enum TaskError {
     Shutdown,
     /// The master was disabled
     Disabled,
+    /// Secure authentication of the request failed
+    Authentication(AuthError),
+}
+
+/// Errors that can occur while authenticating requests with secure authentication (SAv5)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(not(feature = "ffi"), non_exhaustive)]
+pub enum AuthError {
+    /// The outstation sent a challenge but authentication is not configured on the association
+    NotConfigured,
+    /// No update key is available for the configured user
+    NoUpdateKey,
+    /// The outstation sent a challenge before the session keys were established
+    NoSessionKeys,
+    /// The outstation requested a MAC algorithm that isn't supported
+    UnsupportedMacAlgorithm(u8),
+    /// The outstation requested a key wrap algorithm that doesn't match the update key
+    KeyWrapMismatch(u8),
+    /// The outstation reported a session key status other than OK after a key change
+    BadKeyStatus(KeyStatus),
+    /// The MAC sent by the outstation could not be verified
+    BadMac,
+    /// The outstation rejected the request with an authentication error (g120v7)
+    Rejected(AuthErrorCode),
 }
 
 /// Errors that can occur when adding/modifying polls
@@ -254,6 +279,28 @@ impl std::fmt::Display for TaskError {
             TaskError::RejectedByIin2(iin) => {
                 write!(f, "Rejected by IIN2: {}", iin.iin2)
             }
+            TaskError::Authentication(err) => write!(f, "authentication failed: {err}"),
+        }
+    }
+}
+
+impl std::fmt::Display for AuthError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            AuthError::NotConfigured => {
+                f.write_str("secure authentication is not configured on the association")
+            }
+            AuthError::NoUpdateKey => f.write_str("no update key available for the user"),
+            AuthError::NoSessionKeys => f.write_str("session keys have not been established"),
+            AuthError::UnsupportedMacAlgorithm(x) => {
+                write!(f, "unsupported MAC algorithm: {x}")
+            }
+            AuthError::KeyWrapMismatch(x) => {
+                write!(f, "key wrap algorithm ({x}) doesn't match the update key")
+            }
+            AuthError::BadKeyStatus(x) => write!(f, "session key status: {x:?}"),
+            AuthError::BadMac => f.write_str("MAC could not be verified"),
+            AuthError::Rejected(x) => write!(f, "outstation returned error: {x:?}"),
         }
     }
 }
@@ -286,6 +333,12 @@ impl std::fmt::Display for WriteError {
     }
 }
 
+impl From<AuthError> for TaskError {
+    fn from(err: AuthError) -> Self {
+        TaskError::Authentication(err)
+    }
+}
+
 impl From<scursor::WriteError> for TaskError {
     fn from(_: scursor::WriteError) -> Self {
         TaskError::WriteError
@@ -451,6 +504,7 @@ impl From<Shutdown> for WriteError {
 
 impl Error for AssociationError {}
 impl Error for TaskError {}
+impl Error for AuthError {}
 impl Error for PollError {}
 impl Error for CommandError {}
 impl Error for CommandResponseError {}
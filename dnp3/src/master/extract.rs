@@ -4,6 +4,7 @@ use crate::app::parse::count::CountSequence;
 use crate::app::parse::parser::{HeaderCollection, HeaderDetails, ObjectHeader};
 use crate::app::variations::*;
 use crate::app::ResponseHeader;
+use crate::master::data_set::DataSetRegistry;
 use crate::master::ReadType;
 use crate::master::{HeaderInfo, ReadHandler};
 
@@ -13,10 +14,11 @@ pub(crate) async fn extract_measurements(
     read_type: ReadType,
     header: ResponseHeader,
     objects: HeaderCollection<'_>,
+    data_sets: &mut DataSetRegistry,
     handler: &mut dyn ReadHandler,
 ) {
     handler.begin_fragment(read_type, header).get().await;
-    extract_measurements_inner(objects, handler);
+    extract_measurements_inner(objects, data_sets, handler);
     handler.end_fragment(read_type, header).get().await;
 }
 
@@ -24,6 +26,7 @@ pub(crate) async fn extract_measurements(
 /// something that implements `MeasurementHandler`
 pub(crate) fn extract_measurements_inner(
     objects: HeaderCollection<'_>,
+    data_sets: &mut DataSetRegistry,
     handler: &mut dyn ReadHandler,
 ) {
     fn extract_cto_g51v1(prev: Option<Time>, item: Option<Group51Var1>) -> Option<Time> {
@@ -53,6 +56,7 @@ pub(crate) fn extract_measurements_inner(
     fn handle(
         cto: Option<Time>,
         header: ObjectHeader,
+        data_sets: &mut DataSetRegistry,
         handler: &mut dyn ReadHandler,
     ) -> Option<Time> {
         let handled = match &header.details {
@@ -89,6 +93,10 @@ pub(crate) fn extract_measurements_inner(
             HeaderDetails::TwoByteCountAndPrefix(_, var) => {
                 var.extract_measurements_to(cto, handler)
             }
+            // data set objects (g85 - g88)
+            HeaderDetails::TwoByteFreeFormat(1, var) => {
+                data_sets.extract(header.variation, var, handler)
+            }
             _ => false,
         };
 
@@ -106,13 +114,14 @@ pub(crate) fn extract_measurements_inner(
 
     objects
         .iter()
-        .fold(None, |cto, header| handle(cto, header, handler));
+        .fold(None, |cto, header| handle(cto, header, data_sets, handler));
 }
 
 #[cfg(test)]
 mod test {
     use crate::app::attr::*;
     use crate::app::control::CommandStatus;
+    use crate::app::data_set::*;
     use crate::app::parse::options::ParseOptions;
     use crate::app::parse::parser::HeaderCollection;
     use crate::app::*;
@@ -150,6 +159,10 @@ mod test {
         AnalogCommandEvent(Vec<(AnalogOutputCommandEvent, u16)>),
         G102(Vec<(UnsignedInteger, u16)>),
         AbsTime(Timestamp),
+        DataSetDescriptor(u32, DataSetDescriptor),
+        DataSet(bool, u32, DataSet),
+        VirtualTerminal(Vec<(Vec<u8>, u16)>),
+        SecurityStatistic(Vec<(SecurityStatistic, u16)>),
     }
 
     #[derive(Default)]
@@ -281,6 +294,14 @@ mod test {
             self.received.push(Header::G102(x.collect()))
         }
 
+        fn handle_security_statistic(
+            &mut self,
+            _info: HeaderInfo,
+            x: &mut dyn Iterator<Item = (SecurityStatistic, u16)>,
+        ) {
+            self.received.push(Header::SecurityStatistic(x.collect()))
+        }
+
         fn handle_octet_string(
             &mut self,
             _info: HeaderInfo,
@@ -289,6 +310,17 @@ mod test {
             unimplemented!()
         }
 
+        fn handle_virtual_terminal<'a>(
+            &mut self,
+            info: HeaderInfo,
+            iter: &'a mut dyn Iterator<Item = (&'a [u8], u16)>,
+        ) {
+            assert!(info.is_event);
+            self.received.push(Header::VirtualTerminal(
+                iter.map(|(x, i)| (x.to_vec(), i)).collect(),
+            ));
+        }
+
         fn handle_device_attribute(&mut self, _info: HeaderInfo, attr: AnyAttribute) {
             match attr {
                 AnyAttribute::Other(x) => {
@@ -316,6 +348,21 @@ mod test {
         fn handle_abs_time(&mut self, _info: HeaderInfo, time: Timestamp) {
             self.received.push(Header::AbsTime(time));
         }
+
+        fn handle_data_set_descriptor(
+            &mut self,
+            _info: HeaderInfo,
+            id: u32,
+            descriptor: &DataSetDescriptor,
+        ) {
+            self.received
+                .push(Header::DataSetDescriptor(id, descriptor.clone()));
+        }
+
+        fn handle_data_set(&mut self, info: HeaderInfo, id: u32, data_set: &DataSet) {
+            self.received
+                .push(Header::DataSet(info.is_event, id, data_set.clone()));
+        }
     }
 
     #[test]
@@ -337,7 +384,7 @@ mod test {
             0x07,
         );
 
-        extract_measurements_inner(objects, &mut handler);
+        extract_measurements_inner(objects, &mut DataSetRegistry::default(), &mut handler);
 
         assert_eq!(handler.pop(), &[Header::Binary(vec![expected])]);
     }
@@ -365,7 +412,7 @@ mod test {
             0x07,
         );
 
-        extract_measurements_inner(objects, &mut handler);
+        extract_measurements_inner(objects, &mut DataSetRegistry::default(), &mut handler);
         assert_eq!(&handler.pop(), &[Header::Binary(vec![expected])]);
     }
 
@@ -392,7 +439,7 @@ mod test {
             0x07,
         );
 
-        extract_measurements_inner(objects, &mut handler);
+        extract_measurements_inner(objects, &mut DataSetRegistry::default(), &mut handler);
         assert_eq!(&handler.pop(), &[Header::Binary(vec![expected])]);
     }
 
@@ -419,7 +466,7 @@ mod test {
             0x07,
         );
 
-        extract_measurements_inner(objects, &mut handler);
+        extract_measurements_inner(objects, &mut DataSetRegistry::default(), &mut handler);
         assert_eq!(&handler.pop(), &[Header::Binary(vec![expected])]);
     }
 
@@ -446,7 +493,7 @@ mod test {
             0x07,
         );
 
-        extract_measurements_inner(objects, &mut handler);
+        extract_measurements_inner(objects, &mut DataSetRegistry::default(), &mut handler);
         assert_eq!(&handler.pop(), &[Header::Binary(vec![expected])]);
     }
 
@@ -472,7 +519,7 @@ mod test {
             0x07,
         );
 
-        extract_measurements_inner(objects, &mut handler);
+        extract_measurements_inner(objects, &mut DataSetRegistry::default(), &mut handler);
         assert_eq!(&handler.pop(), &[Header::FrozenAnalog(vec![expected])]);
     }
 
@@ -491,7 +538,7 @@ mod test {
             (AnalogInputDeadBand::U16(0xADDE), 0x02),
         ];
 
-        extract_measurements_inner(objects, &mut handler);
+        extract_measurements_inner(objects, &mut DataSetRegistry::default(), &mut handler);
         assert_eq!(&handler.pop(), &[Header::AnalogDeadBand(items)]);
     }
 
@@ -514,7 +561,7 @@ mod test {
             0x07,
         );
 
-        extract_measurements_inner(objects, &mut handler);
+        extract_measurements_inner(objects, &mut DataSetRegistry::default(), &mut handler);
         assert_eq!(&handler.pop(), &[Header::FrozenAnalog(vec![expected])]);
     }
 
@@ -535,7 +582,7 @@ mod test {
         )
         .unwrap();
 
-        extract_measurements_inner(objects, &mut handler);
+        extract_measurements_inner(objects, &mut DataSetRegistry::default(), &mut handler);
 
         let h1 = Header::KnownAttr(Known::String(
             StringAttr::DeviceManufacturersName,
@@ -576,7 +623,7 @@ mod test {
         )
         .unwrap();
 
-        extract_measurements_inner(objects, &mut handler);
+        extract_measurements_inner(objects, &mut DataSetRegistry::default(), &mut handler);
 
         assert_eq!(
             &handler.pop(),
@@ -617,7 +664,7 @@ mod test {
         )
         .unwrap();
 
-        extract_measurements_inner(objects, &mut handler);
+        extract_measurements_inner(objects, &mut DataSetRegistry::default(), &mut handler);
 
         assert_eq!(
             &handler.pop(),
@@ -648,7 +695,7 @@ mod test {
         )
         .unwrap();
 
-        extract_measurements_inner(objects, &mut handler);
+        extract_measurements_inner(objects, &mut DataSetRegistry::default(), &mut handler);
 
         assert_eq!(
             &handler.pop(),
@@ -659,6 +706,40 @@ mod test {
         );
     }
 
+    #[test]
+    fn handles_g122v2() {
+        let mut handler = MockHandler::new();
+        let objects = HeaderCollection::parse(
+            ParseOptions::default(),
+            FunctionCode::UnsolicitedResponse,
+            &[
+                122, 2, 0x17, // g122v2 with 1-byte count and prefix
+                0x01, // count of 1
+                0x05, // index 5
+                0x01, // flags
+                0x02, 0x00, // association id
+                0x2A, 0x00, 0x00, 0x00, // value
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, // time
+            ],
+        )
+        .unwrap();
+
+        extract_measurements_inner(objects, &mut DataSetRegistry::default(), &mut handler);
+
+        assert_eq!(
+            &handler.pop(),
+            &[Header::SecurityStatistic(vec![(
+                SecurityStatistic {
+                    association_id: 2,
+                    value: 42,
+                    flags: Flags::ONLINE,
+                    time: Some(Time::Synchronized(Timestamp::new(1))),
+                },
+                5
+            )])]
+        );
+    }
+
     #[test]
     fn handles_g50v1_with_count_1() {
         let mut handler = MockHandler::new();
@@ -670,7 +751,7 @@ mod test {
         )
         .unwrap();
 
-        extract_measurements_inner(objects, &mut handler);
+        extract_measurements_inner(objects, &mut DataSetRegistry::default(), &mut handler);
 
         assert_eq!(
             &handler.pop(),
@@ -691,7 +772,7 @@ mod test {
         )
         .unwrap();
 
-        extract_measurements_inner(objects, &mut handler);
+        extract_measurements_inner(objects, &mut DataSetRegistry::default(), &mut handler);
 
         assert_eq!(
             &handler.pop(),
@@ -713,7 +794,7 @@ mod test {
         )
         .unwrap();
 
-        extract_measurements_inner(objects, &mut handler);
+        extract_measurements_inner(objects, &mut DataSetRegistry::default(), &mut handler);
 
         // Should not receive any time values since count > 1
         assert_eq!(&handler.pop(), &[]);
@@ -730,9 +811,110 @@ mod test {
         )
         .unwrap();
 
-        extract_measurements_inner(objects, &mut handler);
+        extract_measurements_inner(objects, &mut DataSetRegistry::default(), &mut handler);
 
         // Should not receive any time values since count == 0
         assert_eq!(&handler.pop(), &[]);
     }
+
+    #[test]
+    fn types_data_sets_using_received_descriptors() {
+        let mut handler = MockHandler::new();
+        let mut data_sets = DataSetRegistry::default();
+        let objects = HeaderCollection::parse(
+            ParseOptions::default(),
+            FunctionCode::Response,
+            &[
+                86, 1, 0x5B, 0x01, 0x07, 0x00, // g86v1, free-format, length == 7
+                0x01, 0x05, // id == 5
+                0x04, 0x05, 0x02, 0x04, b'a', // DAEL, UINT, max length 4, name "a"
+                87, 1, 0x5B, 0x01, 0x0B, 0x00, // g87v1, free-format, length == 11
+                0x01, 0x05, // id == 5
+                0x06, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, // time == 1
+                0x01, 0x2A, // value == 42
+            ],
+        )
+        .unwrap();
+
+        extract_measurements_inner(objects, &mut data_sets, &mut handler);
+
+        assert_eq!(
+            handler.pop(),
+            &[
+                Header::DataSetDescriptor(
+                    5,
+                    DataSetDescriptor::new(vec![DescriptorElement::data_element(
+                        DataSetType::UnsignedInt,
+                        4,
+                        "a"
+                    )])
+                ),
+                Header::DataSet(
+                    false,
+                    5,
+                    DataSet::new(Timestamp::new(1), vec![DataSetValue::UnsignedInt(42)])
+                ),
+            ]
+        );
+
+        // the descriptor is remembered for subsequent events
+        let objects = HeaderCollection::parse(
+            ParseOptions::default(),
+            FunctionCode::Response,
+            &[
+                88, 1, 0x5B, 0x01, 0x0B, 0x00, // g88v1, free-format, length == 11
+                0x01, 0x05, // id == 5
+                0x06, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, // time == 2
+                0x01, 0x2A, // value == 42
+                88, 1, 0x5B, 0x01, 0x0B, 0x00, // g88v1, free-format, length == 11
+                0x01, 0x06, // id == 6 (unknown)
+                0x06, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, // time == 2
+                0x01, 0x2A, // value == 42
+            ],
+        )
+        .unwrap();
+
+        extract_measurements_inner(objects, &mut data_sets, &mut handler);
+
+        assert_eq!(
+            handler.pop(),
+            &[
+                Header::DataSet(
+                    true,
+                    5,
+                    DataSet::new(Timestamp::new(2), vec![DataSetValue::UnsignedInt(42)])
+                ),
+                Header::DataSet(
+                    true,
+                    6,
+                    DataSet::new(Timestamp::new(2), vec![DataSetValue::OctetString(vec![42])])
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn handles_g113_virtual_terminal_events() {
+        let mut handler = MockHandler::new();
+        let objects = HeaderCollection::parse(
+            ParseOptions::default(),
+            FunctionCode::UnsolicitedResponse,
+            &[
+                113, 2, 0x28, 0x02, 0x00, // g113v2, two-byte count and prefix, count == 2
+                0x05, 0x00, b'o', b'k', // port 5
+                0x06, 0x00, b'>', b' ', // port 6
+            ],
+        )
+        .unwrap();
+
+        extract_measurements_inner(objects, &mut DataSetRegistry::default(), &mut handler);
+
+        assert_eq!(
+            handler.pop(),
+            &[Header::VirtualTerminal(vec![
+                (b"ok".to_vec(), 5),
+                (b"> ".to_vec(), 6)
+            ])]
+        );
+    }
 }
@@ -1,16 +1,21 @@
 use std::net::SocketAddr;
 use std::time::{Duration, SystemTime};
 
+use crate::app::auth::UpdateKey;
+use crate::app::data_set::DataSet;
 use crate::app::*;
 
 use crate::decode::DecodeLevel;
 use crate::link::EndpointAddress;
 use crate::master::association::AssociationConfig;
+use crate::master::auth::{KeyChangeAuthority, UpdateKeyStore, UserStatusChange};
 use crate::master::error::{AssociationError, CommandError, PollError, TaskError, TimeSyncError};
 use crate::master::messages::{AssociationMsg, AssociationMsgType, MasterMsg, Message};
 use crate::master::poll::{PollHandle, PollMsg};
 use crate::master::promise::Promise;
-use crate::master::request::{CommandHeaders, CommandMode, ReadRequest, TimeSyncProcedure};
+use crate::master::request::{
+    AssignClassRequest, CommandHeaders, CommandMode, ReadRequest, TimeSyncProcedure,
+};
 use crate::master::tasks::command::CommandTask;
 use crate::master::tasks::deadbands::WriteDeadBandsTask;
 use crate::master::tasks::empty_response::EmptyResponseTask;
@@ -24,6 +29,8 @@ use crate::master::tasks::file::write_block::{WriteBlockRequest, WriteBlockTask}
 use crate::master::tasks::read::SingleReadTask;
 use crate::master::tasks::restart::{RestartTask, RestartType};
 use crate::master::tasks::time::TimeSyncTask;
+use crate::master::tasks::update_key::UpdateKeyTask;
+use crate::master::tasks::user_status::UserStatusTask;
 use crate::master::tasks::Task;
 use crate::master::{
     AuthKey, BlockNumber, DeadBandHeader, DirReadConfig, FileCredentials, FileError, FileHandle,
@@ -306,6 +313,40 @@ impl AssociationHandle {
         rx.await?
     }
 
+    /// Perform an ASSIGN_CLASS request to change the event class of points in the outstation
+    pub async fn assign_class(&mut self, request: AssignClassRequest) -> Result<(), WriteError> {
+        self.send_and_expect_empty_response(FunctionCode::AssignClass, request.into_headers())
+            .await
+    }
+
+    /// Write the present value of a data set (g87v1) to the outstation
+    ///
+    /// The values must match the types defined by the data set descriptor in the outstation
+    pub async fn write_data_set(&mut self, id: u32, data_set: DataSet) -> Result<(), WriteError> {
+        self.send_and_expect_empty_response(
+            FunctionCode::Write,
+            Headers::new().add_data_set(id, data_set),
+        )
+        .await
+    }
+
+    /// Write data to a virtual terminal port in the outstation using output blocks (g112)
+    ///
+    /// Data returned by the terminal is reported as g113 events and delivered to
+    /// [`ReadHandler::handle_virtual_terminal`] when events are polled or received in
+    /// unsolicited responses.
+    pub async fn write_virtual_terminal(
+        &mut self,
+        port: u16,
+        data: Vec<u8>,
+    ) -> Result<(), WriteError> {
+        self.send_and_expect_empty_response(
+            FunctionCode::Write,
+            Headers::new().add_virtual_terminal(port, data),
+        )
+        .await
+    }
+
     /// Perform an asynchronous READ request with a custom read handler
     ///
     /// If successful, the custom [ReadHandler](ReadHandler) will process the received measurement data
@@ -508,6 +549,45 @@ impl AssociationHandle {
         reply.await?
     }
 
+    /// Add, delete, or change a secure authentication user (g120v10) on behalf of the authority
+    ///
+    /// The change is certified with the credentials of the authority. Users that are added
+    /// can't authenticate requests until their update key is set with [`Self::change_update_key`].
+    pub async fn change_user_status(
+        &mut self,
+        change: UserStatusChange,
+        authority: KeyChangeAuthority,
+    ) -> Result<(), TaskError> {
+        let (promise, rx) = Promise::one_shot();
+        let task = UserStatusTask::new(change, authority, promise)?;
+        self.send_task(task).await?;
+        rx.await?
+    }
+
+    /// Change the update key of a secure authentication user (g120v11 - g120v15)
+    ///
+    /// The new key is encrypted for the outstation with the credentials of the authority.
+    /// Once the outstation confirms the change, the key is stored with
+    /// [`UpdateKeyStore::set_update_key`] and the session keys of the user are changed.
+    ///
+    /// `outstation_name` is the name the authority assigned to the outstation. Both sides include
+    /// it in the MAC that confirms the change, so it must match the name returned by the outstation's
+    /// [`KeyStore::outstation_name`](crate::outstation::KeyStore::outstation_name).
+    ///
+    /// Returns the user number the outstation assigned to the user name
+    pub async fn change_update_key(
+        &mut self,
+        user_name: String,
+        outstation_name: String,
+        update_key: UpdateKey,
+        authority: KeyChangeAuthority,
+    ) -> Result<u16, TaskError> {
+        let (promise, rx) = Promise::one_shot();
+        let task = UpdateKeyTask::new(user_name, outstation_name, update_key, authority, promise);
+        self.send_task(task).await?;
+        rx.await?
+    }
+
     async fn send_task<T: Into<Task>>(&mut self, task: T) -> Result<(), Shutdown> {
         self.master
             .send_association_message(self.address, AssociationMsgType::QueueTask(task.into()))
@@ -561,6 +641,12 @@ pub enum TaskType {
     FileClose,
     /// Get information about a file
     GetFileInfo,
+    /// Establish or change the secure authentication session keys
+    SessionKeyChange,
+    /// Add, delete, or change a secure authentication user
+    UserStatusChange,
+    /// Change the update key of a secure authentication user
+    UpdateKeyChange,
 }
 
 /// callbacks associated with a single master to outstation association
@@ -569,6 +655,13 @@ pub trait AssociationHandler: Send + Sync {
     fn get_current_time(&self) -> Option<Timestamp> {
         Timestamp::try_from_system_time(SystemTime::now())
     }
+
+    /// Retrieve the store of update keys used to change the session keys
+    ///
+    /// Only used when secure authentication is enabled in the association configuration
+    fn update_key_store(&mut self) -> Option<&mut dyn UpdateKeyStore> {
+        None
+    }
 }
 
 /// Informational callbacks that can be used to monitor master communication
@@ -1,4 +1,5 @@
 pub use association::*;
+pub use auth::*;
 pub use error::*;
 pub use file::*;
 pub use handler::*;
@@ -7,6 +8,7 @@ pub use read_handler::*;
 pub use request::*;
 
 mod association;
+mod auth;
 mod error;
 mod file;
 mod handler;
@@ -14,6 +16,7 @@ mod read_handler;
 mod request;
 
 pub(crate) mod convert;
+pub(crate) mod data_set;
 pub(crate) mod extract;
 pub(crate) mod messages;
 pub(crate) mod poll;
@@ -1,4 +1,5 @@
 use crate::app::attr::{AnyAttribute, Attribute};
+use crate::app::data_set::{DataSet, DataSetDescriptor, DataSetPrototype};
 use crate::app::measurement::*;
 use crate::app::{MaybeAsync, QualifierCode, ResponseHeader, Timestamp, Variation};
 
@@ -118,6 +119,14 @@ pub trait ReadHandler: Send + Sync {
     ) {
     }
 
+    /// Process an object header of `SecurityStatistic` values (g121/g122)
+    fn handle_security_statistic(
+        &mut self,
+        info: HeaderInfo,
+        iter: &mut dyn Iterator<Item = (SecurityStatistic, u16)>,
+    ) {
+    }
+
     /// Process an object header of octet string values
     fn handle_octet_string<'a>(
         &mut self,
@@ -126,6 +135,16 @@ pub trait ReadHandler: Send + Sync {
     ) {
     }
 
+    /// Process an object header of virtual terminal event data (g113)
+    ///
+    /// The index of each item is the virtual terminal port that produced the data.
+    fn handle_virtual_terminal<'a>(
+        &mut self,
+        info: HeaderInfo,
+        iter: &'a mut dyn Iterator<Item = (&'a [u8], u16)>,
+    ) {
+    }
+
     /// Process a device attribute
     fn handle_device_attribute(&mut self, info: HeaderInfo, attr: AnyAttribute) {}
 
@@ -140,6 +159,31 @@ pub trait ReadHandler: Send + Sync {
     /// response contains a count != 1, a warning will be logged and this
     /// callback will not be invoked.
     fn handle_abs_time(&mut self, info: HeaderInfo, time: Timestamp) {}
+
+    /// Process a data set prototype (g85v1)
+    fn handle_data_set_prototype(
+        &mut self,
+        info: HeaderInfo,
+        id: u32,
+        prototype: &DataSetPrototype,
+    ) {
+    }
+
+    /// Process a data set descriptor (g86v1)
+    fn handle_data_set_descriptor(
+        &mut self,
+        info: HeaderInfo,
+        id: u32,
+        descriptor: &DataSetDescriptor,
+    ) {
+    }
+
+    /// Process the values of a data set, either the present value (g87v1) or a snapshot event (g88v1)
+    ///
+    /// The values are typed using the prototypes and descriptors previously read from the
+    /// outstation. Values whose type is unknown are reported as
+    /// [`DataSetValue::OctetString`](crate::app::data_set::DataSetValue::OctetString).
+    fn handle_data_set(&mut self, info: HeaderInfo, id: u32, data_set: &DataSet) {}
 }
 
 pub(crate) fn handle_attribute(
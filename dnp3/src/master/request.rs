@@ -1,9 +1,12 @@
 use crate::app::attr::{AttrSet, OwnedAttribute};
 use std::ops::BitAnd;
 
-use crate::app::control::CommandStatus;
+use crate::app::control::{CommandStatus, PatternControl};
+use crate::app::data_set::{DataSet, Group87Var1, ValueElements};
 use crate::app::format::write::HeaderWriter;
+use crate::app::gen::count::CountVariation;
 use crate::app::gen::prefixed::PrefixedVariation;
+use crate::app::gen::ranged::RangedVariation;
 use crate::app::parse::count::CountSequence;
 use crate::app::parse::parser::{HeaderCollection, HeaderDetails};
 use crate::app::parse::prefix::Prefix;
@@ -13,6 +16,7 @@ use crate::app::Timestamp;
 use crate::app::Variation::Group0;
 use crate::master::error::CommandResponseError;
 use crate::master::TaskError;
+use crate::outstation::database::EventClass;
 use crate::outstation::FreezeInterval;
 
 /// Controls how a command request is issued
@@ -446,6 +450,8 @@ enum Header {
     Read(ReadHeader),
     TimeAndInterval(FreezeInterval),
     Attribute(OwnedAttribute),
+    DataSet(u32, DataSet),
+    VirtualTerminal(u16, Vec<u8>),
 }
 
 impl Header {
@@ -461,6 +467,24 @@ impl Header {
             Header::Attribute(x) => {
                 writer.write_attribute(x)?;
             }
+            Header::DataSet(id, data_set) => {
+                let values = ValueElements::encode(&data_set.values)?;
+                writer.write_free_format(&Group87Var1 {
+                    id: *id,
+                    time: data_set.time,
+                    values: ValueElements::new(&values),
+                })?;
+            }
+            Header::VirtualTerminal(port, data) => {
+                // the length of each block is encoded in the variation
+                for block in data.chunks(u8::MAX as usize) {
+                    writer.write_sized_by_variation(
+                        Variation::Group112(block.len() as u8),
+                        *port,
+                        block,
+                    )?;
+                }
+            }
         }
         Ok(())
     }
@@ -471,6 +495,8 @@ impl Header {
             Header::Read(x) => Some(*x),
             Header::TimeAndInterval(_) => None,
             Header::Attribute(_) => None,
+            Header::DataSet(_, _) => None,
+            Header::VirtualTerminal(_, _) => None,
         }
     }
 }
@@ -557,6 +583,18 @@ impl Headers {
         self.add(Header::Attribute(attr))
     }
 
+    /// Add a free-format header (0x5B) containing the present value of a data set (g87v1)
+    pub fn add_data_set(self, id: u32, data_set: DataSet) -> Self {
+        self.add(Header::DataSet(id, data_set))
+    }
+
+    /// Add virtual terminal output blocks (g112) for a port using a two-byte count and prefix (0x28)
+    ///
+    /// Data longer than 255 bytes is split into multiple headers
+    pub fn add_virtual_terminal(self, port: u16, data: Vec<u8>) -> Self {
+        self.add(Header::VirtualTerminal(port, data))
+    }
+
     pub(crate) fn write(&self, writer: &mut HeaderWriter) -> Result<(), TaskError> {
         for header in self.headers.iter() {
             header.format(writer)?;
@@ -570,6 +608,60 @@ impl Headers {
     }
 }
 
+/// Builder for ASSIGN_CLASS requests
+///
+/// Each call to [`AssignClassRequest::class`] adds a group 60 header. The outstation assigns
+/// the points in the headers that follow it to that class.
+#[derive(Clone, Debug, Default)]
+pub struct AssignClassRequest {
+    headers: Headers,
+}
+
+impl AssignClassRequest {
+    /// Construct an empty request
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Add a group 60 header that assigns subsequent point headers to the specified class
+    ///
+    /// `None` assigns the points to class 0 so that they no longer produce events
+    pub fn class(self, class: Option<EventClass>) -> Self {
+        let variation = match class {
+            None => Variation::Group60Var1,
+            Some(EventClass::Class1) => Variation::Group60Var2,
+            Some(EventClass::Class2) => Variation::Group60Var3,
+            Some(EventClass::Class3) => Variation::Group60Var4,
+        };
+        self.all_objects(variation)
+    }
+
+    /// Add an all objects header (0x06) with the specified variation, e.g. [`Variation::Group30Var0`]
+    pub fn all_objects(self, variation: Variation) -> Self {
+        Self {
+            headers: self.headers.add_all_objects(variation),
+        }
+    }
+
+    /// Add 8-bit start/stop header (0x00) with the specified variation
+    pub fn range_8(self, variation: Variation, start: u8, stop: u8) -> Self {
+        Self {
+            headers: self.headers.add_range_8(variation, start, stop),
+        }
+    }
+
+    /// Add 16-bit start/stop header (0x01) with the specified variation
+    pub fn range_16(self, variation: Variation, start: u16, stop: u16) -> Self {
+        Self {
+            headers: self.headers.add_range_16(variation, start, stop),
+        }
+    }
+
+    pub(crate) fn into_headers(self) -> Headers {
+        self.headers
+    }
+}
+
 /// Enum representing all of the READ request types available from the master API
 #[derive(Clone, Debug)]
 pub enum ReadRequest {
@@ -644,6 +736,9 @@ pub(crate) enum CommandHeader {
     G41V2U16(Vec<(Group41Var2, u16)>),
     G41V3U16(Vec<(Group41Var3, u16)>),
     G41V4U16(Vec<(Group41Var4, u16)>),
+    G12V2(Group12Var2),
+    G12V3U8(u8, u8, Vec<bool>),
+    G12V3U16(u16, u16, Vec<bool>),
 }
 
 pub(crate) trait Command {
@@ -717,6 +812,34 @@ impl Command for Group41Var4 {
     }
 }
 
+impl PatternControl {
+    fn to_headers_u8(&self, start: u8) -> [CommandHeader; 2] {
+        let (stop, mask) = Self::limit_mask(start.into(), u8::MAX.into(), &self.mask);
+        [
+            CommandHeader::G12V2(self.control),
+            CommandHeader::G12V3U8(start, stop as u8, mask),
+        ]
+    }
+
+    fn to_headers_u16(&self, start: u16) -> [CommandHeader; 2] {
+        let (stop, mask) = Self::limit_mask(start, u16::MAX, &self.mask);
+        [
+            CommandHeader::G12V2(self.control),
+            CommandHeader::G12V3U16(start, stop, mask),
+        ]
+    }
+
+    /// limit the mask to the range of the index type, selecting nothing if it's empty
+    fn limit_mask(start: u16, max: u16, mask: &[bool]) -> (u16, Vec<bool>) {
+        let capacity = (max - start) as usize + 1;
+        let mut mask: Vec<bool> = mask.iter().copied().take(capacity).collect();
+        if mask.is_empty() {
+            mask.push(false);
+        }
+        (start + (mask.len() - 1) as u16, mask)
+    }
+}
+
 /// Collection of command headers sent from the master API
 pub struct CommandHeaders {
     headers: Vec<CommandHeader>,
@@ -933,6 +1056,12 @@ impl CommandBuilder {
         }
     }
 
+    fn add_pattern(&mut self, headers: [CommandHeader; 2]) {
+        // the pattern mask must immediately follow the control block
+        self.finish_header();
+        self.headers.extend(headers);
+    }
+
     /// Consume the instance and return a fully built `CommandHeaders`
     pub fn build(mut self) -> CommandHeaders {
         self.finish_header();
@@ -1032,6 +1161,28 @@ impl CommandSupport<Group41Var4> for CommandBuilder {
     }
 }
 
+impl CommandSupport<PatternControl> for CommandBuilder {
+    fn add_u8(&mut self, command: PatternControl, index: u8) {
+        self.add_pattern(command.to_headers_u8(index));
+    }
+
+    fn add_u16(&mut self, command: PatternControl, index: u16) {
+        self.add_pattern(command.to_headers_u16(index));
+    }
+
+    fn single_header_u8(command: PatternControl, index: u8) -> CommandHeaders {
+        CommandHeaders {
+            headers: command.to_headers_u8(index).into(),
+        }
+    }
+
+    fn single_header_u16(command: PatternControl, index: u16) -> CommandHeaders {
+        CommandHeaders {
+            headers: command.to_headers_u16(index).into(),
+        }
+    }
+}
+
 impl Default for CommandBuilder {
     fn default() -> Self {
         Self::new()
@@ -1051,6 +1202,19 @@ impl CommandHeader {
             CommandHeader::G41V2U16(items) => writer.write_prefixed_items(items.iter()),
             CommandHeader::G41V3U16(items) => writer.write_prefixed_items(items.iter()),
             CommandHeader::G41V4U16(items) => writer.write_prefixed_items(items.iter()),
+            CommandHeader::G12V2(control) => writer.write_count_of_one(*control),
+            CommandHeader::G12V3U8(start, stop, mask) => writer.write_packed_bits(
+                Variation::Group12Var3,
+                *start,
+                *stop,
+                mask.iter().copied(),
+            ),
+            CommandHeader::G12V3U16(start, stop, mask) => writer.write_packed_bits(
+                Variation::Group12Var3,
+                *start,
+                *stop,
+                mask.iter().copied(),
+            ),
         }
     }
 
@@ -1085,6 +1249,33 @@ impl CommandHeader {
         Ok(())
     }
 
+    fn compare_pattern_control(
+        seq: CountSequence<'_, Group12Var2>,
+        sent: &Group12Var2,
+    ) -> Result<(), CommandResponseError> {
+        let received = seq
+            .single()
+            .ok_or(CommandResponseError::ObjectCountMismatch)?;
+        if received.status != CommandStatus::Success {
+            return Err(CommandResponseError::BadStatus(received.status));
+        }
+        if received != *sent {
+            return Err(CommandResponseError::ObjectValueMismatch);
+        }
+        Ok(())
+    }
+
+    fn compare_pattern_mask(
+        received: impl Iterator<Item = (bool, u16)>,
+        sent: &[bool],
+    ) -> Result<(), CommandResponseError> {
+        if received.map(|(bit, _)| bit).eq(sent.iter().copied()) {
+            Ok(())
+        } else {
+            Err(CommandResponseError::ObjectValueMismatch)
+        }
+    }
+
     pub(crate) fn compare(&self, response: HeaderDetails) -> Result<(), CommandResponseError> {
         match self {
             CommandHeader::G12V1U8(items) => match response {
@@ -1147,6 +1338,128 @@ impl CommandHeader {
                 }
                 _ => Err(CommandResponseError::HeaderTypeMismatch),
             },
+            CommandHeader::G12V2(control) => match response {
+                HeaderDetails::OneByteCount(_, CountVariation::Group12Var2(seq)) => {
+                    Self::compare_pattern_control(seq, control)
+                }
+                _ => Err(CommandResponseError::HeaderTypeMismatch),
+            },
+            CommandHeader::G12V3U8(start, stop, mask) => match response {
+                HeaderDetails::OneByteStartStop(s, e, RangedVariation::Group12Var3(seq)) => {
+                    if s != *start || e != *stop {
+                        return Err(CommandResponseError::ObjectCountMismatch);
+                    }
+                    Self::compare_pattern_mask(seq.iter(), mask)
+                }
+                _ => Err(CommandResponseError::HeaderTypeMismatch),
+            },
+            CommandHeader::G12V3U16(start, stop, mask) => match response {
+                HeaderDetails::TwoByteStartStop(s, e, RangedVariation::Group12Var3(seq)) => {
+                    if s != *start || e != *stop {
+                        return Err(CommandResponseError::ObjectCountMismatch);
+                    }
+                    Self::compare_pattern_mask(seq.iter(), mask)
+                }
+                _ => Err(CommandResponseError::HeaderTypeMismatch),
+            },
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::app::control::{ControlCode, OpType};
+    use crate::app::format::write::start_response;
+    use crate::app::{ControlField, Iin, ResponseFunction, Sequence};
+    use scursor::WriteCursor;
+
+    fn pattern() -> PatternControl {
+        PatternControl::new(
+            Group12Var2::from_code(ControlCode::from_op_type(OpType::LatchOn)),
+            vec![true, true, false, true],
+        )
+    }
+
+    fn respond_and_compare(
+        sent: &CommandHeaders,
+        response: &CommandHeaders,
+    ) -> Result<(), CommandResponseError> {
+        let mut buffer = [0; 64];
+        let mut cursor = WriteCursor::new(&mut buffer);
+        let mut writer = start_response(
+            ControlField::response(Sequence::default(), true, true, false),
+            ResponseFunction::Response,
+            Iin::default(),
+            &mut cursor,
+        )
+        .unwrap();
+        response.write(&mut writer).unwrap();
+        let response = writer.to_parsed().to_response().unwrap();
+        sent.compare(response.objects.unwrap())
+    }
+
+    #[test]
+    fn formats_pattern_control_block_followed_by_mask() {
+        let mut builder = CommandBuilder::new();
+        builder.add_u8(Group12Var1::from_op_type(OpType::PulseOn), 1);
+        builder.add_u8(pattern(), 2);
+        let headers = builder.build();
+
+        let mut buffer = [0; 64];
+        let mut cursor = WriteCursor::new(&mut buffer);
+        let mut writer = HeaderWriter::new(&mut cursor);
+        headers.write(&mut writer).unwrap();
+
+        // g12v1 with a one byte count and prefix
+        let crob: &[u8] = &[
+            12, 1, 0x17, 0x01, 0x01, 0x01, 0x01, 0xE8, 0x03, 0x00, 0x00, 0xE8, 0x03, 0x00, 0x00,
+            0x00,
+        ];
+        // g12v2 with a count of 1
+        let block: &[u8] = &[
+            12, 2, 0x07, 0x01, 0x03, 0x01, 0xE8, 0x03, 0x00, 0x00, 0xE8, 0x03, 0x00, 0x00, 0x00,
+        ];
+        // g12v3 selecting indices 2, 3 and 5
+        let mask: &[u8] = &[12, 3, 0x00, 0x02, 0x05, 0b0000_1011];
+
+        assert_eq!(writer.written(), [crob, block, mask].concat());
+    }
+
+    #[test]
+    fn pattern_mask_is_limited_to_the_range_of_the_index() {
+        let mut control = pattern();
+        control.mask = vec![true; 10];
+        let headers = CommandBuilder::single_header_u8(control, 250);
+
+        let mut buffer = [0; 64];
+        let mut cursor = WriteCursor::new(&mut buffer);
+        let mut writer = HeaderWriter::new(&mut cursor);
+        headers.write(&mut writer).unwrap();
+
+        assert_eq!(
+            &writer.written()[15..],
+            &[12, 3, 0x00, 250, 255, 0b0011_1111]
+        );
+    }
+
+    #[test]
+    fn compares_echo_of_pattern_control() {
+        let sent = CommandBuilder::single_header_u16(pattern(), 300);
+        assert_eq!(respond_and_compare(&sent, &sent), Ok(()));
+
+        let mut failed = pattern();
+        failed.control.status = CommandStatus::NotSupported;
+        assert_eq!(
+            respond_and_compare(&sent, &CommandBuilder::single_header_u16(failed, 300)),
+            Err(CommandResponseError::BadStatus(CommandStatus::NotSupported))
+        );
+
+        let mut other_mask = pattern();
+        other_mask.mask[2] = true;
+        assert_eq!(
+            respond_and_compare(&sent, &CommandBuilder::single_header_u16(other_mask, 300)),
+            Err(CommandResponseError::ObjectValueMismatch)
+        );
+    }
+}
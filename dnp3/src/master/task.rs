@@ -2,11 +2,12 @@ use tracing::Instrument;
 
 use crate::app::format::write;
 use crate::app::parse::parser::Response;
-use crate::app::{BufferSize, ControlField, Sequence};
+use crate::app::{BufferSize, ControlField, FunctionCode, Sequence};
 use crate::decode::DecodeLevel;
 use crate::link::error::LinkError;
 use crate::link::EndpointAddress;
 use crate::master::association::{AssociationMap, Next};
+use crate::master::auth::AuthMessage;
 use crate::master::error::TaskError;
 use crate::master::messages::{MasterMsg, Message};
 use crate::master::tasks::{AppTask, AssociationTask, NonReadTask, ReadTask, RequestWriter, Task};
@@ -97,6 +98,8 @@ enum ReadResponseAction {
     Ignore,
     ReadNext,
     Complete,
+    /// the request was challenged, the response will have the sequence number of the reply
+    Challenged(Sequence),
 }
 
 impl MasterSession {
@@ -372,7 +375,7 @@ impl MasterSession {
         writer: &mut TransportWriter,
         reader: &mut TransportReader,
     ) -> Result<NextStep, TaskError> {
-        let seq = match self.send_request(io, dest, &task, writer).await {
+        let mut seq = match self.send_request(io, dest, &task, writer).await {
             Ok(seq) => seq,
             Err(err) => {
                 task.on_task_error(self.associations.get_mut(dest.link).ok(), err);
@@ -381,7 +384,7 @@ impl MasterSession {
         };
 
         let timeout = self.associations.get_timeout(dest.link)?;
-        let deadline = timeout.deadline_from_now();
+        let mut deadline = timeout.deadline_from_now();
 
         loop {
             tokio::select! {
@@ -408,6 +411,20 @@ impl MasterSession {
                                 // continue reading responses until timeout
                                 Ok(None) => continue,
                                 Ok(Some(response)) => {
+                                    match self.reply_to_challenge(io, dest, writer, &response).await {
+                                        // wait for the response to the challenged request
+                                        Ok(Some(reply_seq)) => {
+                                            seq = reply_seq;
+                                            deadline = timeout.deadline_from_now();
+                                            continue;
+                                        }
+                                        Ok(None) => {}
+                                        Err(err) => {
+                                            task.on_task_error(self.associations.get_mut(dest.link).ok(), err);
+                                            return Err(err);
+                                        }
+                                    }
+
                                     match self.associations.get_mut(dest.link) {
                                         Err(x) => {
                                             task.on_task_error(None, x.into());
@@ -557,6 +574,11 @@ impl MasterSession {
                                     ReadResponseAction::Ignore => continue,
                                     // read task complete
                                     ReadResponseAction::Complete => return Ok(seq),
+                                    // break to the outer loop and wait for the response to the challenged request
+                                    ReadResponseAction::Challenged(reply_seq) => {
+                                        seq = reply_seq;
+                                        break;
+                                    }
                                     // break to the outer loop and read another response
                                     ReadResponseAction::ReadNext => {
                                         is_first = false;
@@ -614,6 +636,13 @@ impl MasterSession {
             return Ok(ReadResponseAction::Ignore);
         }
 
+        if let Some(reply_seq) = self
+            .reply_to_challenge(io, destination, writer, &response)
+            .await?
+        {
+            return Ok(ReadResponseAction::Challenged(reply_seq));
+        }
+
         // now do validations
 
         if response.header.control.fir && !is_first {
@@ -756,12 +785,50 @@ impl MasterSession {
         let mut cursor = self.tx_buffer.write_cursor();
         let mut hw =
             write::start_request(ControlField::request(seq), request.function(), &mut cursor)?;
-        request.write(&mut hw)?;
+        match association.auth_mut() {
+            Some(auth) => {
+                auth.write_request(request, &mut hw)?;
+                // keep a copy in case the request is challenged
+                auth.record_request(cursor.written());
+            }
+            None => request.write(&mut hw)?,
+        }
         writer
             .write(io, self.decode_level, addr, cursor.written())
             .await?;
         Ok(seq)
     }
+
+    /// Reply to a challenge received in place of the response to the last request
+    ///
+    /// Returns the sequence number of the reply if the response was a challenge
+    async fn reply_to_challenge(
+        &mut self,
+        io: &mut PhysLayer,
+        addr: FragmentAddr,
+        writer: &mut TransportWriter,
+        response: &Response<'_>,
+    ) -> Result<Option<Sequence>, TaskError> {
+        let message = match AuthMessage::parse(response) {
+            Some(x) => x,
+            None => return Ok(None),
+        };
+
+        let association = self.associations.get_mut(addr.link)?;
+        let reply = association.on_auth_message(response.raw_fragment, message)?;
+        let seq = association.increment_seq();
+        let mut cursor = self.tx_buffer.write_cursor();
+        let mut hw = write::start_request(
+            ControlField::request(seq),
+            FunctionCode::AuthRequest,
+            &mut cursor,
+        )?;
+        reply.write(&mut hw)?;
+        writer
+            .write(io, self.decode_level, addr, cursor.written())
+            .await?;
+        Ok(Some(seq))
+    }
 }
 
 // Link status stuff
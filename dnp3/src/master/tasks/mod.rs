@@ -12,7 +12,10 @@ use crate::master::tasks::auto::AutoTask;
 use crate::master::tasks::command::CommandTask;
 use crate::master::tasks::read::SingleReadTask;
 use crate::master::tasks::restart::RestartTask;
+use crate::master::tasks::session_keys::SessionKeyTask;
 use crate::master::tasks::time::TimeSyncTask;
+use crate::master::tasks::update_key::UpdateKeyTask;
+use crate::master::tasks::user_status::UserStatusTask;
 use crate::master::{ReadType, TaskType};
 
 use crate::master::tasks::deadbands::WriteDeadBandsTask;
@@ -33,7 +36,10 @@ pub(crate) mod empty_response;
 pub(crate) mod file;
 pub(crate) mod read;
 pub(crate) mod restart;
+pub(crate) mod session_keys;
 pub(crate) mod time;
+pub(crate) mod update_key;
+pub(crate) mod user_status;
 
 /// Queued task requiring I/O
 pub(crate) struct AssociationTask {
@@ -170,6 +176,12 @@ pub(crate) enum NonReadTask {
     WriteFileBlock(WriteBlockTask),
     /// get info about a file
     GetFileInfo(GetFileInfoTask),
+    /// establish or change the secure authentication session keys
+    SessionKeys(SessionKeyTask),
+    /// add, delete, or change a secure authentication user
+    UserStatus(UserStatusTask),
+    /// change the update key of a secure authentication user
+    UpdateKey(UpdateKeyTask),
 }
 
 impl RequestWriter for ReadTask {
@@ -207,6 +219,9 @@ impl RequestWriter for NonReadTask {
             NonReadTask::CloseFile(t) => t.write(writer)?,
             NonReadTask::WriteFileBlock(t) => t.write(writer)?,
             NonReadTask::AuthFile(t) => t.write(writer)?,
+            NonReadTask::SessionKeys(t) => t.write(writer)?,
+            NonReadTask::UserStatus(t) => t.write(writer)?,
+            NonReadTask::UpdateKey(t) => t.write(writer)?,
         }
         Ok(())
     }
@@ -241,8 +256,14 @@ impl ReadTask {
             }
             ReadTask::SingleRead(task) => match &mut task.custom_handler {
                 Some(handler) => {
-                    extract_measurements(ReadType::SinglePoll, header, objects, handler.as_mut())
-                        .await
+                    extract_measurements(
+                        ReadType::SinglePoll,
+                        header,
+                        objects,
+                        association.data_sets(),
+                        handler.as_mut(),
+                    )
+                    .await
                 }
                 None => association.handle_read_response(header, objects).await,
             },
@@ -309,6 +330,9 @@ impl NonReadTask {
             Self::CloseFile(_) => Some(self),
             Self::WriteFileBlock(_) => Some(self),
             Self::AuthFile(_) => Some(self),
+            Self::SessionKeys(_) => Some(self),
+            Self::UserStatus(_) => Some(self),
+            Self::UpdateKey(_) => Some(self),
         }
     }
 
@@ -326,6 +350,9 @@ impl NonReadTask {
             Self::CloseFile(task) => task.function(),
             Self::WriteFileBlock(task) => task.function(),
             Self::AuthFile(task) => task.function(),
+            Self::SessionKeys(task) => task.function(),
+            Self::UserStatus(task) => task.function(),
+            Self::UpdateKey(task) => task.function(),
         }
     }
 
@@ -343,6 +370,9 @@ impl NonReadTask {
             Self::CloseFile(task) => task.on_task_error(err),
             Self::WriteFileBlock(task) => task.on_task_error(err),
             Self::AuthFile(task) => task.on_task_error(err),
+            Self::SessionKeys(task) => task.on_task_error(association, err),
+            Self::UserStatus(task) => task.on_task_error(err),
+            Self::UpdateKey(task) => task.on_task_error(err),
         }
     }
 
@@ -364,6 +394,9 @@ impl NonReadTask {
             Self::CloseFile(task) => task.handle(response),
             Self::WriteFileBlock(task) => task.handle(response),
             Self::AuthFile(task) => task.handle(response),
+            Self::SessionKeys(task) => task.handle(association, response),
+            Self::UserStatus(task) => task.handle(response),
+            Self::UpdateKey(task) => task.handle(association, response),
         }
     }
 
@@ -385,6 +418,9 @@ impl NonReadTask {
             Self::OpenFile(_) => TaskType::FileOpen,
             Self::CloseFile(_) => TaskType::FileClose,
             Self::WriteFileBlock(_) => TaskType::FileWriteBlock,
+            Self::SessionKeys(_) => TaskType::SessionKeyChange,
+            Self::UserStatus(_) => TaskType::UserStatusChange,
+            Self::UpdateKey(_) => TaskType::UpdateKeyChange,
         }
     }
 }
@@ -0,0 +1,141 @@
+use crate::app::auth::*;
+use crate::app::format::write::HeaderWriter;
+use crate::app::parse::free_format::FreeFormatVariation;
+use crate::app::parse::parser::{HeaderDetails, Response};
+use crate::app::variations::Group120Var4;
+use crate::app::{FunctionCode, ResponseFunction};
+use crate::master::association::Association;
+use crate::master::error::{AuthError, TaskError};
+use crate::master::tasks::{NonReadTask, Task};
+
+/// Change of the session keys that has been sent to the outstation
+pub(crate) struct KeyChange {
+    user: u16,
+    key_change_seq: u32,
+    mac_algorithm: MacAlgorithm,
+    keys: SessionKeys,
+    wrapped_key_data: Vec<u8>,
+}
+
+pub(crate) enum SessionKeyTask {
+    /// Request the status of the session keys (g120v4)
+    StatusRequest(u16),
+    /// Send new session keys wrapped with the update key (g120v6)
+    Change(KeyChange),
+}
+
+impl SessionKeyTask {
+    pub(crate) fn wrap(self) -> Task {
+        NonReadTask::SessionKeys(self).wrap()
+    }
+
+    pub(crate) fn function(&self) -> FunctionCode {
+        FunctionCode::AuthRequest
+    }
+
+    pub(crate) fn write(&self, writer: &mut HeaderWriter) -> Result<(), TaskError> {
+        match self {
+            Self::StatusRequest(user) => writer.write_count_of_one(Group120Var4 { user: *user })?,
+            Self::Change(change) => writer.write_free_format(&Group120Var6 {
+                key_change_seq: change.key_change_seq,
+                user: change.user,
+                wrapped_key_data: &change.wrapped_key_data,
+            })?,
+        }
+        Ok(())
+    }
+
+    pub(crate) fn on_task_error(self, association: Option<&mut Association>, err: TaskError) {
+        if let Some(association) = association {
+            association.on_session_key_change_failure(err);
+        }
+    }
+
+    pub(crate) fn handle(
+        self,
+        association: &mut Association,
+        response: Response,
+    ) -> Result<Option<NonReadTask>, TaskError> {
+        let result = match Self::get_key_status(&response) {
+            None => Err(TaskError::UnexpectedResponseHeaders),
+            Some(status) => match self {
+                Self::StatusRequest(user) => Self::start_change(association, user, &status)
+                    .map(|change| Some(NonReadTask::SessionKeys(Self::Change(change)))),
+                Self::Change(change) => {
+                    Self::verify_change(association, &change, &status).map(|_| {
+                        association.on_session_keys_changed(change.keys);
+                        None
+                    })
+                }
+            },
+        };
+
+        if let Err(err) = result {
+            association.on_session_key_change_failure(err);
+        }
+
+        result
+    }
+
+    fn get_key_status<'a>(response: &Response<'a>) -> Option<Group120Var5<'a>> {
+        if response.header.function != ResponseFunction::AuthResponse {
+            return None;
+        }
+
+        match response.get_only_object_header().ok()?.details {
+            HeaderDetails::TwoByteFreeFormat(1, FreeFormatVariation::Group120Var5(x)) => Some(x),
+            _ => None,
+        }
+    }
+
+    fn start_change(
+        association: &mut Association,
+        user: u16,
+        status: &Group120Var5,
+    ) -> Result<KeyChange, TaskError> {
+        let update_key = association
+            .get_update_key(user)
+            .ok_or(AuthError::NoUpdateKey)?;
+        if KeyWrapAlgorithm::new(status.key_wrap_algorithm) != Some(update_key.algorithm()) {
+            return Err(AuthError::KeyWrapMismatch(status.key_wrap_algorithm).into());
+        }
+        let mac_algorithm = MacAlgorithm::new(status.mac_algorithm)
+            .ok_or(AuthError::UnsupportedMacAlgorithm(status.mac_algorithm))?;
+
+        let keys = SessionKeys::random(update_key.algorithm().key_length());
+        // the status object is included in the wrapped data so the outstation can detect replays
+        let encoded_status = status.encode()?;
+        let wrapped_key_data = wrap_session_keys(&update_key, &keys, &encoded_status);
+
+        Ok(KeyChange {
+            user,
+            key_change_seq: status.key_change_seq,
+            mac_algorithm,
+            keys,
+            wrapped_key_data,
+        })
+    }
+
+    fn verify_change(
+        association: &Association,
+        change: &KeyChange,
+        status: &Group120Var5,
+    ) -> Result<(), TaskError> {
+        if status.key_status != KeyStatus::Ok {
+            return Err(AuthError::BadKeyStatus(status.key_status).into());
+        }
+
+        // the outstation proves it received the keys by authenticating the key change request
+        let request = association.last_auth_request().unwrap_or_default();
+        if !verify_mac(
+            change.mac_algorithm,
+            &change.keys.monitoring,
+            &[request],
+            status.mac,
+        ) {
+            return Err(AuthError::BadMac.into());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,216 @@
+use crate::app::auth::*;
+use crate::app::format::write::HeaderWriter;
+use crate::app::parse::free_format::FreeFormatVariation;
+use crate::app::parse::parser::{HeaderDetails, Response};
+use crate::app::{FunctionCode, ResponseFunction};
+use crate::master::association::Association;
+use crate::master::error::{AuthError, TaskError};
+use crate::master::promise::Promise;
+use crate::master::tasks::{AppTask, NonReadTask, Task};
+use crate::master::KeyChangeAuthority;
+
+/// length of the challenge data sent in the update key change request
+const CHALLENGE_DATA_LENGTH: usize = 4;
+
+/// Proof that the master knows the new update key
+enum Proof {
+    /// MAC calculated with the new update key (g120v15)
+    Mac(Vec<u8>),
+    /// signature calculated with the private key of the user (g120v14)
+    Signature(Vec<u8>),
+}
+
+/// Update key change that has been sent to the outstation
+struct Change {
+    key_change_seq: u32,
+    user: u16,
+    outstation_challenge_data: Vec<u8>,
+    encrypted_key_data: Vec<u8>,
+    proof: Proof,
+}
+
+enum State {
+    /// Request the change for a user name (g120v11)
+    Request,
+    /// Send the encrypted update key (g120v13) and its proof
+    Change(Box<Change>),
+}
+
+/// Change the update key of a user on behalf of the authority
+pub(crate) struct UpdateKeyTask {
+    user_name: String,
+    outstation_name: String,
+    update_key: UpdateKey,
+    authority: KeyChangeAuthority,
+    challenge_data: Vec<u8>,
+    state: State,
+    promise: Promise<Result<u16, TaskError>>,
+}
+
+impl From<UpdateKeyTask> for Task {
+    fn from(value: UpdateKeyTask) -> Self {
+        Task::App(AppTask::NonRead(NonReadTask::UpdateKey(value)))
+    }
+}
+
+impl UpdateKeyTask {
+    pub(crate) fn new(
+        user_name: String,
+        outstation_name: String,
+        update_key: UpdateKey,
+        authority: KeyChangeAuthority,
+        promise: Promise<Result<u16, TaskError>>,
+    ) -> Self {
+        Self {
+            user_name,
+            outstation_name,
+            update_key,
+            authority,
+            challenge_data: random_bytes(CHALLENGE_DATA_LENGTH),
+            state: State::Request,
+            promise,
+        }
+    }
+
+    pub(crate) fn function(&self) -> FunctionCode {
+        FunctionCode::AuthRequest
+    }
+
+    pub(crate) fn write(&self, writer: &mut HeaderWriter) -> Result<(), TaskError> {
+        match &self.state {
+            State::Request => writer.write_free_format(&Group120Var11 {
+                key_change_method: self.authority.method().as_u8(),
+                user_name: self.user_name.as_bytes(),
+                challenge_data: &self.challenge_data,
+            })?,
+            State::Change(change) => {
+                writer.write_free_format(&Group120Var13 {
+                    key_change_seq: change.key_change_seq,
+                    user: change.user,
+                    encrypted_key_data: &change.encrypted_key_data,
+                })?;
+                match &change.proof {
+                    Proof::Mac(mac) => writer.write_free_format(&Group120Var15 { mac })?,
+                    Proof::Signature(signature) => {
+                        writer.write_free_format(&Group120Var14 { signature })?
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn on_task_error(self, err: TaskError) {
+        self.promise.complete(Err(err))
+    }
+
+    pub(crate) fn handle(
+        mut self,
+        association: &mut Association,
+        response: Response,
+    ) -> Result<Option<NonReadTask>, TaskError> {
+        let result = match std::mem::replace(&mut self.state, State::Request) {
+            State::Request => match Self::get_reply(&response) {
+                None => Err(TaskError::UnexpectedResponseHeaders),
+                Some(reply) => {
+                    let change = self.start_change(&reply);
+                    self.state = State::Change(Box::new(change));
+                    return Ok(Some(NonReadTask::UpdateKey(self)));
+                }
+            },
+            State::Change(change) => self.verify_change(&response, &change).map(|_| {
+                association.on_update_key_changed(change.user, self.update_key.clone());
+                change.user
+            }),
+        };
+
+        let err = result.err();
+        self.promise.complete(result);
+        match err {
+            Some(err) => Err(err),
+            None => Ok(None),
+        }
+    }
+
+    fn get_auth_object<'a>(response: &Response<'a>) -> Option<FreeFormatVariation<'a>> {
+        if response.header.function != ResponseFunction::AuthResponse {
+            return None;
+        }
+
+        match response.get_only_object_header().ok()?.details {
+            HeaderDetails::TwoByteFreeFormat(1, x) => Some(x),
+            _ => None,
+        }
+    }
+
+    fn get_reply<'a>(response: &Response<'a>) -> Option<Group120Var12<'a>> {
+        match Self::get_auth_object(response)? {
+            FreeFormatVariation::Group120Var12(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    fn start_change(&mut self, reply: &Group120Var12) -> Change {
+        let plaintext = update_key_data(
+            &self.update_key,
+            self.user_name.as_bytes(),
+            reply.challenge_data,
+        );
+
+        let (encrypted_key_data, proof) = match &mut self.authority {
+            KeyChangeAuthority::Symmetric(key) => {
+                let encrypted = wrap_update_key(key, &plaintext);
+                let mac = update_key_confirmation(
+                    &self.update_key,
+                    self.outstation_name.as_bytes(),
+                    &self.challenge_data,
+                    reply.challenge_data,
+                    reply.key_change_seq,
+                    reply.user,
+                );
+                (encrypted, Proof::Mac(mac))
+            }
+            KeyChangeAuthority::Asymmetric(_, crypto) => {
+                let encrypted = crypto.encrypt(&plaintext);
+                let signature = crypto.sign(&update_key_signature_data(
+                    &self.challenge_data,
+                    reply.challenge_data,
+                    reply.key_change_seq,
+                    reply.user,
+                    &encrypted,
+                ));
+                (encrypted, Proof::Signature(signature))
+            }
+        };
+
+        Change {
+            key_change_seq: reply.key_change_seq,
+            user: reply.user,
+            outstation_challenge_data: reply.challenge_data.to_vec(),
+            encrypted_key_data,
+            proof,
+        }
+    }
+
+    fn verify_change(&self, response: &Response, change: &Change) -> Result<(), TaskError> {
+        let mac = match Self::get_auth_object(response) {
+            Some(FreeFormatVariation::Group120Var15(x)) => x.mac,
+            _ => return Err(TaskError::UnexpectedResponseHeaders),
+        };
+
+        // the outstation proves that it decrypted the same key
+        if !verify_update_key_confirmation(
+            &self.update_key,
+            self.user_name.as_bytes(),
+            &change.outstation_challenge_data,
+            &self.challenge_data,
+            change.key_change_seq,
+            change.user,
+            mac,
+        ) {
+            return Err(AuthError::BadMac.into());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,83 @@
+use crate::app::auth::*;
+use crate::app::format::write::HeaderWriter;
+use crate::app::parse::parser::Response;
+use crate::app::FunctionCode;
+use crate::master::promise::Promise;
+use crate::master::tasks::{AppTask, NonReadTask, Task};
+use crate::master::{KeyChangeAuthority, TaskError, UserStatusChange};
+
+/// Change the status of a user on behalf of the authority (g120v10)
+pub(crate) struct UserStatusTask {
+    key_change_method: KeyChangeMethod,
+    change: UserStatusChange,
+    certification_data: Vec<u8>,
+    promise: Promise<Result<(), TaskError>>,
+}
+
+impl From<UserStatusTask> for Task {
+    fn from(value: UserStatusTask) -> Self {
+        Task::App(AppTask::NonRead(NonReadTask::UserStatus(value)))
+    }
+}
+
+impl UserStatusTask {
+    pub(crate) fn new(
+        change: UserStatusChange,
+        mut authority: KeyChangeAuthority,
+        promise: Promise<Result<(), TaskError>>,
+    ) -> Result<Self, TaskError> {
+        let key_change_method = authority.method();
+        let mut task = Self {
+            key_change_method,
+            change,
+            certification_data: Vec::new(),
+            promise,
+        };
+
+        let data = task.object().certified_data()?;
+        task.certification_data = match &mut authority {
+            KeyChangeAuthority::Symmetric(key) => hmac_sha256(key.data(), &[&data]),
+            KeyChangeAuthority::Asymmetric(_, crypto) => crypto.certify(&data),
+        };
+
+        Ok(task)
+    }
+
+    fn object(&self) -> Group120Var10<'_> {
+        Group120Var10 {
+            key_change_method: self.key_change_method.as_u8(),
+            operation: self.change.operation.as_u8(),
+            status_change_seq: self.change.status_change_seq,
+            role: self.change.role.as_u16(),
+            role_expiry_days: self.change.role_expiry_days,
+            user_name: self.change.user_name.as_bytes(),
+            user_public_key: &self.change.user_public_key,
+            certification_data: &self.certification_data,
+        }
+    }
+
+    pub(crate) fn function(&self) -> FunctionCode {
+        FunctionCode::AuthRequest
+    }
+
+    pub(crate) fn write(&self, writer: &mut HeaderWriter) -> Result<(), TaskError> {
+        writer.write_free_format(&self.object())?;
+        Ok(())
+    }
+
+    pub(crate) fn on_task_error(self, err: TaskError) {
+        self.promise.complete(Err(err))
+    }
+
+    pub(crate) fn handle(self, response: Response) -> Result<Option<NonReadTask>, TaskError> {
+        // the outstation accepts the change with an empty response
+        if !response.raw_objects.is_empty() {
+            self.promise
+                .complete(Err(TaskError::UnexpectedResponseHeaders));
+            return Err(TaskError::UnexpectedResponseHeaders);
+        }
+
+        self.promise.complete(Ok(()));
+        Ok(None)
+    }
+}
@@ -0,0 +1,59 @@
+use crate::app::{Iin, Iin1, Iin2, Sequence, Variation};
+use crate::master::association::AssociationConfig;
+use crate::master::{AssignClassRequest, AssociationHandle, TaskError, WriteError};
+use crate::outstation::database::EventClass;
+
+use super::harness::create_association;
+use super::harness::requests::{empty_response, empty_response_custom_iin};
+
+fn request() -> AssignClassRequest {
+    AssignClassRequest::new()
+        .class(Some(EventClass::Class2))
+        .all_objects(Variation::Group1Var0)
+        .class(None)
+        .range_8(Variation::Group30Var0, 3, 7)
+}
+
+const REQUEST: &[u8] = &[
+    0xC0, 22, 60, 3, 0x06, 1, 0, 0x06, 60, 1, 0x06, 30, 0, 0x00, 3, 7,
+];
+
+fn spawn_assign_class_task(
+    mut association: AssociationHandle,
+) -> tokio::task::JoinHandle<Result<(), WriteError>> {
+    tokio::spawn(async move { association.assign_class(request()).await })
+}
+
+#[tokio::test]
+async fn can_assign_class() {
+    let mut harness = create_association(AssociationConfig::quiet()).await;
+
+    let task = spawn_assign_class_task(harness.association.clone());
+
+    harness
+        .expect_write_and_respond(REQUEST.to_vec(), empty_response(Sequence::new(0)))
+        .await;
+
+    assert_eq!(task.await.unwrap(), Ok(()));
+}
+
+#[tokio::test]
+async fn returns_iin2_error_from_outstation() {
+    let mut harness = create_association(AssociationConfig::quiet()).await;
+
+    let task = spawn_assign_class_task(harness.association.clone());
+
+    let iin = Iin::new(Iin1::default(), Iin2::PARAMETER_ERROR);
+
+    harness
+        .expect_write_and_respond(
+            REQUEST.to_vec(),
+            empty_response_custom_iin(Sequence::new(0), iin),
+        )
+        .await;
+
+    assert_eq!(
+        task.await.unwrap(),
+        Err(WriteError::Task(TaskError::RejectedByIin2(iin)))
+    );
+}
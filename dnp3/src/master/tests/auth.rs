@@ -0,0 +1,410 @@
+use std::collections::HashMap;
+
+use crate::app::auth::*;
+use crate::app::{FunctionCode, Sequence};
+use crate::master::association::AssociationConfig;
+use crate::master::tests::harness::{create_association_with_handler, AssocInfoEvent, TestHarness};
+use crate::master::{
+    AssociationHandler, AuthConfig, AuthError, KeyChangeAuthority, TaskError, TaskType,
+    UpdateKeyStore, UserStatusChange, WriteError,
+};
+
+use super::harness::requests::empty_response;
+
+const UPDATE_KEY: [u8; 16] = [0xAB; 16];
+const CHALLENGE_DATA: [u8; 4] = [0xDE, 0xAD, 0xBE, 0xEF];
+const KEY_STATUS_OK: u8 = 1;
+const KEY_STATUS_NOT_INIT: u8 = 2;
+const AUTHORITY_KEY: [u8; 32] = [0x11; 32];
+const NEW_UPDATE_KEY: [u8; 32] = [0x22; 32];
+const OUTSTATION_CHALLENGE_DATA: [u8; 4] = [0x0A, 0x0B, 0x0C, 0x0D];
+const OUTSTATION_NAME: &str = "outstation";
+
+// write "ls" to virtual terminal port 7
+const WRITE_PORT_7: &[u8] = &[112, 2, 0x28, 0x01, 0x00, 0x07, 0x00, b'l', b's'];
+
+struct KeyStoreHandler(HashMap<u16, UpdateKey>);
+
+impl AssociationHandler for KeyStoreHandler {
+    fn update_key_store(&mut self) -> Option<&mut dyn UpdateKeyStore> {
+        Some(&mut self.0)
+    }
+}
+
+async fn create_harness(aggressive_mode: bool) -> TestHarness {
+    let mut config = AssociationConfig::quiet();
+    config.authentication = Some(AuthConfig {
+        aggressive_mode,
+        ..AuthConfig::default()
+    });
+    let keys = HashMap::from([(DEFAULT_USER, UpdateKey::aes128(UPDATE_KEY))]);
+    create_association_with_handler(config, Box::new(KeyStoreHandler(keys))).await
+}
+
+fn auth_response(seq: u8, variation: u8, object: &[u8]) -> Vec<u8> {
+    let mut data = vec![0xC0 | seq, 0x83, 0x00, 0x00, 120, variation, 0x5B, 0x01];
+    data.extend_from_slice(&(object.len() as u16).to_le_bytes());
+    data.extend_from_slice(object);
+    data
+}
+
+fn key_status(key_change_seq: u32, status: u8, mac: &[u8]) -> Vec<u8> {
+    let mut object = key_change_seq.to_le_bytes().to_vec();
+    object.extend_from_slice(&DEFAULT_USER.to_le_bytes());
+    // AES-128 key wrap and HMAC-SHA-256 truncated to 16 octets
+    object.extend_from_slice(&[0x01, status, 0x04, 0x04, 0x00]);
+    object.extend_from_slice(&CHALLENGE_DATA);
+    object.extend_from_slice(mac);
+    object
+}
+
+fn challenge(challenge_seq: u32) -> Vec<u8> {
+    let mut object = challenge_seq.to_le_bytes().to_vec();
+    object.extend_from_slice(&DEFAULT_USER.to_le_bytes());
+    // HMAC-SHA-256 truncated to 16 octets, critical request
+    object.extend_from_slice(&[0x04, 0x01]);
+    object.extend_from_slice(&CHALLENGE_DATA);
+    object
+}
+
+fn write_request(seq: u8) -> Vec<u8> {
+    let mut request = vec![0xC0 | seq, 0x02];
+    request.extend_from_slice(WRITE_PORT_7);
+    request
+}
+
+/// run the session key status and change exchange returning the keys chosen by the master
+async fn change_session_keys(harness: &mut TestHarness, seq: u8) -> SessionKeys {
+    // session key status request (g120v4) for the default user
+    harness
+        .expect_write_and_respond(
+            vec![0xC0 | seq, 0x20, 120, 4, 0x07, 0x01, 0x01, 0x00],
+            auth_response(seq, 5, &key_status(7, KEY_STATUS_NOT_INIT, &[])),
+        )
+        .await;
+
+    // session key change (g120v6)
+    let request = harness.pop_write().await;
+    assert_eq!(request[0..6], [0xC0 | (seq + 1), 0x20, 120, 6, 0x5B, 0x01]);
+    assert_eq!(request[8..14], [0x07, 0x00, 0x00, 0x00, 0x01, 0x00]);
+
+    let (keys, remainder) =
+        unwrap_session_keys(&UpdateKey::aes128(UPDATE_KEY), &request[14..]).unwrap();
+    assert!(remainder.starts_with(&key_status(7, KEY_STATUS_NOT_INIT, &[])));
+
+    let mac = calc_mac(
+        MacAlgorithm::HmacSha256Trunc16,
+        &keys.monitoring,
+        &[&request],
+    );
+    harness
+        .process_response(auth_response(
+            seq + 1,
+            5,
+            &key_status(8, KEY_STATUS_OK, &mac),
+        ))
+        .await;
+
+    keys
+}
+
+/// write to the virtual terminal and reply to the challenge returning the challenge fragment
+async fn challenge_write(harness: &mut TestHarness, keys: &SessionKeys, seq: u8) -> Vec<u8> {
+    let mut association = harness.association.clone();
+    let task =
+        tokio::spawn(async move { association.write_virtual_terminal(7, b"ls".to_vec()).await });
+
+    let challenge = auth_response(seq, 1, &challenge(1));
+    harness
+        .expect_write_and_respond(write_request(seq), challenge.clone())
+        .await;
+
+    let mac = calc_mac(
+        MacAlgorithm::HmacSha256Trunc16,
+        &keys.control,
+        &[&challenge, &write_request(seq)],
+    );
+    let mut reply = vec![0xC0 | (seq + 1), 0x20, 120, 2, 0x5B, 0x01, 22, 0x00];
+    reply.extend_from_slice(&[0x01, 0x00, 0x00, 0x00, 0x01, 0x00]);
+    reply.extend_from_slice(&mac);
+
+    harness
+        .expect_write_and_respond(reply, empty_response(Sequence::new(seq + 1)))
+        .await;
+
+    assert_eq!(task.await.unwrap(), Ok(()));
+    challenge
+}
+
+#[tokio::test]
+async fn changes_session_keys_and_replies_to_challenge() {
+    let mut harness = create_harness(false).await;
+    let keys = change_session_keys(&mut harness, 0).await;
+    challenge_write(&mut harness, &keys, 2).await;
+
+    assert_eq!(
+        harness.assoc_events.pop(),
+        vec![
+            AssocInfoEvent::TaskStart(
+                TaskType::SessionKeyChange,
+                FunctionCode::AuthRequest,
+                Sequence::new(0)
+            ),
+            AssocInfoEvent::TaskSuccess(
+                TaskType::SessionKeyChange,
+                FunctionCode::AuthRequest,
+                Sequence::new(1)
+            ),
+            AssocInfoEvent::TaskStart(
+                TaskType::GenericEmptyResponse(FunctionCode::Write),
+                FunctionCode::Write,
+                Sequence::new(2)
+            ),
+            // the response to the challenged request has the sequence number of the reply
+            AssocInfoEvent::TaskSuccess(
+                TaskType::GenericEmptyResponse(FunctionCode::Write),
+                FunctionCode::Write,
+                Sequence::new(3)
+            ),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn uses_aggressive_mode_after_first_challenge() {
+    let mut harness = create_harness(true).await;
+    let keys = change_session_keys(&mut harness, 0).await;
+    let challenge = challenge_write(&mut harness, &keys, 2).await;
+
+    let mut association = harness.association.clone();
+    let task =
+        tokio::spawn(async move { association.write_virtual_terminal(7, b"ls".to_vec()).await });
+
+    // g120v3 with the next challenge sequence number, the objects, and then g120v9
+    let mut request = vec![0xC4, 0x02, 120, 3, 0x07, 0x01];
+    request.extend_from_slice(&[0x02, 0x00, 0x00, 0x00, 0x01, 0x00]);
+    request.extend_from_slice(WRITE_PORT_7);
+    let mac = calc_mac(
+        MacAlgorithm::HmacSha256Trunc16,
+        &keys.control,
+        &[&challenge, &request],
+    );
+    request.extend_from_slice(&[120, 9, 0x5B, 0x01, 16, 0x00]);
+    request.extend_from_slice(&mac);
+
+    harness
+        .expect_write_and_respond(request, empty_response(Sequence::new(4)))
+        .await;
+    assert_eq!(task.await.unwrap(), Ok(()));
+}
+
+#[tokio::test]
+async fn error_response_fails_task_and_changes_session_keys() {
+    let mut harness = create_harness(false).await;
+    let keys = change_session_keys(&mut harness, 0).await;
+
+    let mut association = harness.association.clone();
+    let task =
+        tokio::spawn(async move { association.write_virtual_terminal(7, b"ls".to_vec()).await });
+
+    // authorization failed error (g120v7) with an empty text
+    let mut error = vec![0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x07];
+    error.extend_from_slice(&[0x00; 6]);
+    harness
+        .expect_write_and_respond(write_request(2), auth_response(2, 7, &error))
+        .await;
+
+    assert_eq!(
+        task.await.unwrap(),
+        Err(WriteError::Task(TaskError::Authentication(
+            AuthError::Rejected(AuthErrorCode::AuthorizationFailed)
+        )))
+    );
+
+    let new_keys = change_session_keys(&mut harness, 3).await;
+    assert!(new_keys != keys);
+}
+
+#[tokio::test]
+async fn key_change_fails_without_update_key() {
+    let mut config = AssociationConfig::quiet();
+    config.authentication = Some(AuthConfig::new(2));
+    let keys = HashMap::from([(DEFAULT_USER, UpdateKey::aes128(UPDATE_KEY))]);
+    let mut harness =
+        create_association_with_handler(config, Box::new(KeyStoreHandler(keys))).await;
+
+    let mut status = key_status(7, KEY_STATUS_NOT_INIT, &[]);
+    status[4..6].copy_from_slice(&[0x02, 0x00]);
+    harness
+        .expect_write_and_respond(
+            vec![0xC0, 0x20, 120, 4, 0x07, 0x01, 0x02, 0x00],
+            auth_response(0, 5, &status),
+        )
+        .await;
+
+    // the master retries after the back-off without sending a key change
+    harness
+        .expect_write(vec![0xC1, 0x20, 120, 4, 0x07, 0x01, 0x02, 0x00])
+        .await;
+
+    assert_eq!(
+        harness.assoc_events.pop()[1],
+        AssocInfoEvent::TaskFailure(
+            TaskType::SessionKeyChange,
+            TaskError::Authentication(AuthError::NoUpdateKey)
+        )
+    );
+}
+
+fn authority() -> KeyChangeAuthority {
+    KeyChangeAuthority::Symmetric(AuthorityKey::new(AUTHORITY_KEY))
+}
+
+/// spawn an update key change of "bob" to a new AES-256 update key
+async fn request_update_key_change(
+    harness: &mut TestHarness,
+) -> tokio::task::JoinHandle<Result<u16, TaskError>> {
+    let mut association = harness.association.clone();
+    tokio::spawn(async move {
+        association
+            .change_update_key(
+                "bob".to_string(),
+                OUTSTATION_NAME.to_string(),
+                UpdateKey::aes256(NEW_UPDATE_KEY),
+                authority(),
+            )
+            .await
+    })
+}
+
+/// check the update key change request (g120v11) and respond with a reply (g120v12)
+async fn reply_to_update_key_change_request(harness: &mut TestHarness) -> Vec<u8> {
+    let request = harness.pop_write().await;
+    assert_eq!(
+        request[0..16],
+        [
+            0xC2, 0x20, 120, 11, 0x5B, 0x01, 12, 0x00, 0x04, 0x03, 0x00, 0x04, 0x00, b'b', b'o',
+            b'b'
+        ]
+    );
+    let master_challenge_data = request[16..].to_vec();
+    assert_eq!(master_challenge_data.len(), 4);
+
+    // key change sequence number 5 for the default user
+    let mut reply = vec![0x05, 0x00, 0x00, 0x00, 0x01, 0x00, 0x04, 0x00];
+    reply.extend_from_slice(&OUTSTATION_CHALLENGE_DATA);
+    harness.process_response(auth_response(2, 12, &reply)).await;
+    master_challenge_data
+}
+
+/// check the update key change (g120v13) and its confirmation (g120v15)
+async fn expect_update_key_change(harness: &mut TestHarness, master_challenge_data: &[u8]) {
+    let request = harness.pop_write().await;
+    assert_eq!(request[0..6], [0xC3, 0x20, 120, 13, 0x5B, 0x01]);
+    assert_eq!(request[8..14], [0x05, 0x00, 0x00, 0x00, 0x01, 0x00]);
+
+    let length = u16::from_le_bytes([request[14], request[15]]) as usize;
+    let (encrypted, proof) = request[16..].split_at(length);
+    let plaintext = unwrap_update_key(&AuthorityKey::new(AUTHORITY_KEY), encrypted).unwrap();
+    assert!(
+        parse_update_key_data(&plaintext, b"bob", &OUTSTATION_CHALLENGE_DATA)
+            == Some(UpdateKey::aes256(NEW_UPDATE_KEY))
+    );
+
+    assert_eq!(proof[0..6], [120, 15, 0x5B, 0x01, 32, 0x00]);
+    let mac = update_key_confirmation(
+        &UpdateKey::aes256(NEW_UPDATE_KEY),
+        OUTSTATION_NAME.as_bytes(),
+        master_challenge_data,
+        &OUTSTATION_CHALLENGE_DATA,
+        5,
+        DEFAULT_USER,
+    );
+    assert_eq!(proof[6..], mac);
+}
+
+#[tokio::test]
+async fn changes_user_status_with_symmetric_method() {
+    let mut harness = create_harness(false).await;
+    change_session_keys(&mut harness, 0).await;
+
+    let mut association = harness.association.clone();
+    let task = tokio::spawn(async move {
+        let change = UserStatusChange::new(
+            UserStatusOperation::Add,
+            1,
+            "bob".to_string(),
+            UserRole::Operator,
+            30,
+        );
+        association.change_user_status(change, authority()).await
+    });
+
+    let mut object = vec![0x04, 0x01, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 30, 0x00];
+    object.extend_from_slice(&[0x03, 0x00, 0x00, 0x00, 0x00, 0x00]);
+    object.extend_from_slice(b"bob");
+    let certification = hmac_sha256(&AUTHORITY_KEY, &[&object]);
+    object[14] = 32;
+    object.extend_from_slice(&certification);
+
+    let mut request = vec![0xC2, 0x20, 120, 10, 0x5B, 0x01];
+    request.extend_from_slice(&(object.len() as u16).to_le_bytes());
+    request.extend_from_slice(&object);
+
+    harness
+        .expect_write_and_respond(request, empty_response(Sequence::new(2)))
+        .await;
+    assert_eq!(task.await.unwrap(), Ok(()));
+}
+
+#[tokio::test]
+async fn changes_update_key_and_then_session_keys() {
+    let mut harness = create_harness(false).await;
+    change_session_keys(&mut harness, 0).await;
+
+    let task = request_update_key_change(&mut harness).await;
+    let master_challenge_data = reply_to_update_key_change_request(&mut harness).await;
+    expect_update_key_change(&mut harness, &master_challenge_data).await;
+
+    let mac = update_key_confirmation(
+        &UpdateKey::aes256(NEW_UPDATE_KEY),
+        b"bob",
+        &OUTSTATION_CHALLENGE_DATA,
+        &master_challenge_data,
+        5,
+        DEFAULT_USER,
+    );
+    harness.process_response(auth_response(3, 15, &mac)).await;
+    assert_eq!(task.await.unwrap(), Ok(DEFAULT_USER));
+
+    // the session keys of the user are changed right away with the new update key
+    harness
+        .expect_write(vec![0xC4, 0x20, 120, 4, 0x07, 0x01, 0x01, 0x00])
+        .await;
+}
+
+#[tokio::test]
+async fn update_key_change_fails_with_bad_confirmation() {
+    let mut harness = create_harness(false).await;
+    change_session_keys(&mut harness, 0).await;
+
+    let task = request_update_key_change(&mut harness).await;
+    let master_challenge_data = reply_to_update_key_change_request(&mut harness).await;
+    expect_update_key_change(&mut harness, &master_challenge_data).await;
+
+    // the outstation must authenticate its own challenge data first
+    let mac = update_key_confirmation(
+        &UpdateKey::aes256(NEW_UPDATE_KEY),
+        b"bob",
+        &master_challenge_data,
+        &OUTSTATION_CHALLENGE_DATA,
+        5,
+        DEFAULT_USER,
+    );
+    harness.process_response(auth_response(3, 15, &mac)).await;
+    assert_eq!(
+        task.await.unwrap(),
+        Err(TaskError::Authentication(AuthError::BadMac))
+    );
+    harness.assert_no_events();
+}
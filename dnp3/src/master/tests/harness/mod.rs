@@ -22,7 +22,14 @@ pub(crate) mod requests;
 struct DefaultAssociationHandler;
 impl AssociationHandler for DefaultAssociationHandler {}
 
-pub(crate) async fn create_association(mut config: AssociationConfig) -> TestHarness {
+pub(crate) async fn create_association(config: AssociationConfig) -> TestHarness {
+    create_association_with_handler(config, Box::new(DefaultAssociationHandler)).await
+}
+
+pub(crate) async fn create_association_with_handler(
+    mut config: AssociationConfig,
+    assoc_handler: Box<dyn AssociationHandler>,
+) -> TestHarness {
     // use a 1-second timeout for all tests
     config.response_timeout = Timeout::from_secs(1).unwrap();
 
@@ -70,7 +77,7 @@ pub(crate) async fn create_association(mut config: AssociationConfig) -> TestHar
             outstation_address,
             config,
             Box::new(handler),
-            Box::new(DefaultAssociationHandler),
+            assoc_handler,
             Box::new(info),
         )
         .await
@@ -1,6 +1,9 @@
 mod harness;
 
+mod assign_class;
+mod auth;
 mod auto_tasks;
 mod startup;
+mod virtual_terminal;
 
 mod file;
@@ -0,0 +1,28 @@
+use crate::app::Sequence;
+use crate::master::association::AssociationConfig;
+
+use super::harness::create_association;
+use super::harness::requests::empty_response;
+
+#[tokio::test]
+async fn writes_output_block_to_port() {
+    let mut harness = create_association(AssociationConfig::quiet()).await;
+
+    let mut association = harness.association.clone();
+    let task = tokio::spawn(async move {
+        association
+            .write_virtual_terminal(7, b"ls\r\n".to_vec())
+            .await
+    });
+
+    harness
+        .expect_write_and_respond(
+            vec![
+                0xC0, 0x02, 112, 4, 0x28, 0x01, 0x00, 0x07, 0x00, b'l', b's', b'\r', b'\n',
+            ],
+            empty_response(Sequence::new(0)),
+        )
+        .await;
+
+    assert_eq!(task.await.unwrap(), Ok(()));
+}
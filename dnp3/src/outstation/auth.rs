@@ -0,0 +1,892 @@
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime};
+
+use tokio::time::Instant;
+
+use crate::app::auth::*;
+use crate::app::format::write::HeaderWriter;
+use crate::app::gen::count::CountVariation;
+use crate::app::parse::free_format::FreeFormatVariation;
+use crate::app::parse::options::ParseOptions;
+use crate::app::parse::parser::{HeaderCollection, HeaderDetails};
+use crate::app::{FunctionCode, ObjectParseError, RequestHeader, Sequence, Timestamp};
+
+/// length of the challenge data in challenges and session key status objects
+const CHALLENGE_DATA_LENGTH: usize = 4;
+/// g120v3 header with a count of one and the object itself
+const AGGRESSIVE_MODE_HEADER_LENGTH: usize = 10;
+/// g120v9 header with a two-byte free-format length
+const MAC_HEADER_LENGTH: usize = 6;
+
+/// Secure authentication (SAv5) configuration of an outstation
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct AuthConfig {
+    /// MAC algorithm the master must use to reply to challenges and authenticate session keys
+    #[cfg_attr(
+        feature = "serialization",
+        serde(default = "AuthConfig::default_mac_algorithm")
+    )]
+    pub mac_algorithm: MacAlgorithm,
+    /// Period after which session keys the master hasn't changed are no longer accepted
+    ///
+    /// This should be comfortably longer than the key change interval of the master
+    #[cfg_attr(
+        feature = "serialization",
+        serde(default = "AuthConfig::default_session_key_timeout")
+    )]
+    pub session_key_timeout: Duration,
+    /// Accept critical requests that the master authenticates in aggressive mode
+    #[cfg_attr(
+        feature = "serialization",
+        serde(default = "AuthConfig::default_aggressive_mode")
+    )]
+    pub aggressive_mode: bool,
+}
+
+impl AuthConfig {
+    const fn default_mac_algorithm() -> MacAlgorithm {
+        MacAlgorithm::HmacSha256Trunc16
+    }
+
+    const fn default_session_key_timeout() -> Duration {
+        Duration::from_secs(30 * 60)
+    }
+
+    const fn default_aggressive_mode() -> bool {
+        true
+    }
+}
+
+impl Default for AuthConfig {
+    fn default() -> Self {
+        Self {
+            mac_algorithm: Self::default_mac_algorithm(),
+            session_key_timeout: Self::default_session_key_timeout(),
+            aggressive_mode: Self::default_aggressive_mode(),
+        }
+    }
+}
+
+/// Users, update keys, and authorization policy of an outstation using secure authentication
+///
+/// Provided to the outstation by [`OutstationApplication::auth_handler`](crate::outstation::OutstationApplication::auth_handler)
+pub trait AuthHandler: Send + Sync + 'static {
+    /// Retrieve the update key shared with the master for a user
+    ///
+    /// Returning `None` means that the user is unknown and the master
+    /// cannot establish session keys on its behalf
+    fn get_update_key(&mut self, user: u16) -> Option<UpdateKey>;
+
+    /// Decide if an authenticated user may perform a critical function
+    ///
+    /// Applications that implement role-based access control look up the role of the user
+    /// here. Rejected requests are answered with an authorization error (g120v7) and are
+    /// not processed. The default implementation authorizes every function.
+    #[allow(unused_variables)]
+    fn is_authorized(&mut self, user: u16, function: FunctionCode) -> bool {
+        true
+    }
+
+    /// Returns the store of users that the authority manages remotely through the master
+    ///
+    /// Returning `None` (the default) means that user status changes and update key changes
+    /// are rejected. The update keys of the users in the store take precedence over the ones
+    /// returned by [`Self::get_update_key`].
+    fn key_store(&mut self) -> Option<&mut dyn KeyStore> {
+        None
+    }
+}
+
+/// User of an outstation whose status is managed by the authority
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct User {
+    /// Number (USR) that identifies the user in authentication messages
+    pub number: u16,
+    /// Globally unique name assigned by the authority
+    pub name: String,
+    /// Role of the user
+    pub role: UserRole,
+    /// Number of days after which the role expires
+    pub role_expiry_days: u16,
+    /// Public key of the user, only used by the asymmetric methods
+    pub public_key: Vec<u8>,
+    /// Update key of the user, which is unset until the first update key change
+    pub update_key: Option<UpdateKey>,
+}
+
+/// Storage of the users that the authority manages remotely
+///
+/// The outstation adds, changes, and deletes users when it receives a certified user status
+/// change (g120v10) and stores the new update key of a user after an update key change
+/// (g120v11 - g120v15). Implementations should persist the users and the last status change
+/// sequence number so that old changes can't be replayed after a restart.
+///
+/// The asymmetric methods rely on public key cryptography which the library doesn't implement.
+/// Applications that permit them must also implement [`Self::verify_certification`],
+/// [`Self::decrypt_update_key`], and [`Self::verify_signature`].
+pub trait KeyStore: Send + Sync {
+    /// Authority certification key used by the symmetric method
+    fn authority_key(&self) -> Option<AuthorityKey>;
+
+    /// Decide if the authority may use a key change method
+    ///
+    /// The default implementation only permits the symmetric method
+    fn is_permitted(&self, method: KeyChangeMethod) -> bool {
+        method.is_symmetric()
+    }
+
+    /// Name the authority assigned to the outstation
+    ///
+    /// The master includes it in the MAC that confirms an update key change (g120v15)
+    fn outstation_name(&self) -> &str;
+
+    /// Status change sequence number (SCS) of the last change accepted from the authority
+    fn last_status_change_seq(&self) -> u32;
+
+    /// Record the status change sequence number of an accepted change
+    fn set_last_status_change_seq(&mut self, seq: u32);
+
+    /// Retrieve a user by number
+    fn get_user(&self, number: u16) -> Option<User>;
+
+    /// Retrieve a user by name
+    fn find_user(&self, name: &str) -> Option<User>;
+
+    /// Add a user or replace the user with the same number
+    fn store_user(&mut self, user: User);
+
+    /// Delete a user along with its update key
+    fn delete_user(&mut self, number: u16);
+
+    /// Verify the certification data of a user status change with the public key of the authority
+    ///
+    /// `data` is the user status change object (g120v10) without its certification data.
+    /// The default implementation rejects every change.
+    #[allow(unused_variables)]
+    fn verify_certification(
+        &mut self,
+        method: KeyChangeMethod,
+        data: &[u8],
+        certification_data: &[u8],
+    ) -> bool {
+        false
+    }
+
+    /// Decrypt the plaintext of an update key change with the private key of the outstation
+    ///
+    /// The default implementation rejects every change.
+    #[allow(unused_variables)]
+    fn decrypt_update_key(&mut self, method: KeyChangeMethod, data: &[u8]) -> Option<Vec<u8>> {
+        None
+    }
+
+    /// Verify the signature of an update key change with the public key of the user
+    ///
+    /// The default implementation rejects every change.
+    #[allow(unused_variables)]
+    fn verify_signature(
+        &mut self,
+        method: KeyChangeMethod,
+        user: &User,
+        data: &[u8],
+        signature: &[u8],
+    ) -> bool {
+        false
+    }
+}
+
+/// Reasons the outstation refused to authenticate a request
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AuthFailure {
+    /// The user has no update key
+    UnknownUser,
+    /// The user has no valid session keys
+    NoSessionKeys,
+    /// A reply or an aggressive mode request arrived without a preceding challenge
+    NoChallenge,
+    /// The challenge sequence number didn't match the one the outstation expected
+    UnexpectedChallengeSequence(u32),
+    /// The MAC of a reply or an aggressive mode request could not be verified
+    BadMac,
+    /// An aggressive mode request was received but aggressive mode is disabled
+    AggressiveModeDisabled,
+    /// The session key change could not be unwrapped or didn't match the last key status
+    KeyChangeFailed,
+    /// The authenticated user is not authorized to perform the function
+    Unauthorized(FunctionCode),
+    /// The key change method of a user status or update key change is not permitted
+    KeyChangeMethodNotPermitted(u8),
+    /// The status change sequence number wasn't greater than the last one accepted
+    UnexpectedStatusChangeSequence(u32),
+    /// The certification data of a user status change could not be verified
+    InvalidCertification,
+    /// The signature of an update key change could not be verified
+    InvalidSignature,
+    /// The update key change could not be decrypted or didn't match the last reply
+    UpdateKeyChangeFailed,
+}
+
+impl AuthFailure {
+    fn error_code(self) -> AuthErrorCode {
+        match self {
+            Self::UnknownUser => AuthErrorCode::UnknownUser,
+            Self::AggressiveModeDisabled => AuthErrorCode::AggressiveModeNotSupported,
+            Self::Unauthorized(_) => AuthErrorCode::AuthorizationFailed,
+            Self::KeyChangeMethodNotPermitted(_) => {
+                AuthErrorCode::UpdateKeyChangeMethodNotPermitted
+            }
+            Self::InvalidCertification => AuthErrorCode::InvalidCertificationData,
+            Self::InvalidSignature => AuthErrorCode::InvalidSignature,
+            Self::NoSessionKeys
+            | Self::NoChallenge
+            | Self::UnexpectedChallengeSequence(_)
+            | Self::BadMac
+            | Self::KeyChangeFailed
+            | Self::UnexpectedStatusChangeSequence(_)
+            | Self::UpdateKeyChangeFailed => AuthErrorCode::AuthenticationFailed,
+        }
+    }
+}
+
+/// Critical request authenticated in aggressive mode (g120v3, the objects, and then g120v9)
+pub(crate) struct AggressiveRequest<'a> {
+    challenge_seq: u32,
+    pub(crate) user: u16,
+    /// portion of the fragment covered by the MAC
+    authenticated: &'a [u8],
+    mac: &'a [u8],
+    /// object headers between g120v3 and g120v9
+    pub(crate) objects: &'a [u8],
+}
+
+impl<'a> AggressiveRequest<'a> {
+    pub(crate) fn parse(fragment: &'a [u8], objects: HeaderCollection<'a>) -> Option<Self> {
+        let first = match objects.iter().next()?.details {
+            HeaderDetails::OneByteCount(1, CountVariation::Group120Var3(seq)) => seq.single()?,
+            _ => return None,
+        };
+        let mac = match objects.iter().last()?.details {
+            HeaderDetails::TwoByteFreeFormat(1, FreeFormatVariation::Group120Var9(x)) => x.mac,
+            _ => return None,
+        };
+
+        let end = fragment.len().checked_sub(MAC_HEADER_LENGTH + mac.len())?;
+        let start = RequestHeader::LENGTH + AGGRESSIVE_MODE_HEADER_LENGTH;
+
+        Some(Self {
+            challenge_seq: first.challenge_seq,
+            user: first.user,
+            authenticated: &fragment[..end],
+            mac,
+            objects: fragment.get(start..end)?,
+        })
+    }
+}
+
+/// Proof that the master knows the new update key
+enum KeyChangeProof<'a> {
+    /// MAC calculated with the new update key (g120v15)
+    Mac(&'a [u8]),
+    /// signature calculated with the private key of the user (g120v14)
+    Signature(&'a [u8]),
+}
+
+/// Encrypted update key (g120v13) followed by its proof
+pub(crate) struct EncryptedUpdateKey<'a> {
+    pub(crate) change: Group120Var13<'a>,
+    proof: KeyChangeProof<'a>,
+}
+
+impl<'a> EncryptedUpdateKey<'a> {
+    pub(crate) fn parse(objects: HeaderCollection<'a>) -> Option<Self> {
+        let mut headers = objects.iter();
+        let change = match headers.next()?.details {
+            HeaderDetails::TwoByteFreeFormat(1, FreeFormatVariation::Group120Var13(x)) => x,
+            _ => return None,
+        };
+        let proof = match headers.next()?.details {
+            HeaderDetails::TwoByteFreeFormat(1, FreeFormatVariation::Group120Var15(x)) => {
+                KeyChangeProof::Mac(x.mac)
+            }
+            HeaderDetails::TwoByteFreeFormat(1, FreeFormatVariation::Group120Var14(x)) => {
+                KeyChangeProof::Signature(x.signature)
+            }
+            _ => return None,
+        };
+        if headers.next().is_some() {
+            return None;
+        }
+
+        Some(Self { change, proof })
+    }
+}
+
+/// Critical request awaiting the reply to its challenge
+pub(crate) struct PendingRequest {
+    pub(crate) function: FunctionCode,
+    pub(crate) seq: Sequence,
+    pub(crate) frame_id: u32,
+    options: ParseOptions,
+    challenge_seq: u32,
+    mac_algorithm: MacAlgorithm,
+    fragment: Vec<u8>,
+}
+
+impl PendingRequest {
+    pub(crate) fn objects(&self) -> Result<HeaderCollection<'_>, ObjectParseError> {
+        HeaderCollection::parse(
+            self.options,
+            self.function,
+            &self.fragment[RequestHeader::LENGTH..],
+        )
+    }
+}
+
+enum SessionKeyState {
+    Valid {
+        keys: SessionKeys,
+        expiration: Instant,
+        /// key change request that established the keys
+        key_change: Vec<u8>,
+    },
+    Invalid(KeyStatus),
+}
+
+/// last session key status (g120v5) sent to the master which must precede a key change
+struct LastKeyStatus {
+    user: u16,
+    key_change_seq: u32,
+    encoded: Vec<u8>,
+}
+
+struct LastChallenge {
+    mac_algorithm: MacAlgorithm,
+    fragment: Vec<u8>,
+}
+
+/// update key change reply (g120v12) that must precede the encrypted update key
+struct PendingKeyChange {
+    method: KeyChangeMethod,
+    user: User,
+    key_change_seq: u32,
+    master_challenge_data: Vec<u8>,
+    challenge_data: Vec<u8>,
+}
+
+fn key_store(
+    handler: &mut dyn AuthHandler,
+    key_change_method: u8,
+) -> Result<(KeyChangeMethod, &mut dyn KeyStore), AuthFailure> {
+    let not_permitted = AuthFailure::KeyChangeMethodNotPermitted(key_change_method);
+    let method = KeyChangeMethod::new(key_change_method).ok_or(not_permitted)?;
+    let store = handler.key_store().ok_or(not_permitted)?;
+    if !store.is_permitted(method) {
+        return Err(not_permitted);
+    }
+    Ok((method, store))
+}
+
+/// find a user number that isn't used by the application or the key store
+fn allocate_user_number(handler: &mut dyn AuthHandler) -> Option<u16> {
+    (DEFAULT_USER..=u16::MAX).find(|&number| {
+        handler.get_update_key(number).is_none()
+            && handler
+                .key_store()
+                .is_some_and(|store| store.get_user(number).is_none())
+    })
+}
+
+/// Retrieve the update key of a user, preferring the key store which holds keys changed remotely
+pub(crate) fn get_update_key(handler: &mut dyn AuthHandler, user: u16) -> Option<UpdateKey> {
+    handler
+        .key_store()
+        .and_then(|store| store.get_user(user))
+        .and_then(|user| user.update_key)
+        .or_else(|| handler.get_update_key(user))
+}
+
+/// Authentication state of an outstation session
+pub(crate) struct OutstationAuth {
+    config: AuthConfig,
+    users: HashMap<u16, SessionKeyState>,
+    key_change_seq: u32,
+    /// challenge sequence number of the last challenge or aggressive mode request
+    challenge_seq: u32,
+    last_key_status: Option<LastKeyStatus>,
+    last_challenge: Option<LastChallenge>,
+    pending: Option<PendingRequest>,
+    update_key_change_seq: u32,
+    pending_key_change: Option<PendingKeyChange>,
+}
+
+impl OutstationAuth {
+    pub(crate) fn new(config: AuthConfig) -> Self {
+        Self {
+            config,
+            users: HashMap::new(),
+            key_change_seq: 0,
+            challenge_seq: 0,
+            last_key_status: None,
+            last_challenge: None,
+            pending: None,
+            update_key_change_seq: 0,
+            pending_key_change: None,
+        }
+    }
+
+    /// invalidate all session keys when the communication session ends
+    pub(crate) fn reset(&mut self) {
+        for state in self.users.values_mut() {
+            if let SessionKeyState::Valid { .. } = state {
+                *state = SessionKeyState::Invalid(KeyStatus::CommFail);
+            }
+        }
+        self.last_key_status = None;
+        self.last_challenge = None;
+        self.pending = None;
+        self.pending_key_change = None;
+    }
+
+    /// discard the request awaiting a reply, which is only valid until the next request
+    pub(crate) fn take_pending(&mut self) -> Option<PendingRequest> {
+        self.pending.take()
+    }
+
+    fn session_keys(&self, user: u16) -> Option<&SessionKeys> {
+        match self.users.get(&user)? {
+            SessionKeyState::Valid {
+                keys, expiration, ..
+            } if Instant::now() < *expiration => Some(keys),
+            _ => None,
+        }
+    }
+
+    fn key_status(&self, user: u16) -> KeyStatus {
+        match self.users.get(&user) {
+            None => KeyStatus::NotInit,
+            Some(SessionKeyState::Valid { expiration, .. }) => {
+                if Instant::now() < *expiration {
+                    KeyStatus::Ok
+                } else {
+                    KeyStatus::CommFail
+                }
+            }
+            Some(SessionKeyState::Invalid(status)) => *status,
+        }
+    }
+
+    /// Challenge a critical request (g120v1) and hold on to it until the reply arrives
+    pub(crate) fn challenge(
+        &mut self,
+        function: FunctionCode,
+        seq: Sequence,
+        frame_id: u32,
+        objects: HeaderCollection,
+        fragment: &[u8],
+        writer: &mut HeaderWriter,
+    ) {
+        self.challenge_seq = self.challenge_seq.wrapping_add(1);
+        self.pending = Some(PendingRequest {
+            function,
+            seq,
+            frame_id,
+            options: objects.options(),
+            challenge_seq: self.challenge_seq,
+            mac_algorithm: self.config.mac_algorithm,
+            fragment: fragment.to_vec(),
+        });
+
+        let challenge_data = random_bytes(CHALLENGE_DATA_LENGTH);
+        let _ = writer.write_free_format(&Group120Var1 {
+            challenge_seq: self.challenge_seq,
+            // the user is only identified by the reply
+            user: 0,
+            mac_algorithm: self.config.mac_algorithm.as_u8(),
+            reason: CHALLENGE_REASON_CRITICAL,
+            challenge_data: &challenge_data,
+        });
+    }
+
+    /// Record the challenge as it was sent, since the master authenticates the entire fragment
+    pub(crate) fn on_challenge_sent(&mut self, fragment: &[u8]) {
+        if let Some(pending) = &self.pending {
+            self.last_challenge = Some(LastChallenge {
+                mac_algorithm: pending.mac_algorithm,
+                fragment: fragment.to_vec(),
+            });
+        }
+    }
+
+    /// Verify the reply (g120v2) to the last challenge, returning the challenged request
+    pub(crate) fn verify_reply(
+        &self,
+        pending: Option<PendingRequest>,
+        reply: &Group120Var2,
+    ) -> Result<PendingRequest, AuthFailure> {
+        let (pending, challenge) = match (pending, &self.last_challenge) {
+            (Some(pending), Some(challenge)) => (pending, challenge),
+            _ => return Err(AuthFailure::NoChallenge),
+        };
+        if reply.challenge_seq != pending.challenge_seq {
+            return Err(AuthFailure::UnexpectedChallengeSequence(
+                reply.challenge_seq,
+            ));
+        }
+        let keys = self
+            .session_keys(reply.user)
+            .ok_or(AuthFailure::NoSessionKeys)?;
+
+        if !verify_mac(
+            challenge.mac_algorithm,
+            &keys.control,
+            &[&challenge.fragment, &pending.fragment],
+            reply.mac,
+        ) {
+            return Err(AuthFailure::BadMac);
+        }
+
+        Ok(pending)
+    }
+
+    /// Verify a request authenticated in aggressive mode, returning the user
+    pub(crate) fn verify_aggressive(
+        &mut self,
+        request: &AggressiveRequest,
+    ) -> Result<u16, AuthFailure> {
+        if !self.config.aggressive_mode {
+            return Err(AuthFailure::AggressiveModeDisabled);
+        }
+        let challenge = self
+            .last_challenge
+            .as_ref()
+            .ok_or(AuthFailure::NoChallenge)?;
+        if request.challenge_seq != self.challenge_seq.wrapping_add(1) {
+            return Err(AuthFailure::UnexpectedChallengeSequence(
+                request.challenge_seq,
+            ));
+        }
+        let keys = self
+            .session_keys(request.user)
+            .ok_or(AuthFailure::NoSessionKeys)?;
+
+        if !verify_mac(
+            challenge.mac_algorithm,
+            &keys.control,
+            &[&challenge.fragment, request.authenticated],
+            request.mac,
+        ) {
+            return Err(AuthFailure::BadMac);
+        }
+
+        self.challenge_seq = request.challenge_seq;
+        Ok(request.user)
+    }
+
+    /// Respond to a session key status request (g120v4) with the status of the user's keys (g120v5)
+    pub(crate) fn key_status_request(
+        &mut self,
+        user: u16,
+        update_key: &UpdateKey,
+        writer: &mut HeaderWriter,
+    ) {
+        let key_status = self.key_status(user);
+
+        // when the keys are valid, the outstation proves it still has them
+        let mac = match self.users.get(&user) {
+            Some(SessionKeyState::Valid {
+                keys, key_change, ..
+            }) if key_status == KeyStatus::Ok => {
+                calc_mac(self.config.mac_algorithm, &keys.monitoring, &[key_change])
+            }
+            _ => Vec::new(),
+        };
+
+        self.write_key_status(user, update_key, key_status, &mac, writer);
+    }
+
+    /// Process a session key change (g120v6) and respond with the new status of the user's keys (g120v5)
+    ///
+    /// The status is written even if the change fails
+    pub(crate) fn key_change(
+        &mut self,
+        change: &Group120Var6,
+        update_key: &UpdateKey,
+        fragment: &[u8],
+        writer: &mut HeaderWriter,
+    ) -> Result<(), AuthFailure> {
+        // the change must answer the last status sent to the master
+        let keys = match self.last_key_status.take() {
+            Some(status)
+                if status.user == change.user && status.key_change_seq == change.key_change_seq =>
+            {
+                unwrap_session_keys(update_key, change.wrapped_key_data).and_then(
+                    |(keys, remainder)| remainder.starts_with(&status.encoded).then_some(keys),
+                )
+            }
+            _ => None,
+        };
+
+        self.key_change_seq = self.key_change_seq.wrapping_add(1);
+
+        match keys {
+            Some(keys) => {
+                let mac = calc_mac(self.config.mac_algorithm, &keys.monitoring, &[fragment]);
+                self.users.insert(
+                    change.user,
+                    SessionKeyState::Valid {
+                        keys,
+                        expiration: Instant::now() + self.config.session_key_timeout,
+                        key_change: fragment.to_vec(),
+                    },
+                );
+                self.write_key_status(change.user, update_key, KeyStatus::Ok, &mac, writer);
+                Ok(())
+            }
+            None => {
+                self.users
+                    .insert(change.user, SessionKeyState::Invalid(KeyStatus::AuthFail));
+                self.write_key_status(change.user, update_key, KeyStatus::AuthFail, &[], writer);
+                Err(AuthFailure::KeyChangeFailed)
+            }
+        }
+    }
+
+    fn write_key_status(
+        &mut self,
+        user: u16,
+        update_key: &UpdateKey,
+        key_status: KeyStatus,
+        mac: &[u8],
+        writer: &mut HeaderWriter,
+    ) {
+        let challenge_data = random_bytes(CHALLENGE_DATA_LENGTH);
+        let status = Group120Var5 {
+            key_change_seq: self.key_change_seq,
+            user,
+            key_wrap_algorithm: update_key.algorithm().as_u8(),
+            key_status,
+            mac_algorithm: self.config.mac_algorithm.as_u8(),
+            challenge_data: &challenge_data,
+            mac,
+        };
+
+        // the next key change must include the status exactly as it was sent
+        self.last_key_status = status.encode().ok().map(|encoded| LastKeyStatus {
+            user,
+            key_change_seq: self.key_change_seq,
+            encoded,
+        });
+
+        let _ = writer.write_free_format(&status);
+    }
+
+    /// Apply a user status change (g120v10) once its certification data is verified
+    pub(crate) fn user_status_change(
+        &mut self,
+        handler: &mut dyn AuthHandler,
+        change: &Group120Var10,
+    ) -> Result<(), AuthFailure> {
+        let (method, store) = key_store(handler, change.key_change_method)?;
+
+        if change.status_change_seq <= store.last_status_change_seq() {
+            return Err(AuthFailure::UnexpectedStatusChangeSequence(
+                change.status_change_seq,
+            ));
+        }
+
+        let data = change
+            .certified_data()
+            .map_err(|_| AuthFailure::InvalidCertification)?;
+        let certified = match method {
+            KeyChangeMethod::SymmetricAes256Sha256 => store.authority_key().is_some_and(|key| {
+                verify_hmac_sha256(key.data(), &[&data], change.certification_data)
+            }),
+            _ => store.verify_certification(method, &data, change.certification_data),
+        };
+        // the authority never certifies an undefined operation
+        let operation = match UserStatusOperation::new(change.operation) {
+            Some(x) if certified => x,
+            _ => return Err(AuthFailure::InvalidCertification),
+        };
+
+        store.set_last_status_change_seq(change.status_change_seq);
+
+        let name = String::from_utf8_lossy(change.user_name).into_owned();
+        let existing = store.find_user(&name);
+        let mut user = match (operation, existing) {
+            (UserStatusOperation::Delete, Some(user)) => {
+                tracing::info!("deleted user {} ({})", user.number, user.name);
+                store.delete_user(user.number);
+                self.users.remove(&user.number);
+                return Ok(());
+            }
+            (UserStatusOperation::Delete | UserStatusOperation::Change, None) => {
+                return Err(AuthFailure::UnknownUser)
+            }
+            (_, Some(user)) => user,
+            (UserStatusOperation::Add, None) => User {
+                number: allocate_user_number(handler).ok_or(AuthFailure::UnknownUser)?,
+                name,
+                role: UserRole::Viewer,
+                role_expiry_days: 0,
+                public_key: Vec::new(),
+                update_key: None,
+            },
+        };
+
+        user.role = UserRole::new(change.role);
+        user.role_expiry_days = change.role_expiry_days;
+        if !change.user_public_key.is_empty() {
+            user.public_key = change.user_public_key.to_vec();
+        }
+
+        tracing::info!(
+            "changed status of user {} ({}): {:?}",
+            user.number,
+            user.name,
+            user.role
+        );
+        if let Some(store) = handler.key_store() {
+            store.store_user(user);
+        }
+        Ok(())
+    }
+
+    /// Respond to an update key change request (g120v11) with the user number and challenge data (g120v12)
+    pub(crate) fn update_key_change_request(
+        &mut self,
+        handler: &mut dyn AuthHandler,
+        request: &Group120Var11,
+        writer: &mut HeaderWriter,
+    ) -> Result<(), AuthFailure> {
+        let (method, store) = key_store(handler, request.key_change_method)?;
+        let user = store
+            .find_user(&String::from_utf8_lossy(request.user_name))
+            .ok_or(AuthFailure::UnknownUser)?;
+
+        self.update_key_change_seq = self.update_key_change_seq.wrapping_add(1);
+        let challenge_data = random_bytes(CHALLENGE_DATA_LENGTH);
+        let _ = writer.write_free_format(&Group120Var12 {
+            key_change_seq: self.update_key_change_seq,
+            user: user.number,
+            challenge_data: &challenge_data,
+        });
+
+        self.pending_key_change = Some(PendingKeyChange {
+            method,
+            user,
+            key_change_seq: self.update_key_change_seq,
+            master_challenge_data: request.challenge_data.to_vec(),
+            challenge_data,
+        });
+        Ok(())
+    }
+
+    /// Store the new update key (g120v13) and confirm the change to the master (g120v15)
+    pub(crate) fn update_key_change(
+        &mut self,
+        handler: &mut dyn AuthHandler,
+        encrypted: &EncryptedUpdateKey,
+        writer: &mut HeaderWriter,
+    ) -> Result<(), AuthFailure> {
+        // the change must answer the last reply sent to the master
+        let pending = match self.pending_key_change.take() {
+            Some(x)
+                if x.key_change_seq == encrypted.change.key_change_seq
+                    && x.user.number == encrypted.change.user =>
+            {
+                x
+            }
+            _ => return Err(AuthFailure::UpdateKeyChangeFailed),
+        };
+        let (method, store) = key_store(handler, pending.method.as_u8())?;
+
+        let plaintext = match method {
+            KeyChangeMethod::SymmetricAes256Sha256 => store
+                .authority_key()
+                .and_then(|key| unwrap_update_key(&key, encrypted.change.encrypted_key_data)),
+            _ => store.decrypt_update_key(method, encrypted.change.encrypted_key_data),
+        };
+        let update_key = plaintext
+            .and_then(|data| {
+                parse_update_key_data(&data, pending.user.name.as_bytes(), &pending.challenge_data)
+            })
+            .ok_or(AuthFailure::UpdateKeyChangeFailed)?;
+
+        match (method.is_symmetric(), &encrypted.proof) {
+            (true, KeyChangeProof::Mac(mac)) => {
+                if !verify_update_key_confirmation(
+                    &update_key,
+                    store.outstation_name().as_bytes(),
+                    &pending.master_challenge_data,
+                    &pending.challenge_data,
+                    pending.key_change_seq,
+                    pending.user.number,
+                    mac,
+                ) {
+                    return Err(AuthFailure::UpdateKeyChangeFailed);
+                }
+            }
+            (false, KeyChangeProof::Signature(signature)) => {
+                let data = update_key_signature_data(
+                    &pending.master_challenge_data,
+                    &pending.challenge_data,
+                    pending.key_change_seq,
+                    pending.user.number,
+                    encrypted.change.encrypted_key_data,
+                );
+                if !store.verify_signature(method, &pending.user, &data, signature) {
+                    return Err(AuthFailure::InvalidSignature);
+                }
+            }
+            _ => return Err(AuthFailure::UpdateKeyChangeFailed),
+        }
+
+        let number = pending.user.number;
+        let mac = update_key_confirmation(
+            &update_key,
+            pending.user.name.as_bytes(),
+            &pending.challenge_data,
+            &pending.master_challenge_data,
+            pending.key_change_seq,
+            number,
+        );
+        tracing::info!(
+            "changed update key of user {} ({})",
+            number,
+            pending.user.name
+        );
+        store.store_user(User {
+            update_key: Some(update_key.clone()),
+            ..pending.user
+        });
+        // session keys wrapped with the old update key are no longer valid
+        self.users.remove(&number);
+
+        let _ = writer.write_free_format(&Group120Var15 { mac: &mac });
+        Ok(())
+    }
+
+    /// Write an authentication error (g120v7) describing a failure
+    pub(crate) fn write_error(
+        &self,
+        user: u16,
+        association_id: u16,
+        failure: AuthFailure,
+        writer: &mut HeaderWriter,
+    ) {
+        let time = Timestamp::try_from_system_time(SystemTime::now()).unwrap_or(Timestamp::new(0));
+        let _ = writer.write_free_format(&Group120Var7 {
+            challenge_seq: self.challenge_seq,
+            user,
+            association_id,
+            error: failure.error_code(),
+            time,
+            text: &[],
+        });
+    }
+}
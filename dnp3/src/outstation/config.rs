@@ -1,7 +1,8 @@
 use crate::app::{BufferSize, Timeout};
 use crate::decode::DecodeLevel;
 use crate::link::EndpointAddress;
-use crate::outstation::database::{ClassZeroConfig, EventBufferConfig};
+use crate::outstation::database::{ClassZeroConfig, EventBufferConfig, EventClass};
+use crate::outstation::AuthConfig;
 
 /// describes whether an optional feature is enabled or disabled
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -35,6 +36,16 @@ pub struct Features {
     /// This feature is a hack that can make configuration of some systems easier/more flexible, but
     /// should not be used when unsolicited reporting is also required.
     pub respond_to_any_master: Feature,
+    /// if enabled, operations that the [`ControlHandler`](crate::outstation::ControlHandler) reports as successful
+    /// produce binary (g13) and analog (g43) output command events on any command event point
+    /// defined at the same index (default == Disabled)
+    pub command_events: Feature,
+    /// if enabled, FREEZE requests are handled by the built-in freeze engine of the
+    /// [`Database`](crate::outstation::database::Database) using the links created with
+    /// [`Database::link_frozen_counter`](crate::outstation::database::Database::link_frozen_counter)
+    /// instead of [`OutstationApplication::freeze_counter`](crate::outstation::OutstationApplication::freeze_counter)
+    /// (default == Disabled)
+    pub freeze_engine: Feature,
 }
 
 impl Default for Features {
@@ -44,6 +55,105 @@ impl Default for Features {
             broadcast: Feature::Enabled,
             unsolicited: Feature::Enabled,
             respond_to_any_master: Feature::Disabled,
+            command_events: Feature::Disabled,
+            freeze_engine: Feature::Disabled,
+        }
+    }
+}
+
+/// Controls how long the events of a single class are held before they are reported in an unsolicited response
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct UnsolicitedClassHold {
+    /// maximum amount of time the first unreported event of the class is held before an unsolicited response is sent
+    pub max_delay: std::time::Duration,
+    /// number of unreported events of the class that triggers an unsolicited response before `max_delay` elapses
+    pub min_count: u16,
+}
+
+impl UnsolicitedClassHold {
+    /// Events are reported as soon as they are available
+    pub const IMMEDIATE: Self = Self::new(std::time::Duration::ZERO, 1);
+
+    /// construct an `UnsolicitedClassHold` from a maximum delay and a minimum event count
+    pub const fn new(max_delay: std::time::Duration, min_count: u16) -> Self {
+        Self {
+            max_delay,
+            min_count,
+        }
+    }
+
+    /// time at which events held since `held_since` must be reported, or `None` if the
+    /// `max_delay` is too large to be represented and the events are only reported by count
+    pub(crate) fn deadline(
+        &self,
+        held_since: tokio::time::Instant,
+    ) -> Option<tokio::time::Instant> {
+        held_since.checked_add(self.max_delay)
+    }
+
+    pub(crate) fn is_ready(
+        &self,
+        count: usize,
+        held_since: tokio::time::Instant,
+        now: tokio::time::Instant,
+    ) -> bool {
+        count >= self.min_count as usize || self.deadline(held_since).is_some_and(|x| now >= x)
+    }
+}
+
+impl Default for UnsolicitedClassHold {
+    fn default() -> Self {
+        Self::IMMEDIATE
+    }
+}
+
+/// Per-class batching of the events reported in unsolicited responses
+///
+/// An unsolicited response is sent once any class enabled for unsolicited reporting reaches its
+/// [`UnsolicitedClassHold::min_count`] or has held an event for [`UnsolicitedClassHold::max_delay`].
+/// The response then carries the events of every enabled class, including those that were still
+/// being held. The hold restarts after each confirmed unsolicited response.
+///
+/// The default configuration reports every class immediately.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct UnsolicitedHoldConfig {
+    /// if true, Class 1 events are reported immediately regardless of the `class1` hold (default == true)
+    pub class1_immediate: bool,
+    /// hold applied to Class 1 events when `class1_immediate` is false
+    pub class1: UnsolicitedClassHold,
+    /// hold applied to Class 2 events
+    pub class2: UnsolicitedClassHold,
+    /// hold applied to Class 3 events
+    pub class3: UnsolicitedClassHold,
+}
+
+impl UnsolicitedHoldConfig {
+    pub(crate) fn get(&self, class: EventClass) -> UnsolicitedClassHold {
+        match class {
+            EventClass::Class1 if self.class1_immediate => UnsolicitedClassHold::IMMEDIATE,
+            EventClass::Class1 => self.class1,
+            EventClass::Class2 => self.class2,
+            EventClass::Class3 => self.class3,
+        }
+    }
+}
+
+impl Default for UnsolicitedHoldConfig {
+    fn default() -> Self {
+        Self {
+            class1_immediate: true,
+            class1: UnsolicitedClassHold::IMMEDIATE,
+            class2: UnsolicitedClassHold::IMMEDIATE,
+            class3: UnsolicitedClassHold::IMMEDIATE,
         }
     }
 }
@@ -91,6 +201,9 @@ pub struct OutstationConfig {
         serde(default = "OutstationConfig::default_unsolicited_retry_delay")
     )]
     pub unsolicited_retry_delay: std::time::Duration,
+    /// per-class batching of the events reported in unsolicited responses
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub unsolicited_hold: UnsolicitedHoldConfig,
     /// time without any link activity before the outstation will send REQUEST_LINK_STATES
     ///
     /// A value of `None` will disable this feature
@@ -113,6 +226,96 @@ pub struct OutstationConfig {
     /// controls responses to class 0 READ requests
     #[cfg_attr(feature = "serialization", serde(default))]
     pub class_zero: ClassZeroConfig,
+    /// secure authentication (SAv5) of critical requests
+    ///
+    /// A value of `None` (the default) disables secure authentication
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub authentication: Option<AuthConfig>,
+    /// controls how long events are retained for this master while it is disconnected
+    ///
+    /// Only applies when the database is shared with the associations of other masters, see
+    /// [`Server::add_association`](crate::tcp::Server::add_association).
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub offline_association_policy: OfflineAssociationPolicy,
+}
+
+/// Controls how long the events of a database shared by several masters are retained for a
+/// master that is disconnected
+///
+/// Events are removed from the buffer once every master for which they are retained has confirmed
+/// them. Retaining events for a master that never reconnects prevents the other masters from
+/// freeing space in the buffer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub enum OfflineAssociationPolicy {
+    /// Retain events until the master reconnects and confirms them
+    Retain,
+    /// Stop retaining events for the master once it has been disconnected for the specified
+    /// amount of time. Events that the master has not confirmed are then removed as soon as the
+    /// other masters confirm them.
+    ReleaseAfter(std::time::Duration),
+}
+
+impl OfflineAssociationPolicy {
+    /// Default amount of time events are retained for a disconnected master
+    pub const DEFAULT_RELEASE_DELAY: std::time::Duration = std::time::Duration::from_secs(60);
+
+    /// true if events must still be retained for a master that disconnected at `offline_since`
+    pub(crate) fn retains_events(
+        &self,
+        offline_since: tokio::time::Instant,
+        now: tokio::time::Instant,
+    ) -> bool {
+        match self {
+            Self::Retain => true,
+            // a delay that cannot be represented never elapses
+            Self::ReleaseAfter(delay) => offline_since
+                .checked_add(*delay)
+                .map_or(true, |deadline| now < deadline),
+        }
+    }
+}
+
+impl Default for OfflineAssociationPolicy {
+    fn default() -> Self {
+        Self::ReleaseAfter(Self::DEFAULT_RELEASE_DELAY)
+    }
+}
+
+/// Field of an [`OutstationConfig`] that cannot be changed while the outstation is running
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum OutstationConfigField {
+    /// [`OutstationConfig::outstation_address`]
+    OutstationAddress,
+    /// [`OutstationConfig::solicited_buffer_size`]
+    SolicitedBufferSize,
+    /// [`OutstationConfig::unsolicited_buffer_size`]
+    UnsolicitedBufferSize,
+    /// [`OutstationConfig::rx_buffer_size`]
+    RxBufferSize,
+    /// [`OutstationConfig::max_read_request_headers`]
+    MaxReadRequestHeaders,
+    /// [`Features::self_address`]
+    SelfAddress,
+    /// [`OutstationConfig::authentication`]
+    Authentication,
+    /// [`OutstationConfig::master_address`] when the address is used by another master sharing
+    /// the database
+    MasterAddress,
+}
+
+/// Outcome of [`OutstationHandle::reconfigure`](crate::outstation::OutstationHandle::reconfigure)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReconfigureResult {
+    /// fields that differ from the running configuration and were not applied
+    ///
+    /// The outstation must be recreated to change these fields.
+    pub restart_required: Vec<OutstationConfigField>,
+    /// number of buffered events discarded because a type or class held more events than its new limit
+    pub discarded_events: usize,
 }
 
 impl Feature {
@@ -144,6 +347,65 @@ impl OutstationConfig {
     pub const DEFAULT_UNSOLICITED_RETRY_DELAY: std::time::Duration =
         Self::default_unsolicited_retry_delay();
 
+    /// fields of `other` that differ from this configuration and cannot be applied at runtime
+    pub(crate) fn restart_required(&self, other: &Self) -> Vec<OutstationConfigField> {
+        let mut fields = Vec::new();
+        let mut check = |changed: bool, field: OutstationConfigField| {
+            if changed {
+                fields.push(field);
+            }
+        };
+        check(
+            self.outstation_address != other.outstation_address,
+            OutstationConfigField::OutstationAddress,
+        );
+        check(
+            self.solicited_buffer_size != other.solicited_buffer_size,
+            OutstationConfigField::SolicitedBufferSize,
+        );
+        check(
+            self.unsolicited_buffer_size != other.unsolicited_buffer_size,
+            OutstationConfigField::UnsolicitedBufferSize,
+        );
+        check(
+            self.rx_buffer_size != other.rx_buffer_size,
+            OutstationConfigField::RxBufferSize,
+        );
+        check(
+            self.max_read_request_headers != other.max_read_request_headers,
+            OutstationConfigField::MaxReadRequestHeaders,
+        );
+        check(
+            self.features.self_address != other.features.self_address,
+            OutstationConfigField::SelfAddress,
+        );
+        check(
+            self.authentication != other.authentication,
+            OutstationConfigField::Authentication,
+        );
+        fields
+    }
+
+    /// copy the fields of `other` that can be applied at runtime
+    pub(crate) fn apply_runtime_fields(&mut self, other: &Self) {
+        self.master_address = other.master_address;
+        self.event_buffer_config = other.event_buffer_config;
+        self.decode_level = other.decode_level;
+        self.confirm_timeout = other.confirm_timeout;
+        self.select_timeout = other.select_timeout;
+        self.features = Features {
+            self_address: self.features.self_address,
+            ..other.features
+        };
+        self.max_unsolicited_retries = other.max_unsolicited_retries;
+        self.unsolicited_retry_delay = other.unsolicited_retry_delay;
+        self.unsolicited_hold = other.unsolicited_hold;
+        self.keep_alive_timeout = other.keep_alive_timeout;
+        self.max_controls_per_request = other.max_controls_per_request;
+        self.class_zero = other.class_zero;
+        self.offline_association_policy = other.offline_association_policy;
+    }
+
     /// constructs an `OutstationConfig` with default settings, except for the
     /// master and outstation link addresses which really don't have good defaults
     pub fn new(
@@ -164,10 +426,13 @@ impl OutstationConfig {
             features: Features::default(),
             max_unsolicited_retries: None,
             unsolicited_retry_delay: Self::DEFAULT_UNSOLICITED_RETRY_DELAY,
+            unsolicited_hold: UnsolicitedHoldConfig::default(),
             keep_alive_timeout: Self::default_keep_alive_timeout(),
             max_read_request_headers: None,
             max_controls_per_request: None,
             class_zero: ClassZeroConfig::default(),
+            authentication: None,
+            offline_association_policy: OfflineAssociationPolicy::default(),
         }
     }
 }
@@ -1,5 +1,12 @@
 use crate::app::control::*;
+use crate::app::format::write::HeaderWriter;
+use crate::app::gen::count::CountVariation;
 use crate::app::gen::prefixed::PrefixedVariation;
+use crate::app::gen::ranged::RangedVariation;
+use crate::app::measurement::{
+    AnalogCommandValue, AnalogOutputCommandEvent, BinaryOutputCommandEvent, Time,
+};
+use crate::app::parse::bit::BitSequence;
 use crate::app::parse::count::CountSequence;
 use crate::app::parse::parser::{HeaderCollection, HeaderDetails, HeaderIterator, ObjectHeader};
 use crate::app::parse::prefix::Prefix;
@@ -7,19 +14,22 @@ use crate::app::parse::traits::{FixedSizeVariation, Index};
 use crate::app::{QualifierCode, Variation};
 use crate::outstation::control::control_type::ControlType;
 use crate::outstation::control::prefix::PrefixWriter;
-use crate::outstation::database::DatabaseHandle;
+use crate::outstation::database::{Database, DatabaseHandle, Update, UpdateOptions};
 use crate::outstation::traits::{ControlHandler, ControlSupport, OperateType};
+use crate::outstation::Feature;
 
 use scursor::{WriteCursor, WriteError};
 
 pub(crate) struct ControlTransaction<'a> {
     started: bool,
+    command_events: Feature,
     handler: &'a mut dyn ControlHandler,
 }
 
 impl<'a> ControlTransaction<'a> {
     pub(crate) async fn execute<F, R>(
         handler: &'a mut dyn ControlHandler,
+        command_events: Feature,
         database: &mut DatabaseHandle,
         mut func: F,
     ) -> R
@@ -28,6 +38,7 @@ impl<'a> ControlTransaction<'a> {
     {
         let mut tx = ControlTransaction {
             started: false,
+            command_events,
             handler,
         };
 
@@ -46,6 +57,99 @@ impl<'a> ControlTransaction<'a> {
             self.handler.begin_fragment();
         }
     }
+
+    /// produce a command event for a successful operation if the feature is enabled
+    fn record_command_event<T>(
+        &mut self,
+        control: T,
+        index: u16,
+        status: CommandStatus,
+        database: &mut DatabaseHandle,
+    ) where
+        T: ToCommandEvent,
+        Database: Update<T::Event>,
+    {
+        if status != CommandStatus::Success || self.command_events.is_disabled() {
+            return;
+        }
+
+        let event = control.to_command_event(self.handler.get_command_event_time());
+        database.transaction(|db| db.update(index, &event, UpdateOptions::detect_event()));
+    }
+}
+
+/// conversion from a successfully operated control to the corresponding command event
+trait ToCommandEvent {
+    type Event;
+
+    fn to_command_event(&self, time: Option<Time>) -> Self::Event;
+}
+
+impl ToCommandEvent for Group12Var1 {
+    type Event = BinaryOutputCommandEvent;
+
+    fn to_command_event(&self, time: Option<Time>) -> Self::Event {
+        // Latch On / Close map to 1, everything else is either Latch Off / Trip / NULL or unknown
+        let commanded_state = match self.code.tcc {
+            TripCloseCode::Close => true,
+            TripCloseCode::Trip => false,
+            _ => self.code.op_type == OpType::LatchOn,
+        };
+
+        BinaryOutputCommandEvent {
+            commanded_state,
+            status: CommandStatus::Success,
+            time,
+        }
+    }
+}
+
+impl ToCommandEvent for Group41Var1 {
+    type Event = AnalogOutputCommandEvent;
+
+    fn to_command_event(&self, time: Option<Time>) -> Self::Event {
+        AnalogOutputCommandEvent {
+            status: CommandStatus::Success,
+            commanded_value: AnalogCommandValue::I32(self.value),
+            time,
+        }
+    }
+}
+
+impl ToCommandEvent for Group41Var2 {
+    type Event = AnalogOutputCommandEvent;
+
+    fn to_command_event(&self, time: Option<Time>) -> Self::Event {
+        AnalogOutputCommandEvent {
+            status: CommandStatus::Success,
+            commanded_value: AnalogCommandValue::I16(self.value),
+            time,
+        }
+    }
+}
+
+impl ToCommandEvent for Group41Var3 {
+    type Event = AnalogOutputCommandEvent;
+
+    fn to_command_event(&self, time: Option<Time>) -> Self::Event {
+        AnalogOutputCommandEvent {
+            status: CommandStatus::Success,
+            commanded_value: AnalogCommandValue::F32(self.value),
+            time,
+        }
+    }
+}
+
+impl ToCommandEvent for Group41Var4 {
+    type Event = AnalogOutputCommandEvent;
+
+    fn to_command_event(&self, time: Option<Time>) -> Self::Event {
+        AnalogOutputCommandEvent {
+            status: CommandStatus::Success,
+            commanded_value: AnalogCommandValue::F64(self.value),
+            time,
+        }
+    }
 }
 
 impl ControlSupport<Group12Var1> for ControlTransaction<'_> {
@@ -67,7 +171,9 @@ impl ControlSupport<Group12Var1> for ControlTransaction<'_> {
         database: &mut DatabaseHandle,
     ) -> CommandStatus {
         self.start();
-        self.handler.operate(control, index, op_type, database)
+        let status = self.handler.operate(control, index, op_type, database);
+        self.record_command_event(control, index, status, database);
+        status
     }
 }
 
@@ -90,7 +196,9 @@ impl ControlSupport<Group41Var1> for ControlTransaction<'_> {
         database: &mut DatabaseHandle,
     ) -> CommandStatus {
         self.start();
-        self.handler.operate(control, index, op_type, database)
+        let status = self.handler.operate(control, index, op_type, database);
+        self.record_command_event(control, index, status, database);
+        status
     }
 }
 
@@ -113,7 +221,9 @@ impl ControlSupport<Group41Var2> for ControlTransaction<'_> {
         database: &mut DatabaseHandle,
     ) -> CommandStatus {
         self.start();
-        self.handler.operate(control, index, op_type, database)
+        let status = self.handler.operate(control, index, op_type, database);
+        self.record_command_event(control, index, status, database);
+        status
     }
 }
 
@@ -136,7 +246,9 @@ impl ControlSupport<Group41Var3> for ControlTransaction<'_> {
         database: &mut DatabaseHandle,
     ) -> CommandStatus {
         self.start();
-        self.handler.operate(control, index, op_type, database)
+        let status = self.handler.operate(control, index, op_type, database);
+        self.record_command_event(control, index, status, database);
+        status
     }
 }
 
@@ -159,7 +271,9 @@ impl ControlSupport<Group41Var4> for ControlTransaction<'_> {
         database: &mut DatabaseHandle,
     ) -> CommandStatus {
         self.start();
-        self.handler.operate(control, index, op_type, database)
+        let status = self.handler.operate(control, index, op_type, database);
+        self.record_command_event(control, index, status, database);
+        status
     }
 }
 
@@ -218,6 +332,75 @@ pub(crate) enum ControlHeader<'a> {
     TwoByteGroup41Var2(CountSequence<'a, Prefix<u16, Group41Var2>>),
     TwoByteGroup41Var3(CountSequence<'a, Prefix<u16, Group41Var3>>),
     TwoByteGroup41Var4(CountSequence<'a, Prefix<u16, Group41Var4>>),
+    Pattern(Group12Var2, PatternMask<'a>),
+}
+
+/// g12v3 header that selects the outputs operated by a pattern control block
+#[derive(Debug, PartialEq)]
+pub(crate) enum PatternMask<'a> {
+    OneByte(u8, u8, BitSequence<'a>),
+    TwoByte(u16, u16, BitSequence<'a>),
+}
+
+impl<'a> PatternMask<'a> {
+    fn selected(&self) -> impl Iterator<Item = u16> + 'a {
+        let bits = match self {
+            Self::OneByte(_, _, bits) => bits,
+            Self::TwoByte(_, _, bits) => bits,
+        };
+        bits.iter()
+            .filter_map(|(selected, index)| selected.then_some(index))
+    }
+
+    fn write(&self, writer: &mut HeaderWriter) -> Result<(), WriteError> {
+        match self {
+            Self::OneByte(start, stop, bits) => writer.write_packed_bits(
+                Variation::Group12Var3,
+                *start,
+                *stop,
+                bits.iter().map(|(x, _)| x),
+            ),
+            Self::TwoByte(start, stop, bits) => writer.write_packed_bits(
+                Variation::Group12Var3,
+                *start,
+                *stop,
+                bits.iter().map(|(x, _)| x),
+            ),
+        }
+    }
+}
+
+/// read the next control, pairing a pattern control block with the mask that follows it
+fn next_control_header<'a>(
+    iter: &mut HeaderIterator<'a>,
+) -> Option<Result<ControlHeader<'a>, BadControlHeader>> {
+    let header = iter.next()?;
+
+    let control = match &header.details {
+        HeaderDetails::OneByteCount(_, CountVariation::Group12Var2(seq)) => seq.single(),
+        _ => return Some(header.to_control_header()),
+    };
+
+    let result = match (control, iter.next()) {
+        (Some(control), Some(mask)) => match mask.details {
+            HeaderDetails::OneByteStartStop(start, stop, RangedVariation::Group12Var3(bits)) => Ok(
+                ControlHeader::Pattern(control, PatternMask::OneByte(start, stop, bits)),
+            ),
+            HeaderDetails::TwoByteStartStop(start, stop, RangedVariation::Group12Var3(bits)) => Ok(
+                ControlHeader::Pattern(control, PatternMask::TwoByte(start, stop, bits)),
+            ),
+            _ => Err(BadControlHeader::new(
+                mask.variation,
+                mask.details.qualifier(),
+            )),
+        },
+        _ => Err(BadControlHeader::new(
+            header.variation,
+            header.details.qualifier(),
+        )),
+    };
+
+    Some(result)
 }
 
 #[derive(Debug, PartialEq)]
@@ -246,11 +429,9 @@ impl<'a> ControlCollection<'a> {
 
     pub(crate) fn from(headers: HeaderCollection<'a>) -> Result<Self, BadControlHeader> {
         // do one pass to ensure that all headers are control headers
-        let non_control_header: Option<BadControlHeader> =
-            headers.iter().find_map(|x| x.to_control_header().err());
-
-        if let Some(err) = non_control_header {
-            return Err(err);
+        let mut iter = headers.iter();
+        while let Some(header) = next_control_header(&mut iter) {
+            header?;
         }
 
         Ok(ControlCollection { inner: headers })
@@ -346,11 +527,8 @@ impl<'a> Iterator for ControlHeaderIterator<'a> {
     type Item = ControlHeader<'a>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        match self.inner.next() {
-            None => None,
-            // this should always be some b/c of pre-validation
-            Some(x) => x.to_control_header().ok(),
-        }
+        // this should always be ok b/c of pre-validation
+        next_control_header(&mut self.inner)?.ok()
     }
 }
 
@@ -371,6 +549,9 @@ impl ControlHeader<'_> {
             Self::TwoByteGroup41Var2(seq) => respond_with_status(cursor, seq, status),
             Self::TwoByteGroup41Var3(seq) => respond_with_status(cursor, seq, status),
             Self::TwoByteGroup41Var4(seq) => respond_with_status(cursor, seq, status),
+            Self::Pattern(control, mask) => {
+                write_pattern(cursor, Group12Var2 { status, ..*control }, mask)
+            }
         }
     }
 
@@ -463,6 +644,17 @@ impl ControlHeader<'_> {
                 max_controls_per_request,
                 num_controls,
             ),
+            Self::Pattern(control, mask) => {
+                let status = apply_pattern(
+                    *control,
+                    mask,
+                    max_controls_per_request,
+                    num_controls,
+                    |crob, index| crob.select(transaction, index, database),
+                );
+                write_pattern(cursor, Group12Var2 { status, ..*control }, mask)?;
+                Ok(status)
+            }
         }
     }
 
@@ -566,6 +758,17 @@ impl ControlHeader<'_> {
                 max_controls_per_request,
                 num_controls,
             ),
+            Self::Pattern(control, mask) => {
+                let status = apply_pattern(
+                    *control,
+                    mask,
+                    max_controls_per_request,
+                    num_controls,
+                    |crob, index| crob.operate(transaction, index, operate_type, database),
+                );
+                write_pattern(cursor, Group12Var2 { status, ..*control }, mask)?;
+                Ok(status)
+            }
         }
     }
 
@@ -647,6 +850,22 @@ impl ControlHeader<'_> {
                 max_controls_per_request,
                 num_controls,
             ),
+            Self::Pattern(control, mask) => {
+                apply_pattern(
+                    *control,
+                    mask,
+                    max_controls_per_request,
+                    num_controls,
+                    |crob, index| {
+                        crob.operate(
+                            transaction,
+                            index,
+                            OperateType::DirectOperateNoAck,
+                            database,
+                        )
+                    },
+                );
+            }
         }
     }
 }
@@ -750,3 +969,37 @@ fn operate_header_no_ack<I, V>(
         *num_controls += 1;
     }
 }
+
+/// apply the pattern control block as a CROB to every output selected by the mask
+fn apply_pattern<F>(
+    control: Group12Var2,
+    mask: &PatternMask,
+    max_controls_per_request: Option<u16>,
+    num_controls: &mut u16,
+    mut action: F,
+) -> CommandStatus
+where
+    F: FnMut(Group12Var1, u16) -> CommandStatus,
+{
+    let mut ret = CommandStatus::Success;
+    for index in mask.selected() {
+        let status = if max_controls_per_request.map_or(true, |max| *num_controls < max) {
+            action(control.to_crob(), index)
+        } else {
+            CommandStatus::TooManyOps
+        };
+        ret = ret.first_error(status);
+        *num_controls += 1;
+    }
+    ret
+}
+
+fn write_pattern(
+    cursor: &mut WriteCursor,
+    control: Group12Var2,
+    mask: &PatternMask,
+) -> Result<(), WriteError> {
+    let mut writer = HeaderWriter::new(cursor);
+    writer.write_count_of_one(control)?;
+    mask.write(&mut writer)
+}
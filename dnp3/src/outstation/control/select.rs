@@ -34,6 +34,12 @@ impl SelectState {
         self.frame_id = new_frame_id;
     }
 
+    /// a challenged SELECT is considered to have been received when the reply arrives
+    pub(crate) fn update_request(&mut self, seq: Sequence, frame_id: u32) {
+        self.seq = seq;
+        self.frame_id = frame_id;
+    }
+
     pub(crate) fn match_operate(
         &self,
         timeout: Timeout,
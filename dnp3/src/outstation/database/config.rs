@@ -150,6 +150,57 @@ pub enum EventAnalogOutputStatusVariation {
     Group42Var8,
 }
 
+/// Enum representing all possible `BinaryOutputCommandEvent` variations
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub enum EventBinaryOutputCommandVariation {
+    /// Binary Output Command Event - without time
+    Group13Var1,
+    /// Binary Output Command Event - with time
+    Group13Var2,
+}
+
+/// Enum representing all possible `AnalogOutputCommandEvent` variations
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub enum EventAnalogOutputCommandVariation {
+    /// Analog Output Command Event - 32-bit
+    Group43Var1,
+    /// Analog Output Command Event - 16-bit
+    Group43Var2,
+    /// Analog Output Command Event - 32-bit with time
+    Group43Var3,
+    /// Analog Output Command Event - 16-bit with time
+    Group43Var4,
+    /// Analog Output Command Event - Single-precision
+    Group43Var5,
+    /// Analog Output Command Event - Double-precision
+    Group43Var6,
+    /// Analog Output Command Event - Single-precision with time
+    Group43Var7,
+    /// Analog Output Command Event - Double-precision with time
+    Group43Var8,
+}
+
+/// Enum representing all possible `SecurityStatistic` event variations
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub enum EventSecurityStatisticVariation {
+    /// Security Statistic Event - 32-bit with flag
+    Group122Var1,
+    /// Security Statistic Event - 32-bit with flag and time
+    Group122Var2,
+}
+
 // This is always g111vX
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(
@@ -158,6 +209,14 @@ pub enum EventAnalogOutputStatusVariation {
 )]
 pub(crate) struct EventOctetStringVariation;
 
+// Group 102 has no corresponding event group, so this is never transmitted
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub(crate) struct EventUnsignedIntegerVariation;
+
 /// Enum representing all possible `BinaryInput` static variations
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(
@@ -321,6 +380,22 @@ pub(crate) enum AnalogInputDeadBandVariation {
 )]
 pub(crate) struct StaticOctetStringVariation;
 
+// This is always g102v1
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub(crate) struct StaticUnsignedIntegerVariation;
+
+// This is always g121v1
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub(crate) struct StaticSecurityStatisticVariation;
+
 /// configuration for a `BinaryInput` point
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(
@@ -390,6 +465,35 @@ pub struct FrozenCounterConfig {
     pub deadband: u32,
 }
 
+/// How the deadband of an analog point is applied to detect events
+///
+/// In every mode, a change to the flags always produces an event and the deadband written by
+/// the master using group 34 replaces the configured deadband.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub enum DeadbandMode {
+    /// An event is produced when the value differs from the last reported value by more than
+    /// the deadband
+    #[default]
+    Fixed,
+    /// The deadband is a percentage of the full scale range of the point. An event is produced
+    /// when the value differs from the last reported value by more than
+    /// `deadband / 100 * (max - min)`.
+    PercentOfRange {
+        /// minimum value of the full scale range
+        min: f64,
+        /// maximum value of the full scale range
+        max: f64,
+    },
+    /// The deviation from the last reported value is accumulated on every update, and an event
+    /// is produced when the magnitude of the accumulated deviation exceeds the deadband. This
+    /// reports slow drifts that never exceed the deadband in a single update.
+    Integrating,
+}
+
 /// configuration for an `AnalogInput` point
 #[derive(Copy, Clone, Debug, PartialEq)]
 #[cfg_attr(
@@ -403,6 +507,9 @@ pub struct AnalogInputConfig {
     pub e_var: EventAnalogInputVariation,
     /// deadband - value of 0 means that any change will trigger an event
     pub deadband: f64,
+    /// how the deadband is applied
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub deadband_mode: DeadbandMode,
 }
 
 /// configuration for an `AnalogOutputStatus` point
@@ -418,6 +525,35 @@ pub struct AnalogOutputStatusConfig {
     pub e_var: EventAnalogOutputStatusVariation,
     /// deadband - value of 0 means that any change will trigger an event
     pub deadband: f64,
+    /// how the deadband is applied
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub deadband_mode: DeadbandMode,
+}
+
+/// configuration for a `BinaryOutputCommandEvent` point
+///
+/// Command events have no static value, so only the event variation is configurable
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct BinaryOutputCommandEventConfig {
+    /// default event variation
+    pub e_var: EventBinaryOutputCommandVariation,
+}
+
+/// configuration for an `AnalogOutputCommandEvent` point
+///
+/// Command events have no static value, so only the event variation is configurable
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct AnalogOutputCommandEventConfig {
+    /// default event variation
+    pub e_var: EventAnalogOutputCommandVariation,
 }
 
 /// Octet strings don't actually need any configuration b/c the transmitted variation is determined
@@ -429,6 +565,121 @@ pub struct AnalogOutputStatusConfig {
 )]
 pub struct OctetStringConfig;
 
+/// Unsigned integers (g102) only have a single static variation and no events, so there is
+/// nothing to configure. This struct is a placeholder required by the `Add` trait.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct UnsignedIntegerConfig;
+
+/// configuration for a `SecurityStatistic` point
+///
+/// Group 121 only defines a single static variation, so only the event variation is configurable
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct SecurityStatisticConfig {
+    /// default event variation
+    pub e_var: EventSecurityStatisticVariation,
+    /// deadband - value of 0 means that any change will trigger an event
+    pub deadband: u32,
+}
+
+/// Standard security statistics (g121/g122) and the point index at which each one is reported
+///
+/// The outstation session maintains the value of every statistic added to the database using
+/// [`SecurityStatisticConfig`] at the corresponding index. Statistics that the outstation has no
+/// way to detect (reply timeouts, rekeys, and critical messages sent) are never incremented.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub enum SecurityStatisticIndex {
+    /// Requests or confirmations received when the outstation didn't expect them
+    UnexpectedMessages,
+    /// Authenticated requests the user was not authorized to perform
+    AuthorizationFailures,
+    /// Authentication attempts that failed for any reason other than authorization
+    AuthenticationFailures,
+    /// Challenges that were not answered in time
+    ReplyTimeouts,
+    /// Session key changes forced by an authentication failure
+    RekeysDueToAuthenticationFailure,
+    /// Solicited responses sent to the master
+    ///
+    /// Unsolicited responses are not counted so that reporting this statistic
+    /// doesn't produce further unsolicited responses
+    TotalMessagesSent,
+    /// Requests received from the master
+    ///
+    /// Confirms are not counted so that reporting this statistic
+    /// doesn't produce further unsolicited responses
+    TotalMessagesReceived,
+    /// Critical messages sent to the master
+    CriticalMessagesSent,
+    /// Critical requests received from the master
+    CriticalMessagesReceived,
+    /// Malformed requests that the outstation could not process
+    DiscardedMessages,
+    /// Authentication errors (g120v7) sent to the master
+    ErrorMessagesSent,
+    /// Authentication errors received from the master
+    ErrorMessagesReceived,
+    /// Requests successfully authenticated
+    SuccessfulAuthentications,
+    /// Successful session key changes
+    SessionKeyChanges,
+    /// Failed session key changes
+    FailedSessionKeyChanges,
+    /// Successful update key changes
+    UpdateKeyChanges,
+    /// Failed update key changes
+    FailedUpdateKeyChanges,
+    /// Session key changes caused by a restart of the master or outstation
+    RekeysDueToRestarts,
+}
+
+impl SecurityStatisticIndex {
+    /// point index at which the statistic is reported
+    pub const fn index(self) -> u16 {
+        match self {
+            Self::UnexpectedMessages => 0,
+            Self::AuthorizationFailures => 1,
+            Self::AuthenticationFailures => 2,
+            Self::ReplyTimeouts => 3,
+            Self::RekeysDueToAuthenticationFailure => 4,
+            Self::TotalMessagesSent => 5,
+            Self::TotalMessagesReceived => 6,
+            Self::CriticalMessagesSent => 7,
+            Self::CriticalMessagesReceived => 8,
+            Self::DiscardedMessages => 9,
+            Self::ErrorMessagesSent => 10,
+            Self::ErrorMessagesReceived => 11,
+            Self::SuccessfulAuthentications => 12,
+            Self::SessionKeyChanges => 13,
+            Self::FailedSessionKeyChanges => 14,
+            Self::UpdateKeyChanges => 15,
+            Self::FailedUpdateKeyChanges => 16,
+            Self::RekeysDueToRestarts => 17,
+        }
+    }
+}
+
+/// Virtual terminal ports (g112/g113) have no static value and their objects are sized by
+/// variation, so there is nothing to configure. This struct is a placeholder required by the
+/// `Add` trait.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct VirtualTerminalConfig;
+
 impl BinaryInputConfig {
     /// construct a `BinaryConfig` from its fields
     pub fn new(s_var: StaticBinaryInputVariation, e_var: EventBinaryInputVariation) -> Self {
@@ -483,7 +734,7 @@ impl FrozenCounterConfig {
 }
 
 impl AnalogInputConfig {
-    /// construct an `AnalogConfig` from its fields
+    /// construct an `AnalogConfig` from its fields using [`DeadbandMode::Fixed`]
     pub fn new(
         s_var: StaticAnalogInputVariation,
         e_var: EventAnalogInputVariation,
@@ -493,12 +744,21 @@ impl AnalogInputConfig {
             s_var,
             e_var,
             deadband,
+            deadband_mode: DeadbandMode::Fixed,
+        }
+    }
+
+    /// select how the deadband is applied
+    pub fn with_deadband_mode(self, deadband_mode: DeadbandMode) -> Self {
+        Self {
+            deadband_mode,
+            ..self
         }
     }
 }
 
 impl AnalogOutputStatusConfig {
-    /// construct an `AnalogOutputStatusConfig` from its fields
+    /// construct an `AnalogOutputStatusConfig` from its fields using [`DeadbandMode::Fixed`]
     pub fn new(
         s_var: StaticAnalogOutputStatusVariation,
         e_var: EventAnalogOutputStatusVariation,
@@ -508,10 +768,40 @@ impl AnalogOutputStatusConfig {
             s_var,
             e_var,
             deadband,
+            deadband_mode: DeadbandMode::Fixed,
+        }
+    }
+
+    /// select how the deadband is applied
+    pub fn with_deadband_mode(self, deadband_mode: DeadbandMode) -> Self {
+        Self {
+            deadband_mode,
+            ..self
         }
     }
 }
 
+impl BinaryOutputCommandEventConfig {
+    /// construct a `BinaryOutputCommandEventConfig` from its fields
+    pub fn new(e_var: EventBinaryOutputCommandVariation) -> Self {
+        Self { e_var }
+    }
+}
+
+impl AnalogOutputCommandEventConfig {
+    /// construct an `AnalogOutputCommandEventConfig` from its fields
+    pub fn new(e_var: EventAnalogOutputCommandVariation) -> Self {
+        Self { e_var }
+    }
+}
+
+impl SecurityStatisticConfig {
+    /// construct a `SecurityStatisticConfig` from its fields
+    pub fn new(e_var: EventSecurityStatisticVariation, deadband: u32) -> Self {
+        Self { e_var, deadband }
+    }
+}
+
 impl Default for BinaryInputConfig {
     fn default() -> Self {
         Self::new(
@@ -578,3 +868,21 @@ impl Default for AnalogOutputStatusConfig {
         )
     }
 }
+
+impl Default for BinaryOutputCommandEventConfig {
+    fn default() -> Self {
+        Self::new(EventBinaryOutputCommandVariation::Group13Var1)
+    }
+}
+
+impl Default for AnalogOutputCommandEventConfig {
+    fn default() -> Self {
+        Self::new(EventAnalogOutputCommandVariation::Group43Var1)
+    }
+}
+
+impl Default for SecurityStatisticConfig {
+    fn default() -> Self {
+        Self::new(EventSecurityStatisticVariation::Group122Var1, 0)
+    }
+}
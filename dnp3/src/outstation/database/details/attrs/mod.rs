@@ -3,6 +3,7 @@ use crate::app::format::write::HeaderWriter;
 use crate::app::{Iin2, QualifierCode, Variation};
 use crate::outstation::database::details::attrs::map::SetMap;
 use crate::outstation::database::read::AttrHeader;
+use crate::outstation::database::Association;
 use scursor::WriteCursor;
 use std::collections::VecDeque;
 
@@ -169,17 +170,17 @@ impl Selection {
 
 pub(crate) struct AttrHandler {
     map: SetMap,
-    selection: Selection,
+    max_selected: usize,
+    // selected attributes, indexed by association
+    selections: Vec<Selection>,
 }
 
 impl AttrHandler {
     pub(crate) fn new(max_selected: usize) -> Self {
         Self {
             map: SetMap::default(),
-            selection: Selection {
-                max: max_selected,
-                selected: VecDeque::with_capacity(max_selected),
-            },
+            max_selected,
+            selections: Vec::new(),
         }
     }
 
@@ -187,15 +188,30 @@ impl AttrHandler {
         &mut self.map
     }
 
-    pub(crate) fn write(&mut self, cursor: &mut WriteCursor) -> bool {
-        self.selection.write_all(cursor, &self.map)
+    pub(crate) fn write(&mut self, association: Association, cursor: &mut WriteCursor) -> bool {
+        match self.selections.get_mut(association.index()) {
+            Some(selection) => selection.write_all(cursor, &self.map),
+            None => true,
+        }
     }
 
-    pub(crate) fn reset(&mut self) {
-        self.selection.clear();
+    pub(crate) fn reset(&mut self, association: Association) {
+        if let Some(selection) = self.selections.get_mut(association.index()) {
+            selection.clear();
+        }
     }
 
-    pub(crate) fn select(&mut self, header: AttrHeader) -> Iin2 {
+    pub(crate) fn select(&mut self, association: Association, header: AttrHeader) -> Iin2 {
+        let index = association.index();
+        if self.selections.len() <= index {
+            let max = self.max_selected;
+            self.selections.resize_with(index + 1, || Selection {
+                max,
+                selected: VecDeque::with_capacity(max),
+            });
+        }
+        let selection = &mut self.selections[index];
+
         match header {
             AttrHeader::All(var) => {
                 match var {
@@ -203,14 +219,14 @@ impl AttrHandler {
                         // list of variations for every set
                         let mut iin2 = Iin2::default();
                         for set in self.map.sets() {
-                            iin2 |= self.selection.push(Selected::single(set, 255));
+                            iin2 |= selection.push(Selected::single(set, 255));
                         }
                         iin2
                     }
                     254 => {
                         // all attributes in every set
                         self.map.sets().fold(Iin2::default(), |iin, set| {
-                            iin | self.selection.push(Selected::all(set))
+                            iin | selection.push(Selected::all(set))
                         })
                     }
                     _ => {
@@ -231,11 +247,11 @@ impl AttrHandler {
                     }
                 };
                 match var {
-                    254 => self.selection.push(Selected::all(set)),
-                    255 => self.selection.push(Selected::single(set, 255)),
+                    254 => selection.push(Selected::all(set)),
+                    255 => selection.push(Selected::single(set, 255)),
                     _ => {
                         if self.map.exists(set, var) {
-                            self.selection.push(Selected::single(set, var))
+                            selection.push(Selected::single(set, var))
                         } else {
                             Iin2::NO_FUNC_CODE_SUPPORT
                         }
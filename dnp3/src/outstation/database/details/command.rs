@@ -0,0 +1,84 @@
+use std::collections::BTreeMap;
+
+use crate::app::measurement::{AnalogOutputCommandEvent, BinaryOutputCommandEvent};
+use crate::outstation::database::config::*;
+use crate::outstation::database::details::event::buffer::Insertable;
+use crate::outstation::database::EventClass;
+
+/// Configuration of a point that only produces events, i.e. it has no static value
+#[derive(Copy, Clone)]
+pub(crate) struct EventPoint<V> {
+    pub(crate) class: Option<EventClass>,
+    pub(crate) variation: V,
+}
+
+type PointMap<V> = BTreeMap<u16, EventPoint<V>>;
+
+/// Command event types (g13 and g43) that are stored in [`CommandEventPoints`]
+pub(crate) trait CommandEvent: Insertable {
+    fn get_map(points: &CommandEventPoints) -> &PointMap<Self::EventVariation>;
+    fn get_mut_map(points: &mut CommandEventPoints) -> &mut PointMap<Self::EventVariation>;
+}
+
+/// Points for binary and analog output command events
+#[derive(Default)]
+pub(crate) struct CommandEventPoints {
+    binary: PointMap<EventBinaryOutputCommandVariation>,
+    analog: PointMap<EventAnalogOutputCommandVariation>,
+}
+
+impl CommandEventPoints {
+    pub(crate) fn add<T>(
+        &mut self,
+        index: u16,
+        class: Option<EventClass>,
+        variation: T::EventVariation,
+    ) -> bool
+    where
+        T: CommandEvent,
+    {
+        let map = T::get_mut_map(self);
+
+        if map.contains_key(&index) {
+            return false;
+        }
+
+        map.insert(index, EventPoint { class, variation });
+
+        true
+    }
+
+    pub(crate) fn remove<T>(&mut self, index: u16) -> bool
+    where
+        T: CommandEvent,
+    {
+        T::get_mut_map(self).remove(&index).is_some()
+    }
+
+    pub(crate) fn get<T>(&self, index: u16) -> Option<EventPoint<T::EventVariation>>
+    where
+        T: CommandEvent,
+    {
+        T::get_map(self).get(&index).copied()
+    }
+}
+
+impl CommandEvent for BinaryOutputCommandEvent {
+    fn get_map(points: &CommandEventPoints) -> &PointMap<Self::EventVariation> {
+        &points.binary
+    }
+
+    fn get_mut_map(points: &mut CommandEventPoints) -> &mut PointMap<Self::EventVariation> {
+        &mut points.binary
+    }
+}
+
+impl CommandEvent for AnalogOutputCommandEvent {
+    fn get_map(points: &CommandEventPoints) -> &PointMap<Self::EventVariation> {
+        &points.analog
+    }
+
+    fn get_mut_map(points: &mut CommandEventPoints) -> &mut PointMap<Self::EventVariation> {
+        &mut points.analog
+    }
+}
@@ -0,0 +1,254 @@
+use std::collections::{BTreeMap, VecDeque};
+
+use crate::app::data_set::*;
+use crate::app::format::write::HeaderWriter;
+use crate::app::format::WriteError;
+use crate::app::Timestamp;
+use crate::outstation::database::read::DataSetHeader;
+use crate::outstation::database::{Association, EventClass, EventMode, UpdateOptions};
+
+use scursor::WriteCursor;
+
+/// A data set defined by a descriptor along with its present value
+struct DataSetPoint {
+    class: Option<EventClass>,
+    descriptor: DataSetDescriptor,
+    /// types of the values with any prototypes expanded
+    types: Vec<DataSetType>,
+    value: Option<DataSet>,
+}
+
+#[derive(Copy, Clone)]
+enum Selected {
+    Prototype(u16),
+    Descriptor(u16),
+    PresentValue(u16),
+}
+
+/// Data set prototypes (g85), descriptors (g86), and present values (g87)
+#[derive(Default)]
+pub(crate) struct DataSets {
+    prototypes: BTreeMap<u16, DataSetPrototype>,
+    points: BTreeMap<u16, DataSetPoint>,
+    /// selected objects, indexed by association
+    selected: Vec<VecDeque<Selected>>,
+}
+
+impl DataSets {
+    pub(crate) fn define_prototype(&mut self, id: u16, prototype: DataSetPrototype) -> bool {
+        if self.prototypes.contains_key(&id)
+            || self.find_prototype(&prototype.uuid).is_some()
+            || DescriptorElements::encode(&prototype.elements).is_err()
+        {
+            return false;
+        }
+
+        self.prototypes.insert(id, prototype);
+        true
+    }
+
+    pub(crate) fn remove_prototype(&mut self, id: u16) -> bool {
+        self.prototypes.remove(&id).is_some()
+    }
+
+    pub(crate) fn add(
+        &mut self,
+        id: u16,
+        class: Option<EventClass>,
+        descriptor: DataSetDescriptor,
+    ) -> bool {
+        if self.points.contains_key(&id)
+            || DescriptorElements::encode(&descriptor.elements).is_err()
+        {
+            return false;
+        }
+
+        let types = match descriptor.value_types(|uuid| self.find_prototype(uuid)) {
+            Some(x) => x,
+            None => {
+                tracing::warn!("data set {id} references an undefined prototype");
+                return false;
+            }
+        };
+
+        self.points.insert(
+            id,
+            DataSetPoint {
+                class,
+                descriptor,
+                types,
+                value: None,
+            },
+        );
+
+        true
+    }
+
+    pub(crate) fn remove(&mut self, id: u16) -> bool {
+        self.points.remove(&id).is_some()
+    }
+
+    pub(crate) fn get(&self, id: u16) -> Option<DataSet> {
+        self.points.get(&id).and_then(|x| x.value.clone())
+    }
+
+    /// decode values written by the master using the types from the descriptor
+    pub(crate) fn decode(
+        &self,
+        id: u16,
+        time: Timestamp,
+        values: ValueElements,
+    ) -> Option<DataSet> {
+        let point = match self.points.get(&id) {
+            Some(x) => x,
+            None => {
+                tracing::warn!("data set {id} is not defined");
+                return None;
+            }
+        };
+
+        let data_set = DataSet::decode(time, values, &point.types);
+        if !data_set.matches(&point.types) {
+            tracing::warn!("values of data set {id} do not match the descriptor");
+            return None;
+        }
+
+        Some(data_set)
+    }
+
+    /// Update the present value, returning `Err` if the data set is not defined or the values
+    /// don't match the descriptor. If an event should be produced, the class is returned.
+    pub(crate) fn update(
+        &mut self,
+        id: u16,
+        value: &DataSet,
+        options: UpdateOptions,
+    ) -> Result<Option<EventClass>, ()> {
+        let point = self.points.get_mut(&id).ok_or(())?;
+
+        if !value.matches(&point.types) {
+            tracing::warn!("values of data set {id} do not match the descriptor");
+            return Err(());
+        }
+
+        if ValueElements::encode(&value.values).is_err() {
+            tracing::warn!("values of data set {id} are too long to be encoded");
+            return Err(());
+        }
+
+        let changed = point.value.as_ref() != Some(value);
+
+        if options.update_static {
+            point.value = Some(value.clone());
+        }
+
+        let class = match options.event_mode {
+            EventMode::Detect if changed => point.class,
+            EventMode::Force => point.class,
+            _ => None,
+        };
+
+        Ok(class)
+    }
+
+    pub(crate) fn select(&mut self, association: Association, header: DataSetHeader) {
+        let index = association.index();
+        if self.selected.len() <= index {
+            self.selected.resize_with(index + 1, VecDeque::new);
+        }
+        let selected = &mut self.selected[index];
+
+        match header {
+            DataSetHeader::Prototypes => {
+                selected.extend(self.prototypes.keys().map(|id| Selected::Prototype(*id)))
+            }
+            DataSetHeader::Descriptors => {
+                selected.extend(self.points.keys().map(|id| Selected::Descriptor(*id)))
+            }
+            DataSetHeader::PresentValues => selected.extend(
+                self.points
+                    .iter()
+                    .filter(|(_, point)| point.value.is_some())
+                    .map(|(id, _)| Selected::PresentValue(*id)),
+            ),
+        }
+    }
+
+    // return true if we wrote all selected objects
+    pub(crate) fn write(&mut self, association: Association, cursor: &mut WriteCursor) -> bool {
+        let index = association.index();
+        while let Some(selected) = self.selected.get(index).and_then(|x| x.front().copied()) {
+            let start = cursor.position();
+            if self
+                .write_one(selected, &mut HeaderWriter::new(cursor))
+                .is_err()
+            {
+                // out of space
+                let _ = cursor.seek_to(start);
+                return false;
+            }
+            self.selected[index].pop_front();
+        }
+        true
+    }
+
+    pub(crate) fn reset(&mut self, association: Association) {
+        if let Some(selected) = self.selected.get_mut(association.index()) {
+            selected.clear();
+        }
+    }
+
+    fn find_prototype(&self, uuid: &[u8]) -> Option<&DataSetPrototype> {
+        self.prototypes.values().find(|x| x.uuid == uuid)
+    }
+
+    fn write_one(&self, selected: Selected, writer: &mut HeaderWriter) -> Result<(), WriteError> {
+        // the selected object may have been removed since the READ was processed
+        match selected {
+            Selected::Prototype(id) => {
+                if let Some(prototype) = self.prototypes.get(&id) {
+                    let elements = DescriptorElements::encode(&prototype.elements)?;
+                    writer.write_free_format(&Group85Var1 {
+                        id: id as u32,
+                        uuid: prototype.uuid,
+                        elements: DescriptorElements::new(&elements),
+                    })?;
+                }
+            }
+            Selected::Descriptor(id) => {
+                if let Some(point) = self.points.get(&id) {
+                    let elements = DescriptorElements::encode(&point.descriptor.elements)?;
+                    writer.write_free_format(&Group86Var1 {
+                        id: id as u32,
+                        elements: DescriptorElements::new(&elements),
+                    })?;
+                }
+            }
+            Selected::PresentValue(id) => {
+                if let Some(value) = self.points.get(&id).and_then(|x| x.value.as_ref()) {
+                    let values = ValueElements::encode(&value.values)?;
+                    writer.write_free_format(&Group87Var1 {
+                        id: id as u32,
+                        time: value.time,
+                        values: ValueElements::new(&values),
+                    })?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// encode the complete g88v1 object header so that it can be stored in the event buffer
+pub(crate) fn encode_event(id: u16, value: &DataSet) -> Result<Box<[u8]>, WriteError> {
+    let values = ValueElements::encode(&value.values)?;
+    // object header, ID, and time elements are well under 32 bytes
+    let mut buffer = vec![0; 32 + values.len()];
+    let mut cursor = WriteCursor::new(&mut buffer);
+    HeaderWriter::new(&mut cursor).write_free_format(&Group88Var1 {
+        id: id as u32,
+        time: value.time,
+        values: ValueElements::new(&values),
+    })?;
+    Ok(cursor.written().into())
+}
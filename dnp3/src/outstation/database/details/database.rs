@@ -1,26 +1,48 @@
 use crate::app::Iin2;
 use crate::master::EventClasses;
-use crate::outstation::database::details::event::buffer::{EventBuffer, InsertError};
+use std::collections::BTreeMap;
+use std::time::SystemTime;
+
+use crate::app::data_set::{DataSet, DataSetDescriptor, DataSetPrototype, ValueElements};
+use crate::app::Timestamp;
+use crate::link::EndpointAddress;
+use crate::outstation::database::details::command::{CommandEvent, CommandEventPoints};
+use crate::outstation::database::details::data_set::DataSets;
+use crate::outstation::database::details::event::buffer::{
+    DataSetEvent, EventBuffer, FileTransferEvent, InsertError, VirtualTerminalEvent,
+};
+use crate::outstation::database::details::freeze::{FreezeEngine, FreezeTiming};
 use crate::outstation::database::details::range::static_db::{
-    PointConfig, StaticDatabase, Updatable, UpdatableFlags,
+    IndexRange, PointConfig, StaticDatabase, Updatable, UpdatableFlags,
 };
 use crate::outstation::database::read::ReadHeader;
 use crate::outstation::database::{
-    ClassZeroConfig, EventBufferConfig, ResponseInfo, UpdateFlagsType, UpdateInfo, UpdateOptions,
+    Association, ClassZeroConfig, DatabaseSubscription, EventBufferConfig, EventClass,
+    EventJournal, EventJournalError, EventMode, PointChange, PointValue, ResponseInfo, Subscribers,
+    UpdateFlagsType, UpdateInfo, UpdateOptions,
 };
 
 use crate::app::measurement::{
     AnalogInput, AnalogOutputStatus, BinaryInput, BinaryOutputStatus, Counter,
-    DoubleBitBinaryInput, Flags, FrozenCounter, Time,
+    DoubleBitBinaryInput, Flags, FrozenCounter, OctetString, SecurityStatistic, Time,
 };
 use crate::outstation::database::details::attrs::map::SetMap;
-use crate::outstation::{BufferState, OutstationApplication};
+use crate::outstation::{
+    AssignClassIndices, AssignClassType, BufferState, ClassAssignment, ClassCount, EventStatistics,
+    FreezeIndices, FreezeType, OfflineAssociationPolicy, OutstationApplication,
+};
 use scursor::WriteCursor;
 
 pub(crate) struct Database {
     static_db: StaticDatabase,
+    command_events: CommandEventPoints,
+    freeze: FreezeEngine,
+    data_sets: DataSets,
+    /// virtual terminal ports and the class of the events they produce
+    virtual_terminals: BTreeMap<u16, Option<EventClass>>,
     event_buffer: EventBuffer,
     attrs: super::attrs::AttrHandler,
+    subscribers: Subscribers,
 }
 
 impl Database {
@@ -31,54 +53,210 @@ impl Database {
     ) -> Self {
         Self {
             static_db: StaticDatabase::new(max_read_selection, class_zero_config),
+            command_events: CommandEventPoints::default(),
+            freeze: FreezeEngine::default(),
+            data_sets: DataSets::default(),
+            virtual_terminals: BTreeMap::new(),
             event_buffer: EventBuffer::new(config),
             attrs: super::attrs::AttrHandler::new(32),
+            subscribers: Subscribers::default(),
         }
     }
 
+    pub(crate) fn set_event_journal(
+        &mut self,
+        journal: Box<dyn EventJournal>,
+    ) -> Result<usize, EventJournalError> {
+        self.event_buffer.set_journal(journal)
+    }
+
+    pub(crate) fn subscribe(&mut self, capacity: usize) -> DatabaseSubscription {
+        self.subscribers.subscribe(capacity)
+    }
+
+    pub(crate) fn publish_changes(&mut self) {
+        self.subscribers.publish()
+    }
+
     pub(crate) fn get_attr_map(&mut self) -> &mut SetMap {
         self.attrs.get_attr_map()
     }
 
-    pub(crate) fn reset(&mut self) {
-        self.static_db.reset();
-        self.event_buffer.reset();
-        self.attrs.reset();
+    pub(crate) fn reset(&mut self, association: Association) {
+        self.static_db.reset(association);
+        self.event_buffer.reset(association);
+        self.attrs.reset(association);
+        self.data_sets.reset(association);
+    }
+
+    /// apply new event buffer limits and class 0 configuration, returning the number of discarded events
+    pub(crate) fn reconfigure(
+        &mut self,
+        class_zero: ClassZeroConfig,
+        event_config: EventBufferConfig,
+    ) -> usize {
+        self.static_db.set_class_zero(class_zero);
+        self.event_buffer.reconfigure(event_config)
     }
 
     pub(crate) fn set_analog_deadband(&mut self, index: u16, deadband: f64) -> bool {
         self.static_db.set_analog_deadband(index, deadband)
     }
 
+    /// true if the assignment names at least one point in the database
+    pub(crate) fn has_class_assignment_target(&self, assignment: ClassAssignment) -> bool {
+        let range = Self::get_assignment_range(assignment.indices);
+        match assignment.point_type {
+            AssignClassType::BinaryInput => self.static_db.has_points::<BinaryInput>(range),
+            AssignClassType::DoubleBitBinaryInput => {
+                self.static_db.has_points::<DoubleBitBinaryInput>(range)
+            }
+            AssignClassType::BinaryOutputStatus => {
+                self.static_db.has_points::<BinaryOutputStatus>(range)
+            }
+            AssignClassType::Counter => self.static_db.has_points::<Counter>(range),
+            AssignClassType::FrozenCounter => self.static_db.has_points::<FrozenCounter>(range),
+            AssignClassType::AnalogInput => self.static_db.has_points::<AnalogInput>(range),
+            AssignClassType::AnalogOutputStatus => {
+                self.static_db.has_points::<AnalogOutputStatus>(range)
+            }
+            AssignClassType::OctetString => self.static_db.has_points::<OctetString>(range),
+        }
+    }
+
+    fn get_assignment_range(indices: AssignClassIndices) -> Option<IndexRange> {
+        match indices {
+            AssignClassIndices::All => None,
+            AssignClassIndices::Range(start, stop) => Some(IndexRange::new(start, stop)),
+        }
+    }
+
+    pub(crate) fn assign_class(&mut self, assignment: ClassAssignment) {
+        let range = Self::get_assignment_range(assignment.indices);
+        let class = assignment.class;
+        match assignment.point_type {
+            AssignClassType::BinaryInput => {
+                self.static_db.assign_class::<BinaryInput>(range, class)
+            }
+            AssignClassType::DoubleBitBinaryInput => self
+                .static_db
+                .assign_class::<DoubleBitBinaryInput>(range, class),
+            AssignClassType::BinaryOutputStatus => self
+                .static_db
+                .assign_class::<BinaryOutputStatus>(range, class),
+            AssignClassType::Counter => self.static_db.assign_class::<Counter>(range, class),
+            AssignClassType::FrozenCounter => {
+                self.static_db.assign_class::<FrozenCounter>(range, class)
+            }
+            AssignClassType::AnalogInput => {
+                self.static_db.assign_class::<AnalogInput>(range, class)
+            }
+            AssignClassType::AnalogOutputStatus => self
+                .static_db
+                .assign_class::<AnalogOutputStatus>(range, class),
+            AssignClassType::OctetString => {
+                self.static_db.assign_class::<OctetString>(range, class)
+            }
+        }
+    }
+
+    pub(crate) fn add_association(
+        &mut self,
+        master: EndpointAddress,
+        policy: OfflineAssociationPolicy,
+        now: tokio::time::Instant,
+    ) -> Option<Association> {
+        self.event_buffer.add_association(master, policy, now)
+    }
+
+    pub(crate) fn is_master_registered(
+        &self,
+        association: Association,
+        master: EndpointAddress,
+    ) -> bool {
+        self.event_buffer.is_master_registered(association, master)
+    }
+
+    pub(crate) fn set_association_master(
+        &mut self,
+        association: Association,
+        master: EndpointAddress,
+    ) {
+        self.event_buffer
+            .set_association_master(association, master)
+    }
+
+    pub(crate) fn set_association_policy(
+        &mut self,
+        association: Association,
+        policy: OfflineAssociationPolicy,
+    ) {
+        self.event_buffer
+            .set_association_policy(association, policy)
+    }
+
+    pub(crate) fn set_association_online(
+        &mut self,
+        association: Association,
+        online: bool,
+        now: tokio::time::Instant,
+    ) {
+        self.event_buffer
+            .set_association_online(association, online, now)
+    }
+
     pub(crate) fn clear_written_events(
         &mut self,
+        association: Association,
+        now: tokio::time::Instant,
         app: &mut dyn OutstationApplication,
     ) -> BufferState {
-        self.event_buffer.clear_written(app);
+        self.event_buffer.clear_written(association, now, app);
         self.event_buffer.buffer_state()
     }
 
-    pub(crate) fn unwritten_classes(&self) -> EventClasses {
-        self.event_buffer.unwritten_classes()
+    pub(crate) fn unwritten_classes(&self, association: Association) -> EventClasses {
+        self.event_buffer.unwritten_classes(association)
+    }
+
+    pub(crate) fn unwritten_class_count(&self, association: Association) -> ClassCount {
+        self.event_buffer.unwritten_class_count(association)
+    }
+
+    pub(crate) fn is_overflown(&self, association: Association) -> bool {
+        self.event_buffer.is_overflown(association)
     }
 
-    pub(crate) fn is_overflown(&self) -> bool {
-        self.event_buffer.is_overflown()
+    pub(crate) fn event_statistics(&self) -> EventStatistics {
+        self.event_buffer.statistics()
     }
 
-    pub(crate) fn select_by_header(&mut self, header: ReadHeader) -> Iin2 {
+    pub(crate) fn select_by_header(
+        &mut self,
+        association: Association,
+        header: ReadHeader,
+    ) -> Iin2 {
         match header {
-            ReadHeader::Static(header) => self.static_db.select(header),
+            ReadHeader::Static(header) => self.static_db.select(association, header),
             ReadHeader::Event(header) => {
-                self.event_buffer.select_by_header(header);
+                self.event_buffer.select_by_header(association, header);
+                Iin2::default()
+            }
+            ReadHeader::Attr(header) => self.attrs.select(association, header),
+            ReadHeader::DataSet(header) => {
+                self.data_sets.select(association, header);
                 Iin2::default()
             }
-            ReadHeader::Attr(header) => self.attrs.select(header),
         }
     }
 
-    pub(crate) fn select_event_classes(&mut self, classes: EventClasses) -> usize {
-        self.event_buffer.select_by_class(classes, None)
+    pub(crate) fn select_event_classes(
+        &mut self,
+        association: Association,
+        classes: EventClasses,
+    ) -> usize {
+        self.event_buffer
+            .select_by_class(association, classes, None)
     }
 
     pub(crate) fn add<T>(&mut self, index: u16, config: PointConfig<T>) -> bool
@@ -102,6 +280,26 @@ impl Database {
         self.static_db.get::<T>(index)
     }
 
+    pub(crate) fn points<'a, T>(
+        &'a self,
+    ) -> impl Iterator<Item = (u16, &'a PointConfig<T>, &'a T)> + 'a
+    where
+        T: Updatable + 'a,
+    {
+        self.static_db.points::<T>()
+    }
+
+    pub(crate) fn restore<T>(&mut self, index: u16, value: &T) -> bool
+    where
+        T: Updatable,
+    {
+        self.static_db.restore(index, value)
+    }
+
+    pub(crate) fn clear_points(&mut self) {
+        self.static_db.clear()
+    }
+
     pub(crate) fn update_flags(
         &mut self,
         index: u16,
@@ -155,30 +353,340 @@ impl Database {
     where
         T: Updatable,
     {
+        // the prior state is only needed if someone is observing the changes
+        let prior = if self.subscribers.is_active() {
+            self.static_db.get_with_class::<T>(index)
+        } else {
+            None
+        };
+
         let (exists, event_data) = self.static_db.update(value, index, options);
 
         // if an event should be produced, insert it into the buffer
-        if let Some((variation, class)) = event_data {
+        let info = match event_data {
             // Overflow is handled in the event buffer
-            return match self.event_buffer.insert(index, class, value, variation) {
-                Ok(x) => UpdateInfo::Created(x),
-                Err(InsertError::TypeMaxIsZero) => UpdateInfo::NoEvent,
-                Err(InsertError::Overflow { created, discarded }) => {
-                    UpdateInfo::Overflow { created, discarded }
+            Some((variation, class)) => {
+                match value.insert_event(&mut self.event_buffer, index, class, variation) {
+                    Ok(x) => UpdateInfo::Created(x),
+                    Err(InsertError::MaxIsZero) => UpdateInfo::NoEvent,
+                    Err(InsertError::Overflow { created, discarded }) => {
+                        UpdateInfo::Overflow { created, discarded }
+                    }
                 }
+            }
+            None if exists => UpdateInfo::NoEvent,
+            None => UpdateInfo::NoPoint,
+        };
+
+        if let Some((old, class)) = prior {
+            let event_created = match info {
+                UpdateInfo::Created(_) => true,
+                UpdateInfo::Overflow { created, discarded } => created != discarded,
+                UpdateInfo::NoEvent | UpdateInfo::NoPoint => false,
             };
+            if event_created || (options.update_static && old != *value) {
+                let new: PointValue = value.clone().into();
+                self.subscribers.record(PointChange {
+                    point_type: new.point_type(),
+                    index,
+                    old: old.into(),
+                    new,
+                    class,
+                    event_created,
+                });
+            }
         }
 
-        if exists {
-            UpdateInfo::NoEvent
-        } else {
-            UpdateInfo::NoPoint
+        info
+    }
+
+    pub(crate) fn add_command_event<T>(
+        &mut self,
+        index: u16,
+        class: Option<EventClass>,
+        variation: T::EventVariation,
+    ) -> bool
+    where
+        T: CommandEvent,
+    {
+        self.command_events.add::<T>(index, class, variation)
+    }
+
+    pub(crate) fn remove_command_event<T>(&mut self, index: u16) -> bool
+    where
+        T: CommandEvent,
+    {
+        self.command_events.remove::<T>(index)
+    }
+
+    pub(crate) fn update_command_event<T>(
+        &mut self,
+        value: &T,
+        index: u16,
+        options: UpdateOptions,
+    ) -> UpdateInfo
+    where
+        T: CommandEvent,
+    {
+        let point = match self.command_events.get::<T>(index) {
+            None => return UpdateInfo::NoPoint,
+            Some(x) => x,
+        };
+
+        // command events have no static value to compare against, so every update is an event
+        let class = match (point.class, options.event_mode) {
+            (None, _) | (_, EventMode::Suppress) => return UpdateInfo::NoEvent,
+            (Some(class), _) => class,
+        };
+
+        match self
+            .event_buffer
+            .insert(index, class, value, point.variation)
+        {
+            Ok(x) => UpdateInfo::Created(x),
+            Err(InsertError::MaxIsZero) => UpdateInfo::NoEvent,
+            Err(InsertError::Overflow { created, discarded }) => {
+                UpdateInfo::Overflow { created, discarded }
+            }
+        }
+    }
+
+    pub(crate) fn define_data_set_prototype(
+        &mut self,
+        id: u16,
+        prototype: DataSetPrototype,
+    ) -> bool {
+        self.data_sets.define_prototype(id, prototype)
+    }
+
+    pub(crate) fn remove_data_set_prototype(&mut self, id: u16) -> bool {
+        self.data_sets.remove_prototype(id)
+    }
+
+    pub(crate) fn add_data_set(
+        &mut self,
+        id: u16,
+        class: Option<EventClass>,
+        descriptor: DataSetDescriptor,
+    ) -> bool {
+        self.data_sets.add(id, class, descriptor)
+    }
+
+    pub(crate) fn remove_data_set(&mut self, id: u16) -> bool {
+        self.data_sets.remove(id)
+    }
+
+    pub(crate) fn get_data_set(&self, id: u16) -> Option<DataSet> {
+        self.data_sets.get(id)
+    }
+
+    pub(crate) fn decode_data_set(
+        &self,
+        id: u16,
+        time: Timestamp,
+        values: ValueElements,
+    ) -> Option<DataSet> {
+        self.data_sets.decode(id, time, values)
+    }
+
+    pub(crate) fn update_data_set(
+        &mut self,
+        id: u16,
+        value: &DataSet,
+        options: UpdateOptions,
+    ) -> UpdateInfo {
+        let class = match self.data_sets.update(id, value, options) {
+            Err(()) => return UpdateInfo::NoPoint,
+            Ok(None) => return UpdateInfo::NoEvent,
+            Ok(Some(class)) => class,
+        };
+
+        let header = match super::data_set::encode_event(id, value) {
+            Ok(x) => x,
+            Err(_) => {
+                // lengths are validated when the value is updated
+                tracing::error!("unable to encode data set event");
+                return UpdateInfo::NoEvent;
+            }
+        };
+
+        match self
+            .event_buffer
+            .insert(id, class, &DataSetEvent(header), ())
+        {
+            Ok(x) => UpdateInfo::Created(x),
+            Err(InsertError::MaxIsZero) => UpdateInfo::NoEvent,
+            Err(InsertError::Overflow { created, discarded }) => {
+                UpdateInfo::Overflow { created, discarded }
+            }
+        }
+    }
+
+    pub(crate) fn add_virtual_terminal(&mut self, port: u16, class: Option<EventClass>) -> bool {
+        if self.virtual_terminals.contains_key(&port) {
+            return false;
         }
+        self.virtual_terminals.insert(port, class);
+        true
+    }
+
+    pub(crate) fn remove_virtual_terminal(&mut self, port: u16) -> bool {
+        self.virtual_terminals.remove(&port).is_some()
+    }
+
+    pub(crate) fn has_virtual_terminal(&self, port: u16) -> bool {
+        self.virtual_terminals.contains_key(&port)
+    }
+
+    pub(crate) fn push_virtual_terminal_data(&mut self, port: u16, data: &[u8]) -> bool {
+        let class = match self.virtual_terminals.get(&port) {
+            Some(x) => *x,
+            None => return false,
+        };
+
+        if let Some(class) = class {
+            // the length of each object is encoded in the variation
+            for block in data.chunks(u8::MAX as usize) {
+                let _ =
+                    self.event_buffer
+                        .insert(port, class, &VirtualTerminalEvent(block.into()), ());
+            }
+        }
+
+        true
+    }
+
+    pub(crate) fn link_frozen_counter(&mut self, counter: u16, frozen_counter: u16) -> bool {
+        if self.static_db.get::<Counter>(counter).is_none()
+            || self
+                .static_db
+                .get::<FrozenCounter>(frozen_counter)
+                .is_none()
+        {
+            return false;
+        }
+
+        self.freeze.link(counter, frozen_counter);
+        true
     }
 
-    pub(crate) fn write_response_headers(&mut self, cursor: &mut WriteCursor) -> ResponseInfo {
+    pub(crate) fn unlink_frozen_counter(&mut self, counter: u16) -> bool {
+        self.freeze.unlink(counter)
+    }
+
+    pub(crate) fn is_frozen_counter_linked(&self, counter: u16) -> bool {
+        self.freeze.is_linked(counter)
+    }
+
+    pub(crate) fn freeze_counters(&mut self, indices: FreezeIndices, freeze_type: FreezeType) {
+        match freeze_type {
+            FreezeType::ImmediateFreeze => self.freeze_now(indices, false),
+            FreezeType::FreezeAndClear => self.freeze_now(indices, true),
+            FreezeType::FreezeAtTime(interval) => {
+                let wall = match Timestamp::try_from_system_time(SystemTime::now()) {
+                    Some(x) => x,
+                    None => {
+                        tracing::warn!("unable to schedule freeze without a valid system time");
+                        return;
+                    }
+                };
+
+                if let FreezeTiming::Now =
+                    self.freeze
+                        .schedule(indices, interval, wall, tokio::time::Instant::now())
+                {
+                    self.freeze_now(indices, false);
+                }
+            }
+        }
+    }
+
+    pub(crate) fn next_freeze_deadline(&self) -> Option<tokio::time::Instant> {
+        self.freeze.next_deadline()
+    }
+
+    pub(crate) fn run_scheduled_freezes(&mut self, now: tokio::time::Instant) {
+        for indices in self.freeze.poll(now) {
+            self.freeze_now(indices, false);
+        }
+    }
+
+    fn freeze_now(&mut self, indices: FreezeIndices, clear: bool) {
+        let time = Timestamp::try_from_system_time(SystemTime::now()).map(Time::Synchronized);
+
+        for (counter_index, frozen_index) in self.freeze.links(indices) {
+            let counter = match self.static_db.get::<Counter>(counter_index) {
+                None => continue,
+                Some(x) => x,
+            };
+
+            let frozen = FrozenCounter {
+                value: counter.value,
+                flags: counter.flags,
+                time: time.or(counter.time),
+            };
+
+            // every freeze produces an event, even if the value hasn't changed
+            self.update(
+                &frozen,
+                frozen_index,
+                UpdateOptions::new(true, EventMode::Force),
+            );
+
+            if clear {
+                let cleared = Counter {
+                    value: 0,
+                    time: time.or(counter.time),
+                    ..counter
+                };
+                self.update(&cleared, counter_index, UpdateOptions::detect_event());
+            }
+        }
+    }
+
+    pub(crate) fn increment_security_statistic(&mut self, index: u16, association_id: u16) -> bool {
+        let current = match self.static_db.get::<SecurityStatistic>(index) {
+            None => return false,
+            Some(x) => x,
+        };
+
+        let time = Timestamp::try_from_system_time(SystemTime::now()).map(Time::Synchronized);
+
+        let statistic = SecurityStatistic {
+            association_id,
+            value: current.value.wrapping_add(1),
+            time: time.or(current.time),
+            ..current
+        };
+
+        self.update(&statistic, index, UpdateOptions::detect_event());
+        true
+    }
+
+    pub(crate) fn insert_file_transfer_event(
+        &mut self,
+        class: EventClass,
+        header: Box<[u8]>,
+    ) -> UpdateInfo {
+        match self
+            .event_buffer
+            .insert(0, class, &FileTransferEvent(header), ())
+        {
+            Ok(x) => UpdateInfo::Created(x),
+            Err(InsertError::MaxIsZero) => UpdateInfo::NoEvent,
+            Err(InsertError::Overflow { created, discarded }) => {
+                UpdateInfo::Overflow { created, discarded }
+            }
+        }
+    }
+
+    pub(crate) fn write_response_headers(
+        &mut self,
+        association: Association,
+        cursor: &mut WriteCursor,
+    ) -> ResponseInfo {
         // first we write events
-        let result = self.event_buffer.write_events(cursor);
+        let result = self.event_buffer.write_events(association, cursor);
         let has_events = match result {
             Ok(count) => count > 0,
             Err(count) => count > 0,
@@ -190,12 +698,19 @@ impl Database {
             false
         } else {
             // write all events to we can try to write all static data
-            self.static_db.write(cursor).is_ok()
+            self.static_db.write(association, cursor).is_ok()
         };
 
         // next write device attributes
         let complete = if complete {
-            self.attrs.write(cursor)
+            self.attrs.write(association, cursor)
+        } else {
+            false
+        };
+
+        // finally write data sets
+        let complete = if complete {
+            self.data_sets.write(association, cursor)
         } else {
             false
         };
@@ -206,9 +721,13 @@ impl Database {
         }
     }
 
-    pub(crate) fn write_events_only(&mut self, cursor: &mut WriteCursor) -> usize {
+    pub(crate) fn write_events_only(
+        &mut self,
+        association: Association,
+        cursor: &mut WriteCursor,
+    ) -> usize {
         // doesn't matter if we wrote all of them or not
-        match self.event_buffer.write_events(cursor) {
+        match self.event_buffer.write_events(association, cursor) {
             Ok(x) => x,
             Err(x) => x,
         }
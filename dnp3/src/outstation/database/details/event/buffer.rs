@@ -3,18 +3,27 @@ use std::fmt::Debug;
 use std::ops::BitOr;
 
 use crate::app::measurement::{self};
+use crate::link::EndpointAddress;
 use crate::master::EventClasses;
 use crate::outstation::database::config::*;
 use crate::outstation::database::read::EventReadHeader;
-use crate::outstation::database::{EventBufferConfig, EventClass};
+use crate::outstation::database::{
+    Association, EventBufferConfig, EventClass, EventJournal, EventJournalError,
+    EventOverflowPolicy, JournaledEvent, JournaledValue,
+};
 
 use crate::util::BadWrite;
 
-use super::list::VecList;
+use super::list::{Index, VecList};
 use super::writer::EventWriter;
 
-use crate::outstation::database::details::event::traits::OctetStringLength;
-use crate::outstation::{BufferState, ClassCount, OutstationApplication, TypeCount};
+use crate::outstation::database::details::event::traits::{
+    OctetStringLength, VirtualTerminalLength,
+};
+use crate::outstation::{
+    BufferState, ClassCount, EventStatistics, OfflineAssociationPolicy, OutstationApplication,
+    TypeCount,
+};
 use scursor::WriteCursor;
 
 impl From<EventClass> for EventClasses {
@@ -153,6 +162,43 @@ impl ClassCounter {
             EventClass::Class3 => self.num_class_3.decrement(),
         }
     }
+
+    fn get(&self, class: EventClass) -> usize {
+        match class {
+            EventClass::Class1 => self.num_class_1.get(),
+            EventClass::Class2 => self.num_class_2.get(),
+            EventClass::Class3 => self.num_class_3.get(),
+        }
+    }
+}
+
+/// maximum number of buffered events of the same type as the event
+fn get_max_for(config: &EventBufferConfig, event: &Event) -> u16 {
+    match event {
+        Event::Binary(_, _) => config.max_binary,
+        Event::DoubleBitBinary(_, _) => config.max_double_binary,
+        Event::BinaryOutputStatus(_, _) => config.max_binary_output_status,
+        Event::Counter(_, _) => config.max_counter,
+        Event::FrozenCounter(_, _) => config.max_frozen_counter,
+        Event::Analog(_, _) => config.max_analog,
+        Event::AnalogOutputStatus(_, _) => config.max_analog_output_status,
+        Event::OctetString(_, _) => config.max_octet_string,
+        Event::FileTransfer(_) => config.max_file_transfer,
+        Event::BinaryOutputCommand(_, _) => config.max_binary_output_command,
+        Event::AnalogOutputCommand(_, _) => config.max_analog_output_command,
+        Event::DataSet(_) => config.max_data_set,
+        Event::VirtualTerminal(_) => config.max_virtual_terminal,
+        Event::SecurityStatistic(_, _) => config.max_security_statistic,
+    }
+}
+
+/// larger values have a lower priority when discarding events
+fn discard_rank(class: EventClass) -> u8 {
+    match class {
+        EventClass::Class1 => 1,
+        EventClass::Class2 => 2,
+        EventClass::Class3 => 3,
+    }
 }
 
 #[derive(Copy, Clone)]
@@ -165,6 +211,12 @@ pub(crate) struct TypeCounter {
     num_analog: Count,
     num_analog_output_status: Count,
     num_octet_string: Count,
+    num_file_transfer: Count,
+    num_binary_output_command: Count,
+    num_analog_output_command: Count,
+    num_data_set: Count,
+    num_virtual_terminal: Count,
+    num_security_statistic: Count,
 }
 
 impl From<TypeCounter> for TypeCount {
@@ -178,6 +230,12 @@ impl From<TypeCounter> for TypeCount {
             num_analog: value.num_analog.value,
             num_analog_output_status: value.num_analog_output_status.value,
             num_octet_string: value.num_octet_string.value,
+            num_file_transfer: value.num_file_transfer.value,
+            num_binary_output_command: value.num_binary_output_command.value,
+            num_analog_output_command: value.num_analog_output_command.value,
+            num_data_set: value.num_data_set.value,
+            num_virtual_terminal: value.num_virtual_terminal.value,
+            num_security_statistic: value.num_security_statistic.value,
         }
     }
 }
@@ -193,6 +251,12 @@ impl TypeCounter {
             num_analog: Count::new(),
             num_analog_output_status: Count::new(),
             num_octet_string: Count::new(),
+            num_file_transfer: Count::new(),
+            num_binary_output_command: Count::new(),
+            num_analog_output_command: Count::new(),
+            num_data_set: Count::new(),
+            num_virtual_terminal: Count::new(),
+            num_security_statistic: Count::new(),
         }
     }
 
@@ -205,6 +269,12 @@ impl TypeCounter {
         self.num_analog.zero();
         self.num_analog_output_status.zero();
         self.num_octet_string.zero();
+        self.num_file_transfer.zero();
+        self.num_binary_output_command.zero();
+        self.num_analog_output_command.zero();
+        self.num_data_set.zero();
+        self.num_virtual_terminal.zero();
+        self.num_security_statistic.zero();
     }
 
     fn increment(&mut self, event: &Event) {
@@ -217,6 +287,26 @@ impl TypeCounter {
        }
     */
 
+    fn get(&self, event: &Event) -> usize {
+        let count = match event {
+            Event::Binary(_, _) => self.num_binary,
+            Event::DoubleBitBinary(_, _) => self.num_double_binary,
+            Event::BinaryOutputStatus(_, _) => self.num_binary_output_status,
+            Event::Counter(_, _) => self.num_counter,
+            Event::FrozenCounter(_, _) => self.num_frozen_counter,
+            Event::Analog(_, _) => self.num_analog,
+            Event::AnalogOutputStatus(_, _) => self.num_analog_output_status,
+            Event::OctetString(_, _) => self.num_octet_string,
+            Event::FileTransfer(_) => self.num_file_transfer,
+            Event::BinaryOutputCommand(_, _) => self.num_binary_output_command,
+            Event::AnalogOutputCommand(_, _) => self.num_analog_output_command,
+            Event::DataSet(_) => self.num_data_set,
+            Event::VirtualTerminal(_) => self.num_virtual_terminal,
+            Event::SecurityStatistic(_, _) => self.num_security_statistic,
+        };
+        count.get()
+    }
+
     fn modify<F>(&mut self, event: &Event, op: F)
     where
         F: Fn(&mut Count),
@@ -230,6 +320,12 @@ impl TypeCounter {
             Event::Analog(_, _) => op(&mut self.num_analog),
             Event::AnalogOutputStatus(_, _) => op(&mut self.num_analog_output_status),
             Event::OctetString(_, _) => op(&mut self.num_octet_string),
+            Event::FileTransfer(_) => op(&mut self.num_file_transfer),
+            Event::BinaryOutputCommand(_, _) => op(&mut self.num_binary_output_command),
+            Event::AnalogOutputCommand(_, _) => op(&mut self.num_analog_output_command),
+            Event::DataSet(_) => op(&mut self.num_data_set),
+            Event::VirtualTerminal(_) => op(&mut self.num_virtual_terminal),
+            Event::SecurityStatistic(_, _) => op(&mut self.num_security_statistic),
         }
     }
 }
@@ -278,6 +374,12 @@ impl Counters {
             Event::Analog(_, _) => self.types.num_analog.decrement(),
             Event::AnalogOutputStatus(_, _) => self.types.num_analog_output_status.decrement(),
             Event::OctetString(_, _) => self.types.num_octet_string.decrement(),
+            Event::FileTransfer(_) => self.types.num_file_transfer.decrement(),
+            Event::BinaryOutputCommand(_, _) => self.types.num_binary_output_command.decrement(),
+            Event::AnalogOutputCommand(_, _) => self.types.num_analog_output_command.decrement(),
+            Event::DataSet(_) => self.types.num_data_set.decrement(),
+            Event::VirtualTerminal(_) => self.types.num_virtual_terminal.decrement(),
+            Event::SecurityStatistic(_, _) => self.types.num_security_statistic.decrement(),
         }
     }
 }
@@ -335,6 +437,23 @@ pub(crate) enum Event {
         Variation<EventAnalogOutputStatusVariation>,
     ),
     OctetString(Box<[u8]>, Variation<EventOctetStringVariation>),
+    /// complete free-format g70 object header
+    FileTransfer(Box<[u8]>),
+    BinaryOutputCommand(
+        measurement::BinaryOutputCommandEvent,
+        Variation<EventBinaryOutputCommandVariation>,
+    ),
+    AnalogOutputCommand(
+        measurement::AnalogOutputCommandEvent,
+        Variation<EventAnalogOutputCommandVariation>,
+    ),
+    /// complete free-format g88 object header
+    DataSet(Box<[u8]>),
+    VirtualTerminal(VirtualTerminalEvent),
+    SecurityStatistic(
+        measurement::SecurityStatistic,
+        Variation<EventSecurityStatisticVariation>,
+    ),
 }
 
 impl Event {
@@ -348,6 +467,40 @@ impl Event {
             Event::Analog(_, v) => v.select_default(),
             Event::AnalogOutputStatus(_, v) => v.select_default(),
             Event::OctetString(_, v) => v.select_default(),
+            Event::FileTransfer(_) => {}
+            Event::BinaryOutputCommand(_, v) => v.select_default(),
+            Event::AnalogOutputCommand(_, v) => v.select_default(),
+            Event::DataSet(_) => {}
+            Event::VirtualTerminal(_) => {}
+            Event::SecurityStatistic(_, v) => v.select_default(),
+        }
+    }
+
+    fn journaled_value(&self) -> JournaledValue {
+        match &self {
+            Event::Binary(evt, v) => JournaledValue::Binary(*evt, v.default),
+            Event::DoubleBitBinary(evt, v) => JournaledValue::DoubleBitBinary(*evt, v.default),
+            Event::BinaryOutputStatus(evt, v) => {
+                JournaledValue::BinaryOutputStatus(*evt, v.default)
+            }
+            Event::Counter(evt, v) => JournaledValue::Counter(*evt, v.default),
+            Event::FrozenCounter(evt, v) => JournaledValue::FrozenCounter(*evt, v.default),
+            Event::Analog(evt, v) => JournaledValue::Analog(*evt, v.default),
+            Event::AnalogOutputStatus(evt, v) => {
+                JournaledValue::AnalogOutputStatus(*evt, v.default)
+            }
+            // the length was checked when the event was created
+            Event::OctetString(evt, _) => JournaledValue::OctetString(evt.to_vec()),
+            Event::FileTransfer(header) => JournaledValue::FileTransfer(header.to_vec()),
+            Event::BinaryOutputCommand(evt, v) => {
+                JournaledValue::BinaryOutputCommand(*evt, v.default)
+            }
+            Event::AnalogOutputCommand(evt, v) => {
+                JournaledValue::AnalogOutputCommand(*evt, v.default)
+            }
+            Event::DataSet(header) => JournaledValue::DataSet(header.to_vec()),
+            Event::VirtualTerminal(evt) => JournaledValue::VirtualTerminal(evt.0.to_vec()),
+            Event::SecurityStatistic(evt, v) => JournaledValue::SecurityStatistic(*evt, v.default),
         }
     }
 
@@ -368,6 +521,18 @@ impl Event {
             Event::OctetString(evt, _) => {
                 writer.write(cursor, evt, index, OctetStringLength(evt.len()))
             }
+            Event::FileTransfer(header) => writer.write_free_format(cursor, header),
+            Event::BinaryOutputCommand(evt, v) => {
+                writer.write(cursor, evt, index, v.selected.get())
+            }
+            Event::AnalogOutputCommand(evt, v) => {
+                writer.write(cursor, evt, index, v.selected.get())
+            }
+            Event::DataSet(header) => writer.write_free_format(cursor, header),
+            Event::VirtualTerminal(evt) => {
+                writer.write(cursor, evt, index, VirtualTerminalLength(evt.0.len()))
+            }
+            Event::SecurityStatistic(evt, v) => writer.write(cursor, evt, index, v.selected.get()),
         }
     }
 }
@@ -377,6 +542,8 @@ enum EventState {
     Unselected,
     Selected,
     Written,
+    /// confirmed by the master of the association, but retained until every association confirms it
+    Confirmed,
 }
 
 #[derive(Debug, PartialEq)]
@@ -385,17 +552,31 @@ pub(crate) struct EventRecord {
     id: u64,
     class: EventClass,
     event: Event,
-    state: Cell<EventState>,
+    /// state of the event within each association, indexed by `Association::index`
+    states: Vec<Cell<EventState>>,
 }
 
 impl EventRecord {
-    fn new(index: u16, id: u64, class: EventClass, event: Event) -> Self {
+    fn new(index: u16, id: u64, class: EventClass, event: Event, associations: usize) -> Self {
         Self {
             index,
             id,
             class,
             event,
-            state: Cell::new(EventState::Unselected),
+            states: vec![Cell::new(EventState::Unselected); associations],
+        }
+    }
+
+    fn state(&self, association: Association) -> &Cell<EventState> {
+        &self.states[association.index()]
+    }
+
+    fn journaled(&self) -> JournaledEvent {
+        JournaledEvent {
+            id: self.id,
+            index: self.index,
+            class: self.class,
+            value: self.event.journaled_value(),
         }
     }
 }
@@ -406,25 +587,79 @@ pub(crate) trait Insertable: Sized {
     fn get_max(config: &EventBufferConfig) -> u16;
     fn get_type_count(counter: &TypeCounter) -> usize;
     fn is_type(record: &EventRecord) -> bool;
-    fn decrement_type(counter: &mut TypeCounter);
     fn increment_type(counter: &mut TypeCounter);
     fn create_event(&self, default_variation: Self::EventVariation) -> Event;
     // set the selected variation if the record is of this type
     fn select_variation(record: &EventRecord, variation: Self::EventVariation) -> bool;
 }
 
+/// Values that may be inserted into the event buffer when they are updated
+pub(crate) trait EventSource {
+    type EventVariation: Copy;
+
+    fn insert_event(
+        &self,
+        buffer: &mut EventBuffer,
+        index: u16,
+        class: EventClass,
+        default_variation: Self::EventVariation,
+    ) -> Result<u64, InsertError>;
+}
+
+impl<T> EventSource for T
+where
+    T: Insertable,
+{
+    type EventVariation = <T as Insertable>::EventVariation;
+
+    fn insert_event(
+        &self,
+        buffer: &mut EventBuffer,
+        index: u16,
+        class: EventClass,
+        default_variation: Self::EventVariation,
+    ) -> Result<u64, InsertError> {
+        buffer.insert(index, class, self, default_variation)
+    }
+}
+
+/// master registered with the buffer
+struct AssociationInfo {
+    master: EndpointAddress,
+    policy: OfflineAssociationPolicy,
+    /// time at which the master disconnected, or `None` if it is connected
+    offline_since: Option<tokio::time::Instant>,
+    /// true if events were discarded since the master last confirmed events with space available
+    is_overflown: bool,
+}
+
+impl AssociationInfo {
+    fn retains_events(&self, now: tokio::time::Instant) -> bool {
+        match self.offline_since {
+            None => true,
+            Some(since) => self.policy.retains_events(since, now),
+        }
+    }
+}
+
 pub(crate) struct EventBuffer {
     config: EventBufferConfig,
+    /// masters registered with the buffer, indexed by `Association::index`
+    associations: Vec<AssociationInfo>,
     events: VecList<EventRecord>,
     total: Counters,
-    written: Counters,
-    is_overflown: bool,
+    /// events written to each association, but not yet confirmed
+    written: Vec<Counters>,
+    /// events confirmed by each association, but retained for the others
+    confirmed: Vec<Counters>,
     next: u64,
+    journal: Option<Box<dyn EventJournal>>,
+    statistics: EventStatistics,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub(crate) enum InsertError {
-    TypeMaxIsZero,
+    MaxIsZero,
     Overflow { created: u64, discarded: u64 },
 }
 
@@ -433,23 +668,228 @@ impl EventBuffer {
         let max_size = config.max_events();
         Self {
             config,
+            associations: Vec::new(),
             events: VecList::new(max_size),
             total: Counters::new(),
-            written: Counters::new(),
-            is_overflown: false,
+            written: Vec::new(),
+            confirmed: Vec::new(),
             next: 0,
+            journal: None,
+            statistics: EventStatistics::default(),
+        }
+    }
+
+    /// register the association of a master, returning `None` if the address is already registered
+    ///
+    /// The association is offline until the master connects. Buffered events are reported to it
+    /// like any new event.
+    pub(crate) fn add_association(
+        &mut self,
+        master: EndpointAddress,
+        policy: OfflineAssociationPolicy,
+        now: tokio::time::Instant,
+    ) -> Option<Association> {
+        if self.associations.iter().any(|x| x.master == master) {
+            return None;
+        }
+
+        self.associations.push(AssociationInfo {
+            master,
+            policy,
+            offline_since: Some(now),
+            is_overflown: false,
+        });
+        self.written.push(Counters::new());
+        self.confirmed.push(Counters::new());
+        self.events
+            .for_each_mut(|record| record.states.push(Cell::new(EventState::Unselected)));
+
+        Some(Association(self.associations.len() - 1))
+    }
+
+    /// true if an association other than the specified one is registered with the address
+    pub(crate) fn is_master_registered(
+        &self,
+        association: Association,
+        master: EndpointAddress,
+    ) -> bool {
+        self.associations
+            .iter()
+            .enumerate()
+            .any(|(i, x)| i != association.index() && x.master == master)
+    }
+
+    pub(crate) fn set_association_master(
+        &mut self,
+        association: Association,
+        master: EndpointAddress,
+    ) {
+        self.associations[association.index()].master = master;
+    }
+
+    pub(crate) fn set_association_policy(
+        &mut self,
+        association: Association,
+        policy: OfflineAssociationPolicy,
+    ) {
+        self.associations[association.index()].policy = policy;
+    }
+
+    pub(crate) fn set_association_online(
+        &mut self,
+        association: Association,
+        online: bool,
+        now: tokio::time::Instant,
+    ) {
+        let info = &mut self.associations[association.index()];
+        match (online, info.offline_since) {
+            (true, _) => info.offline_since = None,
+            (false, None) => info.offline_since = Some(now),
+            // keep the time at which the master first disconnected
+            (false, Some(_)) => {}
+        }
+    }
+
+    /// apply new limits to the buffer, returning the number of events discarded to satisfy them
+    ///
+    /// Buffered events are retained unless a type or class holds more events than its new limit,
+    /// in which case the excess events are selected using the new overflow policy.
+    pub(crate) fn reconfigure(&mut self, config: EventBufferConfig) -> usize {
+        self.config = config;
+
+        let mut discarded = 0;
+        while let Some(index) = self.select_excess() {
+            if self.discard(index).is_none() {
+                break;
+            }
+            discarded += 1;
+        }
+
+        if discarded > 0 {
+            self.set_overflown();
+        }
+
+        // every type is now within its limit, so the events always fit
+        self.events.set_capacity(self.config.max_events());
+
+        discarded
+    }
+
+    /// select an event of a type or class that exceeds its limit
+    fn select_excess(&self) -> Option<Index> {
+        let is_excess = |record: &EventRecord| {
+            self.total.types.get(&record.event) > get_max_for(&self.config, &record.event) as usize
+                || self
+                    .config
+                    .max_class(record.class)
+                    .is_some_and(|x| self.total.classes.get(record.class) > x as usize)
+        };
+
+        match self.config.overflow_policy {
+            EventOverflowPolicy::DiscardOldest => self.events.find_first(&is_excess),
+            EventOverflowPolicy::DiscardNewest => self
+                .events
+                .iter()
+                .filter(|(_, r)| is_excess(r))
+                .last()
+                .map(|(index, _)| index),
+            EventOverflowPolicy::DiscardLowestClass => {
+                // the oldest event of the lowest priority class
+                let mut lowest: Option<(Index, EventClass)> = None;
+                for (index, record) in self.events.iter().filter(|(_, r)| is_excess(r)) {
+                    match lowest {
+                        Some((_, x)) if discard_rank(x) >= discard_rank(record.class) => {}
+                        _ => lowest = Some((index, record.class)),
+                    }
+                }
+                lowest.map(|(index, _)| index)
+            }
+        }
+    }
+
+    /// restore the events recorded in the journal and record all subsequent changes to the buffer
+    pub(crate) fn set_journal(
+        &mut self,
+        mut journal: Box<dyn EventJournal>,
+    ) -> Result<usize, EventJournalError> {
+        if self.next != 0 {
+            return Err(EventJournalError::EventsAlreadyCreated);
+        }
+
+        // restored events are not counted as generated or discarded
+        let statistics = self.statistics;
+        let mut count = 0;
+        let mut removed = Vec::new();
+        for event in journal.load()? {
+            // restored events keep the identifier they were created with
+            let id = event.id;
+            self.next = id;
+            match self.restore(event) {
+                Ok(_) => count += 1,
+                Err(InsertError::MaxIsZero) => removed.push(id),
+                // replaces a previously restored event
+                Err(InsertError::Overflow { discarded, .. }) => removed.push(discarded),
+            }
+            self.next = id + 1;
+        }
+
+        for id in removed {
+            if let Err(err) = journal.record_remove(id) {
+                tracing::warn!("unable to journal removal of event {id}: {err}");
+            }
+        }
+
+        self.statistics = statistics;
+        self.journal = Some(journal);
+        Ok(count)
+    }
+
+    fn restore(&mut self, event: JournaledEvent) -> Result<u64, InsertError> {
+        let index = event.index;
+        let class = event.class;
+        match event.value {
+            JournaledValue::Binary(x, v) => self.insert(index, class, &x, v),
+            JournaledValue::DoubleBitBinary(x, v) => self.insert(index, class, &x, v),
+            JournaledValue::BinaryOutputStatus(x, v) => self.insert(index, class, &x, v),
+            JournaledValue::Counter(x, v) => self.insert(index, class, &x, v),
+            JournaledValue::FrozenCounter(x, v) => self.insert(index, class, &x, v),
+            JournaledValue::Analog(x, v) => self.insert(index, class, &x, v),
+            JournaledValue::AnalogOutputStatus(x, v) => self.insert(index, class, &x, v),
+            JournaledValue::OctetString(x) => match measurement::OctetString::new(&x) {
+                Ok(x) => self.insert(index, class, &x, EventOctetStringVariation),
+                // an octet string that is too long can never be reported
+                Err(_) => Err(InsertError::MaxIsZero),
+            },
+            JournaledValue::FileTransfer(x) => {
+                self.insert(index, class, &FileTransferEvent(x.into()), ())
+            }
+            JournaledValue::BinaryOutputCommand(x, v) => self.insert(index, class, &x, v),
+            JournaledValue::AnalogOutputCommand(x, v) => self.insert(index, class, &x, v),
+            JournaledValue::DataSet(x) => self.insert(index, class, &DataSetEvent(x.into()), ()),
+            JournaledValue::VirtualTerminal(x) => {
+                self.insert(index, class, &VirtualTerminalEvent(x.into()), ())
+            }
+            JournaledValue::SecurityStatistic(x, v) => self.insert(index, class, &x, v),
         }
     }
 
-    pub(crate) fn unwritten_classes(&self) -> EventClasses {
-        let unwritten = self.total.classes.subtract(&self.written.classes);
+    pub(crate) fn unwritten_classes(&self, association: Association) -> EventClasses {
+        let unwritten = self.unwritten_class_count(association);
         EventClasses::new(
-            unwritten.num_class_1.value > 0,
-            unwritten.num_class_2.value > 0,
-            unwritten.num_class_3.value > 0,
+            unwritten.num_class_1 > 0,
+            unwritten.num_class_2 > 0,
+            unwritten.num_class_3 > 0,
         )
     }
 
+    pub(crate) fn unwritten_class_count(&self, association: Association) -> ClassCount {
+        self.total
+            .classes
+            .subtract(&self.written[association.index()].classes)
+            .subtract(&self.confirmed[association.index()].classes)
+            .into()
+    }
+
     pub(crate) fn insert<T>(
         &mut self,
         index: u16,
@@ -461,31 +901,59 @@ impl EventBuffer {
         T: Insertable,
     {
         let max = T::get_max(&self.config);
+        let class_max = self.config.max_class(class);
 
-        if max == 0 {
-            return Err(InsertError::TypeMaxIsZero);
+        if max == 0 || class_max == Some(0) {
+            return Err(InsertError::MaxIsZero);
         }
 
         let id = self.next;
         self.next += 1;
-
-        let ret = if T::get_type_count(&self.total.types) == max as usize {
-            if let Some(record) = self.events.remove_first(T::is_type) {
-                T::decrement_type(&mut self.total.types);
-                self.total.classes.decrement(record.class);
-                self.is_overflown = true;
+        self.statistics.get_mut(class).generated += 1;
+
+        let type_full = T::get_type_count(&self.total.types) >= max as usize;
+        let class_full = class_max.is_some_and(|x| self.total.classes.get(class) >= x as usize);
+
+        let ret = if type_full || class_full {
+            self.set_overflown();
+            // only discarding an event of the same type and/or class makes room for the new event
+            let makes_room = |record: &EventRecord| {
+                (!type_full || T::is_type(record)) && (!class_full || record.class == class)
+            };
+            let discarded = match self.select_discarded(class, makes_room) {
+                Some(index) => self.discard(index),
+                None => {
+                    // the new event is not buffered
+                    self.statistics.get_mut(class).discarded += 1;
+                    return Err(InsertError::Overflow {
+                        created: id,
+                        discarded: id,
+                    });
+                }
+            };
+            discarded.map_or(Ok(id), |discarded| {
                 Err(InsertError::Overflow {
                     created: id,
-                    discarded: record.id,
+                    discarded,
                 })
-            } else {
-                Ok(id)
-            }
+            })
         } else {
             Ok(id)
         };
 
-        let record = EventRecord::new(index, id, class, event.create_event(default_variation));
+        let record = EventRecord::new(
+            index,
+            id,
+            class,
+            event.create_event(default_variation),
+            self.associations.len(),
+        );
+
+        if let Some(journal) = &mut self.journal {
+            if let Err(err) = journal.record_insert(&record.journaled()) {
+                tracing::warn!("unable to journal event {id}: {err}");
+            }
+        }
 
         self.events.add(record);
         self.total.classes.increment(class);
@@ -494,40 +962,101 @@ impl EventBuffer {
         ret
     }
 
-    pub(crate) fn select_by_header(&mut self, header: EventReadHeader) -> usize {
+    /// select a buffered event to discard in favor of a new event of the specified class, or
+    /// `None` if the new event should be discarded
+    fn select_discarded<F>(&self, class: EventClass, makes_room: F) -> Option<Index>
+    where
+        F: Fn(&EventRecord) -> bool,
+    {
+        match self.config.overflow_policy {
+            EventOverflowPolicy::DiscardOldest => self.events.find_first(&makes_room),
+            EventOverflowPolicy::DiscardNewest => None,
+            EventOverflowPolicy::DiscardLowestClass => {
+                // the oldest event of the lowest priority class
+                let mut lowest: Option<(Index, EventClass)> = None;
+                for (index, record) in self.events.iter().filter(|(_, r)| makes_room(r)) {
+                    match lowest {
+                        Some((_, x)) if discard_rank(x) >= discard_rank(record.class) => {}
+                        _ => lowest = Some((index, record.class)),
+                    }
+                }
+                let (index, lowest) = lowest?;
+                if discard_rank(class) > discard_rank(lowest) {
+                    None
+                } else {
+                    Some(index)
+                }
+            }
+        }
+    }
+
+    /// remove a buffered event returning its id
+    fn discard(&mut self, index: Index) -> Option<u64> {
+        let record = self.events.remove(index)?;
+        self.statistics.get_mut(record.class).discarded += 1;
+        self.total.decrement(record);
+        remove_from_associations(record, &mut self.written, &mut self.confirmed);
+        if let Some(journal) = &mut self.journal {
+            if let Err(err) = journal.record_remove(record.id) {
+                tracing::warn!("unable to journal removal of event {}: {err}", record.id);
+            }
+        }
+        Some(record.id)
+    }
+
+    pub(crate) fn select_by_header(
+        &mut self,
+        association: Association,
+        header: EventReadHeader,
+    ) -> usize {
         match header {
             EventReadHeader::Class1(limit) => {
-                self.select_by_class(EventClass::Class1.into(), limit)
+                self.select_by_class(association, EventClass::Class1.into(), limit)
             }
             EventReadHeader::Class2(limit) => {
-                self.select_by_class(EventClass::Class2.into(), limit)
+                self.select_by_class(association, EventClass::Class2.into(), limit)
             }
             EventReadHeader::Class3(limit) => {
-                self.select_by_class(EventClass::Class3.into(), limit)
+                self.select_by_class(association, EventClass::Class3.into(), limit)
             }
             EventReadHeader::Binary(v, limit) => {
-                self.select_by_type::<measurement::BinaryInput>(v, limit)
+                self.select_by_type::<measurement::BinaryInput>(association, v, limit)
             }
             EventReadHeader::DoubleBitBinary(v, limit) => {
-                self.select_by_type::<measurement::DoubleBitBinaryInput>(v, limit)
+                self.select_by_type::<measurement::DoubleBitBinaryInput>(association, v, limit)
             }
             EventReadHeader::BinaryOutputStatus(v, limit) => {
-                self.select_by_type::<measurement::BinaryOutputStatus>(v, limit)
+                self.select_by_type::<measurement::BinaryOutputStatus>(association, v, limit)
             }
             EventReadHeader::Counter(v, limit) => {
-                self.select_by_type::<measurement::Counter>(v, limit)
+                self.select_by_type::<measurement::Counter>(association, v, limit)
             }
             EventReadHeader::FrozenCounter(v, limit) => {
-                self.select_by_type::<measurement::FrozenCounter>(v, limit)
+                self.select_by_type::<measurement::FrozenCounter>(association, v, limit)
             }
             EventReadHeader::Analog(v, limit) => {
-                self.select_by_type::<measurement::AnalogInput>(v, limit)
+                self.select_by_type::<measurement::AnalogInput>(association, v, limit)
             }
             EventReadHeader::AnalogOutputStatus(v, limit) => {
-                self.select_by_type::<measurement::AnalogOutputStatus>(v, limit)
+                self.select_by_type::<measurement::AnalogOutputStatus>(association, v, limit)
             }
             EventReadHeader::OctetString(limit) => {
-                self.select_by_type::<measurement::OctetString>(None, limit)
+                self.select_by_type::<measurement::OctetString>(association, None, limit)
+            }
+            EventReadHeader::BinaryOutputCommand(v, limit) => {
+                self.select_by_type::<measurement::BinaryOutputCommandEvent>(association, v, limit)
+            }
+            EventReadHeader::AnalogOutputCommand(v, limit) => {
+                self.select_by_type::<measurement::AnalogOutputCommandEvent>(association, v, limit)
+            }
+            EventReadHeader::DataSet(limit) => {
+                self.select_by_type::<DataSetEvent>(association, None, limit)
+            }
+            EventReadHeader::VirtualTerminal(limit) => {
+                self.select_by_type::<VirtualTerminalEvent>(association, None, limit)
+            }
+            EventReadHeader::SecurityStatistic(v, limit) => {
+                self.select_by_type::<measurement::SecurityStatistic>(association, v, limit)
             }
             EventReadHeader::FrozenAnalog(_, _) => {
                 // not currently supported
@@ -536,8 +1065,13 @@ impl EventBuffer {
         }
     }
 
-    pub(crate) fn select_by_class(&mut self, classes: EventClasses, limit: Option<usize>) -> usize {
-        self.select(limit, |e| {
+    pub(crate) fn select_by_class(
+        &mut self,
+        association: Association,
+        classes: EventClasses,
+        limit: Option<usize>,
+    ) -> usize {
+        self.select(association, limit, |e| {
             if classes.matches(e.class) {
                 e.event.select_default_variation();
                 true
@@ -549,6 +1083,7 @@ impl EventBuffer {
 
     fn select_by_type<T>(
         &mut self,
+        association: Association,
         specific_variation: Option<T::EventVariation>,
         limit: Option<usize>,
     ) -> usize
@@ -556,27 +1091,32 @@ impl EventBuffer {
         T: Insertable,
     {
         match specific_variation {
-            Some(x) => self.select_specific_variation::<T>(limit, x),
-            None => self.select_default_variation::<T>(limit),
+            Some(x) => self.select_specific_variation::<T>(association, limit, x),
+            None => self.select_default_variation::<T>(association, limit),
         }
     }
 
     fn select_specific_variation<T>(
         &mut self,
+        association: Association,
         limit: Option<usize>,
         variation: T::EventVariation,
     ) -> usize
     where
         T: Insertable,
     {
-        self.select(limit, |e| T::select_variation(e, variation))
+        self.select(association, limit, |e| T::select_variation(e, variation))
     }
 
-    fn select_default_variation<T>(&mut self, limit: Option<usize>) -> usize
+    fn select_default_variation<T>(
+        &mut self,
+        association: Association,
+        limit: Option<usize>,
+    ) -> usize
     where
         T: Insertable,
     {
-        self.select(limit, |rec| {
+        self.select(association, limit, |rec| {
             if T::is_type(rec) {
                 rec.event.select_default_variation();
                 true
@@ -586,12 +1126,16 @@ impl EventBuffer {
         })
     }
 
-    pub(crate) fn write_events(&mut self, cursor: &mut WriteCursor) -> Result<usize, usize> {
+    pub(crate) fn write_events(
+        &mut self,
+        association: Association,
+        cursor: &mut WriteCursor,
+    ) -> Result<usize, usize> {
         let mut count = 0;
         let mut writer = EventWriter::new();
-        let mut counters = self.written;
+        let mut counters = self.written[association.index()];
         let mut complete = true;
-        for record in self.selected_iter() {
+        for record in self.selected_iter(association) {
             if record
                 .event
                 .write(record.index, cursor, &mut writer)
@@ -602,10 +1146,10 @@ impl EventBuffer {
             }
 
             counters.increment(record);
-            record.state.set(EventState::Written);
+            record.state(association).set(EventState::Written);
             count += 1;
         }
-        self.written = counters;
+        self.written[association.index()] = counters;
 
         if complete {
             Ok(count)
@@ -614,21 +1158,64 @@ impl EventBuffer {
         }
     }
 
-    pub(crate) fn clear_written(&mut self, app: &mut dyn OutstationApplication) -> usize {
+    /// mark the events written to the association as confirmed, removing every event that has
+    /// been confirmed by all of the associations for which events are retained
+    ///
+    /// Events previously confirmed by this association are also removed once the associations
+    /// that have not confirmed them stop retaining events.
+    pub(crate) fn clear_written(
+        &mut self,
+        association: Association,
+        now: tokio::time::Instant,
+        app: &mut dyn OutstationApplication,
+    ) -> usize {
+        // the confirming association always retains its own events
+        let retained: Vec<bool> = self
+            .associations
+            .iter()
+            .enumerate()
+            .map(|(i, x)| i == association.index() || x.retains_events(now))
+            .collect();
         let total = &mut self.total;
+        let written = &mut self.written;
+        let confirmed = &mut self.confirmed;
+        let journal = &mut self.journal;
+        let statistics = &mut self.statistics;
         let count = self.events.remove_all(|event| {
-            if event.state.get() == EventState::Written {
+            if event.state(association).get() == EventState::Written {
+                event.state(association).set(EventState::Confirmed);
+                confirmed[association.index()].increment(event);
+            }
+
+            if event.state(association).get() != EventState::Confirmed {
+                return false;
+            }
+
+            // events are only removed once they have been confirmed by every association that retains them
+            if event
+                .states
+                .iter()
+                .zip(retained.iter())
+                .all(|(state, retained)| !retained || state.get() == EventState::Confirmed)
+            {
                 app.event_cleared(event.id);
+                statistics.get_mut(event.class).confirmed += 1;
+                if let Some(journal) = journal {
+                    if let Err(err) = journal.record_remove(event.id) {
+                        tracing::warn!("unable to journal removal of event {}: {err}", event.id);
+                    }
+                }
                 total.decrement(event);
+                remove_from_associations(event, written, confirmed);
                 true
             } else {
                 false
             }
         });
 
-        self.written.zero();
+        self.written[association.index()].zero();
         if !self.is_any_full() {
-            self.is_overflown = false;
+            self.associations[association.index()].is_overflown = false;
         }
         count
     }
@@ -637,15 +1224,29 @@ impl EventBuffer {
         self.total.into()
     }
 
-    pub(crate) fn reset(&mut self) {
+    pub(crate) fn reset(&mut self, association: Association) {
         for (_, r) in self.events.iter() {
-            r.state.set(EventState::Unselected);
+            let state = r.state(association);
+            if state.get() != EventState::Confirmed {
+                state.set(EventState::Unselected);
+            }
+        }
+        self.written[association.index()].zero();
+    }
+
+    pub(crate) fn is_overflown(&self, association: Association) -> bool {
+        self.associations[association.index()].is_overflown
+    }
+
+    /// events were discarded, which is reported to every association
+    fn set_overflown(&mut self) {
+        for association in self.associations.iter_mut() {
+            association.is_overflown = true;
         }
-        self.written.zero();
     }
 
-    pub(crate) fn is_overflown(&self) -> bool {
-        self.is_overflown
+    pub(crate) fn statistics(&self) -> EventStatistics {
+        self.statistics
     }
 
     fn is_any_full(&self) -> bool {
@@ -657,6 +1258,22 @@ impl EventBuffer {
             || self.is_full::<measurement::AnalogInput>()
             || self.is_full::<measurement::AnalogOutputStatus>()
             || self.is_full::<measurement::OctetString>()
+            || self.is_full::<FileTransferEvent>()
+            || self.is_full::<measurement::BinaryOutputCommandEvent>()
+            || self.is_full::<measurement::AnalogOutputCommandEvent>()
+            || self.is_full::<DataSetEvent>()
+            || self.is_full::<VirtualTerminalEvent>()
+            || self.is_full::<measurement::SecurityStatistic>()
+            || self.is_class_full(EventClass::Class1)
+            || self.is_class_full(EventClass::Class2)
+            || self.is_class_full(EventClass::Class3)
+    }
+
+    fn is_class_full(&self, class: EventClass) -> bool {
+        match self.config.max_class(class) {
+            None | Some(0) => false,
+            Some(max) => self.total.classes.get(class) >= max as usize,
+        }
     }
 
     fn is_full<T>(&self) -> bool
@@ -672,7 +1289,7 @@ impl EventBuffer {
         T::get_type_count(&self.total.types) >= max as usize
     }
 
-    fn select<F>(&mut self, limit: Option<usize>, selector: F) -> usize
+    fn select<F>(&mut self, association: Association, limit: Option<usize>, selector: F) -> usize
     where
         F: Fn(&EventRecord) -> bool,
     {
@@ -681,10 +1298,10 @@ impl EventBuffer {
         for (_, evt) in self
             .events
             .iter()
-            .filter(|(_, e)| e.state.get() == EventState::Unselected && selector(e))
+            .filter(|(_, e)| e.state(association).get() == EventState::Unselected && selector(e))
             .take(limit.unwrap_or(usize::MAX))
         {
-            evt.state.set(EventState::Selected);
+            evt.state(association).set(EventState::Selected);
             count += 1;
         }
 
@@ -692,11 +1309,31 @@ impl EventBuffer {
     }
 
     /// iterator over selected values that need to be written
-    fn selected_iter(&self) -> impl Iterator<Item = &EventRecord> {
+    fn selected_iter(&self, association: Association) -> impl Iterator<Item = &EventRecord> {
         self.events
             .iter()
             .map(|x| x.1)
-            .filter(|x| x.state.get() == EventState::Selected)
+            .filter(move |x| x.state(association).get() == EventState::Selected)
+    }
+}
+
+/// decrement the counters of each association from which the event is removed
+fn remove_from_associations(
+    record: &EventRecord,
+    written: &mut [Counters],
+    confirmed: &mut [Counters],
+) {
+    for ((state, written), confirmed) in record
+        .states
+        .iter()
+        .zip(written.iter_mut())
+        .zip(confirmed.iter_mut())
+    {
+        match state.get() {
+            EventState::Written => written.decrement(record),
+            EventState::Confirmed => confirmed.decrement(record),
+            EventState::Unselected | EventState::Selected => {}
+        }
     }
 }
 
@@ -715,10 +1352,6 @@ impl Insertable for measurement::BinaryInput {
         std::matches!(record.event, Event::Binary(_, _))
     }
 
-    fn decrement_type(counter: &mut TypeCounter) {
-        counter.num_binary.decrement();
-    }
-
     fn increment_type(counter: &mut TypeCounter) {
         counter.num_binary.increment();
     }
@@ -752,10 +1385,6 @@ impl Insertable for measurement::DoubleBitBinaryInput {
         std::matches!(record.event, Event::DoubleBitBinary(_, _))
     }
 
-    fn decrement_type(counter: &mut TypeCounter) {
-        counter.num_double_binary.decrement();
-    }
-
     fn increment_type(counter: &mut TypeCounter) {
         counter.num_double_binary.increment();
     }
@@ -789,10 +1418,6 @@ impl Insertable for measurement::BinaryOutputStatus {
         std::matches!(record.event, Event::BinaryOutputStatus(_, _))
     }
 
-    fn decrement_type(counter: &mut TypeCounter) {
-        counter.num_binary_output_status.decrement();
-    }
-
     fn increment_type(counter: &mut TypeCounter) {
         counter.num_binary_output_status.increment();
     }
@@ -826,10 +1451,6 @@ impl Insertable for measurement::Counter {
         std::matches!(record.event, Event::Counter(_, _))
     }
 
-    fn decrement_type(counter: &mut TypeCounter) {
-        counter.num_counter.decrement();
-    }
-
     fn increment_type(counter: &mut TypeCounter) {
         counter.num_counter.increment();
     }
@@ -863,10 +1484,6 @@ impl Insertable for measurement::FrozenCounter {
         std::matches!(record.event, Event::FrozenCounter(_, _))
     }
 
-    fn decrement_type(counter: &mut TypeCounter) {
-        counter.num_frozen_counter.decrement();
-    }
-
     fn increment_type(counter: &mut TypeCounter) {
         counter.num_frozen_counter.increment();
     }
@@ -900,10 +1517,6 @@ impl Insertable for measurement::AnalogInput {
         std::matches!(record.event, Event::Analog(_, _))
     }
 
-    fn decrement_type(counter: &mut TypeCounter) {
-        counter.num_analog.decrement();
-    }
-
     fn increment_type(counter: &mut TypeCounter) {
         counter.num_analog.increment();
     }
@@ -937,10 +1550,6 @@ impl Insertable for measurement::AnalogOutputStatus {
         std::matches!(record.event, Event::AnalogOutputStatus(_, _))
     }
 
-    fn decrement_type(counter: &mut TypeCounter) {
-        counter.num_analog_output_status.decrement();
-    }
-
     fn increment_type(counter: &mut TypeCounter) {
         counter.num_analog_output_status.increment();
     }
@@ -974,10 +1583,6 @@ impl Insertable for measurement::OctetString {
         std::matches!(record.event, Event::OctetString(_, _))
     }
 
-    fn decrement_type(counter: &mut TypeCounter) {
-        counter.num_octet_string.decrement();
-    }
-
     fn increment_type(counter: &mut TypeCounter) {
         counter.num_octet_string.increment();
     }
@@ -996,73 +1601,351 @@ impl Insertable for measurement::OctetString {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::app::measurement::*;
-    use crate::app::MaybeAsync;
-    use std::collections::VecDeque;
+impl Insertable for measurement::BinaryOutputCommandEvent {
+    type EventVariation = EventBinaryOutputCommandVariation;
 
-    use super::*;
+    fn get_max(config: &EventBufferConfig) -> u16 {
+        config.max_binary_output_command
+    }
 
-    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
-    enum Event {
-        Clear(u64),
+    fn get_type_count(counter: &TypeCounter) -> usize {
+        counter.num_binary_output_command.get()
     }
 
-    #[derive(Default)]
-    struct MockApplication {
-        events: VecDeque<Event>,
+    fn is_type(record: &EventRecord) -> bool {
+        std::matches!(record.event, Event::BinaryOutputCommand(_, _))
     }
 
-    impl OutstationApplication for MockApplication {
-        fn begin_confirm(&mut self) {
-            unreachable!()
-        }
-        fn event_cleared(&mut self, id: u64) {
-            self.events.push_back(Event::Clear(id));
-        }
-        fn end_confirm(&mut self, _state: BufferState) -> MaybeAsync<()> {
-            unreachable!()
+    fn increment_type(counter: &mut TypeCounter) {
+        counter.num_binary_output_command.increment();
+    }
+
+    fn create_event(&self, default_variation: EventBinaryOutputCommandVariation) -> Event {
+        Event::BinaryOutputCommand(*self, Variation::new(default_variation))
+    }
+
+    fn select_variation(record: &EventRecord, variation: Self::EventVariation) -> bool {
+        if let Event::BinaryOutputCommand(_, v) = &record.event {
+            v.selected.set(variation);
+            true
+        } else {
+            false
         }
     }
+}
 
-    fn insert_events(buffer: &mut EventBuffer) {
-        buffer
-            .insert(
-                1,
-                EventClass::Class1,
-                &BinaryInput::new(true, Flags::ONLINE, Time::synchronized(0)),
-                EventBinaryInputVariation::Group2Var1,
-            )
-            .unwrap();
+impl Insertable for measurement::AnalogOutputCommandEvent {
+    type EventVariation = EventAnalogOutputCommandVariation;
 
-        buffer
-            .insert(
-                2,
-                EventClass::Class2,
-                &Counter::new(23, Flags::ONLINE, Time::synchronized(0)),
-                EventCounterVariation::Group22Var2,
-            )
-            .unwrap();
+    fn get_max(config: &EventBufferConfig) -> u16 {
+        config.max_analog_output_command
+    }
 
-        buffer
-            .insert(
-                3,
-                EventClass::Class3,
-                &DoubleBitBinaryInput::new(
-                    DoubleBit::DeterminedOn,
-                    Flags::ONLINE,
-                    Time::synchronized(0),
-                ),
-                EventDoubleBitBinaryInputVariation::Group4Var3,
-            )
-            .unwrap();
+    fn get_type_count(counter: &TypeCounter) -> usize {
+        counter.num_analog_output_command.get()
+    }
 
-        buffer
-            .insert(
-                4,
-                EventClass::Class2,
-                &BinaryInput::new(true, Flags::ONLINE, Time::synchronized(1234)),
+    fn is_type(record: &EventRecord) -> bool {
+        std::matches!(record.event, Event::AnalogOutputCommand(_, _))
+    }
+
+    fn increment_type(counter: &mut TypeCounter) {
+        counter.num_analog_output_command.increment();
+    }
+
+    fn create_event(&self, default_variation: EventAnalogOutputCommandVariation) -> Event {
+        Event::AnalogOutputCommand(*self, Variation::new(default_variation))
+    }
+
+    fn select_variation(record: &EventRecord, variation: Self::EventVariation) -> bool {
+        if let Event::AnalogOutputCommand(_, v) = &record.event {
+            v.selected.set(variation);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl Insertable for measurement::SecurityStatistic {
+    type EventVariation = EventSecurityStatisticVariation;
+
+    fn get_max(config: &EventBufferConfig) -> u16 {
+        config.max_security_statistic
+    }
+
+    fn get_type_count(counter: &TypeCounter) -> usize {
+        counter.num_security_statistic.get()
+    }
+
+    fn is_type(record: &EventRecord) -> bool {
+        std::matches!(record.event, Event::SecurityStatistic(_, _))
+    }
+
+    fn increment_type(counter: &mut TypeCounter) {
+        counter.num_security_statistic.increment();
+    }
+
+    fn create_event(&self, default_variation: EventSecurityStatisticVariation) -> Event {
+        Event::SecurityStatistic(*self, Variation::new(default_variation))
+    }
+
+    fn select_variation(record: &EventRecord, variation: Self::EventVariation) -> bool {
+        if let Event::SecurityStatistic(_, v) = &record.event {
+            v.selected.set(variation);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+// Group 102 has no event group. The buffer never accepts these events because the maximum
+// is always zero, so `create_event` is never invoked.
+// there is no event group for g102, so unsigned integers never produce events
+impl EventSource for measurement::UnsignedInteger {
+    type EventVariation = EventUnsignedIntegerVariation;
+
+    fn insert_event(
+        &self,
+        _buffer: &mut EventBuffer,
+        _index: u16,
+        _class: EventClass,
+        _default_variation: EventUnsignedIntegerVariation,
+    ) -> Result<u64, InsertError> {
+        Err(InsertError::MaxIsZero)
+    }
+}
+
+/// File transfer object (g70v4, g70v5 or g70v6) reported as an event
+pub(crate) struct FileTransferEvent(pub(crate) Box<[u8]>);
+
+impl Insertable for FileTransferEvent {
+    type EventVariation = ();
+
+    fn get_max(config: &EventBufferConfig) -> u16 {
+        config.max_file_transfer
+    }
+
+    fn get_type_count(counter: &TypeCounter) -> usize {
+        counter.num_file_transfer.get()
+    }
+
+    fn is_type(record: &EventRecord) -> bool {
+        std::matches!(record.event, Event::FileTransfer(_))
+    }
+
+    fn increment_type(counter: &mut TypeCounter) {
+        counter.num_file_transfer.increment();
+    }
+
+    fn create_event(&self, _default_variation: ()) -> Event {
+        Event::FileTransfer(self.0.clone())
+    }
+
+    fn select_variation(record: &EventRecord, _variation: ()) -> bool {
+        Self::is_type(record)
+    }
+}
+
+/// Data set snapshot object (g88v1) reported as an event
+pub(crate) struct DataSetEvent(pub(crate) Box<[u8]>);
+
+impl Insertable for DataSetEvent {
+    type EventVariation = ();
+
+    fn get_max(config: &EventBufferConfig) -> u16 {
+        config.max_data_set
+    }
+
+    fn get_type_count(counter: &TypeCounter) -> usize {
+        counter.num_data_set.get()
+    }
+
+    fn is_type(record: &EventRecord) -> bool {
+        std::matches!(record.event, Event::DataSet(_))
+    }
+
+    fn increment_type(counter: &mut TypeCounter) {
+        counter.num_data_set.increment();
+    }
+
+    fn create_event(&self, _default_variation: ()) -> Event {
+        Event::DataSet(self.0.clone())
+    }
+
+    fn select_variation(record: &EventRecord, _variation: ()) -> bool {
+        Self::is_type(record)
+    }
+}
+
+/// Block of virtual terminal data (g113) reported as an event
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct VirtualTerminalEvent(pub(crate) Box<[u8]>);
+
+impl Insertable for VirtualTerminalEvent {
+    type EventVariation = ();
+
+    fn get_max(config: &EventBufferConfig) -> u16 {
+        config.max_virtual_terminal
+    }
+
+    fn get_type_count(counter: &TypeCounter) -> usize {
+        counter.num_virtual_terminal.get()
+    }
+
+    fn is_type(record: &EventRecord) -> bool {
+        std::matches!(record.event, Event::VirtualTerminal(_))
+    }
+
+    fn increment_type(counter: &mut TypeCounter) {
+        counter.num_virtual_terminal.increment();
+    }
+
+    fn create_event(&self, _default_variation: ()) -> Event {
+        Event::VirtualTerminal(self.clone())
+    }
+
+    fn select_variation(record: &EventRecord, _variation: ()) -> bool {
+        Self::is_type(record)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::measurement::*;
+    use crate::app::MaybeAsync;
+    use crate::outstation::ClassEventStatistics;
+    use std::collections::VecDeque;
+    use std::time::Duration;
+    use tokio::time::Instant;
+
+    use super::*;
+
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    enum Event {
+        Clear(u64),
+    }
+
+    #[derive(Default)]
+    struct MockApplication {
+        events: VecDeque<Event>,
+    }
+
+    impl OutstationApplication for MockApplication {
+        fn begin_confirm(&mut self) {
+            unreachable!()
+        }
+        fn event_cleared(&mut self, id: u64) {
+            self.events.push_back(Event::Clear(id));
+        }
+        fn end_confirm(&mut self, _state: BufferState) -> MaybeAsync<()> {
+            unreachable!()
+        }
+    }
+
+    #[derive(Clone, Default)]
+    struct MockJournal {
+        events: std::sync::Arc<std::sync::Mutex<Vec<JournaledEvent>>>,
+    }
+
+    impl MockJournal {
+        fn ids(&self) -> Vec<u64> {
+            self.events.lock().unwrap().iter().map(|x| x.id).collect()
+        }
+    }
+
+    impl EventJournal for MockJournal {
+        fn load(&mut self) -> std::io::Result<Vec<JournaledEvent>> {
+            Ok(self.events.lock().unwrap().clone())
+        }
+
+        fn record_insert(&mut self, event: &JournaledEvent) -> std::io::Result<()> {
+            self.events.lock().unwrap().push(event.clone());
+            Ok(())
+        }
+
+        fn record_remove(&mut self, id: u64) -> std::io::Result<()> {
+            self.events.lock().unwrap().retain(|x| x.id != id);
+            Ok(())
+        }
+    }
+
+    const PRIMARY: Association = Association(0);
+    const SECONDARY: Association = Association(1);
+
+    /// create a buffer with a single connected master
+    fn new_buffer(config: EventBufferConfig) -> EventBuffer {
+        let mut buffer = EventBuffer::new(config);
+        add_master(&mut buffer, 1, OfflineAssociationPolicy::Retain);
+        buffer
+    }
+
+    fn add_master(
+        buffer: &mut EventBuffer,
+        address: u16,
+        policy: OfflineAssociationPolicy,
+    ) -> Association {
+        let association = buffer
+            .add_association(EndpointAddress::raw(address), policy, Instant::now())
+            .unwrap();
+        buffer.set_association_online(association, true, Instant::now());
+        association
+    }
+
+    fn write_and_confirm_class_1(buffer: &mut EventBuffer, app: &mut MockApplication) -> usize {
+        write_and_confirm(buffer, PRIMARY, app)
+    }
+
+    fn write_and_confirm(
+        buffer: &mut EventBuffer,
+        association: Association,
+        app: &mut MockApplication,
+    ) -> usize {
+        let mut backing = [0u8; 64];
+        buffer.select_by_class(association, EventClass::Class1.into(), None);
+        let mut cursor = WriteCursor::new(backing.as_mut());
+        buffer.write_events(association, &mut cursor).unwrap();
+        buffer.clear_written(association, Instant::now(), app)
+    }
+
+    fn insert_events(buffer: &mut EventBuffer) {
+        buffer
+            .insert(
+                1,
+                EventClass::Class1,
+                &BinaryInput::new(true, Flags::ONLINE, Time::synchronized(0)),
+                EventBinaryInputVariation::Group2Var1,
+            )
+            .unwrap();
+
+        buffer
+            .insert(
+                2,
+                EventClass::Class2,
+                &Counter::new(23, Flags::ONLINE, Time::synchronized(0)),
+                EventCounterVariation::Group22Var2,
+            )
+            .unwrap();
+
+        buffer
+            .insert(
+                3,
+                EventClass::Class3,
+                &DoubleBitBinaryInput::new(
+                    DoubleBit::DeterminedOn,
+                    Flags::ONLINE,
+                    Time::synchronized(0),
+                ),
+                EventDoubleBitBinaryInputVariation::Group4Var3,
+            )
+            .unwrap();
+
+        buffer
+            .insert(
+                4,
+                EventClass::Class2,
+                &BinaryInput::new(true, Flags::ONLINE, Time::synchronized(1234)),
                 EventBinaryInputVariation::Group2Var1,
             )
             .unwrap();
@@ -1079,7 +1962,7 @@ mod tests {
 
     #[test]
     fn cannot_insert_if_max_for_type_is_zero() {
-        let mut buffer = EventBuffer::new(EventBufferConfig::no_events());
+        let mut buffer = new_buffer(EventBufferConfig::no_events());
 
         assert_matches!(
             buffer.insert(
@@ -1088,13 +1971,13 @@ mod tests {
                 &BinaryInput::new(true, Flags::ONLINE, Time::synchronized(0)),
                 EventBinaryInputVariation::Group2Var1,
             ),
-            Err(InsertError::TypeMaxIsZero)
+            Err(InsertError::MaxIsZero)
         )
     }
 
     #[test]
     fn overflows_when_max_for_type_is_exceeded() {
-        let mut buffer = EventBuffer::new(EventBufferConfig::all_types(1));
+        let mut buffer = new_buffer(EventBufferConfig::all_types(1));
 
         let binary = BinaryInput::new(true, Flags::ONLINE, Time::synchronized(0));
 
@@ -1123,16 +2006,186 @@ mod tests {
         )
     }
 
+    fn insert_binary(buffer: &mut EventBuffer, class: EventClass) -> Result<u64, InsertError> {
+        buffer.insert(
+            0,
+            class,
+            &BinaryInput::new(true, Flags::ONLINE, Time::synchronized(0)),
+            EventBinaryInputVariation::Group2Var1,
+        )
+    }
+
+    fn buffered_classes(buffer: &EventBuffer) -> Vec<EventClass> {
+        buffer.events.iter().map(|(_, x)| x.class).collect()
+    }
+
+    #[test]
+    fn discard_newest_keeps_buffered_events() {
+        let config = EventBufferConfig::all_types(1)
+            .with_overflow_policy(EventOverflowPolicy::DiscardNewest);
+        let mut buffer = new_buffer(config);
+
+        assert_eq!(insert_binary(&mut buffer, EventClass::Class1), Ok(0));
+        assert_eq!(
+            insert_binary(&mut buffer, EventClass::Class1),
+            Err(InsertError::Overflow {
+                created: 1,
+                discarded: 1
+            })
+        );
+        assert_eq!(
+            buffer.events.iter().map(|(_, x)| x.id).collect::<Vec<_>>(),
+            [0]
+        );
+        assert_eq!(buffer.buffer_state().classes.num_class_1, 1);
+        assert!(buffer.is_overflown(PRIMARY));
+    }
+
+    fn buffered_ids(buffer: &EventBuffer) -> Vec<u64> {
+        buffer.events.iter().map(|(_, x)| x.id).collect()
+    }
+
+    #[test]
+    fn reconfigure_retains_events_that_fit() {
+        let mut buffer = new_buffer(EventBufferConfig::all_types(2));
+        insert_binary(&mut buffer, EventClass::Class1).unwrap();
+        insert_binary(&mut buffer, EventClass::Class2).unwrap();
+
+        assert_eq!(buffer.reconfigure(EventBufferConfig::all_types(4)), 0);
+        assert_eq!(buffered_ids(&buffer), [0, 1]);
+        assert!(!buffer.is_overflown(PRIMARY));
+
+        // the new capacity is available without overflowing
+        assert_eq!(insert_binary(&mut buffer, EventClass::Class1), Ok(2));
+        assert_eq!(insert_binary(&mut buffer, EventClass::Class1), Ok(3));
+        assert_eq!(buffered_ids(&buffer), [0, 1, 2, 3]);
+        assert!(!buffer.is_overflown(PRIMARY));
+    }
+
+    #[test]
+    fn reconfigure_discards_excess_events_using_the_overflow_policy() {
+        let mut buffer = new_buffer(EventBufferConfig::all_types(4));
+        insert_binary(&mut buffer, EventClass::Class3).unwrap();
+        insert_binary(&mut buffer, EventClass::Class1).unwrap();
+        insert_binary(&mut buffer, EventClass::Class2).unwrap();
+        insert_binary(&mut buffer, EventClass::Class3).unwrap();
+
+        assert_eq!(buffer.reconfigure(EventBufferConfig::all_types(3)), 1);
+        assert_eq!(buffered_ids(&buffer), [1, 2, 3]);
+        assert!(buffer.is_overflown(PRIMARY));
+
+        let config = EventBufferConfig::all_types(3)
+            .with_overflow_policy(EventOverflowPolicy::DiscardLowestClass);
+        assert_eq!(
+            buffer.reconfigure(config.with_class_limits(None, None, Some(0))),
+            1
+        );
+        assert_eq!(buffered_ids(&buffer), [1, 2]);
+
+        let config = EventBufferConfig::all_types(1)
+            .with_overflow_policy(EventOverflowPolicy::DiscardNewest);
+        assert_eq!(buffer.reconfigure(config), 1);
+        assert_eq!(buffered_ids(&buffer), [1]);
+        assert_eq!(buffer.buffer_state().classes.num_class_1, 1);
+        assert_eq!(buffer.buffer_state().classes.num_class_2, 0);
+    }
+
+    #[test]
+    fn discard_lowest_class_discards_oldest_event_of_lowest_class() {
+        let config = EventBufferConfig::all_types(3)
+            .with_overflow_policy(EventOverflowPolicy::DiscardLowestClass);
+        let mut buffer = new_buffer(config);
+
+        assert_eq!(insert_binary(&mut buffer, EventClass::Class3), Ok(0));
+        assert_eq!(insert_binary(&mut buffer, EventClass::Class1), Ok(1));
+        assert_eq!(insert_binary(&mut buffer, EventClass::Class3), Ok(2));
+
+        assert_eq!(
+            insert_binary(&mut buffer, EventClass::Class1),
+            Err(InsertError::Overflow {
+                created: 3,
+                discarded: 0
+            })
+        );
+        assert_eq!(
+            insert_binary(&mut buffer, EventClass::Class2),
+            Err(InsertError::Overflow {
+                created: 4,
+                discarded: 2
+            })
+        );
+        assert_eq!(
+            buffered_classes(&buffer),
+            [EventClass::Class1, EventClass::Class1, EventClass::Class2]
+        );
+
+        // a class 3 event has a lower priority than every buffered event
+        assert_eq!(
+            insert_binary(&mut buffer, EventClass::Class3),
+            Err(InsertError::Overflow {
+                created: 5,
+                discarded: 5
+            })
+        );
+        // class 1 events are never discarded in favor of lower classes
+        assert_eq!(
+            insert_binary(&mut buffer, EventClass::Class1),
+            Err(InsertError::Overflow {
+                created: 6,
+                discarded: 4
+            })
+        );
+        assert_eq!(
+            buffered_classes(&buffer),
+            [EventClass::Class1, EventClass::Class1, EventClass::Class1]
+        );
+    }
+
+    #[test]
+    fn class_limit_only_discards_events_of_the_same_class() {
+        let config = EventBufferConfig::all_types(10).with_class_limits(None, None, Some(2));
+        let mut buffer = new_buffer(config);
+
+        assert_eq!(insert_binary(&mut buffer, EventClass::Class3), Ok(0));
+        assert_eq!(insert_binary(&mut buffer, EventClass::Class1), Ok(1));
+        assert_eq!(insert_binary(&mut buffer, EventClass::Class3), Ok(2));
+        assert_eq!(
+            insert_binary(&mut buffer, EventClass::Class3),
+            Err(InsertError::Overflow {
+                created: 3,
+                discarded: 0
+            })
+        );
+        assert_eq!(insert_binary(&mut buffer, EventClass::Class1), Ok(4));
+
+        let state = buffer.buffer_state();
+        assert_eq!(state.classes.num_class_1, 2);
+        assert_eq!(state.classes.num_class_3, 2);
+        assert_eq!(state.types.num_binary_input, 4);
+    }
+
+    #[test]
+    fn cannot_insert_if_max_for_class_is_zero() {
+        let config = EventBufferConfig::all_types(10).with_class_limits(Some(0), None, None);
+        let mut buffer = new_buffer(config);
+
+        assert_eq!(
+            insert_binary(&mut buffer, EventClass::Class1),
+            Err(InsertError::MaxIsZero)
+        );
+        assert_eq!(insert_binary(&mut buffer, EventClass::Class2), Ok(0));
+    }
+
     #[test]
     fn can_select_events_by_class_and_write_some() {
-        let mut buffer = EventBuffer::new(EventBufferConfig::all_types(3));
+        let mut buffer = new_buffer(EventBufferConfig::all_types(3));
 
         insert_events(&mut buffer);
 
         // ignore the class 2 events
         assert_eq!(
             3,
-            buffer.select_by_class(EventClass::Class1 | EventClass::Class3, None)
+            buffer.select_by_class(PRIMARY, EventClass::Class1 | EventClass::Class3, None)
         );
 
         let mut backing = [0u8; 24];
@@ -1141,36 +2194,40 @@ mod tests {
 
         {
             let mut cursor = WriteCursor::new(backing.as_mut());
-            assert_eq!(buffer.write_events(&mut cursor), Err(1)); // not enough space to write both events
+            assert_eq!(buffer.write_events(PRIMARY, &mut cursor), Err(1)); // not enough space to write both events
             let remaining_classes = EventClasses::all();
-            assert_eq!(buffer.unwritten_classes(), remaining_classes);
-            assert_eq!(buffer.clear_written(&mut mock), 1);
-            assert_eq!(buffer.unwritten_classes(), remaining_classes);
+            assert_eq!(buffer.unwritten_classes(PRIMARY), remaining_classes);
+            assert_eq!(buffer.clear_written(PRIMARY, Instant::now(), &mut mock), 1);
+            assert_eq!(buffer.unwritten_classes(PRIMARY), remaining_classes);
         }
 
         {
             let mut cursor = WriteCursor::new(backing.as_mut());
-            assert_eq!(buffer.write_events(&mut cursor), Err(1));
+            assert_eq!(buffer.write_events(PRIMARY, &mut cursor), Err(1));
             let remaining_classes = EventClass::Class1 | EventClass::Class2; //  we just wrote the only class 3 event
-            assert_eq!(buffer.unwritten_classes(), remaining_classes);
-            assert_eq!(buffer.clear_written(&mut mock), 1);
-            assert_eq!(buffer.unwritten_classes(), remaining_classes);
+            assert_eq!(buffer.unwritten_classes(PRIMARY), remaining_classes);
+            assert_eq!(buffer.clear_written(PRIMARY, Instant::now(), &mut mock), 1);
+            assert_eq!(buffer.unwritten_classes(PRIMARY), remaining_classes);
         }
     }
 
     #[test]
     fn can_select_events_by_type() {
-        let mut buffer = EventBuffer::new(EventBufferConfig::all_types(3));
+        let mut buffer = new_buffer(EventBufferConfig::all_types(3));
 
         insert_events(&mut buffer);
 
         // ignore the 2nd binary
-        assert_eq!(1, buffer.select_default_variation::<BinaryInput>(Some(1)));
+        assert_eq!(
+            1,
+            buffer.select_default_variation::<BinaryInput>(PRIMARY, Some(1))
+        );
 
         // select remaining binary events using g2v2
         assert_eq!(
             1,
             buffer.select_specific_variation::<BinaryInput>(
+                PRIMARY,
                 None,
                 EventBinaryInputVariation::Group2Var2
             )
@@ -1179,7 +2236,7 @@ mod tests {
         let mut backing = [0u8; 64];
         let mut cursor = WriteCursor::new(backing.as_mut());
 
-        assert_eq!(2, buffer.write_events(&mut cursor).unwrap());
+        assert_eq!(2, buffer.write_events(PRIMARY, &mut cursor).unwrap());
 
         assert_eq!(
             cursor.written(),
@@ -1193,9 +2250,275 @@ mod tests {
 
         let mut mock = MockApplication::default();
 
-        assert_eq!(2, buffer.clear_written(&mut mock));
+        assert_eq!(2, buffer.clear_written(PRIMARY, Instant::now(), &mut mock));
         assert_eq!(mock.events.pop_front(), Some(Event::Clear(0)));
         assert_eq!(mock.events.pop_front(), Some(Event::Clear(3)));
         assert_eq!(mock.events.pop_front(), None);
     }
+
+    #[test]
+    fn events_are_retained_until_confirmed_by_every_association() {
+        let mut buffer = new_buffer(EventBufferConfig::all_types(3));
+        add_master(&mut buffer, 2, OfflineAssociationPolicy::Retain);
+
+        insert_events(&mut buffer);
+
+        let mut backing = [0u8; 64];
+        let mut mock = MockApplication::default();
+
+        // the primary master reads and confirms the class 1 events
+        assert_eq!(
+            2,
+            buffer.select_by_class(PRIMARY, EventClass::Class1.into(), None)
+        );
+        {
+            let mut cursor = WriteCursor::new(backing.as_mut());
+            assert_eq!(buffer.write_events(PRIMARY, &mut cursor), Ok(2));
+        }
+        assert_eq!(0, buffer.clear_written(PRIMARY, Instant::now(), &mut mock));
+        assert_eq!(mock.events.pop_front(), None);
+        assert_eq!(
+            buffer.unwritten_classes(PRIMARY),
+            EventClass::Class2 | EventClass::Class3
+        );
+        assert_eq!(buffer.unwritten_classes(SECONDARY), EventClasses::all());
+
+        // a new selection by the primary master doesn't include the events it already confirmed
+        buffer.reset(PRIMARY);
+        assert_eq!(
+            0,
+            buffer.select_by_class(PRIMARY, EventClass::Class1.into(), None)
+        );
+
+        // the events are removed once the secondary master confirms them
+        assert_eq!(
+            2,
+            buffer.select_by_class(SECONDARY, EventClass::Class1.into(), None)
+        );
+        {
+            let mut cursor = WriteCursor::new(backing.as_mut());
+            assert_eq!(buffer.write_events(SECONDARY, &mut cursor), Ok(2));
+        }
+        assert_eq!(
+            2,
+            buffer.clear_written(SECONDARY, Instant::now(), &mut mock)
+        );
+        assert_eq!(mock.events.pop_front(), Some(Event::Clear(0)));
+        assert_eq!(mock.events.pop_front(), Some(Event::Clear(4)));
+        assert_eq!(mock.events.pop_front(), None);
+        assert_eq!(
+            buffer.unwritten_classes(SECONDARY),
+            EventClass::Class2 | EventClass::Class3
+        );
+    }
+
+    #[test]
+    fn events_are_released_once_an_offline_association_exceeds_its_delay() {
+        let start = Instant::now();
+        let mut buffer = new_buffer(EventBufferConfig::all_types(3));
+        // the secondary master never connects
+        let secondary = buffer
+            .add_association(
+                EndpointAddress::raw(2),
+                OfflineAssociationPolicy::ReleaseAfter(Duration::from_secs(60)),
+                start,
+            )
+            .unwrap();
+
+        insert_events(&mut buffer);
+
+        let mut backing = [0u8; 64];
+        let mut mock = MockApplication::default();
+
+        assert_eq!(
+            2,
+            buffer.select_by_class(PRIMARY, EventClass::Class1.into(), None)
+        );
+        {
+            let mut cursor = WriteCursor::new(backing.as_mut());
+            assert_eq!(buffer.write_events(PRIMARY, &mut cursor), Ok(2));
+        }
+
+        // the events are retained for the secondary master until the delay elapses
+        let before = start + Duration::from_secs(59);
+        assert_eq!(0, buffer.clear_written(PRIMARY, before, &mut mock));
+        assert_eq!(mock.events.pop_front(), None);
+        assert_eq!(buffer.buffer_state().classes.num_class_1, 2);
+
+        // the next confirm from the primary master removes the events it already confirmed
+        let after = start + Duration::from_secs(60);
+        assert_eq!(2, buffer.clear_written(PRIMARY, after, &mut mock));
+        assert_eq!(mock.events.pop_front(), Some(Event::Clear(0)));
+        assert_eq!(mock.events.pop_front(), Some(Event::Clear(4)));
+        assert_eq!(mock.events.pop_front(), None);
+        assert_eq!(buffer.buffer_state().classes.num_class_1, 0);
+        assert_eq!(buffer.statistics().class1.confirmed, 2);
+        assert_eq!(
+            buffer.unwritten_classes(secondary),
+            EventClass::Class2 | EventClass::Class3
+        );
+
+        // events are retained again once the secondary master connects
+        buffer.set_association_online(secondary, true, after);
+        assert_eq!(
+            2,
+            buffer.select_by_class(PRIMARY, EventClass::Class2.into(), None)
+        );
+        {
+            let mut cursor = WriteCursor::new(backing.as_mut());
+            assert_eq!(buffer.write_events(PRIMARY, &mut cursor), Ok(2));
+        }
+        assert_eq!(0, buffer.clear_written(PRIMARY, after, &mut mock));
+        assert_eq!(buffer.buffer_state().classes.num_class_2, 2);
+    }
+
+    #[test]
+    fn each_association_clears_the_overflow_when_it_confirms_with_space_available() {
+        let mut buffer = new_buffer(EventBufferConfig::all_types(1));
+        let secondary = add_master(&mut buffer, 2, OfflineAssociationPolicy::Retain);
+        let mut mock = MockApplication::default();
+
+        insert_binary(&mut buffer, EventClass::Class1).unwrap();
+        insert_binary(&mut buffer, EventClass::Class1).unwrap_err();
+        assert!(buffer.is_overflown(PRIMARY));
+        assert!(buffer.is_overflown(secondary));
+
+        // the event is retained for the secondary master, so the buffer is still full
+        assert_eq!(0, write_and_confirm(&mut buffer, PRIMARY, &mut mock));
+        assert!(buffer.is_overflown(PRIMARY));
+
+        // the secondary master frees the space, but has not informed the primary master
+        assert_eq!(1, write_and_confirm(&mut buffer, secondary, &mut mock));
+        assert!(!buffer.is_overflown(secondary));
+        assert!(buffer.is_overflown(PRIMARY));
+
+        assert_eq!(0, write_and_confirm(&mut buffer, PRIMARY, &mut mock));
+        assert!(!buffer.is_overflown(PRIMARY));
+    }
+
+    #[test]
+    fn journal_records_insertions_and_removals() {
+        let journal = MockJournal::default();
+        let mut buffer = new_buffer(EventBufferConfig::all_types(3));
+        assert_eq!(buffer.set_journal(Box::new(journal.clone())).unwrap(), 0);
+
+        insert_events(&mut buffer);
+        assert_eq!(journal.ids(), [0, 1, 2, 3, 4]);
+
+        let mut mock = MockApplication::default();
+        assert_eq!(2, write_and_confirm_class_1(&mut buffer, &mut mock));
+        assert_eq!(journal.ids(), [1, 2, 3]);
+    }
+
+    #[test]
+    fn restores_journaled_events_with_their_identifiers() {
+        let journal = MockJournal::default();
+        {
+            let mut buffer = new_buffer(EventBufferConfig::all_types(3));
+            buffer.set_journal(Box::new(journal.clone())).unwrap();
+            insert_events(&mut buffer);
+            // only the first class 1 event is confirmed before the restart
+            buffer.select_by_class(PRIMARY, EventClass::Class1.into(), Some(1));
+            let mut backing = [0u8; 64];
+            let mut cursor = WriteCursor::new(backing.as_mut());
+            buffer.write_events(PRIMARY, &mut cursor).unwrap();
+            buffer.clear_written(PRIMARY, Instant::now(), &mut MockApplication::default());
+        }
+
+        let mut buffer = new_buffer(EventBufferConfig::all_types(3));
+        assert_eq!(buffer.set_journal(Box::new(journal.clone())).unwrap(), 4);
+        assert_eq!(buffer.buffer_state().classes.num_class_1, 1);
+        assert_eq!(buffer.buffer_state().classes.num_class_2, 2);
+        assert_eq!(buffer.buffer_state().classes.num_class_3, 1);
+        assert_eq!(journal.ids(), [1, 2, 3, 4]);
+
+        // new events are assigned identifiers after the restored ones
+        assert_eq!(
+            buffer.insert(
+                6,
+                EventClass::Class1,
+                &Counter::new(7, Flags::ONLINE, Time::synchronized(0)),
+                EventCounterVariation::Group22Var1,
+            ),
+            Ok(5)
+        );
+
+        let mut mock = MockApplication::default();
+        assert_eq!(2, write_and_confirm_class_1(&mut buffer, &mut mock));
+        assert_eq!(mock.events.pop_front(), Some(Event::Clear(4)));
+        assert_eq!(mock.events.pop_front(), Some(Event::Clear(5)));
+        assert_eq!(journal.ids(), [1, 2, 3]);
+    }
+
+    #[test]
+    fn restored_events_in_excess_of_the_limits_are_discarded() {
+        let journal = MockJournal::default();
+        {
+            let mut buffer = new_buffer(EventBufferConfig::all_types(3));
+            buffer.set_journal(Box::new(journal.clone())).unwrap();
+            insert_events(&mut buffer);
+        }
+
+        let mut buffer = new_buffer(EventBufferConfig::all_types(1));
+        assert_eq!(buffer.set_journal(Box::new(journal.clone())).unwrap(), 4);
+        assert!(buffer.is_overflown(PRIMARY));
+        // the oldest binary event is discarded
+        assert_eq!(journal.ids(), [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn cannot_attach_journal_after_events_are_created() {
+        let mut buffer = new_buffer(EventBufferConfig::all_types(3));
+        insert_events(&mut buffer);
+        assert_matches!(
+            buffer.set_journal(Box::new(MockJournal::default())),
+            Err(EventJournalError::EventsAlreadyCreated)
+        );
+    }
+
+    #[test]
+    fn counts_generated_confirmed_and_discarded_events_per_class() {
+        let mut buffer = new_buffer(EventBufferConfig::all_types(2));
+        insert_binary(&mut buffer, EventClass::Class1).unwrap();
+        insert_binary(&mut buffer, EventClass::Class2).unwrap();
+        // discards the oldest event which is the class 1 event
+        insert_binary(&mut buffer, EventClass::Class2).unwrap_err();
+        insert_binary(&mut buffer, EventClass::Class1).unwrap_err();
+
+        let mut mock = MockApplication::default();
+        assert_eq!(write_and_confirm_class_1(&mut buffer, &mut mock), 1);
+
+        let statistics = buffer.statistics();
+        assert_eq!(
+            statistics.class1,
+            ClassEventStatistics {
+                generated: 2,
+                confirmed: 1,
+                discarded: 1,
+            }
+        );
+        assert_eq!(
+            statistics.class2,
+            ClassEventStatistics {
+                generated: 2,
+                confirmed: 0,
+                discarded: 1,
+            }
+        );
+        assert_eq!(statistics.class3, ClassEventStatistics::default());
+    }
+
+    #[test]
+    fn restored_events_are_not_counted() {
+        let journal = MockJournal::default();
+        {
+            let mut buffer = new_buffer(EventBufferConfig::all_types(3));
+            buffer.set_journal(Box::new(journal.clone())).unwrap();
+            insert_events(&mut buffer);
+        }
+
+        let mut buffer = new_buffer(EventBufferConfig::all_types(3));
+        assert_eq!(buffer.set_journal(Box::new(journal)).unwrap(), 5);
+        assert_eq!(buffer.statistics(), EventStatistics::default());
+    }
 }
@@ -128,6 +128,19 @@ impl<T> VecList<T> {
         }
     }
 
+    /// apply a function to every entry in the list
+    pub(crate) fn for_each_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        let mut current = self.state.map(|x| x.head);
+        while let Some(idx) = current {
+            let entry = &mut self.storage[idx];
+            f(&mut entry.data);
+            current = entry.metadata.next;
+        }
+    }
+
     pub(crate) fn new(capacity: usize) -> Self {
         Self {
             version: 0,
@@ -137,6 +150,32 @@ impl<T> VecList<T> {
         }
     }
 
+    /// change the capacity of the list, preserving the order of the entries
+    ///
+    /// Returns false without modifying the list if it contains more entries than the new capacity.
+    pub(crate) fn set_capacity(&mut self, capacity: usize) -> bool {
+        if self.len() > capacity {
+            return false;
+        }
+
+        let old = std::mem::replace(self, Self::new(capacity));
+        let head = old.state.map(|x| x.head);
+        let mut entries: Vec<Option<Entry<T>>> = old.storage.into_iter().map(Some).collect();
+
+        let mut current = head;
+        while let Some(idx) = current {
+            match entries[idx].take() {
+                Some(entry) => {
+                    current = entry.metadata.next;
+                    self.add(entry.data);
+                }
+                None => break,
+            }
+        }
+
+        true
+    }
+
     pub(crate) fn len(&self) -> usize {
         self.state.map_or(0, |x| x.size)
     }
@@ -192,12 +231,17 @@ impl<T> VecList<T> {
         Some(index)
     }
 
+    #[cfg(test)]
     pub(crate) fn remove_first<F>(&mut self, predicate: F) -> Option<&T>
     where
         F: Fn(&T) -> bool,
     {
         let index = self.find_first(&predicate)?;
+        self.remove(index)
+    }
 
+    /// remove the entry at the index, returning a reference to the removed value
+    pub(crate) fn remove(&mut self, index: Index) -> Option<&T> {
         if self.remove_at(index) {
             return self.storage.get(index.value).map(|x| &x.data);
         }
@@ -239,7 +283,7 @@ impl<T> VecList<T> {
         }
     }
 
-    fn find_first<F>(&self, predicate: &F) -> Option<Index>
+    pub(crate) fn find_first<F>(&self, predicate: &F) -> Option<Index>
     where
         F: Fn(&T) -> bool,
     {
@@ -368,6 +412,28 @@ mod tests {
         assert!(!list.remove_at(Index::new(index.version + 1, index.value)));
     }
 
+    #[test]
+    fn can_change_capacity_preserving_order() {
+        let mut list = VecList::new(4);
+        list.add("A").unwrap();
+        let index_b = list.add("B").unwrap();
+        list.add("C").unwrap();
+        assert!(list.remove_at(index_b));
+        list.add("D").unwrap();
+
+        assert!(!list.set_capacity(2));
+        assert_eq!(list.len(), 3);
+
+        assert!(list.set_capacity(3));
+        assert!(list.is_full());
+        let values: Vec<&str> = list.iter().map(|(_, x)| *x).collect();
+        assert_eq!(values, vec!["A", "C", "D"]);
+
+        assert!(list.set_capacity(5));
+        assert!(list.add("E").is_some());
+        assert_eq!(list.len(), 4);
+    }
+
     #[test]
     fn can_iterate_over_values() {
         let mut list = VecList::new(3);
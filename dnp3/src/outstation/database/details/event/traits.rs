@@ -1,6 +1,7 @@
 use crate::app::measurement::*;
 use crate::app::variations::*;
 use crate::outstation::database::config::*;
+use crate::outstation::database::details::event::buffer::VirtualTerminalEvent;
 use crate::outstation::database::details::event::write_fn::{
     write_cto, write_fixed_size, Continue,
 };
@@ -13,6 +14,9 @@ use super::write_fn::write_octet_string;
 #[derive(Copy, Clone, PartialEq, Eq)]
 pub(crate) struct OctetStringLength(pub(crate) usize);
 
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub(crate) struct VirtualTerminalLength(pub(crate) usize);
+
 pub(crate) trait EventVariation<T> {
     fn write(
         &self,
@@ -316,6 +320,120 @@ impl EventVariation<AnalogOutputStatus> for EventAnalogOutputStatusVariation {
     }
 }
 
+impl EventVariation<BinaryOutputCommandEvent> for EventBinaryOutputCommandVariation {
+    fn write(
+        &self,
+        cursor: &mut WriteCursor,
+        event: &BinaryOutputCommandEvent,
+        index: u16,
+        cto: Time,
+    ) -> Result<Continue, WriteError> {
+        match self {
+            Self::Group13Var1 => {
+                write_fixed_size::<Group13Var1, BinaryOutputCommandEvent>(cursor, event, index, cto)
+            }
+            Self::Group13Var2 => {
+                write_fixed_size::<Group13Var2, BinaryOutputCommandEvent>(cursor, event, index, cto)
+            }
+        }
+    }
+
+    fn wrap(&self) -> HeaderType {
+        HeaderType::BinaryOutputCommand(*self)
+    }
+
+    fn get_group_var(&self, _event: &BinaryOutputCommandEvent) -> (u8, u8) {
+        match self {
+            Self::Group13Var1 => (13, 1),
+            Self::Group13Var2 => (13, 2),
+        }
+    }
+}
+
+impl EventVariation<AnalogOutputCommandEvent> for EventAnalogOutputCommandVariation {
+    fn write(
+        &self,
+        cursor: &mut WriteCursor,
+        event: &AnalogOutputCommandEvent,
+        index: u16,
+        cto: Time,
+    ) -> Result<Continue, WriteError> {
+        match self {
+            Self::Group43Var1 => {
+                write_fixed_size::<Group43Var1, AnalogOutputCommandEvent>(cursor, event, index, cto)
+            }
+            Self::Group43Var2 => {
+                write_fixed_size::<Group43Var2, AnalogOutputCommandEvent>(cursor, event, index, cto)
+            }
+            Self::Group43Var3 => {
+                write_fixed_size::<Group43Var3, AnalogOutputCommandEvent>(cursor, event, index, cto)
+            }
+            Self::Group43Var4 => {
+                write_fixed_size::<Group43Var4, AnalogOutputCommandEvent>(cursor, event, index, cto)
+            }
+            Self::Group43Var5 => {
+                write_fixed_size::<Group43Var5, AnalogOutputCommandEvent>(cursor, event, index, cto)
+            }
+            Self::Group43Var6 => {
+                write_fixed_size::<Group43Var6, AnalogOutputCommandEvent>(cursor, event, index, cto)
+            }
+            Self::Group43Var7 => {
+                write_fixed_size::<Group43Var7, AnalogOutputCommandEvent>(cursor, event, index, cto)
+            }
+            Self::Group43Var8 => {
+                write_fixed_size::<Group43Var8, AnalogOutputCommandEvent>(cursor, event, index, cto)
+            }
+        }
+    }
+
+    fn wrap(&self) -> HeaderType {
+        HeaderType::AnalogOutputCommand(*self)
+    }
+
+    fn get_group_var(&self, _event: &AnalogOutputCommandEvent) -> (u8, u8) {
+        match self {
+            Self::Group43Var1 => (43, 1),
+            Self::Group43Var2 => (43, 2),
+            Self::Group43Var3 => (43, 3),
+            Self::Group43Var4 => (43, 4),
+            Self::Group43Var5 => (43, 5),
+            Self::Group43Var6 => (43, 6),
+            Self::Group43Var7 => (43, 7),
+            Self::Group43Var8 => (43, 8),
+        }
+    }
+}
+
+impl EventVariation<SecurityStatistic> for EventSecurityStatisticVariation {
+    fn write(
+        &self,
+        cursor: &mut WriteCursor,
+        event: &SecurityStatistic,
+        index: u16,
+        cto: Time,
+    ) -> Result<Continue, WriteError> {
+        match self {
+            Self::Group122Var1 => {
+                write_fixed_size::<Group122Var1, SecurityStatistic>(cursor, event, index, cto)
+            }
+            Self::Group122Var2 => {
+                write_fixed_size::<Group122Var2, SecurityStatistic>(cursor, event, index, cto)
+            }
+        }
+    }
+
+    fn wrap(&self) -> HeaderType {
+        HeaderType::SecurityStatistic(*self)
+    }
+
+    fn get_group_var(&self, _event: &SecurityStatistic) -> (u8, u8) {
+        match self {
+            Self::Group122Var1 => (122, 1),
+            Self::Group122Var2 => (122, 2),
+        }
+    }
+}
+
 impl EventVariation<Box<[u8]>> for OctetStringLength {
     fn write(
         &self,
@@ -335,3 +453,23 @@ impl EventVariation<Box<[u8]>> for OctetStringLength {
         (111, event.len() as u8)
     }
 }
+
+impl EventVariation<VirtualTerminalEvent> for VirtualTerminalLength {
+    fn write(
+        &self,
+        cursor: &mut WriteCursor,
+        event: &VirtualTerminalEvent,
+        index: u16,
+        _cto: Time,
+    ) -> Result<Continue, WriteError> {
+        write_octet_string(cursor, &event.0, index)
+    }
+
+    fn wrap(&self) -> HeaderType {
+        HeaderType::VirtualTerminal(*self)
+    }
+
+    fn get_group_var(&self, event: &VirtualTerminalEvent) -> (u8, u8) {
+        (113, event.0.len() as u8)
+    }
+}
@@ -4,7 +4,10 @@ use crate::app::variations::{Group51Var1, Group51Var2};
 use crate::app::QualifierCode;
 use crate::app::Timestamp;
 use crate::outstation::database::config::*;
-use crate::outstation::database::details::event::traits::{EventVariation, OctetStringLength};
+use crate::outstation::database::details::event::buffer::VirtualTerminalEvent;
+use crate::outstation::database::details::event::traits::{
+    EventVariation, OctetStringLength, VirtualTerminalLength,
+};
 use crate::outstation::database::details::event::write_fn::Continue;
 
 use crate::util::BadWrite;
@@ -47,6 +50,10 @@ pub(crate) enum HeaderType {
     Analog(EventAnalogInputVariation),
     AnalogOutputStatus(EventAnalogOutputStatusVariation),
     OctetString(OctetStringLength),
+    BinaryOutputCommand(EventBinaryOutputCommandVariation),
+    AnalogOutputCommand(EventAnalogOutputCommandVariation),
+    VirtualTerminal(VirtualTerminalLength),
+    SecurityStatistic(EventSecurityStatisticVariation),
 }
 
 #[derive(Copy, Clone)]
@@ -93,6 +100,29 @@ impl EventWriter {
         result
     }
 
+    /// write a complete free-format object header, e.g. a g70 file transfer object
+    pub(crate) fn write_free_format(
+        &mut self,
+        cursor: &mut WriteCursor,
+        header: &[u8],
+    ) -> Result<(), BadWrite> {
+        if let State::Full = self.state {
+            return Err(BadWrite);
+        }
+
+        match cursor.write_bytes(header) {
+            Ok(()) => {
+                // free-format headers always contain a single object
+                self.state = State::Start;
+                Ok(())
+            }
+            Err(_) => {
+                self.state = State::Full;
+                Err(BadWrite)
+            }
+        }
+    }
+
     fn try_write<E>(
         &mut self,
         cursor: &mut WriteCursor,
@@ -324,6 +354,51 @@ impl Writable for AnalogOutputStatus {
     }
 }
 
+impl Writable for BinaryOutputCommandEvent {
+    type EventVariation = EventBinaryOutputCommandVariation;
+
+    fn get_header_variation(&self, header: &HeaderType) -> Option<Self::EventVariation> {
+        match header {
+            HeaderType::BinaryOutputCommand(var) => Some(*var),
+            _ => None,
+        }
+    }
+
+    fn get_time(&self) -> Option<Time> {
+        self.time
+    }
+}
+
+impl Writable for AnalogOutputCommandEvent {
+    type EventVariation = EventAnalogOutputCommandVariation;
+
+    fn get_header_variation(&self, header: &HeaderType) -> Option<Self::EventVariation> {
+        match header {
+            HeaderType::AnalogOutputCommand(var) => Some(*var),
+            _ => None,
+        }
+    }
+
+    fn get_time(&self) -> Option<Time> {
+        self.time
+    }
+}
+
+impl Writable for SecurityStatistic {
+    type EventVariation = EventSecurityStatisticVariation;
+
+    fn get_header_variation(&self, header: &HeaderType) -> Option<Self::EventVariation> {
+        match header {
+            HeaderType::SecurityStatistic(var) => Some(*var),
+            _ => None,
+        }
+    }
+
+    fn get_time(&self) -> Option<Time> {
+        self.time
+    }
+}
+
 impl Writable for Box<[u8]> {
     type EventVariation = OctetStringLength;
 
@@ -339,6 +414,21 @@ impl Writable for Box<[u8]> {
     }
 }
 
+impl Writable for VirtualTerminalEvent {
+    type EventVariation = VirtualTerminalLength;
+
+    fn get_header_variation(&self, header: &HeaderType) -> Option<Self::EventVariation> {
+        match header {
+            HeaderType::VirtualTerminal(var) => Some(*var),
+            _ => None,
+        }
+    }
+
+    fn get_time(&self) -> Option<Time> {
+        None
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::app::measurement::Flags;
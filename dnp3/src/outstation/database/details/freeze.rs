@@ -0,0 +1,288 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use crate::app::Timestamp;
+use crate::outstation::{FreezeIndices, FreezeInterval};
+
+const MILLIS_PER_HOUR: u64 = 60 * 60 * 1000;
+
+/// When a freeze should be performed
+pub(crate) enum FreezeTiming {
+    /// perform the freeze now
+    Now,
+    /// the freeze has been scheduled for later
+    Scheduled,
+}
+
+#[derive(Copy, Clone)]
+struct FreezeSchedule {
+    indices: FreezeIndices,
+    next: tokio::time::Instant,
+    period: Option<Duration>,
+}
+
+/// Links counters to frozen counters and keeps track of FREEZE_AT_TIME schedules
+#[derive(Default)]
+pub(crate) struct FreezeEngine {
+    // counter index -> frozen counter index
+    links: BTreeMap<u16, u16>,
+    schedules: Vec<FreezeSchedule>,
+}
+
+impl FreezeEngine {
+    pub(crate) fn link(&mut self, counter: u16, frozen_counter: u16) {
+        self.links.insert(counter, frozen_counter);
+    }
+
+    pub(crate) fn unlink(&mut self, counter: u16) -> bool {
+        self.links.remove(&counter).is_some()
+    }
+
+    pub(crate) fn is_linked(&self, counter: u16) -> bool {
+        self.links.contains_key(&counter)
+    }
+
+    /// (counter, frozen counter) pairs selected by the indices
+    pub(crate) fn links(&self, indices: FreezeIndices) -> Vec<(u16, u16)> {
+        let iter = match indices {
+            FreezeIndices::All => self.links.range(..),
+            FreezeIndices::Range(start, stop) => {
+                if start > stop {
+                    return Vec::new();
+                }
+                self.links.range(start..=stop)
+            }
+        };
+        iter.map(|(c, f)| (*c, *f)).collect()
+    }
+
+    /// Schedule a freeze relative to a wall clock time and the corresponding monotonic instant.
+    ///
+    /// A new schedule for the same indices replaces any existing schedule.
+    pub(crate) fn schedule(
+        &mut self,
+        indices: FreezeIndices,
+        interval: FreezeInterval,
+        wall: Timestamp,
+        now: tokio::time::Instant,
+    ) -> FreezeTiming {
+        fn delay(from: u64, to: u64) -> Duration {
+            Duration::from_millis(to.saturating_sub(from))
+        }
+
+        // the first time in the series `base + k * period` that is not in the past
+        fn first_after(base: u64, period: u64, wall: u64) -> u64 {
+            if base >= wall {
+                return base;
+            }
+            let elapsed = wall - base;
+            base + elapsed.div_ceil(period) * period
+        }
+
+        let wall = wall.raw_value();
+
+        // a period of zero is a one-shot freeze, regardless of how the interval was constructed
+        let (time, period) = interval.get_time_and_interval();
+        let schedule = match FreezeInterval::new(time, period) {
+            FreezeInterval::FreezeOnceImmediately => return FreezeTiming::Now,
+            FreezeInterval::FreezeOnceAtTime(time) => FreezeSchedule {
+                indices,
+                next: now + delay(wall, time.raw_value()),
+                period: None,
+            },
+            FreezeInterval::PeriodicallyFreeze(time, period) => {
+                let period = period as u64;
+                FreezeSchedule {
+                    indices,
+                    next: now + delay(wall, first_after(time.raw_value(), period, wall)),
+                    period: Some(Duration::from_millis(period)),
+                }
+            }
+            FreezeInterval::PeriodicallyFreezeRelative(period) => {
+                let period = period as u64;
+                let top_of_hour = wall - (wall % MILLIS_PER_HOUR);
+                FreezeSchedule {
+                    indices,
+                    next: now + delay(wall, first_after(top_of_hour, period, wall)),
+                    period: Some(Duration::from_millis(period)),
+                }
+            }
+        };
+
+        self.schedules.retain(|x| x.indices != indices);
+        self.schedules.push(schedule);
+
+        FreezeTiming::Scheduled
+    }
+
+    /// The earliest time at which a scheduled freeze is due
+    pub(crate) fn next_deadline(&self) -> Option<tokio::time::Instant> {
+        self.schedules.iter().map(|x| x.next).min()
+    }
+
+    /// Remove or advance the schedules that are due, returning the indices to freeze
+    pub(crate) fn poll(&mut self, now: tokio::time::Instant) -> Vec<FreezeIndices> {
+        let mut due = Vec::new();
+
+        self.schedules.retain_mut(|schedule| {
+            if schedule.next > now {
+                return true;
+            }
+
+            due.push(schedule.indices);
+
+            match schedule.period {
+                None => false,
+                Some(period) => {
+                    // skip any periods that were missed
+                    while schedule.next <= now {
+                        schedule.next += period;
+                    }
+                    true
+                }
+            }
+        });
+
+        due
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn freeze_once_immediately_is_not_scheduled() {
+        let mut engine = FreezeEngine::default();
+        let now = tokio::time::Instant::now();
+
+        assert!(matches!(
+            engine.schedule(
+                FreezeIndices::All,
+                FreezeInterval::FreezeOnceImmediately,
+                Timestamp::new(0),
+                now
+            ),
+            FreezeTiming::Now
+        ));
+        assert!(engine.next_deadline().is_none());
+    }
+
+    #[test]
+    fn periodic_freeze_in_the_past_is_aligned_to_the_next_period() {
+        let mut engine = FreezeEngine::default();
+        let now = tokio::time::Instant::now();
+
+        // started at 1000, period of 300, wall time of 1700 -> next freeze at 1900
+        engine.schedule(
+            FreezeIndices::All,
+            FreezeInterval::PeriodicallyFreeze(Timestamp::new(1000), 300),
+            Timestamp::new(1700),
+            now,
+        );
+
+        assert_eq!(
+            engine.next_deadline(),
+            Some(now + Duration::from_millis(200))
+        );
+    }
+
+    #[test]
+    fn relative_freeze_is_aligned_to_the_top_of_the_hour() {
+        let mut engine = FreezeEngine::default();
+        let now = tokio::time::Instant::now();
+
+        // 10 seconds past the hour with a period of 15 seconds
+        engine.schedule(
+            FreezeIndices::All,
+            FreezeInterval::PeriodicallyFreezeRelative(15_000),
+            Timestamp::new(MILLIS_PER_HOUR + 10_000),
+            now,
+        );
+
+        assert_eq!(
+            engine.next_deadline(),
+            Some(now + Duration::from_millis(5_000))
+        );
+    }
+
+    #[test]
+    fn poll_removes_one_shot_and_advances_periodic_schedules() {
+        let mut engine = FreezeEngine::default();
+        let now = tokio::time::Instant::now();
+
+        engine.schedule(
+            FreezeIndices::Range(0, 0),
+            FreezeInterval::FreezeOnceAtTime(Timestamp::new(100)),
+            Timestamp::new(0),
+            now,
+        );
+        engine.schedule(
+            FreezeIndices::Range(1, 1),
+            FreezeInterval::PeriodicallyFreeze(Timestamp::new(100), 100),
+            Timestamp::new(0),
+            now,
+        );
+
+        assert!(engine.poll(now).is_empty());
+
+        // both are due, and a missed period of the periodic schedule is skipped
+        assert_eq!(
+            engine.poll(now + Duration::from_millis(250)),
+            vec![FreezeIndices::Range(0, 0), FreezeIndices::Range(1, 1)]
+        );
+        assert_eq!(
+            engine.next_deadline(),
+            Some(now + Duration::from_millis(300))
+        );
+    }
+
+    #[test]
+    fn new_schedule_replaces_schedule_for_same_indices() {
+        let mut engine = FreezeEngine::default();
+        let now = tokio::time::Instant::now();
+
+        for time in [500, 200] {
+            engine.schedule(
+                FreezeIndices::All,
+                FreezeInterval::FreezeOnceAtTime(Timestamp::new(time)),
+                Timestamp::new(0),
+                now,
+            );
+        }
+
+        assert_eq!(
+            engine.poll(now + Duration::from_millis(1000)),
+            vec![FreezeIndices::All]
+        );
+    }
+
+    #[test]
+    fn periodic_freeze_with_a_zero_period_is_performed_once() {
+        let mut engine = FreezeEngine::default();
+        let now = tokio::time::Instant::now();
+
+        assert!(matches!(
+            engine.schedule(
+                FreezeIndices::All,
+                FreezeInterval::PeriodicallyFreezeRelative(0),
+                Timestamp::new(1000),
+                now
+            ),
+            FreezeTiming::Now
+        ));
+        assert!(engine.next_deadline().is_none());
+
+        // freezes once at the specified time
+        engine.schedule(
+            FreezeIndices::All,
+            FreezeInterval::PeriodicallyFreeze(Timestamp::new(1500), 0),
+            Timestamp::new(1000),
+            now,
+        );
+        let deadline = now + Duration::from_millis(500);
+        assert_eq!(engine.next_deadline(), Some(deadline));
+        assert_eq!(engine.poll(deadline), [FreezeIndices::All]);
+        assert!(engine.next_deadline().is_none());
+    }
+}
@@ -1,4 +1,7 @@
 pub(crate) mod attrs;
+pub(crate) mod command;
+pub(crate) mod data_set;
 pub(crate) mod database;
 pub(crate) mod event;
+pub(crate) mod freeze;
 pub(crate) mod range;
@@ -6,11 +6,13 @@ use crate::app::measurement::*;
 use crate::app::Iin2;
 use crate::outstation::config::OutstationConfig;
 use crate::outstation::database::config::*;
-use crate::outstation::database::details::event::buffer::Insertable;
+use crate::outstation::database::details::event::buffer::EventSource;
 use crate::outstation::database::details::range::traits::StaticVariation;
 use crate::outstation::database::details::range::writer::RangeWriter;
 use crate::outstation::database::read::StaticReadHeader;
-use crate::outstation::database::{ClassZeroConfig, EventClass, EventMode, UpdateOptions};
+use crate::outstation::database::{
+    Association, ClassZeroConfig, EventClass, EventMode, PointValue, UpdateOptions,
+};
 
 use crate::app::attr::AttrSet;
 use crate::util::BadWrite;
@@ -20,10 +22,15 @@ pub(crate) trait EventDetector<T>
 where
     T: Updatable,
 {
-    fn is_event(&self, new: &T, old: &T) -> bool;
+    fn is_event(&mut self, new: &T, old: &T) -> bool;
+
+    /// called whenever the last reported value changes
+    fn reset(&mut self) {}
 }
 
-pub(crate) trait Updatable: Insertable + Clone + Default {
+pub(crate) trait Updatable:
+    EventSource + Clone + Default + PartialEq + Into<PointValue>
+{
     type StaticVariation: StaticVariation<Self>;
     type Detector: EventDetector<Self>;
     fn get_map(maps: &StaticDatabase) -> &PointMap<Self>;
@@ -93,6 +100,8 @@ pub(crate) enum SpecificVariation {
     Analog(Option<StaticAnalogInputVariation>),
     AnalogOutputStatus(Option<StaticAnalogOutputStatusVariation>),
     OctetString,
+    UnsignedInteger,
+    SecurityStatistic,
     AnalogDeadBand(Option<AnalogInputDeadBandVariation>),
 }
 
@@ -176,14 +185,35 @@ where
     }
 }
 
+impl<T> PointConfig<T>
+where
+    T: Updatable,
+{
+    pub(crate) fn class(&self) -> Option<EventClass> {
+        self.class
+    }
+
+    pub(crate) fn detector(&self) -> &T::Detector {
+        &self.detector
+    }
+
+    pub(crate) fn s_var(&self) -> T::StaticVariation {
+        self.s_var
+    }
+
+    pub(crate) fn e_var(&self) -> T::EventVariation {
+        self.e_var
+    }
+}
+
 pub(crate) struct Point<T>
 where
     T: Updatable,
 {
     // current value
     current: T,
-    // value that is frozen during READ requests to be reported
-    selected: T,
+    // values that are frozen during READ requests to be reported, indexed by association
+    selected: Vec<T>,
     // last value that produced an event
     last_event: T,
     // configuration
@@ -197,13 +227,33 @@ where
     pub(crate) fn new(config: PointConfig<T>) -> Self {
         Self {
             current: T::default(),
-            selected: T::default(),
+            selected: Vec::new(),
             last_event: T::default(),
             config,
         }
     }
 }
 
+impl<T> Point<T>
+where
+    T: Updatable,
+{
+    fn select(&mut self, association: Association) {
+        let index = association.index();
+        if self.selected.len() <= index {
+            self.selected.resize_with(index + 1, T::default);
+        }
+        self.selected[index] = self.current.clone();
+    }
+
+    fn selected(&self, association: Association) -> &T {
+        // points added after the READ was processed report their current value
+        self.selected
+            .get(association.index())
+            .unwrap_or(&self.current)
+    }
+}
+
 pub(crate) struct PointMap<T>
 where
     T: Updatable,
@@ -233,6 +283,7 @@ where
 
     fn select_range_with_variation(
         &mut self,
+        association: Association,
         range: IndexRange,
         variation: Option<T::StaticVariation>,
     ) -> Option<VariationRange> {
@@ -243,29 +294,32 @@ where
             // for every point in the range, we copy the current value into a distinct 'selected' cell
             // when writing the response(s) we use the selected value
             // this allows the outstation to send consistent snapshot of the values when a multi-fragment response is required
-            point.selected = point.current.clone();
+            point.select(association);
         }
         Some(T::wrap(range, variation))
     }
 
-    fn select_all(&mut self) -> Option<VariationRange> {
-        self.select_all_with_variation(None)
+    fn select_all(&mut self, association: Association) -> Option<VariationRange> {
+        self.select_all_with_variation(association, None)
     }
 
     fn select_all_with_variation(
         &mut self,
+        association: Association,
         variation: Option<T::StaticVariation>,
     ) -> Option<VariationRange> {
         let range = self.full_range()?;
 
         // as far at the processing goes, we treat this just like a range scan over all the values
-        self.select_range_with_variation(range, variation)
+        self.select_range_with_variation(association, range, variation)
     }
 }
 
 pub(crate) struct StaticDatabase {
     class_zero: ClassZeroConfig,
-    selected: SelectionQueue,
+    max_read_selection: u16,
+    // selected ranges, indexed by association
+    selected: Vec<SelectionQueue>,
     // maps for the various types
     binary: PointMap<BinaryInput>,
     double_bit_binary: PointMap<DoubleBitBinaryInput>,
@@ -275,6 +329,8 @@ pub(crate) struct StaticDatabase {
     analog: PointMap<AnalogInput>,
     analog_output_status: PointMap<AnalogOutputStatus>,
     octet_strings: PointMap<OctetString>,
+    unsigned_integers: PointMap<UnsignedInteger>,
+    security_statistics: PointMap<SecurityStatistic>,
 }
 
 impl Default for StaticDatabase {
@@ -292,7 +348,8 @@ impl StaticDatabase {
 
         Self {
             class_zero,
-            selected: SelectionQueue::new(max_read_selection),
+            max_read_selection,
+            selected: Vec::new(),
             binary: PointMap::empty(),
             double_bit_binary: PointMap::empty(),
             binary_output_status: PointMap::empty(),
@@ -301,6 +358,8 @@ impl StaticDatabase {
             analog: PointMap::empty(),
             analog_output_status: PointMap::empty(),
             octet_strings: PointMap::empty(),
+            unsigned_integers: PointMap::empty(),
+            security_statistics: PointMap::empty(),
         }
     }
 
@@ -322,13 +381,107 @@ impl StaticDatabase {
         }
     }
 
+    pub(crate) fn assign_class<T>(&mut self, range: Option<IndexRange>, class: Option<EventClass>)
+    where
+        T: Updatable,
+    {
+        let map = self.get_mut_map::<T>();
+        let range = match range.or_else(|| map.full_range()) {
+            None => return,
+            Some(x) => x,
+        };
+
+        for (_index, point) in map.inner.range_mut(range) {
+            point.config.class = class;
+        }
+    }
+
+    /// true if a point of the type exists within the range, or at any index if there is no range
+    pub(crate) fn has_points<T>(&self, range: Option<IndexRange>) -> bool
+    where
+        T: Updatable,
+    {
+        let map = self.get_map::<T>();
+        match range {
+            None => !map.inner.is_empty(),
+            Some(range) => map.inner.range(range).next().is_some(),
+        }
+    }
+
+    /// configuration and current value of every point of a type in index order
+    pub(crate) fn points<'a, T>(
+        &'a self,
+    ) -> impl Iterator<Item = (u16, &'a PointConfig<T>, &'a T)> + 'a
+    where
+        T: Updatable + 'a,
+    {
+        self.get_map::<T>()
+            .inner
+            .iter()
+            .map(|(index, point)| (*index, &point.config, &point.current))
+    }
+
+    /// change the types reported during a class 0 READ
+    pub(crate) fn set_class_zero(&mut self, class_zero: ClassZeroConfig) {
+        self.class_zero = class_zero;
+    }
+
+    /// set the current value of a point without performing event detection
+    ///
+    /// The value also becomes the reference value for subsequent event detection
+    pub(crate) fn restore<T>(&mut self, index: u16, value: &T) -> bool
+    where
+        T: Updatable,
+    {
+        match self.get_mut_map::<T>().get_mut(index) {
+            None => false,
+            Some(x) => {
+                x.current = value.clone();
+                for selected in x.selected.iter_mut() {
+                    *selected = value.clone();
+                }
+                x.last_event = value.clone();
+                true
+            }
+        }
+    }
+
+    /// remove every point
+    pub(crate) fn clear(&mut self) {
+        for selected in self.selected.iter_mut() {
+            selected.reset();
+        }
+        self.binary = PointMap::empty();
+        self.double_bit_binary = PointMap::empty();
+        self.binary_output_status = PointMap::empty();
+        self.counter = PointMap::empty();
+        self.frozen_counter = PointMap::empty();
+        self.analog = PointMap::empty();
+        self.analog_output_status = PointMap::empty();
+        self.octet_strings = PointMap::empty();
+        self.unsigned_integers = PointMap::empty();
+        self.security_statistics = PointMap::empty();
+    }
+
     #[cfg(test)]
     pub(crate) fn selection_capacity(&self) -> usize {
-        self.selected.queue.capacity()
+        self.max_read_selection as usize
+    }
+
+    pub(crate) fn reset(&mut self, association: Association) {
+        if let Some(selected) = self.selected.get_mut(association.index()) {
+            selected.reset();
+        }
     }
 
-    pub(crate) fn reset(&mut self) {
-        self.selected.reset();
+    fn selection(&mut self, association: Association) -> &mut SelectionQueue {
+        let index = association.index();
+        if self.selected.len() <= index {
+            let max = self.max_read_selection;
+            self.selected
+                .resize_with(index + 1, || SelectionQueue::new(max));
+        }
+        &mut self.selected[index]
     }
 
     pub(crate) fn add<T>(&mut self, index: u16, config: PointConfig<T>) -> bool
@@ -363,6 +516,16 @@ impl StaticDatabase {
             .map(|point| point.current.clone())
     }
 
+    pub(crate) fn get_with_class<T>(&self, index: u16) -> Option<(T, Option<EventClass>)>
+    where
+        T: Updatable,
+    {
+        self.get_map::<T>()
+            .inner
+            .get(&index)
+            .map(|point| (point.current.clone(), point.config.class))
+    }
+
     pub(crate) fn update<T>(
         &mut self,
         value: &T,
@@ -384,11 +547,13 @@ impl StaticDatabase {
                     EventMode::Suppress => None,
                     EventMode::Force => {
                         x.last_event = value.clone();
+                        x.config.detector.reset();
                         x.config.class.map(|ec| (x.config.e_var, ec))
                     }
                     EventMode::Detect => {
-                        if x.config.detector.is_event(&x.last_event, value) {
+                        if x.config.detector.is_event(value, &x.last_event) {
                             x.last_event = value.clone();
+                            x.config.detector.reset();
                             x.config.class.map(|ec| (x.config.e_var, ec))
                         } else {
                             None
@@ -401,17 +566,21 @@ impl StaticDatabase {
         }
     }
 
-    pub(crate) fn write(&mut self, cursor: &mut WriteCursor) -> Result<(), BadWrite> {
-        while let Some(range) = self.selected.peek() {
-            match self.write_range(cursor, range) {
+    pub(crate) fn write(
+        &mut self,
+        association: Association,
+        cursor: &mut WriteCursor,
+    ) -> Result<(), BadWrite> {
+        while let Some(range) = self.selection(association).peek() {
+            match self.write_range(association, cursor, range) {
                 // done with this header
                 Ok(()) => {
-                    self.selected.pop();
+                    self.selection(association).pop();
                 }
                 // ran out of space
                 Err(s) => {
                     // possibly wrote some data though, so update the selection
-                    self.selected.update_front(s);
+                    self.selection(association).update_front(s);
                     return Err(BadWrite);
                 }
             };
@@ -422,33 +591,39 @@ impl StaticDatabase {
 
     fn write_range(
         &mut self,
+        association: Association,
         cursor: &mut WriteCursor,
         range: VariationRange,
     ) -> Result<(), VariationRange> {
         match range.variation {
             SpecificVariation::Binary(var) => {
-                self.write_typed_range::<BinaryInput>(cursor, range.range, var)
-            }
-            SpecificVariation::DoubleBitBinary(var) => {
-                self.write_typed_range::<DoubleBitBinaryInput>(cursor, range.range, var)
+                self.write_typed_range::<BinaryInput>(association, cursor, range.range, var)
             }
+            SpecificVariation::DoubleBitBinary(var) => self
+                .write_typed_range::<DoubleBitBinaryInput>(association, cursor, range.range, var),
             SpecificVariation::BinaryOutputStatus(var) => {
-                self.write_typed_range::<BinaryOutputStatus>(cursor, range.range, var)
+                self.write_typed_range::<BinaryOutputStatus>(association, cursor, range.range, var)
             }
             SpecificVariation::Counter(var) => {
-                self.write_typed_range::<Counter>(cursor, range.range, var)
+                self.write_typed_range::<Counter>(association, cursor, range.range, var)
             }
             SpecificVariation::FrozenCounter(var) => {
-                self.write_typed_range::<FrozenCounter>(cursor, range.range, var)
+                self.write_typed_range::<FrozenCounter>(association, cursor, range.range, var)
             }
             SpecificVariation::Analog(var) => {
-                self.write_typed_range::<AnalogInput>(cursor, range.range, var)
+                self.write_typed_range::<AnalogInput>(association, cursor, range.range, var)
             }
             SpecificVariation::AnalogOutputStatus(var) => {
-                self.write_typed_range::<AnalogOutputStatus>(cursor, range.range, var)
+                self.write_typed_range::<AnalogOutputStatus>(association, cursor, range.range, var)
             }
             SpecificVariation::OctetString => {
-                self.write_typed_range::<OctetString>(cursor, range.range, None)
+                self.write_typed_range::<OctetString>(association, cursor, range.range, None)
+            }
+            SpecificVariation::UnsignedInteger => {
+                self.write_typed_range::<UnsignedInteger>(association, cursor, range.range, None)
+            }
+            SpecificVariation::SecurityStatistic => {
+                self.write_typed_range::<SecurityStatistic>(association, cursor, range.range, None)
             }
             SpecificVariation::AnalogDeadBand(var) => {
                 self.write_analog_dead_bands(cursor, range.range, var)
@@ -458,6 +633,7 @@ impl StaticDatabase {
 
     fn write_typed_range<T>(
         &mut self,
+        association: Association,
         cursor: &mut WriteCursor,
         range: IndexRange,
         variation: Option<T::StaticVariation>,
@@ -467,13 +643,14 @@ impl StaticDatabase {
     {
         let mut writer = RangeWriter::new();
         for (index, item) in self.get_map::<T>().inner.range(range) {
+            let selected = item.selected(association);
             // first determine what variation should be written
             let info = variation
                 .unwrap_or(item.config.s_var)
-                .promote(&item.selected)
-                .get_write_info(&item.selected);
+                .promote(selected)
+                .get_write_info(selected);
 
-            if writer.write(cursor, *index, &item.selected, info).is_err() {
+            if writer.write(cursor, *index, selected, info).is_err() {
                 // ran out of space, tell calling code to resume at this index
                 return Err(T::wrap(IndexRange::new(*index, range.stop), variation));
             }
@@ -510,31 +687,39 @@ impl StaticDatabase {
         Ok(())
     }
 
-    pub(crate) fn select(&mut self, variation: StaticReadHeader) -> Iin2 {
+    pub(crate) fn select(&mut self, association: Association, variation: StaticReadHeader) -> Iin2 {
         match variation {
-            StaticReadHeader::Class0 => self.select_class_zero(),
+            StaticReadHeader::Class0 => self.select_class_zero(association),
             StaticReadHeader::Binary(variation, range) => {
-                self.select_by_type::<BinaryInput>(variation, range)
+                self.select_by_type::<BinaryInput>(association, variation, range)
             }
             StaticReadHeader::DoubleBitBinary(variation, range) => {
-                self.select_by_type::<DoubleBitBinaryInput>(variation, range)
+                self.select_by_type::<DoubleBitBinaryInput>(association, variation, range)
             }
             StaticReadHeader::BinaryOutputStatus(variation, range) => {
-                self.select_by_type::<BinaryOutputStatus>(variation, range)
+                self.select_by_type::<BinaryOutputStatus>(association, variation, range)
             }
             StaticReadHeader::Counter(variation, range) => {
-                self.select_by_type::<Counter>(variation, range)
+                self.select_by_type::<Counter>(association, variation, range)
             }
             StaticReadHeader::FrozenCounter(variation, range) => {
-                self.select_by_type::<FrozenCounter>(variation, range)
+                self.select_by_type::<FrozenCounter>(association, variation, range)
             }
             StaticReadHeader::Analog(variation, range) => {
-                self.select_by_type::<AnalogInput>(variation, range)
+                self.select_by_type::<AnalogInput>(association, variation, range)
             }
             StaticReadHeader::AnalogOutputStatus(variation, range) => {
-                self.select_by_type::<AnalogOutputStatus>(variation, range)
+                self.select_by_type::<AnalogOutputStatus>(association, variation, range)
+            }
+            StaticReadHeader::OctetString(range) => {
+                self.select_by_type::<OctetString>(association, None, range)
+            }
+            StaticReadHeader::UnsignedInteger(range) => {
+                self.select_by_type::<UnsignedInteger>(association, None, range)
+            }
+            StaticReadHeader::SecurityStatistic(range) => {
+                self.select_by_type::<SecurityStatistic>(association, None, range)
             }
-            StaticReadHeader::OctetString(range) => self.select_by_type::<OctetString>(None, range),
             StaticReadHeader::FrozenAnalog(_, _) => {
                 // we don't support this, but we know what it is
                 Iin2::default()
@@ -543,19 +728,19 @@ impl StaticDatabase {
                 match range {
                     None => {
                         if let Some(range) = self.analog.full_range() {
-                            self.push_selection(VariationRange::new(
-                                range,
-                                SpecificVariation::AnalogDeadBand(var),
-                            ))
+                            self.push_selection(
+                                association,
+                                VariationRange::new(range, SpecificVariation::AnalogDeadBand(var)),
+                            )
                         } else {
                             // we don't have any of those
                             Iin2::default()
                         }
                     }
-                    Some(range) => self.push_selection(VariationRange::new(
-                        range,
-                        SpecificVariation::AnalogDeadBand(var),
-                    )),
+                    Some(range) => self.push_selection(
+                        association,
+                        VariationRange::new(range, SpecificVariation::AnalogDeadBand(var)),
+                    ),
                 }
             }
         }
@@ -563,6 +748,7 @@ impl StaticDatabase {
 
     fn select_by_type<T>(
         &mut self,
+        association: Association,
         variation: Option<T::StaticVariation>,
         range: Option<IndexRange>,
     ) -> Iin2
@@ -570,18 +756,20 @@ impl StaticDatabase {
         T: Updatable,
     {
         let selected = match range {
-            Some(range) => T::get_mut_map(self).select_range_with_variation(range, variation),
-            None => T::get_mut_map(self).select_all_with_variation(variation),
+            Some(range) => {
+                T::get_mut_map(self).select_range_with_variation(association, range, variation)
+            }
+            None => T::get_mut_map(self).select_all_with_variation(association, variation),
         };
 
         match selected {
             None => Iin2::default(),
-            Some(range) => self.push_selection(range),
+            Some(range) => self.push_selection(association, range),
         }
     }
 
-    fn push_selection(&mut self, range: VariationRange) -> Iin2 {
-        if self.selected.push_back(range) {
+    fn push_selection(&mut self, association: Association, range: VariationRange) -> Iin2 {
+        if self.selection(association).push_back(range) {
             Iin2::default()
         } else {
             Iin2::PARAMETER_ERROR
@@ -602,17 +790,17 @@ impl StaticDatabase {
         T::get_mut_map(self)
     }
 
-    fn select_class_zero_type<T>(&mut self) -> Iin2
+    fn select_class_zero_type<T>(&mut self, association: Association) -> Iin2
     where
         T: Updatable,
     {
         if T::enabled_class_zero(&self.class_zero) {
-            let full_range = match T::get_mut_map(self).select_all() {
+            let full_range = match T::get_mut_map(self).select_all(association) {
                 None => return Iin2::default(),
                 Some(x) => x,
             };
 
-            if self.selected.push_back(full_range) {
+            if self.selection(association).push_back(full_range) {
                 Iin2::default()
             } else {
                 // out of space for read headers
@@ -623,15 +811,17 @@ impl StaticDatabase {
         }
     }
 
-    fn select_class_zero(&mut self) -> Iin2 {
-        self.select_class_zero_type::<BinaryInput>()
-            | self.select_class_zero_type::<DoubleBitBinaryInput>()
-            | self.select_class_zero_type::<BinaryOutputStatus>()
-            | self.select_class_zero_type::<Counter>()
-            | self.select_class_zero_type::<FrozenCounter>()
-            | self.select_class_zero_type::<AnalogInput>()
-            | self.select_class_zero_type::<AnalogOutputStatus>()
-            | self.select_class_zero_type::<OctetString>()
+    fn select_class_zero(&mut self, association: Association) -> Iin2 {
+        self.select_class_zero_type::<BinaryInput>(association)
+            | self.select_class_zero_type::<DoubleBitBinaryInput>(association)
+            | self.select_class_zero_type::<BinaryOutputStatus>(association)
+            | self.select_class_zero_type::<Counter>(association)
+            | self.select_class_zero_type::<FrozenCounter>(association)
+            | self.select_class_zero_type::<AnalogInput>(association)
+            | self.select_class_zero_type::<AnalogOutputStatus>(association)
+            | self.select_class_zero_type::<OctetString>(association)
+            | self.select_class_zero_type::<UnsignedInteger>(association)
+            | self.select_class_zero_type::<SecurityStatistic>(association)
     }
 }
 
@@ -643,8 +833,18 @@ where
     deadband: N,
 }
 
+/// Deadband for analog values that supports every [`DeadbandMode`]
+pub(crate) struct AnalogDeadband {
+    deadband: f64,
+    mode: DeadbandMode,
+    /// deviation from the last reported value accumulated in integrating mode
+    integral: f64,
+}
+
 pub(crate) struct OctetStringDetector;
 
+pub(crate) struct UnsignedIntegerDetector;
+
 impl<N> Deadband<N>
 where
     N: std::ops::Sub<N, Output = N> + PartialOrd<N>,
@@ -653,6 +853,13 @@ where
         Self { deadband: value }
     }
 
+    pub(crate) fn value(&self) -> N
+    where
+        N: Copy,
+    {
+        self.deadband
+    }
+
     fn exceeded(&self, lhs: N, rhs: N) -> bool {
         let diff = if lhs > rhs { lhs - rhs } else { rhs - lhs };
 
@@ -660,20 +867,38 @@ where
     }
 }
 
+impl AnalogDeadband {
+    pub(crate) fn new(deadband: f64, mode: DeadbandMode) -> Self {
+        Self {
+            deadband,
+            mode,
+            integral: 0.0,
+        }
+    }
+
+    pub(crate) fn value(&self) -> f64 {
+        self.deadband
+    }
+
+    pub(crate) fn mode(&self) -> DeadbandMode {
+        self.mode
+    }
+}
+
 impl EventDetector<BinaryInput> for FlagsDetector {
-    fn is_event(&self, new: &BinaryInput, old: &BinaryInput) -> bool {
+    fn is_event(&mut self, new: &BinaryInput, old: &BinaryInput) -> bool {
         new.get_wire_flags() != old.get_wire_flags()
     }
 }
 
 impl EventDetector<BinaryOutputStatus> for FlagsDetector {
-    fn is_event(&self, new: &BinaryOutputStatus, old: &BinaryOutputStatus) -> bool {
+    fn is_event(&mut self, new: &BinaryOutputStatus, old: &BinaryOutputStatus) -> bool {
         new.get_wire_flags() != old.get_wire_flags()
     }
 }
 
 impl EventDetector<DoubleBitBinaryInput> for FlagsDetector {
-    fn is_event(&self, new: &DoubleBitBinaryInput, old: &DoubleBitBinaryInput) -> bool {
+    fn is_event(&mut self, new: &DoubleBitBinaryInput, old: &DoubleBitBinaryInput) -> bool {
         new.get_wire_flags() != old.get_wire_flags()
     }
 }
@@ -694,6 +919,12 @@ impl HasValue<u32> for FrozenCounter {
     }
 }
 
+impl HasValue<u32> for SecurityStatistic {
+    fn value(&self) -> u32 {
+        self.value
+    }
+}
+
 impl HasValue<f64> for AnalogInput {
     fn value(&self) -> f64 {
         self.value
@@ -711,7 +942,7 @@ where
     T: Updatable + HasValue<N> + WireFlags,
     N: std::ops::Sub<N, Output = N> + PartialOrd<N>,
 {
-    fn is_event(&self, new: &T, old: &T) -> bool {
+    fn is_event(&mut self, new: &T, old: &T) -> bool {
         if new.get_wire_flags() != old.get_wire_flags() {
             return true;
         }
@@ -720,12 +951,46 @@ where
     }
 }
 
+impl<T> EventDetector<T> for AnalogDeadband
+where
+    T: Updatable + HasValue<f64> + WireFlags,
+{
+    fn is_event(&mut self, new: &T, old: &T) -> bool {
+        if new.get_wire_flags() != old.get_wire_flags() {
+            return true;
+        }
+
+        let deviation = new.value() - old.value();
+
+        match self.mode {
+            DeadbandMode::Fixed => deviation.abs() > self.deadband,
+            DeadbandMode::PercentOfRange { min, max } => {
+                deviation.abs() > self.deadband / 100.0 * (max - min).abs()
+            }
+            DeadbandMode::Integrating => {
+                self.integral += deviation;
+                self.integral.abs() > self.deadband
+            }
+        }
+    }
+
+    fn reset(&mut self) {
+        self.integral = 0.0;
+    }
+}
+
 impl EventDetector<OctetString> for OctetStringDetector {
-    fn is_event(&self, new: &OctetString, old: &OctetString) -> bool {
+    fn is_event(&mut self, new: &OctetString, old: &OctetString) -> bool {
         new.value() != old.value()
     }
 }
 
+impl EventDetector<UnsignedInteger> for UnsignedIntegerDetector {
+    fn is_event(&mut self, _new: &UnsignedInteger, _old: &UnsignedInteger) -> bool {
+        false
+    }
+}
+
 impl Updatable for BinaryInput {
     type StaticVariation = StaticBinaryInputVariation;
     type Detector = FlagsDetector;
@@ -867,7 +1132,7 @@ impl UpdatableFlags for FrozenCounter {
 
 impl Updatable for AnalogInput {
     type StaticVariation = StaticAnalogInputVariation;
-    type Detector = Deadband<f64>;
+    type Detector = AnalogDeadband;
 
     fn get_map(maps: &StaticDatabase) -> &PointMap<Self> {
         &maps.analog
@@ -895,7 +1160,7 @@ impl UpdatableFlags for AnalogInput {
 
 impl Updatable for AnalogOutputStatus {
     type StaticVariation = StaticAnalogOutputStatusVariation;
-    type Detector = Deadband<f64>;
+    type Detector = AnalogDeadband;
 
     fn get_map(maps: &StaticDatabase) -> &PointMap<Self> {
         &maps.analog_output_status
@@ -942,6 +1207,48 @@ impl Updatable for OctetString {
     }
 }
 
+impl Updatable for UnsignedInteger {
+    type StaticVariation = StaticUnsignedIntegerVariation;
+    type Detector = UnsignedIntegerDetector;
+
+    fn get_map(maps: &StaticDatabase) -> &PointMap<Self> {
+        &maps.unsigned_integers
+    }
+
+    fn get_mut_map(maps: &mut StaticDatabase) -> &mut PointMap<Self> {
+        &mut maps.unsigned_integers
+    }
+
+    fn wrap(range: IndexRange, _variation: Option<Self::StaticVariation>) -> VariationRange {
+        SpecificVariation::UnsignedInteger.with(range)
+    }
+
+    fn enabled_class_zero(config: &ClassZeroConfig) -> bool {
+        config.unsigned_integer
+    }
+}
+
+impl Updatable for SecurityStatistic {
+    type StaticVariation = StaticSecurityStatisticVariation;
+    type Detector = Deadband<u32>;
+
+    fn get_map(maps: &StaticDatabase) -> &PointMap<Self> {
+        &maps.security_statistics
+    }
+
+    fn get_mut_map(maps: &mut StaticDatabase) -> &mut PointMap<Self> {
+        &mut maps.security_statistics
+    }
+
+    fn wrap(range: IndexRange, _variation: Option<Self::StaticVariation>) -> VariationRange {
+        SpecificVariation::SecurityStatistic.with(range)
+    }
+
+    fn enabled_class_zero(config: &ClassZeroConfig) -> bool {
+        config.security_statistic
+    }
+}
+
 impl Default for BinaryInput {
     fn default() -> Self {
         Self::new(false, Flags::RESTART, Time::unsynchronized(0))
@@ -994,10 +1301,25 @@ impl Default for OctetString {
     }
 }
 
+impl Default for UnsignedInteger {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl Default for SecurityStatistic {
+    fn default() -> Self {
+        // statistics are maintained by the outstation itself, so they are always online
+        Self::new(0, 0, Flags::ONLINE, Time::unsynchronized(0))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    const ASSOCIATION: Association = Association(0);
+
     fn binary_config(var: StaticBinaryInputVariation) -> PointConfig<BinaryInput> {
         PointConfig {
             class: Some(EventClass::Class1),
@@ -1021,7 +1343,7 @@ mod tests {
             class: Some(EventClass::Class1),
             s_var: var,
             e_var: EventAnalogInputVariation::Group32Var1,
-            detector: Deadband::new(0.0),
+            detector: AnalogDeadband::new(0.0, DeadbandMode::Fixed),
         }
     }
 
@@ -1033,12 +1355,12 @@ mod tests {
         assert!(db.add(1, counter_config(StaticCounterVariation::Group20Var1)));
         assert!(db.add(2, analog_config(StaticAnalogInputVariation::Group30Var1)));
 
-        db.select_class_zero();
+        db.select_class_zero(ASSOCIATION);
 
         let mut buffer = [0u8; 64];
         let mut cursor = WriteCursor::new(buffer.as_mut());
 
-        db.write(&mut cursor).unwrap();
+        db.write(ASSOCIATION, &mut cursor).unwrap();
 
         assert_eq!(
             cursor.written(),
@@ -1060,13 +1382,13 @@ mod tests {
         assert!(db.add(1, counter_config(StaticCounterVariation::Group20Var1)));
         assert!(db.add(2, analog_config(StaticAnalogInputVariation::Group30Var1)));
 
-        db.select_class_zero();
+        db.select_class_zero(ASSOCIATION);
 
         let mut buffer = [0u8; 12]; // can only fit one header at a time
 
         {
             let mut cursor = WriteCursor::new(buffer.as_mut());
-            db.write(&mut cursor).unwrap_err(); // incomplete !
+            db.write(ASSOCIATION, &mut cursor).unwrap_err(); // incomplete !
 
             assert_eq!(
                 cursor.written(),
@@ -1079,7 +1401,7 @@ mod tests {
 
         {
             let mut cursor = WriteCursor::new(buffer.as_mut());
-            db.write(&mut cursor).unwrap_err(); // incomplete !
+            db.write(ASSOCIATION, &mut cursor).unwrap_err(); // incomplete !
 
             assert_eq!(
                 cursor.written(),
@@ -1092,7 +1414,7 @@ mod tests {
 
         {
             let mut cursor = WriteCursor::new(buffer.as_mut());
-            db.write(&mut cursor).unwrap(); // complete !
+            db.write(ASSOCIATION, &mut cursor).unwrap(); // complete !
 
             assert_eq!(
                 cursor.written(),
@@ -1110,12 +1432,12 @@ mod tests {
 
         assert!(db.add(0, binary_config(StaticBinaryInputVariation::Group1Var1)));
 
-        db.select_class_zero();
+        db.select_class_zero(ASSOCIATION);
 
         let mut buffer = [0u8; 64];
         let mut cursor = WriteCursor::new(buffer.as_mut());
 
-        db.write(&mut cursor).unwrap();
+        db.write(ASSOCIATION, &mut cursor).unwrap();
 
         assert_eq!(
             cursor.written(),
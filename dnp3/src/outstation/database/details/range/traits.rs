@@ -263,3 +263,15 @@ impl StaticVariation<OctetString> for StaticOctetStringVariation {
         octet_string(value)
     }
 }
+
+impl StaticVariation<UnsignedInteger> for StaticUnsignedIntegerVariation {
+    fn get_write_info(&self, _value: &UnsignedInteger) -> WriteInfo<UnsignedInteger> {
+        fixed_type::<UnsignedInteger, Group102Var1>()
+    }
+}
+
+impl StaticVariation<SecurityStatistic> for StaticSecurityStatisticVariation {
+    fn get_write_info(&self, _value: &SecurityStatistic) -> WriteInfo<SecurityStatistic> {
+        fixed_type::<SecurityStatistic, Group121Var1>()
+    }
+}
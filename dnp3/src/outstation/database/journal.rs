@@ -0,0 +1,1019 @@
+use std::collections::BTreeMap;
+use std::fs::{File, OpenOptions};
+use std::io::{ErrorKind, Read, Write};
+use std::path::{Path, PathBuf};
+
+use crate::app::control::CommandStatus;
+use crate::app::measurement::*;
+use crate::app::Timestamp;
+use crate::outstation::database::config::*;
+use crate::outstation::database::EventClass;
+
+use scursor::{ReadCursor, ReadError};
+
+/// Event retained by an [`EventJournal`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct JournaledEvent {
+    /// Identifier assigned to the event when it was created
+    pub id: u64,
+    /// Index of the point that produced the event
+    pub index: u16,
+    /// Class of the event
+    pub class: EventClass,
+    /// Value of the event
+    pub value: JournaledValue,
+}
+
+/// Value of a journaled event and the variation in which it is reported by default
+#[derive(Clone, Debug, PartialEq)]
+pub enum JournaledValue {
+    /// Binary input event
+    Binary(BinaryInput, EventBinaryInputVariation),
+    /// Double-bit binary input event
+    DoubleBitBinary(DoubleBitBinaryInput, EventDoubleBitBinaryInputVariation),
+    /// Binary output status event
+    BinaryOutputStatus(BinaryOutputStatus, EventBinaryOutputStatusVariation),
+    /// Counter event
+    Counter(Counter, EventCounterVariation),
+    /// Frozen counter event
+    FrozenCounter(FrozenCounter, EventFrozenCounterVariation),
+    /// Analog input event
+    Analog(AnalogInput, EventAnalogInputVariation),
+    /// Analog output status event
+    AnalogOutputStatus(AnalogOutputStatus, EventAnalogOutputStatusVariation),
+    /// Octet string event of at most 255 bytes
+    OctetString(Vec<u8>),
+    /// Complete free-format file transfer object header (g70)
+    FileTransfer(Vec<u8>),
+    /// Binary output command event
+    BinaryOutputCommand(BinaryOutputCommandEvent, EventBinaryOutputCommandVariation),
+    /// Analog output command event
+    AnalogOutputCommand(AnalogOutputCommandEvent, EventAnalogOutputCommandVariation),
+    /// Complete free-format data set object header (g88)
+    DataSet(Vec<u8>),
+    /// Virtual terminal output block, the index is the port
+    VirtualTerminal(Vec<u8>),
+    /// Security statistic event
+    SecurityStatistic(SecurityStatistic, EventSecurityStatisticVariation),
+}
+
+/// Persistent storage for the events in the outstation's event buffer
+///
+/// The event buffer records every event it inserts and every event it removes, either because
+/// the event was confirmed by every master or because the buffer overflowed. Events that were
+/// recorded but never removed are restored into the buffer when the journal is attached using
+/// [`Database::set_event_journal`](crate::outstation::database::Database::set_event_journal).
+///
+/// Failures to record an event are logged, but do not affect the operation of the outstation.
+pub trait EventJournal: Send {
+    /// Load the events that were recorded but never removed, in the order in which they were inserted
+    fn load(&mut self) -> std::io::Result<Vec<JournaledEvent>>;
+
+    /// Record an event inserted into the buffer
+    fn record_insert(&mut self, event: &JournaledEvent) -> std::io::Result<()>;
+
+    /// Record the removal of a previously inserted event from the buffer
+    fn record_remove(&mut self, id: u64) -> std::io::Result<()>;
+}
+
+/// Errors that can occur when attaching an [`EventJournal`] to the database
+#[derive(Debug)]
+pub enum EventJournalError {
+    /// Events were created before the journal was attached
+    EventsAlreadyCreated,
+    /// The journal could not be loaded
+    Io(std::io::Error),
+}
+
+impl std::fmt::Display for EventJournalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EventsAlreadyCreated => {
+                f.write_str("the event journal must be attached before any events are created")
+            }
+            Self::Io(err) => write!(f, "unable to load the event journal: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for EventJournalError {}
+
+impl From<std::io::Error> for EventJournalError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// [`EventJournal`] implementation that appends records to a file
+///
+/// Each record is written to the file with a single write before it returns, so the journal
+/// survives a restart of the process. By default the data is not synchronized to the storage
+/// device, so the records written shortly before a power failure may be lost. Use
+/// [`FileEventJournal::with_sync_data`] to synchronize every record at the cost of slower writes.
+///
+/// When the journal is loaded, and whenever the file holds several times more records than there
+/// are events that were never removed, the file is compacted so that it only contains those
+/// events. The file is also truncated whenever every recorded event has been removed. A record
+/// that was only partially written when the process was terminated is discarded.
+pub struct FileEventJournal {
+    path: PathBuf,
+    file: File,
+    /// synchronize every record to the storage device
+    sync_data: bool,
+    /// number of recorded events that have not been removed
+    live: usize,
+    /// number of records in the file
+    records: usize,
+}
+
+const INSERT_TAG: u8 = 1;
+const REMOVE_TAG: u8 = 2;
+
+/// the file is compacted once it holds this many times more records than live events
+const COMPACTION_RATIO: usize = 4;
+/// smaller files are only compacted when they are loaded
+const MIN_COMPACTION_RECORDS: usize = 64;
+
+impl FileEventJournal {
+    /// Open or create the journal file at the specified path
+    pub fn open<P: AsRef<Path>>(path: P) -> std::io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let file = Self::open_for_append(&path)?;
+        Ok(Self {
+            path,
+            file,
+            sync_data: false,
+            live: 0,
+            records: 0,
+        })
+    }
+
+    /// Synchronize every record to the storage device before it returns
+    ///
+    /// This guarantees that recorded events survive a power failure, but each record then waits
+    /// for the storage device. Disabled by default.
+    pub fn with_sync_data(self, sync_data: bool) -> Self {
+        Self { sync_data, ..self }
+    }
+
+    fn open_for_append(path: &Path) -> std::io::Result<File> {
+        OpenOptions::new()
+            .create(true)
+            .read(true)
+            .append(true)
+            .open(path)
+    }
+
+    fn append(&mut self, tag: u8, payload: &[u8]) -> std::io::Result<()> {
+        let mut entry = Vec::with_capacity(payload.len() + 5);
+        entry.push(tag);
+        entry.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+        entry.extend_from_slice(payload);
+        self.file.write_all(&entry)?;
+        if self.sync_data {
+            self.file.sync_data()?;
+        }
+        self.records += 1;
+        Ok(())
+    }
+
+    fn compact_if_needed(&mut self) -> std::io::Result<()> {
+        if self.records >= MIN_COMPACTION_RECORDS && self.records > COMPACTION_RATIO * self.live {
+            let events = self.read_live()?;
+            self.compact(&events)?;
+        }
+        Ok(())
+    }
+
+    /// replace the file with one that only contains the specified events
+    fn compact(&mut self, events: &[JournaledEvent]) -> std::io::Result<()> {
+        let mut tmp_path = self.path.clone().into_os_string();
+        tmp_path.push(".tmp");
+        let tmp_path = PathBuf::from(tmp_path);
+
+        {
+            let mut tmp = File::create(&tmp_path)?;
+            for event in events {
+                let payload = encode_event(event);
+                tmp.write_all(&[INSERT_TAG])?;
+                tmp.write_all(&(payload.len() as u32).to_le_bytes())?;
+                tmp.write_all(&payload)?;
+            }
+            tmp.sync_all()?;
+        }
+
+        std::fs::rename(&tmp_path, &self.path)?;
+        self.file = Self::open_for_append(&self.path)?;
+        self.live = events.len();
+        self.records = events.len();
+        Ok(())
+    }
+
+    /// replay the file, returning the events that were never removed in the order they were inserted
+    fn read_live(&self) -> std::io::Result<Vec<JournaledEvent>> {
+        let mut data = Vec::new();
+        File::open(&self.path)?.read_to_end(&mut data)?;
+
+        let mut events: BTreeMap<u64, JournaledEvent> = BTreeMap::new();
+        let mut cursor = ReadCursor::new(&data);
+        while !cursor.is_empty() {
+            let (tag, payload) = match read_record(&mut cursor) {
+                Ok(x) => x,
+                Err(_) => {
+                    tracing::warn!(
+                        "discarding partially written record at the end of event journal {}",
+                        self.path.display()
+                    );
+                    break;
+                }
+            };
+
+            match tag {
+                INSERT_TAG => {
+                    let event = decode_event(payload).map_err(invalid_data)?;
+                    events.insert(event.id, event);
+                }
+                REMOVE_TAG => {
+                    let id = ReadCursor::new(payload)
+                        .read_u64_le()
+                        .map_err(|err| invalid_data(DecodeError::from(err)))?;
+                    events.remove(&id);
+                }
+                _ => {
+                    return Err(invalid_data(format!(
+                        "unknown event journal record type: {tag}"
+                    )))
+                }
+            }
+        }
+
+        Ok(events.into_values().collect())
+    }
+}
+
+impl EventJournal for FileEventJournal {
+    fn load(&mut self) -> std::io::Result<Vec<JournaledEvent>> {
+        let events = self.read_live()?;
+        self.compact(&events)?;
+        Ok(events)
+    }
+
+    fn record_insert(&mut self, event: &JournaledEvent) -> std::io::Result<()> {
+        self.append(INSERT_TAG, &encode_event(event))?;
+        self.live += 1;
+        self.compact_if_needed()
+    }
+
+    fn record_remove(&mut self, id: u64) -> std::io::Result<()> {
+        self.live = self.live.saturating_sub(1);
+        if self.live == 0 {
+            // nothing left to restore
+            self.file.set_len(0)?;
+            self.records = 0;
+            if self.sync_data {
+                self.file.sync_data()?;
+            }
+            return Ok(());
+        }
+        self.append(REMOVE_TAG, &id.to_le_bytes())?;
+        self.compact_if_needed()
+    }
+}
+
+fn read_record<'a>(cursor: &mut ReadCursor<'a>) -> Result<(u8, &'a [u8]), ReadError> {
+    let tag = cursor.read_u8()?;
+    let length = cursor.read_u32_le()?;
+    let payload = cursor.read_bytes(length as usize)?;
+    Ok((tag, payload))
+}
+
+fn invalid_data<E: ToString>(err: E) -> std::io::Error {
+    std::io::Error::new(ErrorKind::InvalidData, err.to_string())
+}
+
+// variations are stored using their position within these lists
+const BINARY_VARIATIONS: &[EventBinaryInputVariation] = &[
+    EventBinaryInputVariation::Group2Var1,
+    EventBinaryInputVariation::Group2Var2,
+    EventBinaryInputVariation::Group2Var3,
+];
+const DOUBLE_BIT_BINARY_VARIATIONS: &[EventDoubleBitBinaryInputVariation] = &[
+    EventDoubleBitBinaryInputVariation::Group4Var1,
+    EventDoubleBitBinaryInputVariation::Group4Var2,
+    EventDoubleBitBinaryInputVariation::Group4Var3,
+];
+const BINARY_OUTPUT_STATUS_VARIATIONS: &[EventBinaryOutputStatusVariation] = &[
+    EventBinaryOutputStatusVariation::Group11Var1,
+    EventBinaryOutputStatusVariation::Group11Var2,
+];
+const COUNTER_VARIATIONS: &[EventCounterVariation] = &[
+    EventCounterVariation::Group22Var1,
+    EventCounterVariation::Group22Var2,
+    EventCounterVariation::Group22Var5,
+    EventCounterVariation::Group22Var6,
+];
+const FROZEN_COUNTER_VARIATIONS: &[EventFrozenCounterVariation] = &[
+    EventFrozenCounterVariation::Group23Var1,
+    EventFrozenCounterVariation::Group23Var2,
+    EventFrozenCounterVariation::Group23Var5,
+    EventFrozenCounterVariation::Group23Var6,
+];
+const ANALOG_VARIATIONS: &[EventAnalogInputVariation] = &[
+    EventAnalogInputVariation::Group32Var1,
+    EventAnalogInputVariation::Group32Var2,
+    EventAnalogInputVariation::Group32Var3,
+    EventAnalogInputVariation::Group32Var4,
+    EventAnalogInputVariation::Group32Var5,
+    EventAnalogInputVariation::Group32Var6,
+    EventAnalogInputVariation::Group32Var7,
+    EventAnalogInputVariation::Group32Var8,
+];
+const ANALOG_OUTPUT_STATUS_VARIATIONS: &[EventAnalogOutputStatusVariation] = &[
+    EventAnalogOutputStatusVariation::Group42Var1,
+    EventAnalogOutputStatusVariation::Group42Var2,
+    EventAnalogOutputStatusVariation::Group42Var3,
+    EventAnalogOutputStatusVariation::Group42Var4,
+    EventAnalogOutputStatusVariation::Group42Var5,
+    EventAnalogOutputStatusVariation::Group42Var6,
+    EventAnalogOutputStatusVariation::Group42Var7,
+    EventAnalogOutputStatusVariation::Group42Var8,
+];
+const BINARY_OUTPUT_COMMAND_VARIATIONS: &[EventBinaryOutputCommandVariation] = &[
+    EventBinaryOutputCommandVariation::Group13Var1,
+    EventBinaryOutputCommandVariation::Group13Var2,
+];
+const ANALOG_OUTPUT_COMMAND_VARIATIONS: &[EventAnalogOutputCommandVariation] = &[
+    EventAnalogOutputCommandVariation::Group43Var1,
+    EventAnalogOutputCommandVariation::Group43Var2,
+    EventAnalogOutputCommandVariation::Group43Var3,
+    EventAnalogOutputCommandVariation::Group43Var4,
+    EventAnalogOutputCommandVariation::Group43Var5,
+    EventAnalogOutputCommandVariation::Group43Var6,
+    EventAnalogOutputCommandVariation::Group43Var7,
+    EventAnalogOutputCommandVariation::Group43Var8,
+];
+const SECURITY_STATISTIC_VARIATIONS: &[EventSecurityStatisticVariation] = &[
+    EventSecurityStatisticVariation::Group122Var1,
+    EventSecurityStatisticVariation::Group122Var2,
+];
+
+mod type_tag {
+    pub(super) const BINARY: u8 = 1;
+    pub(super) const DOUBLE_BIT_BINARY: u8 = 2;
+    pub(super) const BINARY_OUTPUT_STATUS: u8 = 3;
+    pub(super) const COUNTER: u8 = 4;
+    pub(super) const FROZEN_COUNTER: u8 = 5;
+    pub(super) const ANALOG: u8 = 6;
+    pub(super) const ANALOG_OUTPUT_STATUS: u8 = 7;
+    pub(super) const OCTET_STRING: u8 = 8;
+    pub(super) const FILE_TRANSFER: u8 = 9;
+    pub(super) const BINARY_OUTPUT_COMMAND: u8 = 10;
+    pub(super) const ANALOG_OUTPUT_COMMAND: u8 = 11;
+    pub(super) const DATA_SET: u8 = 12;
+    pub(super) const VIRTUAL_TERMINAL: u8 = 13;
+    pub(super) const SECURITY_STATISTIC: u8 = 14;
+}
+
+struct Encoder {
+    data: Vec<u8>,
+}
+
+impl Encoder {
+    fn u8(&mut self, value: u8) {
+        self.data.push(value);
+    }
+
+    fn bool(&mut self, value: bool) {
+        self.u8(value as u8);
+    }
+
+    fn u16(&mut self, value: u16) {
+        self.data.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn u32(&mut self, value: u32) {
+        self.data.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn u64(&mut self, value: u64) {
+        self.data.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn f64(&mut self, value: f64) {
+        self.data.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn bytes(&mut self, value: &[u8]) {
+        self.data.extend_from_slice(value);
+    }
+
+    fn flags(&mut self, flags: Flags) {
+        self.u8(flags.value);
+    }
+
+    fn time(&mut self, time: Option<Time>) {
+        match time {
+            None => self.u8(0),
+            Some(Time::Synchronized(ts)) => {
+                self.u8(1);
+                self.u64(ts.raw_value());
+            }
+            Some(Time::Unsynchronized(ts)) => {
+                self.u8(2);
+                self.u64(ts.raw_value());
+            }
+        }
+    }
+
+    fn variation<T: Copy + PartialEq>(&mut self, all: &[T], variation: T) {
+        let position = all.iter().position(|x| *x == variation).unwrap_or(0);
+        self.u8(position as u8);
+    }
+}
+
+fn encode_event(event: &JournaledEvent) -> Vec<u8> {
+    let mut enc = Encoder { data: Vec::new() };
+    enc.u64(event.id);
+    enc.u16(event.index);
+    enc.u8(match event.class {
+        EventClass::Class1 => 1,
+        EventClass::Class2 => 2,
+        EventClass::Class3 => 3,
+    });
+
+    match &event.value {
+        JournaledValue::Binary(x, v) => {
+            enc.u8(type_tag::BINARY);
+            enc.variation(BINARY_VARIATIONS, *v);
+            enc.bool(x.value);
+            enc.flags(x.flags);
+            enc.time(x.time);
+        }
+        JournaledValue::DoubleBitBinary(x, v) => {
+            enc.u8(type_tag::DOUBLE_BIT_BINARY);
+            enc.variation(DOUBLE_BIT_BINARY_VARIATIONS, *v);
+            enc.u8(x.value.to_byte());
+            enc.flags(x.flags);
+            enc.time(x.time);
+        }
+        JournaledValue::BinaryOutputStatus(x, v) => {
+            enc.u8(type_tag::BINARY_OUTPUT_STATUS);
+            enc.variation(BINARY_OUTPUT_STATUS_VARIATIONS, *v);
+            enc.bool(x.value);
+            enc.flags(x.flags);
+            enc.time(x.time);
+        }
+        JournaledValue::Counter(x, v) => {
+            enc.u8(type_tag::COUNTER);
+            enc.variation(COUNTER_VARIATIONS, *v);
+            enc.u32(x.value);
+            enc.flags(x.flags);
+            enc.time(x.time);
+        }
+        JournaledValue::FrozenCounter(x, v) => {
+            enc.u8(type_tag::FROZEN_COUNTER);
+            enc.variation(FROZEN_COUNTER_VARIATIONS, *v);
+            enc.u32(x.value);
+            enc.flags(x.flags);
+            enc.time(x.time);
+        }
+        JournaledValue::Analog(x, v) => {
+            enc.u8(type_tag::ANALOG);
+            enc.variation(ANALOG_VARIATIONS, *v);
+            enc.f64(x.value);
+            enc.flags(x.flags);
+            enc.time(x.time);
+        }
+        JournaledValue::AnalogOutputStatus(x, v) => {
+            enc.u8(type_tag::ANALOG_OUTPUT_STATUS);
+            enc.variation(ANALOG_OUTPUT_STATUS_VARIATIONS, *v);
+            enc.f64(x.value);
+            enc.flags(x.flags);
+            enc.time(x.time);
+        }
+        JournaledValue::OctetString(x) => {
+            enc.u8(type_tag::OCTET_STRING);
+            enc.bytes(x);
+        }
+        JournaledValue::FileTransfer(x) => {
+            enc.u8(type_tag::FILE_TRANSFER);
+            enc.bytes(x);
+        }
+        JournaledValue::BinaryOutputCommand(x, v) => {
+            enc.u8(type_tag::BINARY_OUTPUT_COMMAND);
+            enc.variation(BINARY_OUTPUT_COMMAND_VARIATIONS, *v);
+            enc.bool(x.commanded_state);
+            enc.u8(x.status.as_u8());
+            enc.time(x.time);
+        }
+        JournaledValue::AnalogOutputCommand(x, v) => {
+            enc.u8(type_tag::ANALOG_OUTPUT_COMMAND);
+            enc.variation(ANALOG_OUTPUT_COMMAND_VARIATIONS, *v);
+            enc.u8(x.status.as_u8());
+            match x.commanded_value {
+                AnalogCommandValue::I16(value) => {
+                    enc.u8(0);
+                    enc.bytes(&value.to_le_bytes());
+                }
+                AnalogCommandValue::I32(value) => {
+                    enc.u8(1);
+                    enc.bytes(&value.to_le_bytes());
+                }
+                AnalogCommandValue::F32(value) => {
+                    enc.u8(2);
+                    enc.bytes(&value.to_le_bytes());
+                }
+                AnalogCommandValue::F64(value) => {
+                    enc.u8(3);
+                    enc.f64(value);
+                }
+            }
+            enc.time(x.time);
+        }
+        JournaledValue::DataSet(x) => {
+            enc.u8(type_tag::DATA_SET);
+            enc.bytes(x);
+        }
+        JournaledValue::VirtualTerminal(x) => {
+            enc.u8(type_tag::VIRTUAL_TERMINAL);
+            enc.bytes(x);
+        }
+        JournaledValue::SecurityStatistic(x, v) => {
+            enc.u8(type_tag::SECURITY_STATISTIC);
+            enc.variation(SECURITY_STATISTIC_VARIATIONS, *v);
+            enc.u16(x.association_id);
+            enc.u32(x.value);
+            enc.flags(x.flags);
+            enc.time(x.time);
+        }
+    }
+
+    enc.data
+}
+
+#[derive(Debug)]
+enum DecodeError {
+    TooShort,
+    BadValue(&'static str),
+}
+
+impl std::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TooShort => f.write_str("event journal record is too short"),
+            Self::BadValue(field) => write!(f, "event journal record contains a bad {field}"),
+        }
+    }
+}
+
+impl From<ReadError> for DecodeError {
+    fn from(_: ReadError) -> Self {
+        Self::TooShort
+    }
+}
+
+struct Decoder<'a> {
+    cursor: ReadCursor<'a>,
+}
+
+impl Decoder<'_> {
+    fn bool(&mut self) -> Result<bool, DecodeError> {
+        Ok(self.cursor.read_u8()? != 0)
+    }
+
+    fn flags(&mut self) -> Result<Flags, DecodeError> {
+        Ok(Flags::new(self.cursor.read_u8()?))
+    }
+
+    fn time(&mut self) -> Result<Option<Time>, DecodeError> {
+        match self.cursor.read_u8()? {
+            0 => Ok(None),
+            1 => Ok(Some(Time::Synchronized(Timestamp::new(
+                self.cursor.read_u64_le()?,
+            )))),
+            2 => Ok(Some(Time::Unsynchronized(Timestamp::new(
+                self.cursor.read_u64_le()?,
+            )))),
+            _ => Err(DecodeError::BadValue("time")),
+        }
+    }
+
+    fn variation<T: Copy>(&mut self, all: &[T]) -> Result<T, DecodeError> {
+        all.get(self.cursor.read_u8()? as usize)
+            .copied()
+            .ok_or(DecodeError::BadValue("variation"))
+    }
+
+    fn remaining(&mut self) -> Vec<u8> {
+        self.cursor.read_all().to_vec()
+    }
+}
+
+fn decode_event(payload: &[u8]) -> Result<JournaledEvent, DecodeError> {
+    let mut dec = Decoder {
+        cursor: ReadCursor::new(payload),
+    };
+
+    let id = dec.cursor.read_u64_le()?;
+    let index = dec.cursor.read_u16_le()?;
+    let class = match dec.cursor.read_u8()? {
+        1 => EventClass::Class1,
+        2 => EventClass::Class2,
+        3 => EventClass::Class3,
+        _ => return Err(DecodeError::BadValue("class")),
+    };
+
+    let value = match dec.cursor.read_u8()? {
+        type_tag::BINARY => {
+            let v = dec.variation(BINARY_VARIATIONS)?;
+            let x = BinaryInput::new(dec.bool()?, dec.flags()?, Time::unsynchronized(0));
+            JournaledValue::Binary(
+                BinaryInput {
+                    time: dec.time()?,
+                    ..x
+                },
+                v,
+            )
+        }
+        type_tag::DOUBLE_BIT_BINARY => {
+            let v = dec.variation(DOUBLE_BIT_BINARY_VARIATIONS)?;
+            let raw = dec.cursor.read_u8()?;
+            let value = DoubleBit::from(raw & 0b10 != 0, raw & 0b01 != 0);
+            let x = DoubleBitBinaryInput::new(value, dec.flags()?, Time::unsynchronized(0));
+            JournaledValue::DoubleBitBinary(
+                DoubleBitBinaryInput {
+                    time: dec.time()?,
+                    ..x
+                },
+                v,
+            )
+        }
+        type_tag::BINARY_OUTPUT_STATUS => {
+            let v = dec.variation(BINARY_OUTPUT_STATUS_VARIATIONS)?;
+            let x = BinaryOutputStatus::new(dec.bool()?, dec.flags()?, Time::unsynchronized(0));
+            JournaledValue::BinaryOutputStatus(
+                BinaryOutputStatus {
+                    time: dec.time()?,
+                    ..x
+                },
+                v,
+            )
+        }
+        type_tag::COUNTER => {
+            let v = dec.variation(COUNTER_VARIATIONS)?;
+            let x = Counter::new(
+                dec.cursor.read_u32_le()?,
+                dec.flags()?,
+                Time::unsynchronized(0),
+            );
+            JournaledValue::Counter(
+                Counter {
+                    time: dec.time()?,
+                    ..x
+                },
+                v,
+            )
+        }
+        type_tag::FROZEN_COUNTER => {
+            let v = dec.variation(FROZEN_COUNTER_VARIATIONS)?;
+            let x = FrozenCounter::new(
+                dec.cursor.read_u32_le()?,
+                dec.flags()?,
+                Time::unsynchronized(0),
+            );
+            JournaledValue::FrozenCounter(
+                FrozenCounter {
+                    time: dec.time()?,
+                    ..x
+                },
+                v,
+            )
+        }
+        type_tag::ANALOG => {
+            let v = dec.variation(ANALOG_VARIATIONS)?;
+            let x = AnalogInput::new(
+                dec.cursor.read_f64_le()?,
+                dec.flags()?,
+                Time::unsynchronized(0),
+            );
+            JournaledValue::Analog(
+                AnalogInput {
+                    time: dec.time()?,
+                    ..x
+                },
+                v,
+            )
+        }
+        type_tag::ANALOG_OUTPUT_STATUS => {
+            let v = dec.variation(ANALOG_OUTPUT_STATUS_VARIATIONS)?;
+            let x = AnalogOutputStatus::new(
+                dec.cursor.read_f64_le()?,
+                dec.flags()?,
+                Time::unsynchronized(0),
+            );
+            JournaledValue::AnalogOutputStatus(
+                AnalogOutputStatus {
+                    time: dec.time()?,
+                    ..x
+                },
+                v,
+            )
+        }
+        type_tag::OCTET_STRING => JournaledValue::OctetString(dec.remaining()),
+        type_tag::FILE_TRANSFER => JournaledValue::FileTransfer(dec.remaining()),
+        type_tag::BINARY_OUTPUT_COMMAND => {
+            let v = dec.variation(BINARY_OUTPUT_COMMAND_VARIATIONS)?;
+            let x = BinaryOutputCommandEvent {
+                commanded_state: dec.bool()?,
+                status: CommandStatus::from(dec.cursor.read_u8()?),
+                time: dec.time()?,
+            };
+            JournaledValue::BinaryOutputCommand(x, v)
+        }
+        type_tag::ANALOG_OUTPUT_COMMAND => {
+            let v = dec.variation(ANALOG_OUTPUT_COMMAND_VARIATIONS)?;
+            let status = CommandStatus::from(dec.cursor.read_u8()?);
+            let commanded_value = match dec.cursor.read_u8()? {
+                0 => AnalogCommandValue::I16(dec.cursor.read_i16_le()?),
+                1 => AnalogCommandValue::I32(dec.cursor.read_i32_le()?),
+                2 => AnalogCommandValue::F32(dec.cursor.read_f32_le()?),
+                3 => AnalogCommandValue::F64(dec.cursor.read_f64_le()?),
+                _ => return Err(DecodeError::BadValue("analog command value")),
+            };
+            let x = AnalogOutputCommandEvent {
+                status,
+                commanded_value,
+                time: dec.time()?,
+            };
+            JournaledValue::AnalogOutputCommand(x, v)
+        }
+        type_tag::DATA_SET => JournaledValue::DataSet(dec.remaining()),
+        type_tag::VIRTUAL_TERMINAL => JournaledValue::VirtualTerminal(dec.remaining()),
+        type_tag::SECURITY_STATISTIC => {
+            let v = dec.variation(SECURITY_STATISTIC_VARIATIONS)?;
+            let x = SecurityStatistic {
+                association_id: dec.cursor.read_u16_le()?,
+                value: dec.cursor.read_u32_le()?,
+                flags: dec.flags()?,
+                time: dec.time()?,
+            };
+            JournaledValue::SecurityStatistic(x, v)
+        }
+        _ => return Err(DecodeError::BadValue("event type")),
+    };
+
+    Ok(JournaledEvent {
+        id,
+        index,
+        class,
+        value,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TempFile(PathBuf);
+
+    impl TempFile {
+        fn new(name: &str) -> Self {
+            let path =
+                std::env::temp_dir().join(format!("dnp3-journal-{}-{}", std::process::id(), name));
+            let _ = std::fs::remove_file(&path);
+            Self(path)
+        }
+    }
+
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.0);
+        }
+    }
+
+    fn event(id: u64, value: JournaledValue) -> JournaledEvent {
+        JournaledEvent {
+            id,
+            index: 7,
+            class: EventClass::Class2,
+            value,
+        }
+    }
+
+    fn all_types() -> Vec<JournaledEvent> {
+        let time = Some(Time::synchronized(1_234_567));
+        vec![
+            event(
+                0,
+                JournaledValue::Binary(
+                    BinaryInput::new(true, Flags::ONLINE, Time::unsynchronized(42)),
+                    EventBinaryInputVariation::Group2Var3,
+                ),
+            ),
+            event(
+                1,
+                JournaledValue::DoubleBitBinary(
+                    DoubleBitBinaryInput {
+                        value: DoubleBit::DeterminedOn,
+                        flags: Flags::ONLINE,
+                        time: None,
+                    },
+                    EventDoubleBitBinaryInputVariation::Group4Var2,
+                ),
+            ),
+            event(
+                2,
+                JournaledValue::BinaryOutputStatus(
+                    BinaryOutputStatus {
+                        value: false,
+                        flags: Flags::RESTART,
+                        time,
+                    },
+                    EventBinaryOutputStatusVariation::Group11Var2,
+                ),
+            ),
+            event(
+                3,
+                JournaledValue::Counter(
+                    Counter {
+                        value: 0xCAFE_BABE,
+                        flags: Flags::ONLINE,
+                        time,
+                    },
+                    EventCounterVariation::Group22Var6,
+                ),
+            ),
+            event(
+                4,
+                JournaledValue::FrozenCounter(
+                    FrozenCounter {
+                        value: 17,
+                        flags: Flags::ONLINE,
+                        time,
+                    },
+                    EventFrozenCounterVariation::Group23Var5,
+                ),
+            ),
+            event(
+                5,
+                JournaledValue::Analog(
+                    AnalogInput {
+                        value: -3.5,
+                        flags: Flags::ONLINE,
+                        time,
+                    },
+                    EventAnalogInputVariation::Group32Var8,
+                ),
+            ),
+            event(
+                6,
+                JournaledValue::AnalogOutputStatus(
+                    AnalogOutputStatus {
+                        value: 98.6,
+                        flags: Flags::ONLINE,
+                        time,
+                    },
+                    EventAnalogOutputStatusVariation::Group42Var7,
+                ),
+            ),
+            event(7, JournaledValue::OctetString(b"hello".to_vec())),
+            event(8, JournaledValue::FileTransfer(vec![70, 4, 0x5B, 0x01])),
+            event(
+                9,
+                JournaledValue::BinaryOutputCommand(
+                    BinaryOutputCommandEvent {
+                        commanded_state: true,
+                        status: CommandStatus::NoSelect,
+                        time,
+                    },
+                    EventBinaryOutputCommandVariation::Group13Var2,
+                ),
+            ),
+            event(
+                10,
+                JournaledValue::AnalogOutputCommand(
+                    AnalogOutputCommandEvent {
+                        status: CommandStatus::Success,
+                        commanded_value: AnalogCommandValue::F32(1.5),
+                        time: None,
+                    },
+                    EventAnalogOutputCommandVariation::Group43Var3,
+                ),
+            ),
+            event(11, JournaledValue::DataSet(vec![88, 1, 0x5B])),
+            event(12, JournaledValue::VirtualTerminal(b"login:".to_vec())),
+            event(
+                13,
+                JournaledValue::SecurityStatistic(
+                    SecurityStatistic {
+                        association_id: 3,
+                        value: 12,
+                        flags: Flags::ONLINE,
+                        time,
+                    },
+                    EventSecurityStatisticVariation::Group122Var2,
+                ),
+            ),
+        ]
+    }
+
+    #[test]
+    fn encoding_round_trips_every_event_type() {
+        for event in all_types() {
+            assert_eq!(decode_event(&encode_event(&event)).unwrap(), event);
+        }
+    }
+
+    #[test]
+    fn loads_events_that_were_not_removed() {
+        let file = TempFile::new("not-removed");
+        let events = all_types();
+
+        let mut journal = FileEventJournal::open(&file.0).unwrap();
+        assert!(journal.load().unwrap().is_empty());
+        for event in events.iter() {
+            journal.record_insert(event).unwrap();
+        }
+        journal.record_remove(0).unwrap();
+        journal.record_remove(5).unwrap();
+        drop(journal);
+
+        let expected: Vec<JournaledEvent> = events
+            .into_iter()
+            .filter(|x| x.id != 0 && x.id != 5)
+            .collect();
+        let mut journal = FileEventJournal::open(&file.0).unwrap();
+        assert_eq!(journal.load().unwrap(), expected);
+
+        // the compacted journal loads the same events
+        let mut journal = FileEventJournal::open(&file.0).unwrap();
+        assert_eq!(journal.load().unwrap(), expected);
+    }
+
+    #[test]
+    fn truncates_file_when_every_event_is_removed() {
+        let file = TempFile::new("truncate");
+        let events = all_types();
+
+        let mut journal = FileEventJournal::open(&file.0).unwrap();
+        journal.load().unwrap();
+        journal.record_insert(&events[0]).unwrap();
+        journal.record_insert(&events[1]).unwrap();
+        journal.record_remove(0).unwrap();
+        journal.record_remove(1).unwrap();
+
+        assert_eq!(std::fs::metadata(&file.0).unwrap().len(), 0);
+        journal.record_insert(&events[2]).unwrap();
+        drop(journal);
+
+        let mut journal = FileEventJournal::open(&file.0).unwrap();
+        assert_eq!(journal.load().unwrap(), vec![events[2].clone()]);
+    }
+
+    #[test]
+    fn compacts_file_when_events_are_never_fully_removed() {
+        let file = TempFile::new("compact");
+        let events = all_types();
+
+        let mut journal = FileEventJournal::open(&file.0)
+            .unwrap()
+            .with_sync_data(true);
+        journal.load().unwrap();
+        // this event is never removed, so the file is never truncated
+        journal.record_insert(&events[0]).unwrap();
+        for id in 1..1000 {
+            let mut event = events[1].clone();
+            event.id = id;
+            journal.record_insert(&event).unwrap();
+            journal.record_remove(id).unwrap();
+            assert!(journal.records <= MIN_COMPACTION_RECORDS);
+        }
+        drop(journal);
+
+        let mut journal = FileEventJournal::open(&file.0).unwrap();
+        assert_eq!(journal.load().unwrap(), vec![events[0].clone()]);
+    }
+
+    #[test]
+    fn discards_partially_written_record() {
+        let file = TempFile::new("partial");
+        let events = all_types();
+
+        let mut journal = FileEventJournal::open(&file.0).unwrap();
+        journal.load().unwrap();
+        journal.record_insert(&events[0]).unwrap();
+        journal.record_insert(&events[1]).unwrap();
+        drop(journal);
+
+        let length = std::fs::metadata(&file.0).unwrap().len();
+        OpenOptions::new()
+            .write(true)
+            .open(&file.0)
+            .unwrap()
+            .set_len(length - 3)
+            .unwrap();
+
+        let mut journal = FileEventJournal::open(&file.0).unwrap();
+        assert_eq!(journal.load().unwrap(), vec![events[0].clone()]);
+    }
+}
@@ -1,7 +1,13 @@
 use std::sync::{Arc, Mutex};
 
 pub use config::*;
-use details::range::static_db::{Deadband, FlagsDetector, OctetStringDetector, PointConfig};
+use details::range::static_db::{
+    AnalogDeadband, Deadband, FlagsDetector, OctetStringDetector, PointConfig,
+    UnsignedIntegerDetector,
+};
+pub use journal::*;
+pub use snapshot::*;
+pub use subscription::*;
 
 use crate::app::measurement::*;
 use crate::app::parse::parser::HeaderCollection;
@@ -10,14 +16,25 @@ use crate::master::EventClasses;
 use crate::outstation::database::read::ReadHeader;
 
 use crate::app::attr::{AttrProp, AttrSet, OwnedAttribute, TypeError};
-use crate::outstation::OutstationApplication;
+use crate::app::data_set::{DataSet, DataSetDescriptor, DataSetPrototype};
+use crate::link::EndpointAddress;
+use crate::outstation::{
+    ClassAssignment, ClassCount, EventStatistics, FreezeIndices, FreezeType,
+    OfflineAssociationPolicy, OutstationApplication,
+};
 use scursor::WriteCursor;
 
 mod config;
 /// private internal control only needed by the parent module
 mod details;
+/// persistent storage for the event buffer
+mod journal;
 /// read headers
 pub(crate) mod read;
+/// snapshot and restore of the static points
+mod snapshot;
+/// change notifications for local observers
+mod subscription;
 
 /// Controls how events are processed when updating values in the database
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -69,10 +86,18 @@ pub struct ClassZeroConfig {
     /// If true, Octet Strings are reported in Class 0 READ requests
     /// This field defaults to `false` for conformance to the standard
     pub octet_string: bool,
+    /// If true, Unsigned Integers (g102) are reported in Class 0 READ requests
+    /// This field defaults to `false` to preserve the content of existing integrity polls
+    pub unsigned_integer: bool,
+    /// If true, Security Statistics (g121) are reported in Class 0 READ requests
+    pub security_statistic: bool,
 }
 
 impl ClassZeroConfig {
-    /// construct a `ClassZeroConfig` from its fields
+    /// construct a `ClassZeroConfig` from the fields defined by the standard
+    ///
+    /// `unsigned_integer` and `security_statistic` take their default values and may be changed
+    /// using [`Self::with_unsigned_integer`] and [`Self::with_security_statistic`]
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         binary: bool,
@@ -84,6 +109,7 @@ impl ClassZeroConfig {
         analog_output_status: bool,
         octet_string: bool,
     ) -> Self {
+        let defaults = Self::default();
         ClassZeroConfig {
             binary,
             double_bit_binary,
@@ -93,6 +119,24 @@ impl ClassZeroConfig {
             analog,
             analog_output_status,
             octet_string,
+            unsigned_integer: defaults.unsigned_integer,
+            security_statistic: defaults.security_statistic,
+        }
+    }
+
+    /// select whether Unsigned Integers (g102) are reported in Class 0 READ requests
+    pub fn with_unsigned_integer(self, unsigned_integer: bool) -> Self {
+        Self {
+            unsigned_integer,
+            ..self
+        }
+    }
+
+    /// select whether Security Statistics (g121) are reported in Class 0 READ requests
+    pub fn with_security_statistic(self, security_statistic: bool) -> Self {
+        Self {
+            security_statistic,
+            ..self
         }
     }
 }
@@ -108,13 +152,38 @@ impl Default for ClassZeroConfig {
             analog: true,
             analog_output_status: true,
             octet_string: false,
+            unsigned_integer: false,
+            security_statistic: true,
         }
     }
 }
 
-/// Maximum number of events for each type.
+/// Event to discard when inserting an event would exceed a limit of the [`EventBufferConfig`]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub enum EventOverflowPolicy {
+    /// Discard the oldest buffered event
+    #[default]
+    DiscardOldest,
+    /// Discard the new event
+    DiscardNewest,
+    /// Discard the oldest buffered event of the lowest priority class, where class 3 has the
+    /// lowest priority and class 1 the highest. The new event is discarded if its class has a
+    /// lower priority than every buffered event that could be discarded.
+    DiscardLowestClass,
+}
+
+/// Maximum number of events for each type and class, and how overflows are handled
 ///
-/// A value of zero means that events will not be buffered for that type.
+/// A value of zero means that events will not be buffered for that type or class.
+///
+/// When inserting an event would exceed the maximum for its type or class, one event is
+/// discarded according to the [`EventOverflowPolicy`]. Only a buffered event of the same
+/// type (and of the same class if that limit is exceeded) may be discarded to make room. If
+/// there is no such event, the new event is discarded.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 #[cfg_attr(
     feature = "serialization",
@@ -145,12 +214,53 @@ pub struct EventBufferConfig {
     /// maximum number of octet string events (g111)
     #[cfg_attr(feature = "serialization", serde(default))]
     pub max_octet_string: u16,
+    /// maximum number of file transfer events (g70v4, g70v5, g70v6)
+    ///
+    /// These events are only produced when the file system operates in
+    /// [`FileTransportMode::Event`](crate::outstation::FileTransportMode::Event)
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub max_file_transfer: u16,
+    /// maximum number of binary output command events (g13)
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub max_binary_output_command: u16,
+    /// maximum number of analog output command events (g43)
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub max_analog_output_command: u16,
+    /// maximum number of data set snapshot events (g88)
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub max_data_set: u16,
+    /// maximum number of virtual terminal events (g113)
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub max_virtual_terminal: u16,
+    /// maximum number of security statistic events (g122)
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub max_security_statistic: u16,
+    /// maximum number of class 1 events of all types, or `None` if only the type limits apply
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub max_class_1: Option<u16>,
+    /// maximum number of class 2 events of all types, or `None` if only the type limits apply
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub max_class_2: Option<u16>,
+    /// maximum number of class 3 events of all types, or `None` if only the type limits apply
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub max_class_3: Option<u16>,
+    /// event discarded when a type or class limit is exceeded
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub overflow_policy: EventOverflowPolicy,
 }
 
 impl EventBufferConfig {
     /// initialize with the same maximum values for all types
     pub fn all_types(max: u16) -> Self {
-        Self::new(max, max, max, max, max, max, max, max)
+        Self {
+            max_file_transfer: max,
+            max_binary_output_command: max,
+            max_analog_output_command: max,
+            max_data_set: max,
+            max_virtual_terminal: max,
+            max_security_statistic: max,
+            ..Self::new(max, max, max, max, max, max, max, max)
+        }
     }
 
     /// initialize the configuration to support no events
@@ -158,7 +268,11 @@ impl EventBufferConfig {
         Self::all_types(0)
     }
 
-    /// create a configuration specifying the max for each type individually
+    /// create a configuration specifying the max for each measurement type individually
+    ///
+    /// The maximum number of file transfer, command, data set, virtual terminal, and security
+    /// statistic events is initialized to zero. No class limits are applied and the oldest event
+    /// is discarded on overflow.
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         max_binary: u16,
@@ -179,6 +293,47 @@ impl EventBufferConfig {
             max_analog,
             max_analog_output_status,
             max_octet_string,
+            max_file_transfer: 0,
+            max_binary_output_command: 0,
+            max_analog_output_command: 0,
+            max_data_set: 0,
+            max_virtual_terminal: 0,
+            max_security_statistic: 0,
+            max_class_1: None,
+            max_class_2: None,
+            max_class_3: None,
+            overflow_policy: EventOverflowPolicy::DiscardOldest,
+        }
+    }
+
+    /// limit the number of events in each class, in addition to the limits for each type
+    pub fn with_class_limits(
+        self,
+        max_class_1: Option<u16>,
+        max_class_2: Option<u16>,
+        max_class_3: Option<u16>,
+    ) -> Self {
+        Self {
+            max_class_1,
+            max_class_2,
+            max_class_3,
+            ..self
+        }
+    }
+
+    /// select the event discarded when a type or class limit is exceeded
+    pub fn with_overflow_policy(self, overflow_policy: EventOverflowPolicy) -> Self {
+        Self {
+            overflow_policy,
+            ..self
+        }
+    }
+
+    fn max_class(&self, class: EventClass) -> Option<u16> {
+        match class {
+            EventClass::Class1 => self.max_class_1,
+            EventClass::Class2 => self.max_class_2,
+            EventClass::Class3 => self.max_class_3,
         }
     }
 
@@ -191,6 +346,12 @@ impl EventBufferConfig {
             + self.max_analog as usize
             + self.max_analog_output_status as usize
             + self.max_octet_string as usize
+            + self.max_file_transfer as usize
+            + self.max_binary_output_command as usize
+            + self.max_analog_output_command as usize
+            + self.max_data_set as usize
+            + self.max_virtual_terminal as usize
+            + self.max_security_statistic as usize
     }
 }
 
@@ -208,6 +369,18 @@ pub(crate) struct EventsInfo {
     pub(crate) is_overflown: bool,
 }
 
+/// Master association on whose behalf events are selected, written, and cleared
+///
+/// Associations are registered with the event buffer of the database, one per master address.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Association(usize);
+
+impl Association {
+    pub(crate) const fn index(self) -> usize {
+        self.0
+    }
+}
+
 /// Options that control how the update is performed. 99% of the time
 /// the default() method should be used to initialize this struct. Very
 /// few applications need to use the other options.
@@ -289,7 +462,9 @@ pub enum UpdateInfo {
     Overflow {
         /// Id of the event that was created
         created: u64,
-        /// Id of the previously inserted event that was discarded
+        /// Id of the event that was discarded according to the [`EventOverflowPolicy`]
+        ///
+        /// This is equal to `created` if the new event was discarded instead of being buffered
         discarded: u64,
     },
 }
@@ -406,6 +581,22 @@ impl Database {
         }
     }
 
+    /// Attach a journal that persists the contents of the event buffer
+    ///
+    /// Events that were recorded in the journal but never removed are restored into the buffer
+    /// with their original identifiers, and every subsequent insertion and removal is recorded.
+    /// Restored events in excess of the configured [`EventBufferConfig`] limits are discarded
+    /// in the same way as any other overflow.
+    ///
+    /// The journal must be attached before any events are created, typically in the same
+    /// transaction that adds the points. Returns the number of restored events.
+    pub fn set_event_journal(
+        &mut self,
+        journal: Box<dyn EventJournal>,
+    ) -> Result<usize, EventJournalError> {
+        self.inner.set_event_journal(journal)
+    }
+
     /// Define an attribute that will be exposed to the master
     pub fn define_attr(
         &mut self,
@@ -414,13 +605,66 @@ impl Database {
     ) -> Result<(), AttrDefError> {
         self.inner.get_attr_map().define(prop, attr)
     }
+
+    /// Define a data set prototype (g85) that may be referenced by data set descriptors
+    ///
+    /// Prototypes must be defined before any descriptors that reference them are added.
+    ///
+    /// Returns false if a prototype with the same id or UUID already exists, or if an
+    /// element is too long to be encoded.
+    pub fn define_data_set_prototype(&mut self, id: u16, prototype: DataSetPrototype) -> bool {
+        self.inner.define_data_set_prototype(id, prototype)
+    }
+
+    /// Report data produced by a virtual terminal port as events (g113) in the class assigned
+    /// to the port
+    ///
+    /// Data longer than 255 bytes is split into multiple objects. Returns false if the port
+    /// has not been added using [`VirtualTerminalConfig`].
+    pub fn push_virtual_terminal_data(&mut self, port: u16, data: &[u8]) -> bool {
+        self.inner.push_virtual_terminal_data(port, data)
+    }
+
+    /// Link a counter to a frozen counter so that the built-in freeze engine copies the value of
+    /// the counter into the frozen counter whenever the counter is frozen.
+    ///
+    /// Both points must already exist. Linking a counter a second time replaces the previous link.
+    ///
+    /// Returns false if either point does not exist.
+    pub fn link_frozen_counter(&mut self, counter: u16, frozen_counter: u16) -> bool {
+        self.inner.link_frozen_counter(counter, frozen_counter)
+    }
+
+    /// Remove the link between a counter and its frozen counter
+    ///
+    /// Returns true if the counter was linked, false otherwise
+    pub fn unlink_frozen_counter(&mut self, counter: u16) -> bool {
+        self.inner.unlink_frozen_counter(counter)
+    }
+
+    /// Freeze the linked counters selected by `indices` using the built-in freeze engine
+    ///
+    /// Every freeze updates the linked frozen counter with the value and flags of the counter
+    /// and the current system time, and always produces a frozen counter event if the point is
+    /// assigned to a class. [`FreezeType::FreezeAndClear`] also resets the counter to zero.
+    ///
+    /// [`FreezeType::FreezeAtTime`] schedules the freeze according to the [`FreezeInterval`](crate::outstation::FreezeInterval),
+    /// replacing any existing schedule for the same indices. Scheduled freezes are performed by
+    /// the outstation task.
+    ///
+    /// This is called automatically when [`Features::freeze_engine`](crate::outstation::Features::freeze_engine)
+    /// is enabled, but may also be called from [`OutstationApplication::freeze_counter`].
+    pub fn freeze_counters(&mut self, indices: FreezeIndices, freeze_type: FreezeType) {
+        self.inner.freeze_counters(indices, freeze_type)
+    }
 }
 
 /// Handle type that can be used to perform transactions on an underlying database
 #[derive(Clone)]
 pub struct DatabaseHandle {
     inner: Arc<Mutex<Database>>,
-    notify: Arc<tokio::sync::Notify>,
+    /// wakes the session of each association, indexed by [`Association`]
+    wakers: Arc<Mutex<Vec<Arc<tokio::sync::Notify>>>>,
 }
 
 impl DatabaseHandle {
@@ -431,14 +675,36 @@ impl DatabaseHandle {
     {
         let ret = {
             let mut db = self.inner.lock().unwrap();
-            func(&mut db)
+            let ret = func(&mut db);
+            db.inner.publish_changes();
+            ret
         };
-        self.notify.notify_one();
+        self.notify();
         ret
     }
 
-    pub(crate) async fn wait_for_change(&self) {
-        self.notify.notified().await
+    /// Subscribe to the changes applied to point values
+    ///
+    /// Every transaction that modifies the static value of a point or produces an event
+    /// delivers a [`ChangeBatch`] to the subscription. Changes made by the outstation itself,
+    /// e.g. scheduled freezes and security statistics, are delivered in the same way. Adding,
+    /// removing and restoring points is not reported.
+    ///
+    /// At most `capacity` batches are buffered. Batches that do not fit are dropped and counted
+    /// in [`ChangeBatch::missed`] so that the outstation never waits on a slow subscriber.
+    pub fn subscribe(&self, capacity: usize) -> DatabaseSubscription {
+        self.inner.lock().unwrap().inner.subscribe(capacity)
+    }
+
+    pub(crate) async fn wait_for_change(&self, association: Association) {
+        let waker = self.wakers.lock().unwrap()[association.index()].clone();
+        waker.notified().await
+    }
+
+    fn notify(&self) {
+        for waker in self.wakers.lock().unwrap().iter() {
+            waker.notify_one();
+        }
     }
 
     pub(crate) fn new(
@@ -452,26 +718,129 @@ impl DatabaseHandle {
                 class_zero_config,
                 event_config,
             ))),
-            notify: Arc::new(tokio::sync::Notify::new()),
+            wakers: Arc::new(Mutex::new(Vec::new())),
         }
     }
 
-    pub(crate) async fn clear_written_events(&mut self, app: &mut dyn OutstationApplication) {
+    /// register the association of a master, returning `None` if the address is already registered
+    pub(crate) fn add_association(
+        &self,
+        master: EndpointAddress,
+        policy: OfflineAssociationPolicy,
+    ) -> Option<Association> {
+        let mut guard = self.inner.lock().unwrap();
+        let association =
+            guard
+                .inner
+                .add_association(master, policy, tokio::time::Instant::now())?;
+        // pushed while holding the database lock so that the index matches the association
+        self.wakers
+            .lock()
+            .unwrap()
+            .push(Arc::new(tokio::sync::Notify::new()));
+        Some(association)
+    }
+
+    /// true if the address is registered by an association other than the specified one
+    pub(crate) fn is_master_registered(
+        &self,
+        association: Association,
+        master: EndpointAddress,
+    ) -> bool {
+        self.inner
+            .lock()
+            .unwrap()
+            .inner
+            .is_master_registered(association, master)
+    }
+
+    pub(crate) fn set_association_master(&self, association: Association, master: EndpointAddress) {
+        self.inner
+            .lock()
+            .unwrap()
+            .inner
+            .set_association_master(association, master)
+    }
+
+    pub(crate) fn set_association_policy(
+        &self,
+        association: Association,
+        policy: OfflineAssociationPolicy,
+    ) {
+        self.inner
+            .lock()
+            .unwrap()
+            .inner
+            .set_association_policy(association, policy)
+    }
+
+    pub(crate) fn set_association_online(&self, association: Association, online: bool) {
+        self.inner.lock().unwrap().inner.set_association_online(
+            association,
+            online,
+            tokio::time::Instant::now(),
+        )
+    }
+
+    pub(crate) async fn clear_written_events(
+        &mut self,
+        association: Association,
+        app: &mut dyn OutstationApplication,
+    ) {
         app.begin_confirm();
-        let state = self.inner.lock().unwrap().inner.clear_written_events(app);
+        let state = self.inner.lock().unwrap().inner.clear_written_events(
+            association,
+            tokio::time::Instant::now(),
+            app,
+        );
         app.end_confirm(state).get().await;
     }
 
-    pub(crate) fn get_events_info(&self) -> EventsInfo {
+    pub(crate) fn get_events_info(&self, association: Association) -> EventsInfo {
         let guard = self.inner.lock().unwrap();
 
         EventsInfo {
-            unwritten_classes: guard.inner.unwritten_classes(),
-            is_overflown: guard.inner.is_overflown(),
+            unwritten_classes: guard.inner.unwritten_classes(association),
+            is_overflown: guard.inner.is_overflown(association),
         }
     }
 
-    pub(crate) fn select(&mut self, headers: &HeaderCollection) -> Iin2 {
+    pub(crate) fn reconfigure(
+        &self,
+        class_zero: ClassZeroConfig,
+        event_config: EventBufferConfig,
+    ) -> usize {
+        let discarded = self
+            .inner
+            .lock()
+            .unwrap()
+            .inner
+            .reconfigure(class_zero, event_config);
+        self.notify();
+        discarded
+    }
+
+    pub(crate) fn unwritten_class_count(&self, association: Association) -> ClassCount {
+        self.inner
+            .lock()
+            .unwrap()
+            .inner
+            .unwritten_class_count(association)
+    }
+
+    pub(crate) fn has_class_assignment_target(&self, assignment: ClassAssignment) -> bool {
+        self.inner
+            .lock()
+            .unwrap()
+            .inner
+            .has_class_assignment_target(assignment)
+    }
+
+    pub(crate) fn event_statistics(&self) -> EventStatistics {
+        self.inner.lock().unwrap().inner.event_statistics()
+    }
+
+    pub(crate) fn select(&mut self, association: Association, headers: &HeaderCollection) -> Iin2 {
         let mut iin2 = Iin2::default();
         let mut guard = self.inner.lock().unwrap();
         for header in headers.iter() {
@@ -479,36 +848,73 @@ impl DatabaseHandle {
                 None => {
                     iin2 |= Iin2::NO_FUNC_CODE_SUPPORT;
                 }
-                Some(x) => iin2 |= guard.inner.select_by_header(x),
+                Some(x) => iin2 |= guard.inner.select_by_header(association, x),
             }
         }
         iin2
     }
 
-    pub(crate) fn write_response_headers(&mut self, cursor: &mut WriteCursor) -> ResponseInfo {
+    pub(crate) fn write_response_headers(
+        &mut self,
+        association: Association,
+        cursor: &mut WriteCursor,
+    ) -> ResponseInfo {
         self.inner
             .lock()
             .unwrap()
             .inner
-            .write_response_headers(cursor)
+            .write_response_headers(association, cursor)
     }
 
     pub(crate) fn write_unsolicited(
         &mut self,
+        association: Association,
         classes: EventClasses,
         cursor: &mut WriteCursor,
     ) -> usize {
         let mut guard = self.inner.lock().unwrap();
-        guard.inner.reset();
-        let count = guard.inner.select_event_classes(classes);
+        guard.inner.reset(association);
+        let count = guard.inner.select_event_classes(association, classes);
         if count == 0 {
             return 0;
         }
-        guard.inner.write_events_only(cursor)
+        guard.inner.write_events_only(association, cursor)
+    }
+
+    pub(crate) fn reset(&mut self, association: Association) {
+        self.inner.lock().unwrap().inner.reset(association)
+    }
+
+    pub(crate) fn next_freeze_deadline(&self) -> Option<tokio::time::Instant> {
+        self.inner.lock().unwrap().inner.next_freeze_deadline()
     }
 
-    pub(crate) fn reset(&mut self) {
-        self.inner.lock().unwrap().inner.reset()
+    pub(crate) fn run_scheduled_freezes(&mut self) {
+        let mut guard = self.inner.lock().unwrap();
+        guard
+            .inner
+            .run_scheduled_freezes(tokio::time::Instant::now());
+        guard.inner.publish_changes();
+    }
+
+    pub(crate) fn increment_security_statistic(
+        &self,
+        statistic: SecurityStatisticIndex,
+        association_id: u16,
+    ) {
+        let updated = {
+            let mut guard = self.inner.lock().unwrap();
+            let updated = guard
+                .inner
+                .increment_security_statistic(statistic.index(), association_id);
+            guard.inner.publish_changes();
+            updated
+        };
+
+        // only wake the session if the statistic is actually maintained
+        if updated {
+            self.notify();
+        }
     }
 }
 
@@ -589,6 +995,45 @@ impl Update<OctetString> for Database {
     }
 }
 
+impl Update<UnsignedInteger> for Database {
+    fn update2(
+        &mut self,
+        index: u16,
+        value: &UnsignedInteger,
+        options: UpdateOptions,
+    ) -> UpdateInfo {
+        self.inner.update(value, index, options)
+    }
+}
+
+impl Update<BinaryOutputCommandEvent> for Database {
+    fn update2(
+        &mut self,
+        index: u16,
+        value: &BinaryOutputCommandEvent,
+        options: UpdateOptions,
+    ) -> UpdateInfo {
+        self.inner.update_command_event(value, index, options)
+    }
+}
+
+impl Update<AnalogOutputCommandEvent> for Database {
+    fn update2(
+        &mut self,
+        index: u16,
+        value: &AnalogOutputCommandEvent,
+        options: UpdateOptions,
+    ) -> UpdateInfo {
+        self.inner.update_command_event(value, index, options)
+    }
+}
+
+impl Update<DataSet> for Database {
+    fn update2(&mut self, index: u16, value: &DataSet, options: UpdateOptions) -> UpdateInfo {
+        self.inner.update_data_set(index, value, options)
+    }
+}
+
 impl Add<BinaryInputConfig> for Database {
     fn add(&mut self, index: u16, class: Option<EventClass>, config: BinaryInputConfig) -> bool {
         let config =
@@ -659,7 +1104,7 @@ impl Add<AnalogInputConfig> for Database {
     fn add(&mut self, index: u16, class: Option<EventClass>, config: AnalogInputConfig) -> bool {
         let config = PointConfig::<AnalogInput>::new(
             class,
-            Deadband::new(config.deadband),
+            AnalogDeadband::new(config.deadband, config.deadband_mode),
             config.s_var,
             config.e_var,
         );
@@ -676,7 +1121,7 @@ impl Add<AnalogOutputStatusConfig> for Database {
     ) -> bool {
         let config = PointConfig::<AnalogOutputStatus>::new(
             class,
-            Deadband::new(config.deadband),
+            AnalogDeadband::new(config.deadband, config.deadband_mode),
             config.s_var,
             config.e_var,
         );
@@ -696,6 +1141,89 @@ impl Add<OctetStringConfig> for Database {
     }
 }
 
+impl Add<UnsignedIntegerConfig> for Database {
+    fn add(
+        &mut self,
+        index: u16,
+        _class: Option<EventClass>,
+        _config: UnsignedIntegerConfig,
+    ) -> bool {
+        // there is no event group for g102, so the point is never assigned to a class
+        let config = PointConfig::<UnsignedInteger>::new(
+            None,
+            UnsignedIntegerDetector,
+            StaticUnsignedIntegerVariation,
+            EventUnsignedIntegerVariation,
+        );
+        self.inner.add(index, config)
+    }
+}
+
+/// Security statistics are maintained by the outstation session, so the index should be one of the
+/// values returned by [`SecurityStatisticIndex::index`]
+impl Add<SecurityStatisticConfig> for Database {
+    fn add(
+        &mut self,
+        index: u16,
+        class: Option<EventClass>,
+        config: SecurityStatisticConfig,
+    ) -> bool {
+        let config = PointConfig::<SecurityStatistic>::new(
+            class,
+            Deadband::new(config.deadband),
+            StaticSecurityStatisticVariation,
+            config.e_var,
+        );
+        self.inner.add(index, config)
+    }
+}
+
+impl Add<BinaryOutputCommandEventConfig> for Database {
+    fn add(
+        &mut self,
+        index: u16,
+        class: Option<EventClass>,
+        config: BinaryOutputCommandEventConfig,
+    ) -> bool {
+        self.inner
+            .add_command_event::<BinaryOutputCommandEvent>(index, class, config.e_var)
+    }
+}
+
+impl Add<AnalogOutputCommandEventConfig> for Database {
+    fn add(
+        &mut self,
+        index: u16,
+        class: Option<EventClass>,
+        config: AnalogOutputCommandEventConfig,
+    ) -> bool {
+        self.inner
+            .add_command_event::<AnalogOutputCommandEvent>(index, class, config.e_var)
+    }
+}
+
+/// Adding a data set descriptor (g86) defines a data set with that id. The class determines how
+/// snapshot events (g88) are reported. All prototypes referenced by the descriptor must already be
+/// defined using [`Database::define_data_set_prototype`].
+impl Add<DataSetDescriptor> for Database {
+    fn add(&mut self, index: u16, class: Option<EventClass>, config: DataSetDescriptor) -> bool {
+        self.inner.add_data_set(index, class, config)
+    }
+}
+
+/// Adding a virtual terminal port allows the master to write output blocks (g112) to it. The class
+/// determines how data pushed using [`Database::push_virtual_terminal_data`] is reported.
+impl Add<VirtualTerminalConfig> for Database {
+    fn add(
+        &mut self,
+        index: u16,
+        class: Option<EventClass>,
+        _config: VirtualTerminalConfig,
+    ) -> bool {
+        self.inner.add_virtual_terminal(index, class)
+    }
+}
+
 impl Remove<BinaryInput> for Database {
     fn remove(&mut self, index: u16) -> bool {
         self.inner.remove::<BinaryInput>(index)
@@ -744,6 +1272,50 @@ impl Remove<OctetString> for Database {
     }
 }
 
+impl Remove<UnsignedInteger> for Database {
+    fn remove(&mut self, index: u16) -> bool {
+        self.inner.remove::<UnsignedInteger>(index)
+    }
+}
+
+impl Remove<SecurityStatistic> for Database {
+    fn remove(&mut self, index: u16) -> bool {
+        self.inner.remove::<SecurityStatistic>(index)
+    }
+}
+
+impl Remove<BinaryOutputCommandEvent> for Database {
+    fn remove(&mut self, index: u16) -> bool {
+        self.inner
+            .remove_command_event::<BinaryOutputCommandEvent>(index)
+    }
+}
+
+impl Remove<AnalogOutputCommandEvent> for Database {
+    fn remove(&mut self, index: u16) -> bool {
+        self.inner
+            .remove_command_event::<AnalogOutputCommandEvent>(index)
+    }
+}
+
+impl Remove<DataSetPrototype> for Database {
+    fn remove(&mut self, index: u16) -> bool {
+        self.inner.remove_data_set_prototype(index)
+    }
+}
+
+impl Remove<DataSetDescriptor> for Database {
+    fn remove(&mut self, index: u16) -> bool {
+        self.inner.remove_data_set(index)
+    }
+}
+
+impl Remove<VirtualTerminalConfig> for Database {
+    fn remove(&mut self, index: u16) -> bool {
+        self.inner.remove_virtual_terminal(index)
+    }
+}
+
 impl Get<BinaryInput> for Database {
     fn get(&self, index: u16) -> Option<BinaryInput> {
         self.inner.get::<BinaryInput>(index)
@@ -792,6 +1364,24 @@ impl Get<OctetString> for Database {
     }
 }
 
+impl Get<UnsignedInteger> for Database {
+    fn get(&self, index: u16) -> Option<UnsignedInteger> {
+        self.inner.get::<UnsignedInteger>(index)
+    }
+}
+
+impl Get<SecurityStatistic> for Database {
+    fn get(&self, index: u16) -> Option<SecurityStatistic> {
+        self.inner.get::<SecurityStatistic>(index)
+    }
+}
+
+impl Get<DataSet> for Database {
+    fn get(&self, index: u16) -> Option<DataSet> {
+        self.inner.get_data_set(index)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -801,6 +1391,18 @@ mod test {
         BinaryInput::new(val, Flags::ONLINE, Time::Synchronized(Timestamp::zero()))
     }
 
+    #[test]
+    fn class_zero_config_new_defaults_fields_outside_the_standard_set() {
+        let config = ClassZeroConfig::new(true, true, true, true, true, true, true, false);
+        assert_eq!(config, ClassZeroConfig::default());
+
+        let config = config
+            .with_unsigned_integer(true)
+            .with_security_statistic(false);
+        assert!(config.unsigned_integer);
+        assert!(!config.security_statistic);
+    }
+
     #[test]
     fn returns_no_point_if_point_not_added() {
         let mut db = Database::new(
@@ -878,6 +1480,28 @@ mod test {
         );
     }
 
+    #[test]
+    fn returns_overflow_with_new_event_discarded() {
+        let mut db = Database::new(
+            None,
+            ClassZeroConfig::default(),
+            EventBufferConfig::all_types(1)
+                .with_overflow_policy(EventOverflowPolicy::DiscardNewest),
+        );
+        db.add(0, Some(EventClass::Class1), BinaryInputConfig::default());
+        assert_eq!(
+            UpdateInfo::Created(0),
+            db.update2(0, &binary(true), UpdateOptions::default())
+        );
+        assert_eq!(
+            UpdateInfo::Overflow {
+                created: 1,
+                discarded: 1
+            },
+            db.update2(0, &binary(false), UpdateOptions::default())
+        );
+    }
+
     #[test]
     fn returns_overflow_no_event_if_no_change() {
         let mut db = Database::new(
@@ -895,4 +1519,230 @@ mod test {
             db.update2(0, &binary(true), UpdateOptions::default())
         );
     }
+
+    const fn binary_command(state: bool) -> BinaryOutputCommandEvent {
+        BinaryOutputCommandEvent {
+            commanded_state: state,
+            status: crate::app::control::CommandStatus::Success,
+            time: None,
+        }
+    }
+
+    #[test]
+    fn command_event_update_requires_point() {
+        let mut db = Database::new(
+            None,
+            ClassZeroConfig::default(),
+            EventBufferConfig::all_types(10),
+        );
+        assert_eq!(
+            UpdateInfo::NoPoint,
+            db.update2(0, &binary_command(true), UpdateOptions::default())
+        );
+        db.add(0, None, BinaryOutputCommandEventConfig::default());
+        assert_eq!(
+            UpdateInfo::NoEvent,
+            db.update2(0, &binary_command(true), UpdateOptions::default())
+        );
+        assert!(Remove::<BinaryOutputCommandEvent>::remove(&mut db, 0));
+        assert_eq!(
+            UpdateInfo::NoPoint,
+            db.update2(0, &binary_command(true), UpdateOptions::default())
+        );
+    }
+
+    #[test]
+    fn command_event_created_on_every_update() {
+        let mut db = Database::new(
+            None,
+            ClassZeroConfig::default(),
+            EventBufferConfig::all_types(3),
+        );
+        db.add(
+            0,
+            Some(EventClass::Class1),
+            BinaryOutputCommandEventConfig::default(),
+        );
+        assert_eq!(
+            UpdateInfo::Created(0),
+            db.update2(0, &binary_command(true), UpdateOptions::default())
+        );
+        assert_eq!(
+            UpdateInfo::Created(1),
+            db.update2(0, &binary_command(true), UpdateOptions::default())
+        );
+        assert_eq!(
+            UpdateInfo::NoEvent,
+            db.update2(0, &binary_command(true), UpdateOptions::no_event())
+        );
+    }
+
+    const fn analog(value: f64) -> AnalogInput {
+        AnalogInput::new(value, Flags::ONLINE, Time::Synchronized(Timestamp::zero()))
+    }
+
+    fn analog_db(deadband: f64, mode: DeadbandMode) -> Database {
+        let mut db = Database::new(
+            None,
+            ClassZeroConfig::default(),
+            EventBufferConfig::all_types(10),
+        );
+        db.add(
+            0,
+            Some(EventClass::Class1),
+            AnalogInputConfig {
+                deadband,
+                ..Default::default()
+            }
+            .with_deadband_mode(mode),
+        );
+        db
+    }
+
+    fn is_event(db: &mut Database, value: f64) -> bool {
+        matches!(
+            db.update2(0, &analog(value), UpdateOptions::default()),
+            UpdateInfo::Created(_)
+        )
+    }
+
+    #[test]
+    fn percent_of_range_deadband_is_scaled_by_the_range() {
+        let mut db = analog_db(
+            5.0,
+            DeadbandMode::PercentOfRange {
+                min: -100.0,
+                max: 100.0,
+            },
+        );
+        assert!(is_event(&mut db, 0.0));
+        assert!(!is_event(&mut db, 10.0));
+        assert!(is_event(&mut db, 10.5));
+        assert!(!is_event(&mut db, 0.5));
+        assert!(is_event(&mut db, 0.0));
+    }
+
+    #[test]
+    fn integrating_deadband_reports_slow_drift() {
+        let mut db = analog_db(5.0, DeadbandMode::Integrating);
+        assert!(is_event(&mut db, 0.0));
+        // accumulated deviation: 2, 4, 6
+        assert!(!is_event(&mut db, 2.0));
+        assert!(!is_event(&mut db, 2.0));
+        assert!(is_event(&mut db, 2.0));
+        // the accumulated deviation is reset after every event
+        assert!(!is_event(&mut db, 4.0));
+        assert!(!is_event(&mut db, 4.0));
+        // deviations in opposite directions cancel
+        assert!(!is_event(&mut db, -1.0));
+        assert!(!is_event(&mut db, 5.0));
+    }
+
+    #[test]
+    fn written_deadband_replaces_integrating_threshold() {
+        let mut db = analog_db(5.0, DeadbandMode::Integrating);
+        assert!(is_event(&mut db, 0.0));
+        assert!(db.inner.set_analog_deadband(0, 1.0));
+        assert!(!is_event(&mut db, 0.5));
+        assert!(is_event(&mut db, 0.6));
+
+        let snapshot = db.snapshot();
+        assert_eq!(snapshot.analog_input[0].config.deadband, 1.0);
+        assert_eq!(
+            snapshot.analog_input[0].config.deadband_mode,
+            DeadbandMode::Integrating
+        );
+    }
+
+    fn subscribed_handle(capacity: usize) -> (DatabaseHandle, DatabaseSubscription) {
+        let handle = DatabaseHandle::new(
+            None,
+            ClassZeroConfig::default(),
+            EventBufferConfig::all_types(10),
+        );
+        handle.transaction(|db| {
+            db.add(0, Some(EventClass::Class1), BinaryInputConfig::default());
+            db.add(0, None, AnalogInputConfig::default());
+        });
+        let subscription = handle.subscribe(capacity);
+        (handle, subscription)
+    }
+
+    #[test]
+    fn subscription_receives_changes_of_each_transaction() {
+        let (handle, mut subscription) = subscribed_handle(10);
+        handle.transaction(|db| {
+            db.update(0, &binary(true), UpdateOptions::default());
+            db.update(0, &analog(42.0), UpdateOptions::default());
+            // the point does not exist
+            db.update(1, &binary(true), UpdateOptions::default());
+        });
+
+        let batch = subscription.try_recv().unwrap();
+        assert_eq!(batch.missed, 0);
+        assert_eq!(
+            batch.changes,
+            vec![
+                PointChange {
+                    point_type: PointType::BinaryInput,
+                    index: 0,
+                    old: PointValue::BinaryInput(BinaryInput::default()),
+                    new: PointValue::BinaryInput(binary(true)),
+                    class: Some(EventClass::Class1),
+                    event_created: true,
+                },
+                PointChange {
+                    point_type: PointType::AnalogInput,
+                    index: 0,
+                    old: PointValue::AnalogInput(AnalogInput::default()),
+                    new: PointValue::AnalogInput(analog(42.0)),
+                    class: None,
+                    event_created: false,
+                },
+            ]
+        );
+
+        // an update that changes nothing is not reported
+        handle.transaction(|db| db.update(0, &binary(true), UpdateOptions::default()));
+        assert!(subscription.try_recv().is_none());
+    }
+
+    #[test]
+    fn subscription_reports_event_without_static_update() {
+        let (handle, mut subscription) = subscribed_handle(10);
+        handle.transaction(|db| {
+            db.update(
+                0,
+                &binary(true),
+                UpdateOptions::new(false, EventMode::Detect),
+            )
+        });
+
+        let batch = subscription.try_recv().unwrap();
+        assert_eq!(batch.changes.len(), 1);
+        assert_eq!(
+            batch.changes[0].old,
+            PointValue::BinaryInput(BinaryInput::default())
+        );
+        assert_eq!(batch.changes[0].new, PointValue::BinaryInput(binary(true)));
+        assert!(batch.changes[0].event_created);
+    }
+
+    #[test]
+    fn subscription_counts_missed_batches() {
+        let (handle, mut subscription) = subscribed_handle(1);
+        for value in [1.0, 2.0, 3.0] {
+            handle.transaction(|db| db.update(0, &analog(value), UpdateOptions::default()));
+        }
+
+        let batch = subscription.try_recv().unwrap();
+        assert_eq!(batch.missed, 0);
+        assert_eq!(batch.changes[0].new, PointValue::AnalogInput(analog(1.0)));
+        assert!(subscription.try_recv().is_none());
+
+        handle.transaction(|db| db.update(0, &analog(4.0), UpdateOptions::default()));
+        let batch = subscription.try_recv().unwrap();
+        assert_eq!(batch.missed, 2);
+        assert_eq!(batch.changes[0].old, PointValue::AnalogInput(analog(3.0)));
+    }
 }
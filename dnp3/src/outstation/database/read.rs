@@ -27,6 +27,8 @@ pub(crate) enum StaticReadHeader {
         Option<IndexRange>,
     ),
     OctetString(Option<IndexRange>),
+    UnsignedInteger(Option<IndexRange>),
+    SecurityStatistic(Option<IndexRange>),
     AnalogInputDeadBand(Option<AnalogInputDeadBandVariation>, Option<IndexRange>),
 }
 
@@ -47,6 +49,21 @@ pub(crate) enum EventReadHeader {
     FrozenAnalog(Option<EventFrozenAnalogInputVariation>, Option<usize>),
     AnalogOutputStatus(Option<EventAnalogOutputStatusVariation>, Option<usize>),
     OctetString(Option<usize>),
+    BinaryOutputCommand(Option<EventBinaryOutputCommandVariation>, Option<usize>),
+    AnalogOutputCommand(Option<EventAnalogOutputCommandVariation>, Option<usize>),
+    DataSet(Option<usize>),
+    VirtualTerminal(Option<usize>),
+    SecurityStatistic(Option<EventSecurityStatisticVariation>, Option<usize>),
+}
+
+#[derive(Copy, Clone)]
+pub(crate) enum DataSetHeader {
+    /// read all prototypes (g85v1)
+    Prototypes,
+    /// read all descriptors (g86v1)
+    Descriptors,
+    /// read the present value of all data sets (g87v1)
+    PresentValues,
 }
 
 #[derive(Copy, Clone)]
@@ -65,6 +82,7 @@ pub(crate) enum ReadHeader {
     Static(StaticReadHeader),
     Event(EventReadHeader),
     Attr(AttrHeader),
+    DataSet(DataSetHeader),
 }
 
 impl From<AttrHeader> for ReadHeader {
@@ -73,6 +91,12 @@ impl From<AttrHeader> for ReadHeader {
     }
 }
 
+impl From<DataSetHeader> for ReadHeader {
+    fn from(value: DataSetHeader) -> Self {
+        ReadHeader::DataSet(value)
+    }
+}
+
 impl From<StaticReadHeader> for ReadHeader {
     fn from(x: StaticReadHeader) -> Self {
         ReadHeader::Static(x)
@@ -219,8 +243,23 @@ impl ReadHeader {
                 .into(),
             ),
             // group 13
-            AllObjectsVariation::Group13Var1 => None,
-            AllObjectsVariation::Group13Var2 => None,
+            AllObjectsVariation::Group13Var0 => {
+                Some(EventReadHeader::BinaryOutputCommand(None, None).into())
+            }
+            AllObjectsVariation::Group13Var1 => Some(
+                EventReadHeader::BinaryOutputCommand(
+                    Some(EventBinaryOutputCommandVariation::Group13Var1),
+                    None,
+                )
+                .into(),
+            ),
+            AllObjectsVariation::Group13Var2 => Some(
+                EventReadHeader::BinaryOutputCommand(
+                    Some(EventBinaryOutputCommandVariation::Group13Var2),
+                    None,
+                )
+                .into(),
+            ),
             // group 20
             AllObjectsVariation::Group20Var0 => Some(StaticReadHeader::Counter(None, None).into()),
             AllObjectsVariation::Group20Var1 => Some(
@@ -617,14 +656,65 @@ impl ReadHeader {
                 .into(),
             ),
             // group 43,
-            AllObjectsVariation::Group43Var1 => None,
-            AllObjectsVariation::Group43Var2 => None,
-            AllObjectsVariation::Group43Var3 => None,
-            AllObjectsVariation::Group43Var4 => None,
-            AllObjectsVariation::Group43Var5 => None,
-            AllObjectsVariation::Group43Var6 => None,
-            AllObjectsVariation::Group43Var7 => None,
-            AllObjectsVariation::Group43Var8 => None,
+            AllObjectsVariation::Group43Var0 => {
+                Some(EventReadHeader::AnalogOutputCommand(None, None).into())
+            }
+            AllObjectsVariation::Group43Var1 => Some(
+                EventReadHeader::AnalogOutputCommand(
+                    Some(EventAnalogOutputCommandVariation::Group43Var1),
+                    None,
+                )
+                .into(),
+            ),
+            AllObjectsVariation::Group43Var2 => Some(
+                EventReadHeader::AnalogOutputCommand(
+                    Some(EventAnalogOutputCommandVariation::Group43Var2),
+                    None,
+                )
+                .into(),
+            ),
+            AllObjectsVariation::Group43Var3 => Some(
+                EventReadHeader::AnalogOutputCommand(
+                    Some(EventAnalogOutputCommandVariation::Group43Var3),
+                    None,
+                )
+                .into(),
+            ),
+            AllObjectsVariation::Group43Var4 => Some(
+                EventReadHeader::AnalogOutputCommand(
+                    Some(EventAnalogOutputCommandVariation::Group43Var4),
+                    None,
+                )
+                .into(),
+            ),
+            AllObjectsVariation::Group43Var5 => Some(
+                EventReadHeader::AnalogOutputCommand(
+                    Some(EventAnalogOutputCommandVariation::Group43Var5),
+                    None,
+                )
+                .into(),
+            ),
+            AllObjectsVariation::Group43Var6 => Some(
+                EventReadHeader::AnalogOutputCommand(
+                    Some(EventAnalogOutputCommandVariation::Group43Var6),
+                    None,
+                )
+                .into(),
+            ),
+            AllObjectsVariation::Group43Var7 => Some(
+                EventReadHeader::AnalogOutputCommand(
+                    Some(EventAnalogOutputCommandVariation::Group43Var7),
+                    None,
+                )
+                .into(),
+            ),
+            AllObjectsVariation::Group43Var8 => Some(
+                EventReadHeader::AnalogOutputCommand(
+                    Some(EventAnalogOutputCommandVariation::Group43Var8),
+                    None,
+                )
+                .into(),
+            ),
             // group 60
             AllObjectsVariation::Group60Var1 => Some(StaticReadHeader::Class0.into()),
             AllObjectsVariation::Group60Var2 => Some(EventReadHeader::Class1(None).into()),
@@ -632,13 +722,53 @@ impl ReadHeader {
             AllObjectsVariation::Group60Var4 => Some(EventReadHeader::Class3(None).into()),
             // group 80
             AllObjectsVariation::Group80Var1 => None,
+            // groups 85 - 88
+            AllObjectsVariation::Group85Var1 => Some(DataSetHeader::Prototypes.into()),
+            AllObjectsVariation::Group86Var1 => Some(DataSetHeader::Descriptors.into()),
+            AllObjectsVariation::Group87Var1 => Some(DataSetHeader::PresentValues.into()),
+            AllObjectsVariation::Group88Var1 => Some(EventReadHeader::DataSet(None).into()),
+            // group 90
+            AllObjectsVariation::Group90Var1 => None,
             // group 102
-            AllObjectsVariation::Group102Var0 => None,
-            AllObjectsVariation::Group102Var1 => None,
+            AllObjectsVariation::Group102Var0 => {
+                Some(StaticReadHeader::UnsignedInteger(None).into())
+            }
+            AllObjectsVariation::Group102Var1 => {
+                Some(StaticReadHeader::UnsignedInteger(None).into())
+            }
             // group 110
             AllObjectsVariation::Group110Var0 => Some(StaticReadHeader::OctetString(None).into()),
             // group 111
             AllObjectsVariation::Group111Var0 => Some(EventReadHeader::OctetString(None).into()),
+            // group 113
+            AllObjectsVariation::Group113Var0 => {
+                Some(EventReadHeader::VirtualTerminal(None).into())
+            }
+            // group 121
+            AllObjectsVariation::Group121Var0 => {
+                Some(StaticReadHeader::SecurityStatistic(None).into())
+            }
+            AllObjectsVariation::Group121Var1 => {
+                Some(StaticReadHeader::SecurityStatistic(None).into())
+            }
+            // group 122
+            AllObjectsVariation::Group122Var0 => {
+                Some(EventReadHeader::SecurityStatistic(None, None).into())
+            }
+            AllObjectsVariation::Group122Var1 => Some(
+                EventReadHeader::SecurityStatistic(
+                    Some(EventSecurityStatisticVariation::Group122Var1),
+                    None,
+                )
+                .into(),
+            ),
+            AllObjectsVariation::Group122Var2 => Some(
+                EventReadHeader::SecurityStatistic(
+                    Some(EventSecurityStatisticVariation::Group122Var2),
+                    None,
+                )
+                .into(),
+            ),
         }
     }
 
@@ -698,8 +828,24 @@ impl ReadHeader {
                 )
                 .into(),
             ),
-            CountVariation::Group13Var1 => None,
-            CountVariation::Group13Var2 => None,
+            CountVariation::Group12Var2(_) => None,
+            CountVariation::Group13Var0 => {
+                Some(EventReadHeader::BinaryOutputCommand(None, Some(count)).into())
+            }
+            CountVariation::Group13Var1 => Some(
+                EventReadHeader::BinaryOutputCommand(
+                    Some(EventBinaryOutputCommandVariation::Group13Var1),
+                    Some(count),
+                )
+                .into(),
+            ),
+            CountVariation::Group13Var2 => Some(
+                EventReadHeader::BinaryOutputCommand(
+                    Some(EventBinaryOutputCommandVariation::Group13Var2),
+                    Some(count),
+                )
+                .into(),
+            ),
             CountVariation::Group22Var0 => Some(EventReadHeader::Counter(None, Some(count)).into()),
             CountVariation::Group22Var1 => Some(
                 EventReadHeader::Counter(Some(EventCounterVariation::Group22Var1), Some(count))
@@ -899,14 +1045,65 @@ impl ReadHeader {
                 )
                 .into(),
             ),
-            CountVariation::Group43Var1 => None,
-            CountVariation::Group43Var2 => None,
-            CountVariation::Group43Var3 => None,
-            CountVariation::Group43Var4 => None,
-            CountVariation::Group43Var5 => None,
-            CountVariation::Group43Var6 => None,
-            CountVariation::Group43Var7 => None,
-            CountVariation::Group43Var8 => None,
+            CountVariation::Group43Var0 => {
+                Some(EventReadHeader::AnalogOutputCommand(None, Some(count)).into())
+            }
+            CountVariation::Group43Var1 => Some(
+                EventReadHeader::AnalogOutputCommand(
+                    Some(EventAnalogOutputCommandVariation::Group43Var1),
+                    Some(count),
+                )
+                .into(),
+            ),
+            CountVariation::Group43Var2 => Some(
+                EventReadHeader::AnalogOutputCommand(
+                    Some(EventAnalogOutputCommandVariation::Group43Var2),
+                    Some(count),
+                )
+                .into(),
+            ),
+            CountVariation::Group43Var3 => Some(
+                EventReadHeader::AnalogOutputCommand(
+                    Some(EventAnalogOutputCommandVariation::Group43Var3),
+                    Some(count),
+                )
+                .into(),
+            ),
+            CountVariation::Group43Var4 => Some(
+                EventReadHeader::AnalogOutputCommand(
+                    Some(EventAnalogOutputCommandVariation::Group43Var4),
+                    Some(count),
+                )
+                .into(),
+            ),
+            CountVariation::Group43Var5 => Some(
+                EventReadHeader::AnalogOutputCommand(
+                    Some(EventAnalogOutputCommandVariation::Group43Var5),
+                    Some(count),
+                )
+                .into(),
+            ),
+            CountVariation::Group43Var6 => Some(
+                EventReadHeader::AnalogOutputCommand(
+                    Some(EventAnalogOutputCommandVariation::Group43Var6),
+                    Some(count),
+                )
+                .into(),
+            ),
+            CountVariation::Group43Var7 => Some(
+                EventReadHeader::AnalogOutputCommand(
+                    Some(EventAnalogOutputCommandVariation::Group43Var7),
+                    Some(count),
+                )
+                .into(),
+            ),
+            CountVariation::Group43Var8 => Some(
+                EventReadHeader::AnalogOutputCommand(
+                    Some(EventAnalogOutputCommandVariation::Group43Var8),
+                    Some(count),
+                )
+                .into(),
+            ),
             CountVariation::Group50Var1(_) => None,
             CountVariation::Group50Var2(_) => None,
             CountVariation::Group50Var3(_) => None,
@@ -918,8 +1115,32 @@ impl ReadHeader {
             CountVariation::Group60Var2 => Some(EventReadHeader::Class1(Some(count)).into()),
             CountVariation::Group60Var3 => Some(EventReadHeader::Class2(Some(count)).into()),
             CountVariation::Group60Var4 => Some(EventReadHeader::Class3(Some(count)).into()),
+            CountVariation::Group91Var1(_) => None,
             CountVariation::Group111Var0 => Some(EventReadHeader::OctetString(Some(count)).into()),
             CountVariation::Group111VarX(_) => None,
+            CountVariation::Group113Var0 => {
+                Some(EventReadHeader::VirtualTerminal(Some(count)).into())
+            }
+            CountVariation::Group113VarX(_) => None,
+            CountVariation::Group120Var3(_) => None,
+            CountVariation::Group120Var4(_) => None,
+            CountVariation::Group122Var0 => {
+                Some(EventReadHeader::SecurityStatistic(None, Some(count)).into())
+            }
+            CountVariation::Group122Var1 => Some(
+                EventReadHeader::SecurityStatistic(
+                    Some(EventSecurityStatisticVariation::Group122Var1),
+                    Some(count),
+                )
+                .into(),
+            ),
+            CountVariation::Group122Var2 => Some(
+                EventReadHeader::SecurityStatistic(
+                    Some(EventSecurityStatisticVariation::Group122Var2),
+                    Some(count),
+                )
+                .into(),
+            ),
         }
     }
 
@@ -976,6 +1197,7 @@ impl ReadHeader {
                 )
                 .into(),
             ),
+            RangedVariation::Group12Var3(_) => None,
             // group 20
             RangedVariation::Group20Var0 => {
                 Some(StaticReadHeader::Counter(None, Some(range)).into())
@@ -1205,14 +1427,25 @@ impl ReadHeader {
             // group 80
             RangedVariation::Group80Var1(_) => None,
             // group 102
-            RangedVariation::Group102Var0 => None,
-            RangedVariation::Group102Var1(_) => None,
+            RangedVariation::Group102Var0 => {
+                Some(StaticReadHeader::UnsignedInteger(Some(range)).into())
+            }
+            RangedVariation::Group102Var1(_) => {
+                Some(StaticReadHeader::UnsignedInteger(Some(range)).into())
+            }
             // group 110
             RangedVariation::Group110Var0 => {
                 Some(StaticReadHeader::OctetString(Some(range)).into())
             }
             // group 111
             RangedVariation::Group110VarX(_, _) => None,
+            // group 121
+            RangedVariation::Group121Var0 => {
+                Some(StaticReadHeader::SecurityStatistic(Some(range)).into())
+            }
+            RangedVariation::Group121Var1(_) => {
+                Some(StaticReadHeader::SecurityStatistic(Some(range)).into())
+            }
         }
     }
 }
@@ -0,0 +1,314 @@
+use crate::app::measurement::*;
+use crate::outstation::database::config::*;
+use crate::outstation::database::details::range::static_db::{PointConfig, Updatable};
+use crate::outstation::database::{details, Add, Database, EventClass};
+
+/// Configuration and current value of a point captured by [`Database::snapshot`]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct PointSnapshot<C, V> {
+    /// Index of the point
+    pub index: u16,
+    /// Class assigned to the point, if any
+    pub class: Option<EventClass>,
+    /// Configuration of the point including the current deadband
+    pub config: C,
+    /// Current value of the point including its flags and timestamp
+    pub value: V,
+}
+
+/// Point configuration and current values of the static types in a [`Database`]
+///
+/// Events, command event points, data sets, virtual terminal ports and attributes are not
+/// part of the snapshot.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct DatabaseSnapshot {
+    /// Binary input points
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub binary_input: Vec<PointSnapshot<BinaryInputConfig, BinaryInput>>,
+    /// Double-bit binary input points
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub double_bit_binary_input:
+        Vec<PointSnapshot<DoubleBitBinaryInputConfig, DoubleBitBinaryInput>>,
+    /// Binary output status points
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub binary_output_status: Vec<PointSnapshot<BinaryOutputStatusConfig, BinaryOutputStatus>>,
+    /// Counter points
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub counter: Vec<PointSnapshot<CounterConfig, Counter>>,
+    /// Frozen counter points
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub frozen_counter: Vec<PointSnapshot<FrozenCounterConfig, FrozenCounter>>,
+    /// Analog input points
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub analog_input: Vec<PointSnapshot<AnalogInputConfig, AnalogInput>>,
+    /// Analog output status points
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub analog_output_status: Vec<PointSnapshot<AnalogOutputStatusConfig, AnalogOutputStatus>>,
+    /// Octet string points
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub octet_string: Vec<PointSnapshot<OctetStringConfig, OctetString>>,
+    /// Unsigned integer points
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub unsigned_integer: Vec<PointSnapshot<UnsignedIntegerConfig, UnsignedInteger>>,
+    /// Security statistic points
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub security_statistic: Vec<PointSnapshot<SecurityStatisticConfig, SecurityStatistic>>,
+}
+
+impl Database {
+    /// Capture the configuration and current value of every static point
+    pub fn snapshot(&self) -> DatabaseSnapshot {
+        DatabaseSnapshot {
+            binary_input: capture::<BinaryInput, _, _>(&self.inner, |x| {
+                BinaryInputConfig::new(x.s_var(), x.e_var())
+            }),
+            double_bit_binary_input: capture::<DoubleBitBinaryInput, _, _>(&self.inner, |x| {
+                DoubleBitBinaryInputConfig::new(x.s_var(), x.e_var())
+            }),
+            binary_output_status: capture::<BinaryOutputStatus, _, _>(&self.inner, |x| {
+                BinaryOutputStatusConfig::new(x.s_var(), x.e_var())
+            }),
+            counter: capture::<Counter, _, _>(&self.inner, |x| {
+                CounterConfig::new(x.s_var(), x.e_var(), x.detector().value())
+            }),
+            frozen_counter: capture::<FrozenCounter, _, _>(&self.inner, |x| {
+                FrozenCounterConfig::new(x.s_var(), x.e_var(), x.detector().value())
+            }),
+            analog_input: capture::<AnalogInput, _, _>(&self.inner, |x| {
+                AnalogInputConfig::new(x.s_var(), x.e_var(), x.detector().value())
+                    .with_deadband_mode(x.detector().mode())
+            }),
+            analog_output_status: capture::<AnalogOutputStatus, _, _>(&self.inner, |x| {
+                AnalogOutputStatusConfig::new(x.s_var(), x.e_var(), x.detector().value())
+                    .with_deadband_mode(x.detector().mode())
+            }),
+            octet_string: capture::<OctetString, _, _>(&self.inner, |_| OctetStringConfig),
+            unsigned_integer: capture::<UnsignedInteger, _, _>(&self.inner, |_| {
+                UnsignedIntegerConfig
+            }),
+            security_statistic: capture::<SecurityStatistic, _, _>(&self.inner, |x| {
+                SecurityStatisticConfig::new(x.e_var(), x.detector().value())
+            }),
+        }
+    }
+
+    /// Replace every static point with the points in a snapshot
+    ///
+    /// The values are restored without producing events and become the reference values for
+    /// subsequent event detection. If the snapshot contains the same index more than once
+    /// for a type, only the first point is restored.
+    pub fn restore(&mut self, snapshot: &DatabaseSnapshot) {
+        self.inner.clear_points();
+        self.restore_points(&snapshot.binary_input);
+        self.restore_points(&snapshot.double_bit_binary_input);
+        self.restore_points(&snapshot.binary_output_status);
+        self.restore_points(&snapshot.counter);
+        self.restore_points(&snapshot.frozen_counter);
+        self.restore_points(&snapshot.analog_input);
+        self.restore_points(&snapshot.analog_output_status);
+        self.restore_points(&snapshot.octet_string);
+        self.restore_points(&snapshot.unsigned_integer);
+        self.restore_points(&snapshot.security_statistic);
+    }
+
+    fn restore_points<C, T>(&mut self, points: &[PointSnapshot<C, T>])
+    where
+        Database: Add<C>,
+        C: Copy,
+        T: Updatable,
+    {
+        for point in points {
+            if self.add(point.index, point.class, point.config) {
+                self.inner.restore(point.index, &point.value);
+            }
+        }
+    }
+}
+
+fn capture<T, C, F>(db: &details::database::Database, config: F) -> Vec<PointSnapshot<C, T>>
+where
+    T: Updatable,
+    F: Fn(&PointConfig<T>) -> C,
+{
+    db.points::<T>()
+        .map(|(index, x, value)| PointSnapshot {
+            index,
+            class: x.class(),
+            config: config(x),
+            value: value.clone(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::link::EndpointAddress;
+    use crate::master::EventClasses;
+    use crate::outstation::database::{
+        ClassZeroConfig, EventBufferConfig, Get, Remove, Update, UpdateInfo, UpdateOptions,
+    };
+    use crate::outstation::OfflineAssociationPolicy;
+
+    fn database() -> Database {
+        Database::new(
+            None,
+            ClassZeroConfig::default(),
+            EventBufferConfig::all_types(10),
+        )
+    }
+
+    fn populate(db: &mut Database) {
+        db.add(
+            3,
+            Some(EventClass::Class1),
+            BinaryInputConfig::new(
+                StaticBinaryInputVariation::Group1Var2,
+                EventBinaryInputVariation::Group2Var2,
+            ),
+        );
+        db.update(
+            3,
+            &BinaryInput::new(true, Flags::ONLINE, Time::synchronized(1234)),
+            UpdateOptions::no_event(),
+        );
+        db.add(
+            7,
+            Some(EventClass::Class2),
+            AnalogInputConfig::new(
+                StaticAnalogInputVariation::Group30Var6,
+                EventAnalogInputVariation::Group32Var8,
+                1.5,
+            ),
+        );
+        db.update(
+            7,
+            &AnalogInput::new(42.0, Flags::ONLINE, Time::unsynchronized(5678)),
+            UpdateOptions::no_event(),
+        );
+        db.add(1, None, OctetStringConfig);
+        db.update(
+            1,
+            &OctetString::new(b"hello").unwrap(),
+            UpdateOptions::no_event(),
+        );
+    }
+
+    #[test]
+    fn snapshot_captures_configuration_and_values() {
+        let mut db = database();
+        populate(&mut db);
+        db.inner.set_analog_deadband(7, 2.5);
+
+        let snapshot = db.snapshot();
+        assert_eq!(
+            snapshot.binary_input,
+            vec![PointSnapshot {
+                index: 3,
+                class: Some(EventClass::Class1),
+                config: BinaryInputConfig::new(
+                    StaticBinaryInputVariation::Group1Var2,
+                    EventBinaryInputVariation::Group2Var2,
+                ),
+                value: BinaryInput::new(true, Flags::ONLINE, Time::synchronized(1234)),
+            }]
+        );
+        assert_eq!(snapshot.analog_input.len(), 1);
+        assert_eq!(snapshot.analog_input[0].config.deadband, 2.5);
+        assert_eq!(snapshot.analog_input[0].class, Some(EventClass::Class2));
+        assert_eq!(
+            snapshot.octet_string[0].value,
+            OctetString::new(b"hello").unwrap()
+        );
+        assert!(snapshot.counter.is_empty());
+    }
+
+    #[test]
+    fn restore_replaces_points_without_producing_events() {
+        let mut source = database();
+        populate(&mut source);
+        let snapshot = source.snapshot();
+
+        let mut db = database();
+        db.add(0, Some(EventClass::Class1), CounterConfig::default());
+        db.restore(&snapshot);
+
+        assert_eq!(db.snapshot(), snapshot);
+        assert!(!Remove::<Counter>::remove(&mut db, 0));
+        let association = db
+            .inner
+            .add_association(
+                EndpointAddress::raw(1),
+                OfflineAssociationPolicy::Retain,
+                tokio::time::Instant::now(),
+            )
+            .unwrap();
+        assert_eq!(
+            db.inner.unwritten_classes(association),
+            EventClasses::none()
+        );
+        assert_eq!(
+            Get::<BinaryInput>::get(&db, 3),
+            Some(BinaryInput::new(
+                true,
+                Flags::ONLINE,
+                Time::synchronized(1234)
+            ))
+        );
+    }
+
+    #[test]
+    fn restored_values_are_the_reference_for_event_detection() {
+        let mut source = database();
+        populate(&mut source);
+        let snapshot = source.snapshot();
+
+        let mut db = database();
+        db.restore(&snapshot);
+
+        // within the deadband of the restored value
+        assert_eq!(
+            db.update2(
+                7,
+                &AnalogInput::new(43.0, Flags::ONLINE, Time::unsynchronized(5679)),
+                UpdateOptions::default(),
+            ),
+            UpdateInfo::NoEvent
+        );
+        assert_eq!(
+            db.update2(
+                3,
+                &BinaryInput::new(false, Flags::ONLINE, Time::synchronized(1235)),
+                UpdateOptions::default(),
+            ),
+            UpdateInfo::Created(0)
+        );
+    }
+
+    #[cfg(feature = "serialization")]
+    #[test]
+    fn snapshot_round_trips_through_serde() {
+        let mut db = database();
+        populate(&mut db);
+        let snapshot = db.snapshot();
+
+        let json = serde_json::to_string(&snapshot).unwrap();
+        assert_eq!(
+            serde_json::from_str::<DatabaseSnapshot>(&json).unwrap(),
+            snapshot
+        );
+        // missing point types default to empty
+        assert_eq!(
+            serde_json::from_str::<DatabaseSnapshot>("{}").unwrap(),
+            DatabaseSnapshot::default()
+        );
+    }
+}
@@ -0,0 +1,209 @@
+use crate::app::measurement::*;
+use crate::outstation::database::EventClass;
+
+/// Type of point reported in a [`PointChange`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PointType {
+    /// Binary input point
+    BinaryInput,
+    /// Double-bit binary input point
+    DoubleBitBinaryInput,
+    /// Binary output status point
+    BinaryOutputStatus,
+    /// Counter point
+    Counter,
+    /// Frozen counter point
+    FrozenCounter,
+    /// Analog input point
+    AnalogInput,
+    /// Analog output status point
+    AnalogOutputStatus,
+    /// Octet string point
+    OctetString,
+    /// Unsigned integer point
+    UnsignedInteger,
+    /// Security statistic point
+    SecurityStatistic,
+}
+
+/// Value of a point reported in a [`PointChange`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum PointValue {
+    /// Binary input value
+    BinaryInput(BinaryInput),
+    /// Double-bit binary input value
+    DoubleBitBinaryInput(DoubleBitBinaryInput),
+    /// Binary output status value
+    BinaryOutputStatus(BinaryOutputStatus),
+    /// Counter value
+    Counter(Counter),
+    /// Frozen counter value
+    FrozenCounter(FrozenCounter),
+    /// Analog input value
+    AnalogInput(AnalogInput),
+    /// Analog output status value
+    AnalogOutputStatus(AnalogOutputStatus),
+    /// Octet string value
+    OctetString(Box<OctetString>),
+    /// Unsigned integer value
+    UnsignedInteger(UnsignedInteger),
+    /// Security statistic value
+    SecurityStatistic(SecurityStatistic),
+}
+
+impl PointValue {
+    /// Type of the point that holds this value
+    pub fn point_type(&self) -> PointType {
+        match self {
+            Self::BinaryInput(_) => PointType::BinaryInput,
+            Self::DoubleBitBinaryInput(_) => PointType::DoubleBitBinaryInput,
+            Self::BinaryOutputStatus(_) => PointType::BinaryOutputStatus,
+            Self::Counter(_) => PointType::Counter,
+            Self::FrozenCounter(_) => PointType::FrozenCounter,
+            Self::AnalogInput(_) => PointType::AnalogInput,
+            Self::AnalogOutputStatus(_) => PointType::AnalogOutputStatus,
+            Self::OctetString(_) => PointType::OctetString,
+            Self::UnsignedInteger(_) => PointType::UnsignedInteger,
+            Self::SecurityStatistic(_) => PointType::SecurityStatistic,
+        }
+    }
+}
+
+macro_rules! impl_from_value {
+    ($($name:ident),*) => {
+        $(
+            impl From<$name> for PointValue {
+                fn from(value: $name) -> Self {
+                    Self::$name(value)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_value!(
+    BinaryInput,
+    DoubleBitBinaryInput,
+    BinaryOutputStatus,
+    Counter,
+    FrozenCounter,
+    AnalogInput,
+    AnalogOutputStatus,
+    UnsignedInteger,
+    SecurityStatistic
+);
+
+impl From<OctetString> for PointValue {
+    fn from(value: OctetString) -> Self {
+        Self::OctetString(Box::new(value))
+    }
+}
+
+/// Change to a single point applied by an update to the [`Database`](crate::outstation::database::Database)
+///
+/// A change is reported whenever an update modifies the static value of a point or produces
+/// an event. The `new` value is the value supplied in the update, which is the value reported
+/// in the event even if the update did not modify the static value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PointChange {
+    /// Type of the point
+    pub point_type: PointType,
+    /// Index of the point
+    pub index: u16,
+    /// Static value of the point prior to the update
+    pub old: PointValue,
+    /// Value supplied in the update
+    pub new: PointValue,
+    /// Class assigned to the point, if any
+    pub class: Option<EventClass>,
+    /// True if the update produced an event that was stored in the event buffer
+    pub event_created: bool,
+}
+
+/// Changes applied to the database during a single transaction
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChangeBatch {
+    /// Number of batches that were dropped since the previous delivered batch because the
+    /// subscription channel was full
+    pub missed: usize,
+    /// Changes in the order they were applied
+    pub changes: Vec<PointChange>,
+}
+
+/// Receiving side of a subscription created with
+/// [`DatabaseHandle::subscribe`](crate::outstation::database::DatabaseHandle::subscribe)
+///
+/// Dropping the subscription removes it from the database.
+#[derive(Debug)]
+pub struct DatabaseSubscription {
+    rx: tokio::sync::mpsc::Receiver<ChangeBatch>,
+}
+
+impl DatabaseSubscription {
+    /// Wait for the next batch of changes
+    ///
+    /// Returns `None` once the database has been dropped and every buffered batch was received.
+    pub async fn recv(&mut self) -> Option<ChangeBatch> {
+        self.rx.recv().await
+    }
+
+    /// Receive the next batch of changes if one is available without waiting
+    pub fn try_recv(&mut self) -> Option<ChangeBatch> {
+        self.rx.try_recv().ok()
+    }
+}
+
+struct Subscriber {
+    tx: tokio::sync::mpsc::Sender<ChangeBatch>,
+    missed: usize,
+}
+
+/// subscribers and the changes recorded during the current transaction
+#[derive(Default)]
+pub(crate) struct Subscribers {
+    subscribers: Vec<Subscriber>,
+    pending: Vec<PointChange>,
+}
+
+impl Subscribers {
+    pub(crate) fn subscribe(&mut self, capacity: usize) -> DatabaseSubscription {
+        let (tx, rx) = tokio::sync::mpsc::channel(capacity.max(1));
+        self.subscribers.push(Subscriber { tx, missed: 0 });
+        DatabaseSubscription { rx }
+    }
+
+    pub(crate) fn is_active(&self) -> bool {
+        !self.subscribers.is_empty()
+    }
+
+    pub(crate) fn record(&mut self, change: PointChange) {
+        self.pending.push(change);
+    }
+
+    /// send the recorded changes to every subscriber, dropping subscribers that have gone away
+    pub(crate) fn publish(&mut self) {
+        if self.pending.is_empty() {
+            return;
+        }
+
+        let changes = std::mem::take(&mut self.pending);
+
+        self.subscribers.retain_mut(|sub| {
+            let batch = ChangeBatch {
+                missed: sub.missed,
+                changes: changes.clone(),
+            };
+            match sub.tx.try_send(batch) {
+                Ok(()) => {
+                    sub.missed = 0;
+                    true
+                }
+                Err(tokio::sync::mpsc::error::TrySendError::Full(_)) => {
+                    sub.missed = sub.missed.saturating_add(1);
+                    true
+                }
+                Err(tokio::sync::mpsc::error::TrySendError::Closed(_)) => false,
+            }
+        });
+    }
+}
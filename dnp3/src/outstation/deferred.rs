@@ -2,7 +2,7 @@ use crate::app::parse::parser::HeaderCollection;
 use crate::app::Iin2;
 use crate::app::Sequence;
 use crate::outstation::database::read::ReadHeader;
-use crate::outstation::database::DatabaseHandle;
+use crate::outstation::database::{Association, DatabaseHandle};
 use crate::transport::FragmentInfo;
 
 #[derive(Copy, Clone)]
@@ -81,15 +81,19 @@ impl DeferredRead {
         self.info = Some(DeferredInfo::new(hash, seq, info, iin2));
     }
 
-    pub(crate) fn select(&mut self, database: &DatabaseHandle) -> Option<DeferredInfo> {
+    pub(crate) fn select(
+        &mut self,
+        database: &DatabaseHandle,
+        association: Association,
+    ) -> Option<DeferredInfo> {
         match self.info {
             None => None,
             Some(x) => {
                 let iin2 = database.transaction(|db| {
-                    db.inner.reset();
+                    db.inner.reset(association);
                     let mut iin2 = Iin2::default();
                     for header in self.vec.iter() {
-                        iin2 |= db.inner.select_by_header(*header);
+                        iin2 |= db.inner.select_by_header(association, *header);
                     }
                     iin2
                 });
@@ -0,0 +1,676 @@
+use std::collections::HashMap;
+
+use crate::app::file::*;
+use crate::app::format::write::HeaderWriter;
+use crate::app::format::WriteError;
+use crate::app::{MaybeAsync, Timestamp};
+use crate::master::{AuthKey, BlockNumber, FileHandle, FileInfo, FileMode};
+use crate::outstation::database::EventClass;
+
+use scursor::WriteCursor;
+
+/// Determines how the outstation reports the results of file operations
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FileTransportMode {
+    /// The results of all file operations are returned directly in the response to the request
+    Immediate,
+    /// The outstation returns a null response to OPEN, CLOSE, DELETE, ABORT and to file block
+    /// READ/WRITE requests. The resulting g70v4, g70v5 and g70v6 objects are then reported
+    /// as events in the specified class.
+    ///
+    /// The event buffer must be configured to hold file transfer events
+    /// (see [`EventBufferConfig::max_file_transfer`](crate::outstation::database::EventBufferConfig::max_file_transfer))
+    Event(EventClass),
+}
+
+/// Parameters of an OPEN request received from the master (g70v3)
+#[derive(Copy, Clone, Debug)]
+pub struct FileOpenRequest<'a> {
+    /// Name of the file to open
+    pub file_name: &'a str,
+    /// Mode in which the file should be opened, one of [`FileMode::Read`], [`FileMode::Write`] or [`FileMode::Append`]
+    pub mode: FileMode,
+    /// Authentication key previously obtained using AUTHENTICATE, or 0 if none was supplied
+    pub auth_key: AuthKey,
+    /// Size of the file being written. Not meaningful when opening a file for reading.
+    pub file_size: u32,
+    /// Time of creation requested by the master when opening a file for writing
+    pub time_created: Timestamp,
+    /// Permissions requested by the master when opening a file for writing
+    pub permissions: Permissions,
+}
+
+/// File system exposed to the master using the Group 70 file transfer objects
+///
+/// The library validates file handles, block numbers, and modes before invoking these
+/// callbacks. Directory listings are served automatically: opening a path for reading
+/// whose [`OutstationFileSystem::stat`] reports [`FileType::Directory`] transfers the
+/// result of [`OutstationFileSystem::list_directory`] encoded as g70v7 objects.
+pub trait OutstationFileSystem: Send + Sync + 'static {
+    /// Controls whether results are returned in responses or reported as events
+    fn transport_mode(&self) -> FileTransportMode {
+        FileTransportMode::Immediate
+    }
+
+    /// Validate a user name and password, returning an authentication key that the master
+    /// will supply in subsequent OPEN and DELETE requests.
+    ///
+    /// Returning `None` tells the master that permission was denied.
+    #[allow(unused_variables)]
+    fn authenticate(&mut self, user_name: &str, password: &str) -> Option<AuthKey> {
+        None
+    }
+
+    /// Open a file associated with the library allocated handle
+    ///
+    /// On success, return the size of the file in bytes.
+    fn open(&mut self, handle: FileHandle, request: FileOpenRequest) -> Result<u32, FileStatus>;
+
+    /// Read the next block of a file opened for reading
+    ///
+    /// At most `block_size` bytes may be returned. Returning fewer than `block_size` bytes
+    /// marks the block as the last block of the file.
+    fn read_block(
+        &mut self,
+        handle: FileHandle,
+        block_size: u16,
+    ) -> MaybeAsync<Result<Vec<u8>, FileStatus>>;
+
+    /// Write the next block of a file opened for writing or appending
+    ///
+    /// `last` is true if the master indicated this is the final block of the file
+    #[allow(unused_variables)]
+    fn write_block(
+        &mut self,
+        handle: FileHandle,
+        data: &[u8],
+        last: bool,
+    ) -> MaybeAsync<Result<(), FileStatus>> {
+        MaybeAsync::ready(Err(FileStatus::PermissionDenied))
+    }
+
+    /// Close a file. The handle is released regardless of the returned status.
+    fn close(&mut self, handle: FileHandle) -> Result<(), FileStatus>;
+
+    /// Abort the transfer of a file, discarding any partially written data
+    ///
+    /// The default implementation closes the file
+    fn abort(&mut self, handle: FileHandle) -> Result<(), FileStatus> {
+        self.close(handle)
+    }
+
+    /// Delete a file
+    #[allow(unused_variables)]
+    fn delete(&mut self, file_name: &str, auth_key: AuthKey) -> Result<(), FileStatus> {
+        Err(FileStatus::PermissionDenied)
+    }
+
+    /// Retrieve information about a file or directory
+    #[allow(unused_variables)]
+    fn stat(&mut self, file_name: &str) -> Result<FileInfo, FileStatus> {
+        Err(FileStatus::FileNotFound)
+    }
+
+    /// List the contents of a directory
+    #[allow(unused_variables)]
+    fn list_directory(&mut self, dir_name: &str) -> Result<Vec<FileInfo>, FileStatus> {
+        Err(FileStatus::FileNotFound)
+    }
+}
+
+/// Response objects produced by the file server
+pub(crate) enum FileResponse {
+    /// g70v2
+    Authenticate(u32),
+    /// g70v4
+    CommandStatus {
+        handle: u32,
+        size: u32,
+        block_size: u16,
+        request_id: u16,
+        status: FileStatus,
+    },
+    /// g70v5
+    Transport {
+        handle: u32,
+        block: u32,
+        data: Vec<u8>,
+    },
+    /// g70v6
+    TransportStatus {
+        handle: u32,
+        block: u32,
+        status: FileStatus,
+    },
+    /// g70v7
+    Info { info: FileInfo, request_id: u16 },
+}
+
+impl FileResponse {
+    fn command_status(handle: u32, request_id: u16, status: FileStatus) -> Self {
+        Self::CommandStatus {
+            handle,
+            size: 0,
+            block_size: 0,
+            request_id,
+            status,
+        }
+    }
+
+    /// true if the object may be reported as an event when operating in event mode
+    pub(crate) fn is_event_capable(&self) -> bool {
+        match self {
+            Self::Authenticate(_) => false,
+            Self::CommandStatus { .. } => true,
+            Self::Transport { .. } => true,
+            Self::TransportStatus { .. } => true,
+            Self::Info { .. } => false,
+        }
+    }
+
+    pub(crate) fn write(&self, writer: &mut HeaderWriter) -> Result<(), WriteError> {
+        match self {
+            Self::Authenticate(key) => writer.write_free_format(&Group70Var2 {
+                auth_key: *key,
+                user_name: "",
+                password: "",
+            }),
+            Self::CommandStatus {
+                handle,
+                size,
+                block_size,
+                request_id,
+                status,
+            } => writer.write_free_format(&Group70Var4 {
+                file_handle: *handle,
+                file_size: *size,
+                max_block_size: *block_size,
+                request_id: *request_id,
+                status_code: *status,
+                text: "",
+            }),
+            Self::Transport {
+                handle,
+                block,
+                data,
+            } => writer.write_free_format(&Group70Var5 {
+                file_handle: *handle,
+                block_number: *block,
+                file_data: data,
+            }),
+            Self::TransportStatus {
+                handle,
+                block,
+                status,
+            } => writer.write_free_format(&Group70Var6 {
+                file_handle: *handle,
+                block_number: *block,
+                status_code: *status,
+                text: "",
+            }),
+            Self::Info { info, request_id } => {
+                writer.write_free_format(&info.to_g70v7(*request_id))
+            }
+        }
+    }
+
+    /// encode the complete object header so that it can be stored in the event buffer
+    pub(crate) fn encode(&self) -> Result<Box<[u8]>, WriteError> {
+        let data_len = match self {
+            Self::Transport { data, .. } => data.len(),
+            _ => 0,
+        };
+        // fixed overhead of the largest event capable object + header is well under 32 bytes
+        let mut buffer = vec![0; 32 + data_len];
+        let mut cursor = WriteCursor::new(&mut buffer);
+        self.write(&mut HeaderWriter::new(&mut cursor))?;
+        Ok(cursor.written().into())
+    }
+}
+
+impl FileInfo {
+    fn to_g70v7(&self, request_id: u16) -> Group70Var7<'_> {
+        Group70Var7 {
+            file_type: self.file_type,
+            file_size: self.size,
+            time_of_creation: self.time_created,
+            permissions: self.permissions,
+            request_id,
+            file_name: &self.name,
+        }
+    }
+}
+
+enum Source {
+    /// file managed by the user file system
+    FileSystem,
+    /// directory listing encoded as g70v7 objects
+    Directory { data: Vec<u8>, pos: usize },
+}
+
+struct Block {
+    number: u32,
+    data: Vec<u8>,
+}
+
+struct FileState {
+    mode: FileMode,
+    block_size: u16,
+    next_block: u32,
+    complete: bool,
+    /// last block read, retained so that a repeated READ can be answered
+    last_read: Option<Block>,
+    source: Source,
+}
+
+impl FileState {
+    fn new(mode: FileMode, block_size: u16, source: Source) -> Self {
+        Self {
+            mode,
+            block_size,
+            next_block: 0,
+            complete: false,
+            last_read: None,
+            source,
+        }
+    }
+
+    fn is_writable(&self) -> bool {
+        matches!(self.mode, FileMode::Write | FileMode::Append)
+    }
+}
+
+/// Tracks open files and validates requests before they reach the user's file system
+pub(crate) struct FileServer {
+    max_block_size: u16,
+    next_handle: u32,
+    files: HashMap<u32, FileState>,
+}
+
+impl FileServer {
+    pub(crate) fn new(max_block_size: u16) -> Self {
+        Self {
+            max_block_size,
+            next_handle: 1,
+            files: HashMap::new(),
+        }
+    }
+
+    fn allocate_handle(&mut self) -> u32 {
+        loop {
+            let handle = self.next_handle;
+            self.next_handle = self.next_handle.wrapping_add(1);
+            // zero is never a valid handle
+            if handle != 0 && !self.files.contains_key(&handle) {
+                return handle;
+            }
+        }
+    }
+
+    fn negotiate_block_size(&self, requested: u16) -> u16 {
+        if requested == 0 {
+            self.max_block_size
+        } else {
+            requested.min(self.max_block_size)
+        }
+    }
+
+    pub(crate) fn authenticate(
+        &mut self,
+        fs: &mut dyn OutstationFileSystem,
+        obj: &Group70Var2,
+    ) -> FileResponse {
+        let key = match fs.authenticate(obj.user_name, obj.password) {
+            Some(key) => key.into(),
+            None => {
+                tracing::warn!("file authentication denied for user: {}", obj.user_name);
+                0
+            }
+        };
+        FileResponse::Authenticate(key)
+    }
+
+    pub(crate) fn open(
+        &mut self,
+        fs: &mut dyn OutstationFileSystem,
+        obj: &Group70Var3,
+    ) -> FileResponse {
+        let block_size = self.negotiate_block_size(obj.max_block_size);
+
+        let source = match obj.mode {
+            FileMode::Read => {
+                // reading a directory returns the listing
+                match fs.stat(obj.file_name) {
+                    Ok(info) if info.file_type == FileType::Directory => {
+                        match Self::encode_directory(fs, obj.file_name) {
+                            Ok(data) => Source::Directory { data, pos: 0 },
+                            Err(status) => {
+                                return FileResponse::command_status(0, obj.request_id, status)
+                            }
+                        }
+                    }
+                    _ => Source::FileSystem,
+                }
+            }
+            FileMode::Write | FileMode::Append => Source::FileSystem,
+            FileMode::Null | FileMode::Reserved(_) => {
+                tracing::warn!("unable to open file with mode: {:?}", obj.mode);
+                return FileResponse::command_status(0, obj.request_id, FileStatus::InvalidMode);
+            }
+        };
+
+        let handle = self.allocate_handle();
+
+        let size = match &source {
+            Source::Directory { data, .. } => data.len() as u32,
+            Source::FileSystem => {
+                let request = FileOpenRequest {
+                    file_name: obj.file_name,
+                    mode: obj.mode,
+                    auth_key: AuthKey::new(obj.auth_key),
+                    file_size: obj.file_size,
+                    time_created: obj.time_of_creation,
+                    permissions: obj.permissions,
+                };
+                match fs.open(FileHandle::new(handle), request) {
+                    Ok(size) => size,
+                    Err(status) => {
+                        return FileResponse::command_status(0, obj.request_id, status);
+                    }
+                }
+            }
+        };
+
+        self.files
+            .insert(handle, FileState::new(obj.mode, block_size, source));
+
+        FileResponse::CommandStatus {
+            handle,
+            size,
+            block_size,
+            request_id: obj.request_id,
+            status: FileStatus::Success,
+        }
+    }
+
+    fn encode_directory(
+        fs: &mut dyn OutstationFileSystem,
+        dir_name: &str,
+    ) -> Result<Vec<u8>, FileStatus> {
+        let entries = fs.list_directory(dir_name)?;
+        let mut data = Vec::new();
+        for entry in entries.iter() {
+            let obj = entry.to_g70v7(0);
+            // each descriptor is fixed size plus the length of the name
+            let mut buffer = vec![0; 20 + entry.name.len()];
+            let mut cursor = WriteCursor::new(&mut buffer);
+            if let Err(err) = obj.write(&mut cursor) {
+                tracing::warn!(
+                    "unable to encode directory entry '{}': {:?}",
+                    entry.name,
+                    err
+                );
+                return Err(FileStatus::Fatal);
+            }
+            data.extend_from_slice(cursor.written());
+        }
+        Ok(data)
+    }
+
+    pub(crate) fn close(
+        &mut self,
+        fs: &mut dyn OutstationFileSystem,
+        obj: &Group70Var4,
+    ) -> FileResponse {
+        self.close_or_abort(fs, obj, false)
+    }
+
+    pub(crate) fn abort(
+        &mut self,
+        fs: &mut dyn OutstationFileSystem,
+        obj: &Group70Var4,
+    ) -> FileResponse {
+        self.close_or_abort(fs, obj, true)
+    }
+
+    /// abort every file left open, e.g. when the session ends or the link drops
+    pub(crate) fn abort_all(&mut self, fs: &mut dyn OutstationFileSystem) {
+        for (handle, state) in self.files.drain() {
+            if let Source::FileSystem = state.source {
+                if let Err(status) = fs.abort(FileHandle::new(handle)) {
+                    tracing::warn!("unable to abort file with handle {}: {:?}", handle, status);
+                }
+            }
+        }
+    }
+
+    fn close_or_abort(
+        &mut self,
+        fs: &mut dyn OutstationFileSystem,
+        obj: &Group70Var4,
+        abort: bool,
+    ) -> FileResponse {
+        let status = match self.files.remove(&obj.file_handle) {
+            None => {
+                tracing::warn!("no open file with handle: {}", obj.file_handle);
+                FileStatus::InvalidHandle
+            }
+            Some(state) => {
+                let result = match state.source {
+                    Source::Directory { .. } => Ok(()),
+                    Source::FileSystem => {
+                        let handle = FileHandle::new(obj.file_handle);
+                        if abort {
+                            fs.abort(handle)
+                        } else {
+                            fs.close(handle)
+                        }
+                    }
+                };
+                match result {
+                    Ok(()) => FileStatus::Success,
+                    Err(status) => status,
+                }
+            }
+        };
+
+        FileResponse::command_status(obj.file_handle, obj.request_id, status)
+    }
+
+    pub(crate) fn delete(
+        &mut self,
+        fs: &mut dyn OutstationFileSystem,
+        obj: &Group70Var3,
+    ) -> FileResponse {
+        let status = match fs.delete(obj.file_name, AuthKey::new(obj.auth_key)) {
+            Ok(()) => FileStatus::Success,
+            Err(status) => status,
+        };
+        FileResponse::command_status(0, obj.request_id, status)
+    }
+
+    pub(crate) fn get_info(
+        &mut self,
+        fs: &mut dyn OutstationFileSystem,
+        obj: &Group70Var7,
+    ) -> FileResponse {
+        match fs.stat(obj.file_name) {
+            Ok(info) => FileResponse::Info {
+                info,
+                request_id: obj.request_id,
+            },
+            Err(status) => FileResponse::command_status(0, obj.request_id, status),
+        }
+    }
+
+    pub(crate) async fn read(
+        &mut self,
+        fs: &mut dyn OutstationFileSystem,
+        obj: &Group70Var5<'_>,
+    ) -> FileResponse {
+        let error = |status: FileStatus| FileResponse::TransportStatus {
+            handle: obj.file_handle,
+            block: obj.block_number,
+            status,
+        };
+
+        let state = match self.files.get_mut(&obj.file_handle) {
+            None => {
+                tracing::warn!("no open file with handle: {}", obj.file_handle);
+                return error(FileStatus::InvalidHandle);
+            }
+            Some(x) => x,
+        };
+
+        if state.mode != FileMode::Read {
+            tracing::warn!("file {} not opened for reading", obj.file_handle);
+            return error(FileStatus::InvalidMode);
+        }
+
+        let requested = BlockNumber::new(obj.block_number).bottom_bits();
+
+        // the master is asking for the block we just sent
+        if let Some(last) = &state.last_read {
+            if BlockNumber::new(last.number).bottom_bits() == requested {
+                return FileResponse::Transport {
+                    handle: obj.file_handle,
+                    block: last.number,
+                    data: last.data.clone(),
+                };
+            }
+        }
+
+        if state.complete || requested != state.next_block {
+            tracing::warn!(
+                "expected read of block {} but master requested {}",
+                state.next_block,
+                requested
+            );
+            return error(FileStatus::BlockSeq);
+        }
+
+        let block_size = state.block_size;
+        let data = match &mut state.source {
+            Source::Directory { data, pos } => {
+                let end = data.len().min(*pos + block_size as usize);
+                let block = data[*pos..end].to_vec();
+                *pos = end;
+                block
+            }
+            Source::FileSystem => {
+                match fs
+                    .read_block(FileHandle::new(obj.file_handle), block_size)
+                    .get()
+                    .await
+                {
+                    Ok(data) => data,
+                    Err(status) => return error(status),
+                }
+            }
+        };
+
+        if data.len() > block_size as usize {
+            tracing::error!(
+                "file system returned {} bytes which exceeds the block size of {}",
+                data.len(),
+                block_size
+            );
+            return error(FileStatus::Fatal);
+        }
+
+        let mut block = BlockNumber::new(requested);
+        if data.len() < block_size as usize {
+            block.set_last();
+            state.complete = true;
+        } else {
+            let mut next = block;
+            if next.increment().is_err() {
+                tracing::warn!("file exceeds the maximum number of blocks");
+                return error(FileStatus::Fatal);
+            }
+            state.next_block = next.bottom_bits();
+        }
+
+        state.last_read = Some(Block {
+            number: block.wire_value(),
+            data: data.clone(),
+        });
+
+        FileResponse::Transport {
+            handle: obj.file_handle,
+            block: block.wire_value(),
+            data,
+        }
+    }
+
+    pub(crate) async fn write(
+        &mut self,
+        fs: &mut dyn OutstationFileSystem,
+        obj: &Group70Var5<'_>,
+    ) -> FileResponse {
+        let status = |status: FileStatus| FileResponse::TransportStatus {
+            handle: obj.file_handle,
+            block: obj.block_number,
+            status,
+        };
+
+        let state = match self.files.get_mut(&obj.file_handle) {
+            None => {
+                tracing::warn!("no open file with handle: {}", obj.file_handle);
+                return status(FileStatus::InvalidHandle);
+            }
+            Some(x) => x,
+        };
+
+        if !state.is_writable() {
+            tracing::warn!("file {} not opened for writing", obj.file_handle);
+            return status(FileStatus::InvalidMode);
+        }
+
+        let block = BlockNumber::new(obj.block_number);
+
+        if state.complete || block.bottom_bits() != state.next_block {
+            tracing::warn!(
+                "expected write of block {} but master sent {}",
+                state.next_block,
+                block.bottom_bits()
+            );
+            return status(FileStatus::BlockSeq);
+        }
+
+        if obj.file_data.len() > state.block_size as usize {
+            tracing::warn!(
+                "received {} bytes which exceeds the block size of {}",
+                obj.file_data.len(),
+                state.block_size
+            );
+            return status(FileStatus::BufferOverrun);
+        }
+
+        let result = fs
+            .write_block(
+                FileHandle::new(obj.file_handle),
+                obj.file_data,
+                block.is_last(),
+            )
+            .get()
+            .await;
+
+        if let Err(err) = result {
+            return status(err);
+        }
+
+        if block.is_last() {
+            state.complete = true;
+        } else {
+            let mut next = block;
+            if next.increment().is_err() {
+                tracing::warn!("file exceeds the maximum number of blocks");
+                return status(FileStatus::Fatal);
+            }
+            state.next_block = next.bottom_bits();
+        }
+
+        status(FileStatus::Success)
+    }
+}
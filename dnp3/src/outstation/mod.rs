@@ -1,22 +1,31 @@
+pub use auth::*;
 pub use config::*;
+pub use file::*;
+pub use statistics::*;
 pub use traits::*;
 
+use std::sync::{Arc, Mutex};
+
 use crate::app::Shutdown;
 use crate::decode::DecodeLevel;
-use crate::outstation::database::{Database, DatabaseHandle};
+use crate::outstation::database::{Association, Database, DatabaseHandle};
 use crate::outstation::task::{ConfigurationChange, OutstationMessage};
+use crate::transport::TransportCounters;
 use crate::util::channel::Sender;
 
 /// database API to add/remove/update values
 pub mod database;
 
+mod auth;
 mod config;
 /// functionality for processing control requests
 pub(crate) mod control;
 /// handling of deferred read requests
 pub(crate) mod deferred;
+mod file;
 /// outstation session
 pub(crate) mod session;
+mod statistics;
 /// async outstation task
 pub(crate) mod task;
 mod traits;
@@ -28,7 +37,15 @@ mod tests;
 #[derive(Clone)]
 pub struct OutstationHandle {
     database: DatabaseHandle,
+    /// association of the master with the event buffer of the database
+    association: Association,
     sender: Sender<OutstationMessage>,
+    /// configuration the outstation is currently running with
+    config: Arc<Mutex<OutstationConfig>>,
+    /// counters maintained by the session
+    statistics: Arc<Mutex<SessionStatistics>>,
+    /// counters maintained by the transport layer
+    transport: Arc<TransportCounters>,
 }
 
 impl OutstationHandle {
@@ -53,6 +70,69 @@ impl OutstationHandle {
         Ok(())
     }
 
+    /// Apply a new configuration to the running outstation
+    ///
+    /// Fields that can be changed safely take effect without interrupting communications. This
+    /// includes the master address, timeouts, features other than [`Features::self_address`],
+    /// unsolicited settings, class 0 configuration and the event buffer limits. Buffered events are
+    /// retained when the event buffer limits change unless a type or class holds more events than
+    /// its new limit.
+    ///
+    /// The remaining fields are reported in [`ReconfigureResult::restart_required`] and keep their
+    /// current values. This includes a master address that is already used by another master
+    /// sharing the database.
+    pub async fn reconfigure(
+        &mut self,
+        config: OutstationConfig,
+    ) -> Result<ReconfigureResult, Shutdown> {
+        let (applied, restart_required) = {
+            let mut applied = *self.config.lock().unwrap();
+            let mut restart_required = applied.restart_required(&config);
+            if self
+                .database
+                .is_master_registered(self.association, config.master_address)
+            {
+                restart_required.push(OutstationConfigField::MasterAddress);
+                applied.apply_runtime_fields(&OutstationConfig {
+                    master_address: applied.master_address,
+                    ..config
+                });
+            } else {
+                applied.apply_runtime_fields(&config);
+            }
+            (applied, restart_required)
+        };
+
+        self.sender
+            .send(ConfigurationChange::Reconfigure(Box::new(applied)).into())
+            .await?;
+
+        // only record the new configuration once the session has accepted it
+        *self.config.lock().unwrap() = applied;
+        self.database
+            .set_association_master(self.association, applied.master_address);
+        self.database
+            .set_association_policy(self.association, applied.offline_association_policy);
+
+        let discarded_events = self
+            .database
+            .reconfigure(applied.class_zero, applied.event_buffer_config);
+
+        Ok(ReconfigureResult {
+            restart_required,
+            discarded_events,
+        })
+    }
+
+    /// Get a snapshot of the counters maintained by the outstation
+    pub fn get_statistics(&self) -> OutstationStatistics {
+        OutstationStatistics {
+            session: self.statistics.lock().unwrap().clone(),
+            events: self.database.event_statistics(),
+            transport: self.transport.get(),
+        }
+    }
+
     /// Enable communications
     pub async fn enable(&mut self) -> Result<(), Shutdown> {
         self.sender.send(OutstationMessage::Enable).await?;
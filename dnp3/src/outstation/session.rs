@@ -1,18 +1,24 @@
 use std::borrow::BorrowMut;
+use std::sync::{Arc, Mutex};
 
 use tracing::Instrument;
 use xxhash_rust::xxh64::xxh64;
 
+use crate::app::activation::StatusElement;
 use crate::app::attr::Attribute;
+use crate::app::auth::{is_critical, Group120Var10, Group120Var11};
 use crate::app::control::CommandStatus;
+use crate::app::data_set::Group87Var1;
 use crate::app::format::write::HeaderWriter;
 use crate::app::gen::all::AllObjectsVariation;
 use crate::app::gen::count::CountVariation;
 use crate::app::gen::ranged::RangedVariation;
 use crate::app::parse::count::CountSequence;
+use crate::app::parse::free_format::FreeFormatVariation;
 use crate::app::parse::parser::{HeaderCollection, HeaderDetails, ObjectHeader, Request};
 use crate::app::variations::{
-    Group34Var1, Group34Var2, Group34Var3, Group50Var1, Group50Var3, Group52Var1, Group52Var2,
+    Group34Var1, Group34Var2, Group34Var3, Group50Var1, Group50Var3, Group50Var4, Group52Var1,
+    Group52Var2,
 };
 use crate::app::*;
 use crate::decode::DecodeLevel;
@@ -20,11 +26,20 @@ use crate::link::error::LinkError;
 use crate::link::header::BroadcastConfirmMode;
 use crate::link::EndpointAddress;
 use crate::master::EventClasses;
-use crate::outstation::config::{Feature, OutstationConfig};
+use crate::outstation::auth::{
+    get_update_key, AggressiveRequest, AuthConfig, AuthFailure, EncryptedUpdateKey, OutstationAuth,
+    PendingRequest,
+};
+use crate::outstation::config::{Feature, OutstationConfig, UnsolicitedHoldConfig};
 use crate::outstation::control::collection::{ControlCollection, ControlTransaction};
 use crate::outstation::control::select::SelectState;
-use crate::outstation::database::{DatabaseHandle, ResponseInfo};
+use crate::outstation::database::{
+    Association, DatabaseHandle, EventClass, Get, ResponseInfo, SecurityStatisticIndex, Update,
+    UpdateInfo, UpdateOptions,
+};
 use crate::outstation::deferred::DeferredRead;
+use crate::outstation::file::{FileResponse, FileServer, FileTransportMode};
+use crate::outstation::statistics::SessionStatistics;
 use crate::outstation::task::{ConfigurationChange, OutstationMessage};
 use crate::outstation::traits::*;
 use crate::transport::{
@@ -37,16 +52,27 @@ use crate::util::phys::PhysLayer;
 
 use crate::app::gen::prefixed::PrefixedVariation;
 use crate::app::parse::bit::BitSequence;
+use crate::app::parse::bytes::PrefixedBytesSequence;
 use crate::app::parse::prefix::Prefix;
 use crate::app::parse::traits::{FixedSizeVariation, Index};
 use crate::util::session::{Enabled, RunError, StopReason};
 
+/// response header, free-format object header, and the fixed size fields of g70v5
+const FILE_TRANSPORT_OVERHEAD: usize = ResponseHeader::LENGTH + 6 + 8;
+
 #[derive(Copy, Clone)]
 enum TimeoutStatus {
     Yes,
     No,
 }
 
+#[derive(Copy, Clone, Debug)]
+enum ApplicationFunction {
+    Initialize,
+    Start,
+    Stop,
+}
+
 #[derive(Copy, Clone)]
 enum ControlType {
     Select,
@@ -157,10 +183,14 @@ pub(crate) struct SessionConfig {
     broadcast: Feature,
     unsolicited: Feature,
     respond_to_any_master: Feature,
+    command_events: Feature,
+    freeze_engine: Feature,
     max_unsolicited_retries: Option<usize>,
     unsolicited_retry_delay: std::time::Duration,
+    unsolicited_hold: UnsolicitedHoldConfig,
     keep_alive_timeout: Option<std::time::Duration>,
     max_controls_per_request: Option<u16>,
+    authentication: Option<AuthConfig>,
 }
 pub(crate) struct SessionParameters {
     max_read_headers_per_request: u16,
@@ -176,14 +206,29 @@ impl From<OutstationConfig> for SessionConfig {
             broadcast: config.features.broadcast,
             unsolicited: config.features.unsolicited,
             respond_to_any_master: config.features.respond_to_any_master,
+            command_events: config.features.command_events,
+            freeze_engine: config.features.freeze_engine,
             max_unsolicited_retries: config.max_unsolicited_retries,
             unsolicited_retry_delay: config.unsolicited_retry_delay,
+            unsolicited_hold: config.unsolicited_hold,
             keep_alive_timeout: config.keep_alive_timeout,
             max_controls_per_request: config.max_controls_per_request,
+            authentication: config.authentication,
         }
     }
 }
 
+impl SessionConfig {
+    /// apply the fields that may change while the session is running
+    fn reconfigure(&mut self, config: &OutstationConfig) {
+        let authentication = self.authentication;
+        *self = SessionConfig {
+            authentication,
+            ..SessionConfig::from(*config)
+        };
+    }
+}
+
 impl From<OutstationConfig> for SessionParameters {
     fn from(x: OutstationConfig) -> Self {
         SessionParameters {
@@ -203,6 +248,14 @@ enum UnsolicitedState {
     Ready(Option<tokio::time::Instant>),
 }
 
+/// time at which each class first had events that were not reported in an unsolicited response
+#[derive(Copy, Clone, Default)]
+struct UnsolicitedHoldState {
+    class1: Option<tokio::time::Instant>,
+    class2: Option<tokio::time::Instant>,
+    class3: Option<tokio::time::Instant>,
+}
+
 /// state that mutates while the session runs
 struct SessionState {
     restart_iin_asserted: bool,
@@ -210,24 +263,28 @@ struct SessionState {
     last_valid_request: Option<LastValidRequest>,
     select: Option<SelectState>,
     unsolicited: UnsolicitedState,
+    unsolicited_hold: UnsolicitedHoldState,
     unsolicited_seq: Sequence,
     deferred_read: DeferredRead,
     last_recorded_time: Option<tokio::time::Instant>,
     last_broadcast_type: Option<BroadcastConfirmMode>,
+    files: FileServer,
 }
 
 impl SessionState {
-    fn new(max_read_headers: u16) -> Self {
+    fn new(max_read_headers: u16, max_file_block_size: u16) -> Self {
         Self {
             enabled_unsolicited_classes: EventClasses::none(),
             restart_iin_asserted: true,
             last_valid_request: None,
             select: None,
             unsolicited: UnsolicitedState::NullRequired,
+            unsolicited_hold: UnsolicitedHoldState::default(),
             unsolicited_seq: Sequence::default(),
             deferred_read: DeferredRead::new(max_read_headers),
             last_recorded_time: None,
             last_broadcast_type: None,
+            files: FileServer::new(max_file_block_size),
         }
     }
 
@@ -246,11 +303,22 @@ pub(crate) struct OutstationSession {
     unsol_tx_buffer: Buffer,
     config: SessionConfig,
     destination: FragmentAddr,
+    /// association of the master with the event buffer of the database
+    association: Association,
     state: SessionState,
     application: Box<dyn OutstationApplication>,
     info: Box<dyn OutstationInformation>,
     control_handler: Box<dyn ControlHandler>,
     next_link_status: Option<tokio::time::Instant>,
+    auth: Option<OutstationAuth>,
+    /// counters shared with the handle
+    statistics: Arc<Mutex<SessionStatistics>>,
+}
+
+impl Drop for OutstationSession {
+    fn drop(&mut self) {
+        self.abort_open_files();
+    }
 }
 
 enum Confirm {
@@ -320,28 +388,43 @@ impl OutstationSession {
         initial_state: Enabled,
         messages: Receiver<OutstationMessage>,
         destination: FragmentAddr,
+        association: Association,
         config: SessionConfig,
         param: SessionParameters,
         application: Box<dyn OutstationApplication>,
         information: Box<dyn OutstationInformation>,
         control_handler: Box<dyn ControlHandler>,
+        statistics: Arc<Mutex<SessionStatistics>>,
     ) -> Self {
         let next_link_status = config
             .keep_alive_timeout
             .map(|delay| tokio::time::Instant::now() + delay);
 
+        // file blocks must fit in either a solicited or an unsolicited response
+        let max_file_block_size = param
+            .sol_tx_buffer_size
+            .value()
+            .min(param.unsol_tx_buffer_size.value())
+            .saturating_sub(FILE_TRANSPORT_OVERHEAD)
+            .min(u16::MAX as usize) as u16;
+
+        let auth = config.authentication.map(OutstationAuth::new);
+
         Self {
             enabled: initial_state,
             messages,
             config,
             sol_tx_buffer: param.sol_tx_buffer_size.create_buffer(),
             unsol_tx_buffer: param.unsol_tx_buffer_size.create_buffer(),
-            state: SessionState::new(param.max_read_headers_per_request),
+            state: SessionState::new(param.max_read_headers_per_request, max_file_block_size),
             application,
             info: information,
             control_handler,
             next_link_status,
             destination,
+            association,
+            auth,
+            statistics,
         }
     }
 
@@ -361,8 +444,21 @@ impl OutstationSession {
     }
 
     /// used when the there is no running IO to process outstation messages
-    pub(crate) async fn process_next_message(&mut self) -> Result<(), StopReason> {
-        self.handle_next_message().await
+    pub(crate) async fn process_next_message(
+        &mut self,
+        database: &mut DatabaseHandle,
+    ) -> Result<(), StopReason> {
+        // scheduled freezes are performed even when there is no session
+        let next_action =
+            NextIdleAction::SleepUntilEvent.select_earliest(database.next_freeze_deadline());
+
+        tokio::select! {
+            res = self.handle_next_message() => res.map(|_| ()),
+            _ = Self::sleep_only(next_action) => {
+                database.run_scheduled_freezes();
+                Ok(())
+            }
+        }
     }
 
     pub(crate) async fn run(
@@ -375,11 +471,21 @@ impl OutstationSession {
         loop {
             if let Err(err) = self.run_idle_state(io, reader, writer, database).await {
                 self.state.reset();
+                self.abort_open_files();
+                if let Some(auth) = &mut self.auth {
+                    auth.reset();
+                }
                 return err;
             }
         }
     }
 
+    fn abort_open_files(&mut self) {
+        if let Some(fs) = self.application.file_system() {
+            self.state.files.abort_all(fs);
+        }
+    }
+
     async fn write_unsolicited(
         &mut self,
         io: &mut PhysLayer,
@@ -424,6 +530,7 @@ impl OutstationSession {
         database: &DatabaseHandle,
     ) -> Result<Response, LinkError> {
         response.header.iin |= self.get_response_iin(database);
+        self.increment_statistic(database, SecurityStatisticIndex::TotalMessagesSent);
 
         // Determine if we need to ask for confirmation due to broadcast
         if let Some(BroadcastConfirmMode::Mandatory) = self.state.last_broadcast_type {
@@ -449,14 +556,16 @@ impl OutstationSession {
         let _ = response.header.write(&mut cursor);
 
         let len = std::cmp::max(cursor.written().len(), response.size);
+        let fragment = self.sol_tx_buffer.get(len).unwrap();
+
+        if response.header.function == ResponseFunction::AuthResponse {
+            if let Some(auth) = &mut self.auth {
+                auth.on_challenge_sent(fragment);
+            }
+        }
 
         writer
-            .write(
-                io,
-                self.config.decode_level,
-                respond_to,
-                self.sol_tx_buffer.get(len).unwrap(),
-            )
+            .write(io, self.config.decode_level, respond_to, fragment)
             .await
     }
 
@@ -467,6 +576,9 @@ impl OutstationSession {
         writer: &mut TransportWriter,
         database: &mut DatabaseHandle,
     ) -> Result<(), RunError> {
+        // perform any scheduled freezes that are due before processing requests
+        database.run_scheduled_freezes();
+
         // handle a request fragment if present
         self.handle_one_request_from_idle(io, reader, writer, database)
             .await?;
@@ -481,7 +593,9 @@ impl OutstationSession {
         // check to see if we should perform a link status check
         self.check_link_status(io, writer).await?;
 
-        let next_action = next_action.select_earliest(self.next_link_status);
+        let next_action = next_action
+            .select_earliest(self.next_link_status)
+            .select_earliest(database.next_freeze_deadline());
 
         // wait for an event
         tokio::select! {
@@ -489,7 +603,7 @@ impl OutstationSession {
                 // make sure an I/O error didn't occur, ending the session
                 frame_read?;
             }
-            _ = database.wait_for_change() => {
+            _ = database.wait_for_change(self.association) => {
                 // wake for unsolicited here
             }
             res = self.sleep_until(next_action) => {
@@ -536,6 +650,11 @@ impl OutstationSession {
                     }
                 }
 
+                // hold the events until a class reaches its count or delay
+                if let Some(action) = self.unsolicited_hold(database) {
+                    return Ok(action);
+                }
+
                 // perform regular unsolicited
                 match self
                     .maybe_perform_unsolicited(io, reader, writer, database)
@@ -552,9 +671,10 @@ impl OutstationSession {
                     }
                     Some(UnsolicitedResult::Confirmed) => {
                         database
-                            .clear_written_events(self.application.as_mut())
+                            .clear_written_events(self.association, self.application.as_mut())
                             .await;
                         self.state.unsolicited = UnsolicitedState::Ready(None);
+                        self.state.unsolicited_hold = UnsolicitedHoldState::default();
                         Ok(NextIdleAction::NoSleep)
                     }
                 }
@@ -562,6 +682,62 @@ impl OutstationSession {
         }
     }
 
+    /// returns how long to wait while events are held, or `None` if the events should be
+    /// reported now or there are no events to report
+    fn unsolicited_hold(&mut self, database: &mut DatabaseHandle) -> Option<NextIdleAction> {
+        let count = database.unwritten_class_count(self.association);
+        let hold = self.config.unsolicited_hold;
+        let now = tokio::time::Instant::now();
+        let enabled = self.state.enabled_unsolicited_classes;
+        let state = &mut self.state.unsolicited_hold;
+
+        let mut held = false;
+        let mut deadline: Option<tokio::time::Instant> = None;
+        for (class, is_enabled, count, held_since) in [
+            (
+                EventClass::Class1,
+                enabled.class1,
+                count.num_class_1,
+                &mut state.class1,
+            ),
+            (
+                EventClass::Class2,
+                enabled.class2,
+                count.num_class_2,
+                &mut state.class2,
+            ),
+            (
+                EventClass::Class3,
+                enabled.class3,
+                count.num_class_3,
+                &mut state.class3,
+            ),
+        ] {
+            if !is_enabled || count == 0 {
+                *held_since = None;
+                continue;
+            }
+
+            let since = *held_since.get_or_insert(now);
+            let hold = hold.get(class);
+            if hold.is_ready(count, since, now) {
+                return None;
+            }
+
+            held = true;
+            if let Some(expires) = hold.deadline(since) {
+                deadline = Some(deadline.map_or(expires, |x| x.min(expires)));
+            }
+        }
+
+        match deadline {
+            Some(deadline) => Some(NextIdleAction::SleepUnit(deadline)),
+            // the held classes can only be released by new events
+            None if held => Some(NextIdleAction::SleepUntilEvent),
+            None => None,
+        }
+    }
+
     async fn check_link_status(
         &mut self,
         io: &mut PhysLayer,
@@ -631,7 +807,11 @@ impl OutstationSession {
     fn write_unsolicited_data(&mut self, database: &mut DatabaseHandle) -> Option<Response> {
         let mut cursor = self.unsol_tx_buffer.write_cursor();
         let _ = cursor.skip(ResponseHeader::LENGTH);
-        let count = database.write_unsolicited(self.state.enabled_unsolicited_classes, &mut cursor);
+        let count = database.write_unsolicited(
+            self.association,
+            self.state.enabled_unsolicited_classes,
+            &mut cursor,
+        );
 
         if count == 0 {
             return None;
@@ -661,6 +841,7 @@ impl OutstationSession {
 
         // enter unsolicited confirm wait state
         self.info.enter_unsolicited_confirm_wait(response.seq());
+        self.statistics.lock().unwrap().on_unsolicited_series();
 
         let mut retry_count = RetryCounter::new(self.config.max_unsolicited_retries);
 
@@ -700,12 +881,17 @@ impl OutstationSession {
                     }
 
                     self.info.unsolicited_confirm_timeout(response.seq(), retry);
+                    self.statistics
+                        .lock()
+                        .unwrap()
+                        .on_unsolicited_confirm_timeout();
 
                     if !retry {
                         return Ok(UnsolicitedResult::Timeout);
                     }
 
                     // perform a retry
+                    self.statistics.lock().unwrap().on_unsolicited_retry();
                     self.repeat_unsolicited(io, writer, response).await?;
 
                     // update the deadline
@@ -733,6 +919,10 @@ impl OutstationSession {
             None => return Ok(UnsolicitedWaitResult::ReadNext),
             Some(TransportRequest::Request(info, request)) => {
                 self.on_link_activity();
+                self.statistics
+                    .lock()
+                    .unwrap()
+                    .on_request(request.header.function);
                 (info, request)
             }
             Some(TransportRequest::LinkLayerMessage) => {
@@ -747,6 +937,8 @@ impl OutstationSession {
             }
         };
 
+        self.count_received_message(database, request);
+
         match self.classify(info, request) {
             FragmentType::UnsolicitedConfirm(seq) => {
                 if seq == uns_ecsn {
@@ -760,6 +952,7 @@ impl OutstationSession {
                         "ignoring unsolicited confirm with wrong sequence number ({})",
                         seq.value()
                     );
+                    self.increment_statistic(database, SecurityStatisticIndex::UnexpectedMessages);
                     Ok(UnsolicitedWaitResult::ReadNext)
                 }
             }
@@ -779,6 +972,8 @@ impl OutstationSession {
             }
             FragmentType::MalformedRequest(_, err) => {
                 self.state.deferred_read.clear();
+                self.increment_statistic(database, SecurityStatisticIndex::DiscardedMessages);
+                self.statistics.lock().unwrap().on_malformed_request();
 
                 let seq = request.header.control.seq;
                 let iin = Iin::default() | Iin2::from(err);
@@ -795,13 +990,7 @@ impl OutstationSession {
             FragmentType::NewNonRead(hash, objects) => {
                 self.state.deferred_read.clear();
                 let mut response = self
-                    .handle_non_read(
-                        database,
-                        request.header.function,
-                        request.header.control.seq,
-                        info.id,
-                        objects,
-                    )
+                    .handle_new_non_read(database, info.id, request, objects)
                     .await;
                 if let Some(response) = &mut response {
                     *response = self
@@ -855,47 +1044,57 @@ impl OutstationSession {
         reader: &mut TransportReader,
         deadline: tokio::time::Instant,
     ) -> Result<TimeoutStatus, RunError> {
-        let decode_level = self.config.decode_level;
-        tokio::select! {
-             res = self.sleep_until(NextIdleAction::SleepUnit(deadline)) => {
-                 res?;
-                 Ok(TimeoutStatus::Yes)
-             }
-             res = reader.read(io, decode_level) => {
-                 res?;
-                 Ok(TimeoutStatus::No)
-             }
+        loop {
+            let decode_level = self.config.decode_level;
+            tokio::select! {
+                 res = self.sleep_until(NextIdleAction::SleepUnit(deadline)) => {
+                     res?;
+                     // a configuration change ends the sleep before the deadline
+                     if tokio::time::Instant::now() >= deadline {
+                         return Ok(TimeoutStatus::Yes);
+                     }
+                 }
+                 res = reader.read(io, decode_level) => {
+                     res?;
+                     return Ok(TimeoutStatus::No);
+                 }
+            }
         }
     }
 
-    async fn sleep_until(&mut self, next_action: NextIdleAction) -> Result<(), RunError> {
-        async fn sleep_only(next_action: NextIdleAction) {
-            match next_action {
-                NextIdleAction::NoSleep => {}
-                NextIdleAction::SleepUnit(x) => tokio::time::sleep_until(x).await,
-                NextIdleAction::SleepUntilEvent => crate::util::future::forever().await,
-            }
+    async fn sleep_only(next_action: NextIdleAction) {
+        match next_action {
+            NextIdleAction::NoSleep => {}
+            NextIdleAction::SleepUnit(x) => tokio::time::sleep_until(x).await,
+            NextIdleAction::SleepUntilEvent => crate::util::future::forever().await,
         }
+    }
 
+    /// sleep until the next action is due, returning early if the configuration changes so that
+    /// the caller can re-evaluate what to do next
+    async fn sleep_until(&mut self, next_action: NextIdleAction) -> Result<(), RunError> {
         loop {
             tokio::select! {
-                 _ = sleep_only(next_action) => {
+                 _ = Self::sleep_only(next_action) => {
                         return Ok(());
                  }
                  res = self.handle_next_message() => {
-                     res?;
+                     if res? {
+                         return Ok(());
+                     }
                  }
             }
         }
     }
 
-    async fn handle_next_message(&mut self) -> Result<(), StopReason> {
+    /// process the next message, returning true if it changed the configuration
+    async fn handle_next_message(&mut self) -> Result<bool, StopReason> {
         match self.messages.receive().await? {
             OutstationMessage::Shutdown => Err(StopReason::Shutdown),
             OutstationMessage::Enable => {
                 tracing::info!("enable communication");
                 self.enabled = Enabled::Yes;
-                Ok(())
+                Ok(false)
             }
             OutstationMessage::Disable => {
                 tracing::info!("disable communication");
@@ -904,7 +1103,7 @@ impl OutstationSession {
             }
             OutstationMessage::Configuration(change) => {
                 self.handle_config_change(change);
-                Ok(())
+                Ok(true)
             }
         }
     }
@@ -915,6 +1114,11 @@ impl OutstationSession {
                 tracing::info!("decode level changed to: {:?}", level);
                 self.config.decode_level = level;
             }
+            ConfigurationChange::Reconfigure(config) => {
+                tracing::info!("configuration changed");
+                self.config.reconfigure(&config);
+                self.change_master_address(config.master_address);
+            }
         }
     }
 
@@ -925,7 +1129,7 @@ impl OutstationSession {
         writer: &mut TransportWriter,
         database: &mut DatabaseHandle,
     ) -> Result<(), RunError> {
-        if let Some(x) = self.state.deferred_read.select(database) {
+        if let Some(x) = self.state.deferred_read.select(database, self.association) {
             tracing::info!("handling deferred READ request");
             let (response, mut series) = self.format_read_response(database, true, x.seq, x.iin2);
             let response = self
@@ -1018,16 +1222,24 @@ impl OutstationSession {
         database: &mut DatabaseHandle,
     ) -> Option<LastValidRequest> {
         self.info.process_request_from_idle(request.header);
+        self.statistics
+            .lock()
+            .unwrap()
+            .on_request(request.header.function);
 
         let seq = request.header.control.seq;
 
+        self.count_received_message(database, request);
+
         match self.classify(info, request) {
             FragmentType::MalformedRequest(hash, err) => {
+                self.increment_statistic(database, SecurityStatisticIndex::DiscardedMessages);
+                self.statistics.lock().unwrap().on_malformed_request();
                 let response = Response::empty_solicited(seq, Iin::default() | Iin2::from(err));
                 Some(LastValidRequest::new(seq, hash, Some(response), None))
             }
             FragmentType::NewRead(hash, objects) => {
-                let (response, series) = self.format_first_read_response(database, seq, objects);
+                let (response, series) = self.handle_read(database, seq, objects).await;
                 Some(LastValidRequest::new(seq, hash, Some(response), series))
             }
             FragmentType::RepeatRead(hash, _, objects) => {
@@ -1035,12 +1247,12 @@ impl OutstationSession {
                 // also reply to duplicate READ requests from idle, but this
                 // is plainly wrong since it can't possibly handle a multi-fragmented
                 // response correctly. Answering a repeat READ with a fresh response is harmless
-                let (response, series) = self.format_first_read_response(database, seq, objects);
+                let (response, series) = self.handle_read(database, seq, objects).await;
                 Some(LastValidRequest::new(seq, hash, Some(response), series))
             }
             FragmentType::NewNonRead(hash, objects) => {
                 let response = self
-                    .handle_non_read(database, request.header.function, seq, info.id, objects)
+                    .handle_new_non_read(database, info.id, request, objects)
                     .await;
                 Some(LastValidRequest::new(seq, hash, response, None))
             }
@@ -1063,6 +1275,7 @@ impl OutstationSession {
                     "ignoring solicited CONFIRM from idle state with seq: {}",
                     seq.value()
                 );
+                self.increment_statistic(database, SecurityStatisticIndex::UnexpectedMessages);
                 None
             }
             FragmentType::UnsolicitedConfirm(seq) => {
@@ -1070,6 +1283,7 @@ impl OutstationSession {
                     "ignoring unsolicited CONFIRM from idle state with seq: {}",
                     seq.value()
                 );
+                self.increment_statistic(database, SecurityStatisticIndex::UnexpectedMessages);
                 None
             }
         }
@@ -1091,6 +1305,9 @@ impl OutstationSession {
             TransportRequestError::RequestValidationError(seq, _) => Some(seq),
         };
 
+        self.increment_statistic(database, SecurityStatisticIndex::DiscardedMessages);
+        self.statistics.lock().unwrap().on_malformed_request();
+
         if let Some(seq) = seq {
             let iin = Iin::default() | Iin2::NO_FUNC_CODE_SUPPORT;
             self.write_solicited(
@@ -1105,13 +1322,29 @@ impl OutstationSession {
         Ok(())
     }
 
+    async fn handle_read(
+        &mut self,
+        database: &mut DatabaseHandle,
+        seq: Sequence,
+        object_headers: HeaderCollection<'_>,
+    ) -> (Response, Option<ResponseSeries>) {
+        if Self::is_file_transport(object_headers) {
+            let response = self
+                .handle_file_request(database, FunctionCode::Read, seq, object_headers)
+                .await;
+            (response, None)
+        } else {
+            self.format_first_read_response(database, seq, object_headers)
+        }
+    }
+
     fn format_first_read_response(
         &mut self,
         database: &mut DatabaseHandle,
         seq: Sequence,
         object_headers: HeaderCollection,
     ) -> (Response, Option<ResponseSeries>) {
-        let iin2 = database.select(&object_headers);
+        let iin2 = database.select(self.association, &object_headers);
         self.format_read_response(database, true, seq, iin2)
     }
 
@@ -1125,7 +1358,7 @@ impl OutstationSession {
         let (len, info) = {
             let mut cursor = self.sol_tx_buffer.write_cursor();
             let _ = cursor.skip(ResponseHeader::LENGTH);
-            let info = database.write_response_headers(&mut cursor);
+            let info = database.write_response_headers(self.association, &mut cursor);
             (cursor.written().len(), info)
         };
 
@@ -1137,142 +1370,729 @@ impl OutstationSession {
         (Response::new(header, len), info.get_response_series(seq))
     }
 
-    async fn handle_non_read(
+    /// authenticate new non-read requests before processing them if secure authentication is enabled
+    async fn handle_new_non_read(
         &mut self,
         database: &mut DatabaseHandle,
-        function: FunctionCode,
-        seq: Sequence,
         frame_id: u32,
+        request: Request<'_>,
         object_headers: HeaderCollection<'_>,
     ) -> Option<Response> {
-        let mut result = match function {
-            FunctionCode::Write => Some(self.handle_write(seq, object_headers, database).await),
-            // these function don't process objects
-            FunctionCode::DelayMeasure => Some(self.handle_delay_measure(seq)),
-            FunctionCode::RecordCurrentTime => Some(self.handle_record_current_time(seq)),
-            FunctionCode::ColdRestart => {
-                let delay = self.application.cold_restart();
-                Some(self.handle_restart(seq, delay))
-            }
-            FunctionCode::WarmRestart => {
-                let delay = self.application.warm_restart();
-                Some(self.handle_restart(seq, delay))
+        let function = request.header.function;
+        let seq = request.header.control.seq;
+
+        // a challenged request may only be completed by the very next request
+        let pending = match &mut self.auth {
+            None => {
+                return self
+                    .handle_non_read(database, function, seq, frame_id, object_headers)
+                    .await
             }
-            // controls
-            FunctionCode::Select => {
-                self.handle_controls(ControlType::Select, database, seq, frame_id, object_headers)
+            Some(auth) => auth.take_pending(),
+        };
+
+        match function {
+            FunctionCode::AuthRequest => {
+                self.handle_auth_request(database, seq, frame_id, request, object_headers, pending)
                     .await
             }
-            FunctionCode::Operate => {
-                self.handle_controls(
-                    ControlType::Operate,
-                    database,
-                    seq,
-                    frame_id,
-                    object_headers,
-                )
-                .await
+            // only used by the master to report errors which the outstation doesn't act on
+            FunctionCode::AuthRequestNoAck => {
+                self.increment_statistic(database, SecurityStatisticIndex::ErrorMessagesReceived);
+                None
             }
-            FunctionCode::DirectOperate => {
-                self.handle_controls(
-                    ControlType::DirectOperate,
-                    database,
-                    seq,
-                    frame_id,
-                    object_headers,
-                )
-                .await
+            _ if is_critical(function) => {
+                self.handle_critical_request(database, seq, frame_id, request, object_headers)
+                    .await
             }
-            FunctionCode::DirectOperateNoResponse => {
-                self.handle_controls(
-                    ControlType::DirectOperateNoAck,
-                    database,
+            _ => {
+                self.handle_non_read(database, function, seq, frame_id, object_headers)
+                    .await
+            }
+        }
+    }
+
+    async fn handle_critical_request(
+        &mut self,
+        database: &mut DatabaseHandle,
+        seq: Sequence,
+        frame_id: u32,
+        request: Request<'_>,
+        object_headers: HeaderCollection<'_>,
+    ) -> Option<Response> {
+        let function = request.header.function;
+        self.increment_statistic(database, SecurityStatisticIndex::CriticalMessagesReceived);
+        let auth = self.auth.as_mut()?;
+
+        let aggressive = match AggressiveRequest::parse(request.raw_fragment, object_headers) {
+            Some(x) => x,
+            None => {
+                let mut cursor = self.sol_tx_buffer.write_cursor();
+                let _ = cursor.skip(ResponseHeader::LENGTH);
+                let mut writer = HeaderWriter::new(&mut cursor);
+                auth.challenge(
+                    function,
                     seq,
                     frame_id,
                     object_headers,
-                )
-                .await
+                    request.raw_fragment,
+                    &mut writer,
+                );
+                return Some(Self::auth_response(seq, cursor.written().len()));
             }
-            FunctionCode::ImmediateFreeze => {
-                Some(self.handle_freeze(database, seq, object_headers, FreezeType::ImmediateFreeze))
+        };
+
+        let user = match auth.verify_aggressive(&aggressive) {
+            Ok(user) => user,
+            Err(failure) => {
+                return Some(self.handle_auth_failure(database, seq, aggressive.user, failure))
             }
-            FunctionCode::ImmediateFreezeNoResponse => {
-                self.handle_freeze(database, seq, object_headers, FreezeType::ImmediateFreeze);
-                None
+        };
+
+        match HeaderCollection::parse(object_headers.options(), function, aggressive.objects) {
+            Ok(objects) => {
+                self.execute_authenticated(database, user, function, seq, frame_id, objects)
+                    .await
             }
-            FunctionCode::FreezeClear => {
-                Some(self.handle_freeze(database, seq, object_headers, FreezeType::FreezeAndClear))
+            Err(err) => Some(Response::empty_solicited(
+                seq,
+                Iin::default() | Iin2::from(err),
+            )),
+        }
+    }
+
+    async fn handle_auth_request(
+        &mut self,
+        database: &mut DatabaseHandle,
+        seq: Sequence,
+        frame_id: u32,
+        request: Request<'_>,
+        object_headers: HeaderCollection<'_>,
+        pending: Option<PendingRequest>,
+    ) -> Option<Response> {
+        let auth = self.auth.as_mut()?;
+
+        if let Some(encrypted) = EncryptedUpdateKey::parse(object_headers) {
+            return Some(self.handle_update_key_change(database, seq, &encrypted));
+        }
+
+        let header = match object_headers.get_only_header() {
+            Ok(x) => x,
+            Err(err) => {
+                tracing::warn!(
+                    "AUTH_REQUEST must contain a single object header: {:?}",
+                    err
+                );
+                return Some(Response::empty_solicited(
+                    seq,
+                    Iin::default() | Iin2::PARAMETER_ERROR,
+                ));
             }
-            FunctionCode::FreezeClearNoResponse => {
-                self.handle_freeze(database, seq, object_headers, FreezeType::FreezeAndClear);
-                None
+        };
+
+        let (user, change) = match header.details {
+            HeaderDetails::OneByteCount(1, CountVariation::Group120Var4(objects)) => {
+                (objects.single()?.user, None)
             }
-            FunctionCode::FreezeAtTime => {
-                Some(self.handle_freeze_at_time(database, seq, object_headers))
+            HeaderDetails::TwoByteFreeFormat(1, FreeFormatVariation::Group120Var6(change)) => {
+                (change.user, Some(change))
             }
-            FunctionCode::FreezeAtTimeNoResponse => {
-                self.handle_freeze_at_time(database, seq, object_headers);
-                None
+            HeaderDetails::TwoByteFreeFormat(1, FreeFormatVariation::Group120Var10(change)) => {
+                return Some(self.handle_user_status_change(database, seq, &change));
             }
-            FunctionCode::EnableUnsolicited => {
-                Some(self.handle_enable_or_disable_unsolicited(true, seq, object_headers))
+            HeaderDetails::TwoByteFreeFormat(1, FreeFormatVariation::Group120Var11(request)) => {
+                return Some(self.handle_update_key_change_request(database, seq, &request));
             }
-            FunctionCode::DisableUnsolicited => {
-                Some(self.handle_enable_or_disable_unsolicited(false, seq, object_headers))
+            HeaderDetails::TwoByteFreeFormat(1, FreeFormatVariation::Group120Var2(reply)) => {
+                return match auth.verify_reply(pending, &reply) {
+                    Ok(pending) => {
+                        self.execute_challenged(database, reply.user, seq, frame_id, pending)
+                            .await
+                    }
+                    Err(failure) => {
+                        Some(self.handle_auth_failure(database, seq, reply.user, failure))
+                    }
+                };
             }
-
             _ => {
-                tracing::warn!("unsupported function code: {:?}", function);
-                Some(Response::empty_solicited(
+                tracing::warn!(
+                    "unsupported AUTH_REQUEST object: {}",
+                    header.variation.description()
+                );
+                return Some(Response::empty_solicited(
                     seq,
                     Iin::default() | Iin2::NO_FUNC_CODE_SUPPORT,
+                ));
+            }
+        };
+
+        let update_key = match self
+            .application
+            .auth_handler()
+            .and_then(|handler| get_update_key(handler, user))
+        {
+            Some(x) => x,
+            None => {
+                return Some(self.handle_auth_failure(
+                    database,
+                    seq,
+                    user,
+                    AuthFailure::UnknownUser,
                 ))
             }
         };
 
-        if let Some(response) = &mut result {
-            response.header.iin |= Self::get_iin2(function, object_headers);
+        let mut cursor = self.sol_tx_buffer.write_cursor();
+        let _ = cursor.skip(ResponseHeader::LENGTH);
+        let mut writer = HeaderWriter::new(&mut cursor);
+
+        let key_change = match change {
+            None => {
+                auth.key_status_request(user, &update_key, &mut writer);
+                None
+            }
+            Some(change) => {
+                Some(auth.key_change(&change, &update_key, request.raw_fragment, &mut writer))
+            }
+        };
+        let len = cursor.written().len();
+
+        // a failed change is reported to the master in the key status
+        match key_change {
+            None => {}
+            Some(Ok(())) => {
+                self.increment_statistic(database, SecurityStatisticIndex::SessionKeyChanges)
+            }
+            Some(Err(failure)) => {
+                tracing::warn!("session key change failed for user {}", user);
+                self.info.authentication_failure(user, failure);
+                self.increment_statistic(database, SecurityStatisticIndex::FailedSessionKeyChanges);
+            }
         }
 
-        result
+        Some(Self::auth_response(seq, len))
     }
 
-    fn get_iin2(function: FunctionCode, object_headers: HeaderCollection) -> Iin2 {
-        if function.get_function_info().objects_allowed {
-            return Iin2::default();
-        }
+    /// change the status of a user as certified by the authority and respond with an empty response
+    fn handle_user_status_change(
+        &mut self,
+        database: &DatabaseHandle,
+        seq: Sequence,
+        change: &Group120Var10,
+    ) -> Response {
+        let result = match (&mut self.auth, self.application.auth_handler()) {
+            (Some(auth), Some(handler)) => auth.user_status_change(handler, change),
+            _ => Err(AuthFailure::KeyChangeMethodNotPermitted(
+                change.key_change_method,
+            )),
+        };
 
-        if object_headers.is_empty() {
-            Iin2::default()
-        } else {
-            tracing::warn!("ignoring object headers in {:?} request", function);
-            Iin2::PARAMETER_ERROR
+        match result {
+            Ok(()) => Response::empty_solicited(seq, Iin::default()),
+            // the user isn't identified by number until its update key is changed
+            Err(failure) => self.handle_auth_failure(database, seq, 0, failure),
         }
     }
 
-    async fn handle_write(
+    fn handle_update_key_change_request(
         &mut self,
-        seq: Sequence,
-        object_headers: HeaderCollection<'_>,
         database: &DatabaseHandle,
+        seq: Sequence,
+        request: &Group120Var11,
     ) -> Response {
-        let mut iin2 = Iin2::default();
-        let mut empty = true;
+        let mut cursor = self.sol_tx_buffer.write_cursor();
+        let _ = cursor.skip(ResponseHeader::LENGTH);
+        let mut writer = HeaderWriter::new(&mut cursor);
 
-        for header in object_headers.iter() {
-            empty = false;
-            iin2 = self.handle_single_write_header(header, database).await;
-        }
+        let result = match (&mut self.auth, self.application.auth_handler()) {
+            (Some(auth), Some(handler)) => {
+                auth.update_key_change_request(handler, request, &mut writer)
+            }
+            _ => Err(AuthFailure::KeyChangeMethodNotPermitted(
+                request.key_change_method,
+            )),
+        };
 
-        if empty {
-            tracing::warn!("empty WRITE request");
+        match result {
+            Ok(()) => Self::auth_response(seq, cursor.written().len()),
+            Err(failure) => self.handle_auth_failure(database, seq, 0, failure),
         }
-
-        Response::empty_solicited(seq, Iin::default() | iin2)
     }
 
-    fn handle_write_iin(&mut self, bits: BitSequence) -> Iin2 {
-        let mut iin2 = Iin2::default();
+    fn handle_update_key_change(
+        &mut self,
+        database: &DatabaseHandle,
+        seq: Sequence,
+        encrypted: &EncryptedUpdateKey,
+    ) -> Response {
+        let mut cursor = self.sol_tx_buffer.write_cursor();
+        let _ = cursor.skip(ResponseHeader::LENGTH);
+        let mut writer = HeaderWriter::new(&mut cursor);
+
+        let result = match (&mut self.auth, self.application.auth_handler()) {
+            (Some(auth), Some(handler)) => auth.update_key_change(handler, encrypted, &mut writer),
+            _ => Err(AuthFailure::UpdateKeyChangeFailed),
+        };
+
+        let len = cursor.written().len();
+
+        match result {
+            Ok(()) => {
+                self.increment_statistic(database, SecurityStatisticIndex::UpdateKeyChanges);
+                Self::auth_response(seq, len)
+            }
+            Err(failure) => self.handle_auth_failure(database, seq, encrypted.change.user, failure),
+        }
+    }
+
+    /// process a critical request once the master replied to its challenge
+    async fn execute_challenged(
+        &mut self,
+        database: &mut DatabaseHandle,
+        user: u16,
+        seq: Sequence,
+        frame_id: u32,
+        pending: PendingRequest,
+    ) -> Option<Response> {
+        let objects = match pending.objects() {
+            Ok(x) => x,
+            Err(err) => {
+                return Some(Response::empty_solicited(
+                    seq,
+                    Iin::default() | Iin2::from(err),
+                ))
+            }
+        };
+
+        if pending.function == FunctionCode::Select {
+            self.state.select = None;
+        }
+
+        // the request is processed as it was received so that SELECT/OPERATE matching still works
+        let mut response = self
+            .execute_authenticated(
+                database,
+                user,
+                pending.function,
+                pending.seq,
+                pending.frame_id,
+                objects,
+            )
+            .await;
+
+        // but the master expects the response to match the reply
+        if let Some(select) = &mut self.state.select {
+            if pending.function == FunctionCode::Select {
+                select.update_request(seq, frame_id);
+            }
+        }
+        if let Some(response) = &mut response {
+            response.header.control.seq = seq;
+        }
+
+        response
+    }
+
+    async fn execute_authenticated(
+        &mut self,
+        database: &mut DatabaseHandle,
+        user: u16,
+        function: FunctionCode,
+        seq: Sequence,
+        frame_id: u32,
+        object_headers: HeaderCollection<'_>,
+    ) -> Option<Response> {
+        let authorized = self
+            .application
+            .auth_handler()
+            .is_some_and(|handler| handler.is_authorized(user, function));
+
+        // the request was authenticated before it was checked for authorization
+        self.increment_statistic(database, SecurityStatisticIndex::SuccessfulAuthentications);
+
+        if !authorized {
+            return Some(self.handle_auth_failure(
+                database,
+                seq,
+                user,
+                AuthFailure::Unauthorized(function),
+            ));
+        }
+
+        self.handle_non_read(database, function, seq, frame_id, object_headers)
+            .await
+    }
+
+    /// report the failure and respond with an authentication error (g120v7)
+    fn handle_auth_failure(
+        &mut self,
+        database: &DatabaseHandle,
+        seq: Sequence,
+        user: u16,
+        failure: AuthFailure,
+    ) -> Response {
+        tracing::warn!("authentication failed for user {}: {:?}", user, failure);
+        self.info.authentication_failure(user, failure);
+        self.increment_statistic(database, Self::failure_statistic(failure));
+        if self.auth.is_some() {
+            self.increment_statistic(database, SecurityStatisticIndex::ErrorMessagesSent);
+        }
+
+        let mut cursor = self.sol_tx_buffer.write_cursor();
+        let _ = cursor.skip(ResponseHeader::LENGTH);
+        if let Some(auth) = &self.auth {
+            let mut writer = HeaderWriter::new(&mut cursor);
+            auth.write_error(
+                user,
+                self.destination.link.raw_value(),
+                failure,
+                &mut writer,
+            );
+        }
+        Self::auth_response(seq, cursor.written().len())
+    }
+
+    fn failure_statistic(failure: AuthFailure) -> SecurityStatisticIndex {
+        match failure {
+            AuthFailure::Unauthorized(_) => SecurityStatisticIndex::AuthorizationFailures,
+            AuthFailure::KeyChangeMethodNotPermitted(_)
+            | AuthFailure::UnexpectedStatusChangeSequence(_)
+            | AuthFailure::InvalidCertification
+            | AuthFailure::InvalidSignature
+            | AuthFailure::UpdateKeyChangeFailed => SecurityStatisticIndex::FailedUpdateKeyChanges,
+            AuthFailure::UnknownUser
+            | AuthFailure::NoSessionKeys
+            | AuthFailure::NoChallenge
+            | AuthFailure::UnexpectedChallengeSequence(_)
+            | AuthFailure::BadMac
+            | AuthFailure::AggressiveModeDisabled
+            | AuthFailure::KeyChangeFailed => SecurityStatisticIndex::AuthenticationFailures,
+        }
+    }
+
+    fn increment_statistic(&self, database: &DatabaseHandle, statistic: SecurityStatisticIndex) {
+        database.increment_security_statistic(statistic, self.destination.link.raw_value());
+    }
+
+    /// confirms are not counted, otherwise confirming an unsolicited response that reports the
+    /// statistic would produce another unsolicited response
+    fn count_received_message(&self, database: &DatabaseHandle, request: Request) {
+        if request.header.function != FunctionCode::Confirm {
+            self.increment_statistic(database, SecurityStatisticIndex::TotalMessagesReceived);
+        }
+    }
+
+    fn auth_response(seq: Sequence, size: usize) -> Response {
+        let header = ResponseHeader::new(
+            ControlField::response(seq, true, true, false),
+            ResponseFunction::AuthResponse,
+            Iin::default(),
+        );
+        Response::new(header, size)
+    }
+
+    async fn handle_non_read(
+        &mut self,
+        database: &mut DatabaseHandle,
+        function: FunctionCode,
+        seq: Sequence,
+        frame_id: u32,
+        object_headers: HeaderCollection<'_>,
+    ) -> Option<Response> {
+        let mut result = match function {
+            FunctionCode::Write => {
+                if Self::is_file_transport(object_headers) {
+                    Some(
+                        self.handle_file_request(database, function, seq, object_headers)
+                            .await,
+                    )
+                } else {
+                    Some(self.handle_write(seq, object_headers, database).await)
+                }
+            }
+            // these function don't process objects
+            FunctionCode::DelayMeasure => Some(self.handle_delay_measure(seq)),
+            FunctionCode::RecordCurrentTime => Some(self.handle_record_current_time(seq)),
+            FunctionCode::ColdRestart => {
+                let delay = self.application.cold_restart();
+                Some(self.handle_restart(seq, delay))
+            }
+            FunctionCode::WarmRestart => {
+                let delay = self.application.warm_restart();
+                Some(self.handle_restart(seq, delay))
+            }
+            // controls
+            FunctionCode::Select => {
+                self.handle_controls(ControlType::Select, database, seq, frame_id, object_headers)
+                    .await
+            }
+            FunctionCode::Operate => {
+                self.handle_controls(
+                    ControlType::Operate,
+                    database,
+                    seq,
+                    frame_id,
+                    object_headers,
+                )
+                .await
+            }
+            FunctionCode::DirectOperate => {
+                self.handle_controls(
+                    ControlType::DirectOperate,
+                    database,
+                    seq,
+                    frame_id,
+                    object_headers,
+                )
+                .await
+            }
+            FunctionCode::DirectOperateNoResponse => {
+                self.handle_controls(
+                    ControlType::DirectOperateNoAck,
+                    database,
+                    seq,
+                    frame_id,
+                    object_headers,
+                )
+                .await
+            }
+            FunctionCode::ImmediateFreeze => {
+                Some(self.handle_freeze(database, seq, object_headers, FreezeType::ImmediateFreeze))
+            }
+            FunctionCode::ImmediateFreezeNoResponse => {
+                self.handle_freeze(database, seq, object_headers, FreezeType::ImmediateFreeze);
+                None
+            }
+            FunctionCode::FreezeClear => {
+                Some(self.handle_freeze(database, seq, object_headers, FreezeType::FreezeAndClear))
+            }
+            FunctionCode::FreezeClearNoResponse => {
+                self.handle_freeze(database, seq, object_headers, FreezeType::FreezeAndClear);
+                None
+            }
+            FunctionCode::FreezeAtTime => {
+                Some(self.handle_freeze_at_time(database, seq, object_headers))
+            }
+            FunctionCode::FreezeAtTimeNoResponse => {
+                self.handle_freeze_at_time(database, seq, object_headers);
+                None
+            }
+            FunctionCode::AssignClass => {
+                Some(self.handle_assign_class(database, seq, object_headers))
+            }
+            FunctionCode::InitializeApplication => Some(self.handle_application_function(
+                ApplicationFunction::Initialize,
+                seq,
+                object_headers,
+            )),
+            FunctionCode::StartApplication => Some(self.handle_application_function(
+                ApplicationFunction::Start,
+                seq,
+                object_headers,
+            )),
+            FunctionCode::StopApplication => Some(self.handle_application_function(
+                ApplicationFunction::Stop,
+                seq,
+                object_headers,
+            )),
+            FunctionCode::SaveConfiguration => Some(self.handle_save_configuration(seq)),
+            FunctionCode::ActivateConfig => Some(self.handle_activate_config(seq, object_headers)),
+            FunctionCode::EnableUnsolicited => {
+                Some(self.handle_enable_or_disable_unsolicited(true, seq, object_headers))
+            }
+            FunctionCode::DisableUnsolicited => {
+                Some(self.handle_enable_or_disable_unsolicited(false, seq, object_headers))
+            }
+            // file transfer
+            FunctionCode::AuthenticateFile
+            | FunctionCode::OpenFile
+            | FunctionCode::CloseFile
+            | FunctionCode::DeleteFile
+            | FunctionCode::GetFileInfo
+            | FunctionCode::AbortFile => Some(
+                self.handle_file_request(database, function, seq, object_headers)
+                    .await,
+            ),
+
+            _ => {
+                tracing::warn!("unsupported function code: {:?}", function);
+                Some(Response::empty_solicited(
+                    seq,
+                    Iin::default() | Iin2::NO_FUNC_CODE_SUPPORT,
+                ))
+            }
+        };
+
+        if let Some(response) = &mut result {
+            response.header.iin |= Self::get_iin2(function, object_headers);
+        }
+
+        result
+    }
+
+    /// true if the request reads or writes a block of a file (g70v5)
+    fn is_file_transport(object_headers: HeaderCollection) -> bool {
+        object_headers.iter().any(|header| {
+            matches!(
+                header.details,
+                HeaderDetails::TwoByteFreeFormat(_, FreeFormatVariation::Group70Var5(_))
+            )
+        })
+    }
+
+    async fn handle_file_request(
+        &mut self,
+        database: &mut DatabaseHandle,
+        function: FunctionCode,
+        seq: Sequence,
+        object_headers: HeaderCollection<'_>,
+    ) -> Response {
+        let fs = match self.application.file_system() {
+            Some(fs) => fs,
+            None => {
+                tracing::warn!("file transfer not supported: {:?}", function);
+                return Response::empty_solicited(seq, Iin::default() | Iin2::NO_FUNC_CODE_SUPPORT);
+            }
+        };
+
+        let header = match object_headers.get_only_header() {
+            Ok(x) => x,
+            Err(err) => {
+                tracing::warn!(
+                    "{:?} request must contain a single object header: {:?}",
+                    function,
+                    err
+                );
+                return Response::empty_solicited(seq, Iin::default() | Iin2::PARAMETER_ERROR);
+            }
+        };
+
+        let mode = fs.transport_mode();
+        let qualifier = header.details.qualifier();
+        let files = &mut self.state.files;
+        let response = match (function, header.details) {
+            (
+                FunctionCode::AuthenticateFile,
+                HeaderDetails::TwoByteFreeFormat(_, FreeFormatVariation::Group70Var2(obj)),
+            ) => files.authenticate(fs, &obj),
+            (
+                FunctionCode::OpenFile,
+                HeaderDetails::TwoByteFreeFormat(_, FreeFormatVariation::Group70Var3(obj)),
+            ) => files.open(fs, &obj),
+            (
+                FunctionCode::CloseFile,
+                HeaderDetails::TwoByteFreeFormat(_, FreeFormatVariation::Group70Var4(obj)),
+            ) => files.close(fs, &obj),
+            (
+                FunctionCode::AbortFile,
+                HeaderDetails::TwoByteFreeFormat(_, FreeFormatVariation::Group70Var4(obj)),
+            ) => files.abort(fs, &obj),
+            (
+                FunctionCode::DeleteFile,
+                HeaderDetails::TwoByteFreeFormat(_, FreeFormatVariation::Group70Var3(obj)),
+            ) => files.delete(fs, &obj),
+            (
+                FunctionCode::GetFileInfo,
+                HeaderDetails::TwoByteFreeFormat(_, FreeFormatVariation::Group70Var7(obj)),
+            ) => files.get_info(fs, &obj),
+            (
+                FunctionCode::Read,
+                HeaderDetails::TwoByteFreeFormat(_, FreeFormatVariation::Group70Var5(obj)),
+            ) => files.read(fs, &obj).await,
+            (
+                FunctionCode::Write,
+                HeaderDetails::TwoByteFreeFormat(_, FreeFormatVariation::Group70Var5(obj)),
+            ) => files.write(fs, &obj).await,
+            _ => {
+                tracing::warn!(
+                    "{:?} not supported with qualifier: {} and variation: {}",
+                    function,
+                    qualifier,
+                    header.variation
+                );
+                return Response::empty_solicited(seq, Iin::default() | Iin2::PARAMETER_ERROR);
+            }
+        };
+
+        match mode {
+            FileTransportMode::Event(class) if response.is_event_capable() => {
+                Self::insert_file_event(database, class, &response);
+                Response::empty_solicited(seq, Iin::default())
+            }
+            _ => self.write_file_response(seq, &response),
+        }
+    }
+
+    fn insert_file_event(database: &DatabaseHandle, class: EventClass, response: &FileResponse) {
+        let header = match response.encode() {
+            Ok(x) => x,
+            Err(err) => {
+                tracing::error!("unable to encode file transfer event: {:?}", err);
+                return;
+            }
+        };
+
+        let info =
+            database.transaction(|db| db.inner.insert_file_transfer_event(class, header.clone()));
+
+        if let UpdateInfo::NoEvent = info {
+            tracing::warn!("event buffer is not configured to hold file transfer events");
+        }
+    }
+
+    fn write_file_response(&mut self, seq: Sequence, response: &FileResponse) -> Response {
+        let mut cursor = self.sol_tx_buffer.write_cursor();
+        let _ = cursor.skip(ResponseHeader::LENGTH);
+        let mut writer = HeaderWriter::new(&mut cursor);
+        if let Err(err) = response.write(&mut writer) {
+            tracing::error!("unable to write file transfer response: {:?}", err);
+            return Response::empty_solicited(seq, Iin::default() | Iin2::PARAMETER_ERROR);
+        }
+
+        let header = ResponseHeader::new(
+            ControlField::single_response(seq),
+            ResponseFunction::Response,
+            Iin::default(),
+        );
+        Response::new(header, cursor.written().len())
+    }
+
+    fn get_iin2(function: FunctionCode, object_headers: HeaderCollection) -> Iin2 {
+        if function.get_function_info().objects_allowed {
+            return Iin2::default();
+        }
+
+        if object_headers.is_empty() {
+            Iin2::default()
+        } else {
+            tracing::warn!("ignoring object headers in {:?} request", function);
+            Iin2::PARAMETER_ERROR
+        }
+    }
+
+    async fn handle_write(
+        &mut self,
+        seq: Sequence,
+        object_headers: HeaderCollection<'_>,
+        database: &DatabaseHandle,
+    ) -> Response {
+        let mut iin2 = Iin2::default();
+        let mut empty = true;
+
+        for header in object_headers.iter() {
+            empty = false;
+            iin2 = self.handle_single_write_header(header, database).await;
+        }
+
+        if empty {
+            tracing::warn!("empty WRITE request");
+        }
+
+        Response::empty_solicited(seq, Iin::default() | iin2)
+    }
+
+    fn handle_write_iin(&mut self, bits: BitSequence) -> Iin2 {
+        let mut iin2 = Iin2::default();
         for (value, index) in bits.iter() {
             if index == 7 {
                 // restart IIN
@@ -1310,6 +2130,170 @@ impl OutstationSession {
         Iin2::default()
     }
 
+    async fn handle_write_data_set(&mut self, obj: Group87Var1<'_>, db: &DatabaseHandle) -> Iin2 {
+        let id = match u16::try_from(obj.id) {
+            Ok(x) => x,
+            Err(_) => {
+                tracing::warn!("data set id {} is out of range", obj.id);
+                return Iin2::PARAMETER_ERROR;
+            }
+        };
+
+        let data_set = match db.transaction(|db| db.inner.decode_data_set(id, obj.time, obj.values))
+        {
+            Some(x) => x,
+            None => return Iin2::PARAMETER_ERROR,
+        };
+
+        // let the application validate and persist the value
+        if !self.application.write_data_set(id, &data_set).get().await {
+            return Iin2::PARAMETER_ERROR;
+        }
+
+        let info = db.transaction(|db| {
+            db.inner
+                .update_data_set(id, &data_set, UpdateOptions::detect_event())
+        });
+
+        if info == UpdateInfo::NoPoint {
+            return Iin2::PARAMETER_ERROR;
+        }
+
+        Iin2::default()
+    }
+
+    async fn handle_write_octet_strings<'a>(
+        &mut self,
+        values: impl Iterator<Item = (&'a [u8], u16)>,
+        db: &DatabaseHandle,
+    ) -> Iin2 {
+        let mut iin2 = Iin2::default();
+        for (bytes, index) in values {
+            let value = match measurement::OctetString::new(bytes) {
+                Ok(x) => x,
+                Err(err) => {
+                    tracing::warn!("octet string {index} has an invalid length: {err}");
+                    iin2 |= Iin2::PARAMETER_ERROR;
+                    continue;
+                }
+            };
+
+            let exists =
+                db.transaction(|db| Get::<measurement::OctetString>::get(db, index).is_some());
+            if !exists {
+                tracing::warn!("octet string {index} does not exist");
+                iin2 |= Iin2::PARAMETER_ERROR;
+                continue;
+            }
+
+            // let the application validate and persist the value
+            match self
+                .application
+                .write_octet_string(index, &value)
+                .get()
+                .await
+            {
+                WriteOctetString::Reject => iin2 |= Iin2::PARAMETER_ERROR,
+                WriteOctetString::Accept => {}
+                WriteOctetString::Update(options) => {
+                    db.transaction(|db| db.update(index, &value, options));
+                }
+            }
+        }
+        iin2
+    }
+
+    async fn handle_write_time_and_interval<I>(
+        &mut self,
+        items: CountSequence<'_, Prefix<I, Group50Var4>>,
+        db: &DatabaseHandle,
+    ) -> Iin2
+    where
+        I: Index,
+    {
+        let freeze_engine = self.config.freeze_engine.is_enabled();
+        let mut iin2 = Iin2::default();
+        for item in items.iter() {
+            let index = item.index.widen_to_u16();
+            let value = TimeAndInterval::from(item.value);
+
+            // the built-in freeze engine applies the value as the schedule of a linked counter
+            let schedule = if freeze_engine {
+                let interval = match value.to_freeze_interval() {
+                    Some(x) => x,
+                    None => {
+                        tracing::warn!(
+                            "time and interval {index} cannot be used as a freeze schedule: {:?}",
+                            value.units
+                        );
+                        iin2 |= Iin2::PARAMETER_ERROR;
+                        continue;
+                    }
+                };
+                if !db.transaction(|db| db.inner.is_frozen_counter_linked(index)) {
+                    tracing::warn!("counter {index} is not linked to a frozen counter");
+                    iin2 |= Iin2::PARAMETER_ERROR;
+                    continue;
+                }
+                Some(interval)
+            } else {
+                None
+            };
+
+            if !self
+                .application
+                .write_time_and_interval(index, value)
+                .get()
+                .await
+            {
+                iin2 |= Iin2::PARAMETER_ERROR;
+                continue;
+            }
+
+            if let Some(interval) = schedule {
+                db.transaction(|db| {
+                    db.freeze_counters(
+                        FreezeIndices::Range(index, index),
+                        FreezeType::FreezeAtTime(interval),
+                    )
+                });
+            }
+        }
+        iin2
+    }
+
+    fn handle_write_virtual_terminal<I>(
+        &mut self,
+        blocks: PrefixedBytesSequence<'_, I>,
+        db: &DatabaseHandle,
+    ) -> Iin2
+    where
+        I: Index,
+    {
+        let handler = match self.application.virtual_terminal() {
+            Some(x) => x,
+            None => {
+                tracing::warn!("virtual terminals are not supported");
+                return Iin2::NO_FUNC_CODE_SUPPORT;
+            }
+        };
+
+        let mut database = db.clone();
+        let mut iin2 = Iin2::default();
+        for (data, index) in blocks.iter() {
+            let port = index.widen_to_u16();
+            if !database.transaction(|db| db.inner.has_virtual_terminal(port)) {
+                tracing::warn!("virtual terminal port {port} does not exist");
+                iin2 |= Iin2::PARAMETER_ERROR;
+                continue;
+            }
+            if let Err(err) = handler.write(port, data, &mut database) {
+                iin2 |= err.into();
+            }
+        }
+        iin2
+    }
+
     fn handle_write_abs_time(&mut self, seq: CountSequence<Group50Var1>) -> Iin2 {
         if let Some(value) = seq.single() {
             match self.application.write_absolute_time(value.time) {
@@ -1397,6 +2381,35 @@ impl OutstationSession {
             HeaderDetails::TwoByteCountAndPrefix(_, PrefixedVariation::Group34Var3(seq)) => {
                 self.handle_write_analog_deadbands(seq, db).await
             }
+            // indexed time and interval
+            HeaderDetails::OneByteCountAndPrefix(_, PrefixedVariation::Group50Var4(seq)) => {
+                self.handle_write_time_and_interval(seq, db).await
+            }
+            HeaderDetails::TwoByteCountAndPrefix(_, PrefixedVariation::Group50Var4(seq)) => {
+                self.handle_write_time_and_interval(seq, db).await
+            }
+            HeaderDetails::TwoByteFreeFormat(_, FreeFormatVariation::Group87Var1(obj)) => {
+                self.handle_write_data_set(obj, db).await
+            }
+            // octet strings
+            HeaderDetails::OneByteStartStop(_, _, RangedVariation::Group110VarX(_, seq))
+            | HeaderDetails::TwoByteStartStop(_, _, RangedVariation::Group110VarX(_, seq)) => {
+                self.handle_write_octet_strings(seq.iter(), db).await
+            }
+            HeaderDetails::OneByteCountAndPrefix(_, PrefixedVariation::Group110VarX(_, seq)) => {
+                self.handle_write_octet_strings(seq.iter().map(|(x, i)| (x, i.widen_to_u16())), db)
+                    .await
+            }
+            HeaderDetails::TwoByteCountAndPrefix(_, PrefixedVariation::Group110VarX(_, seq)) => {
+                self.handle_write_octet_strings(seq.iter(), db).await
+            }
+            // virtual terminal output blocks
+            HeaderDetails::OneByteCountAndPrefix(_, PrefixedVariation::Group112VarX(_, seq)) => {
+                self.handle_write_virtual_terminal(seq, db)
+            }
+            HeaderDetails::TwoByteCountAndPrefix(_, PrefixedVariation::Group112VarX(_, seq)) => {
+                self.handle_write_virtual_terminal(seq, db)
+            }
             _ => {
                 tracing::warn!(
                     "WRITE not supported with qualifier: {} and variation: {}",
@@ -1517,6 +2530,7 @@ impl OutstationSession {
             let max_controls_per_request = self.config.max_controls_per_request;
             let result = ControlTransaction::execute(
                 self.control_handler.borrow_mut(),
+                self.config.command_events,
                 database,
                 |tx, db| {
                     controls.operate_with_response(
@@ -1533,6 +2547,10 @@ impl OutstationSession {
             (result, cursor.written().len())
         };
 
+        if let Ok(status) = result {
+            self.statistics.lock().unwrap().on_operate(status);
+        }
+
         // Calculate IIN and return it
         let mut iin = Iin::default();
 
@@ -1596,9 +2614,12 @@ impl OutstationSession {
         controls: ControlCollection<'_>,
     ) {
         let max_controls_per_request = self.config.max_controls_per_request;
-        ControlTransaction::execute(self.control_handler.borrow_mut(), database, |tx, db| {
-            controls.operate_no_ack(tx, db, max_controls_per_request)
-        })
+        ControlTransaction::execute(
+            self.control_handler.borrow_mut(),
+            self.config.command_events,
+            database,
+            |tx, db| controls.operate_no_ack(tx, db, max_controls_per_request),
+        )
         .await;
     }
 
@@ -1665,6 +2686,7 @@ impl OutstationSession {
             let max_controls_per_request = self.config.max_controls_per_request;
             let result: Result<CommandStatus, scursor::WriteError> = ControlTransaction::execute(
                 self.control_handler.borrow_mut(),
+                self.config.command_events,
                 database,
                 |tx, db| {
                     controls.select_with_response(&mut cursor, tx, db, max_controls_per_request)
@@ -1675,6 +2697,10 @@ impl OutstationSession {
             (result, cursor.written().len())
         };
 
+        if let Ok(status) = result {
+            self.statistics.lock().unwrap().on_select(status);
+        }
+
         // Record the select state
         if let Ok(CommandStatus::Success) = result {
             self.state.select = Some(SelectState::new(
@@ -1729,6 +2755,7 @@ impl OutstationSession {
                             let max_controls_per_request = self.config.max_controls_per_request;
                             ControlTransaction::execute(
                                 self.control_handler.borrow_mut(),
+                                self.config.command_events,
                                 database,
                                 |tx, db| {
                                     controls
@@ -1756,6 +2783,8 @@ impl OutstationSession {
             (status, cursor.written().len())
         };
 
+        self.statistics.lock().unwrap().on_operate(status);
+
         // Calculate IIN and return it
         let mut iin = Iin::default();
 
@@ -1846,25 +2875,276 @@ impl OutstationSession {
         freeze_type: FreezeType,
         details: HeaderDetails,
     ) -> Iin2 {
-        match details {
-            HeaderDetails::AllObjects(AllObjectsVariation::Group20Var0) => self
-                .application
-                .freeze_counter(FreezeIndices::All, freeze_type, database)
-                .map_or_else(|err| err.into(), |_| Iin2::default()),
-            HeaderDetails::OneByteStartStop(start, stop, RangedVariation::Group20Var0) => self
-                .application
-                .freeze_counter(
-                    FreezeIndices::Range(start as u16, stop as u16),
-                    freeze_type,
-                    database,
-                )
-                .map_or_else(|err| err.into(), |_| Iin2::default()),
-            HeaderDetails::TwoByteStartStop(start, stop, RangedVariation::Group20Var0) => self
-                .application
-                .freeze_counter(FreezeIndices::Range(start, stop), freeze_type, database)
-                .map_or_else(|err| err.into(), |_| Iin2::default()),
-            _ => Iin2::NO_FUNC_CODE_SUPPORT,
+        let indices = match details {
+            HeaderDetails::AllObjects(AllObjectsVariation::Group20Var0) => FreezeIndices::All,
+            HeaderDetails::OneByteStartStop(start, stop, RangedVariation::Group20Var0) => {
+                FreezeIndices::Range(start as u16, stop as u16)
+            }
+            HeaderDetails::TwoByteStartStop(start, stop, RangedVariation::Group20Var0) => {
+                FreezeIndices::Range(start, stop)
+            }
+            _ => return Iin2::NO_FUNC_CODE_SUPPORT,
+        };
+
+        if self.config.freeze_engine.is_enabled() {
+            database.transaction(|db| db.freeze_counters(indices, freeze_type));
+            return Iin2::default();
+        }
+
+        self.application
+            .freeze_counter(indices, freeze_type, database)
+            .map_or_else(|err| err.into(), |_| Iin2::default())
+    }
+
+    fn handle_assign_class(
+        &mut self,
+        database: &mut DatabaseHandle,
+        seq: Sequence,
+        object_headers: HeaderCollection,
+    ) -> Response {
+        if !self.application.support_assign_class() {
+            tracing::warn!("ASSIGN_CLASS not supported by the application");
+            return Response::empty_solicited(seq, Iin::default() | Iin2::NO_FUNC_CODE_SUPPORT);
+        }
+
+        let mut iin = Iin::default();
+
+        // each group of point headers is preceded by a g60 header specifying the class
+        let mut class: Option<Option<EventClass>> = None;
+
+        for header in object_headers.iter() {
+            let (point_type, indices) = match &header.details {
+                HeaderDetails::AllObjects(AllObjectsVariation::Group60Var1) => {
+                    class = Some(None);
+                    continue;
+                }
+                HeaderDetails::AllObjects(AllObjectsVariation::Group60Var2) => {
+                    class = Some(Some(EventClass::Class1));
+                    continue;
+                }
+                HeaderDetails::AllObjects(AllObjectsVariation::Group60Var3) => {
+                    class = Some(Some(EventClass::Class2));
+                    continue;
+                }
+                HeaderDetails::AllObjects(AllObjectsVariation::Group60Var4) => {
+                    class = Some(Some(EventClass::Class3));
+                    continue;
+                }
+                details => match Self::get_class_assignment_target(details) {
+                    Some(x) => x,
+                    None => {
+                        tracing::warn!(
+                            "ASSIGN_CLASS not supported for {} with qualifier: {}",
+                            header.variation,
+                            header.details.qualifier()
+                        );
+                        iin.iin2.set(Iin2::OBJECT_UNKNOWN);
+                        continue;
+                    }
+                },
+            };
+
+            let class = match class {
+                Some(x) => x,
+                None => {
+                    tracing::warn!(
+                        "ASSIGN_CLASS on {} w/o preceding g60 header",
+                        header.variation
+                    );
+                    iin.iin2.set(Iin2::PARAMETER_ERROR);
+                    continue;
+                }
+            };
+
+            let assignment = ClassAssignment {
+                class,
+                point_type,
+                indices,
+            };
+
+            if !database.has_class_assignment_target(assignment) {
+                tracing::warn!(
+                    "ASSIGN_CLASS on {} matches no points in the database",
+                    header.variation
+                );
+                match indices {
+                    // the outstation has no points of this type
+                    AssignClassIndices::All => iin.iin2.set(Iin2::OBJECT_UNKNOWN),
+                    AssignClassIndices::Range(_, _) => iin.iin2.set(Iin2::PARAMETER_ERROR),
+                }
+                continue;
+            }
+
+            match self.application.assign_class(assignment) {
+                Ok(()) => database.transaction(|db| db.inner.assign_class(assignment)),
+                Err(err) => iin |= Iin2::from(err),
+            }
+        }
+
+        Response::empty_solicited(seq, iin)
+    }
+
+    fn get_class_assignment_target(
+        details: &HeaderDetails,
+    ) -> Option<(AssignClassType, AssignClassIndices)> {
+        let (variation, indices) = match details {
+            HeaderDetails::AllObjects(var) => {
+                let point_type = match var {
+                    AllObjectsVariation::Group1Var0 => AssignClassType::BinaryInput,
+                    AllObjectsVariation::Group3Var0 => AssignClassType::DoubleBitBinaryInput,
+                    AllObjectsVariation::Group10Var0 => AssignClassType::BinaryOutputStatus,
+                    AllObjectsVariation::Group20Var0 => AssignClassType::Counter,
+                    AllObjectsVariation::Group21Var0 => AssignClassType::FrozenCounter,
+                    AllObjectsVariation::Group30Var0 => AssignClassType::AnalogInput,
+                    AllObjectsVariation::Group40Var0 => AssignClassType::AnalogOutputStatus,
+                    AllObjectsVariation::Group110Var0 => AssignClassType::OctetString,
+                    _ => return None,
+                };
+                return Some((point_type, AssignClassIndices::All));
+            }
+            HeaderDetails::OneByteStartStop(start, stop, var) => {
+                (var, AssignClassIndices::Range(*start as u16, *stop as u16))
+            }
+            HeaderDetails::TwoByteStartStop(start, stop, var) => {
+                (var, AssignClassIndices::Range(*start, *stop))
+            }
+            _ => return None,
+        };
+
+        let point_type = match variation {
+            RangedVariation::Group1Var0 => AssignClassType::BinaryInput,
+            RangedVariation::Group3Var0 => AssignClassType::DoubleBitBinaryInput,
+            RangedVariation::Group10Var0 => AssignClassType::BinaryOutputStatus,
+            RangedVariation::Group20Var0 => AssignClassType::Counter,
+            RangedVariation::Group21Var0 => AssignClassType::FrozenCounter,
+            RangedVariation::Group30Var0 => AssignClassType::AnalogInput,
+            RangedVariation::Group40Var0 => AssignClassType::AnalogOutputStatus,
+            RangedVariation::Group110Var0 => AssignClassType::OctetString,
+            _ => return None,
+        };
+
+        Some((point_type, indices))
+    }
+
+    fn handle_application_function(
+        &mut self,
+        function: ApplicationFunction,
+        seq: Sequence,
+        object_headers: HeaderCollection,
+    ) -> Response {
+        let mut iin = Iin::default();
+
+        for header in object_headers.iter() {
+            let id = match &header.details {
+                HeaderDetails::AllObjects(AllObjectsVariation::Group90Var1) => ApplicationId::All,
+                HeaderDetails::TwoByteFreeFormat(_, FreeFormatVariation::Group90Var1(obj)) => {
+                    ApplicationId::Name(obj.name)
+                }
+                _ => {
+                    tracing::warn!(
+                        "{:?} application not supported for {} with qualifier: {}",
+                        function,
+                        header.variation,
+                        header.details.qualifier()
+                    );
+                    iin.iin2.set(Iin2::OBJECT_UNKNOWN);
+                    continue;
+                }
+            };
+
+            let result = match function {
+                ApplicationFunction::Initialize => self.application.initialize_application(id),
+                ApplicationFunction::Start => self.application.start_application(id),
+                ApplicationFunction::Stop => self.application.stop_application(id),
+            };
+
+            if let Err(err) = result {
+                tracing::warn!("{:?} application {:?} failed: {:?}", function, id, err);
+                iin |= Iin2::from(err);
+            }
+        }
+
+        Response::empty_solicited(seq, iin)
+    }
+
+    fn handle_save_configuration(&mut self, seq: Sequence) -> Response {
+        let iin2 = match self.application.save_configuration() {
+            Ok(()) => Iin2::default(),
+            Err(err) => {
+                tracing::warn!("SAVE_CONFIGURATION failed: {:?}", err);
+                Iin2::from(err)
+            }
+        };
+
+        Response::empty_solicited(seq, Iin::default() | iin2)
+    }
+
+    fn handle_activate_config(
+        &mut self,
+        seq: Sequence,
+        object_headers: HeaderCollection,
+    ) -> Response {
+        if !self.application.support_activate_config() {
+            tracing::warn!("ACTIVATE_CONFIG not supported by the application");
+            return Response::empty_solicited(seq, Iin::default() | Iin2::NO_FUNC_CODE_SUPPORT);
+        }
+
+        let mut iin = Iin::default();
+        let mut statuses: Vec<ActivationStatus> = Vec::new();
+
+        for header in object_headers.iter() {
+            match &header.details {
+                HeaderDetails::TwoByteFreeFormat(_, FreeFormatVariation::Group70Var8(obj)) => {
+                    statuses.push(
+                        self.application
+                            .activate_config(ConfigObject::File(obj.file_specification)),
+                    );
+                }
+                HeaderDetails::OneByteStartStop(_, _, RangedVariation::Group110VarX(_, seq))
+                | HeaderDetails::TwoByteStartStop(_, _, RangedVariation::Group110VarX(_, seq)) => {
+                    for (value, index) in seq.iter() {
+                        statuses.push(
+                            self.application
+                                .activate_config(ConfigObject::String { index, value }),
+                        );
+                    }
+                }
+                _ => {
+                    tracing::warn!(
+                        "ACTIVATE_CONFIG not supported for {} with qualifier: {}",
+                        header.variation,
+                        header.details.qualifier()
+                    );
+                    iin.iin2.set(Iin2::OBJECT_UNKNOWN);
+                }
+            }
+        }
+
+        let delay = self.application.end_activate_config();
+        let time_delay_ms: u32 = delay.as_millis().try_into().unwrap_or(u32::MAX);
+
+        let elements: Vec<StatusElement> = statuses
+            .iter()
+            .map(|x| StatusElement {
+                code: x.code,
+                ancillary: x.ancillary.as_slice(),
+            })
+            .collect();
+
+        let mut cursor = self.sol_tx_buffer.write_cursor();
+        let _ = cursor.skip(ResponseHeader::LENGTH);
+        let mut writer = HeaderWriter::new(&mut cursor);
+        if let Err(err) = writer.write_activation_status(time_delay_ms, &elements) {
+            tracing::error!("unable to write ACTIVATE_CONFIG response: {:?}", err);
+            return Response::empty_solicited(seq, iin | Iin2::PARAMETER_ERROR);
         }
+
+        let header = ResponseHeader::new(
+            ControlField::single_response(seq),
+            ResponseFunction::Response,
+            iin,
+        );
+        Response::new(header, cursor.written().len())
     }
 
     fn get_response_iin(&mut self, database: &DatabaseHandle) -> Iin {
@@ -1876,7 +3156,7 @@ impl OutstationSession {
         }
 
         // Events available
-        let events_info = database.get_events_info();
+        let events_info = database.get_events_info(self.association);
         if events_info.unwritten_classes.class1 {
             iin |= Iin1::CLASS_1_EVENTS;
         }
@@ -1936,6 +3216,17 @@ impl OutstationSession {
             return BroadcastAction::IgnoredByConfiguration;
         }
 
+        // a broadcast request cannot be challenged, so critical functions are never authenticated
+        if self.auth.is_some() && is_critical(request.header.function) {
+            tracing::warn!(
+                "ignoring critical broadcast request (authentication required): {:?}",
+                request.header.function
+            );
+            self.increment_statistic(database, SecurityStatisticIndex::CriticalMessagesReceived);
+            self.increment_statistic(database, SecurityStatisticIndex::DiscardedMessages);
+            return BroadcastAction::UnsupportedFunction(request.header.function);
+        }
+
         let objects = match request.objects {
             Ok(x) => x,
             Err(err) => {
@@ -2026,7 +3317,7 @@ impl OutstationSession {
                     self.state.last_broadcast_type = None;
 
                     database
-                        .clear_written_events(self.application.as_mut())
+                        .clear_written_events(self.association, self.application.as_mut())
                         .await;
 
                     if series.fin {
@@ -2048,12 +3339,12 @@ impl OutstationSession {
                 }
                 Confirm::Timeout => {
                     tracing::warn!("confirm timeout");
-                    database.reset();
+                    database.reset(self.association);
                     return Ok(());
                 }
                 Confirm::NewRequest => {
                     tracing::info!("aborting solicited response due to new request");
-                    database.reset();
+                    database.reset(self.association);
                     return Ok(());
                 }
             }
@@ -2072,6 +3363,10 @@ impl OutstationSession {
             match self.read_until(io, reader, deadline).await? {
                 TimeoutStatus::Yes => {
                     self.info.solicited_confirm_timeout(ecsn);
+                    self.statistics
+                        .lock()
+                        .unwrap()
+                        .on_solicited_confirm_timeout();
                     return Ok(Confirm::Timeout);
                 }
                 // process data
@@ -2123,7 +3418,7 @@ impl OutstationSession {
             None => return ConfirmAction::ContinueWait,
         };
 
-        match self.classify(info, request) {
+        let action = match self.classify(info, request) {
             FragmentType::MalformedRequest(_, _) => ConfirmAction::NewRequest,
             FragmentType::NewRead(_, _) => ConfirmAction::NewRequest,
             FragmentType::RepeatRead(_, response, _) => {
@@ -2151,7 +3446,17 @@ impl OutstationSession {
                 tracing::warn!("ignoring unsolicited confirm with seq: {}", seq.value());
                 ConfirmAction::ContinueWait
             }
+        };
+
+        // new requests are retained and counted when they are processed from the idle state
+        if !matches!(action, ConfirmAction::NewRequest) {
+            self.statistics
+                .lock()
+                .unwrap()
+                .on_request(request.header.function);
         }
+
+        action
     }
 
     fn classify<'a>(&self, info: FragmentInfo, request: Request<'a>) -> FragmentType<'a> {
@@ -0,0 +1,180 @@
+use std::collections::BTreeMap;
+
+use crate::app::control::CommandStatus;
+use crate::app::FunctionCode;
+use crate::outstation::database::EventClass;
+
+/// Snapshot of the counters maintained by a running outstation
+///
+/// Counters start at zero when the outstation is created and are never reset, including when
+/// communications are disabled or a new connection is established.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct OutstationStatistics {
+    /// Counters maintained by the application layer session
+    pub session: SessionStatistics,
+    /// Counters maintained by the event buffer
+    pub events: EventStatistics,
+    /// Counters maintained by the link and transport layers
+    pub transport: TransportStatistics,
+}
+
+/// Counters maintained by the application layer session
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SessionStatistics {
+    /// Number of requests received for each function code, including confirms
+    ///
+    /// Repeated requests are counted each time they are received. Requests whose header cannot be
+    /// parsed are only counted in `malformed_requests`.
+    pub requests: BTreeMap<FunctionCode, u64>,
+    /// Number of requests that could not be parsed or were otherwise invalid
+    pub malformed_requests: u64,
+    /// Number of SELECT requests that did not succeed, by the status returned to the master
+    pub select_failures: CommandStatusCounts,
+    /// Number of OPERATE and DIRECT_OPERATE requests that did not succeed, by the status returned
+    /// to the master
+    pub operate_failures: CommandStatusCounts,
+    /// Number of unsolicited response series started, including null unsolicited responses
+    pub unsolicited_series: u64,
+    /// Number of unsolicited responses that were retransmitted
+    pub unsolicited_retries: u64,
+    /// Number of times the outstation timed out waiting for an unsolicited confirm
+    pub unsolicited_confirm_timeouts: u64,
+    /// Number of times the outstation timed out waiting for a solicited confirm
+    pub solicited_confirm_timeouts: u64,
+}
+
+impl SessionStatistics {
+    /// Number of requests received with the specified function code
+    pub fn num_requests(&self, function: FunctionCode) -> u64 {
+        self.requests.get(&function).copied().unwrap_or(0)
+    }
+
+    pub(crate) fn on_request(&mut self, function: FunctionCode) {
+        *self.requests.entry(function).or_default() += 1;
+    }
+
+    pub(crate) fn on_malformed_request(&mut self) {
+        self.malformed_requests += 1;
+    }
+
+    pub(crate) fn on_select(&mut self, status: CommandStatus) {
+        if status != CommandStatus::Success {
+            self.select_failures.increment(status);
+        }
+    }
+
+    pub(crate) fn on_operate(&mut self, status: CommandStatus) {
+        if status != CommandStatus::Success {
+            self.operate_failures.increment(status);
+        }
+    }
+
+    pub(crate) fn on_unsolicited_series(&mut self) {
+        self.unsolicited_series += 1;
+    }
+
+    pub(crate) fn on_unsolicited_retry(&mut self) {
+        self.unsolicited_retries += 1;
+    }
+
+    pub(crate) fn on_unsolicited_confirm_timeout(&mut self) {
+        self.unsolicited_confirm_timeouts += 1;
+    }
+
+    pub(crate) fn on_solicited_confirm_timeout(&mut self) {
+        self.solicited_confirm_timeouts += 1;
+    }
+}
+
+/// Counts keyed by [`CommandStatus`] in the order each status was first recorded
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CommandStatusCounts {
+    counts: Vec<(CommandStatus, u64)>,
+}
+
+impl CommandStatusCounts {
+    /// Count recorded for the specified status
+    pub fn get(&self, status: CommandStatus) -> u64 {
+        self.counts
+            .iter()
+            .find(|(x, _)| *x == status)
+            .map_or(0, |(_, count)| *count)
+    }
+
+    /// Sum of the counts of every status
+    pub fn total(&self) -> u64 {
+        self.counts.iter().map(|(_, count)| count).sum()
+    }
+
+    /// Iterate over the statuses that have been recorded and their counts
+    pub fn iter(&self) -> impl Iterator<Item = (CommandStatus, u64)> + '_ {
+        self.counts.iter().copied()
+    }
+
+    fn increment(&mut self, status: CommandStatus) {
+        match self.counts.iter_mut().find(|(x, _)| *x == status) {
+            Some((_, count)) => *count += 1,
+            None => self.counts.push((status, 1)),
+        }
+    }
+}
+
+/// Counters maintained by the event buffer for each class
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct EventStatistics {
+    /// Class 1 events
+    pub class1: ClassEventStatistics,
+    /// Class 2 events
+    pub class2: ClassEventStatistics,
+    /// Class 3 events
+    pub class3: ClassEventStatistics,
+}
+
+impl EventStatistics {
+    /// Counters for the specified class
+    pub fn get(&self, class: EventClass) -> &ClassEventStatistics {
+        match class {
+            EventClass::Class1 => &self.class1,
+            EventClass::Class2 => &self.class2,
+            EventClass::Class3 => &self.class3,
+        }
+    }
+
+    pub(crate) fn get_mut(&mut self, class: EventClass) -> &mut ClassEventStatistics {
+        match class {
+            EventClass::Class1 => &mut self.class1,
+            EventClass::Class2 => &mut self.class2,
+            EventClass::Class3 => &mut self.class3,
+        }
+    }
+}
+
+/// Event counters for a single class
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct ClassEventStatistics {
+    /// Number of events created, including events that were discarded because the buffer was full
+    ///
+    /// Events restored from an [`EventJournal`](crate::outstation::database::EventJournal) are
+    /// not counted.
+    pub generated: u64,
+    /// Number of events removed from the buffer after being confirmed by every master
+    pub confirmed: u64,
+    /// Number of events discarded because the buffer was full or its limits were reduced
+    pub discarded: u64,
+}
+
+/// Counters maintained by the link and transport layers
+///
+/// Only frames exchanged with the transport layer are counted. Frames handled entirely by the link
+/// layer, e.g. reset link states requests and the acknowledgements sent in reply, are not counted.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct TransportStatistics {
+    /// Number of link frames received, including link status requests and responses
+    pub link_frames_rx: u64,
+    /// Number of link frames transmitted, including link status requests
+    pub link_frames_tx: u64,
+    /// Number of transport segments received
+    pub segments_rx: u64,
+    /// Number of transport segments transmitted
+    pub segments_tx: u64,
+}
@@ -1,10 +1,13 @@
+use std::sync::{Arc, Mutex};
+
 use crate::app::parse::options::ParseOptions;
 use crate::decode::DecodeLevel;
 use crate::link::reader::LinkModes;
 use crate::link::EndpointAddress;
 use crate::outstation::config::*;
-use crate::outstation::database::DatabaseHandle;
+use crate::outstation::database::{Association, DatabaseHandle};
 use crate::outstation::session::OutstationSession;
+use crate::outstation::statistics::SessionStatistics;
 use crate::outstation::traits::{ControlHandler, OutstationApplication, OutstationInformation};
 use crate::outstation::OutstationHandle;
 use crate::transport::{FragmentAddr, TransportReader, TransportWriter};
@@ -13,6 +16,7 @@ use crate::util::session::{Enabled, RunError, StopReason};
 
 pub(crate) enum ConfigurationChange {
     SetDecodeLevel(DecodeLevel),
+    Reconfigure(Box<OutstationConfig>),
 }
 
 impl From<ConfigurationChange> for OutstationMessage {
@@ -33,6 +37,7 @@ pub(crate) struct OutstationTask {
     reader: TransportReader,
     writer: TransportWriter,
     database: DatabaseHandle,
+    association: Association,
 }
 
 impl OutstationTask {
@@ -48,12 +53,43 @@ impl OutstationTask {
         information: Box<dyn OutstationInformation>,
         control_handler: Box<dyn ControlHandler>,
     ) -> (Self, OutstationHandle) {
-        let (tx, rx) = crate::util::channel::request_channel();
-        let handle = DatabaseHandle::new(
+        let database = DatabaseHandle::new(
             config.max_read_request_headers,
             config.class_zero,
             config.event_buffer_config,
         );
+        let association = database
+            .add_association(config.master_address, config.offline_association_policy)
+            .expect("a new database has no associations");
+        Self::create_with_database(
+            initial_state,
+            link_modes,
+            parse_options,
+            config,
+            phys_addr,
+            application,
+            information,
+            control_handler,
+            database,
+            association,
+        )
+    }
+
+    /// create an `OutstationTask` for an association registered with an existing database
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn create_with_database(
+        initial_state: Enabled,
+        link_modes: LinkModes,
+        parse_options: ParseOptions,
+        config: OutstationConfig,
+        phys_addr: PhysAddr,
+        application: Box<dyn OutstationApplication>,
+        information: Box<dyn OutstationInformation>,
+        control_handler: Box<dyn ControlHandler>,
+        handle: DatabaseHandle,
+        association: Association,
+    ) -> (Self, OutstationHandle) {
+        let (tx, rx) = crate::util::channel::request_channel();
         let (reader, writer) = crate::transport::create_outstation_transport_layer(
             link_modes,
             parse_options,
@@ -61,6 +97,8 @@ impl OutstationTask {
             config.features.self_address,
             config.rx_buffer_size,
         );
+        let statistics = Arc::new(Mutex::new(SessionStatistics::default()));
+        let transport = reader.counters();
         let destination = FragmentAddr {
             link: config.master_address,
             phys: phys_addr,
@@ -70,21 +108,28 @@ impl OutstationTask {
                 initial_state,
                 rx,
                 destination,
+                association,
                 config.into(),
                 config.into(),
                 application,
                 information,
                 control_handler,
+                statistics.clone(),
             ),
             reader,
             writer,
             database: handle.clone(),
+            association,
         };
         (
             task,
             OutstationHandle {
                 database: handle,
+                association,
                 sender: tx,
+                config: Arc::new(Mutex::new(config)),
+                statistics,
+                transport,
             },
         )
     }
@@ -95,14 +140,20 @@ impl OutstationTask {
 
     pub(crate) fn change_master_address(&mut self, address: EndpointAddress) {
         self.session.change_master_address(address);
+        self.database
+            .set_association_master(self.association, address);
     }
 
     /// run the outstation task asynchronously until a `SessionError` occurs
     pub(crate) async fn run(&mut self, io: &mut PhysLayer) -> RunError {
+        // events are retained for the association while it is offline according to its policy
+        self.database.set_association_online(self.association, true);
         let res = self
             .session
             .run(io, &mut self.reader, &mut self.writer, &mut self.database)
             .await;
+        self.database
+            .set_association_online(self.association, false);
 
         self.reader.reset();
         self.writer.reset();
@@ -112,7 +163,7 @@ impl OutstationTask {
 
     /// process received outstation messages while idle without a session
     pub(crate) async fn process_next_message(&mut self) -> Result<(), StopReason> {
-        self.session.process_next_message().await
+        self.session.process_next_message(&mut self.database).await
     }
 
     #[cfg(test)]
@@ -0,0 +1,160 @@
+use std::time::Duration;
+
+use crate::outstation::tests::harness::*;
+use crate::outstation::{ActivationStatus, RequestError};
+
+const EMPTY_RESPONSE: &[u8] = &[0xC0, 0x81, 0x80, 0x00];
+const EMPTY_RESPONSE_NO_FUNC_SUPPORTED: &[u8] = &[0xC0, 0x81, 0x80, 0x01];
+
+fn activate_request(objects: &[&[u8]]) -> Vec<u8> {
+    let mut request = vec![0xC0, 31];
+    for obj in objects {
+        request.extend_from_slice(obj);
+    }
+    request
+}
+
+/// g70v8 free-format header with a file specification string
+fn file_spec(name: &str) -> Vec<u8> {
+    let mut obj = vec![70, 8, 0x5B, 0x01];
+    obj.extend_from_slice(&(name.len() as u16).to_le_bytes());
+    obj.extend_from_slice(name.as_bytes());
+    obj
+}
+
+/// g110 header with a single octet string at the specified index
+fn octet_string(index: u8, value: &str) -> Vec<u8> {
+    let mut obj = vec![110, value.len() as u8, 0x00, index, index];
+    obj.extend_from_slice(value.as_bytes());
+    obj
+}
+
+#[tokio::test]
+async fn save_configuration_reports_success() {
+    let mut harness = new_harness(get_default_config());
+    harness
+        .application_data
+        .lock()
+        .unwrap()
+        .save_configuration_result = Ok(());
+
+    harness
+        .test_request_response(&[0xC0, 19], EMPTY_RESPONSE)
+        .await;
+
+    harness.check_events(&[Event::SaveConfiguration]);
+}
+
+#[tokio::test]
+async fn save_configuration_reports_error_in_iin() {
+    let mut harness = new_harness(get_default_config());
+    harness
+        .application_data
+        .lock()
+        .unwrap()
+        .save_configuration_result = Err(RequestError::ParameterError);
+
+    harness
+        .test_request_response(&[0xC0, 19], &[0xC0, 0x81, 0x80, 0x04])
+        .await;
+
+    harness.check_events(&[Event::SaveConfiguration]);
+}
+
+#[tokio::test]
+async fn activates_files_and_strings_with_delay() {
+    let mut harness = new_harness(get_default_config());
+    harness.application_data.lock().unwrap().activation_delay = Duration::from_millis(1500);
+
+    let request = activate_request(&[&file_spec("config.xml"), &octet_string(3, "settings")]);
+
+    harness
+        .test_request_response(
+            &request,
+            &[
+                0xC0, 0x81, 0x80, 0x00, 91, 1, 0x07, 0x01, 0xDC, 0x05, 0x00, 0x00, 0x02, 0x01,
+                0x00, 0x01, 0x00,
+            ],
+        )
+        .await;
+
+    harness.check_events(&[
+        Event::ActivateConfig(None),
+        Event::ActivateConfig(Some(3)),
+        Event::EndActivateConfig,
+    ]);
+
+    assert_eq!(
+        harness.application_data.lock().unwrap().activated_config,
+        vec!["config.xml".to_string(), "settings".to_string()]
+    );
+}
+
+#[tokio::test]
+async fn reports_failure_status_with_ancillary_data() {
+    let mut harness = new_harness(get_default_config());
+    harness
+        .application_data
+        .lock()
+        .unwrap()
+        .activation_failures
+        .insert(
+            "bad.xml".to_string(),
+            ActivationStatus::failure(4).with_ancillary(b"bad".to_vec()),
+        );
+
+    let request = activate_request(&[&file_spec("good.xml"), &file_spec("bad.xml")]);
+
+    harness
+        .test_request_response(
+            &request,
+            &[
+                0xC0, 0x81, 0x80, 0x00, 91, 1, 0x07, 0x01, 0x00, 0x00, 0x00, 0x00, 0x02, 0x01,
+                0x00, 0x04, 0x04, b'b', b'a', b'd',
+            ],
+        )
+        .await;
+
+    harness.check_events(&[
+        Event::ActivateConfig(None),
+        Event::ActivateConfig(None),
+        Event::EndActivateConfig,
+    ]);
+}
+
+#[tokio::test]
+async fn sets_object_unknown_for_other_objects() {
+    let mut harness = new_harness(get_default_config());
+
+    let request = activate_request(&[&[30, 0, 0x06], &file_spec("config.xml")]);
+
+    harness
+        .test_request_response(
+            &request,
+            &[
+                0xC0, 0x81, 0x80, 0x02, 91, 1, 0x07, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x01, 0x00,
+            ],
+        )
+        .await;
+
+    harness.check_events(&[Event::ActivateConfig(None), Event::EndActivateConfig]);
+}
+
+#[tokio::test]
+async fn rejects_activate_config_when_not_supported() {
+    let mut harness = new_harness(get_default_config());
+    harness
+        .application_data
+        .lock()
+        .unwrap()
+        .support_activate_config = false;
+
+    harness
+        .test_request_response(
+            &activate_request(&[&file_spec("config.xml")]),
+            EMPTY_RESPONSE_NO_FUNC_SUPPORTED,
+        )
+        .await;
+
+    harness.check_no_events();
+}
@@ -0,0 +1,137 @@
+use crate::outstation::tests::harness::*;
+
+const EMPTY_RESPONSE: &[u8] = &[0xC0, 0x81, 0x80, 0x00];
+const EMPTY_RESPONSE_OBJECT_UNKNOWN: &[u8] = &[0xC0, 0x81, 0x80, 0x02];
+const EMPTY_RESPONSE_ALREADY_EXECUTING: &[u8] = &[0xC0, 0x81, 0x80, 0x10];
+
+const INITIALIZE_APPLICATION: u8 = 16;
+const START_APPLICATION: u8 = 17;
+const STOP_APPLICATION: u8 = 18;
+
+/// request with a single g90v1 free-format header
+fn request(function: u8, name: &str) -> Vec<u8> {
+    let mut request = vec![0xC0, function, 90, 1, 0x5B, 0x01];
+    request.extend_from_slice(&(name.len() as u16).to_le_bytes());
+    request.extend_from_slice(name.as_bytes());
+    request
+}
+
+fn add_application(harness: &mut OutstationHarness, name: &str, running: bool) {
+    harness
+        .application_data
+        .lock()
+        .unwrap()
+        .applications
+        .insert(name.to_string(), running);
+}
+
+fn is_running(harness: &OutstationHarness, name: &str) -> bool {
+    *harness
+        .application_data
+        .lock()
+        .unwrap()
+        .applications
+        .get(name)
+        .unwrap()
+}
+
+#[tokio::test]
+async fn starts_and_stops_named_application() {
+    let mut harness = new_harness(get_default_config());
+    add_application(&mut harness, "logic", false);
+
+    harness
+        .test_request_response(&request(START_APPLICATION, "logic"), EMPTY_RESPONSE)
+        .await;
+    harness.check_events(&[Event::StartApplication]);
+    assert!(is_running(&harness, "logic"));
+
+    harness
+        .test_request_response(
+            &[
+                0xC1,
+                STOP_APPLICATION,
+                90,
+                1,
+                0x5B,
+                0x01,
+                5,
+                0,
+                b'l',
+                b'o',
+                b'g',
+                b'i',
+                b'c',
+            ],
+            &[0xC1, 0x81, 0x80, 0x00],
+        )
+        .await;
+    harness.check_events(&[Event::StopApplication]);
+    assert!(!is_running(&harness, "logic"));
+}
+
+#[tokio::test]
+async fn initializes_named_application() {
+    let mut harness = new_harness(get_default_config());
+    add_application(&mut harness, "logic", false);
+
+    harness
+        .test_request_response(&request(INITIALIZE_APPLICATION, "logic"), EMPTY_RESPONSE)
+        .await;
+    harness.check_events(&[Event::InitializeApplication]);
+}
+
+#[tokio::test]
+async fn stops_all_applications() {
+    let mut harness = new_harness(get_default_config());
+    add_application(&mut harness, "logic1", true);
+    add_application(&mut harness, "logic2", true);
+
+    harness
+        .test_request_response(&[0xC0, STOP_APPLICATION, 90, 1, 0x06], EMPTY_RESPONSE)
+        .await;
+    harness.check_events(&[Event::StopApplication]);
+
+    assert!(!is_running(&harness, "logic1"));
+    assert!(!is_running(&harness, "logic2"));
+}
+
+#[tokio::test]
+async fn unknown_application_sets_object_unknown() {
+    let mut harness = new_harness(get_default_config());
+
+    harness
+        .test_request_response(
+            &request(START_APPLICATION, "missing"),
+            EMPTY_RESPONSE_OBJECT_UNKNOWN,
+        )
+        .await;
+    harness.check_events(&[Event::StartApplication]);
+}
+
+#[tokio::test]
+async fn starting_running_application_sets_already_executing() {
+    let mut harness = new_harness(get_default_config());
+    add_application(&mut harness, "logic", true);
+
+    harness
+        .test_request_response(
+            &request(START_APPLICATION, "logic"),
+            EMPTY_RESPONSE_ALREADY_EXECUTING,
+        )
+        .await;
+    harness.check_events(&[Event::StartApplication]);
+}
+
+#[tokio::test]
+async fn other_objects_set_object_unknown() {
+    let mut harness = new_harness(get_default_config());
+
+    harness
+        .test_request_response(
+            &[0xC0, START_APPLICATION, 30, 0, 0x06],
+            EMPTY_RESPONSE_OBJECT_UNKNOWN,
+        )
+        .await;
+    harness.check_no_events();
+}
@@ -0,0 +1,179 @@
+use crate::app::measurement::{AnalogInput, Flags, Time};
+use crate::outstation::database::{
+    Add, AnalogInputConfig, BinaryInputConfig, EventClass, Update, UpdateOptions,
+};
+use crate::outstation::tests::harness::*;
+use crate::outstation::{AssignClassIndices, AssignClassType, ClassAssignment, RequestError};
+
+const EMPTY_RESPONSE: &[u8] = &[0xC0, 0x81, 0x80, 0x00];
+const EMPTY_RESPONSE_NO_FUNC_SUPPORTED: &[u8] = &[0xC0, 0x81, 0x80, 0x01];
+const EMPTY_RESPONSE_OBJECT_UNKNOWN: &[u8] = &[0xC0, 0x81, 0x80, 0x02];
+const EMPTY_RESPONSE_PARAM_ERROR: &[u8] = &[0xC0, 0x81, 0x80, 0x04];
+
+// analog inputs 0 => class 0, 1-2 => class 2
+const ASSIGN_ANALOGS: &[u8] = &[
+    0xC0, 22, 60, 1, 0x06, 30, 0, 0x00, 0, 0, 60, 3, 0x06, 30, 0, 0x00, 1, 2,
+];
+
+fn add_analogs(harness: &mut OutstationHarness) {
+    harness.handle.database.transaction(|db| {
+        for i in 0..3 {
+            db.add(i, Some(EventClass::Class1), AnalogInputConfig::default());
+        }
+    });
+}
+
+fn update_analogs(harness: &mut OutstationHarness) {
+    harness.handle.database.transaction(|db| {
+        for i in 0..3 {
+            db.update(
+                i,
+                &AnalogInput::new(1.0, Flags::ONLINE, Time::synchronized(0)),
+                UpdateOptions::detect_event(),
+            );
+        }
+    });
+}
+
+#[tokio::test]
+async fn assigns_ranges_of_points_to_classes() {
+    let mut harness = new_harness(get_default_config());
+    add_analogs(&mut harness);
+
+    harness
+        .test_request_response(ASSIGN_ANALOGS, EMPTY_RESPONSE)
+        .await;
+
+    harness.check_events(&[
+        Event::AssignClass(ClassAssignment {
+            class: None,
+            point_type: AssignClassType::AnalogInput,
+            indices: AssignClassIndices::Range(0, 0),
+        }),
+        Event::AssignClass(ClassAssignment {
+            class: Some(EventClass::Class2),
+            point_type: AssignClassType::AnalogInput,
+            indices: AssignClassIndices::Range(1, 2),
+        }),
+    ]);
+
+    update_analogs(&mut harness);
+
+    // only class 2 events are now available
+    harness
+        .test_request_response(&[0xC1, 0x01, 60, 4, 0x06], &[0xC1, 0x81, 0x84, 0x00])
+        .await;
+}
+
+#[tokio::test]
+async fn assigns_all_points_of_a_type() {
+    let mut harness = new_harness(get_default_config());
+    harness.handle.database.transaction(|db| {
+        db.add(0, Some(EventClass::Class1), BinaryInputConfig::default());
+    });
+
+    harness
+        .test_request_response(&[0xC0, 22, 60, 4, 0x06, 1, 0, 0x06], EMPTY_RESPONSE)
+        .await;
+
+    harness.check_events(&[Event::AssignClass(ClassAssignment {
+        class: Some(EventClass::Class3),
+        point_type: AssignClassType::BinaryInput,
+        indices: AssignClassIndices::All,
+    })]);
+}
+
+#[tokio::test]
+async fn vetoed_assignment_does_not_modify_database() {
+    let mut harness = new_harness(get_default_config());
+    add_analogs(&mut harness);
+    harness.application_data.lock().unwrap().assign_class_result =
+        Err(RequestError::ParameterError);
+
+    harness
+        .test_request_response(ASSIGN_ANALOGS, EMPTY_RESPONSE_PARAM_ERROR)
+        .await;
+
+    update_analogs(&mut harness);
+
+    // all the events are still class 1
+    harness
+        .test_request_response(&[0xC1, 0x01, 60, 4, 0x06], &[0xC1, 0x81, 0x82, 0x00])
+        .await;
+}
+
+#[tokio::test]
+async fn rejects_range_that_matches_no_points() {
+    let mut harness = new_harness(get_default_config());
+    add_analogs(&mut harness);
+
+    // analog inputs 10-20 => class 2
+    harness
+        .test_request_response(
+            &[0xC0, 22, 60, 3, 0x06, 30, 0, 0x00, 10, 20],
+            EMPTY_RESPONSE_PARAM_ERROR,
+        )
+        .await;
+
+    harness.check_no_events();
+}
+
+#[tokio::test]
+async fn rejects_type_without_points() {
+    let mut harness = new_harness(get_default_config());
+    add_analogs(&mut harness);
+
+    // all binary inputs => class 2
+    harness
+        .test_request_response(
+            &[0xC0, 22, 60, 3, 0x06, 1, 0, 0x06],
+            EMPTY_RESPONSE_OBJECT_UNKNOWN,
+        )
+        .await;
+
+    harness.check_no_events();
+}
+
+#[tokio::test]
+async fn rejects_point_header_without_preceding_class() {
+    let mut harness = new_harness(get_default_config());
+
+    harness
+        .test_request_response(&[0xC0, 22, 30, 0, 0x06], EMPTY_RESPONSE_PARAM_ERROR)
+        .await;
+
+    harness.check_no_events();
+}
+
+#[tokio::test]
+async fn rejects_types_that_cannot_be_assigned() {
+    let mut harness = new_harness(get_default_config());
+
+    harness
+        .test_request_response(
+            &[0xC0, 22, 60, 2, 0x06, 31, 0, 0x06],
+            EMPTY_RESPONSE_OBJECT_UNKNOWN,
+        )
+        .await;
+
+    harness.check_no_events();
+}
+
+#[tokio::test]
+async fn rejects_assign_class_when_not_supported() {
+    let mut harness = new_harness(get_default_config());
+    harness
+        .application_data
+        .lock()
+        .unwrap()
+        .support_assign_class = false;
+
+    harness
+        .test_request_response(
+            &[0xC0, 22, 60, 2, 0x06, 30, 0, 0x06],
+            EMPTY_RESPONSE_NO_FUNC_SUPPORTED,
+        )
+        .await;
+
+    harness.check_no_events();
+}
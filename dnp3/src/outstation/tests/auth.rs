@@ -0,0 +1,778 @@
+use crate::app::auth::*;
+use crate::app::variations::Group41Var2;
+use crate::app::FunctionCode;
+use crate::link::header::BroadcastConfirmMode;
+use crate::outstation::config::OutstationConfig;
+use crate::outstation::tests::harness::*;
+use crate::outstation::traits::OperateType;
+use crate::outstation::{AuthConfig, AuthFailure, BroadcastAction, User};
+
+const UPDATE_KEY: [u8; 16] = [0xAB; 16];
+const G41V2_INDEX_7: Control = Control::G41V2(Group41Var2::new(513), 7);
+// g41v2 - count == 1, index == 7, value = 513, status == SUCCESS
+const G41V2: &[u8] = &[41, 2, 0x17, 0x01, 0x07, 0x01, 0x02, 0x00];
+
+fn config() -> OutstationConfig {
+    let mut config = get_default_config();
+    config.authentication = Some(AuthConfig::default());
+    config
+}
+
+fn create_harness() -> OutstationHarness {
+    let harness = new_harness(config());
+    harness
+        .application_data
+        .lock()
+        .unwrap()
+        .update_keys
+        .insert(DEFAULT_USER, UpdateKey::aes128(UPDATE_KEY));
+    harness
+}
+
+fn request(seq: u8, function: FunctionCode, objects: &[u8]) -> Vec<u8> {
+    let mut data = vec![0xC0 | seq, function.as_u8()];
+    data.extend_from_slice(objects);
+    data
+}
+
+fn auth_request(seq: u8, variation: u8, object: &[u8]) -> Vec<u8> {
+    let mut data = vec![0xC0 | seq, 0x20, 120, variation, 0x5B, 0x01];
+    data.extend_from_slice(&(object.len() as u16).to_le_bytes());
+    data.extend_from_slice(object);
+    data
+}
+
+/// return the object of an authentication response (g120vX) after checking the headers
+fn auth_object(response: &[u8], seq: u8, variation: u8) -> &[u8] {
+    assert_eq!(response[0..2], [0xC0 | seq, 0x83]);
+    assert_eq!(response[4..8], [120, variation, 0x5B, 0x01]);
+    &response[10..]
+}
+
+fn control_response(seq: u8) -> Vec<u8> {
+    let mut data = vec![0xC0 | seq, 0x81, 0x80, 0x00];
+    data.extend_from_slice(G41V2);
+    data
+}
+
+/// perform the session key status and change exchange as the master would
+async fn change_session_keys(harness: &mut OutstationHarness, seq: u8) -> SessionKeys {
+    harness
+        .send_and_process(&[0xC0 | seq, 0x20, 120, 4, 0x07, 0x01, 0x01, 0x00])
+        .await;
+    let response = harness.expect_write().await;
+    let status = auth_object(&response, seq, 5);
+    // user 1, AES-128 key wrap, NOT_INIT, HMAC-SHA-256 truncated to 16 octets, 4 octets of challenge data
+    assert_eq!(status[4..11], [0x01, 0x00, 0x01, 0x02, 0x04, 0x04, 0x00]);
+    assert_eq!(status.len(), 15);
+
+    let keys = SessionKeys::random(16);
+    let mut change = status[0..4].to_vec();
+    change.extend_from_slice(&[0x01, 0x00]);
+    change.extend_from_slice(&wrap_session_keys(
+        &UpdateKey::aes128(UPDATE_KEY),
+        &keys,
+        status,
+    ));
+    let change = auth_request(seq + 1, 6, &change);
+    harness.send_and_process(&change).await;
+
+    let response = harness.expect_write().await;
+    let status = auth_object(&response, seq + 1, 5);
+    assert_eq!(status[4..8], [0x01, 0x00, 0x01, 0x01]);
+    // the outstation proves that it received the keys
+    let mac = calc_mac(
+        MacAlgorithm::HmacSha256Trunc16,
+        &keys.monitoring,
+        &[&change],
+    );
+    assert_eq!(status[15..], mac);
+
+    keys
+}
+
+/// send a critical request and return the challenge after checking that nothing was processed
+async fn expect_challenge(harness: &mut OutstationHarness, request: &[u8]) -> Vec<u8> {
+    harness.send_and_process(request).await;
+    let challenge = harness.expect_write().await;
+    let object = auth_object(&challenge, request[0] & 0x0F, 1);
+    // HMAC-SHA-256 truncated to 16 octets, critical request, 4 octets of challenge data
+    assert_eq!(object[6..8], [0x04, 0x01]);
+    assert_eq!(object.len(), 12);
+    harness.check_no_events();
+    challenge
+}
+
+fn reply(seq: u8, challenge: &[u8], request: &[u8], key: &[u8]) -> Vec<u8> {
+    let mut object = challenge[10..14].to_vec();
+    object.extend_from_slice(&DEFAULT_USER.to_le_bytes());
+    object.extend_from_slice(&calc_mac(
+        MacAlgorithm::HmacSha256Trunc16,
+        key,
+        &[challenge, request],
+    ));
+    auth_request(seq, 2, &object)
+}
+
+/// check an authentication error (g120v7) returning the error code
+async fn expect_error(harness: &mut OutstationHarness, seq: u8, user: u16) -> u8 {
+    let response = harness.expect_write().await;
+    let error = auth_object(&response, seq, 7);
+    assert_eq!(error[4..6], user.to_le_bytes());
+    // association id is the address of the master
+    assert_eq!(error[6..8], [0x01, 0x00]);
+    error[8]
+}
+
+#[tokio::test]
+async fn challenges_critical_request_and_processes_it_after_valid_reply() {
+    let mut harness = create_harness();
+    let keys = change_session_keys(&mut harness, 0).await;
+
+    let operate = request(2, FunctionCode::DirectOperate, G41V2);
+    let challenge = expect_challenge(&mut harness, &operate).await;
+
+    harness
+        .test_request_response(
+            &reply(3, &challenge, &operate, &keys.control),
+            &control_response(3),
+        )
+        .await;
+
+    harness.check_events(&[
+        Event::BeginControls,
+        Event::Operate(G41V2_INDEX_7, OperateType::DirectOperate),
+        Event::EndControls,
+    ]);
+}
+
+#[tokio::test]
+async fn reads_are_not_challenged() {
+    let mut harness = create_harness();
+
+    harness
+        .test_request_response(&[0xC0, 0x01, 60, 1, 0x06], &[0xC0, 0x81, 0x80, 0x00])
+        .await;
+}
+
+#[tokio::test]
+async fn ignores_critical_broadcast_requests() {
+    let mut harness = new_harness_for_broadcast(config(), BroadcastConfirmMode::Mandatory);
+
+    let requests: [(FunctionCode, &[u8]); 2] = [
+        (FunctionCode::DirectOperateNoResponse, G41V2),
+        (FunctionCode::RecordCurrentTime, &[]),
+    ];
+
+    for (function, objects) in requests {
+        harness
+            .send_and_process(&request(0, function, objects))
+            .await;
+        harness.expect_no_response();
+        harness.check_events(&[Event::BroadcastReceived(
+            function,
+            BroadcastAction::UnsupportedFunction(function),
+        )]);
+        harness.check_no_events();
+    }
+}
+
+#[tokio::test]
+async fn rejects_reply_with_bad_mac() {
+    let mut harness = create_harness();
+    change_session_keys(&mut harness, 0).await;
+
+    let operate = request(2, FunctionCode::DirectOperate, G41V2);
+    let challenge = expect_challenge(&mut harness, &operate).await;
+
+    harness
+        .send_and_process(&reply(3, &challenge, &operate, &[0xCD; 16]))
+        .await;
+    assert_eq!(
+        expect_error(&mut harness, 3, DEFAULT_USER).await,
+        AuthErrorCode::AuthenticationFailed.as_u8()
+    );
+    harness.check_events(&[Event::AuthenticationFailure(
+        DEFAULT_USER,
+        AuthFailure::BadMac,
+    )]);
+
+    // the challenged request was discarded
+    harness
+        .send_and_process(&reply(4, &challenge, &operate, &[0xCD; 16]))
+        .await;
+    expect_error(&mut harness, 4, DEFAULT_USER).await;
+    harness.check_events(&[Event::AuthenticationFailure(
+        DEFAULT_USER,
+        AuthFailure::NoChallenge,
+    )]);
+}
+
+#[tokio::test]
+async fn rejects_reply_without_session_keys() {
+    let mut harness = create_harness();
+
+    let operate = request(0, FunctionCode::DirectOperate, G41V2);
+    let challenge = expect_challenge(&mut harness, &operate).await;
+
+    harness
+        .send_and_process(&reply(1, &challenge, &operate, &[0xCD; 16]))
+        .await;
+    expect_error(&mut harness, 1, DEFAULT_USER).await;
+    harness.check_events(&[Event::AuthenticationFailure(
+        DEFAULT_USER,
+        AuthFailure::NoSessionKeys,
+    )]);
+}
+
+#[tokio::test]
+async fn performs_select_before_operate_with_challenges() {
+    let mut harness = create_harness();
+    let keys = change_session_keys(&mut harness, 0).await;
+
+    let select = request(2, FunctionCode::Select, G41V2);
+    let challenge = expect_challenge(&mut harness, &select).await;
+    harness
+        .test_request_response(
+            &reply(3, &challenge, &select, &keys.control),
+            &control_response(3),
+        )
+        .await;
+    harness.check_events(&[
+        Event::BeginControls,
+        Event::Select(G41V2_INDEX_7),
+        Event::EndControls,
+    ]);
+
+    let operate = request(4, FunctionCode::Operate, G41V2);
+    let challenge = expect_challenge(&mut harness, &operate).await;
+    harness
+        .test_request_response(
+            &reply(5, &challenge, &operate, &keys.control),
+            &control_response(5),
+        )
+        .await;
+    harness.check_events(&[
+        Event::BeginControls,
+        Event::Operate(G41V2_INDEX_7, OperateType::SelectBeforeOperate),
+        Event::EndControls,
+    ]);
+}
+
+#[tokio::test]
+async fn accepts_aggressive_mode_requests_after_first_challenge() {
+    let mut harness = create_harness();
+    let keys = change_session_keys(&mut harness, 0).await;
+
+    let operate = request(2, FunctionCode::DirectOperate, G41V2);
+    let challenge = expect_challenge(&mut harness, &operate).await;
+    harness
+        .test_request_response(
+            &reply(3, &challenge, &operate, &keys.control),
+            &control_response(3),
+        )
+        .await;
+    harness.check_events(&[
+        Event::BeginControls,
+        Event::Operate(G41V2_INDEX_7, OperateType::DirectOperate),
+        Event::EndControls,
+    ]);
+
+    let aggressive = |seq: u8, challenge_seq: u32| {
+        let mut request = vec![0xC0 | seq, 0x05, 120, 3, 0x07, 0x01];
+        request.extend_from_slice(&challenge_seq.to_le_bytes());
+        request.extend_from_slice(&DEFAULT_USER.to_le_bytes());
+        request.extend_from_slice(G41V2);
+        let mac = calc_mac(
+            MacAlgorithm::HmacSha256Trunc16,
+            &keys.control,
+            &[&challenge, &request],
+        );
+        request.extend_from_slice(&[120, 9, 0x5B, 0x01, 16, 0x00]);
+        request.extend_from_slice(&mac);
+        request
+    };
+
+    harness
+        .test_request_response(&aggressive(4, 2), &control_response(4))
+        .await;
+    harness.check_events(&[
+        Event::BeginControls,
+        Event::Operate(G41V2_INDEX_7, OperateType::DirectOperate),
+        Event::EndControls,
+    ]);
+
+    // the challenge sequence number can't be reused
+    harness.send_and_process(&aggressive(5, 2)).await;
+    expect_error(&mut harness, 5, DEFAULT_USER).await;
+    harness.check_events(&[Event::AuthenticationFailure(
+        DEFAULT_USER,
+        AuthFailure::UnexpectedChallengeSequence(2),
+    )]);
+}
+
+#[tokio::test]
+async fn rejects_unauthorized_function() {
+    let mut harness = create_harness();
+    harness
+        .application_data
+        .lock()
+        .unwrap()
+        .denied_functions
+        .push(FunctionCode::DirectOperate);
+    let keys = change_session_keys(&mut harness, 0).await;
+
+    let operate = request(2, FunctionCode::DirectOperate, G41V2);
+    let challenge = expect_challenge(&mut harness, &operate).await;
+
+    harness
+        .send_and_process(&reply(3, &challenge, &operate, &keys.control))
+        .await;
+    assert_eq!(
+        expect_error(&mut harness, 3, DEFAULT_USER).await,
+        AuthErrorCode::AuthorizationFailed.as_u8()
+    );
+    harness.check_events(&[Event::AuthenticationFailure(
+        DEFAULT_USER,
+        AuthFailure::Unauthorized(FunctionCode::DirectOperate),
+    )]);
+}
+
+#[tokio::test]
+async fn rejects_key_status_request_for_unknown_user() {
+    let mut harness = create_harness();
+
+    harness
+        .send_and_process(&[0xC0, 0x20, 120, 4, 0x07, 0x01, 0x02, 0x00])
+        .await;
+    assert_eq!(
+        expect_error(&mut harness, 0, 2).await,
+        AuthErrorCode::UnknownUser.as_u8()
+    );
+    harness.check_events(&[Event::AuthenticationFailure(2, AuthFailure::UnknownUser)]);
+}
+
+#[tokio::test]
+async fn reports_key_change_with_wrong_update_key() {
+    let mut harness = create_harness();
+
+    harness
+        .send_and_process(&[0xC0, 0x20, 120, 4, 0x07, 0x01, 0x01, 0x00])
+        .await;
+    let response = harness.expect_write().await;
+    let status = auth_object(&response, 0, 5);
+
+    let mut change = status[0..4].to_vec();
+    change.extend_from_slice(&[0x01, 0x00]);
+    change.extend_from_slice(&wrap_session_keys(
+        &UpdateKey::aes128([0xCD; 16]),
+        &SessionKeys::random(16),
+        status,
+    ));
+    harness.send_and_process(&auth_request(1, 6, &change)).await;
+
+    // the new key status is AUTH_FAIL without a MAC
+    let response = harness.expect_write().await;
+    let status = auth_object(&response, 1, 5);
+    assert_eq!(
+        status[0..8],
+        [0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x04]
+    );
+    assert_eq!(status.len(), 15);
+    harness.check_events(&[Event::AuthenticationFailure(
+        DEFAULT_USER,
+        AuthFailure::KeyChangeFailed,
+    )]);
+}
+
+const AUTHORITY_KEY: [u8; 32] = [0x11; 32];
+const NEW_UPDATE_KEY: [u8; 32] = [0x22; 32];
+const USER_PUBLIC_KEY: [u8; 8] = [0x33; 8];
+const MASTER_CHALLENGE_DATA: [u8; 4] = [0x0A, 0x0B, 0x0C, 0x0D];
+
+const KCM_SYMMETRIC: u8 = 4;
+const KCM_ASYMMETRIC: u8 = 68;
+const OPERATION_ADD: u8 = 1;
+const OPERATION_DELETE: u8 = 2;
+
+fn create_provisioning_harness() -> OutstationHarness {
+    let harness = create_harness();
+    harness.application_data.lock().unwrap().authority_key = Some(AuthorityKey::new(AUTHORITY_KEY));
+    harness
+}
+
+/// user status change (g120v10) for an operator certified with `certification`
+fn status_change<F>(
+    seq: u8,
+    kcm: u8,
+    operation: u8,
+    status_change_seq: u32,
+    public_key: &[u8],
+    certification: F,
+) -> Vec<u8>
+where
+    F: FnOnce(&[u8]) -> Vec<u8>,
+{
+    let mut object = vec![kcm, operation];
+    object.extend_from_slice(&status_change_seq.to_le_bytes());
+    // operator role that expires after 30 days
+    object.extend_from_slice(&[0x01, 0x00, 30, 0x00]);
+    object.extend_from_slice(&[0x03, 0x00]);
+    object.extend_from_slice(&(public_key.len() as u16).to_le_bytes());
+    object.extend_from_slice(&[0x00, 0x00]);
+    object.extend_from_slice(b"bob");
+    object.extend_from_slice(public_key);
+
+    let certification_data = certification(&object);
+    object[14..16].copy_from_slice(&(certification_data.len() as u16).to_le_bytes());
+    object.extend_from_slice(&certification_data);
+    auth_request(seq, 10, &object)
+}
+
+fn certify_symmetric(data: &[u8]) -> Vec<u8> {
+    hmac_sha256(&AUTHORITY_KEY, &[data])
+}
+
+async fn add_user(harness: &mut OutstationHarness, seq: u8) {
+    harness
+        .test_request_response(
+            &status_change(seq, KCM_SYMMETRIC, OPERATION_ADD, 1, &[], certify_symmetric),
+            &[0xC0 | seq, 0x81, 0x80, 0x00],
+        )
+        .await;
+}
+
+/// send an update key change request (g120v11) for "bob" returning the reply (g120v12)
+async fn request_update_key_change(harness: &mut OutstationHarness, seq: u8, kcm: u8) -> Vec<u8> {
+    let mut object = vec![kcm, 0x03, 0x00, 0x04, 0x00];
+    object.extend_from_slice(b"bob");
+    object.extend_from_slice(&MASTER_CHALLENGE_DATA);
+    harness
+        .send_and_process(&auth_request(seq, 11, &object))
+        .await;
+
+    let response = harness.expect_write().await;
+    let reply = auth_object(&response, seq, 12).to_vec();
+    // bob is the second user, followed by 4 octets of challenge data
+    assert_eq!(reply[4..8], [0x02, 0x00, 0x04, 0x00]);
+    assert_eq!(reply.len(), 12);
+    reply
+}
+
+/// update key change (g120v13) followed by its proof (g120v14 or g120v15)
+fn update_key_change(
+    seq: u8,
+    reply: &[u8],
+    encrypted: &[u8],
+    variation: u8,
+    proof: &[u8],
+) -> Vec<u8> {
+    let mut object = reply[0..6].to_vec();
+    object.extend_from_slice(&(encrypted.len() as u16).to_le_bytes());
+    object.extend_from_slice(encrypted);
+    let mut request = auth_request(seq, 13, &object);
+    request.extend_from_slice(&[120, variation, 0x5B, 0x01]);
+    request.extend_from_slice(&(proof.len() as u16).to_le_bytes());
+    request.extend_from_slice(proof);
+    request
+}
+
+fn key_change_seq(reply: &[u8]) -> u32 {
+    u32::from_le_bytes(reply[0..4].try_into().unwrap())
+}
+
+fn symmetric_update_key_change(
+    seq: u8,
+    reply: &[u8],
+    outstation_name: &str,
+    challenge_data: &[u8],
+) -> Vec<u8> {
+    let update_key = UpdateKey::aes256(NEW_UPDATE_KEY);
+    let plaintext = update_key_data(&update_key, b"bob", &reply[8..]);
+    let mac = update_key_confirmation(
+        &update_key,
+        outstation_name.as_bytes(),
+        challenge_data,
+        &reply[8..],
+        key_change_seq(reply),
+        2,
+    );
+    update_key_change(
+        seq,
+        reply,
+        &wrap_update_key(&AuthorityKey::new(AUTHORITY_KEY), &plaintext),
+        15,
+        &mac,
+    )
+}
+
+/// check the confirmation (g120v15) of an update key change
+async fn expect_confirmation(harness: &mut OutstationHarness, seq: u8, reply: &[u8]) {
+    let response = harness.expect_write().await;
+    let mac = update_key_confirmation(
+        &UpdateKey::aes256(NEW_UPDATE_KEY),
+        b"bob",
+        &reply[8..],
+        &MASTER_CHALLENGE_DATA,
+        key_change_seq(reply),
+        2,
+    );
+    assert_eq!(auth_object(&response, seq, 15), mac);
+}
+
+fn get_user(harness: &OutstationHarness, number: u16) -> Option<User> {
+    harness
+        .application_data
+        .lock()
+        .unwrap()
+        .users
+        .get(&number)
+        .cloned()
+}
+
+#[tokio::test]
+async fn adds_user_and_changes_its_update_key_with_symmetric_method() {
+    let mut harness = create_provisioning_harness();
+    add_user(&mut harness, 0).await;
+
+    assert_eq!(
+        get_user(&harness, 2),
+        Some(User {
+            number: 2,
+            name: "bob".to_string(),
+            role: UserRole::Operator,
+            role_expiry_days: 30,
+            public_key: Vec::new(),
+            update_key: None,
+        })
+    );
+
+    let reply = request_update_key_change(&mut harness, 1, KCM_SYMMETRIC).await;
+    harness
+        .send_and_process(&symmetric_update_key_change(
+            2,
+            &reply,
+            OUTSTATION_NAME,
+            &MASTER_CHALLENGE_DATA,
+        ))
+        .await;
+    expect_confirmation(&mut harness, 2, &reply).await;
+
+    assert_eq!(
+        get_user(&harness, 2).unwrap().update_key,
+        Some(UpdateKey::aes256(NEW_UPDATE_KEY))
+    );
+
+    // session keys of the new user are now wrapped with its AES-256 update key
+    harness
+        .send_and_process(&[0xC3, 0x20, 120, 4, 0x07, 0x01, 0x02, 0x00])
+        .await;
+    let response = harness.expect_write().await;
+    assert_eq!(auth_object(&response, 3, 5)[4..8], [0x02, 0x00, 0x02, 0x02]);
+    harness.check_no_events();
+}
+
+#[tokio::test]
+async fn rejects_replayed_user_status_change() {
+    let mut harness = create_provisioning_harness();
+    add_user(&mut harness, 0).await;
+
+    harness
+        .send_and_process(&status_change(
+            1,
+            KCM_SYMMETRIC,
+            OPERATION_DELETE,
+            1,
+            &[],
+            certify_symmetric,
+        ))
+        .await;
+    assert_eq!(
+        expect_error(&mut harness, 1, 0).await,
+        AuthErrorCode::AuthenticationFailed.as_u8()
+    );
+    harness.check_events(&[Event::AuthenticationFailure(
+        0,
+        AuthFailure::UnexpectedStatusChangeSequence(1),
+    )]);
+    assert!(get_user(&harness, 2).is_some());
+}
+
+#[tokio::test]
+async fn rejects_user_status_change_with_bad_certification() {
+    let mut harness = create_provisioning_harness();
+
+    harness
+        .send_and_process(&status_change(
+            0,
+            KCM_SYMMETRIC,
+            OPERATION_ADD,
+            1,
+            &[],
+            |data| hmac_sha256(&[0xCD; 32], &[data]),
+        ))
+        .await;
+    assert_eq!(
+        expect_error(&mut harness, 0, 0).await,
+        AuthErrorCode::InvalidCertificationData.as_u8()
+    );
+    harness.check_events(&[Event::AuthenticationFailure(
+        0,
+        AuthFailure::InvalidCertification,
+    )]);
+
+    let data = harness.application_data.lock().unwrap();
+    assert!(data.users.is_empty());
+    assert_eq!(data.last_status_change_seq, 0);
+}
+
+#[tokio::test]
+async fn rejects_asymmetric_method_unless_permitted() {
+    let mut harness = create_provisioning_harness();
+    add_user(&mut harness, 0).await;
+
+    let mut object = vec![KCM_ASYMMETRIC, 0x03, 0x00, 0x04, 0x00];
+    object.extend_from_slice(b"bob");
+    object.extend_from_slice(&MASTER_CHALLENGE_DATA);
+    harness
+        .send_and_process(&auth_request(1, 11, &object))
+        .await;
+    assert_eq!(
+        expect_error(&mut harness, 1, 0).await,
+        AuthErrorCode::UpdateKeyChangeMethodNotPermitted.as_u8()
+    );
+    harness.check_events(&[Event::AuthenticationFailure(
+        0,
+        AuthFailure::KeyChangeMethodNotPermitted(KCM_ASYMMETRIC),
+    )]);
+}
+
+#[tokio::test]
+async fn changes_update_key_with_asymmetric_method() {
+    let mut harness = create_provisioning_harness();
+    harness.application_data.lock().unwrap().permit_asymmetric = true;
+
+    harness
+        .test_request_response(
+            &status_change(
+                0,
+                KCM_ASYMMETRIC,
+                OPERATION_ADD,
+                1,
+                &USER_PUBLIC_KEY,
+                |data| hmac_sha256(&AUTHORITY_SIGNING_KEY, &[data]),
+            ),
+            &[0xC0, 0x81, 0x80, 0x00],
+        )
+        .await;
+    assert_eq!(get_user(&harness, 2).unwrap().public_key, USER_PUBLIC_KEY);
+
+    let reply = request_update_key_change(&mut harness, 1, KCM_ASYMMETRIC).await;
+    let encrypted: Vec<u8> =
+        update_key_data(&UpdateKey::aes256(NEW_UPDATE_KEY), b"bob", &reply[8..])
+            .iter()
+            .map(|x| x ^ ASYMMETRIC_MASK)
+            .collect();
+    let signature = hmac_sha256(
+        &USER_PUBLIC_KEY,
+        &[&update_key_signature_data(
+            &MASTER_CHALLENGE_DATA,
+            &reply[8..],
+            key_change_seq(&reply),
+            2,
+            &encrypted,
+        )],
+    );
+    harness
+        .send_and_process(&update_key_change(2, &reply, &encrypted, 14, &signature))
+        .await;
+    expect_confirmation(&mut harness, 2, &reply).await;
+
+    assert_eq!(
+        get_user(&harness, 2).unwrap().update_key,
+        Some(UpdateKey::aes256(NEW_UPDATE_KEY))
+    );
+}
+
+#[tokio::test]
+async fn rejects_update_key_change_with_bad_mac() {
+    let mut harness = create_provisioning_harness();
+    add_user(&mut harness, 0).await;
+
+    let reply = request_update_key_change(&mut harness, 1, KCM_SYMMETRIC).await;
+    harness
+        .send_and_process(&symmetric_update_key_change(
+            2,
+            &reply,
+            OUTSTATION_NAME,
+            &[0x00; 4],
+        ))
+        .await;
+    assert_eq!(
+        expect_error(&mut harness, 2, 2).await,
+        AuthErrorCode::AuthenticationFailed.as_u8()
+    );
+    harness.check_events(&[Event::AuthenticationFailure(
+        2,
+        AuthFailure::UpdateKeyChangeFailed,
+    )]);
+    assert_eq!(get_user(&harness, 2).unwrap().update_key, None);
+}
+
+#[tokio::test]
+async fn rejects_update_key_change_confirmed_for_another_outstation() {
+    let mut harness = create_provisioning_harness();
+    add_user(&mut harness, 0).await;
+
+    let reply = request_update_key_change(&mut harness, 1, KCM_SYMMETRIC).await;
+    harness
+        .send_and_process(&symmetric_update_key_change(
+            2,
+            &reply,
+            "other",
+            &MASTER_CHALLENGE_DATA,
+        ))
+        .await;
+    assert_eq!(
+        expect_error(&mut harness, 2, 2).await,
+        AuthErrorCode::AuthenticationFailed.as_u8()
+    );
+    harness.check_events(&[Event::AuthenticationFailure(
+        2,
+        AuthFailure::UpdateKeyChangeFailed,
+    )]);
+    assert_eq!(get_user(&harness, 2).unwrap().update_key, None);
+}
+
+#[tokio::test]
+async fn deleted_user_can_not_change_its_update_key() {
+    let mut harness = create_provisioning_harness();
+    add_user(&mut harness, 0).await;
+
+    harness
+        .test_request_response(
+            &status_change(
+                1,
+                KCM_SYMMETRIC,
+                OPERATION_DELETE,
+                2,
+                &[],
+                certify_symmetric,
+            ),
+            &[0xC1, 0x81, 0x80, 0x00],
+        )
+        .await;
+    assert!(get_user(&harness, 2).is_none());
+
+    let mut object = vec![KCM_SYMMETRIC, 0x03, 0x00, 0x04, 0x00];
+    object.extend_from_slice(b"bob");
+    object.extend_from_slice(&MASTER_CHALLENGE_DATA);
+    harness
+        .send_and_process(&auth_request(2, 11, &object))
+        .await;
+    assert_eq!(
+        expect_error(&mut harness, 2, 0).await,
+        AuthErrorCode::UnknownUser.as_u8()
+    );
+    harness.check_events(&[Event::AuthenticationFailure(0, AuthFailure::UnknownUser)]);
+}
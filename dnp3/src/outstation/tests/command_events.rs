@@ -0,0 +1,210 @@
+use crate::app::control::*;
+use crate::app::measurement::*;
+use crate::app::variations::Group41Var2;
+use crate::outstation::config::Feature;
+use crate::outstation::database::*;
+use crate::outstation::tests::harness::*;
+use crate::outstation::traits::OperateType;
+
+const READ_CLASS_123: &[u8] = &[0xC0, 0x01, 60, 2, 0x06, 60, 3, 0x06, 60, 4, 0x06];
+// direct operate, seq == 0, g41v2 - count == 1, index == 7, value = 513, status == SUCCESS
+const DIRECT_OPERATE_SEQ0_G41V2: &[u8] = &[0xC0, 0x05, 41, 2, 0x17, 0x01, 0x07, 0x01, 0x02, 0x00];
+// direct operate, seq == 0, g12v1 - count == 1, index == 3, LATCH_ON, count == 1, on/off == 1000
+const DIRECT_OPERATE_SEQ0_G12V1: &[u8] = &[
+    0xC0, 0x05, 12, 1, 0x17, 0x01, 0x03, 0x03, 0x01, 0xE8, 0x03, 0x00, 0x00, 0xE8, 0x03, 0x00,
+    0x00, 0x00,
+];
+// response, seq == 0, restart IIN + echo of request headers
+const RESPONSE_SEQ0_G41V2_SUCCESS: &[u8] = &[
+    0xC0, 0x81, 0x80, 0x00, 41, 2, 0x17, 0x1, 0x07, 0x01, 0x02, 0x00,
+];
+// same as above, but with class 1 events available in IIN1
+const RESPONSE_SEQ0_G41V2_SUCCESS_WITH_EVENT: &[u8] = &[
+    0xC0, 0x81, 0x82, 0x00, 41, 2, 0x17, 0x1, 0x07, 0x01, 0x02, 0x00,
+];
+
+fn add_analog_command_point(database: &mut Database) {
+    database.add(
+        7,
+        Some(EventClass::Class1),
+        AnalogOutputCommandEventConfig::default(),
+    );
+}
+
+#[tokio::test]
+async fn reports_binary_command_events_in_class_read() {
+    let mut harness = new_harness(get_default_config());
+
+    harness.handle.database.transaction(|db| {
+        db.add(
+            3,
+            Some(EventClass::Class1),
+            BinaryOutputCommandEventConfig::default(),
+        );
+        db.update(
+            3,
+            &BinaryOutputCommandEvent {
+                commanded_state: true,
+                status: CommandStatus::Success,
+                time: None,
+            },
+            UpdateOptions::default(),
+        );
+    });
+
+    harness
+        .test_request_response(
+            READ_CLASS_123,
+            &[
+                0xE0, 0x81, 0x80, 0x00, 13, 1, 0x28, 0x01, 0x00, 0x03, 0x00, 0x80,
+            ],
+        )
+        .await;
+
+    harness.check_events(&[Event::EnterSolicitedConfirmWait(0)]);
+}
+
+#[tokio::test]
+async fn reads_analog_command_events_with_specific_variation() {
+    let mut harness = new_harness(get_default_config());
+
+    harness.handle.database.transaction(|db| {
+        add_analog_command_point(db);
+        db.update(
+            7,
+            &AnalogOutputCommandEvent {
+                status: CommandStatus::Success,
+                commanded_value: AnalogCommandValue::F32(2.5),
+                time: None,
+            },
+            UpdateOptions::default(),
+        );
+    });
+
+    // read g43v2 (16-bit) which truncates the floating point value
+    harness
+        .test_request_response(
+            &[0xC0, 0x01, 43, 2, 0x06],
+            &[
+                0xE0, 0x81, 0x80, 0x00, 43, 2, 0x28, 0x01, 0x00, 0x07, 0x00, 0x00, 0x02, 0x00,
+            ],
+        )
+        .await;
+
+    harness.check_events(&[Event::EnterSolicitedConfirmWait(0)]);
+}
+
+#[tokio::test]
+async fn operate_produces_command_event_when_enabled() {
+    let mut config = get_default_config();
+    config.features.command_events = Feature::Enabled;
+    let mut harness = new_harness(config);
+
+    harness
+        .handle
+        .database
+        .transaction(add_analog_command_point);
+
+    harness
+        .test_request_response(
+            DIRECT_OPERATE_SEQ0_G41V2,
+            RESPONSE_SEQ0_G41V2_SUCCESS_WITH_EVENT,
+        )
+        .await;
+
+    harness.check_events(&[
+        Event::BeginControls,
+        Event::Operate(
+            Control::G41V2(Group41Var2::new(513), 7),
+            OperateType::DirectOperate,
+        ),
+        Event::EndControls,
+    ]);
+
+    // g43v1 with status == SUCCESS and value == 513
+    harness
+        .test_request_response(
+            &[0xC1, 0x01, 60, 2, 0x06],
+            &[
+                0xE1, 0x81, 0x80, 0x00, 43, 1, 0x28, 0x01, 0x00, 0x07, 0x00, 0x00, 0x01, 0x02,
+                0x00, 0x00,
+            ],
+        )
+        .await;
+
+    harness.check_events(&[Event::EnterSolicitedConfirmWait(1)]);
+}
+
+#[tokio::test]
+async fn latch_on_produces_binary_command_event_with_state_set() {
+    let mut config = get_default_config();
+    config.features.command_events = Feature::Enabled;
+    let mut harness = new_harness(config);
+
+    harness.handle.database.transaction(|db| {
+        db.add(
+            3,
+            Some(EventClass::Class1),
+            BinaryOutputCommandEventConfig::default(),
+        )
+    });
+
+    // class 1 events are available in IIN1
+    let mut response = vec![0xC0, 0x81, 0x82, 0x00];
+    response.extend_from_slice(&DIRECT_OPERATE_SEQ0_G12V1[2..]);
+    harness
+        .test_request_response(DIRECT_OPERATE_SEQ0_G12V1, &response)
+        .await;
+
+    harness.check_events(&[
+        Event::BeginControls,
+        Event::Operate(
+            Control::G12V1(
+                Group12Var1::from_code(ControlCode::from_op_type(OpType::LatchOn)),
+                3,
+            ),
+            OperateType::DirectOperate,
+        ),
+        Event::EndControls,
+    ]);
+
+    harness
+        .test_request_response(
+            &[0xC1, 0x01, 13, 0, 0x06],
+            &[
+                0xE1, 0x81, 0x80, 0x00, 13, 1, 0x28, 0x01, 0x00, 0x03, 0x00, 0x80,
+            ],
+        )
+        .await;
+
+    harness.check_events(&[Event::EnterSolicitedConfirmWait(1)]);
+}
+
+#[tokio::test]
+async fn operate_does_not_produce_command_event_when_disabled() {
+    let mut harness = new_harness(get_default_config());
+
+    harness
+        .handle
+        .database
+        .transaction(add_analog_command_point);
+
+    harness
+        .test_request_response(DIRECT_OPERATE_SEQ0_G41V2, RESPONSE_SEQ0_G41V2_SUCCESS)
+        .await;
+
+    harness.check_events(&[
+        Event::BeginControls,
+        Event::Operate(
+            Control::G41V2(Group41Var2::new(513), 7),
+            OperateType::DirectOperate,
+        ),
+        Event::EndControls,
+    ]);
+
+    harness
+        .test_request_response(&[0xC1, 0x01, 60, 2, 0x06], &[0xC1, 0x81, 0x80, 0x00])
+        .await;
+
+    harness.check_no_events();
+}
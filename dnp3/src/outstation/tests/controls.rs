@@ -1,6 +1,7 @@
 use tokio::time::Duration;
 
-use crate::app::variations::Group41Var2;
+use crate::app::control::{ControlCode, OpType};
+use crate::app::variations::{Group12Var1, Group41Var2};
 use crate::app::FunctionCode;
 use crate::link::header::BroadcastConfirmMode;
 use crate::outstation::config::Feature;
@@ -37,6 +38,21 @@ const RESPONSE_SEQ2_G41V2_NO_SELECT: &[u8] = &[
 const RESPONSE_SEQ1_G41V2_INDEX8_NO_SELECT: &[u8] = &[
     0xC1, 0x81, 0x80, 0x00, 41, 2, 0x17, 0x1, 0x08, 0x01, 0x02, 0x02,
 ];
+// direct operate, seq == 0, g12v2 - count == 1, LATCH_ON, on/off == 100ms, g12v3 - start == 2, stop == 5, mask == 1011
+const DIRECT_OPERATE_SEQ0_PATTERN: &[u8] = &[
+    0xC0, 0x05, 12, 2, 0x07, 0x01, 0x03, 0x01, 0x64, 0x00, 0x00, 0x00, 0x64, 0x00, 0x00, 0x00,
+    0x00, 12, 3, 0x00, 0x02, 0x05, 0x0B,
+];
+// response, seq == 0, restart IIN + echo of request headers
+const RESPONSE_SEQ0_PATTERN_SUCCESS: &[u8] = &[
+    0xC0, 0x81, 0x80, 0x00, 12, 2, 0x07, 0x01, 0x03, 0x01, 0x64, 0x00, 0x00, 0x00, 0x64, 0x00,
+    0x00, 0x00, 0x00, 12, 3, 0x00, 0x02, 0x05, 0x0B,
+];
+// response, seq == 0, restart IIN + echo of request headers but with STATUS == 8 (TOO_MANY_OPS)
+const RESPONSE_SEQ0_PATTERN_TOO_MANY_OPS: &[u8] = &[
+    0xC0, 0x81, 0x80, 0x00, 12, 2, 0x07, 0x01, 0x03, 0x01, 0x64, 0x00, 0x00, 0x00, 0x64, 0x00,
+    0x00, 0x00, 0x08, 12, 3, 0x00, 0x02, 0x05, 0x0B,
+];
 // response, seq == 1, restart IIN + echo of request headers but with STATUS == 1 (TIMEOUT)
 const RESPONSE_SEQ1_G41V2_SELECT_TIMEOUT: &[u8] = &[
     0xC1, 0x81, 0x80, 0x00, 41, 2, 0x17, 0x1, 0x07, 0x01, 0x02, 0x01,
@@ -254,3 +270,62 @@ async fn accept_two_identical_selects_before_operate() {
         Event::EndControls,
     ]);
 }
+
+fn pattern_crob(index: u16) -> Control {
+    Control::G12V1(
+        Group12Var1::new(ControlCode::from_op_type(OpType::LatchOn), 1, 100, 100),
+        index,
+    )
+}
+
+#[tokio::test]
+async fn pattern_control_operates_each_output_selected_by_the_mask() {
+    let mut harness = new_harness(get_default_config());
+
+    harness
+        .test_request_response(DIRECT_OPERATE_SEQ0_PATTERN, RESPONSE_SEQ0_PATTERN_SUCCESS)
+        .await;
+
+    harness.check_events(&[
+        Event::BeginControls,
+        Event::Operate(pattern_crob(2), OperateType::DirectOperate),
+        Event::Operate(pattern_crob(3), OperateType::DirectOperate),
+        Event::Operate(pattern_crob(5), OperateType::DirectOperate),
+        Event::EndControls,
+    ]);
+}
+
+#[tokio::test]
+async fn pattern_control_counts_each_selected_output_against_the_limit() {
+    let mut config = get_default_config();
+    config.max_controls_per_request = Some(2);
+    let mut harness = new_harness(config);
+
+    harness
+        .test_request_response(
+            DIRECT_OPERATE_SEQ0_PATTERN,
+            RESPONSE_SEQ0_PATTERN_TOO_MANY_OPS,
+        )
+        .await;
+
+    harness.check_events(&[
+        Event::BeginControls,
+        Event::Operate(pattern_crob(2), OperateType::DirectOperate),
+        Event::Operate(pattern_crob(3), OperateType::DirectOperate),
+        Event::EndControls,
+    ]);
+}
+
+#[tokio::test]
+async fn rejects_pattern_control_without_a_mask() {
+    let mut harness = new_harness(get_default_config());
+
+    harness
+        .test_request_response(
+            &DIRECT_OPERATE_SEQ0_PATTERN[..17],
+            &[0xC0, 0x81, 0x80, 0x04],
+        )
+        .await;
+
+    harness.check_no_events();
+}
@@ -0,0 +1,203 @@
+use crate::app::data_set::*;
+use crate::app::Timestamp;
+use crate::outstation::database::EventClass::Class1;
+use crate::outstation::database::*;
+use crate::outstation::tests::harness::*;
+
+const UUID: [u8; UUID_LENGTH] = [0xAA; UUID_LENGTH];
+const READ_CLASS_1: &[u8] = &[0xC0, 0x01, 60, 2, 0x06];
+const EMPTY_RESPONSE: &[u8] = &[0xC0, 0x81, 0x80, 0x00];
+
+// g87v1, id == 1, time == 1, values == [7, "hi"]
+const G87V1_ID1: &[u8] = &[
+    87, 1, 0x5B, 0x01, 0x0E, 0x00, 0x01, 0x01, 0x06, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+    0x07, 0x02, b'h', b'i',
+];
+
+fn data_set() -> DataSet {
+    DataSet::new(
+        Timestamp::new(1),
+        vec![
+            DataSetValue::UnsignedInt(7),
+            DataSetValue::VisibleString("hi".to_string()),
+        ],
+    )
+}
+
+fn add_data_set(harness: &mut OutstationHarness) {
+    harness.handle.transaction(|db| {
+        assert!(db.define_data_set_prototype(
+            0,
+            DataSetPrototype::new(
+                UUID,
+                vec![DescriptorElement::data_element(
+                    DataSetType::UnsignedInt,
+                    2,
+                    "x"
+                )]
+            )
+        ));
+        assert!(db.add(
+            1,
+            Some(Class1),
+            DataSetDescriptor::new(vec![
+                DescriptorElement::prototype(UUID),
+                DescriptorElement::data_element(DataSetType::VisibleString, 8, "s"),
+            ])
+        ));
+    });
+}
+
+fn with_header(header: &[u8], objects: &[u8]) -> Vec<u8> {
+    let mut bytes = header.to_vec();
+    bytes.extend_from_slice(objects);
+    bytes
+}
+
+#[tokio::test]
+async fn cannot_add_data_set_with_undefined_prototype() {
+    let harness = new_harness(get_default_config());
+    harness.handle.transaction(|db| {
+        assert!(!db.add(
+            1,
+            Some(Class1),
+            DataSetDescriptor::new(vec![DescriptorElement::prototype(UUID)])
+        ));
+    });
+}
+
+#[tokio::test]
+async fn can_read_prototypes_and_descriptors() {
+    let mut harness = new_harness(get_default_config());
+    add_data_set(&mut harness);
+
+    let mut prototype = vec![
+        0xC0, 0x81, 0x80, 0x00, 85, 1, 0x5B, 0x01, 0x18, 0x00, 0x01, 0x00, 0x10,
+    ];
+    prototype.extend_from_slice(&UUID);
+    prototype.extend_from_slice(&[0x04, 0x05, 0x02, 0x02, b'x']);
+
+    harness
+        .test_request_response(&[0xC0, 0x01, 85, 1, 0x06], &prototype)
+        .await;
+
+    let mut descriptor = vec![
+        0xC1, 0x81, 0x80, 0x00, 86, 1, 0x5B, 0x01, 0x1B, 0x00, 0x01, 0x01, 0x13, 0x06, 0x00, 0x00,
+    ];
+    descriptor.extend_from_slice(&UUID);
+    descriptor.extend_from_slice(&[0x04, 0x05, 0x01, 0x08, b's']);
+
+    harness
+        .test_request_response(&[0xC1, 0x01, 86, 1, 0x06], &descriptor)
+        .await;
+
+    harness.check_no_events();
+}
+
+#[tokio::test]
+async fn update_produces_present_value_and_event() {
+    let mut harness = new_harness(get_default_config());
+    add_data_set(&mut harness);
+
+    // nothing to report before the first update
+    harness
+        .test_request_response(&[0xC0, 0x01, 87, 1, 0x06], EMPTY_RESPONSE)
+        .await;
+
+    harness.handle.transaction(|db| {
+        assert_eq!(
+            db.update2(1, &data_set(), UpdateOptions::default()),
+            UpdateInfo::Created(0)
+        );
+        assert_eq!(db.get(1), Some(data_set()));
+    });
+
+    harness
+        .test_request_response(
+            &[0xC1, 0x01, 87, 1, 0x06],
+            &with_header(&[0xC1, 0x81, 0x82, 0x00], G87V1_ID1),
+        )
+        .await;
+
+    let mut event = with_header(&[0xE2, 0x81, 0x80, 0x00], G87V1_ID1);
+    event[4] = 88;
+    harness
+        .test_request_response(&[0xC2, 0x01, 60, 2, 0x06], &event)
+        .await;
+
+    harness.check_events(&[Event::EnterSolicitedConfirmWait(2)]);
+}
+
+#[tokio::test]
+async fn rejects_update_that_does_not_match_descriptor() {
+    let mut harness = new_harness(get_default_config());
+    add_data_set(&mut harness);
+
+    harness.handle.transaction(|db| {
+        let value = DataSet::new(Timestamp::new(1), vec![DataSetValue::UnsignedInt(7)]);
+        assert_eq!(
+            db.update2(1, &value, UpdateOptions::default()),
+            UpdateInfo::NoPoint
+        );
+        assert_eq!(db.get(1), None::<DataSet>);
+    });
+
+    harness
+        .test_request_response(READ_CLASS_1, EMPTY_RESPONSE)
+        .await;
+}
+
+#[tokio::test]
+async fn write_updates_present_value() {
+    let mut harness = new_harness(get_default_config());
+    add_data_set(&mut harness);
+
+    harness
+        .test_request_response(
+            &with_header(&[0xC0, 0x02], G87V1_ID1),
+            &[0xC0, 0x81, 0x82, 0x00],
+        )
+        .await;
+
+    harness.check_events(&[Event::WriteDataSet(1)]);
+    harness.handle.transaction(|db| {
+        assert_eq!(db.get(1), Some(data_set()));
+    });
+}
+
+#[tokio::test]
+async fn write_is_rejected_by_application() {
+    let mut harness = new_harness(get_default_config());
+    add_data_set(&mut harness);
+    harness
+        .application_data
+        .lock()
+        .unwrap()
+        .accept_data_set_writes = false;
+
+    harness
+        .test_request_response(
+            &with_header(&[0xC0, 0x02], G87V1_ID1),
+            &[0xC0, 0x81, 0x80, 0x04],
+        )
+        .await;
+
+    harness.check_events(&[Event::WriteDataSet(1)]);
+    harness.handle.transaction(|db| {
+        assert_eq!(db.get(1), None::<DataSet>);
+    });
+}
+
+#[tokio::test]
+async fn write_of_undefined_data_set_is_rejected() {
+    let mut harness = new_harness(get_default_config());
+
+    harness
+        .test_request_response(
+            &with_header(&[0xC0, 0x02], G87V1_ID1),
+            &[0xC0, 0x81, 0x80, 0x04],
+        )
+        .await;
+
+    harness.check_no_events();
+}
@@ -0,0 +1,550 @@
+use scursor::WriteCursor;
+
+use crate::app::format::free_format::FreeFormat;
+use crate::app::format::write::HeaderWriter;
+use crate::app::*;
+use crate::master::FileMode;
+use crate::outstation::database::EventClass;
+use crate::outstation::tests::harness::*;
+use crate::outstation::FileTransportMode;
+
+const HELLO_WORLD: &[u8] = b"hello world!";
+const BLOCK_SIZE: u16 = 5;
+
+fn last_block(block: u32) -> u32 {
+    (1 << 31) | block
+}
+
+fn request<T: FreeFormat>(function: FunctionCode, seq: u8, variation: &T) -> Vec<u8> {
+    let mut request = [0xC0 | seq, function.as_u8()].to_vec();
+    request.extend_from_slice(&encode(variation));
+    request
+}
+
+fn response<T: FreeFormat>(seq: u8, iin1: u8, variation: &T) -> Vec<u8> {
+    let mut response = [0xC0 | seq, 0x81, iin1, 0x00].to_vec();
+    response.extend_from_slice(&encode(variation));
+    response
+}
+
+fn encode<T: FreeFormat>(variation: &T) -> Vec<u8> {
+    let mut buffer: [u8; 256] = [0; 256];
+    let mut cursor = WriteCursor::new(&mut buffer);
+    let mut writer = HeaderWriter::new(&mut cursor);
+    writer.write_free_format(variation).unwrap();
+    cursor.written().to_vec()
+}
+
+fn open_request(seq: u8, file_name: &str, mode: FileMode, max_block_size: u16) -> Vec<u8> {
+    request(
+        FunctionCode::OpenFile,
+        seq,
+        &Group70Var3 {
+            time_of_creation: Timestamp::zero(),
+            permissions: Permissions::default(),
+            auth_key: 0,
+            file_size: 0,
+            mode,
+            max_block_size,
+            request_id: 7,
+            file_name,
+        },
+    )
+}
+
+fn command_status(
+    handle: u32,
+    size: u32,
+    block_size: u16,
+    status: FileStatus,
+) -> Group70Var4<'static> {
+    Group70Var4 {
+        file_handle: handle,
+        file_size: size,
+        max_block_size: block_size,
+        request_id: 7,
+        status_code: status,
+        text: "",
+    }
+}
+
+fn read_request(seq: u8, handle: u32, block_number: u32) -> Vec<u8> {
+    request(
+        FunctionCode::Read,
+        seq,
+        &Group70Var5 {
+            file_handle: handle,
+            block_number,
+            file_data: &[],
+        },
+    )
+}
+
+fn transport_response(seq: u8, handle: u32, block_number: u32, file_data: &[u8]) -> Vec<u8> {
+    response(
+        seq,
+        0x80,
+        &Group70Var5 {
+            file_handle: handle,
+            block_number,
+            file_data,
+        },
+    )
+}
+
+fn close_request(seq: u8, handle: u32) -> Vec<u8> {
+    request(
+        FunctionCode::CloseFile,
+        seq,
+        &command_status(handle, 0, 0, FileStatus::Success),
+    )
+}
+
+fn add_file(harness: &OutstationHarness, name: &str, contents: &[u8]) {
+    harness
+        .application_data
+        .lock()
+        .unwrap()
+        .files
+        .insert(name.to_string(), contents.to_vec());
+}
+
+async fn open_hello_world(harness: &mut OutstationHarness) {
+    add_file(harness, "hello.txt", HELLO_WORLD);
+    harness
+        .test_request_response(
+            &open_request(0, "hello.txt", FileMode::Read, BLOCK_SIZE),
+            &response(
+                0,
+                0x80,
+                &command_status(1, HELLO_WORLD.len() as u32, BLOCK_SIZE, FileStatus::Success),
+            ),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn authenticate_returns_key_on_success() {
+    let mut harness = new_harness(get_default_config());
+
+    harness
+        .test_request_response(
+            &request(
+                FunctionCode::AuthenticateFile,
+                0,
+                &Group70Var2 {
+                    auth_key: 0,
+                    user_name: MockFileSystem::USER_NAME,
+                    password: MockFileSystem::PASSWORD,
+                },
+            ),
+            &response(
+                0,
+                0x80,
+                &Group70Var2 {
+                    auth_key: MockFileSystem::AUTH_KEY,
+                    user_name: "",
+                    password: "",
+                },
+            ),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn authenticate_returns_zero_key_on_failure() {
+    let mut harness = new_harness(get_default_config());
+
+    harness
+        .test_request_response(
+            &request(
+                FunctionCode::AuthenticateFile,
+                0,
+                &Group70Var2 {
+                    auth_key: 0,
+                    user_name: MockFileSystem::USER_NAME,
+                    password: "wrong",
+                },
+            ),
+            &response(
+                0,
+                0x80,
+                &Group70Var2 {
+                    auth_key: 0,
+                    user_name: "",
+                    password: "",
+                },
+            ),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn can_read_file_in_blocks() {
+    let mut harness = new_harness(get_default_config());
+
+    open_hello_world(&mut harness).await;
+
+    harness
+        .test_request_response(
+            &read_request(1, 1, 0),
+            &transport_response(1, 1, 0, b"hello"),
+        )
+        .await;
+    harness
+        .test_request_response(
+            &read_request(2, 1, 1),
+            &transport_response(2, 1, 1, b" worl"),
+        )
+        .await;
+    harness
+        .test_request_response(
+            &read_request(3, 1, 2),
+            &transport_response(3, 1, last_block(2), b"d!"),
+        )
+        .await;
+    harness
+        .test_request_response(
+            &close_request(4, 1),
+            &response(4, 0x80, &command_status(1, 0, 0, FileStatus::Success)),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn repeated_block_request_returns_same_data() {
+    let mut harness = new_harness(get_default_config());
+
+    open_hello_world(&mut harness).await;
+
+    harness
+        .test_request_response(
+            &read_request(1, 1, 0),
+            &transport_response(1, 1, 0, b"hello"),
+        )
+        .await;
+    harness
+        .test_request_response(
+            &read_request(2, 1, 0),
+            &transport_response(2, 1, 0, b"hello"),
+        )
+        .await;
+    harness
+        .test_request_response(
+            &read_request(3, 1, 1),
+            &transport_response(3, 1, 1, b" worl"),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn out_of_sequence_block_returns_transport_status() {
+    let mut harness = new_harness(get_default_config());
+
+    open_hello_world(&mut harness).await;
+
+    harness
+        .test_request_response(
+            &read_request(1, 1, 1),
+            &response(
+                1,
+                0x80,
+                &Group70Var6 {
+                    file_handle: 1,
+                    block_number: 1,
+                    status_code: FileStatus::BlockSeq,
+                    text: "",
+                },
+            ),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn read_with_unknown_handle_returns_transport_status() {
+    let mut harness = new_harness(get_default_config());
+
+    harness
+        .test_request_response(
+            &read_request(0, 42, 0),
+            &response(
+                0,
+                0x80,
+                &Group70Var6 {
+                    file_handle: 42,
+                    block_number: 0,
+                    status_code: FileStatus::InvalidHandle,
+                    text: "",
+                },
+            ),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn open_missing_file_returns_error_status() {
+    let mut harness = new_harness(get_default_config());
+
+    harness
+        .test_request_response(
+            &open_request(0, "missing.txt", FileMode::Read, BLOCK_SIZE),
+            &response(0, 0x80, &command_status(0, 0, 0, FileStatus::FileNotFound)),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn can_write_file_in_blocks() {
+    let mut harness = new_harness(get_default_config());
+
+    harness
+        .test_request_response(
+            &open_request(0, "new.txt", FileMode::Write, BLOCK_SIZE),
+            &response(
+                0,
+                0x80,
+                &command_status(1, 0, BLOCK_SIZE, FileStatus::Success),
+            ),
+        )
+        .await;
+
+    for (seq, block, data) in [(1, 0, b"abcd".as_slice()), (2, last_block(1), b"ef")] {
+        harness
+            .test_request_response(
+                &request(
+                    FunctionCode::Write,
+                    seq,
+                    &Group70Var5 {
+                        file_handle: 1,
+                        block_number: block,
+                        file_data: data,
+                    },
+                ),
+                &response(
+                    seq,
+                    0x80,
+                    &Group70Var6 {
+                        file_handle: 1,
+                        block_number: block,
+                        status_code: FileStatus::Success,
+                        text: "",
+                    },
+                ),
+            )
+            .await;
+    }
+
+    harness
+        .test_request_response(
+            &close_request(3, 1),
+            &response(3, 0x80, &command_status(1, 0, 0, FileStatus::Success)),
+        )
+        .await;
+
+    assert_eq!(
+        harness.application_data.lock().unwrap().files["new.txt"],
+        b"abcdef"
+    );
+}
+
+#[tokio::test]
+async fn can_delete_file() {
+    let mut harness = new_harness(get_default_config());
+
+    add_file(&harness, "old.txt", HELLO_WORLD);
+
+    harness
+        .test_request_response(
+            &request(
+                FunctionCode::DeleteFile,
+                0,
+                &Group70Var3 {
+                    time_of_creation: Timestamp::zero(),
+                    permissions: Permissions::default(),
+                    auth_key: MockFileSystem::AUTH_KEY,
+                    file_size: 0,
+                    mode: FileMode::Null,
+                    max_block_size: 0,
+                    request_id: 7,
+                    file_name: "old.txt",
+                },
+            ),
+            &response(0, 0x80, &command_status(0, 0, 0, FileStatus::Success)),
+        )
+        .await;
+
+    assert!(harness.application_data.lock().unwrap().files.is_empty());
+}
+
+#[tokio::test]
+async fn get_file_info_returns_descriptor() {
+    let mut harness = new_harness(get_default_config());
+
+    add_file(&harness, "hello.txt", HELLO_WORLD);
+
+    let descriptor = Group70Var7 {
+        file_type: FileType::File,
+        file_size: HELLO_WORLD.len() as u32,
+        time_of_creation: Timestamp::zero(),
+        permissions: Permissions::default(),
+        request_id: 7,
+        file_name: "hello.txt",
+    };
+
+    harness
+        .test_request_response(
+            &request(
+                FunctionCode::GetFileInfo,
+                0,
+                &Group70Var7 {
+                    file_size: 0,
+                    ..descriptor
+                },
+            ),
+            &response(0, 0x80, &descriptor),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn get_info_for_missing_file_returns_command_status() {
+    let mut harness = new_harness(get_default_config());
+
+    harness
+        .test_request_response(
+            &request(
+                FunctionCode::GetFileInfo,
+                0,
+                &Group70Var7 {
+                    file_type: FileType::File,
+                    file_size: 0,
+                    time_of_creation: Timestamp::zero(),
+                    permissions: Permissions::default(),
+                    request_id: 7,
+                    file_name: "missing.txt",
+                },
+            ),
+            &response(0, 0x80, &command_status(0, 0, 0, FileStatus::FileNotFound)),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn reading_directory_returns_descriptors() {
+    let mut harness = new_harness(get_default_config());
+
+    add_file(&harness, "a", b"1");
+    add_file(&harness, "b", b"22");
+
+    let mut listing = Vec::new();
+    for (name, size) in [("a", 1), ("b", 2)] {
+        let mut buffer = [0; 64];
+        let mut cursor = WriteCursor::new(&mut buffer);
+        Group70Var7 {
+            file_type: FileType::File,
+            file_size: size,
+            time_of_creation: Timestamp::zero(),
+            permissions: Permissions::default(),
+            request_id: 0,
+            file_name: name,
+        }
+        .write(&mut cursor)
+        .unwrap();
+        listing.extend_from_slice(cursor.written());
+    }
+
+    // both descriptors fit within a single block
+    harness
+        .test_request_response(
+            &open_request(0, MockFileSystem::ROOT, FileMode::Read, 64),
+            &response(
+                0,
+                0x80,
+                &command_status(1, listing.len() as u32, 64, FileStatus::Success),
+            ),
+        )
+        .await;
+
+    harness
+        .test_request_response(
+            &read_request(1, 1, 0),
+            &transport_response(1, 1, last_block(0), &listing),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn event_mode_returns_file_responses_as_events() {
+    let mut harness = new_harness(get_default_config());
+
+    add_file(&harness, "hello.txt", HELLO_WORLD);
+    harness.application_data.lock().unwrap().file_transport_mode =
+        FileTransportMode::Event(EventClass::Class1);
+
+    // the open is acknowledged with a null response indicating class 1 events
+    harness
+        .test_request_response(
+            &open_request(0, "hello.txt", FileMode::Read, BLOCK_SIZE),
+            &[0xC0, 0x81, 0x82, 0x00],
+        )
+        .await;
+
+    // the command status is returned as an event
+    let mut expected = response(
+        1,
+        0x80,
+        &command_status(1, HELLO_WORLD.len() as u32, BLOCK_SIZE, FileStatus::Success),
+    );
+    expected[0] = 0xE1;
+
+    harness
+        .test_request_response(&[0xC1, 0x01, 60, 2, 0x06], &expected)
+        .await;
+}
+
+#[tokio::test]
+async fn open_files_are_aborted_when_the_link_drops() {
+    let mut harness = new_harness(get_default_config());
+
+    open_hello_world(&mut harness).await;
+    harness.disconnect().await;
+
+    assert_eq!(
+        harness.application_data.lock().unwrap().aborted_files,
+        ["hello.txt"]
+    );
+}
+
+#[tokio::test]
+async fn open_files_are_aborted_when_the_outstation_stops() {
+    let mut harness = new_harness(get_default_config());
+
+    open_hello_world(&mut harness).await;
+    harness.stop().await;
+
+    assert_eq!(
+        harness.application_data.lock().unwrap().aborted_files,
+        ["hello.txt"]
+    );
+}
+
+#[tokio::test]
+async fn closed_files_are_not_aborted() {
+    let mut harness = new_harness(get_default_config());
+
+    open_hello_world(&mut harness).await;
+    harness
+        .test_request_response(
+            &close_request(1, 1),
+            &response(1, 0x80, &command_status(1, 0, 0, FileStatus::Success)),
+        )
+        .await;
+    harness.disconnect().await;
+
+    assert!(harness
+        .application_data
+        .lock()
+        .unwrap()
+        .aborted_files
+        .is_empty());
+}
@@ -1,10 +1,24 @@
+use std::time::{Duration, SystemTime};
+
+use crate::app::measurement::{Counter, Flags, FrozenCounter, Time};
 use crate::app::Timestamp;
+use crate::outstation::config::{Feature, OutstationConfig};
+use crate::outstation::database::*;
 use crate::outstation::tests::harness::*;
-use crate::outstation::{FreezeIndices, FreezeInterval, FreezeType};
+use crate::outstation::{
+    FreezeIndices, FreezeInterval, FreezeType, IntervalUnits, TimeAndInterval,
+};
 
 const EMPTY_RESPONSE: &[u8] = &[0xC0, 0x81, 0x80, 0x00];
 const EMPTY_RESPONSE_PARAM_ERROR: &[u8] = &[0xC0, 0x81, 0x80, 0x04];
 const EMPTY_RESPONSE_NO_FUNC_SUPPORTED: &[u8] = &[0xC0, 0x81, 0x80, 0x01];
+// same as EMPTY_RESPONSE, but with class 1 events available in IIN1
+const EMPTY_RESPONSE_WITH_EVENT: &[u8] = &[0xC0, 0x81, 0x82, 0x00];
+const READ_CLASS_1_SEQ1: &[u8] = &[0xC1, 0x01, 60, 2, 0x06];
+// g23v1 - count == 1, index == 0, flags == ONLINE, value == 42
+const RESPONSE_SEQ1_FROZEN_COUNTER_EVENT: &[u8] = &[
+    0xE1, 0x81, 0x80, 0x00, 23, 1, 0x28, 0x01, 0x00, 0x00, 0x00, 0x01, 42, 0x00, 0x00, 0x00,
+];
 
 #[tokio::test]
 async fn immediate_freeze_all_counters() {
@@ -186,3 +200,228 @@ async fn freeze_at_time_no_ack() {
 
     assert_eq!(harness.io.pop_event(), None);
 }
+
+fn freeze_engine_config() -> OutstationConfig {
+    let mut config = get_default_config();
+    config.features.freeze_engine = Feature::Enabled;
+    config
+}
+
+fn new_freeze_engine_harness() -> OutstationHarness {
+    let harness = new_harness(freeze_engine_config());
+
+    harness.handle.database.transaction(|db| {
+        db.add(0, None, CounterConfig::default());
+        db.add(0, Some(EventClass::Class1), FrozenCounterConfig::default());
+        db.update(
+            0,
+            &Counter::new(42, Flags::ONLINE, Time::synchronized(0)),
+            UpdateOptions::no_event(),
+        );
+        assert!(db.link_frozen_counter(0, 0));
+    });
+
+    harness
+}
+
+fn get_counter(harness: &OutstationHarness) -> Counter {
+    harness
+        .handle
+        .database
+        .transaction(|db| Get::<Counter>::get(db, 0))
+        .unwrap()
+}
+
+fn get_frozen_counter(harness: &OutstationHarness) -> FrozenCounter {
+    harness
+        .handle
+        .database
+        .transaction(|db| Get::<FrozenCounter>::get(db, 0))
+        .unwrap()
+}
+
+#[tokio::test]
+async fn freeze_engine_copies_linked_counter_and_produces_event() {
+    let mut harness = new_freeze_engine_harness();
+
+    harness
+        .test_request_response(&[0xC0, 0x07, 20, 0, 0x06], EMPTY_RESPONSE_WITH_EVENT)
+        .await;
+
+    // the application is not invoked
+    harness.check_no_events();
+
+    assert_eq!(get_frozen_counter(&harness).value, 42);
+    assert_eq!(get_counter(&harness).value, 42);
+
+    harness
+        .test_request_response(READ_CLASS_1_SEQ1, RESPONSE_SEQ1_FROZEN_COUNTER_EVENT)
+        .await;
+
+    harness.check_events(&[Event::EnterSolicitedConfirmWait(1)]);
+}
+
+#[tokio::test]
+async fn freeze_engine_clears_counter_on_freeze_and_clear() {
+    let mut harness = new_freeze_engine_harness();
+
+    harness
+        .test_request_response(&[0xC0, 0x09, 20, 0, 0x00, 0, 0], EMPTY_RESPONSE_WITH_EVENT)
+        .await;
+
+    harness.check_no_events();
+
+    assert_eq!(get_frozen_counter(&harness).value, 42);
+    assert_eq!(get_counter(&harness).value, 0);
+}
+
+#[tokio::test]
+async fn freeze_engine_ignores_counters_outside_of_range() {
+    let mut harness = new_freeze_engine_harness();
+
+    harness
+        .test_request_response(&[0xC0, 0x07, 20, 0, 0x00, 1, 5], EMPTY_RESPONSE)
+        .await;
+
+    harness.check_no_events();
+
+    assert_eq!(get_frozen_counter(&harness).value, 0);
+}
+
+#[tokio::test]
+async fn cannot_link_counter_that_does_not_exist() {
+    let harness = new_harness(freeze_engine_config());
+
+    harness.handle.database.transaction(|db| {
+        db.add(0, None, FrozenCounterConfig::default());
+        assert!(!db.link_frozen_counter(0, 0));
+        assert!(!db.unlink_frozen_counter(0));
+    });
+}
+
+#[tokio::test]
+async fn freeze_engine_performs_scheduled_freeze() {
+    let mut harness = new_freeze_engine_harness();
+
+    let freeze_time = Timestamp::try_from_system_time(SystemTime::now())
+        .unwrap()
+        .checked_add(Duration::from_secs(1))
+        .unwrap();
+
+    // freeze once at the specified time with an interval of 0
+    let mut request = vec![0xC0, 0x0B, 50, 2, 0x07, 0x01];
+    request.extend_from_slice(&freeze_time.raw_value().to_le_bytes()[0..6]);
+    request.extend_from_slice(&[0x00, 0x00, 0x00, 0x00, 20, 0, 0x06]);
+
+    harness
+        .test_request_response(&request, EMPTY_RESPONSE)
+        .await;
+
+    harness.check_no_events();
+    assert_eq!(get_frozen_counter(&harness).value, 0);
+
+    tokio::time::pause();
+    tokio::time::advance(Duration::from_secs(2)).await;
+
+    harness
+        .test_request_response(READ_CLASS_1_SEQ1, RESPONSE_SEQ1_FROZEN_COUNTER_EVENT)
+        .await;
+
+    harness.check_events(&[Event::EnterSolicitedConfirmWait(1)]);
+}
+
+// g50v4 - count == 1, one byte index, 6 byte time, 4 byte interval, 1 byte units
+fn write_time_and_interval(index: u8, value: TimeAndInterval) -> Vec<u8> {
+    let mut request = vec![0xC0, 0x02, 50, 4, 0x17, 0x01, index];
+    request.extend_from_slice(&value.time.raw_value().to_le_bytes()[0..6]);
+    request.extend_from_slice(&value.interval.to_le_bytes());
+    request.push(value.units.to_u8());
+    request
+}
+
+#[tokio::test]
+async fn write_time_and_interval_invokes_application() {
+    let mut harness = new_harness(get_default_config());
+
+    let value = TimeAndInterval::new(Timestamp::new(0x0102030405), 5, IntervalUnits::Seconds);
+
+    harness
+        .test_request_response(&write_time_and_interval(3, value), EMPTY_RESPONSE)
+        .await;
+
+    harness.check_events(&[Event::WriteTimeAndInterval(3, value)]);
+}
+
+#[tokio::test]
+async fn write_time_and_interval_rejected_by_application() {
+    let mut harness = new_harness(get_default_config());
+    harness
+        .application_data
+        .lock()
+        .unwrap()
+        .accept_time_and_interval_writes = false;
+
+    let value = TimeAndInterval::new(Timestamp::zero(), 1, IntervalUnits::Months);
+
+    harness
+        .test_request_response(
+            &write_time_and_interval(0, value),
+            EMPTY_RESPONSE_PARAM_ERROR,
+        )
+        .await;
+
+    harness.check_events(&[Event::WriteTimeAndInterval(0, value)]);
+}
+
+#[tokio::test]
+async fn freeze_engine_applies_time_and_interval_as_schedule() {
+    let mut harness = new_freeze_engine_harness();
+
+    let freeze_time = Timestamp::try_from_system_time(SystemTime::now())
+        .unwrap()
+        .checked_add(Duration::from_secs(1))
+        .unwrap();
+    let value = TimeAndInterval::new(freeze_time, 0, IntervalUnits::NoRepeat);
+
+    harness
+        .test_request_response(&write_time_and_interval(0, value), EMPTY_RESPONSE)
+        .await;
+
+    harness.check_events(&[Event::WriteTimeAndInterval(0, value)]);
+    assert_eq!(get_frozen_counter(&harness).value, 0);
+
+    tokio::time::pause();
+    tokio::time::advance(Duration::from_secs(2)).await;
+
+    harness
+        .test_request_response(READ_CLASS_1_SEQ1, RESPONSE_SEQ1_FROZEN_COUNTER_EVENT)
+        .await;
+
+    harness.check_events(&[Event::EnterSolicitedConfirmWait(1)]);
+}
+
+#[tokio::test]
+async fn freeze_engine_rejects_time_and_interval_that_cannot_be_scheduled() {
+    let mut harness = new_freeze_engine_harness();
+
+    // months do not have a fixed length
+    let value = TimeAndInterval::new(Timestamp::zero(), 1, IntervalUnits::Months);
+    harness
+        .test_request_response(
+            &write_time_and_interval(0, value),
+            EMPTY_RESPONSE_PARAM_ERROR,
+        )
+        .await;
+
+    // counter 1 is not linked to a frozen counter
+    let value = TimeAndInterval::new(Timestamp::zero(), 1, IntervalUnits::Hours);
+    harness
+        .test_request_response(
+            &write_time_and_interval(1, value),
+            EMPTY_RESPONSE_PARAM_ERROR,
+        )
+        .await;
+
+    // the application is not invoked
+    harness.check_no_events();
+}
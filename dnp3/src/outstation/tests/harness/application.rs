@@ -1,19 +1,77 @@
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
-use crate::app::{MaybeAsync, Timestamp};
+use crate::app::auth::{hmac_sha256, AuthorityKey, KeyChangeMethod, UpdateKey};
+use crate::app::data_set::DataSet;
+use crate::app::measurement::OctetString;
+use crate::app::{FunctionCode, MaybeAsync, Timestamp};
 use crate::outstation::database::DatabaseHandle;
-use crate::outstation::tests::harness::{Event, EventSender};
-use crate::outstation::traits::{OutstationApplication, RequestError, RestartDelay};
-use crate::outstation::{BufferState, FreezeIndices, FreezeType};
+use crate::outstation::tests::harness::{Event, EventSender, MockFileSystem};
+use crate::outstation::traits::{
+    OutstationApplication, RequestError, RestartDelay, TimeAndInterval, VirtualTerminalHandler,
+    WriteOctetString,
+};
+use crate::outstation::{
+    ActivationStatus, ApplicationControlError, ApplicationId, AuthHandler, BufferState,
+    ClassAssignment, ConfigObject, FileTransportMode, FreezeIndices, FreezeType, KeyStore,
+    OutstationFileSystem, User,
+};
+
+/// key of the authority used to "sign" status changes when the asymmetric method is permitted
+pub(crate) const AUTHORITY_SIGNING_KEY: [u8; 8] = [0x77; 8];
+/// name the authority assigned to the outstation
+pub(crate) const OUTSTATION_NAME: &str = "outstation";
+/// update key changes are "encrypted" by XOR with this value when the asymmetric method is permitted
+pub(crate) const ASYMMETRIC_MASK: u8 = 0x5A;
 
 pub(crate) struct MockOutstationApplication {
     events: EventSender,
     data: Arc<Mutex<ApplicationData>>,
+    file_system: MockFileSystem,
+    virtual_terminal: MockVirtualTerminal,
+    auth: MockAuthHandler,
+}
+
+/// update keys, denied functions, and the key store come from the shared application data
+///
+/// The asymmetric method is emulated with an HMAC in place of signatures and a XOR mask
+/// in place of encryption
+struct MockAuthHandler {
+    data: Arc<Mutex<ApplicationData>>,
+}
+
+/// echoes everything written to a port back to the master as g113 events
+struct MockVirtualTerminal {
+    events: EventSender,
 }
 
 pub(crate) struct ApplicationData {
     pub(crate) processing_delay: u16,
     pub(crate) restart_delay: Option<RestartDelay>,
+    pub(crate) files: HashMap<String, Vec<u8>>,
+    /// names of the files aborted by the outstation, in order
+    pub(crate) aborted_files: Vec<String>,
+    pub(crate) file_transport_mode: FileTransportMode,
+    pub(crate) support_assign_class: bool,
+    pub(crate) assign_class_result: Result<(), RequestError>,
+    pub(crate) save_configuration_result: Result<(), RequestError>,
+    pub(crate) support_activate_config: bool,
+    pub(crate) activated_config: Vec<String>,
+    pub(crate) activation_failures: HashMap<String, ActivationStatus>,
+    pub(crate) activation_delay: Duration,
+    /// known applications and whether they are running
+    pub(crate) applications: HashMap<String, bool>,
+    pub(crate) accept_data_set_writes: bool,
+    pub(crate) octet_string_writes: WriteOctetString,
+    pub(crate) accept_time_and_interval_writes: bool,
+    pub(crate) support_virtual_terminal: bool,
+    pub(crate) update_keys: HashMap<u16, UpdateKey>,
+    pub(crate) denied_functions: Vec<FunctionCode>,
+    pub(crate) authority_key: Option<AuthorityKey>,
+    pub(crate) permit_asymmetric: bool,
+    pub(crate) last_status_change_seq: u32,
+    pub(crate) users: HashMap<u16, User>,
 }
 
 impl ApplicationData {
@@ -21,6 +79,52 @@ impl ApplicationData {
         Self {
             processing_delay: 0,
             restart_delay: None,
+            files: HashMap::new(),
+            aborted_files: Vec::new(),
+            file_transport_mode: FileTransportMode::Immediate,
+            support_assign_class: true,
+            assign_class_result: Ok(()),
+            save_configuration_result: Err(RequestError::NotSupported),
+            support_activate_config: true,
+            activated_config: Vec::new(),
+            activation_failures: HashMap::new(),
+            activation_delay: Duration::ZERO,
+            applications: HashMap::new(),
+            accept_data_set_writes: true,
+            octet_string_writes: WriteOctetString::Reject,
+            accept_time_and_interval_writes: true,
+            support_virtual_terminal: true,
+            update_keys: HashMap::new(),
+            denied_functions: Vec::new(),
+            authority_key: None,
+            permit_asymmetric: false,
+            last_status_change_seq: 0,
+            users: HashMap::new(),
+        }
+    }
+}
+
+impl ApplicationData {
+    fn set_running(
+        &mut self,
+        id: ApplicationId,
+        running: bool,
+    ) -> Result<(), ApplicationControlError> {
+        match id {
+            ApplicationId::All => {
+                for state in self.applications.values_mut() {
+                    *state = running;
+                }
+                Ok(())
+            }
+            ApplicationId::Name(name) => match self.applications.get_mut(name) {
+                None => Err(ApplicationControlError::UnknownApplication),
+                Some(state) if *state == running => Err(ApplicationControlError::AlreadyExecuting),
+                Some(state) => {
+                    *state = running;
+                    Ok(())
+                }
+            },
         }
     }
 }
@@ -30,7 +134,17 @@ impl MockOutstationApplication {
         events: EventSender,
     ) -> (Arc<Mutex<ApplicationData>>, Box<dyn OutstationApplication>) {
         let data = Arc::new(Mutex::new(ApplicationData::new()));
-        (data.clone(), Box::new(Self { events, data }))
+        let file_system = MockFileSystem::new(data.clone());
+        (
+            data.clone(),
+            Box::new(Self {
+                events: events.clone(),
+                data: data.clone(),
+                file_system,
+                virtual_terminal: MockVirtualTerminal { events },
+                auth: MockAuthHandler { data },
+            }),
+        )
     }
 }
 
@@ -83,6 +197,107 @@ impl OutstationApplication for MockOutstationApplication {
         MaybeAsync::ready(())
     }
 
+    fn file_system(&mut self) -> Option<&mut dyn OutstationFileSystem> {
+        Some(&mut self.file_system)
+    }
+
+    fn virtual_terminal(&mut self) -> Option<&mut dyn VirtualTerminalHandler> {
+        if self.data.lock().unwrap().support_virtual_terminal {
+            Some(&mut self.virtual_terminal)
+        } else {
+            None
+        }
+    }
+
+    fn auth_handler(&mut self) -> Option<&mut dyn AuthHandler> {
+        Some(&mut self.auth)
+    }
+
+    fn support_assign_class(&mut self) -> bool {
+        self.data.lock().unwrap().support_assign_class
+    }
+
+    fn assign_class(&mut self, assignment: ClassAssignment) -> Result<(), RequestError> {
+        self.events.send(Event::AssignClass(assignment));
+        self.data.lock().unwrap().assign_class_result
+    }
+
+    fn save_configuration(&mut self) -> Result<(), RequestError> {
+        self.events.send(Event::SaveConfiguration);
+        self.data.lock().unwrap().save_configuration_result
+    }
+
+    fn support_activate_config(&mut self) -> bool {
+        self.data.lock().unwrap().support_activate_config
+    }
+
+    fn activate_config(&mut self, object: ConfigObject) -> ActivationStatus {
+        let (index, name) = match object {
+            ConfigObject::File(name) => (None, name.to_string()),
+            ConfigObject::String { index, value } => {
+                (Some(index), String::from_utf8_lossy(value).to_string())
+            }
+        };
+        self.events.send(Event::ActivateConfig(index));
+        let mut data = self.data.lock().unwrap();
+        let status = data
+            .activation_failures
+            .get(&name)
+            .cloned()
+            .unwrap_or_else(ActivationStatus::success);
+        data.activated_config.push(name);
+        status
+    }
+
+    fn end_activate_config(&mut self) -> Duration {
+        self.events.send(Event::EndActivateConfig);
+        self.data.lock().unwrap().activation_delay
+    }
+
+    fn initialize_application(&mut self, id: ApplicationId) -> Result<(), ApplicationControlError> {
+        self.events.send(Event::InitializeApplication);
+        match id {
+            ApplicationId::All => Ok(()),
+            ApplicationId::Name(name) => {
+                if self.data.lock().unwrap().applications.contains_key(name) {
+                    Ok(())
+                } else {
+                    Err(ApplicationControlError::UnknownApplication)
+                }
+            }
+        }
+    }
+
+    fn start_application(&mut self, id: ApplicationId) -> Result<(), ApplicationControlError> {
+        self.events.send(Event::StartApplication);
+        self.data.lock().unwrap().set_running(id, true)
+    }
+
+    fn stop_application(&mut self, id: ApplicationId) -> Result<(), ApplicationControlError> {
+        self.events.send(Event::StopApplication);
+        self.data.lock().unwrap().set_running(id, false)
+    }
+
+    fn write_data_set(&mut self, id: u16, _data_set: &DataSet) -> MaybeAsync<bool> {
+        self.events.send(Event::WriteDataSet(id));
+        MaybeAsync::ready(self.data.lock().unwrap().accept_data_set_writes)
+    }
+
+    fn write_octet_string(
+        &mut self,
+        index: u16,
+        value: &OctetString,
+    ) -> MaybeAsync<WriteOctetString> {
+        self.events
+            .send(Event::WriteOctetString(index, value.value().len()));
+        MaybeAsync::ready(self.data.lock().unwrap().octet_string_writes)
+    }
+
+    fn write_time_and_interval(&mut self, index: u16, value: TimeAndInterval) -> MaybeAsync<bool> {
+        self.events.send(Event::WriteTimeAndInterval(index, value));
+        MaybeAsync::ready(self.data.lock().unwrap().accept_time_and_interval_writes)
+    }
+
     fn begin_confirm(&mut self) {
         self.events.send(Event::BeginConfirm);
     }
@@ -96,3 +311,103 @@ impl OutstationApplication for MockOutstationApplication {
         MaybeAsync::ready(())
     }
 }
+
+impl VirtualTerminalHandler for MockVirtualTerminal {
+    fn write(
+        &mut self,
+        port: u16,
+        data: &[u8],
+        database: &mut DatabaseHandle,
+    ) -> Result<(), RequestError> {
+        self.events
+            .send(Event::WriteVirtualTerminal(port, data.len()));
+        database.transaction(|db| db.push_virtual_terminal_data(port, data));
+        Ok(())
+    }
+}
+
+impl AuthHandler for MockAuthHandler {
+    fn get_update_key(&mut self, user: u16) -> Option<UpdateKey> {
+        self.data.lock().unwrap().update_keys.get(&user).cloned()
+    }
+
+    fn is_authorized(&mut self, _user: u16, function: FunctionCode) -> bool {
+        !self
+            .data
+            .lock()
+            .unwrap()
+            .denied_functions
+            .contains(&function)
+    }
+
+    fn key_store(&mut self) -> Option<&mut dyn KeyStore> {
+        Some(self)
+    }
+}
+
+impl KeyStore for MockAuthHandler {
+    fn authority_key(&self) -> Option<AuthorityKey> {
+        self.data.lock().unwrap().authority_key.clone()
+    }
+
+    fn is_permitted(&self, method: KeyChangeMethod) -> bool {
+        method.is_symmetric() || self.data.lock().unwrap().permit_asymmetric
+    }
+
+    fn outstation_name(&self) -> &str {
+        OUTSTATION_NAME
+    }
+
+    fn last_status_change_seq(&self) -> u32 {
+        self.data.lock().unwrap().last_status_change_seq
+    }
+
+    fn set_last_status_change_seq(&mut self, seq: u32) {
+        self.data.lock().unwrap().last_status_change_seq = seq;
+    }
+
+    fn get_user(&self, number: u16) -> Option<User> {
+        self.data.lock().unwrap().users.get(&number).cloned()
+    }
+
+    fn find_user(&self, name: &str) -> Option<User> {
+        self.data
+            .lock()
+            .unwrap()
+            .users
+            .values()
+            .find(|user| user.name == name)
+            .cloned()
+    }
+
+    fn store_user(&mut self, user: User) {
+        self.data.lock().unwrap().users.insert(user.number, user);
+    }
+
+    fn delete_user(&mut self, number: u16) {
+        self.data.lock().unwrap().users.remove(&number);
+    }
+
+    fn verify_certification(
+        &mut self,
+        _method: KeyChangeMethod,
+        data: &[u8],
+        certification_data: &[u8],
+    ) -> bool {
+        hmac_sha256(&AUTHORITY_SIGNING_KEY, &[data]) == certification_data
+    }
+
+    fn decrypt_update_key(&mut self, _method: KeyChangeMethod, data: &[u8]) -> Option<Vec<u8>> {
+        Some(data.iter().map(|x| x ^ ASYMMETRIC_MASK).collect())
+    }
+
+    fn verify_signature(
+        &mut self,
+        _method: KeyChangeMethod,
+        user: &User,
+        data: &[u8],
+        signature: &[u8],
+    ) -> bool {
+        hmac_sha256(&user.public_key, &[data]) == signature
+    }
+}
@@ -1,6 +1,6 @@
 use crate::app::variations::{Group12Var1, Group41Var1, Group41Var2, Group41Var3, Group41Var4};
-use crate::outstation::traits::{BroadcastAction, OperateType, RestartDelay};
-use crate::outstation::{BufferState, FreezeIndices, FreezeType};
+use crate::outstation::traits::{BroadcastAction, OperateType, RestartDelay, TimeAndInterval};
+use crate::outstation::{AuthFailure, BufferState, ClassAssignment, FreezeIndices, FreezeType};
 
 use crate::app::{FunctionCode, Timestamp};
 
@@ -37,9 +37,21 @@ pub(crate) enum Event {
     BeginWriteDeadBands,
     WriteDeadBand(u16, f64),
     EndWriteDeadBands,
+    AssignClass(ClassAssignment),
+    SaveConfiguration,
+    ActivateConfig(Option<u16>),
+    EndActivateConfig,
+    InitializeApplication,
+    StartApplication,
+    StopApplication,
+    WriteDataSet(u16),
+    WriteOctetString(u16, usize),
+    WriteTimeAndInterval(u16, TimeAndInterval),
+    WriteVirtualTerminal(u16, usize),
     BeginConfirm,
     Cleared(u64),
     EndConfirm(BufferState),
+    AuthenticationFailure(u16, AuthFailure),
 }
 
 #[derive(Clone)]
@@ -0,0 +1,188 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use crate::app::{FileStatus, FileType, MaybeAsync, Permissions, Timestamp};
+use crate::master::{AuthKey, FileHandle, FileInfo, FileMode};
+use crate::outstation::tests::harness::ApplicationData;
+use crate::outstation::{FileOpenRequest, FileTransportMode, OutstationFileSystem};
+
+/// in-memory file system with a single root directory
+pub(crate) struct MockFileSystem {
+    data: Arc<Mutex<ApplicationData>>,
+    open: HashMap<u32, OpenFile>,
+}
+
+struct OpenFile {
+    name: String,
+    pos: usize,
+}
+
+impl MockFileSystem {
+    pub(crate) const ROOT: &'static str = "/";
+    pub(crate) const USER_NAME: &'static str = "root";
+    pub(crate) const PASSWORD: &'static str = "secret";
+    pub(crate) const AUTH_KEY: u32 = 0xCAFE;
+
+    pub(crate) fn new(data: Arc<Mutex<ApplicationData>>) -> Self {
+        Self {
+            data,
+            open: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn file_info(name: &str, size: usize) -> FileInfo {
+        FileInfo {
+            name: name.to_string(),
+            file_type: FileType::File,
+            size: size as u32,
+            time_created: Timestamp::zero(),
+            permissions: Permissions::default(),
+        }
+    }
+}
+
+impl OutstationFileSystem for MockFileSystem {
+    fn transport_mode(&self) -> FileTransportMode {
+        self.data.lock().unwrap().file_transport_mode
+    }
+
+    fn authenticate(&mut self, user_name: &str, password: &str) -> Option<AuthKey> {
+        if user_name == Self::USER_NAME && password == Self::PASSWORD {
+            Some(AuthKey::new(Self::AUTH_KEY))
+        } else {
+            None
+        }
+    }
+
+    fn open(&mut self, handle: FileHandle, request: FileOpenRequest) -> Result<u32, FileStatus> {
+        let mut data = self.data.lock().unwrap();
+        let size = match request.mode {
+            FileMode::Read => match data.files.get(request.file_name) {
+                None => return Err(FileStatus::FileNotFound),
+                Some(x) => x.len(),
+            },
+            FileMode::Write => {
+                data.files.insert(request.file_name.to_string(), Vec::new());
+                0
+            }
+            FileMode::Append => data
+                .files
+                .entry(request.file_name.to_string())
+                .or_default()
+                .len(),
+            _ => return Err(FileStatus::InvalidMode),
+        };
+
+        self.open.insert(
+            handle.into(),
+            OpenFile {
+                name: request.file_name.to_string(),
+                pos: 0,
+            },
+        );
+
+        Ok(size as u32)
+    }
+
+    fn read_block(
+        &mut self,
+        handle: FileHandle,
+        block_size: u16,
+    ) -> MaybeAsync<Result<Vec<u8>, FileStatus>> {
+        let file = match self.open.get_mut(&handle.into()) {
+            None => return MaybeAsync::ready(Err(FileStatus::InvalidHandle)),
+            Some(x) => x,
+        };
+
+        let data = self.data.lock().unwrap();
+        let contents = data.files.get(&file.name).unwrap();
+        let end = contents.len().min(file.pos + block_size as usize);
+        let block = contents[file.pos..end].to_vec();
+        file.pos = end;
+
+        MaybeAsync::ready(Ok(block))
+    }
+
+    fn write_block(
+        &mut self,
+        handle: FileHandle,
+        data: &[u8],
+        _last: bool,
+    ) -> MaybeAsync<Result<(), FileStatus>> {
+        let file = match self.open.get(&handle.into()) {
+            None => return MaybeAsync::ready(Err(FileStatus::InvalidHandle)),
+            Some(x) => x,
+        };
+
+        self.data
+            .lock()
+            .unwrap()
+            .files
+            .get_mut(&file.name)
+            .unwrap()
+            .extend_from_slice(data);
+
+        MaybeAsync::ready(Ok(()))
+    }
+
+    fn close(&mut self, handle: FileHandle) -> Result<(), FileStatus> {
+        match self.open.remove(&handle.into()) {
+            None => Err(FileStatus::InvalidHandle),
+            Some(_) => Ok(()),
+        }
+    }
+
+    fn abort(&mut self, handle: FileHandle) -> Result<(), FileStatus> {
+        match self.open.remove(&handle.into()) {
+            None => Err(FileStatus::InvalidHandle),
+            Some(file) => {
+                self.data.lock().unwrap().aborted_files.push(file.name);
+                Ok(())
+            }
+        }
+    }
+
+    fn delete(&mut self, file_name: &str, auth_key: AuthKey) -> Result<(), FileStatus> {
+        if u32::from(auth_key) != Self::AUTH_KEY {
+            return Err(FileStatus::PermissionDenied);
+        }
+
+        match self.data.lock().unwrap().files.remove(file_name) {
+            None => Err(FileStatus::FileNotFound),
+            Some(_) => Ok(()),
+        }
+    }
+
+    fn stat(&mut self, file_name: &str) -> Result<FileInfo, FileStatus> {
+        let data = self.data.lock().unwrap();
+        if file_name == Self::ROOT {
+            return Ok(FileInfo {
+                name: Self::ROOT.to_string(),
+                file_type: FileType::Directory,
+                size: data.files.len() as u32,
+                time_created: Timestamp::zero(),
+                permissions: Permissions::default(),
+            });
+        }
+
+        match data.files.get(file_name) {
+            None => Err(FileStatus::FileNotFound),
+            Some(x) => Ok(Self::file_info(file_name, x.len())),
+        }
+    }
+
+    fn list_directory(&mut self, dir_name: &str) -> Result<Vec<FileInfo>, FileStatus> {
+        if dir_name != Self::ROOT {
+            return Err(FileStatus::FileNotFound);
+        }
+
+        let data = self.data.lock().unwrap();
+        let mut entries: Vec<FileInfo> = data
+            .files
+            .iter()
+            .map(|(name, contents)| Self::file_info(name, contents.len()))
+            .collect();
+        entries.sort_by(|lhs, rhs| lhs.name.cmp(&rhs.name));
+        Ok(entries)
+    }
+}
@@ -4,7 +4,7 @@ use crate::link::header::{BroadcastConfirmMode, FrameInfo, FrameType};
 use crate::link::reader::LinkModes;
 use crate::link::EndpointAddress;
 use crate::outstation::config::{Feature, OutstationConfig};
-use crate::outstation::database::EventBufferConfig;
+use crate::outstation::database::{DatabaseHandle, EventBufferConfig};
 use crate::outstation::task::OutstationTask;
 use crate::outstation::tests::harness::{
     event_handlers, ApplicationData, Event, EventReceiver, MockControlHandler,
@@ -93,6 +93,18 @@ impl OutstationHarness {
         }
     }
 
+    /// stop the outstation task so that messages can no longer be sent to it
+    pub(crate) async fn stop(&mut self) {
+        self.task.abort();
+        let _ = (&mut self.task).await;
+    }
+
+    /// simulate the link dropping and wait for the session to end
+    pub(crate) async fn disconnect(&mut self) {
+        self.io.read_error(std::io::ErrorKind::ConnectionReset);
+        let _ = (&mut self.task).await;
+    }
+
     pub(crate) fn check_no_events(&mut self) {
         if let Some(x) = self.events.poll() {
             panic!("expected no events, but next event is: {x:?}")
@@ -101,42 +113,72 @@ impl OutstationHarness {
 }
 
 pub(crate) fn new_harness(config: OutstationConfig) -> OutstationHarness {
-    new_harness_impl(config, None, None)
+    new_harness_impl(config, None, None, None)
 }
 
 pub(crate) fn new_harness_with_master_addr(
     config: OutstationConfig,
     master_address: EndpointAddress,
 ) -> OutstationHarness {
-    new_harness_impl(config, None, Some(master_address))
+    new_harness_impl(config, None, Some(master_address), None)
 }
 
 pub(crate) fn new_harness_for_broadcast(
     config: OutstationConfig,
     broadcast: BroadcastConfirmMode,
 ) -> OutstationHarness {
-    new_harness_impl(config, Some(broadcast), None)
+    new_harness_impl(config, Some(broadcast), None, None)
+}
+
+/// create a harness for another master that shares the database of an existing outstation
+pub(crate) fn new_harness_sharing_database(
+    outstation: &OutstationHandle,
+    config: OutstationConfig,
+) -> OutstationHarness {
+    new_harness_impl(config, None, None, Some(outstation.get_database_handle()))
 }
 
 fn new_harness_impl(
     config: OutstationConfig,
     broadcast: Option<BroadcastConfirmMode>,
     master_address: Option<EndpointAddress>,
+    database: Option<DatabaseHandle>,
 ) -> OutstationHarness {
     let (sender, receiver) = event_handlers();
 
     let (data, application) = MockOutstationApplication::create(sender.clone());
-
-    let (task, handle) = OutstationTask::create(
-        Enabled::Yes,
-        LinkModes::test(),
-        ParseOptions::get_static(),
-        config,
-        PhysAddr::None,
-        application,
-        MockOutstationInformation::create(sender.clone()),
-        MockControlHandler::create(sender.clone()),
-    );
+    let information = MockOutstationInformation::create(sender.clone());
+    let control_handler = MockControlHandler::create(sender.clone());
+
+    let (task, handle) = match database {
+        None => OutstationTask::create(
+            Enabled::Yes,
+            LinkModes::test(),
+            ParseOptions::get_static(),
+            config,
+            PhysAddr::None,
+            application,
+            information,
+            control_handler,
+        ),
+        Some(database) => {
+            let association = database
+                .add_association(config.master_address, config.offline_association_policy)
+                .unwrap();
+            OutstationTask::create_with_database(
+                Enabled::Yes,
+                LinkModes::test(),
+                ParseOptions::get_static(),
+                config,
+                PhysAddr::None,
+                application,
+                information,
+                control_handler,
+                database,
+                association,
+            )
+        }
+    };
 
     let mut task = Box::new(task);
 
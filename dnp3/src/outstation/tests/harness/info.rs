@@ -3,6 +3,7 @@ use crate::app::RequestHeader;
 use crate::app::Sequence;
 use crate::outstation::tests::harness::{Event, EventSender};
 use crate::outstation::traits::{BroadcastAction, OutstationInformation};
+use crate::outstation::AuthFailure;
 
 pub(crate) struct MockOutstationInformation {
     events: EventSender,
@@ -70,4 +71,9 @@ impl OutstationInformation for MockOutstationInformation {
         self.events
             .send(Event::UnsolicitedConfirmReceived(ecsn.value()))
     }
+
+    fn authentication_failure(&mut self, user: u16, failure: AuthFailure) {
+        self.events
+            .send(Event::AuthenticationFailure(user, failure))
+    }
 }
@@ -1,11 +1,13 @@
 pub(crate) use application::*;
 pub(crate) use control::*;
 pub(crate) use event::*;
+pub(crate) use file_system::*;
 pub(crate) use harness::*;
 pub(crate) use info::*;
 
 mod application;
 mod control;
 mod event;
+mod file_system;
 mod harness;
 mod info;
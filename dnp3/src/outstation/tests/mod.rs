@@ -1,25 +1,51 @@
 pub(crate) mod harness;
 
+/// activating configuration files and strings
+mod activate_config;
 /// respond/ignore addresses
 mod addressing;
+/// initializing, starting, and stopping applications
+mod application;
+/// assigning points to event classes
+mod assign_class;
+/// secure authentication (SAv5)
+mod auth;
+/// binary and analog output command events
+mod command_events;
 /// control functionality
 mod controls;
+/// data set prototypes, descriptors, present values, and events
+mod data_sets;
+/// file transfer
+mod file;
 /// freeze counters tests
 mod freeze;
 /// various IIN bit tests
 mod iin;
+/// multiple masters with independent associations
+mod multiple_masters;
 /// encoding tests for octet strings
 mod octet_strings;
 /// reading g34
 mod read_dead_band;
 /// state machine for READ requests
 mod read_states;
+/// applying a new configuration to a running outstation
+mod reconfigure;
 /// clear restart IIN + cold/warm restart
 mod restart;
+/// security statistics (g121/g122)
+mod security_statistics;
+/// protocol statistics and counters
+mod statistics;
 /// time synchronization
 mod time;
+/// reading g102
+mod unsigned_integers;
 /// unsolicited responses
 mod unsolicited;
+/// virtual terminal output blocks and events
+mod virtual_terminal;
 /// writing g34
 mod write_dead_band;
 
@@ -0,0 +1,279 @@
+use std::net::{IpAddr, Ipv4Addr};
+use std::time::Duration;
+
+use crate::app::measurement::*;
+use crate::app::variations::Group41Var2;
+use crate::app::{BufferSize, NullListener, Timestamp};
+use crate::link::{EndpointAddress, LinkErrorMode};
+use crate::outstation::database::*;
+use crate::outstation::tests::harness::*;
+use crate::outstation::traits::OperateType;
+use crate::outstation::{OfflineAssociationPolicy, OutstationConfig};
+use crate::tcp::{AddAssociationError, AddressFilter, Server};
+
+const READ_CLASS_123_SEQ_0: &[u8] = &[0xC0, 0x01, 60, 2, 0x06, 60, 3, 0x06, 60, 4, 0x06];
+const READ_CLASS_123_SEQ_1: &[u8] = &[0xC1, 0x01, 60, 2, 0x06, 60, 3, 0x06, 60, 4, 0x06];
+const CONFIRM_SEQ_0: &[u8] = &[0xC0, 0x00];
+const CONFIRM_SEQ_1: &[u8] = &[0xC1, 0x00];
+const READ_CLASS_0_SEQ_0: &[u8] = &[0xC0, 0x01, 0x3C, 0x01, 0x06];
+const EMPTY_RESPONSE_SEQ_1: &[u8] = &[0xC1, 0x81, 0x80, 0x00];
+const BINARY_EVENT_RESPONSE: &[u8] = &[
+    0xE0, 0x81, 0x80, 0x00, 0x02, 0x01, 0x28, 0x01, 0x00, 0x00, 0x00, 0x81,
+];
+const BINARY_EVENT_FALSE_RESPONSE_SEQ_1: &[u8] = &[
+    0xE1, 0x81, 0x80, 0x00, 0x02, 0x01, 0x28, 0x01, 0x00, 0x00, 0x00, 0x01,
+];
+
+const NULL_UNSOL_SEQ_0: &[u8] = &[0xF0, 0x82, 0x80, 0x00];
+const UNS_CONFIRM_SEQ_0: &[u8] = &[0xD0, 0x00];
+const UNSOL_G2V1_SEQ_1: &[u8] = &[
+    0xF1, 0x82, 0x80, 0x00, 0x02, 0x01, 0x28, 0x01, 0x00, 0x00, 0x00, 0x81,
+];
+const ENABLE_UNSOLICITED_SEQ_0: &[u8] = &[
+    0xC0, 0x14, 0x3C, 0x02, 0x06, 0x3C, 0x03, 0x06, 0x3C, 0x04, 0x06,
+];
+const EMPTY_RESPONSE_SEQ_0: &[u8] = &[0xC0, 0x81, 0x80, 0x00];
+
+const G41V2_INDEX_7: Control = Control::G41V2(Group41Var2::new(513), 7);
+const SELECT_SEQ0_G41V2: &[u8] = &[0xC0, 0x03, 41, 2, 0x17, 0x01, 0x07, 0x01, 0x02, 0x00];
+const OPERATE_SEQ1_G41V2: &[u8] = &[0xC1, 0x04, 41, 2, 0x17, 0x01, 0x07, 0x01, 0x02, 0x00];
+const RESPONSE_SEQ0_G41V2_SUCCESS: &[u8] = &[
+    0xC0, 0x81, 0x80, 0x00, 41, 2, 0x17, 0x1, 0x07, 0x01, 0x02, 0x00,
+];
+const RESPONSE_SEQ1_G41V2_SUCCESS: &[u8] = &[
+    0xC1, 0x81, 0x80, 0x00, 41, 2, 0x17, 0x1, 0x07, 0x01, 0x02, 0x00,
+];
+// status == NO_SELECT
+const RESPONSE_SEQ1_G41V2_NO_SELECT: &[u8] = &[
+    0xC1, 0x81, 0x80, 0x00, 41, 2, 0x17, 0x1, 0x07, 0x01, 0x02, 0x02,
+];
+
+fn secondary() -> EndpointAddress {
+    EndpointAddress::try_new(2).unwrap()
+}
+
+fn get_secondary_config() -> OutstationConfig {
+    let mut config = get_default_config();
+    config.master_address = secondary();
+    config
+}
+
+fn create_binary_and_event(database: &mut Database) {
+    database.add(0, Some(EventClass::Class1), BinaryInputConfig::default());
+    database.update(
+        0,
+        &BinaryInput::new(true, Flags::ONLINE, Time::Synchronized(Timestamp::new(0))),
+        UpdateOptions::default(),
+    );
+}
+
+fn create_second_event(database: &mut Database) {
+    database.update(
+        0,
+        &BinaryInput::new(false, Flags::ONLINE, Time::Synchronized(Timestamp::new(0))),
+        UpdateOptions::default(),
+    );
+}
+
+#[tokio::test]
+async fn events_confirmed_by_one_master_are_still_reported_to_the_other() {
+    let mut primary = new_harness(get_default_config());
+    let mut secondary = new_harness_sharing_database(&primary.handle, get_secondary_config());
+    primary.handle.transaction(create_binary_and_event);
+
+    primary
+        .test_request_response(READ_CLASS_123_SEQ_0, BINARY_EVENT_RESPONSE)
+        .await;
+    primary.check_events(&[Event::EnterSolicitedConfirmWait(0)]);
+    primary.send_and_process(CONFIRM_SEQ_0).await;
+    primary.check_events(&[Event::SolicitedConfirmReceived(0)]);
+
+    // the primary master has confirmed the event
+    primary
+        .test_request_response(READ_CLASS_123_SEQ_1, EMPTY_RESPONSE_SEQ_1)
+        .await;
+
+    // but the secondary master has not
+    secondary
+        .test_request_response(READ_CLASS_123_SEQ_0, BINARY_EVENT_RESPONSE)
+        .await;
+    secondary.send_and_process(CONFIRM_SEQ_0).await;
+    secondary
+        .test_request_response(READ_CLASS_123_SEQ_1, EMPTY_RESPONSE_SEQ_1)
+        .await;
+
+    assert_eq!(primary.handle.get_statistics().events.class1.confirmed, 1);
+}
+
+#[tokio::test]
+async fn every_master_with_unsolicited_enabled_receives_the_event() {
+    let mut primary = new_harness(get_default_unsolicited_config());
+    let mut config = get_default_unsolicited_config();
+    config.master_address = secondary();
+    let mut secondary = new_harness_sharing_database(&primary.handle, config);
+
+    for harness in [&mut primary, &mut secondary] {
+        harness.expect_response(NULL_UNSOL_SEQ_0).await;
+        harness.send_and_process(UNS_CONFIRM_SEQ_0).await;
+        harness
+            .test_request_response(ENABLE_UNSOLICITED_SEQ_0, EMPTY_RESPONSE_SEQ_0)
+            .await;
+    }
+
+    primary.handle.transaction(create_binary_and_event);
+
+    // both sessions must be woken by the change, not by one of their timers
+    for harness in [&mut primary, &mut secondary] {
+        tokio::time::timeout(
+            Duration::from_secs(1),
+            harness.expect_response(UNSOL_G2V1_SEQ_1),
+        )
+        .await
+        .expect("session was not woken by the change");
+    }
+}
+
+#[tokio::test]
+async fn masters_read_static_values_independently() {
+    let mut config = get_default_config();
+    config.solicited_buffer_size = BufferSize::min();
+    let mut secondary_config = config;
+    secondary_config.master_address = secondary();
+
+    let mut primary = new_harness(config);
+    let mut secondary = new_harness_sharing_database(&primary.handle, secondary_config);
+    primary.handle.transaction(|db| {
+        // enough points to require two fragments
+        for index in 0..60 {
+            db.add(index, None, AnalogInputConfig::default());
+        }
+    });
+
+    // the primary master does not confirm the first fragment yet
+    primary.send_and_process(READ_CLASS_0_SEQ_0).await;
+    assert_eq!(primary.expect_write().await[0], 0xA0);
+
+    primary.handle.transaction(|db| {
+        db.update(
+            59,
+            &AnalogInput::new(42.0, Flags::ONLINE, Time::Synchronized(Timestamp::new(0))),
+            UpdateOptions::default(),
+        );
+    });
+
+    // the secondary master is answered while the response series of the primary is in progress
+    secondary.send_and_process(READ_CLASS_0_SEQ_0).await;
+    assert_eq!(secondary.expect_write().await[0], 0xA0);
+    secondary.send_and_process(CONFIRM_SEQ_0).await;
+    let last = secondary.expect_write().await;
+    assert_eq!(last[0], 0x41);
+    assert_eq!(last[last.len() - 5..], [0x01, 42, 0, 0, 0]);
+
+    // the primary master still receives the values that were selected for it
+    primary.send_and_process(CONFIRM_SEQ_0).await;
+    let last = primary.expect_write().await;
+    assert_eq!(last[0], 0x41);
+    assert_eq!(last[last.len() - 5..], [0x02, 0, 0, 0, 0]);
+}
+
+#[tokio::test]
+async fn select_made_by_one_master_cannot_be_operated_by_the_other() {
+    let mut primary = new_harness(get_default_config());
+    let mut secondary = new_harness_sharing_database(&primary.handle, get_secondary_config());
+
+    primary
+        .test_request_response(SELECT_SEQ0_G41V2, RESPONSE_SEQ0_G41V2_SUCCESS)
+        .await;
+    primary.check_events(&[
+        Event::BeginControls,
+        Event::Select(G41V2_INDEX_7),
+        Event::EndControls,
+    ]);
+
+    secondary
+        .test_request_response(OPERATE_SEQ1_G41V2, RESPONSE_SEQ1_G41V2_NO_SELECT)
+        .await;
+    secondary.check_no_events();
+
+    // the select is still valid for the master that made it
+    primary
+        .test_request_response(OPERATE_SEQ1_G41V2, RESPONSE_SEQ1_G41V2_SUCCESS)
+        .await;
+    primary.check_events(&[
+        Event::BeginControls,
+        Event::Operate(G41V2_INDEX_7, OperateType::SelectBeforeOperate),
+        Event::EndControls,
+    ]);
+}
+
+#[tokio::test]
+async fn events_are_released_once_an_offline_master_exceeds_its_delay() {
+    let mut primary = new_harness(get_default_config());
+
+    // the secondary master is associated with the database but never connects
+    primary
+        .handle
+        .get_database_handle()
+        .add_association(
+            secondary(),
+            OfflineAssociationPolicy::ReleaseAfter(Duration::from_secs(60)),
+        )
+        .unwrap();
+    primary.handle.transaction(create_binary_and_event);
+
+    primary
+        .test_request_response(READ_CLASS_123_SEQ_0, BINARY_EVENT_RESPONSE)
+        .await;
+    primary.send_and_process(CONFIRM_SEQ_0).await;
+
+    // the event is retained for the secondary master
+    assert_eq!(primary.handle.get_statistics().events.class1.confirmed, 0);
+
+    tokio::time::pause();
+    tokio::time::advance(Duration::from_secs(60)).await;
+    tokio::time::resume();
+
+    // the next confirm from the primary master releases both events
+    primary.handle.transaction(create_second_event);
+    primary
+        .test_request_response(READ_CLASS_123_SEQ_1, BINARY_EVENT_FALSE_RESPONSE_SEQ_1)
+        .await;
+    primary.send_and_process(CONFIRM_SEQ_1).await;
+
+    assert_eq!(primary.handle.get_statistics().events.class1.confirmed, 2);
+}
+
+#[test]
+fn cannot_associate_the_same_master_address_twice() {
+    let mut server =
+        Server::new_tcp_server(LinkErrorMode::Close, "127.0.0.1:20000".parse().unwrap());
+
+    let (sender, _receiver) = event_handlers();
+    let filter = |last| AddressFilter::Exact(IpAddr::V4(Ipv4Addr::new(192, 168, 0, last)));
+
+    let (outstation, _) = server
+        .add_outstation_no_spawn(
+            get_default_config(),
+            MockOutstationApplication::create(sender.clone()).1,
+            MockOutstationInformation::create(sender.clone()),
+            MockControlHandler::create(sender.clone()),
+            NullListener::create(),
+            filter(1),
+        )
+        .unwrap();
+
+    let result = server.add_association_no_spawn(
+        &outstation,
+        get_default_config(),
+        MockOutstationApplication::create(sender.clone()).1,
+        MockOutstationInformation::create(sender.clone()),
+        MockControlHandler::create(sender),
+        NullListener::create(),
+        filter(2),
+    );
+
+    assert_eq!(
+        result.err(),
+        Some(AddAssociationError::DuplicateMasterAddress)
+    );
+}
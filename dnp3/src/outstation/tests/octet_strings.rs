@@ -1,7 +1,8 @@
 use crate::app::measurement::OctetString;
 use crate::outstation::database::EventClass::{Class1, Class2};
-use crate::outstation::database::{Add, OctetStringConfig, Update, UpdateOptions};
+use crate::outstation::database::{Add, Get, OctetStringConfig, Update, UpdateOptions};
 use crate::outstation::tests::harness::*;
+use crate::outstation::WriteOctetString;
 
 #[tokio::test]
 async fn objects_of_same_length_are_encoded_in_a_single_header() {
@@ -139,3 +140,90 @@ async fn events_with_different_lengths_use_different_headers() {
 
     harness.check_events(&[Event::EnterSolicitedConfirmWait(0)]);
 }
+
+// write, seq == 0, g110v2 with a one byte count and prefix, index == 1, value == [0xAA, 0xBB]
+const WRITE_G110V2_INDEX_1: &[u8] = &[0xC0, 0x02, 110, 2, 0x17, 0x01, 0x01, 0xAA, 0xBB];
+
+fn octet_string_harness(write: WriteOctetString) -> OutstationHarness {
+    let harness = new_harness(get_default_config());
+    harness.application_data.lock().unwrap().octet_string_writes = write;
+    harness.handle.transaction(|db| {
+        db.add(1, Some(Class1), OctetStringConfig);
+        db.update(
+            1,
+            &OctetString::new(&[0x01]).unwrap(),
+            UpdateOptions::no_event(),
+        );
+    });
+    harness
+}
+
+fn value_of_index_1(harness: &mut OutstationHarness) -> Vec<u8> {
+    harness
+        .handle
+        .transaction(|db| Get::<OctetString>::get(db, 1).unwrap().value().to_vec())
+}
+
+#[tokio::test]
+async fn write_is_rejected_by_default() {
+    let mut harness = octet_string_harness(WriteOctetString::Reject);
+
+    harness
+        .test_request_response(WRITE_G110V2_INDEX_1, &[0xC0, 0x81, 0x80, 0x04])
+        .await;
+
+    harness.check_events(&[Event::WriteOctetString(1, 2)]);
+    assert_eq!(value_of_index_1(&mut harness), [0x01]);
+}
+
+#[tokio::test]
+async fn accepted_write_does_not_modify_the_database() {
+    let mut harness = octet_string_harness(WriteOctetString::Accept);
+
+    harness
+        .test_request_response(WRITE_G110V2_INDEX_1, &[0xC0, 0x81, 0x80, 0x00])
+        .await;
+
+    harness.check_events(&[Event::WriteOctetString(1, 2)]);
+    assert_eq!(value_of_index_1(&mut harness), [0x01]);
+}
+
+#[tokio::test]
+async fn write_can_update_the_database_and_produce_an_event() {
+    let mut harness = octet_string_harness(WriteOctetString::Update(UpdateOptions::default()));
+
+    // range qualifier, start == 1, stop == 1
+    harness
+        .test_request_response(
+            &[0xC0, 0x02, 110, 2, 0x00, 0x01, 0x01, 0xAA, 0xBB],
+            &[0xC0, 0x81, 0x82, 0x00],
+        )
+        .await;
+
+    harness.check_events(&[Event::WriteOctetString(1, 2)]);
+    assert_eq!(value_of_index_1(&mut harness), [0xAA, 0xBB]);
+
+    // g111v2 event for index 1
+    harness
+        .test_request_response(
+            &[0xC1, 0x01, 60, 2, 0x06],
+            &[
+                0xE1, 0x81, 0x80, 0x00, 111, 2, 0x28, 0x01, 0x00, 0x01, 0x00, 0xAA, 0xBB,
+            ],
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn write_to_undefined_point_is_rejected() {
+    let mut harness = octet_string_harness(WriteOctetString::Update(UpdateOptions::default()));
+
+    harness
+        .test_request_response(
+            &[0xC0, 0x02, 110, 2, 0x17, 0x01, 0x02, 0xAA, 0xBB],
+            &[0xC0, 0x81, 0x80, 0x04],
+        )
+        .await;
+
+    harness.check_no_events();
+}
@@ -0,0 +1,141 @@
+use crate::app::measurement::{BinaryInput, Flags, Time};
+use crate::app::{BufferSize, Timeout};
+use crate::link::EndpointAddress;
+use crate::outstation::config::{Feature, OutstationConfigField, ReconfigureResult};
+use crate::outstation::database::{
+    Add, BinaryInputConfig, EventBufferConfig, EventClass, Update, UpdateOptions,
+};
+
+use super::harness::*;
+
+const READ_CLASS_0: &[u8] = &[0xC0, 0x01, 60, 1, 0x06];
+const READ_CLASS_1: &[u8] = &[0xC0, 0x01, 60, 2, 0x06];
+const EMPTY_RESPONSE: &[u8] = &[0xC0, 0x81, 0x80, 0x00];
+const NULL_UNSOL_SEQ_0: &[u8] = &[0xF0, 0x82, 0x80, 0x00];
+
+fn generate_binary_events(harness: &mut OutstationHarness, count: usize) {
+    harness.handle.database.transaction(|database| {
+        database.add(0, Some(EventClass::Class1), BinaryInputConfig::default());
+        for i in 0..count {
+            database.update(
+                0,
+                &BinaryInput::new(i % 2 != 0, Flags::ONLINE, Time::synchronized(0)),
+                UpdateOptions::default(),
+            );
+        }
+    });
+}
+
+#[tokio::test]
+async fn reports_fields_that_require_restart() {
+    let config = get_default_config();
+    let mut harness = new_harness(config);
+
+    let mut new_config = config;
+    new_config.outstation_address = EndpointAddress::try_new(11).unwrap();
+    new_config.rx_buffer_size = BufferSize::min();
+    new_config.features.self_address = Feature::Enabled;
+    new_config.confirm_timeout = Timeout::from_secs(10).unwrap();
+
+    assert_eq!(
+        harness.handle.reconfigure(new_config).await.unwrap(),
+        ReconfigureResult {
+            restart_required: vec![
+                OutstationConfigField::OutstationAddress,
+                OutstationConfigField::RxBufferSize,
+                OutstationConfigField::SelfAddress,
+            ],
+            discarded_events: 0,
+        }
+    );
+
+    // fields that were not applied are reported again
+    assert_eq!(
+        harness
+            .handle
+            .reconfigure(new_config)
+            .await
+            .unwrap()
+            .restart_required
+            .len(),
+        3
+    );
+
+    harness
+        .test_request_response(READ_CLASS_0, EMPTY_RESPONSE)
+        .await;
+}
+
+#[tokio::test]
+async fn enables_unsolicited_responses_without_restart() {
+    let config = get_default_config();
+    let mut harness = new_harness(config);
+
+    // the outstation is idle and waiting for a request when the configuration changes
+    harness
+        .test_request_response(READ_CLASS_0, EMPTY_RESPONSE)
+        .await;
+    harness.expect_no_response();
+
+    let mut new_config = config;
+    new_config.features.unsolicited = Feature::Enabled;
+    let result = harness.handle.reconfigure(new_config).await.unwrap();
+    assert!(result.restart_required.is_empty());
+
+    harness.expect_response(NULL_UNSOL_SEQ_0).await;
+    harness.check_events(&[Event::EnterUnsolicitedConfirmWait(0)]);
+}
+
+#[tokio::test]
+async fn keeps_the_current_configuration_if_the_outstation_has_shut_down() {
+    let config = get_default_config();
+    let mut harness = new_harness(config);
+    harness.stop().await;
+
+    let mut new_config = config;
+    new_config.features.unsolicited = Feature::Enabled;
+    assert!(harness.handle.reconfigure(new_config).await.is_err());
+
+    assert_eq!(
+        harness.handle.config.lock().unwrap().features.unsolicited,
+        Feature::Disabled
+    );
+}
+
+#[tokio::test]
+async fn applies_class_zero_config() {
+    let config = get_default_config();
+    let mut harness = new_harness(config);
+    generate_binary_events(&mut harness, 1);
+
+    let mut new_config = config;
+    new_config.class_zero.binary = false;
+    harness.handle.reconfigure(new_config).await.unwrap();
+
+    harness
+        .test_request_response(READ_CLASS_0, &[0xC0, 0x81, 0x82, 0x00])
+        .await;
+}
+
+#[tokio::test]
+async fn resizes_event_buffer_retaining_the_newest_events() {
+    let config = get_default_config();
+    let mut harness = new_harness(config);
+    generate_binary_events(&mut harness, 4);
+
+    let mut new_config = config;
+    new_config.event_buffer_config = EventBufferConfig::all_types(2);
+    let result = harness.handle.reconfigure(new_config).await.unwrap();
+    assert!(result.restart_required.is_empty());
+    assert_eq!(result.discarded_events, 2);
+
+    harness
+        .test_request_response(
+            READ_CLASS_1,
+            &[
+                0xE0, 0x81, 0x80, 0x08, 0x02, 0x01, 0x28, 0x02, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00,
+                0x81,
+            ],
+        )
+        .await;
+}
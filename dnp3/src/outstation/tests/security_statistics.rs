@@ -0,0 +1,141 @@
+use crate::outstation::database::EventClass::Class1;
+use crate::outstation::database::*;
+use crate::outstation::tests::harness::*;
+
+const READ_G121: &[u8] = &[0xC0, 0x01, 121, 0, 0x06];
+
+fn add_statistic(
+    harness: &mut OutstationHarness,
+    statistic: SecurityStatisticIndex,
+    class: Option<EventClass>,
+) {
+    harness.handle.transaction(|db| {
+        assert!(db.add(statistic.index(), class, SecurityStatisticConfig::default()));
+    });
+}
+
+#[tokio::test]
+async fn received_requests_are_counted() {
+    let mut harness = new_harness(get_default_config());
+    add_statistic(
+        &mut harness,
+        SecurityStatisticIndex::TotalMessagesReceived,
+        None,
+    );
+
+    // the READ itself is counted before the response is built
+    harness
+        .test_request_response(
+            READ_G121,
+            &[
+                0xC0, 0x81, 0x80, 0x00, 121, 1, 0x01, 6, 0, 6, 0, 0x01, 0x01, 0x00, 0x01, 0x00,
+                0x00, 0x00,
+            ],
+        )
+        .await;
+    harness
+        .test_request_response(
+            &[0xC1, 0x01, 121, 0, 0x06],
+            &[
+                0xC1, 0x81, 0x80, 0x00, 121, 1, 0x01, 6, 0, 6, 0, 0x01, 0x01, 0x00, 0x02, 0x00,
+                0x00, 0x00,
+            ],
+        )
+        .await;
+
+    harness.check_no_events();
+}
+
+#[tokio::test]
+async fn malformed_requests_are_counted_as_discarded() {
+    let mut harness = new_harness(get_default_config());
+    add_statistic(
+        &mut harness,
+        SecurityStatisticIndex::DiscardedMessages,
+        None,
+    );
+
+    // truncated object header
+    harness
+        .test_request_response(&[0xC0, 0x01, 121], &[0xC0, 0x81, 0x80, 0x04])
+        .await;
+    harness
+        .test_request_response(
+            &[0xC1, 0x01, 121, 0, 0x06],
+            &[
+                0xC1, 0x81, 0x80, 0x00, 121, 1, 0x01, 9, 0, 9, 0, 0x01, 0x01, 0x00, 0x01, 0x00,
+                0x00, 0x00,
+            ],
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn statistics_assigned_to_a_class_produce_events() {
+    let mut harness = new_harness(get_default_config());
+    add_statistic(
+        &mut harness,
+        SecurityStatisticIndex::TotalMessagesReceived,
+        Some(Class1),
+    );
+
+    // the READ increments the counter which produces a g122v1 event
+    harness
+        .test_request_response(
+            &[0xC0, 0x01, 60, 2, 0x06],
+            &[
+                0xE0, 0x81, 0x80, 0x00, 122, 1, 0x28, 0x01, 0x00, 6, 0, 0x01, 0x01, 0x00, 0x01,
+                0x00, 0x00, 0x00,
+            ],
+        )
+        .await;
+    harness.check_events(&[Event::EnterSolicitedConfirmWait(0)]);
+}
+
+#[tokio::test]
+async fn confirming_unsolicited_statistic_events_does_not_produce_more_events() {
+    let mut harness = new_harness(get_default_unsolicited_config());
+    add_statistic(
+        &mut harness,
+        SecurityStatisticIndex::TotalMessagesReceived,
+        Some(Class1),
+    );
+
+    harness.expect_response(&[0xF0, 0x82, 0x80, 0x00]).await;
+    harness.send_and_process(&[0xD0, 0x00]).await;
+    harness.check_events(&[
+        Event::EnterUnsolicitedConfirmWait(0),
+        Event::UnsolicitedConfirmReceived(0),
+    ]);
+
+    // the ENABLE_UNSOLICITED request is counted and its event is reported unsolicited
+    harness
+        .test_request_response(&[0xC0, 0x14, 60, 2, 0x06], &[0xC0, 0x81, 0x82, 0x00])
+        .await;
+    harness
+        .expect_response(&[
+            0xF1, 0x82, 0x80, 0x00, 122, 1, 0x28, 0x01, 0x00, 6, 0, 0x01, 0x01, 0x00, 0x01, 0x00,
+            0x00, 0x00,
+        ])
+        .await;
+    harness.send_and_process(&[0xD1, 0x00]).await;
+    harness.check_events(&[
+        Event::EnterUnsolicitedConfirmWait(1),
+        Event::UnsolicitedConfirmReceived(1),
+    ]);
+
+    // the confirm is not counted, so no further unsolicited response is produced
+    tokio::time::pause();
+    tokio::time::sleep(std::time::Duration::from_secs(10)).await;
+    harness.expect_no_response();
+}
+
+#[tokio::test]
+async fn statistics_are_not_maintained_unless_added() {
+    let mut harness = new_harness(get_default_config());
+
+    harness
+        .test_request_response(READ_G121, &[0xC0, 0x81, 0x80, 0x00])
+        .await;
+    harness.check_no_events();
+}
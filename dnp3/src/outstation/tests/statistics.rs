@@ -0,0 +1,183 @@
+use crate::app::control::CommandStatus;
+use crate::app::measurement::{BinaryInput, Flags, Time};
+use crate::app::FunctionCode;
+use crate::outstation::database::{Add, BinaryInputConfig, EventClass, Update, UpdateOptions};
+use crate::outstation::{ClassEventStatistics, TransportStatistics};
+
+use super::harness::*;
+
+const READ_CLASS_0: &[u8] = &[0xC0, 0x01, 60, 1, 0x06];
+const READ_CLASS_1: &[u8] = &[0xC0, 0x01, 60, 2, 0x06];
+const READ_CLASS_1_SEQ1: &[u8] = &[0xC1, 0x01, 60, 2, 0x06];
+const CONFIRM_SEQ1: &[u8] = &[0xC1, 0x00];
+const EMPTY_RESPONSE: &[u8] = &[0xC0, 0x81, 0x80, 0x00];
+// read, seq == 0, unknown object g255v255
+const READ_UNKNOWN_OBJECT: &[u8] = &[0xC0, 0x01, 255, 255, 0x06];
+const RESPONSE_OBJECT_UNKNOWN: &[u8] = &[0xC0, 0x81, 0x80, 0x02];
+// seq == 0, function code 0x70 is not defined
+const UNKNOWN_FUNCTION: &[u8] = &[0xC0, 0x70];
+const RESPONSE_NO_FUNC_CODE_SUPPORT: &[u8] = &[0xC0, 0x81, 0x80, 0x01];
+// select, seq == 0, g41v2 - count == 1, index == 7, value = 513, status == SUCCESS
+const SELECT_SEQ0_G41V2: &[u8] = &[0xC0, 0x03, 41, 2, 0x17, 0x01, 0x07, 0x01, 0x02, 0x00];
+const RESPONSE_SEQ0_G41V2_SUCCESS: &[u8] = &[
+    0xC0, 0x81, 0x80, 0x00, 41, 2, 0x17, 0x1, 0x07, 0x01, 0x02, 0x00,
+];
+// operate, seq == 2, g41v2 - count == 1, index == 7, value = 513, status == SUCCESS
+const OPERATE_SEQ2_G41V2: &[u8] = &[0xC2, 0x04, 41, 2, 0x17, 0x01, 0x07, 0x01, 0x02, 0x00];
+const RESPONSE_SEQ2_G41V2_NO_SELECT: &[u8] = &[
+    0xC2, 0x81, 0x80, 0x00, 41, 2, 0x17, 0x1, 0x07, 0x01, 0x02, 0x02,
+];
+const NULL_UNSOL_SEQ_0: &[u8] = &[0xF0, 0x82, 0x80, 0x00];
+const NULL_UNSOL_SEQ_1: &[u8] = &[0xF1, 0x82, 0x80, 0x00];
+
+fn generate_binary_events(harness: &mut OutstationHarness, count: usize) {
+    harness.handle.database.transaction(|database| {
+        database.add(0, Some(EventClass::Class1), BinaryInputConfig::default());
+        for i in 0..count {
+            database.update(
+                0,
+                &BinaryInput::new(i % 2 != 0, Flags::ONLINE, Time::synchronized(0)),
+                UpdateOptions::default(),
+            );
+        }
+    });
+}
+
+#[tokio::test]
+async fn counts_requests_by_function_code_and_frames() {
+    let mut harness = new_harness(get_default_config());
+
+    harness
+        .test_request_response(READ_CLASS_0, EMPTY_RESPONSE)
+        .await;
+    // repeated requests are counted again
+    harness
+        .test_request_response(READ_CLASS_0, EMPTY_RESPONSE)
+        .await;
+    harness
+        .test_request_response(
+            super::data::DELAY_MEASURE,
+            super::data::RESPONSE_TIME_DELAY_FINE_ZERO,
+        )
+        .await;
+
+    let statistics = harness.handle.get_statistics();
+    assert_eq!(statistics.session.num_requests(FunctionCode::Read), 2);
+    assert_eq!(
+        statistics.session.num_requests(FunctionCode::DelayMeasure),
+        1
+    );
+    assert_eq!(statistics.session.num_requests(FunctionCode::Write), 0);
+    assert_eq!(statistics.session.malformed_requests, 0);
+    assert_eq!(
+        statistics.transport,
+        TransportStatistics {
+            link_frames_rx: 3,
+            link_frames_tx: 3,
+            segments_rx: 3,
+            segments_tx: 3,
+        }
+    );
+}
+
+#[tokio::test]
+async fn counts_malformed_requests() {
+    let mut harness = new_harness(get_default_config());
+
+    harness
+        .test_request_response(READ_UNKNOWN_OBJECT, RESPONSE_OBJECT_UNKNOWN)
+        .await;
+    harness
+        .test_request_response(UNKNOWN_FUNCTION, RESPONSE_NO_FUNC_CODE_SUPPORT)
+        .await;
+
+    let statistics = harness.handle.get_statistics();
+    assert_eq!(statistics.session.malformed_requests, 2);
+    // the header of a request with an unknown function code cannot be parsed
+    assert_eq!(statistics.session.num_requests(FunctionCode::Read), 1);
+    assert_eq!(statistics.session.requests.len(), 1);
+}
+
+#[tokio::test]
+async fn counts_select_and_operate_failures_by_status() {
+    let mut harness = new_harness(get_default_config());
+
+    harness
+        .test_request_response(SELECT_SEQ0_G41V2, RESPONSE_SEQ0_G41V2_SUCCESS)
+        .await;
+    harness
+        .test_request_response(OPERATE_SEQ2_G41V2, RESPONSE_SEQ2_G41V2_NO_SELECT)
+        .await;
+
+    let session = harness.handle.get_statistics().session;
+    assert_eq!(session.num_requests(FunctionCode::Select), 1);
+    assert_eq!(session.num_requests(FunctionCode::Operate), 1);
+    assert_eq!(session.select_failures.total(), 0);
+    assert_eq!(session.operate_failures.get(CommandStatus::NoSelect), 1);
+    assert_eq!(
+        session.operate_failures.iter().collect::<Vec<_>>(),
+        [(CommandStatus::NoSelect, 1)]
+    );
+}
+
+#[tokio::test]
+async fn counts_solicited_confirm_timeouts_and_confirmed_events() {
+    let mut harness = new_harness(get_default_config());
+    generate_binary_events(&mut harness, 2);
+
+    harness.send_and_process(READ_CLASS_1).await;
+    harness.expect_write().await;
+    harness.check_events(&[Event::EnterSolicitedConfirmWait(0)]);
+
+    tokio::time::pause();
+    harness
+        .wait_for_events(&[Event::SolicitedConfirmTimeout(0)])
+        .await;
+    tokio::time::resume();
+
+    let statistics = harness.handle.get_statistics();
+    assert_eq!(statistics.session.solicited_confirm_timeouts, 1);
+    assert_eq!(statistics.events.class1.confirmed, 0);
+
+    // the events are reported again and confirmed
+    harness.send_and_process(READ_CLASS_1_SEQ1).await;
+    harness.expect_write().await;
+    harness.check_events(&[Event::EnterSolicitedConfirmWait(1)]);
+    harness.send_and_process(CONFIRM_SEQ1).await;
+    harness.check_events(&[Event::SolicitedConfirmReceived(1)]);
+
+    let statistics = harness.handle.get_statistics();
+    assert_eq!(statistics.session.num_requests(FunctionCode::Read), 2);
+    assert_eq!(statistics.session.num_requests(FunctionCode::Confirm), 1);
+    assert_eq!(
+        *statistics.events.get(EventClass::Class1),
+        ClassEventStatistics {
+            generated: 2,
+            confirmed: 2,
+            discarded: 0,
+        }
+    );
+}
+
+#[tokio::test]
+async fn counts_unsolicited_series_and_timeouts() {
+    let mut harness = new_harness(get_default_unsolicited_config());
+    harness.expect_response(NULL_UNSOL_SEQ_0).await;
+
+    tokio::time::pause();
+    harness
+        .wait_for_events(&[
+            Event::EnterUnsolicitedConfirmWait(0),
+            Event::UnsolicitedConfirmTimeout(0, false),
+        ])
+        .await;
+
+    // null unsolicited responses are regenerated in a new series instead of retried
+    harness.expect_response(NULL_UNSOL_SEQ_1).await;
+    harness.check_events(&[Event::EnterUnsolicitedConfirmWait(1)]);
+
+    let session = harness.handle.get_statistics().session;
+    assert_eq!(session.unsolicited_series, 2);
+    assert_eq!(session.unsolicited_confirm_timeouts, 1);
+    assert_eq!(session.unsolicited_retries, 0);
+}
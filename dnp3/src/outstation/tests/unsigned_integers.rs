@@ -0,0 +1,101 @@
+use crate::app::measurement::UnsignedInteger;
+use crate::outstation::database::EventClass::Class1;
+use crate::outstation::database::{Add, UnsignedIntegerConfig, Update, UpdateOptions};
+use crate::outstation::tests::harness::*;
+
+const READ_CLASS_0: &[u8] = &[0xC0, 0x01, 60, 1, 0x06];
+const EMPTY_RESPONSE: &[u8] = &[0xC0, 0x81, 0x80, 0x00];
+
+fn add_points(harness: &mut OutstationHarness) {
+    harness.handle.transaction(|db| {
+        for i in 0..3 {
+            db.add(i, Some(Class1), UnsignedIntegerConfig);
+            db.update(
+                i,
+                &UnsignedInteger::new(0xA0 + i as u8),
+                UpdateOptions::default(),
+            );
+        }
+    });
+}
+
+#[tokio::test]
+async fn can_read_all_unsigned_integers() {
+    let mut harness = new_harness(get_default_config());
+    add_points(&mut harness);
+
+    // single g102v1 header, indices 0 to 2
+    harness
+        .test_request_response(
+            &[0xC0, 0x01, 102, 0, 0x06],
+            &[
+                0xC0, 0x81, 0x80, 0x00, 102, 1, 0x01, 0, 0, 2, 0, 0xA0, 0xA1, 0xA2,
+            ],
+        )
+        .await;
+
+    harness.check_no_events();
+}
+
+#[tokio::test]
+async fn can_read_unsigned_integers_by_range() {
+    let mut harness = new_harness(get_default_config());
+    add_points(&mut harness);
+
+    // read g102v1 with a 1-byte start/stop range of 1 to 2
+    harness
+        .test_request_response(
+            &[0xC0, 0x01, 102, 1, 0x00, 1, 2],
+            &[0xC0, 0x81, 0x80, 0x00, 102, 1, 0x01, 1, 0, 2, 0, 0xA1, 0xA2],
+        )
+        .await;
+
+    harness.check_no_events();
+}
+
+#[tokio::test]
+async fn unsigned_integers_do_not_produce_events() {
+    let mut harness = new_harness(get_default_config());
+    add_points(&mut harness);
+
+    // read class 1, 2, and 3
+    harness
+        .test_request_response(
+            &[0xC0, 0x01, 60, 2, 0x06, 60, 3, 0x06, 60, 4, 0x06],
+            EMPTY_RESPONSE,
+        )
+        .await;
+
+    harness.check_no_events();
+}
+
+#[tokio::test]
+async fn unsigned_integers_are_excluded_from_class_zero_by_default() {
+    let mut harness = new_harness(get_default_config());
+    add_points(&mut harness);
+
+    harness
+        .test_request_response(READ_CLASS_0, EMPTY_RESPONSE)
+        .await;
+
+    harness.check_no_events();
+}
+
+#[tokio::test]
+async fn unsigned_integers_are_reported_in_class_zero_when_enabled() {
+    let mut config = get_default_config();
+    config.class_zero.unsigned_integer = true;
+    let mut harness = new_harness(config);
+    add_points(&mut harness);
+
+    harness
+        .test_request_response(
+            READ_CLASS_0,
+            &[
+                0xC0, 0x81, 0x80, 0x00, 102, 1, 0x01, 0, 0, 2, 0, 0xA0, 0xA1, 0xA2,
+            ],
+        )
+        .await;
+
+    harness.check_no_events();
+}
@@ -3,7 +3,7 @@ use crate::app::measurement::*;
 use crate::app::parse::options::ParseOptions;
 use crate::app::parse::parser::{HeaderDetails, ParsedFragment};
 use crate::app::{BufferSize, Timestamp};
-use crate::outstation::config::OutstationConfig;
+use crate::outstation::config::{OutstationConfig, UnsolicitedClassHold};
 use crate::outstation::database::*;
 use crate::outstation::{BufferState, ClassCount, TypeCount};
 
@@ -23,6 +23,9 @@ const UNSOL_G2V1_SEQ1: &[u8] = &[
 const UNSOL_G2V1_SEQ2: &[u8] = &[
     0xF2, 0x82, 0x80, 0x00, 0x02, 0x01, 0x28, 0x01, 0x00, 0x00, 0x00, 0x81,
 ];
+const UNSOL_TWO_G2V1_SEQ1: &[u8] = &[
+    0xF1, 0x82, 0x80, 0x00, 0x02, 0x01, 0x28, 0x02, 0x00, 0x00, 0x00, 0x81, 0x01, 0x00, 0x81,
+];
 const ENABLE_UNSOLICITED_SEQ0: &[u8] = &[
     0xC0, 0x14, 0x3C, 0x02, 0x06, 0x3C, 0x03, 0x06, 0x3C, 0x04, 0x06,
 ];
@@ -50,6 +53,24 @@ fn generate_binary_event(handle: &mut DatabaseHandle) {
     });
 }
 
+fn add_binary_points(handle: &mut DatabaseHandle, classes: &[EventClass]) {
+    handle.transaction(|db| {
+        for (index, class) in classes.iter().enumerate() {
+            db.add(index as u16, Some(*class), BinaryInputConfig::default());
+        }
+    });
+}
+
+fn update_binary(handle: &mut DatabaseHandle, index: u16) {
+    handle.transaction(|db| {
+        db.update(
+            index,
+            &BinaryInput::new(true, Flags::ONLINE, Time::synchronized(0)),
+            UpdateOptions::default(),
+        )
+    });
+}
+
 async fn enable_unsolicited(harness: &mut OutstationHarness) {
     harness
         .test_request_response(ENABLE_UNSOLICITED_SEQ0, EMPTY_RESPONSE_SEQ0)
@@ -209,6 +230,12 @@ async fn defers_read_during_unsol_confirm_wait() {
                 num_analog: 0,
                 num_analog_output_status: 0,
                 num_octet_string: 0,
+                num_file_transfer: 0,
+                num_binary_output_command: 0,
+                num_analog_output_command: 0,
+                num_data_set: 0,
+                num_virtual_terminal: 0,
+                num_security_statistic: 0,
             },
         }),
     ]);
@@ -324,6 +351,7 @@ async fn sends_unsolicited_from_one_update() {
                     s_var: StaticAnalogInputVariation::Group30Var1,
                     e_var: EventAnalogInputVariation::Group32Var1, // 5  bytes
                     deadband: 0.0,
+                    deadband_mode: DeadbandMode::Fixed,
                 },
             );
         }
@@ -443,3 +471,144 @@ async fn buffer_overflow_issue() {
         )
         .await;
 }
+
+const HOLD_DELAY: std::time::Duration = std::time::Duration::from_secs(60);
+
+fn config_with_hold(class1_immediate: bool) -> OutstationConfig {
+    let mut config = get_default_unsolicited_config();
+    config.unsolicited_hold.class1_immediate = class1_immediate;
+    config.unsolicited_hold.class1 = UnsolicitedClassHold::new(HOLD_DELAY, 2);
+    config.unsolicited_hold.class2 = UnsolicitedClassHold::new(HOLD_DELAY, 2);
+    config
+}
+
+#[tokio::test]
+async fn holds_unsolicited_until_class_count_is_reached() {
+    let mut harness = new_harness(config_with_hold(true));
+    confirm_null_unsolicited(&mut harness).await;
+    enable_unsolicited(&mut harness).await;
+    add_binary_points(
+        &mut harness.handle.database,
+        &[EventClass::Class2, EventClass::Class2],
+    );
+
+    tokio::time::pause();
+    update_binary(&mut harness.handle.database, 0);
+    tokio::time::sleep(HOLD_DELAY / 2).await;
+    harness.expect_no_response();
+
+    update_binary(&mut harness.handle.database, 1);
+    harness.expect_response(UNSOL_TWO_G2V1_SEQ1).await;
+    harness.check_events(&[Event::EnterUnsolicitedConfirmWait(1)]);
+}
+
+#[tokio::test]
+async fn holds_unsolicited_until_class_delay_elapses() {
+    let mut harness = new_harness(config_with_hold(true));
+    confirm_null_unsolicited(&mut harness).await;
+    enable_unsolicited(&mut harness).await;
+    add_binary_points(&mut harness.handle.database, &[EventClass::Class2]);
+
+    tokio::time::pause();
+    let start = tokio::time::Instant::now();
+    update_binary(&mut harness.handle.database, 0);
+    harness.expect_response(UNSOL_G2V1_SEQ1).await;
+    harness.check_events(&[Event::EnterUnsolicitedConfirmWait(1)]);
+    assert!(start.elapsed() >= HOLD_DELAY);
+}
+
+#[tokio::test]
+async fn class_1_override_reports_held_events_immediately() {
+    let mut harness = new_harness(config_with_hold(true));
+    confirm_null_unsolicited(&mut harness).await;
+    enable_unsolicited(&mut harness).await;
+    add_binary_points(
+        &mut harness.handle.database,
+        &[EventClass::Class2, EventClass::Class1],
+    );
+
+    tokio::time::pause();
+    update_binary(&mut harness.handle.database, 0);
+    tokio::time::sleep(HOLD_DELAY / 2).await;
+    harness.expect_no_response();
+
+    // the class 1 event is sent immediately along with the held class 2 event
+    update_binary(&mut harness.handle.database, 1);
+    harness.expect_response(UNSOL_TWO_G2V1_SEQ1).await;
+    harness.check_events(&[Event::EnterUnsolicitedConfirmWait(1)]);
+}
+
+#[tokio::test]
+async fn hold_without_a_representable_delay_waits_for_the_class_count() {
+    let mut config = get_default_unsolicited_config();
+    config.unsolicited_hold.class2 = UnsolicitedClassHold::new(std::time::Duration::MAX, 2);
+    let mut harness = new_harness(config);
+    confirm_null_unsolicited(&mut harness).await;
+    enable_unsolicited(&mut harness).await;
+    add_binary_points(
+        &mut harness.handle.database,
+        &[EventClass::Class2, EventClass::Class2],
+    );
+
+    tokio::time::pause();
+    update_binary(&mut harness.handle.database, 0);
+    tokio::time::sleep(HOLD_DELAY * 1000).await;
+    harness.expect_no_response();
+
+    update_binary(&mut harness.handle.database, 1);
+    harness.expect_response(UNSOL_TWO_G2V1_SEQ1).await;
+    harness.check_events(&[Event::EnterUnsolicitedConfirmWait(1)]);
+}
+
+#[tokio::test]
+async fn holds_class_1_events_without_override() {
+    let mut harness = new_harness(config_with_hold(false));
+    confirm_null_unsolicited(&mut harness).await;
+    enable_unsolicited(&mut harness).await;
+    add_binary_points(&mut harness.handle.database, &[EventClass::Class1]);
+
+    tokio::time::pause();
+    update_binary(&mut harness.handle.database, 0);
+    tokio::time::sleep(HOLD_DELAY / 2).await;
+    harness.expect_no_response();
+
+    harness.expect_response(UNSOL_G2V1_SEQ1).await;
+    harness.check_events(&[Event::EnterUnsolicitedConfirmWait(1)]);
+}
+
+#[tokio::test]
+async fn hold_restarts_after_confirmed_unsolicited_response() {
+    let mut harness = new_harness(config_with_hold(true));
+    confirm_null_unsolicited(&mut harness).await;
+    enable_unsolicited(&mut harness).await;
+    add_binary_points(
+        &mut harness.handle.database,
+        &[EventClass::Class2, EventClass::Class2],
+    );
+
+    tokio::time::pause();
+    update_binary(&mut harness.handle.database, 0);
+    update_binary(&mut harness.handle.database, 1);
+    harness.expect_response(UNSOL_TWO_G2V1_SEQ1).await;
+    harness.send_and_process(UNS_CONFIRM_SEQ_1).await;
+    harness.check_events(&[
+        Event::EnterUnsolicitedConfirmWait(1),
+        Event::UnsolicitedConfirmReceived(1),
+    ]);
+
+    // a single new event is held for the full delay
+    harness.handle.database.transaction(|db| {
+        db.update(
+            0,
+            &BinaryInput::new(false, Flags::ONLINE, Time::synchronized(0)),
+            UpdateOptions::default(),
+        )
+    });
+    let start = tokio::time::Instant::now();
+    harness
+        .expect_response(&[
+            0xF2, 0x82, 0x80, 0x00, 0x02, 0x01, 0x28, 0x01, 0x00, 0x00, 0x00, 0x01,
+        ])
+        .await;
+    assert!(start.elapsed() >= HOLD_DELAY);
+}
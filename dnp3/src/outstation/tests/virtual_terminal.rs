@@ -0,0 +1,97 @@
+use crate::outstation::database::EventClass::Class1;
+use crate::outstation::database::*;
+use crate::outstation::tests::harness::*;
+
+// write "abc" to port 5 using g112v3 with a two-byte count and prefix
+const WRITE_PORT_5: &[u8] = &[
+    0xC0, 0x02, 112, 3, 0x28, 0x01, 0x00, 0x05, 0x00, b'a', b'b', b'c',
+];
+
+fn add_port(harness: &mut OutstationHarness, class: Option<EventClass>) {
+    harness.handle.transaction(|db| {
+        assert!(db.add(5, class, VirtualTerminalConfig));
+    });
+}
+
+#[tokio::test]
+async fn write_is_passed_to_handler_and_echo_is_reported_as_event() {
+    let mut harness = new_harness(get_default_config());
+    add_port(&mut harness, Some(Class1));
+
+    harness
+        .test_request_response(WRITE_PORT_5, &[0xC0, 0x81, 0x82, 0x00])
+        .await;
+    harness.check_events(&[Event::WriteVirtualTerminal(5, 3)]);
+
+    harness
+        .test_request_response(
+            &[0xC1, 0x01, 60, 2, 0x06],
+            &[
+                0xE1, 0x81, 0x80, 0x00, 113, 3, 0x28, 0x01, 0x00, 0x05, 0x00, b'a', b'b', b'c',
+            ],
+        )
+        .await;
+    harness.check_events(&[Event::EnterSolicitedConfirmWait(1)]);
+}
+
+#[tokio::test]
+async fn write_to_undefined_port_is_rejected() {
+    let mut harness = new_harness(get_default_config());
+
+    harness
+        .test_request_response(WRITE_PORT_5, &[0xC0, 0x81, 0x80, 0x04])
+        .await;
+    harness.check_no_events();
+}
+
+#[tokio::test]
+async fn write_is_rejected_without_handler() {
+    let mut harness = new_harness(get_default_config());
+    add_port(&mut harness, Some(Class1));
+    harness
+        .application_data
+        .lock()
+        .unwrap()
+        .support_virtual_terminal = false;
+
+    harness
+        .test_request_response(WRITE_PORT_5, &[0xC0, 0x81, 0x80, 0x01])
+        .await;
+    harness.check_no_events();
+}
+
+#[tokio::test]
+async fn port_without_class_does_not_produce_events() {
+    let mut harness = new_harness(get_default_config());
+    add_port(&mut harness, None);
+
+    harness
+        .test_request_response(WRITE_PORT_5, &[0xC0, 0x81, 0x80, 0x00])
+        .await;
+    harness.check_events(&[Event::WriteVirtualTerminal(5, 3)]);
+}
+
+#[tokio::test]
+async fn long_data_is_split_into_multiple_objects() {
+    let mut harness = new_harness(get_default_config());
+    add_port(&mut harness, Some(Class1));
+
+    let data: Vec<u8> = (0..300).map(|x| x as u8).collect();
+    harness.handle.transaction(|db| {
+        assert!(db.push_virtual_terminal_data(5, &data));
+        assert!(!db.push_virtual_terminal_data(6, &data));
+    });
+
+    let mut expected = vec![
+        0xE0, 0x81, 0x80, 0x00, 113, 255, 0x28, 0x01, 0x00, 0x05, 0x00,
+    ];
+    expected.extend_from_slice(&data[..255]);
+    expected.extend_from_slice(&[113, 45, 0x28, 0x01, 0x00, 0x05, 0x00]);
+    expected.extend_from_slice(&data[255..]);
+
+    // read g113 events using the all objects qualifier
+    harness
+        .test_request_response(&[0xC0, 0x01, 113, 0, 0x06], &expected)
+        .await;
+    harness.check_events(&[Event::EnterSolicitedConfirmWait(0)]);
+}
@@ -1,10 +1,15 @@
 use crate::app::attr::Attribute;
-use crate::app::variations::Group50Var2;
+use crate::app::data_set::DataSet;
+use crate::app::measurement::{OctetString, Time};
+use crate::app::variations::{Group50Var2, Group50Var4};
 use crate::app::RequestHeader;
 use crate::app::Sequence;
 use crate::app::{control::*, Timestamp};
 use crate::app::{FunctionCode, MaybeAsync};
-use crate::outstation::database::DatabaseHandle;
+use crate::outstation::database::{DatabaseHandle, EventClass, UpdateOptions};
+use crate::outstation::{AuthFailure, AuthHandler, OutstationFileSystem};
+
+use std::time::Duration;
 
 /// Application-controlled IIN bits
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
@@ -83,6 +88,18 @@ pub struct TypeCount {
     pub num_analog_output_status: usize,
     /// number octet string events remaining in the buffer
     pub num_octet_string: usize,
+    /// number of file transfer events remaining in the buffer
+    pub num_file_transfer: usize,
+    /// number of binary output command events remaining in the buffer
+    pub num_binary_output_command: usize,
+    /// number of analog output command events remaining in the buffer
+    pub num_analog_output_command: usize,
+    /// number of data set snapshot events remaining in the buffer
+    pub num_data_set: usize,
+    /// number of virtual terminal events remaining in the buffer
+    pub num_virtual_terminal: usize,
+    /// number of security statistic events remaining in the buffer
+    pub num_security_statistic: usize,
 }
 
 /// Information about the state of buffer after a CONFIRM has been processed
@@ -94,6 +111,20 @@ pub struct BufferState {
     pub types: TypeCount,
 }
 
+/// Result of validating an octet string in a WRITE request
+#[derive(Debug, Copy, Clone)]
+pub enum WriteOctetString {
+    /// reject the write with IIN2.PARAMETER_ERROR
+    Reject,
+    /// accept the write without modifying the database
+    Accept,
+    /// accept the write and update the value in the database using the specified options
+    ///
+    /// An octet string event (g111) is produced if the options detect events and the
+    /// point is assigned to a class.
+    Update(UpdateOptions),
+}
+
 /// dynamic information required by the outstation from the user application
 pub trait OutstationApplication: Sync + Send + 'static {
     /// The value returned by this method is used in conjunction with the `Delay Measurement`
@@ -209,6 +240,159 @@ pub trait OutstationApplication: Sync + Send + 'static {
         MaybeAsync::ready(true)
     }
 
+    /// Called in response to a WRITE request containing a group 87 data set. The present value
+    /// is automatically updated in the database if this method returns true.
+    ///
+    /// This callback is only invoked for data sets that have been added to the database. The
+    /// library decodes the values using the descriptor and rejects writes with values that do not
+    /// match the descriptor with IIN2::PARAM_ERROR.
+    ///
+    /// Returning false (the default) rejects the write with IIN2::PARAM_ERROR.
+    #[allow(unused_variables)]
+    fn write_data_set(&mut self, id: u16, data_set: &DataSet) -> MaybeAsync<bool> {
+        MaybeAsync::ready(false)
+    }
+
+    /// Called for each octet string in a WRITE request containing group 110 objects.
+    ///
+    /// This callback is only invoked for octet strings that have been added to the database.
+    /// Writes to undefined points are rejected with IIN2::PARAM_ERROR within the library.
+    ///
+    /// Returning [`WriteOctetString::Reject`] (the default) rejects the write with IIN2::PARAM_ERROR.
+    #[allow(unused_variables)]
+    fn write_octet_string(
+        &mut self,
+        index: u16,
+        value: &OctetString,
+    ) -> MaybeAsync<WriteOctetString> {
+        MaybeAsync::ready(WriteOctetString::Reject)
+    }
+
+    /// Called for each indexed time and interval (g50v4) in a WRITE request
+    ///
+    /// When [`Features::freeze_engine`](crate::outstation::Features::freeze_engine) is enabled,
+    /// the index refers to a counter linked to a frozen counter and the value must be convertible
+    /// using [`TimeAndInterval::to_freeze_interval`]. Other values are rejected with
+    /// IIN2::PARAM_ERROR within the library. If this method returns true, the value is then
+    /// applied as the freeze schedule of the counter.
+    ///
+    /// Returning false (the default) rejects the write with IIN2::PARAM_ERROR.
+    #[allow(unused_variables)]
+    fn write_time_and_interval(&mut self, index: u16, value: TimeAndInterval) -> MaybeAsync<bool> {
+        MaybeAsync::ready(false)
+    }
+
+    /// Returns the file system exposed to the master using Group 70 file transfer objects
+    ///
+    /// Returning `None` (the default) causes all file transfer requests to be rejected with
+    /// IIN2.0 NO_FUNC_CODE_SUPPORT
+    fn file_system(&mut self) -> Option<&mut dyn OutstationFileSystem> {
+        None
+    }
+
+    /// Returns the handler for virtual terminal output blocks (g112) written by the master
+    ///
+    /// Returning `None` (the default) causes all g112 WRITE requests to be rejected with
+    /// IIN2.0 NO_FUNC_CODE_SUPPORT
+    fn virtual_terminal(&mut self) -> Option<&mut dyn VirtualTerminalHandler> {
+        None
+    }
+
+    /// Returns the users and update keys used when secure authentication is enabled
+    ///
+    /// Returning `None` (the default) means that no user is known, so the master can never
+    /// establish session keys and every critical request fails authentication
+    fn auth_handler(&mut self) -> Option<&mut dyn AuthHandler> {
+        None
+    }
+
+    /// Controls outstation support for the ASSIGN_CLASS function code
+    ///
+    /// Returning false (the default) causes ASSIGN_CLASS requests to be rejected with
+    /// IIN2.0 NO_FUNC_CODE_SUPPORT
+    ///
+    /// Returning true will cause [`Self::assign_class`] to be called for each point header in the request
+    /// that names at least one point in the database. Headers that name no points are rejected with
+    /// IIN2.2 PARAMETER_ERROR, or IIN2.1 OBJECT_UNKNOWN if the database has no points of the type.
+    fn support_assign_class(&mut self) -> bool {
+        false
+    }
+
+    /// Called for each point header in an ASSIGN_CLASS request before the database is modified
+    ///
+    /// Returning an error vetoes the assignment and the error is reported in IIN2. Returning
+    /// `Ok(())` causes the new class to be stored in the database. Events created afterwards use
+    /// the new class while events already in the buffer retain the class they were created with.
+    ///
+    /// This callback allows application code to persist the assignment to non-volatile memory if desired
+    #[allow(unused_variables)]
+    fn assign_class(&mut self, assignment: ClassAssignment) -> Result<(), RequestError> {
+        Ok(())
+    }
+
+    /// Called when a SAVE_CONFIGURATION request is received
+    ///
+    /// The returned value is reported in IIN2. The default implementation returns
+    /// [`RequestError::NotSupported`] which causes IIN2.0 NO_FUNC_CODE_SUPPORT to be set.
+    ///
+    /// SAVE_CONFIGURATION is deprecated by the standard, ACTIVATE_CONFIG should be used instead.
+    fn save_configuration(&mut self) -> Result<(), RequestError> {
+        Err(RequestError::NotSupported)
+    }
+
+    /// Returns true if the application supports ACTIVATE_CONFIG requests
+    ///
+    /// Returning false (the default) causes ACTIVATE_CONFIG requests to be rejected with
+    /// IIN2.0 NO_FUNC_CODE_SUPPORT
+    ///
+    /// Returning true will cause [`Self::activate_config`] to be called for each object in the request
+    /// followed by a single call to [`Self::end_activate_config`]
+    fn support_activate_config(&mut self) -> bool {
+        false
+    }
+
+    /// Called for each file specification string (g70v8) or octet string (g110) in an ACTIVATE_CONFIG request
+    ///
+    /// The returned status is reported to the master in the g91v1 object of the response, in
+    /// the same order as the objects appear in the request
+    #[allow(unused_variables)]
+    fn activate_config(&mut self, object: ConfigObject) -> ActivationStatus {
+        ActivationStatus::success()
+    }
+
+    /// Called once all the objects in an ACTIVATE_CONFIG request have been processed
+    ///
+    /// The returned duration is the time the master should wait before communicating with the outstation
+    /// after the activation. It is reported in the time delay field of g91v1 with millisecond resolution.
+    fn end_activate_config(&mut self) -> Duration {
+        Duration::ZERO
+    }
+
+    /// Called for each application identifier (g90v1) in an INITIALIZE_APPLICATION request
+    ///
+    /// The application should be placed in the ready-to-run state. Errors are reported in IIN2.
+    /// The default implementation returns [`ApplicationControlError::NotSupported`].
+    #[allow(unused_variables)]
+    fn initialize_application(&mut self, id: ApplicationId) -> Result<(), ApplicationControlError> {
+        Err(ApplicationControlError::NotSupported)
+    }
+
+    /// Called for each application identifier (g90v1) in a START_APPLICATION request
+    ///
+    /// Errors are reported in IIN2. The default implementation returns [`ApplicationControlError::NotSupported`].
+    #[allow(unused_variables)]
+    fn start_application(&mut self, id: ApplicationId) -> Result<(), ApplicationControlError> {
+        Err(ApplicationControlError::NotSupported)
+    }
+
+    /// Called for each application identifier (g90v1) in a STOP_APPLICATION request
+    ///
+    /// Errors are reported in IIN2. The default implementation returns [`ApplicationControlError::NotSupported`].
+    #[allow(unused_variables)]
+    fn stop_application(&mut self, id: ApplicationId) -> Result<(), ApplicationControlError> {
+        Err(ApplicationControlError::NotSupported)
+    }
+
     /// Called when a CONFIRM is received to a response or unsolicited response, but before any
     /// previously transmitted events are cleared from the buffer
     fn begin_confirm(&mut self) {}
@@ -226,6 +410,25 @@ pub trait OutstationApplication: Sync + Send + 'static {
     }
 }
 
+/// Virtual terminal ports exposed to the master using Group 112 and Group 113
+///
+/// Ports must be added to the database using
+/// [`VirtualTerminalConfig`](crate::outstation::database::VirtualTerminalConfig) before the master
+/// can write to them. Data returned by a terminal is reported to the master as g113 events by
+/// calling [`Database::push_virtual_terminal_data`](crate::outstation::database::Database::push_virtual_terminal_data),
+/// either from within [`VirtualTerminalHandler::write`] or at any later time.
+pub trait VirtualTerminalHandler: Send + Sync + 'static {
+    /// Called for each output block (g112) the master writes to a port that exists in the database
+    ///
+    /// Returning an error sets the corresponding IIN2 bit in the response
+    fn write(
+        &mut self,
+        port: u16,
+        data: &[u8],
+        database: &mut DatabaseHandle,
+    ) -> Result<(), RequestError>;
+}
+
 /// enumeration describing how the outstation processed a broadcast request
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum BroadcastAction {
@@ -236,6 +439,8 @@ pub enum BroadcastAction {
     /// Outstation was unable to parse the object headers and ignored the request
     BadObjectHeaders,
     /// Outstation ignored the broadcast message b/c the function is not supported via Broadcast
+    ///
+    /// When secure authentication is enabled, every critical function is unsupported via broadcast
     UnsupportedFunction(FunctionCode),
 }
 
@@ -267,6 +472,8 @@ pub trait OutstationInformation: Sync + Send + 'static {
     fn unsolicited_confirmed(&mut self, _ecsn: Sequence) {}
     /// master cleared the restart IIN bit
     fn clear_restart_iin(&mut self) {}
+    /// secure authentication of a request from the user failed
+    fn authentication_failure(&mut self, _user: u16, _failure: AuthFailure) {}
 }
 
 /// enumeration describing how the master requested the control operation
@@ -332,6 +539,116 @@ pub enum FreezeIndices {
     Range(u16, u16),
 }
 
+/// Point type targeted by an ASSIGN_CLASS request
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AssignClassType {
+    /// Binary input
+    BinaryInput,
+    /// Double-bit binary input
+    DoubleBitBinaryInput,
+    /// Binary output status
+    BinaryOutputStatus,
+    /// Counter
+    Counter,
+    /// Frozen counter
+    FrozenCounter,
+    /// Analog input
+    AnalogInput,
+    /// Analog output status
+    AnalogOutputStatus,
+    /// Octet string
+    OctetString,
+}
+
+/// Indices targeted by an ASSIGN_CLASS request
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AssignClassIndices {
+    /// All points of the type
+    All,
+    /// Range of points (the range is inclusive)
+    Range(u16, u16),
+}
+
+/// A single point header of an ASSIGN_CLASS request combined with the class from the preceding g60 header
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ClassAssignment {
+    /// Class to which the points are assigned. `None` assigns the points to class 0 so that they no longer produce events.
+    pub class: Option<EventClass>,
+    /// Type of the points
+    pub point_type: AssignClassType,
+    /// Indices of the points
+    pub indices: AssignClassIndices,
+}
+
+/// Application named by a g90v1 header in an INITIALIZE_APPLICATION, START_APPLICATION, or STOP_APPLICATION request
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ApplicationId<'a> {
+    /// All applications (g90v1 with qualifier 0x06)
+    All,
+    /// Application with the specified name (g90v1 with free-format qualifier 0x5B)
+    Name(&'a str),
+}
+
+/// Errors that may be returned when initializing, starting, or stopping an application
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ApplicationControlError {
+    /// The outstation does not support the function (IIN2.0 NO_FUNC_CODE_SUPPORT)
+    NotSupported,
+    /// The application is not known to the outstation (IIN2.1 OBJECT_UNKNOWN)
+    UnknownApplication,
+    /// The request cannot be performed in the current state of the application (IIN2.2 PARAMETER_ERROR)
+    ParameterError,
+    /// The operation is already in progress (IIN2.4 ALREADY_EXECUTING)
+    AlreadyExecuting,
+}
+
+/// An object in an ACTIVATE_CONFIG request that names the configuration to activate
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ConfigObject<'a> {
+    /// File specification string from g70v8
+    File(&'a str),
+    /// Octet string from g110
+    String {
+        /// index of the octet string
+        index: u16,
+        /// value of the octet string
+        value: &'a [u8],
+    },
+}
+
+/// Status of a single object in an ACTIVATE_CONFIG request reported in g91v1
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActivationStatus {
+    /// Status code. Zero indicates success, all other values are application-specific failures.
+    pub code: u8,
+    /// Optional ancillary data describing the status (at most 254 bytes)
+    pub ancillary: Vec<u8>,
+}
+
+impl ActivationStatus {
+    /// Status indicating that the object was activated successfully
+    pub fn success() -> Self {
+        Self {
+            code: 0,
+            ancillary: Vec::new(),
+        }
+    }
+
+    /// Status indicating that the object could not be activated
+    pub fn failure(code: u8) -> Self {
+        Self {
+            code,
+            ancillary: Vec::new(),
+        }
+    }
+
+    /// Attach ancillary data to the status
+    pub fn with_ancillary(self, ancillary: Vec<u8>) -> Self {
+        Self { ancillary, ..self }
+    }
+}
+
 /// This object maps to the fields of g50v2
 ///
 /// There is a table on page 57 of 1815-2012 that describes these 4 permutations
@@ -382,6 +699,137 @@ impl From<FreezeInterval> for Group50Var2 {
     }
 }
 
+/// Units of the interval in an indexed time and interval object (g50v4)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum IntervalUnits {
+    /// The time does not repeat
+    NoRepeat,
+    /// Milliseconds
+    Milliseconds,
+    /// Seconds
+    Seconds,
+    /// Minutes
+    Minutes,
+    /// Hours
+    Hours,
+    /// Days
+    Days,
+    /// Weeks
+    Weeks,
+    /// Months on the same day of the month
+    Months,
+    /// Months on the same day of the week counted from the beginning of the month
+    MonthsSameDayOfWeekFromStart,
+    /// Months on the same day of the week counted from the end of the month
+    MonthsSameDayOfWeekFromEnd,
+    /// Seasons
+    Seasons,
+    /// Interval defined by the device
+    DeviceDefined,
+    /// Reserved value not defined in the standard
+    Other(u8),
+}
+
+impl IntervalUnits {
+    /// construct the units from the raw value
+    pub fn from_u8(value: u8) -> Self {
+        match value {
+            0 => Self::NoRepeat,
+            1 => Self::Milliseconds,
+            2 => Self::Seconds,
+            3 => Self::Minutes,
+            4 => Self::Hours,
+            5 => Self::Days,
+            6 => Self::Weeks,
+            7 => Self::Months,
+            8 => Self::MonthsSameDayOfWeekFromStart,
+            9 => Self::MonthsSameDayOfWeekFromEnd,
+            10 => Self::Seasons,
+            128 => Self::DeviceDefined,
+            _ => Self::Other(value),
+        }
+    }
+
+    /// convert the units to the raw value
+    pub fn to_u8(self) -> u8 {
+        match self {
+            Self::NoRepeat => 0,
+            Self::Milliseconds => 1,
+            Self::Seconds => 2,
+            Self::Minutes => 3,
+            Self::Hours => 4,
+            Self::Days => 5,
+            Self::Weeks => 6,
+            Self::Months => 7,
+            Self::MonthsSameDayOfWeekFromStart => 8,
+            Self::MonthsSameDayOfWeekFromEnd => 9,
+            Self::Seasons => 10,
+            Self::DeviceDefined => 128,
+            Self::Other(x) => x,
+        }
+    }
+
+    /// length of a single unit in milliseconds, if it has a fixed length
+    pub fn to_millis(self) -> Option<u64> {
+        match self {
+            Self::Milliseconds => Some(1),
+            Self::Seconds => Some(1000),
+            Self::Minutes => Some(60 * 1000),
+            Self::Hours => Some(60 * 60 * 1000),
+            Self::Days => Some(24 * 60 * 60 * 1000),
+            Self::Weeks => Some(7 * 24 * 60 * 60 * 1000),
+            _ => None,
+        }
+    }
+}
+
+/// This object maps to the fields of g50v4
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TimeAndInterval {
+    /// Start time
+    pub time: Timestamp,
+    /// Number of units in the interval
+    pub interval: u32,
+    /// Units of the interval
+    pub units: IntervalUnits,
+}
+
+impl TimeAndInterval {
+    /// construct a new instance from its fields
+    pub fn new(time: Timestamp, interval: u32, units: IntervalUnits) -> Self {
+        Self {
+            time,
+            interval,
+            units,
+        }
+    }
+
+    /// convert to the equivalent [`FreezeInterval`]
+    ///
+    /// Returns `None` if the units do not have a fixed length or the interval in milliseconds
+    /// does not fit in the 32-bit interval of a [`FreezeInterval`].
+    pub fn to_freeze_interval(&self) -> Option<FreezeInterval> {
+        let interval = match self.units {
+            IntervalUnits::NoRepeat => 0,
+            units => {
+                let millis = units.to_millis()?.checked_mul(self.interval as u64)?;
+                u32::try_from(millis).ok()?
+            }
+        };
+        Some(FreezeInterval::new(self.time, interval))
+    }
+}
+
+impl From<Group50Var4> for TimeAndInterval {
+    fn from(value: Group50Var4) -> Self {
+        Self::new(
+            value.time,
+            value.interval,
+            IntervalUnits::from_u8(value.units),
+        )
+    }
+}
+
 /// Freeze operation type
 #[cfg_attr(not(feature = "ffi"), non_exhaustive)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -396,6 +844,9 @@ pub enum FreezeType {
 }
 
 /// callbacks for handling controls
+///
+/// A pattern control block (g12v2) is delivered as a CROB (g12v1) for each output selected
+/// by the pattern mask (g12v3) that follows it.
 pub trait ControlHandler:
     ControlSupport<Group12Var1>
     + ControlSupport<Group41Var1>
@@ -418,6 +869,14 @@ pub trait ControlHandler:
     fn end_fragment(&mut self, _database: &mut DatabaseHandle) -> MaybeAsync<()> {
         MaybeAsync::ready(())
     }
+
+    /// timestamp applied to command events produced automatically from successful operations
+    ///
+    /// Only called when [`Features::command_events`](crate::outstation::Features::command_events)
+    /// is enabled. Returning `None` produces events without a timestamp.
+    fn get_command_event_time(&mut self) -> Option<Time> {
+        None
+    }
 }
 
 /// Struct with a default implementation of [ControlHandler](crate::outstation::ControlHandler)
@@ -16,6 +16,38 @@ use crate::util::shutdown::ShutdownListener;
 use std::net::SocketAddr;
 use tracing::Instrument;
 
+/// error returned when a master cannot be associated with an existing outstation
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AddAssociationError {
+    /// filter conflicts with an existing filter
+    FilterConflict,
+    /// another master with the same address is already associated with the outstation
+    DuplicateMasterAddress,
+}
+
+impl std::error::Error for AddAssociationError {}
+
+impl std::fmt::Display for AddAssociationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            AddAssociationError::FilterConflict => {
+                f.write_str("filter conflicts with an existing filter")
+            }
+            AddAssociationError::DuplicateMasterAddress => {
+                f.write_str("master address is already associated with the outstation")
+            }
+        }
+    }
+}
+
+impl From<FilterError> for AddAssociationError {
+    fn from(err: FilterError) -> Self {
+        match err {
+            FilterError::Conflict => AddAssociationError::FilterConflict,
+        }
+    }
+}
+
 struct OutstationInfo {
     filter: AddressFilter,
     handle: OutstationHandle,
@@ -91,11 +123,7 @@ impl Server {
         listener: Box<dyn Listener<ConnectionState>>,
         filter: AddressFilter,
     ) -> Result<(OutstationHandle, impl std::future::Future<Output = ()>), FilterError> {
-        for item in self.outstations.iter() {
-            if filter.conflicts_with(&item.filter) {
-                return Err(FilterError::Conflict);
-            }
-        }
+        self.check_filter(&filter)?;
 
         let (task, handle) = OutstationTask::create(
             Enabled::Yes,
@@ -108,6 +136,103 @@ impl Server {
             control_handler,
         );
 
+        Ok(self.add_task(task, handle, config, listener, filter))
+    }
+
+    /// associate an additional master with an existing outstation, but do not spawn it
+    ///
+    /// The master communicates with the outstation over its own connections, accepted using
+    /// `filter`, and is served by an independent session with its own sequence numbers, select
+    /// state, enabled unsolicited classes and event confirmations. The session reports the
+    /// points and events of the [`Database`](crate::outstation::database::Database) of
+    /// `outstation`, which may be associated with any number of masters. An event is removed from
+    /// the buffer once it has been confirmed by every master for which it is retained, see
+    /// [`OutstationConfig::offline_association_policy`].
+    ///
+    /// The class 0 and event buffer configuration belong to the shared database. The
+    /// [`OutstationConfig::class_zero`], [`OutstationConfig::event_buffer_config`] and
+    /// [`OutstationConfig::max_read_request_headers`] fields of `config` are not applied when the
+    /// master is added, but [`OutstationHandle::reconfigure`] applies the first two to the
+    /// database for every master.
+    #[allow(clippy::too_many_arguments)]
+    pub fn add_association_no_spawn(
+        &mut self,
+        outstation: &OutstationHandle,
+        config: OutstationConfig,
+        application: Box<dyn OutstationApplication>,
+        information: Box<dyn OutstationInformation>,
+        control_handler: Box<dyn ControlHandler>,
+        listener: Box<dyn Listener<ConnectionState>>,
+        filter: AddressFilter,
+    ) -> Result<(OutstationHandle, impl std::future::Future<Output = ()>), AddAssociationError>
+    {
+        self.check_filter(&filter)?;
+
+        let database = outstation.get_database_handle();
+        let association = database
+            .add_association(config.master_address, config.offline_association_policy)
+            .ok_or(AddAssociationError::DuplicateMasterAddress)?;
+
+        let (task, handle) = OutstationTask::create_with_database(
+            Enabled::Yes,
+            self.link_modes,
+            ParseOptions::get_static(),
+            config,
+            PhysAddr::None,
+            application,
+            information,
+            control_handler,
+            database,
+            association,
+        );
+
+        Ok(self.add_task(task, handle, config, listener, filter))
+    }
+
+    /// associate an additional master with an existing outstation and spawn it
+    ///
+    /// See [`Server::add_association_no_spawn`] for details. Must be called from within the Tokio runtime
+    #[allow(clippy::too_many_arguments)]
+    pub fn add_association(
+        &mut self,
+        outstation: &OutstationHandle,
+        config: OutstationConfig,
+        application: Box<dyn OutstationApplication>,
+        information: Box<dyn OutstationInformation>,
+        control_handler: Box<dyn ControlHandler>,
+        listener: Box<dyn Listener<ConnectionState>>,
+        filter: AddressFilter,
+    ) -> Result<OutstationHandle, AddAssociationError> {
+        let (handle, future) = self.add_association_no_spawn(
+            outstation,
+            config,
+            application,
+            information,
+            control_handler,
+            listener,
+            filter,
+        )?;
+        tokio::spawn(future);
+        Ok(handle)
+    }
+
+    fn check_filter(&self, filter: &AddressFilter) -> Result<(), FilterError> {
+        for item in self.outstations.iter() {
+            if filter.conflicts_with(&item.filter) {
+                return Err(FilterError::Conflict);
+            }
+        }
+        Ok(())
+    }
+
+    fn add_task(
+        &mut self,
+        task: OutstationTask,
+        handle: OutstationHandle,
+        config: OutstationConfig,
+        listener: Box<dyn Listener<ConnectionState>>,
+        filter: AddressFilter,
+    ) -> (OutstationHandle, impl std::future::Future<Output = ()>) {
         let (mut adapter, tx) = ServerTask::create(Session::outstation(task), listener);
 
         let outstation = OutstationInfo {
@@ -126,7 +251,7 @@ impl Server {
                 )
                 .await;
         };
-        Ok((handle, future))
+        (handle, future)
     }
 
     /// associate an outstation with the TcpServer and spawn it
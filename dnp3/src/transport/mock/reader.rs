@@ -4,7 +4,7 @@ use crate::link::header::FrameInfo;
 use crate::link::reader::LinkModes;
 use crate::link::EndpointAddress;
 use crate::outstation::Feature;
-use crate::transport::{Fragment, FragmentAddr, FragmentInfo, TransportData};
+use crate::transport::{Fragment, FragmentAddr, FragmentInfo, TransportCounters, TransportData};
 use crate::util::buffer::Buffer;
 use crate::util::phys::{PhysAddr, PhysLayer};
 
@@ -86,6 +86,7 @@ impl MockReader {
         &mut self,
         io: &mut PhysLayer,
         level: DecodeLevel,
+        counters: &TransportCounters,
     ) -> Result<(), LinkError> {
         if self.count > 0 {
             return Ok(());
@@ -100,6 +101,9 @@ impl MockReader {
             .await?;
         self.count = count;
         self.frame_id = self.frame_id.wrapping_add(1);
+        // each fragment is treated as a single frame and segment
+        counters.on_link_frame_rx();
+        counters.on_segment_rx();
         Ok(())
     }
 }
@@ -2,7 +2,7 @@ use crate::app::EndpointType;
 use crate::decode::DecodeLevel;
 use crate::link::error::LinkError;
 use crate::link::EndpointAddress;
-use crate::transport::FragmentAddr;
+use crate::transport::{FragmentAddr, TransportCounters};
 use crate::util::phys::PhysLayer;
 
 pub(crate) struct MockWriter {
@@ -28,9 +28,13 @@ impl MockWriter {
         level: DecodeLevel,
         destination: FragmentAddr,
         fragment: &[u8],
+        counters: &TransportCounters,
     ) -> Result<(), LinkError> {
         io.write(fragment, destination.phys, level.physical).await?;
         self.num_writes += 1;
+        // each fragment is treated as a single frame and segment
+        counters.on_link_frame_tx();
+        counters.on_segment_tx();
         Ok(())
     }
 
@@ -39,7 +43,9 @@ impl MockWriter {
         _: &mut PhysLayer,
         _: FragmentAddr,
         _: DecodeLevel,
+        counters: &TransportCounters,
     ) -> Result<(), LinkError> {
+        counters.on_link_frame_tx();
         Ok(())
     }
 }
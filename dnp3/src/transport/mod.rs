@@ -3,6 +3,8 @@ pub(crate) use types::*;
 pub(crate) use writer::*;
 
 use crate::app::parse::options::ParseOptions;
+use std::sync::Arc;
+
 use crate::app::{BufferSize, EndpointType};
 use crate::link::reader::LinkModes;
 use crate::link::EndpointAddress;
@@ -23,9 +25,16 @@ pub(crate) fn create_master_transport_layer(
     address: EndpointAddress,
     rx_buffer_size: BufferSize<2048, 2048>,
 ) -> (TransportReader, TransportWriter) {
+    let counters = Arc::new(TransportCounters::default());
     (
-        TransportReader::master(link_modes, parse_options, address, rx_buffer_size.value()),
-        TransportWriter::new(EndpointType::Master, address),
+        TransportReader::master(
+            link_modes,
+            parse_options,
+            address,
+            rx_buffer_size.value(),
+            counters.clone(),
+        ),
+        TransportWriter::new(EndpointType::Master, address, counters),
     )
 }
 
@@ -36,6 +45,7 @@ pub(crate) fn create_outstation_transport_layer(
     self_address: Feature,
     rx_buffer_size: BufferSize,
 ) -> (TransportReader, TransportWriter) {
+    let counters = Arc::new(TransportCounters::default());
     (
         TransportReader::outstation(
             link_modes,
@@ -43,7 +53,8 @@ pub(crate) fn create_outstation_transport_layer(
             address,
             self_address,
             rx_buffer_size.value(),
+            counters.clone(),
         ),
-        TransportWriter::new(EndpointType::Outstation, address),
+        TransportWriter::new(EndpointType::Outstation, address, counters),
     )
 }
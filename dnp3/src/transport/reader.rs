@@ -7,10 +7,11 @@ use crate::link::reader::LinkModes;
 use crate::link::EndpointAddress;
 use crate::outstation::Feature;
 use crate::transport::{
-    FragmentAddr, FragmentInfo, LinkLayerMessage, TransportData, TransportRequest,
-    TransportResponse,
+    FragmentAddr, FragmentInfo, LinkLayerMessage, TransportCounters, TransportData,
+    TransportRequest, TransportResponse,
 };
 use crate::util::phys::PhysLayer;
+use std::sync::Arc;
 
 #[cfg(not(test))]
 /// This type definition is used so that we can mock the transport reader during testing.
@@ -22,6 +23,7 @@ pub(crate) type InnerReaderType = crate::transport::mock::reader::MockReader;
 pub(crate) struct TransportReader {
     parse_options: ParseOptions,
     inner: InnerReaderType,
+    counters: Arc<TransportCounters>,
 }
 
 pub(crate) struct RequestGuard<'a> {
@@ -60,10 +62,12 @@ impl TransportReader {
         parse_options: ParseOptions,
         address: EndpointAddress,
         rx_buffer_size: usize,
+        counters: Arc<TransportCounters>,
     ) -> Self {
         Self {
             parse_options,
             inner: InnerReaderType::master(link_modes, address, rx_buffer_size),
+            counters,
         }
     }
 
@@ -73,13 +77,20 @@ impl TransportReader {
         address: EndpointAddress,
         self_address: Feature,
         rx_buffer_size: usize,
+        counters: Arc<TransportCounters>,
     ) -> Self {
         Self {
             parse_options,
             inner: InnerReaderType::outstation(link_modes, address, self_address, rx_buffer_size),
+            counters,
         }
     }
 
+    /// counters shared by the reader and writer of this transport layer
+    pub(crate) fn counters(&self) -> Arc<TransportCounters> {
+        self.counters.clone()
+    }
+
     #[cfg(test)]
     pub(crate) fn get_inner(&mut self) -> &mut InnerReaderType {
         &mut self.inner
@@ -94,7 +105,7 @@ impl TransportReader {
         io: &mut PhysLayer,
         decode_level: DecodeLevel,
     ) -> Result<(), LinkError> {
-        self.inner.read(io, decode_level).await?;
+        self.inner.read(io, decode_level, &self.counters).await?;
         if decode_level.application.enabled() {
             self.decode(decode_level.application);
         }
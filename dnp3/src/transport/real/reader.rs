@@ -9,7 +9,7 @@ use crate::outstation::Feature;
 use crate::transport::real::assembler::{Assembler, AssemblyState};
 use crate::transport::real::display::SegmentDisplay;
 use crate::transport::real::header::Header;
-use crate::transport::{LinkLayerMessage, LinkLayerMessageType, TransportData};
+use crate::transport::{LinkLayerMessage, LinkLayerMessageType, TransportCounters, TransportData};
 use crate::util::phys::PhysLayer;
 
 pub(crate) struct Reader {
@@ -86,6 +86,7 @@ impl Reader {
         &mut self,
         io: &mut PhysLayer,
         level: DecodeLevel,
+        counters: &TransportCounters,
     ) -> Result<(), LinkError> {
         if self.assembler.peek().is_some() {
             return Ok(());
@@ -95,10 +96,12 @@ impl Reader {
 
         loop {
             let info = self.link.read(io, level, &mut payload).await?;
+            counters.on_link_frame_rx();
 
             match info.frame_type {
                 FrameType::Data => match payload.get() {
                     [transport, data @ ..] => {
+                        counters.on_segment_rx();
                         let header = Header::from_u8(*transport);
                         if level.transport.enabled() {
                             tracing::info!(
@@ -8,7 +8,7 @@ use crate::transport::real::header::Header;
 use crate::transport::real::sequence::Sequence;
 use crate::util::phys::PhysLayer;
 
-use crate::transport::FragmentAddr;
+use crate::transport::{FragmentAddr, TransportCounters};
 use scursor::WriteCursor;
 
 pub(crate) struct Writer {
@@ -38,6 +38,7 @@ impl Writer {
         level: DecodeLevel,
         destination: FragmentAddr,
         fragment: &[u8],
+        counters: &TransportCounters,
     ) -> Result<(), LinkError> {
         let chunks = fragment.chunks(crate::link::constant::MAX_APP_BYTES_PER_FRAME);
 
@@ -71,6 +72,8 @@ impl Writer {
             }
             io.write(data.frame, destination.phys, level.physical)
                 .await?;
+            counters.on_link_frame_tx();
+            counters.on_segment_tx();
         }
 
         Ok(())
@@ -81,6 +84,7 @@ impl Writer {
         io: &mut PhysLayer,
         destination: FragmentAddr,
         level: DecodeLevel,
+        counters: &TransportCounters,
     ) -> Result<(), LinkError> {
         let mut cursor = WriteCursor::new(&mut self.buffer);
         let header = crate::link::header::Header::request_link_status(
@@ -95,6 +99,7 @@ impl Writer {
         }
         io.write(data.frame, destination.phys, level.physical)
             .await?;
+        counters.on_link_frame_tx();
 
         Ok(())
     }
@@ -2,7 +2,9 @@ use crate::app::parse::parser::{Request, Response};
 use crate::app::{HeaderParseError, RequestValidationError, ResponseValidationError, Sequence};
 use crate::link::header::BroadcastConfirmMode;
 use crate::link::EndpointAddress;
+use crate::outstation::TransportStatistics;
 use crate::util::phys::PhysAddr;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 #[derive(Debug, Copy, Clone)]
 pub(crate) struct FragmentAddr {
@@ -104,3 +106,40 @@ impl From<HeaderParseError> for TransportRequestError {
         Self::HeaderParseError(from)
     }
 }
+
+/// counts of the link frames and transport segments processed by a transport layer, shared with
+/// the handle so that they can be read while the task is running
+#[derive(Default)]
+pub(crate) struct TransportCounters {
+    link_frames_rx: AtomicU64,
+    link_frames_tx: AtomicU64,
+    segments_rx: AtomicU64,
+    segments_tx: AtomicU64,
+}
+
+impl TransportCounters {
+    pub(crate) fn on_link_frame_rx(&self) {
+        self.link_frames_rx.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn on_link_frame_tx(&self) {
+        self.link_frames_tx.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn on_segment_rx(&self) {
+        self.segments_rx.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn on_segment_tx(&self) {
+        self.segments_tx.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn get(&self) -> TransportStatistics {
+        TransportStatistics {
+            link_frames_rx: self.link_frames_rx.load(Ordering::Relaxed),
+            link_frames_tx: self.link_frames_tx.load(Ordering::Relaxed),
+            segments_rx: self.segments_rx.load(Ordering::Relaxed),
+            segments_tx: self.segments_tx.load(Ordering::Relaxed),
+        }
+    }
+}
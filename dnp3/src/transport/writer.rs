@@ -4,8 +4,9 @@ use crate::app::EndpointType;
 use crate::decode::DecodeLevel;
 use crate::link::error::LinkError;
 use crate::link::EndpointAddress;
-use crate::transport::FragmentAddr;
+use crate::transport::{FragmentAddr, TransportCounters};
 use crate::util::phys::PhysLayer;
+use std::sync::Arc;
 
 /// This type definition is used so that we can mock the transport writer during testing.
 /// If Rust eventually allows `async fn` in traits, this could be removed
@@ -16,12 +17,18 @@ pub(crate) type InnerTransportWriter = crate::transport::mock::writer::MockWrite
 
 pub(crate) struct TransportWriter {
     inner: InnerTransportWriter,
+    counters: Arc<TransportCounters>,
 }
 
 impl TransportWriter {
-    pub(crate) fn new(endpoint_type: EndpointType, local_address: EndpointAddress) -> Self {
+    pub(crate) fn new(
+        endpoint_type: EndpointType,
+        local_address: EndpointAddress,
+        counters: Arc<TransportCounters>,
+    ) -> Self {
         Self {
             inner: InnerTransportWriter::new(endpoint_type, local_address),
+            counters,
         }
     }
 
@@ -47,7 +54,9 @@ impl TransportWriter {
                 }
             }
         }
-        self.inner.write(io, level, destination, fragment).await
+        self.inner
+            .write(io, level, destination, fragment, &self.counters)
+            .await
     }
 
     pub(crate) async fn send_link_status_request(
@@ -56,6 +65,8 @@ impl TransportWriter {
         level: DecodeLevel,
         dest: FragmentAddr,
     ) -> Result<(), LinkError> {
-        self.inner.write_link_status_request(io, dest, level).await
+        self.inner
+            .write_link_status_request(io, dest, level, &self.counters)
+            .await
     }
 }
@@ -552,6 +552,7 @@ async fn run_server(mut server: Server, cli: &CliArgs) -> Result<(), Box<dyn std
                     s_var: StaticAnalogInputVariation::Group30Var1,
                     e_var: EventAnalogInputVariation::Group32Var1,
                     deadband: 0.0,
+                    deadband_mode: DeadbandMode::Fixed,
                 },
             );
             db.add(
@@ -460,6 +460,7 @@ impl From<ResponseHeader> for ffi::ResponseHeader {
             func: match header.function {
                 ResponseFunction::Response => ffi::ResponseFunction::Response,
                 ResponseFunction::UnsolicitedResponse => ffi::ResponseFunction::UnsolicitedResponse,
+                ResponseFunction::AuthResponse => ffi::ResponseFunction::AuthResponse,
             },
             iin: ffi::Iin {
                 iin1: header.iin.iin1.into(),
@@ -512,6 +513,9 @@ impl From<TaskType> for ffi::TaskType {
             TaskType::FileOpen => ffi::TaskType::FileOpen,
             TaskType::FileClose => ffi::TaskType::FileClose,
             TaskType::FileAuth => ffi::TaskType::FileAuth,
+            TaskType::SessionKeyChange => ffi::TaskType::SessionKeyChange,
+            TaskType::UserStatusChange => ffi::TaskType::UserStatusChange,
+            TaskType::UpdateKeyChange => ffi::TaskType::UpdateKeyChange,
         }
     }
 }
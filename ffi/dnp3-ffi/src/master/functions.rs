@@ -424,6 +424,8 @@ impl TryFrom<ffi::AssociationConfig> for AssociationConfig {
                 config.event_scan_on_events_available(),
             ),
             max_queued_user_requests: config.max_queued_user_requests as usize,
+            // secure authentication is not exposed in the bindings
+            authentication: None,
         })
     }
 }
@@ -1350,6 +1352,7 @@ macro_rules! define_task_from_impl {
                     TaskError::Disabled => Self::NoConnection,
                     TaskError::BadEncoding(_) => Self::BadEncoding,
                     TaskError::RejectedByIin2(_) => Self::IinError,
+                    TaskError::Authentication(_) => Self::AuthenticationFailed,
                 }
             }
         }
@@ -1417,8 +1420,12 @@ impl From<ffi::FunctionCode> for dnp3::app::FunctionCode {
             ffi::FunctionCode::GetFileInfo => Self::GetFileInfo,
             ffi::FunctionCode::AuthenticateFile => Self::AuthenticateFile,
             ffi::FunctionCode::AbortFile => Self::AbortFile,
+            ffi::FunctionCode::ActivateConfig => Self::ActivateConfig,
+            ffi::FunctionCode::AuthRequest => Self::AuthRequest,
+            ffi::FunctionCode::AuthRequestNoAck => Self::AuthRequestNoAck,
             ffi::FunctionCode::Response => Self::Response,
             ffi::FunctionCode::UnsolicitedResponse => Self::UnsolicitedResponse,
+            ffi::FunctionCode::AuthResponse => Self::AuthResponse,
         }
     }
 }
@@ -565,8 +565,12 @@ impl From<FunctionCode> for ffi::FunctionCode {
             FunctionCode::GetFileInfo => ffi::FunctionCode::GetFileInfo,
             FunctionCode::AuthenticateFile => ffi::FunctionCode::AuthenticateFile,
             FunctionCode::AbortFile => ffi::FunctionCode::AbortFile,
+            FunctionCode::ActivateConfig => ffi::FunctionCode::ActivateConfig,
+            FunctionCode::AuthRequest => ffi::FunctionCode::AuthRequest,
+            FunctionCode::AuthRequestNoAck => ffi::FunctionCode::AuthRequestNoAck,
             FunctionCode::Response => ffi::FunctionCode::Response,
             FunctionCode::UnsolicitedResponse => ffi::FunctionCode::UnsolicitedResponse,
+            FunctionCode::AuthResponse => ffi::FunctionCode::AuthResponse,
         }
     }
 }
@@ -730,6 +730,8 @@ impl From<ffi::AnalogInputConfig> for AnalogInputConfig {
                 }
             },
             deadband: from.deadband(),
+            // deadband modes are not exposed in the bindings
+            deadband_mode: DeadbandMode::Fixed,
         }
     }
 }
@@ -788,6 +790,8 @@ impl From<ffi::AnalogOutputStatusConfig> for AnalogOutputStatusConfig {
                 }
             },
             deadband: from.deadband(),
+            // deadband modes are not exposed in the bindings
+            deadband_mode: DeadbandMode::Fixed,
         }
     }
 }
@@ -5,8 +5,11 @@ use std::time::Duration;
 pub use database::*;
 use dnp3::app::{BufferSize, BufferSizeError, Listener, MaybeAsync, Timeout};
 use dnp3::link::{EndpointAddress, LinkErrorMode, LinkReadMode};
-use dnp3::outstation::database::{ClassZeroConfig, EventBufferConfig};
-use dnp3::outstation::{ConnectionState, Feature, Features, OutstationConfig, OutstationHandle};
+use dnp3::outstation::database::{ClassZeroConfig, EventBufferConfig, EventOverflowPolicy};
+use dnp3::outstation::{
+    ConnectionState, Feature, Features, OfflineAssociationPolicy, OutstationConfig,
+    OutstationHandle, UnsolicitedHoldConfig,
+};
 use dnp3::tcp::{FilterError, ServerHandle};
 pub use struct_constructors::*;
 
@@ -646,10 +649,16 @@ fn convert_outstation_config(
         features: config.features().into(),
         max_unsolicited_retries: Some(config.max_unsolicited_retries() as usize),
         unsolicited_retry_delay: config.unsolicited_retry_delay(),
+        // unsolicited batching is not exposed in the bindings
+        unsolicited_hold: UnsolicitedHoldConfig::default(),
         keep_alive_timeout,
         class_zero: config.class_zero.into(),
         max_read_request_headers: Some(config.max_read_request_headers),
         max_controls_per_request: Some(config.max_controls_per_request),
+        // secure authentication is not exposed in the bindings
+        authentication: None,
+        // multiple masters are not exposed in the bindings
+        offline_association_policy: OfflineAssociationPolicy::default(),
     })
 }
 
@@ -692,6 +701,10 @@ impl From<&ffi::OutstationFeatures> for Features {
             broadcast: to_feature(from.broadcast()),
             unsolicited: to_feature(from.unsolicited()),
             respond_to_any_master: to_feature(from.respond_to_any_master()),
+            // command events are not exposed in the bindings
+            command_events: Feature::Disabled,
+            // the freeze engine is not exposed in the bindings
+            freeze_engine: Feature::Disabled,
         }
     }
 }
@@ -707,6 +720,10 @@ impl From<ffi::ClassZeroConfig> for ClassZeroConfig {
             analog: from.analog(),
             analog_output_status: from.analog_output_status(),
             octet_string: from.octet_string(),
+            // unsigned integers are not exposed in the bindings
+            unsigned_integer: false,
+            // security statistics are not exposed in the bindings
+            security_statistic: false,
         }
     }
 }
@@ -722,6 +739,22 @@ impl From<&ffi::EventBufferConfig> for EventBufferConfig {
             max_analog: from.max_analog(),
             max_analog_output_status: from.max_analog_output_status(),
             max_octet_string: from.max_octet_string(),
+            // file transfer is not exposed in the bindings
+            max_file_transfer: 0,
+            // command events are not exposed in the bindings
+            max_binary_output_command: 0,
+            max_analog_output_command: 0,
+            // data sets are not exposed in the bindings
+            max_data_set: 0,
+            // virtual terminals are not exposed in the bindings
+            max_virtual_terminal: 0,
+            // security statistics are not exposed in the bindings
+            max_security_statistic: 0,
+            // class limits and overflow policies are not exposed in the bindings
+            max_class_1: None,
+            max_class_2: None,
+            max_class_3: None,
+            overflow_policy: EventOverflowPolicy::DiscardOldest,
         }
     }
 }
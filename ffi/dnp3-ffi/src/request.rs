@@ -259,6 +259,9 @@ impl From<ffi::Variation> for Variation {
             ffi::Variation::Group11Var1 => Variation::Group11Var1,
             ffi::Variation::Group11Var2 => Variation::Group11Var2,
             ffi::Variation::Group12Var1 => Variation::Group12Var1,
+            ffi::Variation::Group12Var2 => Variation::Group12Var2,
+            ffi::Variation::Group12Var3 => Variation::Group12Var3,
+            ffi::Variation::Group13Var0 => Variation::Group13Var0,
             ffi::Variation::Group13Var1 => Variation::Group13Var1,
             ffi::Variation::Group13Var2 => Variation::Group13Var2,
             ffi::Variation::Group20Var0 => Variation::Group20Var0,
@@ -342,6 +345,7 @@ impl From<ffi::Variation> for Variation {
             ffi::Variation::Group42Var6 => Variation::Group42Var6,
             ffi::Variation::Group42Var7 => Variation::Group42Var7,
             ffi::Variation::Group42Var8 => Variation::Group42Var8,
+            ffi::Variation::Group43Var0 => Variation::Group43Var0,
             ffi::Variation::Group43Var1 => Variation::Group43Var1,
             ffi::Variation::Group43Var2 => Variation::Group43Var2,
             ffi::Variation::Group43Var3 => Variation::Group43Var3,
@@ -373,15 +377,41 @@ impl From<ffi::Variation> for Variation {
 
             ffi::Variation::Group80Var1 => Variation::Group80Var1,
 
+            ffi::Variation::Group85Var1 => Variation::Group85Var1,
+            ffi::Variation::Group86Var1 => Variation::Group86Var1,
+            ffi::Variation::Group87Var1 => Variation::Group87Var1,
+            ffi::Variation::Group88Var1 => Variation::Group88Var1,
+
+            ffi::Variation::Group90Var1 => Variation::Group90Var1,
+            ffi::Variation::Group91Var1 => Variation::Group91Var1,
+
             ffi::Variation::Group102Var0 => Variation::Group102Var0,
             ffi::Variation::Group102Var1 => Variation::Group102Var1,
 
             ffi::Variation::Group110 => Variation::Group110(0),
             ffi::Variation::Group111 => Variation::Group111(0),
-            /*
             ffi::Variation::Group112 => Variation::Group112(0),
             ffi::Variation::Group113 => Variation::Group113(0),
-             */
+
+            ffi::Variation::Group120Var1 => Variation::Group120Var1,
+            ffi::Variation::Group120Var2 => Variation::Group120Var2,
+            ffi::Variation::Group120Var3 => Variation::Group120Var3,
+            ffi::Variation::Group120Var4 => Variation::Group120Var4,
+            ffi::Variation::Group120Var5 => Variation::Group120Var5,
+            ffi::Variation::Group120Var6 => Variation::Group120Var6,
+            ffi::Variation::Group120Var7 => Variation::Group120Var7,
+            ffi::Variation::Group120Var9 => Variation::Group120Var9,
+            ffi::Variation::Group120Var10 => Variation::Group120Var10,
+            ffi::Variation::Group120Var11 => Variation::Group120Var11,
+            ffi::Variation::Group120Var12 => Variation::Group120Var12,
+            ffi::Variation::Group120Var13 => Variation::Group120Var13,
+            ffi::Variation::Group120Var14 => Variation::Group120Var14,
+            ffi::Variation::Group120Var15 => Variation::Group120Var15,
+            ffi::Variation::Group121Var0 => Variation::Group121Var0,
+            ffi::Variation::Group121Var1 => Variation::Group121Var1,
+            ffi::Variation::Group122Var0 => Variation::Group122Var0,
+            ffi::Variation::Group122Var1 => Variation::Group122Var1,
+            ffi::Variation::Group122Var2 => Variation::Group122Var2,
         }
     }
 }
@@ -412,6 +442,9 @@ impl From<Variation> for ffi::Variation {
             Variation::Group11Var1 => ffi::Variation::Group11Var1,
             Variation::Group11Var2 => ffi::Variation::Group11Var2,
             Variation::Group12Var1 => ffi::Variation::Group12Var1,
+            Variation::Group12Var2 => ffi::Variation::Group12Var2,
+            Variation::Group12Var3 => ffi::Variation::Group12Var3,
+            Variation::Group13Var0 => ffi::Variation::Group13Var0,
             Variation::Group13Var1 => ffi::Variation::Group13Var1,
             Variation::Group13Var2 => ffi::Variation::Group13Var2,
             Variation::Group20Var0 => ffi::Variation::Group20Var0,
@@ -485,6 +518,7 @@ impl From<Variation> for ffi::Variation {
             Variation::Group40Var3 => ffi::Variation::Group40Var3,
             Variation::Group40Var4 => ffi::Variation::Group40Var4,
 
+            Variation::Group43Var0 => ffi::Variation::Group43Var0,
             Variation::Group43Var1 => ffi::Variation::Group43Var1,
             Variation::Group43Var2 => ffi::Variation::Group43Var2,
             Variation::Group43Var3 => ffi::Variation::Group43Var3,
@@ -531,11 +565,41 @@ impl From<Variation> for ffi::Variation {
 
             Variation::Group80Var1 => ffi::Variation::Group80Var1,
 
+            Variation::Group85Var1 => ffi::Variation::Group85Var1,
+            Variation::Group86Var1 => ffi::Variation::Group86Var1,
+            Variation::Group87Var1 => ffi::Variation::Group87Var1,
+            Variation::Group88Var1 => ffi::Variation::Group88Var1,
+
+            Variation::Group90Var1 => ffi::Variation::Group90Var1,
+            Variation::Group91Var1 => ffi::Variation::Group91Var1,
+
             Variation::Group102Var0 => ffi::Variation::Group102Var0,
             Variation::Group102Var1 => ffi::Variation::Group102Var1,
 
             Variation::Group110(_) => ffi::Variation::Group110,
             Variation::Group111(_) => ffi::Variation::Group111,
+            Variation::Group112(_) => ffi::Variation::Group112,
+            Variation::Group113(_) => ffi::Variation::Group113,
+
+            Variation::Group120Var1 => ffi::Variation::Group120Var1,
+            Variation::Group120Var2 => ffi::Variation::Group120Var2,
+            Variation::Group120Var3 => ffi::Variation::Group120Var3,
+            Variation::Group120Var4 => ffi::Variation::Group120Var4,
+            Variation::Group120Var5 => ffi::Variation::Group120Var5,
+            Variation::Group120Var6 => ffi::Variation::Group120Var6,
+            Variation::Group120Var7 => ffi::Variation::Group120Var7,
+            Variation::Group120Var9 => ffi::Variation::Group120Var9,
+            Variation::Group120Var10 => ffi::Variation::Group120Var10,
+            Variation::Group120Var11 => ffi::Variation::Group120Var11,
+            Variation::Group120Var12 => ffi::Variation::Group120Var12,
+            Variation::Group120Var13 => ffi::Variation::Group120Var13,
+            Variation::Group120Var14 => ffi::Variation::Group120Var14,
+            Variation::Group120Var15 => ffi::Variation::Group120Var15,
+            Variation::Group121Var0 => ffi::Variation::Group121Var0,
+            Variation::Group121Var1 => ffi::Variation::Group121Var1,
+            Variation::Group122Var0 => ffi::Variation::Group122Var0,
+            Variation::Group122Var1 => ffi::Variation::Group122Var1,
+            Variation::Group122Var2 => ffi::Variation::Group122Var2,
         }
     }
 }
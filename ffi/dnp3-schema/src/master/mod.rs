@@ -1046,6 +1046,18 @@ fn define_association_information(
         .push("file_open", "Open a file on the outstation")?
         .push("file_write_block", "Write a file block to the outstation")?
         .push("file_close", "Close a file on the outstation")?
+        .push(
+            "session_key_change",
+            "Establish or change the secure authentication session keys",
+        )?
+        .push(
+            "user_status_change",
+            "Add, delete, or change a secure authentication user",
+        )?
+        .push(
+            "update_key_change",
+            "Change the update key of a secure authentication user",
+        )?
         .doc("Task type used in {interface:association_information}")?
         .build()?;
 
@@ -1218,6 +1230,10 @@ const TASK_ERRORS: &[(&str, &str)] = &[
     ("shutdown", "master was shutdown"),
     ("association_removed", "association was removed mid-task"),
     ("bad_encoding", "request data could not be encoded"),
+    (
+        "authentication_failed",
+        "secure authentication of the request failed",
+    ),
 ];
 
 pub(crate) trait TaskErrors: Sized {
@@ -9,6 +9,7 @@ pub(crate) fn define(
         .define_enum("response_function")?
         .push("response", "Solicited response")?
         .push("unsolicited_response", "Unsolicited response")?
+        .push("auth_response", "Secure authentication response")?
         .doc("Type of response")?
         .build()?;
 
@@ -873,8 +873,12 @@ fn define_function_code(lib: &mut LibraryBuilder) -> BackTraced<EnumHandle> {
         .push("get_file_info", "Outstation shall retrieve information about a file (value == 28)")?
         .push("authenticate_file", "Outstation shall return a file authentication key (value == 29)")?
         .push("abort_file", "Outstation shall abort a file transfer operation (value == 30)")?
+        .push("activate_config", "Outstation shall activate the configuration specified by the objects in the request (value == 31)")?
+        .push("auth_request", "Master or outstation requests that authentication be performed (value == 32)")?
+        .push("auth_request_no_ack", "Same as AuthRequest but the receiver shall not send a response (value == 33)")?
         .push("response", "Master shall interpret this fragment as an Application Layer response to an ApplicationLayer request (value == 129)")?
         .push("unsolicited_response", "Master shall interpret this fragment as an unsolicited response that was not prompted by an explicit request (value == 130)")?
+        .push("auth_response", "Authentication response to an AuthRequest (value == 131)")?
         .doc("Application layer function code")?
         .build()?;
 
@@ -42,6 +42,9 @@ pub(crate) fn define(lib: &mut LibraryBuilder) -> BackTraced<EnumHandle> {
             gv(12, 1),
             "Binary Output Command - Control Relay Output Block",
         )?
+        .push(gv(12, 2), "Binary Output Command - Pattern control block")?
+        .push(gv(12, 3), "Binary Output Command - Pattern mask")?
+        .push(gv(13, 0), "Binary Output Command Event - Default variation")?
         .push(
             gv(13, 1),
             "Binary Output Command Event - command status without time",
@@ -200,6 +203,7 @@ pub(crate) fn define(lib: &mut LibraryBuilder) -> BackTraced<EnumHandle> {
             gv(42, 8),
             "Analog Output Event - Double-precision floating point with time",
         )?
+        .push(gv(43, 0), "Analog Output Command Event - Default variation")?
         .push(
             gv(43, 1),
             "Analog Output Command Event - 32-bit without time",
@@ -258,12 +262,53 @@ pub(crate) fn define(lib: &mut LibraryBuilder) -> BackTraced<EnumHandle> {
         .push(gv(70, 8), "File control - file specification string")?
         // iin
         .push(gv(80, 1), "Internal Indications - Packed format")?
+        // data sets
+        .push(gv(85, 1), "Data-Set Prototype - With UUID")?
+        .push(gv(86, 1), "Data-Set Descriptor - Data-Set Contents")?
+        .push(gv(87, 1), "Data-Set - Present Value")?
+        .push(gv(88, 1), "Data-Set Event - Snapshot")?
+        // application identifier
+        .push(gv(90, 1), "Application Identifier")?
+        // activate configuration
+        .push(
+            gv(91, 1),
+            "Activate Configuration - Status of Requested Operations",
+        )?
         // unsigned integer
         .push(gv(102, 0), "Unsigned Integer - Default Variation")?
         .push(gv(102, 1), "Unsigned Integer - 8-bit")?
         // octet strings
         .push("group110", "Octet String")?
         .push("group111", "Octet String Event")?
+        // virtual terminals
+        .push("group112", "Virtual Terminal Output Block")?
+        .push("group113", "Virtual Terminal Event Data")?
+        // secure authentication
+        .push(gv(120, 1), "Authentication - challenge")?
+        .push(gv(120, 2), "Authentication - reply")?
+        .push(gv(120, 3), "Authentication - aggressive mode request")?
+        .push(gv(120, 4), "Authentication - session key status request")?
+        .push(gv(120, 5), "Authentication - session key status")?
+        .push(gv(120, 6), "Authentication - session key change")?
+        .push(gv(120, 7), "Authentication - error")?
+        .push(gv(120, 9), "Authentication - message authentication code")?
+        .push(gv(120, 10), "Authentication - user status change")?
+        .push(gv(120, 11), "Authentication - update key change request")?
+        .push(gv(120, 12), "Authentication - update key change reply")?
+        .push(gv(120, 13), "Authentication - update key change")?
+        .push(gv(120, 14), "Authentication - update key change signature")?
+        .push(
+            gv(120, 15),
+            "Authentication - update key change confirmation",
+        )?
+        .push(gv(121, 0), "Security Statistic - Default Variation")?
+        .push(gv(121, 1), "Security Statistic - 32-bit with flag")?
+        .push(gv(122, 0), "Security Statistic Event - Default Variation")?
+        .push(gv(122, 1), "Security Statistic Event - 32-bit with flag")?
+        .push(
+            gv(122, 2),
+            "Security Statistic Event - 32-bit with flag and time",
+        )?
         .doc("Group/Variation")?
         .build()?;
 